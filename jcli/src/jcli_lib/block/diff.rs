@@ -0,0 +1,315 @@
+use super::{load_block, Error};
+use chain_impl_mockchain::certificate::SignedCertificate;
+use jormungandr_lib::interfaces::{Block0Configuration, Initial, Value};
+use serde::Serialize;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::{Path, PathBuf},
+};
+use structopt::StructOpt;
+
+#[derive(StructOpt)]
+pub struct Diff {
+    /// the genesis file or block 0 to compare from
+    #[structopt(parse(from_os_str))]
+    before: PathBuf,
+
+    /// the genesis file or block 0 to compare to
+    #[structopt(parse(from_os_str))]
+    after: PathBuf,
+}
+
+impl Diff {
+    pub fn exec(self) -> Result<(), Error> {
+        let before = load_genesis(&self.before)?;
+        let after = load_genesis(&self.after)?;
+        let diff = GenesisDiff::new(&before, &after);
+        serde_yaml::to_writer(std::io::stdout(), &diff).map_err(Error::GenesisSerializationFailed)
+    }
+}
+
+/// loads either a genesis YAML file or an encoded block 0
+fn load_genesis(path: &Path) -> Result<Block0Configuration, Error> {
+    let bytes = std::fs::read(path).map_err(|source| Error::InputInvalid {
+        source,
+        path: path.to_path_buf(),
+    })?;
+    if let Ok(genesis) = serde_yaml::from_slice(&bytes) {
+        return Ok(genesis);
+    }
+    let block = load_block(bytes.as_slice()).map_err(|_| Error::GenesisUnrecognized {
+        path: path.to_path_buf(),
+    })?;
+    Ok(Block0Configuration::from_block(&block)?)
+}
+
+#[derive(Serialize)]
+struct GenesisDiff {
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    parameters: BTreeMap<String, Change<serde_json::Value>>,
+    #[serde(skip_serializing_if = "SetDiff::is_empty")]
+    consensus_leader_ids: SetDiff,
+    #[serde(skip_serializing_if = "SetDiff::is_empty")]
+    committees: SetDiff,
+    #[serde(skip_serializing_if = "FundsDiff::is_empty")]
+    funds: FundsDiff,
+    #[serde(skip_serializing_if = "SetDiff::is_empty")]
+    vote_plans: SetDiff,
+    /// the initial certificates other than the vote plans
+    #[serde(skip_serializing_if = "SetDiff::is_empty")]
+    certificates: SetDiff,
+}
+
+#[derive(Serialize)]
+struct Change<T> {
+    before: Option<T>,
+    after: Option<T>,
+}
+
+#[derive(Serialize)]
+struct SetDiff {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    added: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    removed: Vec<String>,
+}
+
+/// the funds of the genesis, summed per address
+#[derive(Serialize)]
+struct FundsDiff {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    total: Option<Change<Value>>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    added: BTreeMap<String, Value>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    removed: BTreeMap<String, Value>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    changed: BTreeMap<String, Change<Value>>,
+}
+
+/// the content of a genesis, as compared by the diff
+struct Summary {
+    parameters: serde_json::Map<String, serde_json::Value>,
+    consensus_leader_ids: BTreeSet<String>,
+    committees: BTreeSet<String>,
+    funds: BTreeMap<String, u64>,
+    vote_plans: BTreeSet<String>,
+    certificates: BTreeSet<String>,
+}
+
+impl GenesisDiff {
+    fn new(before: &Block0Configuration, after: &Block0Configuration) -> Self {
+        let before = Summary::new(before);
+        let after = Summary::new(after);
+
+        let keys: BTreeSet<&String> = before
+            .parameters
+            .keys()
+            .chain(after.parameters.keys())
+            .collect();
+        let parameters = keys
+            .into_iter()
+            .filter_map(|key| {
+                let before = before.parameters.get(key);
+                let after = after.parameters.get(key);
+                if before == after {
+                    return None;
+                }
+                Some((
+                    key.clone(),
+                    Change {
+                        before: before.cloned(),
+                        after: after.cloned(),
+                    },
+                ))
+            })
+            .collect();
+
+        GenesisDiff {
+            parameters,
+            consensus_leader_ids: SetDiff::new(
+                &before.consensus_leader_ids,
+                &after.consensus_leader_ids,
+            ),
+            committees: SetDiff::new(&before.committees, &after.committees),
+            funds: FundsDiff::new(&before.funds, &after.funds),
+            vote_plans: SetDiff::new(&before.vote_plans, &after.vote_plans),
+            certificates: SetDiff::new(&before.certificates, &after.certificates),
+        }
+    }
+}
+
+impl Summary {
+    fn new(genesis: &Block0Configuration) -> Self {
+        let mut parameters = match serde_json::to_value(&genesis.blockchain_configuration) {
+            Ok(serde_json::Value::Object(parameters)) => parameters,
+            _ => serde_json::Map::new(),
+        };
+        let consensus_leader_ids = strings(parameters.remove("consensus_leader_ids"));
+        let committees = strings(parameters.remove("committees"));
+
+        let mut funds = BTreeMap::new();
+        let mut vote_plans = BTreeSet::new();
+        let mut certificates = BTreeSet::new();
+        for initial in &genesis.initial {
+            match initial {
+                Initial::Fund(utxos) => {
+                    for utxo in utxos {
+                        *funds.entry(utxo.address.to_string()).or_insert(0) +=
+                            u64::from(utxo.value);
+                    }
+                }
+                Initial::LegacyFund(utxos) => {
+                    for utxo in utxos {
+                        *funds.entry(utxo.address.to_string()).or_insert(0) +=
+                            u64::from(utxo.value);
+                    }
+                }
+                Initial::Cert(cert) => match &cert.0 {
+                    SignedCertificate::VotePlan(vote_plan, _) => {
+                        vote_plans.insert(vote_plan.to_id().to_string());
+                    }
+                    _ => {
+                        certificates.insert(cert.to_string());
+                    }
+                },
+            }
+        }
+
+        Summary {
+            parameters,
+            consensus_leader_ids,
+            committees,
+            funds,
+            vote_plans,
+            certificates,
+        }
+    }
+}
+
+fn strings(value: Option<serde_json::Value>) -> BTreeSet<String> {
+    match value {
+        Some(serde_json::Value::Array(values)) => values
+            .into_iter()
+            .map(|value| match value {
+                serde_json::Value::String(value) => value,
+                value => value.to_string(),
+            })
+            .collect(),
+        _ => BTreeSet::new(),
+    }
+}
+
+impl SetDiff {
+    fn new(before: &BTreeSet<String>, after: &BTreeSet<String>) -> Self {
+        SetDiff {
+            added: after.difference(before).cloned().collect(),
+            removed: before.difference(after).cloned().collect(),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+impl FundsDiff {
+    fn new(before: &BTreeMap<String, u64>, after: &BTreeMap<String, u64>) -> Self {
+        let mut added = BTreeMap::new();
+        let mut removed = BTreeMap::new();
+        let mut changed = BTreeMap::new();
+        for (address, value) in after {
+            match before.get(address) {
+                None => {
+                    added.insert(address.clone(), Value::from(*value));
+                }
+                Some(previous) if previous != value => {
+                    changed.insert(
+                        address.clone(),
+                        Change {
+                            before: Some(Value::from(*previous)),
+                            after: Some(Value::from(*value)),
+                        },
+                    );
+                }
+                Some(_) => {}
+            }
+        }
+        for (address, value) in before {
+            if !after.contains_key(address) {
+                removed.insert(address.clone(), Value::from(*value));
+            }
+        }
+
+        let total_before: u64 = before.values().sum();
+        let total_after: u64 = after.values().sum();
+        let total = if total_before != total_after {
+            Some(Change {
+                before: Some(Value::from(total_before)),
+                after: Some(Value::from(total_after)),
+            })
+        } else {
+            None
+        };
+
+        FundsDiff {
+            total,
+            added,
+            removed,
+            changed,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.total.is_none()
+            && self.added.is_empty()
+            && self.removed.is_empty()
+            && self.changed.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(values: &[&str]) -> BTreeSet<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn set_diff_lists_added_and_removed_entries() {
+        let diff = SetDiff::new(&set(&["a", "b"]), &set(&["b", "c"]));
+        assert_eq!(diff.added, vec!["c".to_string()]);
+        assert_eq!(diff.removed, vec!["a".to_string()]);
+        assert!(SetDiff::new(&set(&["a"]), &set(&["a"])).is_empty());
+    }
+
+    #[test]
+    fn funds_diff_compares_the_funds_per_address() {
+        let before: BTreeMap<String, u64> = vec![
+            ("kept".to_string(), 10),
+            ("changed".to_string(), 20),
+            ("removed".to_string(), 30),
+        ]
+        .into_iter()
+        .collect();
+        let after: BTreeMap<String, u64> = vec![
+            ("kept".to_string(), 10),
+            ("changed".to_string(), 25),
+            ("added".to_string(), 5),
+        ]
+        .into_iter()
+        .collect();
+
+        let diff = FundsDiff::new(&before, &after);
+        assert_eq!(diff.added["added"], Value::from(5));
+        assert_eq!(diff.removed["removed"], Value::from(30));
+        assert_eq!(diff.changed["changed"].after, Some(Value::from(25)));
+        assert!(!diff.changed.contains_key("kept"));
+        let total = diff.total.unwrap();
+        assert_eq!(total.before, Some(Value::from(60)));
+        assert_eq!(total.after, Some(Value::from(40)));
+
+        assert!(FundsDiff::new(&before, &before).is_empty());
+    }
+}
@@ -1,3 +1,5 @@
+mod diff;
+
 use crate::jcli_lib::utils::io;
 use chain_core::property::{Block as _, Deserialize, Serialize};
 use chain_impl_mockchain::{
@@ -39,6 +41,8 @@ pub enum Error {
     BlockSerializationFailed(#[source] std::io::Error),
     #[error("failed to serialize genesis")]
     GenesisSerializationFailed(#[source] serde_yaml::Error),
+    #[error("'{path}' is neither a genesis file nor a block 0")]
+    GenesisUnrecognized { path: PathBuf },
     #[error("failed to build genesis from block 0")]
     BuildingGenesisFromBlock0Failed(#[from] Block0ConfigurationError),
 }
@@ -53,6 +57,7 @@ impl Genesis {
             Genesis::Encode(create_arguments) => encode_block_0(create_arguments),
             Genesis::Decode(info_arguments) => decode_block_0(info_arguments),
             Genesis::Hash(hash_arguments) => print_hash(hash_arguments),
+            Genesis::Diff(diff) => diff.exec(),
         }
     }
 }
@@ -102,6 +107,11 @@ pub enum Genesis {
 
     /// print the block hash (aka the block id) of the block 0
     Hash(Input),
+
+    /// print the differences between two genesis, each given either as
+    /// a YAML file or as a block 0: the blockchain parameters, the
+    /// consensus leaders, the committees, the funds and the vote plans
+    Diff(diff::Diff),
 }
 
 #[derive(StructOpt)]
@@ -6,6 +6,7 @@ mod new_stake_pool_retirement;
 mod new_vote_cast;
 mod new_vote_plan;
 mod new_vote_tally;
+mod ownership_proof;
 mod show;
 mod sign;
 mod weighted_pool_ids;
@@ -111,6 +112,20 @@ pub enum Error {
     SharesError(#[from] SharesError),
     #[error("expected decrypted private tally, found {found}")]
     PrivateTallyExpected { found: &'static str },
+    #[error("invalid ownership proof: {0}")]
+    OwnershipProofInvalid(&'static str),
+    #[error("ownership proof signature number {index} is invalid")]
+    OwnershipProofSignatureFailed { index: usize },
+    #[error("ownership proof signature number {index} is not from an owner of the stake pool")]
+    OwnershipProofNotOwner { index: usize },
+    #[error("ownership proof is bound to the identity '{actual}', expected '{expected}'")]
+    OwnershipProofIdentityMismatch { expected: String, actual: String },
+    #[error("ownership proof is for the stake pool {actual}, expected {expected}")]
+    OwnershipProofPoolMismatch { expected: String, actual: String },
+    #[error(
+        "ownership proof is signed by {got} pool owners, the management threshold is {threshold}"
+    )]
+    OwnershipProofBelowThreshold { got: usize, threshold: usize },
 }
 
 #[allow(clippy::large_enum_variant)]
@@ -126,6 +141,8 @@ pub enum Certificate {
     Show(show::ShowArgs),
     /// Print certificate
     Print(PrintArgs),
+    /// Prove the ownership of a stake pool off-chain
+    OwnershipProof(ownership_proof::OwnershipProof),
 }
 
 #[allow(clippy::large_enum_variant)]
@@ -216,6 +233,7 @@ impl Certificate {
             Certificate::Sign(args) => args.exec()?,
             Certificate::Print(args) => args.exec()?,
             Certificate::Show(args) => args.exec()?,
+            Certificate::OwnershipProof(args) => args.exec()?,
         }
 
         Ok(())
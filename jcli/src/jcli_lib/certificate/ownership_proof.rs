@@ -0,0 +1,205 @@
+use crate::jcli_lib::{
+    certificate::{read_cert, read_input, write_output, Error},
+    utils::{
+        io,
+        key_parser::{parse_ed25519_secret_key, parse_pub_key},
+    },
+};
+use chain_crypto::{bech32::Bech32 as _, Ed25519, PublicKey, Signature, Verification};
+use chain_impl_mockchain::certificate::{Certificate, PoolId, PoolRegistration};
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeSet, path::PathBuf};
+use structopt::StructOpt;
+
+const MESSAGE_PREFIX: &str = "jormungandr stake pool ownership proof";
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub enum OwnershipProof {
+    /// sign a proof binding the stake pool to an external identity
+    /// (a web site, a ticker, an account of a registration portal...)
+    /// with the secret keys of the pool owners
+    Create(Create),
+    /// verify that an ownership proof is signed by the owners of the pool
+    Verify(Verify),
+}
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct Create {
+    /// the external identity the stake pool is bound to
+    #[structopt(long = "identity")]
+    pub identity: String,
+    /// path to the file with the secret key of a pool owner
+    #[structopt(short = "k", long = "key")]
+    pub signing_keys: Vec<PathBuf>,
+    /// get the stake pool registration certificate from the given file.
+    /// If no file provided, it will be read from the standard input
+    #[structopt(short = "c", long = "certificate")]
+    pub input: Option<PathBuf>,
+    /// write the proof into the given file. If no file provided it will
+    /// be written into the standard output
+    #[structopt(short = "o", long = "output")]
+    pub output: Option<PathBuf>,
+}
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct Verify {
+    /// get the proof from the given file. If no file provided, it will be
+    /// read from the standard input
+    #[structopt(short = "p", long = "proof")]
+    pub proof: Option<PathBuf>,
+    /// the stake pool registration certificate, the signers are checked to
+    /// be owners of the pool and to reach its management threshold
+    #[structopt(short = "c", long = "certificate")]
+    pub certificate: PathBuf,
+    /// the identity the proof is expected to be bound to
+    #[structopt(long = "identity")]
+    pub identity: Option<String>,
+}
+
+/// Signed statement of the pool owners that the stake pool belongs to the
+/// given identity, meant to be checked off-chain.
+#[derive(Serialize, Deserialize)]
+struct Proof {
+    pool_id: String,
+    identity: String,
+    message: String,
+    signatures: Vec<OwnerSignature>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct OwnerSignature {
+    public_key: String,
+    signature: String,
+}
+
+impl OwnershipProof {
+    pub fn exec(self) -> Result<(), Error> {
+        match self {
+            OwnershipProof::Create(args) => args.exec(),
+            OwnershipProof::Verify(args) => args.exec(),
+        }
+    }
+}
+
+impl Create {
+    fn exec(self) -> Result<(), Error> {
+        let registration = read_registration(self.input)?;
+        if self.signing_keys.is_empty() {
+            return Err(Error::NoSigningKeys);
+        }
+
+        let pool_id = registration.to_id();
+        let message = message(&pool_id, &self.identity);
+        let mut signatures = Vec::new();
+        for (index, path) in self.signing_keys.iter().enumerate() {
+            let key = parse_ed25519_secret_key(read_input(Some(path))?.trim())?;
+            let public_key = key.to_public();
+            if !registration.owners.contains(&public_key) {
+                return Err(Error::KeyNotFound { index });
+            }
+            let signature: Signature<String, Ed25519> = key.sign(&message);
+            signatures.push(OwnerSignature {
+                public_key: public_key.to_bech32_str(),
+                signature: signature.to_bech32_str(),
+            });
+        }
+
+        let proof = Proof {
+            pool_id: pool_id.to_string(),
+            identity: self.identity,
+            message,
+            signatures,
+        };
+        write_output(self.output, serde_json::to_string_pretty(&proof)?)
+    }
+}
+
+impl Verify {
+    fn exec(self) -> Result<(), Error> {
+        let reader = io::open_file_read(&self.proof).map_err(|source| Error::InputInvalid {
+            source,
+            path: self.proof.clone().unwrap_or_default(),
+        })?;
+        let proof: Proof = serde_json::from_reader(reader)?;
+        let registration = read_registration(Some(self.certificate))?;
+
+        let pool_id: PoolId = proof
+            .pool_id
+            .parse()
+            .map_err(|_| Error::OwnershipProofInvalid("invalid stake pool id"))?;
+        // a consistent signature proves nothing unless the signer owns the pool
+        if registration.to_id() != pool_id {
+            return Err(Error::OwnershipProofPoolMismatch {
+                expected: registration.to_id().to_string(),
+                actual: proof.pool_id,
+            });
+        }
+        if let Some(identity) = &self.identity {
+            if identity != &proof.identity {
+                return Err(Error::OwnershipProofIdentityMismatch {
+                    expected: identity.clone(),
+                    actual: proof.identity,
+                });
+            }
+        }
+        if proof.message != message(&pool_id, &proof.identity) {
+            return Err(Error::OwnershipProofInvalid(
+                "the message does not match the stake pool id and identity",
+            ));
+        }
+        if proof.signatures.is_empty() {
+            return Err(Error::OwnershipProofInvalid("the proof is not signed"));
+        }
+
+        let mut owners = BTreeSet::new();
+        for (index, owner_signature) in proof.signatures.iter().enumerate() {
+            let public_key: PublicKey<Ed25519> = parse_pub_key(&owner_signature.public_key)
+                .map_err(|_| Error::OwnershipProofInvalid("invalid owner public key"))?;
+            let signature =
+                Signature::<String, Ed25519>::try_from_bech32_str(&owner_signature.signature)
+                    .map_err(|_| Error::OwnershipProofInvalid("invalid owner signature"))?;
+            if let Verification::Failed = signature.verify(&public_key, &proof.message) {
+                return Err(Error::OwnershipProofSignatureFailed { index });
+            }
+            match registration
+                .owners
+                .iter()
+                .position(|owner| owner == &public_key)
+            {
+                Some(owner) => owners.insert(owner),
+                None => return Err(Error::OwnershipProofNotOwner { index }),
+            };
+        }
+
+        // the same owner signing twice counts once
+        let threshold = registration.management_threshold() as usize;
+        if owners.len() < threshold {
+            return Err(Error::OwnershipProofBelowThreshold {
+                got: owners.len(),
+                threshold,
+            });
+        }
+
+        println!("Success");
+        Ok(())
+    }
+}
+
+fn read_registration(input: Option<PathBuf>) -> Result<PoolRegistration, Error> {
+    match read_cert(input.as_deref())?.into() {
+        Certificate::PoolRegistration(registration) => Ok(registration),
+        _ => Err(Error::NotStakePoolRegistration),
+    }
+}
+
+/// The signed message names both the pool and the identity, so that a proof
+/// cannot be replayed for another pool or another identity.
+fn message(pool_id: &PoolId, identity: &str) -> String {
+    format!(
+        "{}\npool id: {}\nidentity: {}",
+        MESSAGE_PREFIX, pool_id, identity
+    )
+}
@@ -21,8 +21,24 @@ impl Block {
         let mut hex_str = String::new();
         BufReader::new(reader).read_line(&mut hex_str)?;
         let bytes = hex::decode(hex_str.trim())?;
-        let message = BlockMock::deserialize(bytes.as_ref()).map_err(Error::MessageMalformed)?;
-        println!("{:#?}", message);
-        Ok(())
+        print_block(&bytes).map_err(Error::MessageMalformed)
+    }
+}
+
+/// Decode a binary-encoded block and print its header and fragments
+pub fn print_block(bytes: &[u8]) -> Result<(), std::io::Error> {
+    let block = BlockMock::deserialize(bytes)?;
+    println!("{:#?}", block);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn malformed_blocks_are_not_printed() {
+        assert!(print_block(&[]).is_err());
+        assert!(print_block(&[0xff; 16]).is_err());
     }
 }
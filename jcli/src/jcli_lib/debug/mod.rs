@@ -1,4 +1,4 @@
-mod block;
+pub(crate) mod block;
 mod message;
 use hex::FromHexError;
 use std::path::PathBuf;
@@ -0,0 +1,386 @@
+//! Reporting of the errors of the jcli commands.
+//!
+//! With `--error-format json`, a failing command prints a single JSON object
+//! on the standard error:
+//!
+//! ```json
+//! {"command":"rest","code":"rest.RequestError","message":"...","causes":["..."]}
+//! ```
+//!
+//! The code is the name of the command followed by the code of the failure
+//! cause, listed for each variant of the `Error` enum of the command module in
+//! this file. The exit status identifies the command, see
+//! [`CommandError::exit_code`].
+
+use serde::Serialize;
+use std::{error::Error, fmt, str::FromStr};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorFormat {
+    Text,
+    Json,
+}
+
+impl FromStr for ErrorFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "text" => Ok(ErrorFormat::Text),
+            "json" => Ok(ErrorFormat::Json),
+            other => Err(format!("unknown error format '{}'", other)),
+        }
+    }
+}
+
+/// Error of a jcli command, with its stable code
+#[derive(Debug)]
+pub struct CommandError {
+    command: &'static str,
+    code: String,
+    error: Box<dyn Error>,
+}
+
+#[derive(Serialize)]
+struct JsonError<'a> {
+    command: &'a str,
+    code: &'a str,
+    message: String,
+    causes: Vec<String>,
+}
+
+impl CommandError {
+    pub(crate) fn new<E: Error + ErrorCode + 'static>(command: &'static str, error: E) -> Self {
+        CommandError {
+            command,
+            code: format!("{}.{}", command, error.error_code()),
+            error: Box::new(error),
+        }
+    }
+
+    pub fn command(&self) -> &str {
+        self.command
+    }
+
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// The exit status of jcli when the command fails. Errors raised outside
+    /// of the commands exit with status 1.
+    ///
+    /// | command         | status |
+    /// |-----------------|--------|
+    /// | key             | 10     |
+    /// | address         | 11     |
+    /// | genesis         | 12     |
+    /// | rest            | 13     |
+    /// | transaction     | 14     |
+    /// | debug           | 15     |
+    /// | certificate     | 16     |
+    /// | auto-completion | 17     |
+    /// | utils           | 18     |
+    /// | votes           | 19     |
+    pub fn exit_code(&self) -> i32 {
+        match self.command {
+            "key" => 10,
+            "address" => 11,
+            "genesis" => 12,
+            "rest" => 13,
+            "transaction" => 14,
+            "debug" => 15,
+            "certificate" => 16,
+            "auto-completion" => 17,
+            "utils" => 18,
+            "votes" => 19,
+            _ => 1,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        let mut causes = Vec::new();
+        let mut source = self.error.source();
+        while let Some(cause) = source {
+            causes.push(cause.to_string());
+            source = cause.source();
+        }
+        serde_json::to_string(&JsonError {
+            command: self.command,
+            code: &self.code,
+            message: self.error.to_string(),
+            causes,
+        })
+        .expect("error serialization cannot fail")
+    }
+}
+
+impl fmt::Display for CommandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.error.fmt(f)
+    }
+}
+
+impl Error for CommandError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.error.source()
+    }
+}
+
+/// The stable code of the failure cause of a command
+pub trait ErrorCode {
+    fn error_code(&self) -> &'static str;
+}
+
+// The codes are published, they must not change when a variant is renamed:
+// they are spelled out, and a new variant does not compile until its code is
+// added here.
+macro_rules! error_codes {
+    ($($command:ident::Error { $($variant:ident => $code:literal,)* },)*) => {
+        $(
+            impl ErrorCode for super::$command::Error {
+                fn error_code(&self) -> &'static str {
+                    use super::$command::Error::*;
+                    match self {
+                        $($variant { .. } => $code,)*
+                    }
+                }
+            }
+        )*
+    };
+}
+
+error_codes! {
+    key::Error {
+        Io => "Io",
+        Bech32 => "Bech32",
+        Hex => "Hex",
+        SecretKey => "SecretKey",
+        PublicKey => "PublicKey",
+        Signature => "Signature",
+        Rand => "Rand",
+        InvalidSeed => "InvalidSeed",
+        InvalidOutput => "InvalidOutput",
+        UnknownBech32PrivKeyHrp => "UnknownBech32PrivKeyHrp",
+        UnknownBech32PubKeyHrp => "UnknownBech32PubKeyHrp",
+        UnexpectedBech32SignHrp => "UnexpectedBech32SignHrp",
+        SignatureVerification => "SignatureVerification",
+        Derivation => "Derivation",
+        UnexpectedBip32Bech32Hrp => "UnexpectedBip32Bech32Hrp",
+    },
+    address::Error {
+        MultisigAddressNotSupported => "MultisigAddressNotSupported",
+    },
+    block::Error {
+        InputInvalid => "InputInvalid",
+        OutputInvalid => "OutputInvalid",
+        BlockFileCorrupted => "BlockFileCorrupted",
+        GenesisFileCorrupted => "GenesisFileCorrupted",
+        GeneratedBlock0Invalid => "GeneratedBlock0Invalid",
+        BlockSerializationFailed => "BlockSerializationFailed",
+        GenesisSerializationFailed => "GenesisSerializationFailed",
+        GenesisUnrecognized => "GenesisUnrecognized",
+        BuildingGenesisFromBlock0Failed => "BuildingGenesisFromBlock0Failed",
+    },
+    rest::Error {
+        InputFragmentMalformed => "InputFragmentMalformed",
+        OutputFormatFailed => "OutputFormatFailed",
+        InputFileInvalid => "InputFileInvalid",
+        InputFileYamlMalformed => "InputFileYamlMalformed",
+        InputHexMalformed => "InputHexMalformed",
+        RequestError => "RequestError",
+        ClientError => "ClientError",
+        SerdeError => "SerdeError",
+        BlockMalformed => "BlockMalformed",
+        SupportBundleWrite => "SupportBundleWrite",
+        TipDateUnavailable => "TipDateUnavailable",
+        TipDateMalformed => "TipDateMalformed",
+    },
+    transaction::Error {
+        StagingFileOpenFailed => "StagingFileOpenFailed",
+        StagingFileReadFailed => "StagingFileReadFailed",
+        StagingFileWriteFailed => "StagingFileWriteFailed",
+        SecretKeyReadFailed => "SecretKeyReadFailed",
+        SecretFileReadFailed => "SecretFileReadFailed",
+        SecretFileMalformed => "SecretFileMalformed",
+        WitnessFileReadFailed => "WitnessFileReadFailed",
+        WitnessFileWriteFailed => "WitnessFileWriteFailed",
+        WitnessFileBech32Malformed => "WitnessFileBech32Malformed",
+        WitnessFileBech32HrpInvalid => "WitnessFileBech32HrpInvalid",
+        WitnessFileBech32EncodingFailed => "WitnessFileBech32EncodingFailed",
+        WitnessFileDeserializationFailed => "WitnessFileDeserializationFailed",
+        WitnessFileSerializationFailed => "WitnessFileSerializationFailed",
+        InfoFileWriteFailed => "InfoFileWriteFailed",
+        OutputFormatFailed => "OutputFormatFailed",
+        TxKindToAddExtraInvalid => "TxKindToAddExtraInvalid",
+        TxKindToAddInputInvalid => "TxKindToAddInputInvalid",
+        TxKindToAddOutputInvalid => "TxKindToAddOutputInvalid",
+        TxKindToAddWitnessInvalid => "TxKindToAddWitnessInvalid",
+        TxKindToSealInvalid => "TxKindToSealInvalid",
+        TxKindToFinalizeInvalid => "TxKindToFinalizeInvalid",
+        TxKindToGetMessageInvalid => "TxKindToGetMessageInvalid",
+        TxKindToSignDataHashInvalid => "TxKindToSignDataHashInvalid",
+        TxKindToSetValidityTimeInvalid => "TxKindToSetValidityTimeInvalid",
+        TooManyWitnessesToAddWitness => "TooManyWitnessesToAddWitness",
+        WitnessCountToSealInvalid => "WitnessCountToSealInvalid",
+        AccountAddressSingle => "AccountAddressSingle",
+        AccountAddressGroup => "AccountAddressGroup",
+        AccountAddressScript => "AccountAddressScript",
+        TxFinalizationFailed => "TxFinalizationFailed",
+        MessageSerializationFailed => "MessageSerializationFailed",
+        InfoCalculationFailed => "InfoCalculationFailed",
+        InfoExpectedSingleAccount => "InfoExpectedSingleAccount",
+        MakeWitnessAccountCounterMissing => "MakeWitnessAccountCounterMissing",
+        TxDoesntNeedPayloadAuth => "TxDoesntNeedPayloadAuth",
+        TxNeedPayloadAuth => "TxNeedPayloadAuth",
+        NoSigningKeys => "NoSigningKeys",
+        CertificateError => "CertificateError",
+        TxWithOwnerStakeDelegationMultiInputs => "TxWithOwnerStakeDelegationMultiInputs",
+        TxWithOwnerStakeDelegationHasUtxoInput => "TxWithOwnerStakeDelegationHasUtxoInput",
+        TxWithOwnerStakeDelegationHasOutputs => "TxWithOwnerStakeDelegationHasOutputs",
+        Block0Error => "Block0Error",
+        AccountIdError => "AccountIdError",
+        RestError => "RestError",
+        RandError => "RandError",
+        InvalidBlock0HeaderHash => "InvalidBlock0HeaderHash",
+        CancelByUser => "CancelByUser",
+        UserInputError => "UserInputError",
+        ExpiryDateMissing => "ExpiryDateMissing",
+        ValidityEraParametersMissing => "ValidityEraParametersMissing",
+        ValidityBlockchainNotStarted => "ValidityBlockchainNotStarted",
+        ValidityTooLong => "ValidityTooLong",
+        CannotFinalizeWithoutValidUntil => "CannotFinalizeWithoutValidUntil",
+        ExportFileWriteFailed => "ExportFileWriteFailed",
+        ImportFileReadFailed => "ImportFileReadFailed",
+        CborEncodingFailed => "CborEncodingFailed",
+        CborDecodingFailed => "CborDecodingFailed",
+        QrCodeTooLarge => "QrCodeTooLarge",
+        QrCodeImageWriteFailed => "QrCodeImageWriteFailed",
+        QrCodeImageReadFailed => "QrCodeImageReadFailed",
+        QrCodeNotFound => "QrCodeNotFound",
+        QrCodeExtractFailed => "QrCodeExtractFailed",
+        QrCodeDecodeFailed => "QrCodeDecodeFailed",
+    },
+    debug::Error {
+        Io => "Io",
+        InputInvalid => "InputInvalid",
+        HexMalformed => "HexMalformed",
+        MessageMalformed => "MessageMalformed",
+    },
+    certificate::Error {
+        KeyInvalid => "KeyInvalid",
+        Io => "Io",
+        NotStakePoolRegistration => "NotStakePoolRegistration",
+        InputInvalid => "InputInvalid",
+        OutputInvalid => "OutputInvalid",
+        InvalidCertificate => "InvalidCertificate",
+        InvalidCertificateBech32 => "InvalidCertificateBech32",
+        ManagementThresholdInvalid => "ManagementThresholdInvalid",
+        NoSigningKeys => "NoSigningKeys",
+        ExpectingOnlyOneSigningKey => "ExpectingOnlyOneSigningKey",
+        OwnerStakeDelegationDoesntNeedSignature => "OwnerStakeDelegationDoesntNeedSignature",
+        VotePlanDoesntNeedSignature => "VotePlanDoesntNeedSignature",
+        VoteCastDoesntNeedSignature => "VoteCastDoesntNeedSignature",
+        KeyNotFound => "KeyNotFound",
+        ExpectedSignedOrNotCertificate => "ExpectedSignedOrNotCertificate",
+        InvalidBech32 => "InvalidBech32",
+        PoolDelegationWithZeroWeight => "PoolDelegationWithZeroWeight",
+        InvalidPoolDelegationWeights => "InvalidPoolDelegationWeights",
+        TooManyPoolDelegations => "TooManyPoolDelegations",
+        InvalidPoolDelegation => "InvalidPoolDelegation",
+        InvalidVotePlanVoteBlockDates => "InvalidVotePlanVoteBlockDates",
+        InvalidVotePlanCommitteeBlockDates => "InvalidVotePlanCommitteeBlockDates",
+        TooManyVotePlanProposals => "TooManyVotePlanProposals",
+        NotVotePlanCertificate => "NotVotePlanCertificate",
+        VotePlanConfig => "VotePlanConfig",
+        Base64 => "Base64",
+        ElectionPublicKey => "ElectionPublicKey",
+        InvalidBech32Key => "InvalidBech32Key",
+        InvalidJson => "InvalidJson",
+        InvalidPrivateVotePlanCommitteeKeys => "InvalidPrivateVotePlanCommitteeKeys",
+        VotePlanError => "VotePlanError",
+        SharesError => "SharesError",
+        PrivateTallyExpected => "PrivateTallyExpected",
+        OwnershipProofInvalid => "OwnershipProofInvalid",
+        OwnershipProofSignatureFailed => "OwnershipProofSignatureFailed",
+        OwnershipProofNotOwner => "OwnershipProofNotOwner",
+        OwnershipProofIdentityMismatch => "OwnershipProofIdentityMismatch",
+        OwnershipProofPoolMismatch => "OwnershipProofPoolMismatch",
+        OwnershipProofBelowThreshold => "OwnershipProofBelowThreshold",
+    },
+    auto_completion::Error {
+        OutputNotExist => "OutputNotExist",
+        OutputNotDir => "OutputNotDir",
+    },
+    utils::Error {
+        Bech32ConversionFailure => "Bech32ConversionFailure",
+    },
+    vote::Error {
+        Io => "Io",
+        Hex => "Hex",
+        Base64 => "Base64",
+        Bech32 => "Bech32",
+        Rand => "Rand",
+        InvalidSeed => "InvalidSeed",
+        InvalidOutput => "InvalidOutput",
+        InvalidPublicKey => "InvalidPublicKey",
+        InvalidSecretKey => "InvalidSecretKey",
+        InvalidCrs => "InvalidCrs",
+        InvalidThreshold => "InvalidThreshold",
+        InvalidCommitteMemberIndex => "InvalidCommitteMemberIndex",
+        EncryptedTallyRead => "EncryptedTallyRead",
+        DecryptionKeyRead => "DecryptionKeyRead",
+        PrivateTallyExpected => "PrivateTallyExpected",
+        TallyError => "TallyError",
+        FormatError => "FormatError",
+        JsonError => "JsonError",
+        VotePlanError => "VotePlanError",
+        SharesError => "SharesError",
+        SharesCountMismatch => "SharesCountMismatch",
+        InvalidDecryptionShares => "InvalidDecryptionShares",
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::jcli_lib::{address, auto_completion, certificate, transaction};
+
+    #[test]
+    fn codes_are_stable() {
+        let codes = vec![
+            CommandError::new("address", address::Error::MultisigAddressNotSupported),
+            CommandError::new("auto-completion", auto_completion::Error::OutputNotDir),
+            CommandError::new(
+                "certificate",
+                certificate::Error::OwnershipProofNotOwner { index: 1 },
+            ),
+            CommandError::new("certificate", certificate::Error::NoSigningKeys),
+            CommandError::new("transaction", transaction::Error::CancelByUser),
+            CommandError::new("transaction", transaction::Error::ExpiryDateMissing),
+        ];
+        let codes: Vec<_> = codes
+            .iter()
+            .map(|error| (error.code(), error.exit_code()))
+            .collect();
+        assert_eq!(
+            codes,
+            vec![
+                ("address.MultisigAddressNotSupported", 11),
+                ("auto-completion.OutputNotDir", 17),
+                ("certificate.OwnershipProofNotOwner", 16),
+                ("certificate.NoSigningKeys", 16),
+                ("transaction.CancelByUser", 14),
+                ("transaction.ExpiryDateMissing", 14),
+            ]
+        );
+    }
+
+    #[test]
+    fn json_errors_carry_the_code() {
+        let error = CommandError::new("transaction", transaction::Error::CancelByUser);
+        let json: serde_json::Value = serde_json::from_str(&error.to_json()).unwrap();
+        assert_eq!(json["command"], "transaction");
+        assert_eq!(json["code"], "transaction.CancelByUser");
+        assert_eq!(json["message"], "canceled by user");
+    }
+}
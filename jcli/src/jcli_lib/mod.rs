@@ -3,6 +3,7 @@ pub mod auto_completion;
 pub mod block;
 pub mod certificate;
 pub mod debug;
+pub mod error;
 pub mod key;
 pub mod rest;
 pub mod transaction;
@@ -10,6 +11,7 @@ pub mod vote;
 
 pub mod utils;
 
+pub use error::{CommandError, ErrorFormat};
 use std::error::Error;
 use structopt::StructOpt;
 
@@ -26,6 +28,11 @@ pub struct JCli {
     #[structopt(long = "source-version")]
     source_version: bool,
 
+    /// format of the errors printed on the standard error: text or json.
+    /// In JSON, an error is reported with a stable code for the failure cause.
+    #[structopt(long = "error-format", default_value = "text")]
+    error_format: ErrorFormat,
+
     #[structopt(subcommand)]
     command: Option<JCliCommand>,
 }
@@ -58,6 +65,10 @@ pub enum JCliCommand {
 }
 
 impl JCli {
+    pub fn error_format(&self) -> ErrorFormat {
+        self.error_format
+    }
+
     pub fn exec(self) -> Result<(), Box<dyn Error>> {
         use std::io::Write as _;
         if self.full_version {
@@ -65,7 +76,7 @@ impl JCli {
         } else if self.source_version {
             Ok(writeln!(std::io::stdout(), "{}", env!("SOURCE_VERSION"))?)
         } else if let Some(cmd) = self.command {
-            cmd.exec()
+            Ok(cmd.exec()?)
         } else {
             writeln!(std::io::stderr(), "No command, try `--help'")?;
             std::process::exit(1);
@@ -74,20 +85,25 @@ impl JCli {
 }
 
 impl JCliCommand {
-    pub fn exec(self) -> Result<(), Box<dyn Error>> {
+    pub fn exec(self) -> Result<(), CommandError> {
         use self::JCliCommand::*;
         match self {
-            Key(key) => key.exec()?,
-            Address(address) => address.exec()?,
-            Genesis(genesis) => genesis.exec()?,
-            Rest(rest) => rest.exec()?,
-            Transaction(transaction) => transaction.exec()?,
-            Debug(debug) => debug.exec()?,
-            Certificate(certificate) => certificate.exec()?,
-            AutoCompletion(auto_completion) => auto_completion.exec::<Self>()?,
-            Utils(utils) => utils.exec()?,
-            Votes(vote) => vote.exec()?,
-        };
-        Ok(())
+            Key(key) => key.exec().map_err(|e| CommandError::new("key", e)),
+            Address(address) => address.exec().map_err(|e| CommandError::new("address", e)),
+            Genesis(genesis) => genesis.exec().map_err(|e| CommandError::new("genesis", e)),
+            Rest(rest) => rest.exec().map_err(|e| CommandError::new("rest", e)),
+            Transaction(transaction) => transaction
+                .exec()
+                .map_err(|e| CommandError::new("transaction", e)),
+            Debug(debug) => debug.exec().map_err(|e| CommandError::new("debug", e)),
+            Certificate(certificate) => certificate
+                .exec()
+                .map_err(|e| CommandError::new("certificate", e)),
+            AutoCompletion(auto_completion) => auto_completion
+                .exec::<Self>()
+                .map_err(|e| CommandError::new("auto-completion", e)),
+            Utils(utils) => utils.exec().map_err(|e| CommandError::new("utils", e)),
+            Votes(vote) => vote.exec().map_err(|e| CommandError::new("votes", e)),
+        }
     }
 }
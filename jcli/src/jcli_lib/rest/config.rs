@@ -1,4 +1,5 @@
 use bytes::Bytes;
+use jormungandr_lib::client::blocking;
 use reqwest::{
     blocking::{Client, RequestBuilder},
     Url,
@@ -68,10 +69,15 @@ pub enum Error {
 }
 
 impl RestArgs {
-    pub fn client(self) -> Result<RestClient, Error> {
-        use reqwest::{blocking::ClientBuilder, Certificate};
-        use std::{fs::File, io::Read};
+    pub fn new(host: Url) -> Self {
+        Self {
+            host,
+            debug: false,
+            tls_cert_path: None,
+        }
+    }
 
+    pub fn client(self) -> Result<RestClient, Error> {
         let Self {
             tls_cert_path,
             host,
@@ -82,22 +88,7 @@ impl RestArgs {
             return Err(Error::HostAddrNotBase { addr: host });
         }
 
-        let client_builder = ClientBuilder::new();
-
-        // load certificate
-        let client_builder = if let Some(path) = tls_cert_path {
-            let mut buf = Vec::new();
-            File::open(path)
-                .map_err(Error::CertIo)?
-                .read_to_end(&mut buf)
-                .map_err(Error::CertIo)?;
-            let cert = Certificate::from_pem(&buf).map_err(Error::Pem)?;
-            client_builder.use_rustls_tls().add_root_certificate(cert)
-        } else {
-            client_builder
-        };
-
-        let client = client_builder.build().map_err(Error::Client)?;
+        let client = http_client(tls_cert_path)?;
 
         let rest_client = RestClient {
             client,
@@ -107,6 +98,45 @@ impl RestArgs {
 
         Ok(rest_client)
     }
+
+    /// The typed client of jormungandr-lib, for the requests it offers. The
+    /// requests and responses are not printed with `--debug`.
+    pub fn typed_client(self) -> Result<blocking::RestClient, Error> {
+        let Self {
+            tls_cert_path,
+            host,
+            debug: _,
+        } = self;
+
+        if host.cannot_be_a_base() {
+            return Err(Error::HostAddrNotBase { addr: host });
+        }
+
+        let client = http_client(tls_cert_path)?;
+        Ok(blocking::RestClient::with_client(host, client))
+    }
+}
+
+fn http_client(tls_cert_path: Option<PathBuf>) -> Result<Client, Error> {
+    use reqwest::{blocking::ClientBuilder, Certificate};
+    use std::{fs::File, io::Read};
+
+    let client_builder = ClientBuilder::new();
+
+    // load certificate
+    let client_builder = if let Some(path) = tls_cert_path {
+        let mut buf = Vec::new();
+        File::open(path)
+            .map_err(Error::CertIo)?
+            .read_to_end(&mut buf)
+            .map_err(Error::CertIo)?;
+        let cert = Certificate::from_pem(&buf).map_err(Error::Pem)?;
+        client_builder.use_rustls_tls().add_root_certificate(cert)
+    } else {
+        client_builder
+    };
+
+    client_builder.build().map_err(Error::Client)
 }
 
 impl RestClient {
@@ -1,7 +1,9 @@
 mod config;
 pub mod v0;
+pub mod v1;
 
 use crate::jcli_lib::utils::{io::ReadYamlError, output_format};
+use chain_impl_mockchain::block::BlockDateParseError;
 pub use config::RestArgs;
 use hex::FromHexError;
 use structopt::StructOpt;
@@ -13,6 +15,8 @@ use thiserror::Error;
 pub enum Rest {
     /// API version 0
     V0(v0::V0),
+    /// API version 1
+    V1(v1::V1),
 }
 
 #[derive(Debug, Error)]
@@ -29,8 +33,18 @@ pub enum Error {
     InputHexMalformed(#[from] FromHexError),
     #[error("error when trying to perform an HTTP request")]
     RequestError(#[from] config::Error),
+    #[error("the request to the node failed")]
+    ClientError(#[from] jormungandr_lib::client::Error),
     #[error("error loading data from response")]
     SerdeError(#[from] serde_json::Error),
+    #[error("block is malformed")]
+    BlockMalformed(#[source] std::io::Error),
+    #[error("could not write the support bundle")]
+    SupportBundleWrite(#[source] std::io::Error),
+    #[error("the node did not report the date of its tip")]
+    TipDateUnavailable,
+    #[error("the date of the tip is malformed")]
+    TipDateMalformed(#[from] BlockDateParseError),
 }
 
 impl From<ReadYamlError> for Error {
@@ -46,6 +60,7 @@ impl Rest {
     pub fn exec(self) -> Result<(), Error> {
         match self {
             Rest::V0(v0) => v0.exec(),
+            Rest::V1(v1) => v1.exec(),
         }
     }
 }
@@ -1,4 +1,5 @@
 use super::next_id::NextId;
+use crate::jcli_lib::debug::block::print_block;
 use crate::jcli_lib::rest::{Error, RestArgs};
 use structopt::StructOpt;
 
@@ -9,6 +10,10 @@ pub enum Subcommand {
     Get {
         #[structopt(flatten)]
         args: RestArgs,
+        /// Decode the block and display its header and fragments instead of
+        /// printing it hex-encoded
+        #[structopt(long)]
+        decode: bool,
     },
     /// Get block descendant ID
     NextId(NextId),
@@ -17,18 +22,21 @@ pub enum Subcommand {
 impl Subcommand {
     pub fn exec(self, block_id: String) -> Result<(), Error> {
         match self {
-            Subcommand::Get { args } => exec_get(block_id, args),
+            Subcommand::Get { args, decode } => exec_get(block_id, args, decode),
             Subcommand::NextId(next_id) => next_id.exec(block_id),
         }
     }
 }
 
-fn exec_get(block_id: String, args: RestArgs) -> Result<(), Error> {
+fn exec_get(block_id: String, args: RestArgs, decode: bool) -> Result<(), Error> {
     let response = args
         .client()?
         .get(&["v0", "block", &block_id])
         .execute()?
         .bytes()?;
+    if decode {
+        return print_block(&response).map_err(Error::BlockMalformed);
+    }
     println!("{}", hex::encode(&response));
     Ok(())
 }
@@ -44,6 +44,18 @@ pub enum GetLogs {
         args: RestArgs,
         #[structopt(flatten)]
         output_format: OutputFormat,
+        /// only return logs with given status
+        #[structopt(long, possible_values = &["pending", "rejected", "block"])]
+        status: Option<String>,
+        /// only return logs scheduled in given epoch
+        #[structopt(long)]
+        epoch: Option<u32>,
+        /// number of logs to skip, logs are ordered from the most recently scheduled one
+        #[structopt(long)]
+        offset: Option<usize>,
+        /// maximum number of logs to return
+        #[structopt(long)]
+        limit: Option<usize>,
     },
 }
 
@@ -59,7 +71,20 @@ impl Leaders {
             Leaders::Logs(GetLogs::Get {
                 args,
                 output_format,
-            }) => get_logs(args, output_format),
+                status,
+                epoch,
+                offset,
+                limit,
+            }) => get_logs(
+                args,
+                output_format,
+                &[
+                    ("status", status),
+                    ("epoch", epoch.map(|v| v.to_string())),
+                    ("offset", offset.map(|v| v.to_string())),
+                    ("limit", limit.map(|v| v.to_string())),
+                ],
+            ),
         }
     }
 }
@@ -91,10 +116,15 @@ fn delete(args: RestArgs, id: u32) -> Result<(), Error> {
     Ok(())
 }
 
-fn get_logs(args: RestArgs, output_format: OutputFormat) -> Result<(), Error> {
+fn get_logs(
+    args: RestArgs,
+    output_format: OutputFormat,
+    query: &[(&str, Option<String>)],
+) -> Result<(), Error> {
     let response = args
         .client()?
         .get(&["v0", "leaders", "logs"])
+        .query(&query)
         .execute()?
         .json()?;
     let formatted = output_format.format_json(response)?;
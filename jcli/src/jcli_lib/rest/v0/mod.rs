@@ -11,6 +11,7 @@ mod shutdown;
 mod stake;
 mod stake_pool;
 mod stake_pools;
+mod support_bundle;
 mod tip;
 mod utxo;
 mod vote;
@@ -53,6 +54,8 @@ pub enum V0 {
     Rewards(rewards::Rewards),
     /// Vote related operations
     Vote(vote::Vote),
+    /// Collect the state of the node into an archive for bug reports
+    SupportBundle(support_bundle::SupportBundle),
 }
 
 impl V0 {
@@ -74,6 +77,7 @@ impl V0 {
             V0::Diagnostic(diagnostic) => diagnostic.exec(),
             V0::Rewards(rewards) => rewards.exec(),
             V0::Vote(vote) => vote.exec(),
+            V0::SupportBundle(support_bundle) => support_bundle.exec(),
         }
     }
 }
@@ -30,6 +30,5 @@ impl Settings {
 }
 
 pub fn request_settings(args: RestArgs) -> Result<SettingsDto, Error> {
-    serde_json::from_str(&(args.client()?.get(&["v0", "settings"]).execute()?.text()?))
-        .map_err(Error::SerdeError)
+    args.typed_client()?.settings().map_err(Into::into)
 }
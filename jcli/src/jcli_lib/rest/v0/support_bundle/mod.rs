@@ -0,0 +1,82 @@
+use crate::jcli_lib::rest::{Error, RestArgs};
+use flate2::{write::GzEncoder, Compression};
+use std::fs::File;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+use structopt::StructOpt;
+
+/// Files of the bundle and the endpoints their content is fetched from
+const ENDPOINTS: &[(&str, &[&str])] = &[
+    ("node-stats.json", &["v0", "node", "stats"]),
+    ("settings.json", &["v0", "settings"]),
+    ("network-stats.json", &["v0", "network", "stats"]),
+    ("leadership-logs.json", &["v0", "leaders", "logs"]),
+    ("fragment-logs.json", &["v0", "fragment", "logs"]),
+    ("diagnostic.json", &["v0", "diagnostic"]),
+];
+
+/// Collect the state of the node into a single archive to attach to bug reports
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct SupportBundle {
+    #[structopt(flatten)]
+    args: RestArgs,
+    /// path of the gzip-compressed tar archive to write
+    #[structopt(short, long)]
+    output: PathBuf,
+}
+
+impl SupportBundle {
+    pub fn exec(self) -> Result<(), Error> {
+        let file = File::create(&self.output).map_err(Error::SupportBundleWrite)?;
+        let mut archive = tar::Builder::new(GzEncoder::new(file, Compression::default()));
+        let mtime = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |time| time.as_secs());
+
+        for (name, path) in ENDPOINTS {
+            // the bundle is most useful when something is wrong with the node,
+            // a failing endpoint is recorded instead of aborting the collection
+            let (name, content) = match self
+                .args
+                .clone()
+                .client()?
+                .get(path)
+                .execute()
+                .and_then(|response| response.text())
+            {
+                Ok(content) => (name.to_string(), content),
+                Err(err) => {
+                    eprintln!("could not fetch {}: {}", name, err);
+                    (format!("{}.error", name), error_report(&err))
+                }
+            };
+            let mut header = tar::Header::new_gnu();
+            header.set_size(content.len() as u64);
+            header.set_mode(0o644);
+            header.set_mtime(mtime);
+            header.set_cksum();
+            archive
+                .append_data(&mut header, name, content.as_bytes())
+                .map_err(Error::SupportBundleWrite)?;
+        }
+
+        archive
+            .into_inner()
+            .and_then(|encoder| encoder.finish())
+            .map_err(Error::SupportBundleWrite)?;
+        println!("{}", self.output.display());
+        Ok(())
+    }
+}
+
+fn error_report(err: &dyn std::error::Error) -> String {
+    let mut report = err.to_string();
+    let mut source = err.source();
+    while let Some(err) = source {
+        report.push_str(": ");
+        report.push_str(&err.to_string());
+        source = err.source();
+    }
+    report
+}
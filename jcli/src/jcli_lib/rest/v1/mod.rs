@@ -0,0 +1,23 @@
+mod stake_pool;
+mod vote;
+
+use crate::jcli_lib::rest::Error;
+use structopt::StructOpt;
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub enum V1 {
+    /// Stake pool operations
+    StakePool(stake_pool::StakePool),
+    /// Vote related operations
+    Vote(vote::Vote),
+}
+
+impl V1 {
+    pub fn exec(self) -> Result<(), Error> {
+        match self {
+            V1::StakePool(stake_pool) => stake_pool.exec(),
+            V1::Vote(vote) => vote.exec(),
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use crate::jcli_lib::rest::{Error, RestArgs};
+use crate::jcli_lib::utils::OutputFormat;
+use jormungandr_lib::interfaces::{Rewards, StakePoolDashboard, StakePoolStats, TaxTypeSerde};
+use serde::Serialize;
+use structopt::StructOpt;
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub enum StakePool {
+    /// Get an overview of the stake, delegators, produced blocks and
+    /// retirement status of a stake pool
+    Dashboard {
+        #[structopt(flatten)]
+        args: RestArgs,
+        /// hex-encoded pool ID
+        pool_id: String,
+        #[structopt(flatten)]
+        output_format: OutputFormat,
+    },
+}
+
+#[derive(Serialize)]
+struct Dashboard {
+    pool_id: String,
+    #[serde(flatten)]
+    dashboard: StakePoolDashboard,
+    tax: TaxTypeSerde,
+    last_rewards: Rewards,
+}
+
+impl StakePool {
+    pub fn exec(self) -> Result<(), Error> {
+        let StakePool::Dashboard {
+            args,
+            pool_id,
+            output_format,
+        } = self;
+        let dashboard: StakePoolDashboard = args
+            .clone()
+            .client()?
+            .get(&["v1", "stake-pools", &pool_id, "dashboard"])
+            .execute()?
+            .json()?;
+        let stats: StakePoolStats = args
+            .client()?
+            .get(&["v0", "stake_pool", &pool_id])
+            .execute()?
+            .json()?;
+        let report = Dashboard {
+            pool_id,
+            dashboard,
+            tax: stats.tax,
+            last_rewards: stats.rewards,
+        };
+        let formatted = output_format.format_json(serde_json::to_value(report)?)?;
+        println!("{}", formatted);
+        Ok(())
+    }
+}
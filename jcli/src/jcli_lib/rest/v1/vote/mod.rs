@@ -0,0 +1,20 @@
+mod plans;
+
+use self::plans::Plans;
+use crate::jcli_lib::rest::Error;
+use structopt::StructOpt;
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub enum Vote {
+    /// Vote plans operations
+    Plans(Plans),
+}
+
+impl Vote {
+    pub fn exec(self) -> Result<(), Error> {
+        match self {
+            Vote::Plans(plans) => plans.exec(),
+        }
+    }
+}
@@ -0,0 +1,201 @@
+use crate::jcli_lib::rest::{Error, RestArgs};
+use crate::jcli_lib::utils::OutputFormat;
+use jormungandr_lib::interfaces::{BlockDate, NodeStatsDto, VotePlanStatus, VotePrivacy};
+use serde::Serialize;
+use structopt::StructOpt;
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub enum Plans {
+    /// List the vote plans active in the ledger of the tip
+    List {
+        #[structopt(flatten)]
+        args: RestArgs,
+        /// print one row per vote plan, with its phase, number of proposals,
+        /// votes cast and payload type, instead of the full vote plans
+        #[structopt(long)]
+        summary: bool,
+        /// print the summary as JSON instead of a table
+        #[structopt(long, requires = "summary")]
+        json: bool,
+        /// format of the full vote plans, not used with `--summary`
+        #[structopt(flatten)]
+        output_format: OutputFormat,
+    },
+}
+
+#[derive(Serialize, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+enum Phase {
+    /// before the start of the vote
+    Upcoming,
+    Vote,
+    Committee,
+    Ended,
+}
+
+impl Phase {
+    fn new(plan: &VotePlanStatus, date: BlockDate) -> Self {
+        if date < plan.vote_start {
+            Phase::Upcoming
+        } else if date < plan.vote_end {
+            Phase::Vote
+        } else if date < plan.committee_end {
+            Phase::Committee
+        } else {
+            Phase::Ended
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Phase::Upcoming => "upcoming",
+            Phase::Vote => "vote",
+            Phase::Committee => "committee",
+            Phase::Ended => "ended",
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct PlanSummary {
+    id: String,
+    phase: Phase,
+    payload: VotePrivacy,
+    proposals: usize,
+    votes_cast: usize,
+}
+
+impl PlanSummary {
+    fn new(plan: &VotePlanStatus, date: BlockDate) -> Self {
+        PlanSummary {
+            id: plan.id.to_string(),
+            phase: Phase::new(plan, date),
+            payload: plan.payload.into(),
+            proposals: plan.proposals.len(),
+            votes_cast: plan
+                .proposals
+                .iter()
+                .map(|proposal| proposal.votes_cast)
+                .sum(),
+        }
+    }
+}
+
+impl Plans {
+    pub fn exec(self) -> Result<(), Error> {
+        let Plans::List {
+            args,
+            summary,
+            json,
+            output_format,
+        } = self;
+        let response = args
+            .clone()
+            .client()?
+            .get(&["v0", "vote", "active", "plans"])
+            .execute()?
+            .json()?;
+        if !summary {
+            let formatted = output_format.format_json(response)?;
+            println!("{}", formatted);
+            return Ok(());
+        }
+
+        let plans: Vec<VotePlanStatus> = serde_json::from_value(response)?;
+        let date = tip_date(args)?;
+        let summaries: Vec<PlanSummary> = plans
+            .iter()
+            .map(|plan| PlanSummary::new(plan, date))
+            .collect();
+        if json {
+            println!("{}", serde_json::to_string_pretty(&summaries)?);
+        } else {
+            print_table(&summaries);
+        }
+        Ok(())
+    }
+}
+
+/// The phases are computed against the date of the last block of the node,
+/// which is the date of the ledger the vote plans are taken from.
+fn tip_date(args: RestArgs) -> Result<BlockDate, Error> {
+    let stats: NodeStatsDto = args
+        .client()?
+        .get(&["v0", "node", "stats"])
+        .execute()?
+        .json()?;
+    let date = stats
+        .stats
+        .and_then(|stats| stats.last_block_date)
+        .ok_or(Error::TipDateUnavailable)?;
+    Ok(date.parse()?)
+}
+
+fn print_table(summaries: &[PlanSummary]) {
+    println!(
+        "{:<64}  {:<9}  {:<7}  {:>9}  {:>10}",
+        "ID", "PHASE", "PAYLOAD", "PROPOSALS", "VOTES CAST"
+    );
+    for summary in summaries {
+        println!(
+            "{:<64}  {:<9}  {:<7}  {:>9}  {:>10}",
+            summary.id,
+            summary.phase.as_str(),
+            summary.payload.to_string(),
+            summary.proposals,
+            summary.votes_cast
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chain_impl_mockchain::vote::PayloadType;
+    use jormungandr_lib::interfaces::VoteProposalStatus;
+
+    fn plan(votes_cast: &[usize]) -> VotePlanStatus {
+        VotePlanStatus {
+            id: [0; 32].into(),
+            payload: PayloadType::Private,
+            vote_start: BlockDate::new(1, 0),
+            vote_end: BlockDate::new(2, 0),
+            committee_end: BlockDate::new(3, 0),
+            committee_member_keys: Vec::new(),
+            proposals: votes_cast
+                .iter()
+                .enumerate()
+                .map(|(index, votes_cast)| VoteProposalStatus {
+                    index: index as u8,
+                    proposal_id: [index as u8; 32].into(),
+                    options: 0..3,
+                    tally: None,
+                    votes_cast: *votes_cast,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn phase_follows_the_dates_of_the_plan() {
+        let plan = plan(&[]);
+        let phase = |epoch, slot_id| Phase::new(&plan, BlockDate::new(epoch, slot_id)).as_str();
+        assert_eq!(phase(0, 10), "upcoming");
+        assert_eq!(phase(1, 0), "vote");
+        assert_eq!(phase(1, 10), "vote");
+        assert_eq!(phase(2, 0), "committee");
+        assert_eq!(phase(3, 0), "ended");
+    }
+
+    #[test]
+    fn summary_counts_the_votes_of_all_proposals() {
+        let summary = PlanSummary::new(&plan(&[2, 0, 5]), BlockDate::new(1, 5));
+        assert_eq!(summary.proposals, 3);
+        assert_eq!(summary.votes_cast, 7);
+        assert_eq!(summary.payload.to_string(), "private");
+        let json = serde_json::to_value(&summary).unwrap();
+        assert_eq!(json["phase"], "vote");
+        assert_eq!(json["payload"], "private");
+    }
+}
@@ -0,0 +1,128 @@
+use crate::jcli_lib::{
+    transaction::{common, staging::Staging, Error},
+    utils::io,
+};
+use image::{DynamicImage, ImageBuffer, ImageOutputFormat, Luma};
+use qrcodegen::{QrCode, QrCodeEcc};
+use std::io::{Read, Write};
+use std::path::PathBuf;
+use structopt::{clap::arg_enum, StructOpt};
+
+/// size in pixels of a module (a dark or light square) of the QR code
+const MODULE_SIZE: u32 = 8;
+/// quiet zone around the QR code, in modules
+const QR_CODE_BORDER: u32 = 4;
+
+arg_enum! {
+    #[derive(StructOpt, Debug, Clone, Copy)]
+    pub enum ExchangeFormat {
+        Cbor,
+        Qr,
+    }
+}
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct Export {
+    #[structopt(flatten)]
+    pub common: common::CommonTransaction,
+
+    /// format of the export: `cbor` for a binary blob, `qr` for a PNG image
+    /// of a QR code holding the same blob
+    #[structopt(long = "format", default_value = "cbor")]
+    pub format: ExchangeFormat,
+
+    /// write the export in the given file or to the standard output
+    #[structopt(long = "output")]
+    pub output: Option<PathBuf>,
+}
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct Import {
+    #[structopt(flatten)]
+    pub common: common::CommonTransaction,
+
+    /// format of the import, as given to `export`
+    #[structopt(long = "format", default_value = "cbor")]
+    pub format: ExchangeFormat,
+
+    /// read the export from the given file or from the standard input
+    #[structopt(long = "input")]
+    pub input: Option<PathBuf>,
+}
+
+impl Export {
+    pub fn exec(self) -> Result<(), Error> {
+        let transaction = self.common.load()?;
+        let cbor = serde_cbor::to_vec(&transaction).map_err(Error::CborEncodingFailed)?;
+        let bytes = match self.format {
+            ExchangeFormat::Cbor => cbor,
+            ExchangeFormat::Qr => qr_code_png(&cbor)?,
+        };
+        let mut output =
+            io::open_file_write(&self.output).map_err(|source| Error::ExportFileWriteFailed {
+                source,
+                path: io::path_to_path_buf(&self.output),
+            })?;
+        output
+            .write_all(&bytes)
+            .and_then(|()| output.flush())
+            .map_err(|source| Error::ExportFileWriteFailed {
+                source,
+                path: io::path_to_path_buf(&self.output),
+            })
+    }
+}
+
+impl Import {
+    pub fn exec(self) -> Result<(), Error> {
+        let mut bytes = Vec::new();
+        io::open_file_read(&self.input)
+            .and_then(|mut input| input.read_to_end(&mut bytes))
+            .map_err(|source| Error::ImportFileReadFailed {
+                source,
+                path: io::path_to_path_buf(&self.input),
+            })?;
+        let cbor = match self.format {
+            ExchangeFormat::Cbor => bytes,
+            ExchangeFormat::Qr => qr_code_payload(&bytes)?,
+        };
+        let transaction: Staging =
+            serde_cbor::from_slice(&cbor).map_err(Error::CborDecodingFailed)?;
+        self.common.store(&transaction)
+    }
+}
+
+fn qr_code_png(payload: &[u8]) -> Result<Vec<u8>, Error> {
+    let qr = QrCode::encode_binary(payload, QrCodeEcc::Medium)?;
+    let modules = qr.size() as u32;
+    let size = (modules + 2 * QR_CODE_BORDER) * MODULE_SIZE;
+    let img = ImageBuffer::from_fn(size, size, |x, y| {
+        let module_x = (x / MODULE_SIZE) as i32 - QR_CODE_BORDER as i32;
+        let module_y = (y / MODULE_SIZE) as i32 - QR_CODE_BORDER as i32;
+        // out of range coordinates are light modules
+        if qr.get_module(module_x, module_y) {
+            Luma([0u8])
+        } else {
+            Luma([255u8])
+        }
+    });
+    let mut png = Vec::new();
+    DynamicImage::ImageLuma8(img)
+        .write_to(&mut png, ImageOutputFormat::Png)
+        .map_err(Error::QrCodeImageWriteFailed)?;
+    Ok(png)
+}
+
+fn qr_code_payload(image: &[u8]) -> Result<Vec<u8>, Error> {
+    let img = image::load_from_memory(image)
+        .map_err(Error::QrCodeImageReadFailed)?
+        .into_luma8();
+    let mut decoder = quircs::Quirc::default();
+    let code = decoder
+        .identify(img.width() as usize, img.height() as usize, &img)
+        .next()
+        .ok_or(Error::QrCodeNotFound)??;
+    Ok(code.decode()?.payload)
+}
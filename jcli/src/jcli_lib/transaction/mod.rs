@@ -5,6 +5,7 @@ pub mod add_output;
 mod add_witness;
 mod auth;
 mod common;
+mod exchange;
 pub mod finalize;
 mod info;
 mod mk_witness;
@@ -13,6 +14,7 @@ mod seal;
 mod set_expiry_date;
 mod simplified;
 mod staging;
+mod validity;
 
 use self::staging::StagingKind;
 use crate::jcli_lib::{
@@ -69,6 +71,11 @@ pub enum Transaction {
     ToMessage(common::CommonTransaction),
     /// send a transaction from one account to another (simplified method)
     MakeTransaction(simplified::MakeTransaction),
+    /// export the transaction as a CBOR blob or a QR code image, to move it
+    /// to and from an air-gapped machine holding the signing keys
+    Export(exchange::Export),
+    /// import a transaction exported with 'export'
+    Import(exchange::Import),
 }
 
 type StaticStr = &'static str;
@@ -220,8 +227,45 @@ pub enum Error {
     #[error("error requesting user input")]
     UserInputError(#[from] std::io::Error),
 
+    #[error("no expiry date given, use a block date or `--valid-for-slots`/`--valid-for-epochs`")]
+    ExpiryDateMissing,
+    #[error("the era parameters are needed to compute the expiry date, use `--settings-host` or `--block0-time`, `--slot-duration` and `--slots-per-epoch`")]
+    ValidityEraParametersMissing,
+    #[error("the blockchain has not started yet, cannot compute the expiry date")]
+    ValidityBlockchainNotStarted,
+    #[error("the transaction cannot be valid for more than {max_expiry_epochs} epochs")]
+    ValidityTooLong { max_expiry_epochs: u8 },
     #[error("cannot finalize the payload without a validity end date set")]
     CannotFinalizeWithoutValidUntil,
+
+    #[error("could not write exported transaction file '{path}'")]
+    ExportFileWriteFailed {
+        #[source]
+        source: std::io::Error,
+        path: PathBuf,
+    },
+    #[error("could not read exported transaction file '{path}'")]
+    ImportFileReadFailed {
+        #[source]
+        source: std::io::Error,
+        path: PathBuf,
+    },
+    #[error("could not encode the transaction as CBOR")]
+    CborEncodingFailed(#[source] serde_cbor::Error),
+    #[error("could not decode the transaction from CBOR")]
+    CborDecodingFailed(#[source] serde_cbor::Error),
+    #[error("the transaction is too large for a QR code")]
+    QrCodeTooLarge(#[from] qrcodegen::DataTooLong),
+    #[error("could not write the QR code image")]
+    QrCodeImageWriteFailed(#[source] image::ImageError),
+    #[error("could not read the QR code image")]
+    QrCodeImageReadFailed(#[source] image::ImageError),
+    #[error("no QR code found in the image")]
+    QrCodeNotFound,
+    #[error("could not extract the QR code from the image")]
+    QrCodeExtractFailed(#[from] quircs::ExtractError),
+    #[error("could not decode the QR code")]
+    QrCodeDecodeFailed(#[from] quircs::DecodeError),
 }
 
 /*
@@ -252,6 +296,8 @@ impl Transaction {
             Transaction::ToMessage(common) => display_message(common),
             Transaction::MakeTransaction(send) => send.exec(),
             Transaction::SetExpiryDate(set_expiry_date) => set_expiry_date.exec(),
+            Transaction::Export(export) => export.exec(),
+            Transaction::Import(import) => import.exec(),
         }
     }
 }
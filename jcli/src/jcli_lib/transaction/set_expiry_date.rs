@@ -1,4 +1,4 @@
-use crate::jcli_lib::transaction::{common, Error};
+use crate::jcli_lib::transaction::{common, validity::ValidityWindow, Error};
 use jormungandr_lib::interfaces;
 use structopt::StructOpt;
 
@@ -9,14 +9,22 @@ pub struct SetExpiryDate {
     pub common: common::CommonTransaction,
 
     /// the slot this transaction should be valid until, for example 3.14
-    #[structopt(name = "BLOCKDATE")]
-    pub valid_until: interfaces::BlockDate,
+    #[structopt(
+        name = "BLOCKDATE",
+        required_unless_one = &["valid-for-slots", "valid-for-epochs"],
+        conflicts_with_all = &["valid-for-slots", "valid-for-epochs"]
+    )]
+    pub valid_until: Option<interfaces::BlockDate>,
+
+    #[structopt(flatten)]
+    pub validity: ValidityWindow,
 }
 
 impl SetExpiryDate {
     pub fn exec(self) -> Result<(), Error> {
+        let valid_until = self.validity.valid_until(self.valid_until, None)?;
         let mut transaction = self.common.load()?;
-        transaction.set_expiry_date(self.valid_until)?;
+        transaction.set_expiry_date(valid_until)?;
         self.common.store(&transaction)
     }
 }
@@ -1,5 +1,5 @@
 use crate::jcli_lib::rest::RestArgs;
-use crate::jcli_lib::transaction::{common, Error};
+use crate::jcli_lib::transaction::{common, validity::ValidityWindow, Error};
 use crate::transaction::mk_witness::WitnessType;
 use crate::transaction::staging::Staging;
 use crate::utils::key_parser::{parse_ed25519_secret_key, read_ed25519_secret_key_from_file};
@@ -41,8 +41,16 @@ pub struct MakeTransaction {
     #[structopt(long)]
     pub block0_hash: String,
 
-    #[structopt(long)]
-    pub valid_until: interfaces::BlockDate,
+    /// the slot the transaction should be valid until, for example 3.14
+    #[structopt(
+        long,
+        required_unless_one = &["valid-for-slots", "valid-for-epochs"],
+        conflicts_with_all = &["valid-for-slots", "valid-for-epochs"]
+    )]
+    pub valid_until: Option<interfaces::BlockDate>,
+
+    #[structopt(flatten)]
+    pub validity: ValidityWindow,
 
     /// the file path to the file to read the signing key from.
     /// If omitted it will be read from the standard input.
@@ -69,6 +77,9 @@ pub struct MakeTransaction {
 
 impl MakeTransaction {
     pub fn exec(self) -> Result<(), Error> {
+        let valid_until = self
+            .validity
+            .valid_until(self.valid_until, Some(self.rest_args.clone()))?;
         let secret_key = read_secret_key(self.secret)?;
         let receiver_address = if let Some(address) = self.receiver {
             address
@@ -82,7 +93,7 @@ impl MakeTransaction {
             secret_key,
             self.value,
             &self.block0_hash,
-            self.valid_until,
+            valid_until,
             self.rest_args.clone(),
             self.change,
             self.force,
@@ -0,0 +1,150 @@
+use crate::jcli_lib::{
+    rest::{self, RestArgs},
+    transaction::Error,
+};
+use jormungandr_lib::{
+    interfaces::BlockDate,
+    time::{SecondsSinceUnixEpoch, SystemTime},
+};
+use reqwest::Url;
+use std::num::{NonZeroU32, NonZeroU8};
+use structopt::StructOpt;
+
+/// Computes the expiry date of a transaction from a validity duration, instead
+/// of having the user compute the block date by hand.
+///
+/// The current date is derived from the era parameters of the blockchain,
+/// either queried from a node or given on the command line.
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct ValidityWindow {
+    /// make the transaction valid for the given number of slots from now
+    #[structopt(long, conflicts_with = "valid-for-epochs")]
+    pub valid_for_slots: Option<u32>,
+    /// make the transaction valid for the given number of epochs from now
+    #[structopt(long)]
+    pub valid_for_epochs: Option<u32>,
+    /// node API address to query the era parameters from, when they are not
+    /// given with `--block0-time`, `--slot-duration` and `--slots-per-epoch`
+    #[structopt(long = "settings-host")]
+    pub host: Option<Url>,
+    /// start time of the blockchain, in seconds since the unix epoch
+    #[structopt(long, requires_all = &["slot-duration", "slots-per-epoch"])]
+    pub block0_time: Option<SecondsSinceUnixEpoch>,
+    /// duration of a slot, in seconds
+    #[structopt(long, requires = "block0-time")]
+    pub slot_duration: Option<NonZeroU8>,
+    /// number of slots in an epoch
+    #[structopt(long, requires = "block0-time")]
+    pub slots_per_epoch: Option<NonZeroU32>,
+}
+
+struct EraParameters {
+    block0_time: SystemTime,
+    slot_duration: u64,
+    slots_per_epoch: u32,
+    // only known when the parameters are queried from a node
+    max_expiry_epochs: Option<u8>,
+}
+
+impl ValidityWindow {
+    /// The expiry date given explicitly or, if none, computed from the
+    /// validity duration. `rest_args` are used to query the era parameters
+    /// when neither `--settings-host` nor the offline parameters are given.
+    pub fn valid_until(
+        &self,
+        explicit: Option<BlockDate>,
+        rest_args: Option<RestArgs>,
+    ) -> Result<BlockDate, Error> {
+        if let Some(valid_until) = explicit {
+            return Ok(valid_until);
+        }
+        let era = self.era_parameters(rest_args)?;
+        let slots_per_epoch = u64::from(era.slots_per_epoch);
+        let elapsed = SystemTime::now()
+            .duration_since(era.block0_time)
+            .map_err(|_| Error::ValidityBlockchainNotStarted)?;
+        let current = elapsed.as_secs() / era.slot_duration;
+
+        let validity = match (self.valid_for_slots, self.valid_for_epochs) {
+            (Some(slots), _) => u64::from(slots),
+            (None, Some(epochs)) => u64::from(epochs) * slots_per_epoch,
+            (None, None) => return Err(Error::ExpiryDateMissing),
+        };
+        expiry_date(current, validity, slots_per_epoch, era.max_expiry_epochs)
+    }
+
+    fn era_parameters(&self, rest_args: Option<RestArgs>) -> Result<EraParameters, Error> {
+        if let (Some(block0_time), Some(slot_duration), Some(slots_per_epoch)) =
+            (self.block0_time, self.slot_duration, self.slots_per_epoch)
+        {
+            return Ok(EraParameters {
+                block0_time: SystemTime::from_secs_since_epoch(block0_time.to_secs()),
+                slot_duration: u64::from(slot_duration.get()),
+                slots_per_epoch: slots_per_epoch.get(),
+                max_expiry_epochs: None,
+            });
+        }
+
+        let rest_args = match (&self.host, rest_args) {
+            (Some(host), _) => RestArgs::new(host.clone()),
+            (None, Some(rest_args)) => rest_args,
+            (None, None) => return Err(Error::ValidityEraParametersMissing),
+        };
+        let settings = rest::v0::settings::request_settings(rest_args)?;
+        Ok(EraParameters {
+            block0_time: settings.block0_time,
+            slot_duration: settings.slot_duration,
+            slots_per_epoch: settings.slots_per_epoch,
+            max_expiry_epochs: Some(settings.tx_max_expiry_epochs),
+        })
+    }
+}
+
+/// The date `validity` slots after the slot `current`, counted from block0.
+fn expiry_date(
+    current: u64,
+    validity: u64,
+    slots_per_epoch: u64,
+    max_expiry_epochs: Option<u8>,
+) -> Result<BlockDate, Error> {
+    let valid_until = current + validity;
+    let current_epoch = current / slots_per_epoch;
+    let epoch = valid_until / slots_per_epoch;
+
+    if let Some(max_expiry_epochs) = max_expiry_epochs {
+        if epoch > current_epoch + u64::from(max_expiry_epochs) {
+            return Err(Error::ValidityTooLong { max_expiry_epochs });
+        }
+    }
+
+    Ok(BlockDate::new(
+        epoch as u32,
+        (valid_until % slots_per_epoch) as u32,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn expiry_date_is_counted_in_slots_from_now() {
+        assert_eq!(expiry_date(25, 3, 10, None).unwrap(), BlockDate::new(2, 8));
+        assert_eq!(expiry_date(25, 30, 10, None).unwrap(), BlockDate::new(5, 5));
+    }
+
+    #[test]
+    pub fn expiry_date_is_bounded_by_the_max_expiry_epochs() {
+        assert_eq!(
+            expiry_date(25, 14, 10, Some(1)).unwrap(),
+            BlockDate::new(3, 9)
+        );
+        assert!(matches!(
+            expiry_date(25, 15, 10, Some(1)),
+            Err(Error::ValidityTooLong {
+                max_expiry_epochs: 1
+            })
+        ));
+    }
+}
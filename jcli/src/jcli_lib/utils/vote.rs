@@ -105,6 +105,17 @@ impl From<Vec<chain_vote::TallyDecryptShare>> for MemberVotePlanShares {
     }
 }
 
+impl TryFrom<MemberVotePlanShares> for Vec<chain_vote::TallyDecryptShare> {
+    type Error = SharesError;
+    fn try_from(shares: MemberVotePlanShares) -> Result<Self, Self::Error> {
+        shares
+            .0
+            .into_iter()
+            .map(chain_vote::TallyDecryptShare::try_from)
+            .collect()
+    }
+}
+
 impl TryFrom<Vec<MemberVotePlanShares>> for VotePlanDecryptShares {
     type Error = SharesError;
     fn try_from(shares: Vec<MemberVotePlanShares>) -> Result<Self, Self::Error> {
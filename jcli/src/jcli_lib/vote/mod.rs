@@ -3,7 +3,7 @@ use crate::jcli_lib::utils::vote::{SharesError, VotePlanError};
 
 pub mod bech32_constants;
 mod committee;
-mod election_public_key;
+pub(crate) mod election_public_key;
 mod tally;
 
 use structopt::StructOpt;
@@ -54,6 +54,10 @@ pub enum Error {
     VotePlanError(#[from] VotePlanError),
     #[error(transparent)]
     SharesError(#[from] SharesError),
+    #[error("found {shares} shares for {proposals} encrypted proposals")]
+    SharesCountMismatch { shares: usize, proposals: usize },
+    #[error("{invalid} decryption shares are invalid")]
+    InvalidDecryptionShares { invalid: usize },
 }
 
 #[derive(StructOpt)]
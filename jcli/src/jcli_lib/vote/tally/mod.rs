@@ -1,5 +1,6 @@
 mod decrypt_tally;
 mod decryption_shares;
+mod verify_share;
 
 use super::Error;
 use structopt::StructOpt;
@@ -20,6 +21,11 @@ pub enum Tally {
     /// The decrypted tally data will be printed in hexadecimal encoding
     /// on standard output.
     DecryptResults(decrypt_tally::TallyVotePlanWithAllShares),
+    /// Check the decryption shares of a committee member against the
+    /// encrypted tallies of a vote plan and the public key of the member.
+    ///
+    /// The proposals whose share is invalid are printed on standard output.
+    VerifyShare(verify_share::VerifyShare),
 }
 
 impl Tally {
@@ -28,6 +34,7 @@ impl Tally {
             Tally::DecryptionShares(cmd) => cmd.exec(),
             Tally::DecryptResults(cmd) => cmd.exec(),
             Tally::MergeShares(cmd) => cmd.exec(),
+            Tally::VerifyShare(cmd) => cmd.exec(),
         }
     }
 }
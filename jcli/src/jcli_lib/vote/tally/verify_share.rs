@@ -0,0 +1,106 @@
+use super::Error;
+use crate::jcli_lib::utils::vote::{self, MemberVotePlanShares};
+use crate::jcli_lib::utils::{io, OutputFormat};
+use crate::jcli_lib::vote::election_public_key::parse_member_key;
+use chain_vote::committee::MemberPublicKey;
+use chain_vote::EncryptedTally;
+use jormungandr_lib::crypto::hash::Hash;
+use jormungandr_lib::interfaces::{PrivateTallyState, Tally};
+use serde::Serialize;
+use std::convert::TryInto;
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct VerifyShare {
+    /// The path to json-encoded vote plan the shares were created for. If this
+    /// parameter is not specified, the vote plan will be read from the standard
+    /// input.
+    #[structopt(long)]
+    vote_plan: Option<PathBuf>,
+    /// The id of the vote plan.
+    /// Can be left unspecified if there is only one vote plan in the input
+    #[structopt(long)]
+    vote_plan_id: Option<Hash>,
+    /// The path to the shares of the committee member, as created by
+    /// `decryption-shares`.
+    #[structopt(long)]
+    shares: PathBuf,
+    /// The bech32-encoded public key of the committee member.
+    #[structopt(long, parse(try_from_str = parse_member_key))]
+    key: MemberPublicKey,
+    #[structopt(flatten)]
+    output_format: OutputFormat,
+}
+
+#[derive(Serialize)]
+struct Output {
+    /// number of the shares checked, one per encrypted proposal
+    verified: usize,
+    /// indices of the proposals whose share is invalid
+    invalid: Vec<u8>,
+}
+
+impl VerifyShare {
+    pub fn exec(&self) -> Result<(), Error> {
+        let vote_plan =
+            vote::get_vote_plan_by_id(self.vote_plan.as_ref(), self.vote_plan_id.as_ref())?;
+        let shares: MemberVotePlanShares =
+            serde_json::from_reader(io::open_file_read(&Some(&self.shares))?)?;
+        let shares: Vec<chain_vote::TallyDecryptShare> = shares.try_into()?;
+
+        // the shares are created for the encrypted proposals only, in order
+        let encrypted_tallies = vote_plan
+            .proposals
+            .into_iter()
+            .filter_map(|proposal| match proposal.tally {
+                Some(Tally::Private {
+                    state:
+                        PrivateTallyState::Encrypted {
+                            encrypted_tally, ..
+                        },
+                }) => Some((proposal.index, encrypted_tally.into_bytes())),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        if encrypted_tallies.len() != shares.len() {
+            return Err(Error::SharesCountMismatch {
+                shares: shares.len(),
+                proposals: encrypted_tallies.len(),
+            });
+        }
+
+        let mut invalid = Vec::new();
+        for ((index, encrypted_tally), share) in encrypted_tallies.iter().zip(shares.iter()) {
+            let encrypted_tally =
+                EncryptedTally::from_bytes(encrypted_tally).ok_or(Error::EncryptedTallyRead)?;
+            if encrypted_tally
+                .validate_partial_decryptions(
+                    std::slice::from_ref(&self.key),
+                    std::slice::from_ref(share),
+                )
+                .is_err()
+            {
+                invalid.push(*index);
+            }
+        }
+
+        let output = Output {
+            verified: shares.len(),
+            invalid,
+        };
+        println!(
+            "{}",
+            self.output_format
+                .format_json(serde_json::to_value(&output)?)?
+        );
+        if output.invalid.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::InvalidDecryptionShares {
+                invalid: output.invalid.len(),
+            })
+        }
+    }
+}
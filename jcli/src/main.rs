@@ -1,18 +1,31 @@
+use jcli_lib::{CommandError, ErrorFormat};
 use std::error::Error;
 use structopt::StructOpt;
 
 fn main() {
-    jcli_lib::JCli::from_args()
-        .exec()
-        .unwrap_or_else(report_error)
+    let jcli = jcli_lib::JCli::from_args();
+    let error_format = jcli.error_format();
+    jcli.exec()
+        .unwrap_or_else(|error| report_error(error, error_format))
 }
 
-fn report_error(error: Box<dyn Error>) {
-    eprintln!("{}", error);
-    let mut source = error.source();
-    while let Some(sub_error) = source {
-        eprintln!("  |-> {}", sub_error);
-        source = sub_error.source();
+fn report_error(error: Box<dyn Error>, error_format: ErrorFormat) {
+    let command_error = error.downcast_ref::<CommandError>();
+    if error_format == ErrorFormat::Json {
+        match command_error {
+            Some(command_error) => eprintln!("{}", command_error.to_json()),
+            None => eprintln!(
+                "{}",
+                serde_json::json!({ "code": "jcli", "message": error.to_string() })
+            ),
+        }
+    } else {
+        eprintln!("{}", error);
+        let mut source = error.source();
+        while let Some(sub_error) = source {
+            eprintln!("  |-> {}", sub_error);
+            source = sub_error.source();
+        }
     }
-    std::process::exit(1)
+    std::process::exit(command_error.map_or(1, CommandError::exit_code))
 }
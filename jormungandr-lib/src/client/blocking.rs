@@ -0,0 +1,119 @@
+//! Synchronous counterpart of [`RestClient`](super::RestClient).
+
+use super::{
+    account_path, decode_body, fragment_ids_query, parse_statuses, with_trailing_slash, Error,
+    FRAGMENTS, FRAGMENT_LOGS, FRAGMENT_STATUSES, MESSAGE, SETTINGS, SETTINGS_AT, VOTE_PLANS,
+};
+use crate::interfaces::{
+    AccountState, FragmentLog, FragmentStatus, FragmentsBatch, FragmentsProcessingSummary,
+    SettingsDto, VotePlanStatus,
+};
+use chain_core::property::Serialize as _;
+use chain_impl_mockchain::{
+    account::Identifier,
+    fragment::{Fragment, FragmentId},
+};
+use reqwest::{
+    blocking::{Client, Response},
+    header::CONTENT_TYPE,
+    Url,
+};
+use serde::de::DeserializeOwned;
+use std::collections::HashMap;
+
+/// Blocking client of the REST API of a node.
+#[derive(Debug, Clone)]
+pub struct RestClient {
+    base: Url,
+    client: Client,
+}
+
+impl RestClient {
+    /// `base` is the address of the REST API, e.g. `http://127.0.0.1:8443/api`.
+    pub fn new(base: Url) -> Self {
+        Self::with_client(base, Client::new())
+    }
+
+    /// Sends the requests with a client configured by the caller, e.g. with
+    /// TLS certificates or timeouts.
+    pub fn with_client(base: Url, client: Client) -> Self {
+        Self {
+            base: with_trailing_slash(base),
+            client,
+        }
+    }
+
+    pub fn settings(&self) -> Result<SettingsDto, Error> {
+        self.get(SETTINGS)
+    }
+
+    /// The settings that applied in the given epoch.
+    pub fn settings_at(&self, epoch: u32) -> Result<SettingsDto, Error> {
+        let response = self
+            .client
+            .get(self.url(SETTINGS_AT)?)
+            .query(&[("epoch", epoch)])
+            .send()?;
+        decode(response)
+    }
+
+    pub fn account_state(&self, account: &Identifier) -> Result<AccountState, Error> {
+        self.get(&account_path(account))
+    }
+
+    pub fn send_fragment(&self, fragment: &Fragment) -> Result<FragmentsProcessingSummary, Error> {
+        let body = fragment
+            .serialize_as_vec()
+            .map_err(Error::FragmentEncoding)?;
+        let response = self
+            .client
+            .post(self.url(MESSAGE)?)
+            .header(CONTENT_TYPE, "application/octet-stream")
+            .body(body)
+            .send()?;
+        decode(response)
+    }
+
+    pub fn send_fragments(
+        &self,
+        batch: &FragmentsBatch,
+    ) -> Result<FragmentsProcessingSummary, Error> {
+        let response = self.client.post(self.url(FRAGMENTS)?).json(batch).send()?;
+        decode(response)
+    }
+
+    pub fn fragment_statuses(
+        &self,
+        ids: &[FragmentId],
+    ) -> Result<HashMap<FragmentId, FragmentStatus>, Error> {
+        let response = self
+            .client
+            .get(self.url(FRAGMENT_STATUSES)?)
+            .query(&[("fragment_ids", fragment_ids_query(ids))])
+            .send()?;
+        parse_statuses(decode(response)?)
+    }
+
+    pub fn fragment_logs(&self) -> Result<Vec<FragmentLog>, Error> {
+        self.get(FRAGMENT_LOGS)
+    }
+
+    pub fn vote_plan_statuses(&self) -> Result<Vec<VotePlanStatus>, Error> {
+        self.get(VOTE_PLANS)
+    }
+
+    fn url(&self, path: &str) -> Result<Url, Error> {
+        self.base.join(path).map_err(Into::into)
+    }
+
+    fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T, Error> {
+        let response = self.client.get(self.url(path)?).send()?;
+        decode(response)
+    }
+}
+
+fn decode<T: DeserializeOwned>(response: Response) -> Result<T, Error> {
+    let status = response.status();
+    let body = response.text()?;
+    decode_body(status, body)
+}
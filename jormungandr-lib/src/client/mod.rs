@@ -0,0 +1,293 @@
+//! Typed client of the REST API of the node.
+//!
+//! The requests and responses use the types of the [`interfaces`](crate::interfaces)
+//! module, so that the client stays in sync with the node. [`RestClient`] is
+//! asynchronous, [`blocking::RestClient`] offers the same requests for
+//! synchronous code.
+
+pub mod blocking;
+
+use crate::interfaces::{
+    AccountState, FragmentLog, FragmentStatus, FragmentsBatch, FragmentsProcessingSummary,
+    SettingsDto, VotePlanStatus,
+};
+use chain_core::property::Serialize as _;
+use chain_impl_mockchain::{
+    account::Identifier,
+    fragment::{Fragment, FragmentId},
+};
+use reqwest::{header::CONTENT_TYPE, StatusCode, Url};
+use serde::de::DeserializeOwned;
+use std::{collections::HashMap, str::FromStr};
+use thiserror::Error;
+
+const SETTINGS: &str = "v0/settings";
+const SETTINGS_AT: &str = "v1/settings";
+const MESSAGE: &str = "v0/message";
+const VOTE_PLANS: &str = "v0/vote/active/plans";
+const FRAGMENTS: &str = "v1/fragments";
+const FRAGMENT_STATUSES: &str = "v1/fragments/statuses";
+const FRAGMENT_LOGS: &str = "v1/fragments/logs";
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("invalid REST API address")]
+    InvalidUrl(#[from] url::ParseError),
+    #[error("request failed")]
+    Request(#[from] reqwest::Error),
+    #[error("the node answered with status {status}: {body}")]
+    Status { status: StatusCode, body: String },
+    #[error("{} of the fragments were rejected", .0.rejected.len())]
+    FragmentsRejected(FragmentsProcessingSummary),
+    #[error("all the fragments were already known to the node")]
+    FragmentsAlreadyKnown(FragmentsProcessingSummary),
+    #[error("cannot decode the response of the node")]
+    Decode(#[from] serde_json::Error),
+    #[error("cannot encode the fragment")]
+    FragmentEncoding(#[source] std::io::Error),
+    #[error("the node answered with an invalid fragment id")]
+    InvalidFragmentId(#[from] chain_crypto::hash::Error),
+}
+
+/// Asynchronous client of the REST API of a node.
+#[derive(Debug, Clone)]
+pub struct RestClient {
+    base: Url,
+    client: reqwest::Client,
+}
+
+impl RestClient {
+    /// `base` is the address of the REST API, e.g. `http://127.0.0.1:8443/api`.
+    pub fn new(base: Url) -> Self {
+        Self::with_client(base, reqwest::Client::new())
+    }
+
+    /// Sends the requests with a client configured by the caller, e.g. with
+    /// TLS certificates or timeouts.
+    pub fn with_client(base: Url, client: reqwest::Client) -> Self {
+        Self {
+            base: with_trailing_slash(base),
+            client,
+        }
+    }
+
+    pub async fn settings(&self) -> Result<SettingsDto, Error> {
+        self.get(SETTINGS).await
+    }
+
+    /// The settings that applied in the given epoch.
+    pub async fn settings_at(&self, epoch: u32) -> Result<SettingsDto, Error> {
+        let response = self
+            .client
+            .get(self.url(SETTINGS_AT)?)
+            .query(&[("epoch", epoch)])
+            .send()
+            .await?;
+        decode(response).await
+    }
+
+    pub async fn account_state(&self, account: &Identifier) -> Result<AccountState, Error> {
+        self.get(&account_path(account)).await
+    }
+
+    pub async fn send_fragment(
+        &self,
+        fragment: &Fragment,
+    ) -> Result<FragmentsProcessingSummary, Error> {
+        let body = fragment
+            .serialize_as_vec()
+            .map_err(Error::FragmentEncoding)?;
+        let response = self
+            .client
+            .post(self.url(MESSAGE)?)
+            .header(CONTENT_TYPE, "application/octet-stream")
+            .body(body)
+            .send()
+            .await?;
+        decode(response).await
+    }
+
+    pub async fn send_fragments(
+        &self,
+        batch: &FragmentsBatch,
+    ) -> Result<FragmentsProcessingSummary, Error> {
+        let response = self
+            .client
+            .post(self.url(FRAGMENTS)?)
+            .json(batch)
+            .send()
+            .await?;
+        decode(response).await
+    }
+
+    pub async fn fragment_statuses(
+        &self,
+        ids: &[FragmentId],
+    ) -> Result<HashMap<FragmentId, FragmentStatus>, Error> {
+        let response = self
+            .client
+            .get(self.url(FRAGMENT_STATUSES)?)
+            .query(&[("fragment_ids", fragment_ids_query(ids))])
+            .send()
+            .await?;
+        parse_statuses(decode(response).await?)
+    }
+
+    pub async fn fragment_logs(&self) -> Result<Vec<FragmentLog>, Error> {
+        self.get(FRAGMENT_LOGS).await
+    }
+
+    pub async fn vote_plan_statuses(&self) -> Result<Vec<VotePlanStatus>, Error> {
+        self.get(VOTE_PLANS).await
+    }
+
+    fn url(&self, path: &str) -> Result<Url, Error> {
+        self.base.join(path).map_err(Into::into)
+    }
+
+    async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T, Error> {
+        let response = self.client.get(self.url(path)?).send().await?;
+        decode(response).await
+    }
+}
+
+async fn decode<T: DeserializeOwned>(response: reqwest::Response) -> Result<T, Error> {
+    let status = response.status();
+    let body = response.text().await?;
+    decode_body(status, body)
+}
+
+/// The node answers with the processing summary when it rejects fragments, or
+/// when it already knows all of them, any other failure is reported with the
+/// body of the response.
+fn decode_body<T: DeserializeOwned>(status: StatusCode, body: String) -> Result<T, Error> {
+    if status.is_success() {
+        return serde_json::from_str(&body).map_err(Into::into);
+    }
+    let summary = || serde_json::from_str::<FragmentsProcessingSummary>(&body).ok();
+    match status {
+        StatusCode::BAD_REQUEST => {
+            if let Some(summary) = summary() {
+                return Err(Error::FragmentsRejected(summary));
+            }
+        }
+        StatusCode::CONFLICT => {
+            if let Some(summary) = summary() {
+                return Err(Error::FragmentsAlreadyKnown(summary));
+            }
+        }
+        _ => {}
+    }
+    Err(Error::Status { status, body })
+}
+
+// `Url::join` replaces the last segment of a base without a trailing slash
+fn with_trailing_slash(mut base: Url) -> Url {
+    if !base.path().ends_with('/') {
+        let path = format!("{}/", base.path());
+        base.set_path(&path);
+    }
+    base
+}
+
+fn account_path(account: &Identifier) -> String {
+    format!("v0/account/{}", hex::encode(account.as_ref().as_ref()))
+}
+
+fn fragment_ids_query(ids: &[FragmentId]) -> String {
+    ids.iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn parse_statuses(
+    statuses: HashMap<String, FragmentStatus>,
+) -> Result<HashMap<FragmentId, FragmentStatus>, Error> {
+    statuses
+        .into_iter()
+        .map(|(id, status)| Ok((FragmentId::from_str(&id)?, status)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interfaces::{FragmentRejectionReason, RejectedFragmentInfo};
+
+    fn summary(reason: FragmentRejectionReason) -> FragmentsProcessingSummary {
+        FragmentsProcessingSummary {
+            accepted: Vec::new(),
+            rejected: vec![RejectedFragmentInfo {
+                id: FragmentId::hash_bytes(&[1]),
+                reason,
+            }],
+        }
+    }
+
+    #[test]
+    fn rejected_and_duplicate_fragments_carry_the_summary() {
+        let rejected = summary(FragmentRejectionReason::FragmentInvalid);
+        let body = serde_json::to_string(&rejected).unwrap();
+        match decode_body::<FragmentsProcessingSummary>(StatusCode::BAD_REQUEST, body) {
+            Err(Error::FragmentsRejected(summary)) => assert_eq!(summary, rejected),
+            other => panic!("unexpected result {:?}", other),
+        }
+
+        let duplicate = summary(FragmentRejectionReason::FragmentAlreadyInLog);
+        let body = serde_json::to_string(&duplicate).unwrap();
+        match decode_body::<FragmentsProcessingSummary>(StatusCode::CONFLICT, body) {
+            Err(Error::FragmentsAlreadyKnown(summary)) => assert_eq!(summary, duplicate),
+            other => panic!("unexpected result {:?}", other),
+        }
+    }
+
+    #[test]
+    fn successful_responses_are_decoded() {
+        let summary = summary(FragmentRejectionReason::FragmentAlreadyInLog);
+        let body = serde_json::to_string(&summary).unwrap();
+        let decoded: FragmentsProcessingSummary = decode_body(StatusCode::OK, body).unwrap();
+        assert_eq!(decoded, summary);
+
+        assert!(matches!(
+            decode_body::<FragmentsProcessingSummary>(StatusCode::OK, "{}".to_owned()),
+            Err(Error::Decode(_))
+        ));
+    }
+
+    #[test]
+    fn other_failures_carry_the_body() {
+        for status in vec![
+            StatusCode::BAD_REQUEST,
+            StatusCode::NOT_FOUND,
+            StatusCode::INTERNAL_SERVER_ERROR,
+        ] {
+            match decode_body::<FragmentsProcessingSummary>(status, "failed".to_owned()) {
+                Err(Error::Status {
+                    status: answered,
+                    body,
+                }) => {
+                    assert_eq!(answered, status);
+                    assert_eq!(body, "failed");
+                }
+                other => panic!("unexpected result {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn paths_are_joined_below_the_base() {
+        for base in &["http://127.0.0.1:8443/api", "http://127.0.0.1:8443/api/"] {
+            let base = with_trailing_slash(Url::parse(base).unwrap());
+            assert_eq!(
+                base.join(SETTINGS).unwrap().as_str(),
+                "http://127.0.0.1:8443/api/v0/settings"
+            );
+        }
+        let root = with_trailing_slash(Url::parse("http://127.0.0.1:8443").unwrap());
+        assert_eq!(
+            root.join(FRAGMENTS).unwrap().as_str(),
+            "http://127.0.0.1:8443/v1/fragments"
+        );
+    }
+}
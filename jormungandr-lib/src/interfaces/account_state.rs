@@ -1,4 +1,7 @@
-use crate::{crypto::hash::Hash, interfaces::Value};
+use crate::{
+    crypto::hash::Hash,
+    interfaces::{BlockDate, Value},
+};
 use chain_impl_mockchain::{accounting::account, block::Epoch};
 use serde::{Deserialize, Serialize};
 use std::convert::TryInto;
@@ -122,6 +125,30 @@ impl AccountState {
     }
 }
 
+/// State of an account as it was right after the given block was applied
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AccountStateAt {
+    /// hash of the block
+    pub block: Hash,
+    /// date of the block
+    pub date: BlockDate,
+    pub state: AccountState,
+}
+
+/// Change of the balance or of the spending counter of an account, observed
+/// when the given block became the tip of the main branch
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AccountEvent {
+    /// hex-encoded public key of the account
+    pub account_id: String,
+    /// hash of the block
+    pub block: Hash,
+    /// date of the block
+    pub date: BlockDate,
+    pub value: Value,
+    pub counter: u32,
+}
+
 /* ---------------- Conversion --------------------------------------------- */
 
 impl From<account::LastRewards> for LastRewards {
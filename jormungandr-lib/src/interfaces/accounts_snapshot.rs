@@ -0,0 +1,242 @@
+//! Proofs of the balance of an account at the end of an epoch.
+//!
+//! The accounts of the ledger at the end of an epoch are the leaves of a
+//! binary Merkle tree, ordered by public key. The nodes publish the root of the
+//! tree, and a proof is the path from the leaf of an account to the root: an
+//! external system knowing the root checks a balance without trusting the node
+//! serving the proof.
+
+use crate::{crypto::hash::Hash, interfaces::Value};
+use chain_crypto::hash::Blake2b256;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+const LEAF_TAG: u8 = 0;
+const NODE_TAG: u8 = 1;
+
+/// Root of the accounts of the ledger at the end of an epoch
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AccountsSnapshot {
+    pub epoch: u32,
+    /// hash of the last block of the epoch
+    pub block: Hash,
+    pub root: Hash,
+    /// number of accounts, the leaves of the tree
+    pub accounts: u64,
+}
+
+/// Balance of an account in an [`AccountsSnapshot`], with the path from the
+/// leaf of the account to the root
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BalanceProof {
+    #[serde(flatten)]
+    pub snapshot: AccountsSnapshot,
+    /// hex-encoded public key of the account
+    pub account_id: String,
+    pub value: Value,
+    /// position of the account among the leaves
+    pub index: u64,
+    /// the siblings of the nodes on the path, from the leaf up
+    pub path: Vec<Hash>,
+}
+
+#[derive(Debug, Error)]
+pub enum BalanceProofError {
+    #[error("invalid account id")]
+    InvalidAccountId(#[from] hex::FromHexError),
+    #[error("account index {index} is out of the {accounts} accounts of the snapshot")]
+    IndexOutOfRange { index: u64, accounts: u64 },
+    #[error("the path has {actual} nodes, {expected} are expected")]
+    PathLength { expected: usize, actual: usize },
+    #[error("the path does not lead to the root of the snapshot")]
+    RootMismatch,
+}
+
+impl BalanceProof {
+    /// Checks that the path leads from the leaf of the account to the root of
+    /// the snapshot. The root itself is to be compared with the one published
+    /// for the epoch.
+    pub fn verify(&self) -> Result<(), BalanceProofError> {
+        let account_id = hex::decode(&self.account_id)?;
+        let accounts = self.snapshot.accounts;
+        if self.index >= accounts {
+            return Err(BalanceProofError::IndexOutOfRange {
+                index: self.index,
+                accounts,
+            });
+        }
+
+        let expected = path_length(self.index, accounts);
+        if self.path.len() != expected {
+            return Err(BalanceProofError::PathLength {
+                expected,
+                actual: self.path.len(),
+            });
+        }
+
+        let mut hash = leaf_hash(&account_id, self.value);
+        let mut siblings = self.path.iter();
+        let mut index = self.index;
+        let mut width = accounts;
+        while width > 1 {
+            if index % 2 == 1 {
+                hash = node_hash(siblings.next().unwrap(), &hash);
+            } else if index + 1 < width {
+                hash = node_hash(&hash, siblings.next().unwrap());
+            }
+            index /= 2;
+            width = (width + 1) / 2;
+        }
+
+        if hash == self.snapshot.root {
+            Ok(())
+        } else {
+            Err(BalanceProofError::RootMismatch)
+        }
+    }
+}
+
+/// Merkle tree of the accounts of a ledger. The leaves are given in the order
+/// of the public keys of the accounts; at each level, the last node is moved
+/// up unchanged when it has no sibling.
+pub struct AccountsMerkleTree {
+    /// the leaves first, the root last
+    levels: Vec<Vec<Hash>>,
+}
+
+impl AccountsMerkleTree {
+    pub fn new<'a>(accounts: impl IntoIterator<Item = (&'a [u8], Value)>) -> Self {
+        let leaves: Vec<Hash> = accounts
+            .into_iter()
+            .map(|(account_id, value)| leaf_hash(account_id, value))
+            .collect();
+        let mut levels = vec![leaves];
+        while levels.last().unwrap().len() > 1 {
+            let next = levels
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => node_hash(left, right),
+                    [single] => *single,
+                    _ => unreachable!(),
+                })
+                .collect();
+            levels.push(next);
+        }
+        AccountsMerkleTree { levels }
+    }
+
+    /// number of accounts in the tree
+    pub fn len(&self) -> usize {
+        self.levels[0].len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.levels[0].is_empty()
+    }
+
+    /// the root of the tree, the hash of nothing for an empty ledger
+    pub fn root(&self) -> Hash {
+        match self.levels.last().unwrap().first() {
+            Some(root) => *root,
+            None => Blake2b256::new(&[]).into(),
+        }
+    }
+
+    /// the siblings of the nodes on the path from the leaf at `index` to the
+    /// root, as expected in [`BalanceProof::path`]
+    pub fn path(&self, index: usize) -> Vec<Hash> {
+        let mut path = Vec::new();
+        let mut index = index;
+        for level in &self.levels[..self.levels.len() - 1] {
+            if let Some(sibling) = level.get(index ^ 1) {
+                path.push(*sibling);
+            }
+            index /= 2;
+        }
+        path
+    }
+}
+
+/// number of siblings on the path from the leaf at `index` to the root of a
+/// tree of `width` leaves
+fn path_length(mut index: u64, mut width: u64) -> usize {
+    let mut length = 0;
+    while width > 1 {
+        if index % 2 == 1 || index + 1 < width {
+            length += 1;
+        }
+        index /= 2;
+        width = (width + 1) / 2;
+    }
+    length
+}
+
+fn leaf_hash(account_id: &[u8], value: Value) -> Hash {
+    let mut bytes = Vec::with_capacity(1 + account_id.len() + 8);
+    bytes.push(LEAF_TAG);
+    bytes.extend_from_slice(account_id);
+    bytes.extend_from_slice(&u64::from(value).to_be_bytes());
+    Blake2b256::new(&bytes).into()
+}
+
+fn node_hash(left: &Hash, right: &Hash) -> Hash {
+    let left: [u8; 32] = (*left).into();
+    let right: [u8; 32] = (*right).into();
+    let mut bytes = Vec::with_capacity(1 + 2 * 32);
+    bytes.push(NODE_TAG);
+    bytes.extend_from_slice(&left);
+    bytes.extend_from_slice(&right);
+    Blake2b256::new(&bytes).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn accounts(count: u8) -> Vec<([u8; 32], Value)> {
+        (0..count)
+            .map(|i| ([i; 32], Value::from(u64::from(i) * 100)))
+            .collect()
+    }
+
+    fn proof(accounts: &[([u8; 32], Value)], index: usize) -> BalanceProof {
+        let tree = AccountsMerkleTree::new(accounts.iter().map(|(id, value)| (&id[..], *value)));
+        BalanceProof {
+            snapshot: AccountsSnapshot {
+                epoch: 1,
+                block: Hash::from([0; 32]),
+                root: tree.root(),
+                accounts: tree.len() as u64,
+            },
+            account_id: hex::encode(accounts[index].0),
+            value: accounts[index].1,
+            index: index as u64,
+            path: tree.path(index),
+        }
+    }
+
+    #[test]
+    fn proofs_of_all_accounts_verify() {
+        for count in 1..=9 {
+            let accounts = accounts(count);
+            for index in 0..accounts.len() {
+                proof(&accounts, index)
+                    .verify()
+                    .unwrap_or_else(|e| panic!("account {} of {}: {}", index, count, e));
+            }
+        }
+    }
+
+    #[test]
+    fn proof_of_another_balance_is_rejected() {
+        let accounts = accounts(5);
+        let mut proof = proof(&accounts, 4);
+        proof.value = Value::from(1);
+        assert!(matches!(
+            proof.verify(),
+            Err(BalanceProofError::RootMismatch)
+        ));
+    }
+}
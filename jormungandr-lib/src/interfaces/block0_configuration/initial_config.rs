@@ -483,7 +483,7 @@ enum DiscriminationDef {
 
 #[derive(Serialize, Deserialize)]
 #[serde(rename_all = "snake_case", remote = "ConsensusVersion")]
-enum ConsensusVersionDef {
+pub(crate) enum ConsensusVersionDef {
     Bft,
     GenesisPraos,
 }
@@ -17,6 +17,7 @@ pub use self::default_values::*;
 pub use self::epoch_stability_depth::EpochStabilityDepth;
 pub use self::fees_go_to::FeesGoTo;
 pub use self::initial_config::BlockchainConfiguration;
+pub(crate) use self::initial_config::ConsensusVersionDef;
 pub use self::initial_fragment::{
     try_initials_vec_from_messages, Initial, InitialUTxO, LegacyUTxO,
 };
@@ -0,0 +1,25 @@
+use crate::time::Duration;
+use serde::{Deserialize, Serialize};
+use std::net::SocketAddr;
+
+/// progress of the bootstrap of the node from the network
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BootstrapStatus {
+    /// whether the node is done bootstrapping
+    pub completed: bool,
+    /// number of the bootstrap attempts so far
+    pub attempt: u32,
+    /// the peer the blocks are currently downloaded from
+    pub peer: Option<SocketAddr>,
+    /// number of blocks applied since the start of the bootstrap
+    pub blocks_applied: u64,
+    /// chain length of the tip of the node
+    pub chain_length: u32,
+    /// chain length of the tip of the peer, when known
+    pub network_chain_length: Option<u32>,
+    /// number of blocks left to download from the peer, when known
+    pub remaining_blocks: Option<u32>,
+    /// estimation based on the rate at which the blocks were applied so far
+    pub estimated_remaining_time: Option<Duration>,
+}
@@ -1,3 +1,4 @@
+use crate::time::Duration;
 use std::path::PathBuf;
 
 use serde::{Deserialize, Serialize};
@@ -13,6 +14,45 @@ pub struct PersistentLog {
     pub dir: PathBuf,
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct PoolSnapshot {
+    /// file the pending fragments are written to at shutdown and
+    /// restored from at startup
+    pub path: PathBuf,
+}
+
+/// bounds on the validity of the fragments accepted in the mempool, relative
+/// to the date of the current tip
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ValidityWindow {
+    /// reject fragments valid until more than this number of epochs
+    /// after the current one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_epochs_ahead: Option<u32>,
+    /// reject fragments expiring in less than this number of slots
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_slots_before_expiry: Option<u32>,
+}
+
+/// limits on the fragment logs of processed (included in a block or
+/// rejected) fragments, on top of `log_max_entries`
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct LogGc {
+    /// remove the logs of fragments processed more than this number of
+    /// epochs before the current one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_age_epochs: Option<u32>,
+    /// maximum number of logs of fragments included in a block
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_in_block_entries: Option<usize>,
+    /// maximum number of logs of rejected fragments
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_rejected_entries: Option<usize>,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct Mempool {
@@ -22,9 +62,22 @@ pub struct Mempool {
     /// maximum number of entries in the fragment logs
     #[serde(default)]
     pub log_max_entries: LogMaxEntries,
+    /// garbage collection of the fragment logs
+    #[serde(default)]
+    pub log_gc: Option<LogGc>,
+    /// interval at which fragments still pending in the mempool are
+    /// announced to the peers again. Rebroadcasting is disabled if not set.
+    #[serde(default)]
+    pub rebroadcast_interval: Option<Duration>,
     /// path to the persistent log of all incoming fragments
     #[serde(default)]
     pub persistent_log: Option<PersistentLog>,
+    /// keep the fragments pending in the mempool across node restarts
+    #[serde(default)]
+    pub snapshot: Option<PoolSnapshot>,
+    /// reject fragments whose validity is out of the given bounds
+    #[serde(default)]
+    pub validity_window: Option<ValidityWindow>,
 }
 
 impl Default for PoolMaxEntries {
@@ -44,7 +97,11 @@ impl Default for Mempool {
         Mempool {
             pool_max_entries: PoolMaxEntries::default(),
             log_max_entries: LogMaxEntries::default(),
+            log_gc: None,
+            rebroadcast_interval: None,
             persistent_log: None,
+            snapshot: None,
+            validity_window: None,
         }
     }
 }
@@ -4,9 +4,12 @@ mod node;
 mod secret;
 
 pub use log::{Log, LogEntry, LogOutput};
-pub use mempool::{LogMaxEntries, Mempool, PersistentLog, PoolMaxEntries};
+pub use mempool::{
+    LogGc, LogMaxEntries, Mempool, PersistentLog, PoolMaxEntries, PoolSnapshot, ValidityWindow,
+};
 pub use node::{
-    Cors, Explorer, LayersConfig, NodeConfig, NodeId, P2p, Policy, PreferredListConfig, Rest, Tls,
+    BlockPropagationConfig, Cors, Explorer, ExplorerIndexer, LayerSizeConfig, LayersConfig,
+    NodeConfig, NodeId, P2p, Policy, PreferredListConfig, Rest, SimulationClock, Tls,
     TopicsOfInterest, TrustedPeer,
 };
 pub use secret::{Bft, GenesisPraos, NodeSecret};
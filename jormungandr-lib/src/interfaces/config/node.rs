@@ -5,7 +5,7 @@ use crate::time::Duration;
 use chain_crypto::Ed25519;
 use multiaddr::Multiaddr;
 use serde::{de::Visitor, Deserialize, Deserializer, Serialize};
-use std::{fmt, net::SocketAddr, path::PathBuf, str::FromStr};
+use std::{fmt, net::SocketAddr, num::NonZeroU32, path::PathBuf, str::FromStr};
 const DEFAULT_PREFERRED_VIEW_MAX: usize = 20;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -18,6 +18,12 @@ pub struct Rest {
     /// Enables CORS if provided
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cors: Option<Cors>,
+    /// Maximum size in bytes of a request body, larger requests are rejected
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_body_size: Option<u64>,
+    /// Maximum time allowed to handle an API request, no limit if not provided
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_timeout: Option<Duration>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -35,6 +41,9 @@ pub struct Cors {
     /// If none provided, echos request origin
     #[serde(default)]
     pub allowed_origins: Vec<CorsOrigin>,
+    /// If none provided, `GET` and `POST` are allowed
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allowed_methods: Vec<CorsMethod>,
     /// If none provided, CORS responses won't be cached
     pub max_age_secs: Option<u64>,
 }
@@ -87,6 +96,46 @@ impl AsRef<str> for CorsOrigin {
     }
 }
 
+/// HTTP method allowed in cross-origin requests, stored in upper case
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct CorsMethod(String);
+
+impl<'de> Deserialize<'de> for CorsMethod {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use http::Method;
+        use serde::de::Unexpected;
+
+        const METHODS: [Method; 7] = [
+            Method::GET,
+            Method::HEAD,
+            Method::POST,
+            Method::PUT,
+            Method::DELETE,
+            Method::OPTIONS,
+            Method::PATCH,
+        ];
+
+        let v = String::deserialize(deserializer)?.to_uppercase();
+        if METHODS.iter().any(|method| method.as_str() == v) {
+            Ok(CorsMethod(v))
+        } else {
+            Err(serde::de::Error::invalid_value(
+                Unexpected::Str(&v),
+                &"one of GET, HEAD, POST, PUT, DELETE, OPTIONS or PATCH",
+            ))
+        }
+    }
+}
+
+impl AsRef<str> for CorsMethod {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct P2p {
     /// The public address to which other peers may connect to
@@ -104,6 +153,11 @@ pub struct P2p {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub listen: Option<SocketAddr>,
 
+    /// Port of the loopback listener serving the gRPC services reserved to
+    /// the clients on the host of the node.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub local_listen_port: Option<u16>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_connections: Option<u32>,
 
@@ -125,6 +179,14 @@ pub struct P2p {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub network_stuck_check: Option<Duration>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_propagation: Option<BlockPropagationConfig>,
+
+    /// peers the node always stays connected to and always propagates
+    /// blocks and fragments to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preferred_peers: Option<Vec<TrustedPeer>>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -144,6 +206,16 @@ pub struct Policy {
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Explorer {
     pub enabled: bool,
+    /// run the explorer as an indexer of another node
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub indexer: Option<ExplorerIndexer>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct ExplorerIndexer {
+    /// the node the blocks are pulled from
+    pub upstream: TrustedPeer,
 }
 
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
@@ -153,6 +225,24 @@ pub struct LayersConfig {
     pub preferred_list: Option<PreferredListConfig>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub topics_of_interest: Option<TopicsOfInterest>,
+    /// sizes of the poldercast rings layer
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rings: Option<LayerSizeConfig>,
+    /// sizes of the poldercast cyclon layer
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cyclon: Option<LayerSizeConfig>,
+}
+
+/// Number of entries a topology layer contributes to the view of the
+/// neighborhood and to the gossips sent to the other nodes. The defaults of
+/// the layer are used for the values which are not set.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LayerSizeConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub view_size: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gossip_size: Option<usize>,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
@@ -180,6 +270,24 @@ pub struct PreferredListConfig {
     pub peers: Vec<TrustedPeer>,
 }
 
+/// Settings for announcing new blocks to the peers.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct BlockPropagationConfig {
+    /// Maximum number of peers a block is announced to, priority peers included.
+    /// If not set, the block is announced to all the peers selected by the topology.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fan_out: Option<usize>,
+
+    /// Peers which are always announced new blocks first.
+    #[serde(default)]
+    pub priority_peers: Vec<TrustedPeer>,
+
+    /// Whether the trusted peers should be treated as priority peers as well.
+    #[serde(default)]
+    pub prioritize_trusted_peers: bool,
+}
+
 /// Identifier of a peer node.
 pub type NodeId = Identifier<Ed25519>;
 
@@ -197,6 +305,15 @@ pub struct TrustedPeer {
     pub id: Option<NodeId>,
 }
 
+/// Simulated clock of the node, for tests. Only accepted by the nodes built
+/// with the `simulation-clock` feature.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct SimulationClock {
+    /// How many times faster than the system clock the clock of the node runs
+    pub acceleration: NonZeroU32,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct NodeConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -208,6 +325,8 @@ pub struct NodeConfig {
     pub mempool: Option<Mempool>,
     pub bootstrap_from_trusted_peers: Option<bool>,
     pub skip_bootstrap: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub simulation_clock: Option<SimulationClock>,
 }
 
 impl P2p {
@@ -216,3 +335,24 @@ impl P2p {
             .or_else(|| multiaddr_utils::to_tcp_socket_addr(&self.public_address))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cors_methods_are_checked_and_upper_cased() {
+        let cors: Cors = serde_yaml::from_str(
+            "allowed_origins: [\"http://127.0.0.1:8080\"]\nallowed_methods: [get, Post]\n",
+        )
+        .unwrap();
+        let methods: Vec<&str> = cors.allowed_methods.iter().map(AsRef::as_ref).collect();
+        assert_eq!(methods, vec!["GET", "POST"]);
+
+        assert!(serde_yaml::from_str::<Cors>("allowed_methods: [CONNECT]").is_err());
+        assert!(serde_yaml::from_str::<Cors>("{}")
+            .unwrap()
+            .allowed_methods
+            .is_empty());
+    }
+}
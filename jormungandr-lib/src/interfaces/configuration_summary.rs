@@ -0,0 +1,69 @@
+use super::block0_configuration::ConsensusVersionDef;
+use chain_impl_mockchain::chaintypes::ConsensusVersion;
+use serde::{Deserialize, Serialize};
+use std::net::SocketAddr;
+
+/// the configuration a node is effectively running with, once the command
+/// line, the configuration file and block0 are combined. It is served without
+/// authentication: nothing telling whether the node is a leader or where it
+/// keeps its files goes in there.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigurationSummary {
+    /// full version of the node
+    pub version: String,
+    #[serde(with = "ConsensusVersionDef")]
+    pub consensus: ConsensusVersion,
+    pub listen_address: Option<SocketAddr>,
+    pub public_address: Option<SocketAddr>,
+    pub rest_listen_address: Option<SocketAddr>,
+    pub trusted_peers: usize,
+    pub max_connections: usize,
+    pub mempool: MempoolLimits,
+    pub storage: StorageMode,
+    pub subsystems: Subsystems,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MempoolLimits {
+    pub pool_max_entries: usize,
+    pub log_max_entries: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", tag = "mode")]
+pub enum StorageMode {
+    /// the blocks are lost when the node stops
+    InMemory,
+    Persistent,
+}
+
+/// the optional parts of the node, enabled or not
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Subsystems {
+    pub explorer: bool,
+    pub prometheus: bool,
+    pub rewards_export: bool,
+    pub storage_backup: bool,
+    pub fragment_persistent_log: bool,
+    pub mempool_snapshot: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn storage_mode_only_tells_the_mode() {
+        assert_eq!(
+            serde_json::to_string(&StorageMode::Persistent).unwrap(),
+            r#"{"mode":"persistent"}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&StorageMode::InMemory).unwrap(),
+            r#"{"mode":"inMemory"}"#
+        );
+    }
+}
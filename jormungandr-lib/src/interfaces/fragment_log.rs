@@ -1,6 +1,11 @@
-use crate::{crypto::hash::Hash, interfaces::BlockDate, time::SystemTime};
+use crate::{
+    crypto::hash::Hash,
+    interfaces::{AccountIdentifier, BlockDate},
+    time::SystemTime,
+};
 use chain_impl_mockchain::key;
 use serde::{Deserialize, Serialize};
+use std::net::SocketAddr;
 
 /// identify the source of a fragment
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
@@ -125,3 +130,49 @@ impl FragmentLog {
         &self.status
     }
 }
+
+/// a fragment that was dropped from the mempool because it was not included
+/// in a block before its `valid_until` date
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExpiredFragment {
+    pub fragment_id: Hash,
+    pub valid_until: BlockDate,
+    pub expired_at: BlockDate,
+}
+
+/// a fragment spending an UTxO or an account spending counter which is already
+/// spent by another fragment known to the node (pending or already in a block)
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FragmentConflict {
+    pub fragment_id: Hash,
+    pub conflicts_with: Hash,
+    /// what both fragments spend
+    pub spent: ConflictingSpend,
+    pub received_from: FragmentOrigin,
+    /// the peer the fragment was received from, if received from the network
+    pub peer: Option<SocketAddr>,
+    pub conflicts_with_received_from: FragmentOrigin,
+    /// the peer the fragment which spent first was received from, if received
+    /// from the network
+    pub conflicts_with_peer: Option<SocketAddr>,
+    pub detected_at: SystemTime,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ConflictingSpend {
+    #[serde(rename_all = "camelCase")]
+    Utxo {
+        /// transaction id of the UTxO
+        transaction_id: Hash,
+        /// output index of the UTxO
+        output_index: u8,
+    },
+    #[serde(rename_all = "camelCase")]
+    Account {
+        account: AccountIdentifier,
+        spending_counter: u32,
+    },
+}
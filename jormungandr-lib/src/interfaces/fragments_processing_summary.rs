@@ -12,8 +12,14 @@ pub enum FragmentRejectionReason {
     FragmentAlreadyInLog,
     FragmentInvalid,
     PreviousFragmentInvalid,
-    PoolOverflow { pool_number: usize },
+    PoolOverflow {
+        pool_number: usize,
+    },
     FragmentExpired,
+    /// the fragment is valid further in the future than allowed by the mempool policy
+    FragmentExpiryTooFar,
+    /// the fragment expires sooner than allowed by the mempool policy
+    FragmentExpiresTooSoon,
 }
 
 /// Information about a fragment rejected by the mempool. This is different from being rejected by
@@ -44,6 +50,8 @@ impl FragmentRejectionReason {
             FragmentRejectionReason::FragmentInvalid
                 | FragmentRejectionReason::PreviousFragmentInvalid
                 | FragmentRejectionReason::PoolOverflow { .. }
+                | FragmentRejectionReason::FragmentExpiryTooFar
+                | FragmentRejectionReason::FragmentExpiresTooSoon
         )
     }
 }
@@ -53,6 +61,31 @@ impl FragmentsProcessingSummary {
     pub fn is_error(&self) -> bool {
         self.rejected.iter().any(|info| info.reason.is_error())
     }
+
+    /// Number of fragments rejected because they were already in the fragment logs.
+    pub fn already_known(&self) -> usize {
+        self.rejected
+            .iter()
+            .filter(|info| info.reason == FragmentRejectionReason::FragmentAlreadyInLog)
+            .count()
+    }
+
+    /// Number of rejections because a fragment pool was full. A fragment refused
+    /// by several pools is counted once per pool.
+    pub fn pool_overflows(&self) -> usize {
+        self.rejected
+            .iter()
+            .filter(|info| matches!(info.reason, FragmentRejectionReason::PoolOverflow { .. }))
+            .count()
+    }
+
+    /// Whether all the fragments were rejected because they were already in the
+    /// fragment logs, i.e. the submission was a duplicate of a previous one.
+    pub fn is_already_known(&self) -> bool {
+        self.accepted.is_empty()
+            && !self.rejected.is_empty()
+            && self.already_known() == self.rejected.len()
+    }
 }
 
 #[cfg(test)]
@@ -102,4 +135,27 @@ mod tests {
         let deserialized_summary = serde_json::from_str(&json).unwrap();
         summary == deserialized_summary
     }
+
+    #[quickcheck]
+    fn summaries_rejecting_only_logged_fragments_are_already_known(
+        summary: FragmentsProcessingSummary,
+    ) -> bool {
+        let expected = summary.accepted.is_empty()
+            && !summary.rejected.is_empty()
+            && summary
+                .rejected
+                .iter()
+                .all(|info| info.reason == FragmentRejectionReason::FragmentAlreadyInLog);
+        summary.is_already_known() == expected
+    }
+
+    #[test]
+    fn empty_summaries_are_not_already_known() {
+        let summary = FragmentsProcessingSummary {
+            accepted: Vec::new(),
+            rejected: Vec::new(),
+        };
+        assert_eq!(summary.already_known(), 0);
+        assert!(!summary.is_already_known());
+    }
 }
@@ -1,11 +1,14 @@
 mod account_identifier;
 mod account_state;
+mod accounts_snapshot;
 mod address;
 mod block0_configuration;
 mod blockdate;
+mod bootstrap_status;
 mod certificate;
 mod committee;
 mod config;
+mod configuration_summary;
 mod fragment;
 mod fragment_log;
 mod fragment_log_persistent;
@@ -14,10 +17,12 @@ mod fragments_processing_summary;
 mod leadership_log;
 mod linear_fee;
 mod old_address;
+mod peer_event;
 mod peer_stats;
 mod ratio;
 mod reward_parameters;
 mod rewards_info;
+mod rewards_projection;
 mod settings;
 mod stake;
 mod stake_distribution;
@@ -32,18 +37,28 @@ mod value;
 mod vote;
 
 pub use self::account_identifier::AccountIdentifier;
-pub use self::account_state::AccountState;
+pub use self::account_state::{AccountEvent, AccountState, AccountStateAt};
+pub use self::accounts_snapshot::{
+    AccountsMerkleTree, AccountsSnapshot, BalanceProof, BalanceProofError,
+};
 pub use self::address::Address;
 pub use self::block0_configuration::*;
 pub use self::blockdate::BlockDate;
+pub use self::bootstrap_status::BootstrapStatus;
 pub use self::certificate::{
     Certificate, CertificateFromBech32Error, CertificateFromStrError, CertificateToBech32Error,
     SignedCertificate, CERTIFICATE_HRP, SIGNED_CERTIFICATE_HRP,
 };
 pub use self::committee::CommitteeIdDef;
 pub use self::config::*;
+pub use self::configuration_summary::{
+    ConfigurationSummary, MempoolLimits, StorageMode, Subsystems,
+};
 pub use self::fragment::FragmentDef;
-pub use self::fragment_log::{FragmentLog, FragmentOrigin, FragmentStatus};
+pub use self::fragment_log::{
+    ConflictingSpend, ExpiredFragment, FragmentConflict, FragmentLog, FragmentOrigin,
+    FragmentStatus,
+};
 pub use self::fragment_log_persistent::{
     load_persistent_fragments_logs_from_folder_path, read_persistent_fragment_logs_from_file_path,
     DeserializeError as FragmentLogDeserializeError, FileFragments, PersistentFragmentLog,
@@ -57,14 +72,20 @@ pub use self::leadership_log::{
 };
 pub use self::linear_fee::LinearFeeDef;
 pub use self::old_address::OldAddress;
+pub use self::peer_event::{ConnectionDirection, PeerEvent, PeerEventKind};
 pub use self::peer_stats::{PeerRecord, PeerStats, Subscription};
 pub use self::ratio::{ParseRatioError, Ratio};
 pub use self::reward_parameters::RewardParams;
 pub use self::rewards_info::EpochRewardsInfo;
-pub use self::settings::{ParametersDef, RatioDef, SettingsDto, TaxTypeDef, TaxTypeSerde};
+pub use self::rewards_projection::{
+    epoch_rewards, tax_cut, PoolRewardsProjection, RewardsProjection,
+};
+pub use self::settings::{
+    ParametersDef, RatioDef, SettingsDto, SlotStart, TaxTypeDef, TaxTypeSerde,
+};
 pub use self::stake::{Stake, StakeDef};
 pub use self::stake_distribution::{StakeDistribution, StakeDistributionDto};
-pub use self::stake_pool_stats::{Rewards, StakePoolStats};
+pub use self::stake_pool_stats::{Rewards, StakePoolBlock, StakePoolDashboard, StakePoolStats};
 pub use self::stats::{NodeState, NodeStats, NodeStatsDto};
 pub use self::tax_type::TaxType;
 pub use self::transaction_input::{TransactionInput, TransactionInputType};
@@ -0,0 +1,46 @@
+use crate::time::SystemTime;
+use serde::{Deserialize, Serialize};
+use std::net::SocketAddr;
+
+/// an event in the lifecycle of a connection with a peer
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PeerEvent {
+    pub time: SystemTime,
+    pub address: SocketAddr,
+    /// the identifier of the node in the topology, when it is known
+    pub node_id: Option<String>,
+    #[serde(flatten)]
+    pub kind: PeerEventKind,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ConnectionDirection {
+    /// the peer connected to this node
+    Inbound,
+    /// this node connected to the peer
+    Outbound,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "camelCase")]
+pub enum PeerEventKind {
+    Connected {
+        direction: ConnectionDirection,
+    },
+    /// the connection could not be established
+    ConnectionFailed {
+        reason: String,
+    },
+    HandshakeFailed {
+        direction: ConnectionDirection,
+        reason: String,
+    },
+    /// the peer was reported too many times and is not contacted until the
+    /// quarantine is lifted
+    Quarantined,
+    Disconnected {
+        reason: String,
+    },
+}
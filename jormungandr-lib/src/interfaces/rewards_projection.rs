@@ -0,0 +1,155 @@
+//! Projection of the rewards distributed at the end of the ongoing epoch.
+//!
+//! The stake pools are rewarded in proportion to the blocks they produce; the
+//! projection expects each of them to produce its share of the blocks
+//! according to its stake. The fees collected during the epoch and the
+//! participation capping of the pools are not accounted for.
+
+use chain_impl_mockchain::{
+    block::Epoch,
+    rewards::{CompoundingType, Limit, Parameters, Ratio, TaxType},
+};
+use serde::{Deserialize, Serialize};
+use std::cmp::min;
+
+/// Rewards expected at the end of an epoch, given the stake distribution and
+/// the reward settings of the epoch
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RewardsProjection {
+    pub epoch: u32,
+    /// value left in the rewards pot
+    pub remaining_rewards: u64,
+    /// value the epoch draws from the rewards pot
+    pub drawn: u64,
+    /// part of the drawn value going to the treasury
+    pub treasury: u64,
+    /// part of the drawn value shared between the stake pools
+    pub pools_total: u64,
+    /// stake delegated to the stake pools
+    pub active_stake: u64,
+    /// ordered by expected rewards of the delegators per stake, highest first
+    pub pools: Vec<PoolRewardsProjection>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PoolRewardsProjection {
+    /// hex-encoded identifier of the stake pool
+    pub pool_id: String,
+    pub stake: u64,
+    /// blocks produced by the pool in the epoch so far
+    pub blocks_produced: u32,
+    /// rewards of the pool, before its tax
+    pub total: u64,
+    /// part of the rewards kept by the operators of the pool
+    pub operators: u64,
+    /// part of the rewards shared between the delegators
+    pub delegators: u64,
+    /// rewards of the delegators for each million of stake delegated
+    pub delegators_per_million: u64,
+}
+
+/// Value an epoch draws from the rewards pot, before it is capped by the
+/// value left in the pot. `declared_stake` is the stake delegated to the
+/// stake pools.
+pub fn epoch_rewards(epoch: Epoch, params: &Parameters, declared_stake: u64) -> u64 {
+    if epoch < params.epoch_start {
+        return 0;
+    }
+    let zone = u64::from((epoch - params.epoch_start) / params.epoch_rate.get());
+    let ratio = &params.compounding_ratio;
+    let drawn = match params.compounding_type {
+        CompoundingType::Linear => {
+            let reduce_by = (ratio.numerator * zone) / ratio.denominator.get();
+            params.initial_value.saturating_sub(reduce_by)
+        }
+        CompoundingType::Halvening => {
+            (0..zone).fold(params.initial_value, |acc, _| apply_ratio(acc, ratio))
+        }
+    };
+    match &params.reward_drawing_limit_max {
+        Limit::None => drawn,
+        Limit::ByStakeAbsolute(ratio) => min(drawn, apply_ratio(declared_stake, ratio)),
+    }
+}
+
+/// Splits `value` between the tax and what is left after it, in this order
+pub fn tax_cut(value: u64, tax: &TaxType) -> (u64, u64) {
+    let fixed = tax.fixed.0;
+    if value <= fixed {
+        return (value, 0);
+    }
+    let left = value - fixed;
+    let proportional = apply_ratio(left, &tax.ratio);
+    let proportional = match tax.max_limit {
+        Some(limit) => min(limit.get(), proportional),
+        None => proportional,
+    };
+    (fixed + proportional, left - proportional)
+}
+
+fn apply_ratio(value: u64, ratio: &Ratio) -> u64 {
+    (u128::from(value) * u128::from(ratio.numerator) / u128::from(ratio.denominator.get())) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chain_impl_mockchain::value::Value;
+    use std::num::{NonZeroU32, NonZeroU64};
+
+    fn params(compounding_type: CompoundingType, limit: Limit) -> Parameters {
+        Parameters {
+            initial_value: 1000,
+            compounding_ratio: Ratio {
+                numerator: 1,
+                denominator: NonZeroU64::new(2).unwrap(),
+            },
+            compounding_type,
+            epoch_rate: NonZeroU32::new(2).unwrap(),
+            epoch_start: 1,
+            reward_drawing_limit_max: limit,
+            pool_participation_capping: None,
+        }
+    }
+
+    #[test]
+    fn rewards_decrease_every_epoch_rate() {
+        let halving = params(CompoundingType::Halvening, Limit::None);
+        assert_eq!(epoch_rewards(0, &halving, 0), 0);
+        assert_eq!(epoch_rewards(2, &halving, 0), 1000);
+        assert_eq!(epoch_rewards(3, &halving, 0), 500);
+        assert_eq!(epoch_rewards(5, &halving, 0), 250);
+
+        let linear = params(CompoundingType::Linear, Limit::None);
+        assert_eq!(epoch_rewards(5, &linear, 0), 999);
+    }
+
+    #[test]
+    fn rewards_are_limited_by_the_stake() {
+        let limit = Limit::ByStakeAbsolute(Ratio {
+            numerator: 1,
+            denominator: NonZeroU64::new(100).unwrap(),
+        });
+        let params = params(CompoundingType::Halvening, limit);
+        assert_eq!(epoch_rewards(1, &params, 20_000), 200);
+        assert_eq!(epoch_rewards(1, &params, 1_000_000), 1000);
+    }
+
+    #[test]
+    fn tax_is_fixed_then_proportional_up_to_the_limit() {
+        let mut tax = TaxType {
+            fixed: Value(10),
+            ratio: Ratio {
+                numerator: 1,
+                denominator: NonZeroU64::new(10).unwrap(),
+            },
+            max_limit: None,
+        };
+        assert_eq!(tax_cut(5, &tax), (5, 0));
+        assert_eq!(tax_cut(110, &tax), (20, 90));
+        tax.max_limit = NonZeroU64::new(5);
+        assert_eq!(tax_cut(110, &tax), (15, 95));
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{
-    interfaces::{LinearFeeDef, ValueDef},
+    interfaces::{BlockDate, LinearFeeDef, ValueDef},
     time::SystemTime,
 };
 use chain_addr::Discrimination;
@@ -32,6 +32,19 @@ pub struct SettingsDto {
     pub tx_max_expiry_epochs: u8,
 }
 
+/// The start of a slot, with the parameters of the era it belongs to
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct SlotStart {
+    pub date: BlockDate,
+    pub time: SystemTime,
+    /// whether the slot is the first one of its epoch
+    pub epoch_start: bool,
+    /// in seconds
+    pub slot_duration: u64,
+    pub slots_per_epoch: u32,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(deny_unknown_fields, remote = "TaxType")]
 pub struct TaxTypeDef {
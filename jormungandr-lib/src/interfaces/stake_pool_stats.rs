@@ -1,4 +1,5 @@
-use crate::interfaces::{TaxTypeSerde, ValueDef};
+use crate::crypto::hash::Hash;
+use crate::interfaces::{BlockDate, TaxTypeSerde, ValueDef};
 use chain_impl_mockchain::value::Value;
 use serde::{Deserialize, Serialize};
 
@@ -21,3 +22,35 @@ pub struct Rewards {
     #[serde(with = "ValueDef")]
     pub value_for_stakers: Value,
 }
+
+/// Operational overview of a stake pool, as seen from the tip of the node
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct StakePoolDashboard {
+    /// epoch of the current tip
+    pub epoch: u32,
+    pub total_stake: u64,
+    /// number of accounts delegating (partially or not) to the pool
+    pub delegators: u64,
+    pub blocks_current_epoch: u32,
+    /// `None` if the node does not know about the previous epoch (e.g. epoch 0)
+    pub blocks_previous_epoch: Option<u32>,
+    /// remaining slots of the current epoch the pool is leader of. Only known
+    /// if the pool is run by this node, `None` otherwise.
+    pub scheduled_slots_remaining: Option<u64>,
+    /// a retirement certificate for this pool is waiting in the mempool
+    pub pending_retirement: bool,
+    /// balance of the reward account, `None` if the pool has no single
+    /// reward account
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reward_account_balance: Option<u64>,
+}
+
+/// Block of the main branch produced by a stake pool
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct StakePoolBlock {
+    pub hash: Hash,
+    pub date: BlockDate,
+    pub chain_length: u32,
+}
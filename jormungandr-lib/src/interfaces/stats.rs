@@ -1,7 +1,7 @@
 use crate::time::SystemTime;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct NodeStatsDto {
     pub version: String,
@@ -10,7 +10,7 @@ pub struct NodeStatsDto {
     pub stats: Option<NodeStats>,
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct NodeStats {
     pub block_recv_cnt: u64,
@@ -28,6 +28,60 @@ pub struct NodeStats {
     pub peer_quarantined_cnt: usize,
     pub peer_total_cnt: usize,
     pub tx_recv_cnt: u64,
+    /// number of fragments removed from the mempool because their
+    /// `valid_until` date has passed
+    #[serde(default)]
+    pub tx_expired_cnt: u64,
+    /// number of received fragments spending an UTxO already spent by another
+    /// fragment known to the node
+    #[serde(default)]
+    pub tx_conflict_cnt: u64,
+    /// number of fragments received through the REST API while already in
+    /// the fragment logs
+    #[serde(default)]
+    pub tx_duplicate_rest_cnt: u64,
+    /// number of fragments received from the network while already in the
+    /// fragment logs
+    #[serde(default)]
+    pub tx_duplicate_network_cnt: u64,
+    /// number of fragment logs evicted, either because the logs were full or
+    /// by the garbage collection policy
+    #[serde(default)]
+    pub fragment_log_evicted_cnt: u64,
+    /// number of fragments in the fullest fragment pool
+    #[serde(default)]
+    pub pool_entry_cnt: usize,
+    /// sum of the sizes of the fragments in the fullest fragment pool, in bytes
+    #[serde(default)]
+    pub pool_size_bytes: u64,
+    /// highest number of fragments in a fragment pool since the node started
+    #[serde(default)]
+    pub pool_entry_max_cnt: usize,
+    /// number of fragments refused because the fragment pool was full
+    #[serde(default)]
+    pub pool_full_evicted_cnt: u64,
+    /// number of fragments dropped from the fragment pool because the ledger
+    /// rejected them when building a block, typically because another
+    /// fragment spent the same funds first
+    #[serde(default)]
+    pub pool_rejected_evicted_cnt: u64,
+    /// number of requests handled by the REST API
+    #[serde(default)]
+    pub rest_request_cnt: u64,
+    /// number of REST API requests answered with an error status
+    #[serde(default)]
+    pub rest_error_cnt: u64,
+    /// stake delegated to the stake pools in the epoch of the tip
+    #[serde(default)]
+    pub active_stake: u64,
+    /// number of stake pools registered in the ledger of the tip, refreshed on
+    /// epoch transitions
+    #[serde(default)]
+    pub stake_pool_cnt: usize,
+    /// share of the stake delegated to a stake pool in the epoch of the tip,
+    /// between 0 and 1
+    #[serde(default)]
+    pub delegation_participation: Option<f64>,
     pub uptime: Option<u64>,
 }
 
@@ -2,6 +2,8 @@
 #[macro_use(quickcheck)]
 extern crate quickcheck;
 
+#[cfg(feature = "client")]
+pub mod client;
 pub mod crypto;
 pub mod interfaces;
 pub mod multiaddr;
@@ -16,4 +16,16 @@ fn main() {
     println!("cargo:rustc-env=FULL_VERSION={}", version.full());
     println!("cargo:rustc-env=SIMPLE_VERSION={}", version.simple());
     println!("cargo:rustc-env=SOURCE_VERSION={}", version.hash());
+
+    tonic_build::compile_protos("proto/local.proto").unwrap();
+
+    // the node service is generated in chain-network, only its descriptors
+    // are needed for the gRPC reflection
+    let out_dir = std::path::PathBuf::from(std::env::var("OUT_DIR").unwrap());
+    tonic_build::configure()
+        .build_client(false)
+        .build_server(false)
+        .file_descriptor_set_path(out_dir.join("node_descriptor.bin"))
+        .compile(&["proto/node.proto"], &["proto"])
+        .unwrap();
 }
@@ -0,0 +1,376 @@
+use super::Ref;
+use crate::blockcfg::{BlockDate, HeaderHash};
+use chain_impl_mockchain::{account::Identifier, value::Value};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+};
+use thiserror::Error;
+use tokio::sync::{broadcast::error::RecvError, mpsc, watch};
+
+/// maximum number of simultaneous subscriptions to account events
+const MAX_SUBSCRIPTIONS: usize = 256;
+/// events buffered for each subscriber before it is considered lagging
+const SUBSCRIPTION_BUFFER_SIZE: usize = 64;
+
+/// The balance or the spending counter of a watched account changed when the
+/// main branch moved to `block`
+#[derive(Debug, Clone)]
+pub struct AccountEvent {
+    pub account: Identifier,
+    pub value: Value,
+    pub counter: u32,
+    pub block: HeaderHash,
+    pub date: BlockDate,
+}
+
+#[derive(Debug, Error)]
+pub enum SubscriptionError {
+    #[error("too many subscriptions to account events, at most {0} are served")]
+    TooManySubscriptions(usize),
+}
+
+struct Subscriber {
+    accounts: HashSet<Identifier>,
+    events: mpsc::Sender<AccountEvent>,
+    missed: Arc<AtomicU64>,
+}
+
+#[derive(Default)]
+struct Subscribers {
+    next_id: u64,
+    subscribers: HashMap<u64, Subscriber>,
+}
+
+impl Subscribers {
+    /// the accounts followed by at least one subscriber
+    fn accounts(&self) -> Vec<Identifier> {
+        self.subscribers
+            .values()
+            .flat_map(|subscriber| subscriber.accounts.iter())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .cloned()
+            .collect()
+    }
+
+    /// Hands each subscriber the events of its own accounts. A subscriber
+    /// whose buffer is full misses the events, it is told so on its next
+    /// receive.
+    fn dispatch(&self, events: &[AccountEvent]) {
+        for subscriber in self.subscribers.values() {
+            for event in events
+                .iter()
+                .filter(|event| subscriber.accounts.contains(&event.account))
+            {
+                if subscriber.events.try_send(event.clone()).is_err() {
+                    subscriber.missed.fetch_add(1, Ordering::Relaxed);
+                }
+            }
+        }
+    }
+}
+
+type TipChange = Option<(Arc<Ref>, Arc<Ref>)>;
+
+/// The subscriptions to the changes of the accounts. The block processing
+/// only hands over the new tip: the events are computed and filtered for
+/// each subscriber by a separate task, started with the first subscription.
+#[derive(Clone)]
+pub struct AccountEvents {
+    subscribers: Arc<Mutex<Subscribers>>,
+    tips: Arc<watch::Sender<TipChange>>,
+    worker: Arc<Mutex<Option<watch::Receiver<TipChange>>>>,
+}
+
+impl Default for AccountEvents {
+    fn default() -> Self {
+        let (tips, worker) = watch::channel(None);
+        AccountEvents {
+            subscribers: Default::default(),
+            tips: Arc::new(tips),
+            worker: Arc::new(Mutex::new(Some(worker))),
+        }
+    }
+}
+
+impl AccountEvents {
+    /// Follows the given accounts. Must be called within the tokio runtime.
+    pub fn subscribe(
+        &self,
+        accounts: HashSet<Identifier>,
+    ) -> Result<AccountEventsSubscription, SubscriptionError> {
+        let (sender, events) = mpsc::channel(SUBSCRIPTION_BUFFER_SIZE);
+        let missed = Arc::new(AtomicU64::new(0));
+        let id = {
+            let mut subscribers = self.subscribers.lock().unwrap();
+            if subscribers.subscribers.len() >= MAX_SUBSCRIPTIONS {
+                return Err(SubscriptionError::TooManySubscriptions(MAX_SUBSCRIPTIONS));
+            }
+            let id = subscribers.next_id;
+            subscribers.next_id += 1;
+            subscribers.subscribers.insert(
+                id,
+                Subscriber {
+                    accounts,
+                    events: sender,
+                    missed: Arc::clone(&missed),
+                },
+            );
+            id
+        };
+
+        if let Some(tips) = self.worker.lock().unwrap().take() {
+            tokio::spawn(run_worker(Arc::clone(&self.subscribers), tips));
+        }
+
+        Ok(AccountEventsSubscription {
+            id,
+            subscribers: Arc::clone(&self.subscribers),
+            events,
+            missed,
+        })
+    }
+
+    /// Called by the block processing when the main branch moves from
+    /// `previous_tip` to `new_tip`, does not wait for the events.
+    pub(super) fn tip_changed(&self, previous_tip: &Arc<Ref>, new_tip: &Arc<Ref>) {
+        // the worker, or the receiver waiting for it, is never dropped
+        let _ = self
+            .tips
+            .send(Some((Arc::clone(previous_tip), Arc::clone(new_tip))));
+    }
+}
+
+async fn run_worker(subscribers: Arc<Mutex<Subscribers>>, mut tips: watch::Receiver<TipChange>) {
+    // the tips changed while the events were computed are coalesced: the
+    // events are computed from the last state reported to the subscribers
+    let mut reported: Option<Arc<Ref>> = None;
+    while tips.changed().await.is_ok() {
+        let (previous_tip, new_tip) = match &*tips.borrow() {
+            Some((previous_tip, new_tip)) => (Arc::clone(previous_tip), Arc::clone(new_tip)),
+            None => continue,
+        };
+        let previous_tip = reported.take().unwrap_or(previous_tip);
+        let accounts = subscribers.lock().unwrap().accounts();
+        if !accounts.is_empty() {
+            let events = account_events(&accounts, &previous_tip, &new_tip);
+            subscribers.lock().unwrap().dispatch(&events);
+        }
+        reported = Some(new_tip);
+    }
+}
+
+/// Receives the events of a set of accounts, until dropped.
+pub struct AccountEventsSubscription {
+    id: u64,
+    subscribers: Arc<Mutex<Subscribers>>,
+    events: mpsc::Receiver<AccountEvent>,
+    missed: Arc<AtomicU64>,
+}
+
+impl AccountEventsSubscription {
+    /// Waits for the next event of one of the subscribed accounts. On
+    /// `RecvError::Lagged` some events were missed, the subscriber should get
+    /// the current state of its accounts before receiving further events.
+    pub async fn recv(&mut self) -> Result<AccountEvent, RecvError> {
+        let missed = self.missed.swap(0, Ordering::Relaxed);
+        if missed > 0 {
+            return Err(RecvError::Lagged(missed));
+        }
+        self.events.recv().await.ok_or(RecvError::Closed)
+    }
+}
+
+impl Drop for AccountEventsSubscription {
+    fn drop(&mut self) {
+        self.subscribers
+            .lock()
+            .unwrap()
+            .subscribers
+            .remove(&self.id);
+    }
+}
+
+/// Lists the given accounts whose balance or spending counter differ between
+/// two successive tips of the main branch. `previous_tip` may be more than one
+/// block behind `new_tip`, e.g. after a branch switch, in which case only the
+/// resulting state is reported, at the date of the new tip.
+fn account_events(accounts: &[Identifier], previous_tip: &Ref, new_tip: &Ref) -> Vec<AccountEvent> {
+    let previous_ledger = previous_tip.ledger();
+    let new_ledger = new_tip.ledger();
+    let state = |ledger: &crate::blockcfg::Ledger, account: &Identifier| {
+        ledger
+            .accounts()
+            .get_state(account)
+            .ok()
+            .map(|state| (state.value(), state.get_counter()))
+    };
+
+    account_changes(
+        accounts,
+        |account| state(&previous_ledger, account),
+        |account| state(&new_ledger, account),
+    )
+    .into_iter()
+    .map(|(account, value, counter)| AccountEvent {
+        account,
+        value,
+        counter,
+        block: new_tip.hash(),
+        date: new_tip.block_date(),
+    })
+    .collect()
+}
+
+/// The accounts whose state, the balance and the spending counter, differs
+/// between two ledgers, with their new state. Removed accounts are not listed.
+fn account_changes<P, N>(
+    accounts: &[Identifier],
+    previous_state: P,
+    new_state: N,
+) -> Vec<(Identifier, Value, u32)>
+where
+    P: Fn(&Identifier) -> Option<(Value, u32)>,
+    N: Fn(&Identifier) -> Option<(Value, u32)>,
+{
+    accounts
+        .iter()
+        .filter_map(|account| {
+            let state = new_state(account)?;
+            if previous_state(account) == Some(state) {
+                return None;
+            }
+            Some((account.clone(), state.0, state.1))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chain_crypto::{Ed25519, SecretKey};
+    use futures::FutureExt;
+
+    fn account() -> Identifier {
+        SecretKey::<Ed25519>::generate(rand::rngs::OsRng)
+            .to_public()
+            .into()
+    }
+
+    fn event(account: &Identifier, value: u64) -> AccountEvent {
+        AccountEvent {
+            account: account.clone(),
+            value: Value(value),
+            counter: 0,
+            block: HeaderHash::hash_bytes(&value.to_be_bytes()),
+            date: BlockDate::first(),
+        }
+    }
+
+    #[test]
+    fn changed_balances_and_counters_are_reported() {
+        let (unchanged, paid, spent, created, removed) =
+            (account(), account(), account(), account(), account());
+        let previous: HashMap<_, _> = vec![
+            (unchanged.clone(), (Value(10), 0)),
+            (paid.clone(), (Value(10), 0)),
+            (spent.clone(), (Value(10), 0)),
+            (removed.clone(), (Value(10), 0)),
+        ]
+        .into_iter()
+        .collect();
+        let mut new = previous.clone();
+        new.insert(paid.clone(), (Value(20), 0));
+        new.insert(spent.clone(), (Value(10), 1));
+        new.insert(created.clone(), (Value(5), 0));
+        new.remove(&removed);
+
+        let accounts = vec![
+            unchanged,
+            paid.clone(),
+            spent.clone(),
+            created.clone(),
+            removed,
+        ];
+        let changes = account_changes(
+            &accounts,
+            |account| previous.get(account).cloned(),
+            |account| new.get(account).cloned(),
+        );
+        assert_eq!(
+            changes,
+            vec![
+                (paid, Value(20), 0),
+                (spent, Value(10), 1),
+                (created, Value(5), 0)
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn subscribers_only_receive_the_events_of_their_accounts() {
+        let hub = AccountEvents::default();
+        let (first, second) = (account(), account());
+        let mut first_subscription = hub
+            .subscribe(vec![first.clone()].into_iter().collect())
+            .unwrap();
+        let mut both_subscription = hub
+            .subscribe(vec![first.clone(), second.clone()].into_iter().collect())
+            .unwrap();
+
+        let watched = hub.subscribers.lock().unwrap().accounts();
+        assert_eq!(watched.len(), 2);
+
+        hub.subscribers
+            .lock()
+            .unwrap()
+            .dispatch(&[event(&second, 1), event(&first, 2)]);
+
+        assert_eq!(first_subscription.recv().await.unwrap().value, Value(2));
+        assert_eq!(both_subscription.recv().await.unwrap().value, Value(1));
+        assert_eq!(both_subscription.recv().await.unwrap().value, Value(2));
+        assert!(first_subscription.recv().now_or_never().is_none());
+
+        drop(both_subscription);
+        assert_eq!(hub.subscribers.lock().unwrap().accounts(), vec![first]);
+    }
+
+    #[tokio::test]
+    async fn lagging_subscribers_are_told_how_many_events_they_missed() {
+        let hub = AccountEvents::default();
+        let account = account();
+        let mut subscription = hub
+            .subscribe(vec![account.clone()].into_iter().collect())
+            .unwrap();
+
+        let events: Vec<_> = (0..SUBSCRIPTION_BUFFER_SIZE as u64 + 3)
+            .map(|value| event(&account, value))
+            .collect();
+        hub.subscribers.lock().unwrap().dispatch(&events);
+
+        assert!(matches!(
+            subscription.recv().await,
+            Err(RecvError::Lagged(3))
+        ));
+        assert_eq!(subscription.recv().await.unwrap().value, Value(0));
+    }
+
+    #[tokio::test]
+    async fn subscriptions_are_capped() {
+        let hub = AccountEvents::default();
+        let mut subscriptions: Vec<_> = (0..MAX_SUBSCRIPTIONS)
+            .map(|_| hub.subscribe(HashSet::new()).unwrap())
+            .collect();
+        assert!(matches!(
+            hub.subscribe(HashSet::new()),
+            Err(SubscriptionError::TooManySubscriptions(MAX_SUBSCRIPTIONS))
+        ));
+
+        subscriptions.pop();
+        assert!(hub.subscribe(HashSet::new()).is_ok());
+    }
+}
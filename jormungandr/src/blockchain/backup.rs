@@ -0,0 +1,191 @@
+//! Periodic backups of the block storage, taken while the node is running.
+//!
+//! The storage files are written to in the background, so they are not copied
+//! as they are: the blocks are copied into a new storage instead, which can be
+//! used in place of the storage directory of a node.
+
+use super::storage::{self, Storage};
+use crate::settings::start::config::{StorageBackup, StorageBackupMode};
+use thiserror::Error;
+
+use std::fs::{self, File};
+use std::io;
+use std::path::{Path, PathBuf};
+
+const BACKUP_PREFIX: &str = "storage-";
+const TMP_EXTENSION: &str = "tmp";
+const TAR_EXTENSION: &str = "tar";
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("cannot write the storage backup")]
+    Io(#[from] io::Error),
+    #[error("cannot copy the storage")]
+    Storage(#[from] storage::Error),
+    #[error("the storage backup task has panicked")]
+    Join(#[from] tokio::task::JoinError),
+}
+
+/// Backs up the storage every `config.interval`, the first backup is taken
+/// one interval after the start of the node.
+pub async fn run(storage: Storage, config: StorageBackup) {
+    loop {
+        tokio::time::sleep(config.interval.into()).await;
+        let (storage, config) = (storage.clone(), config.clone());
+        let result = tokio::task::spawn_blocking(move || backup(&storage, &config))
+            .await
+            .map_err(Error::from)
+            .and_then(|result| result);
+        match result {
+            Ok(path) => tracing::info!(path = %path.display(), "storage backed up"),
+            Err(err) => tracing::error!(reason = %err, "storage backup failed"),
+        }
+    }
+}
+
+fn backup(storage: &Storage, config: &StorageBackup) -> Result<PathBuf, Error> {
+    fs::create_dir_all(&config.dir)?;
+    let name = format!(
+        "{}{}",
+        BACKUP_PREFIX,
+        chrono::Utc::now().format("%Y%m%dT%H%M%SZ")
+    );
+    let copy_path = config.dir.join(&name).with_extension(TMP_EXTENSION);
+    if copy_path.exists() {
+        // left over by an interrupted backup
+        fs::remove_dir_all(&copy_path)?;
+    }
+    storage.copy_to(&copy_path)?;
+
+    let path = match config.mode {
+        StorageBackupMode::Directory => {
+            let path = config.dir.join(&name);
+            fs::rename(&copy_path, &path)?;
+            path
+        }
+        StorageBackupMode::Tar => {
+            let path = config.dir.join(format!("{}.{}", name, TAR_EXTENSION));
+            let tmp_path = path.with_extension(format!("{}.{}", TAR_EXTENSION, TMP_EXTENSION));
+            let mut archive = tar::Builder::new(File::create(&tmp_path)?);
+            archive.append_dir_all(".", &copy_path)?;
+            archive.into_inner()?.sync_all()?;
+            fs::remove_dir_all(&copy_path)?;
+            fs::rename(&tmp_path, &path)?;
+            path
+        }
+    };
+
+    let mut backups = backups(&config.dir)?;
+    let outdated = backups.len().saturating_sub(config.retain);
+    for outdated in backups.drain(..outdated) {
+        if outdated.is_dir() {
+            fs::remove_dir_all(&outdated)?;
+        } else {
+            fs::remove_file(&outdated)?;
+        }
+    }
+
+    Ok(path)
+}
+
+/// The complete backups found in the directory, the oldest first.
+fn backups(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut backups = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let is_backup = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .map_or(false, |name| name.starts_with(BACKUP_PREFIX));
+        let is_complete = path
+            .extension()
+            .map_or(true, |extension| extension != TMP_EXTENSION);
+        if is_backup && is_complete {
+            backups.push(path);
+        }
+    }
+    // the names only differ by the date, in a format which sorts chronologically
+    backups.sort();
+    Ok(backups)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blockchain::{storage::tests::block, MAIN_BRANCH_TAG};
+    use tracing::Span;
+
+    fn config(dir: &Path, mode: StorageBackupMode) -> StorageBackup {
+        StorageBackup {
+            dir: dir.to_path_buf(),
+            interval: std::time::Duration::from_secs(3600).into(),
+            retain: 3,
+            mode,
+        }
+    }
+
+    #[test]
+    fn backups_are_restored_as_storage() {
+        let storage = Storage::memory(Span::none()).unwrap();
+        let block0 = block(None, 0);
+        let head = block(Some(&block0), 1);
+        storage.put_block(&block0).unwrap();
+        storage.put_block(&head).unwrap();
+        storage
+            .put_tag(MAIN_BRANCH_TAG, head.header.hash())
+            .unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        let restored_dir = dir.path().join("restored");
+
+        let archive = backup(
+            &storage,
+            &config(&dir.path().join("tar"), StorageBackupMode::Tar),
+        )
+        .unwrap();
+        tar::Archive::new(File::open(&archive).unwrap())
+            .unpack(&restored_dir)
+            .unwrap();
+        let backup_dir = dir.path().join("directory");
+        let path = backup(&storage, &config(&backup_dir, StorageBackupMode::Directory)).unwrap();
+        assert_eq!(backups(&backup_dir).unwrap(), vec![path.clone()]);
+
+        for restored_dir in &[path, restored_dir] {
+            let restored = Storage::file(restored_dir, Span::none()).unwrap();
+            assert_eq!(
+                restored.get_tag(MAIN_BRANCH_TAG).unwrap(),
+                Some(head.header.hash())
+            );
+            assert!(restored.block_exists(block0.header.hash()).unwrap());
+            assert!(restored.block_exists(head.header.hash()).unwrap());
+        }
+    }
+
+    #[test]
+    fn only_the_last_backups_are_retained() {
+        let storage = Storage::memory(Span::none()).unwrap();
+        storage.put_block(&block(None, 0)).unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        let older: Vec<_> = (1..=3)
+            .map(|day| {
+                dir.path()
+                    .join(format!("{}2020010{}T000000Z", BACKUP_PREFIX, day))
+            })
+            .collect();
+        for path in &older {
+            fs::create_dir(path).unwrap();
+        }
+        let interrupted = older[0].with_extension(TMP_EXTENSION);
+        fs::create_dir(&interrupted).unwrap();
+        fs::write(dir.path().join("notes"), b"not a backup").unwrap();
+
+        let path = backup(&storage, &config(dir.path(), StorageBackupMode::Directory)).unwrap();
+        assert_eq!(
+            backups(dir.path()).unwrap(),
+            vec![older[1].clone(), older[2].clone(), path]
+        );
+        assert!(!older[0].exists());
+        assert!(interrupted.exists());
+    }
+}
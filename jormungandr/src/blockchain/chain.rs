@@ -49,18 +49,29 @@ See Internal documentation for more details: doc/internal_design.md
 [`Branch`]: ./struct.Branch.html
 */
 #![allow(clippy::large_enum_variant)]
-use super::{branch::Branches, reference_cache::RefCache};
+use super::{
+    account_events::{
+        AccountEvents, AccountEventsSubscription, SubscriptionError as AccountEventsError,
+    },
+    branch::Branches,
+    producers::ProducerIndex,
+    reference_cache::RefCache,
+    rewards_export,
+    vote_plan_events::VotePlanEvent,
+};
 use crate::{
     blockcfg::{
         Block, Block0Error, BlockDate, ChainLength, Epoch, EpochRewardsInfo, Header, HeaderHash,
         Leadership, Ledger, LedgerParameters, RewardsInfoParameters,
     },
     blockchain::{Branch, Checkpoints, Multiverse, Ref, Storage, StorageError},
+    settings::start::config::RewardsExport,
 };
 use chain_core::property::HasHeader;
-use chain_impl_mockchain::{leadership::Verification, ledger};
+use chain_impl_mockchain::{account::Identifier, leadership::Verification, ledger};
 use chain_time::TimeFrame;
-use std::sync::Arc;
+use std::{collections::HashSet, sync::Arc};
+use tokio::sync::broadcast;
 use tokio_stream::StreamExt;
 
 #[derive(Debug, thiserror::Error)]
@@ -125,6 +136,9 @@ pub type Result<T> = core::result::Result<T, Error>;
 
 pub const MAIN_BRANCH_TAG: &str = "HEAD";
 
+// Vote plan events are rare, slow subscribers only miss events older than this.
+const VOTE_PLAN_EVENTS_CHANNEL_SIZE: usize = 64;
+
 /// Performs lightweight sanity checks on information fields of a block header
 /// against those in the header of the block's parent.
 /// The `parent` header must have been retrieved based on, or otherwise
@@ -157,6 +171,21 @@ pub fn pre_verify_link(
     Ok(())
 }
 
+/// Checks the cryptographic proof of the header against the leadership schedule
+/// of its epoch.
+///
+/// Unlike the application of the block to the ledger, this does not depend on
+/// the state of the parent block: the headers of an epoch can be verified in
+/// parallel, ahead of the sequential application of their blocks.
+pub fn verify_header_proof(header: &Header, leadership: &Leadership) -> Result<()> {
+    match leadership.verify(header) {
+        Verification::Failure(error) => Err(Error::BlockHeaderVerificationFailed(
+            HeaderChainVerifyError::NewLeadershipScheduleFailed(error),
+        )),
+        Verification::Success => Ok(()),
+    }
+}
+
 /// blockchain object, can be safely shared across multiple threads. However it is better not
 /// to as some operations may require a mutex.
 ///
@@ -179,6 +208,14 @@ pub struct Blockchain {
     block0: HeaderHash,
 
     rewards_report_all: bool,
+
+    rewards_export: Option<RewardsExport>,
+
+    vote_plan_events: broadcast::Sender<VotePlanEvent>,
+
+    account_events: AccountEvents,
+
+    producers: ProducerIndex,
 }
 
 pub enum PreCheckedHeader {
@@ -263,6 +300,9 @@ impl AppliedBlock {
 #[derive(Clone, PartialEq, Eq)]
 pub enum CheckHeaderProof {
     SkipFromStorage,
+    /// the proof was verified against the leadership schedule the parent
+    /// passes on to the header, see `verify_header_proof`
+    SkipVerifiedAhead,
     Enabled,
 }
 
@@ -272,6 +312,7 @@ impl Blockchain {
         storage: Storage,
         cache_capacity: usize,
         rewards_report_all: bool,
+        rewards_export: Option<RewardsExport>,
     ) -> Self {
         Blockchain {
             branches: Branches::new(),
@@ -280,6 +321,10 @@ impl Blockchain {
             storage,
             block0,
             rewards_report_all,
+            rewards_export,
+            vote_plan_events: broadcast::channel(VOTE_PLAN_EVENTS_CHANNEL_SIZE).0,
+            account_events: AccountEvents::default(),
+            producers: ProducerIndex::default(),
         }
     }
 
@@ -287,6 +332,37 @@ impl Blockchain {
         &self.block0
     }
 
+    pub fn rewards_export(&self) -> Option<&RewardsExport> {
+        self.rewards_export.as_ref()
+    }
+
+    pub fn subscribe_vote_plan_events(&self) -> broadcast::Receiver<VotePlanEvent> {
+        self.vote_plan_events.subscribe()
+    }
+
+    pub(super) fn publish_vote_plan_event(&self, event: VotePlanEvent) {
+        // there may be no subscriber at all
+        let _ = self.vote_plan_events.send(event);
+    }
+
+    /// Follows the changes of balance and spending counter of the given
+    /// accounts, as blocks are applied on the main branch.
+    pub fn subscribe_account_events(
+        &self,
+        accounts: HashSet<Identifier>,
+    ) -> Result<AccountEventsSubscription, AccountEventsError> {
+        self.account_events.subscribe(accounts)
+    }
+
+    pub(super) fn account_events(&self) -> &AccountEvents {
+        &self.account_events
+    }
+
+    /// Blocks of the main branch produced by each stake pool
+    pub fn producers(&self) -> &ProducerIndex {
+        &self.producers
+    }
+
     pub fn storage(&self) -> &Storage {
         &self.storage
     }
@@ -441,12 +517,7 @@ impl Blockchain {
         } = new_epoch_leadership_from(current_date.epoch, parent, rewards_report_all);
 
         if check_header_proof == CheckHeaderProof::Enabled {
-            match epoch_leadership_schedule.verify(&header) {
-                Verification::Failure(error) => Err(Error::BlockHeaderVerificationFailed(
-                    HeaderChainVerifyError::NewLeadershipScheduleFailed(error),
-                )),
-                Verification::Success => Ok(()),
-            }?;
+            verify_header_proof(&header, &epoch_leadership_schedule)?;
         }
 
         Ok(PostCheckedHeader {
@@ -789,6 +860,7 @@ impl Blockchain {
                 .await;
 
             count += 1;
+            self.producers.push(new_ref.header());
             let _: Arc<Ref> = branch.update_ref(new_ref).await;
 
             let block_process_end = std::time::SystemTime::now();
@@ -805,53 +877,6 @@ impl Blockchain {
     }
 }
 
-fn write_reward_info(
-    epoch: Epoch,
-    parent_hash: HeaderHash,
-    rewards_info: &EpochRewardsInfo,
-) -> std::io::Result<()> {
-    use std::{
-        env::var,
-        fs::rename,
-        fs::File,
-        io::{BufWriter, Write},
-        path::PathBuf,
-    };
-
-    if let Ok(directory) = var("JORMUNGANDR_REWARD_DUMP_DIRECTORY") {
-        let directory = PathBuf::from(directory);
-
-        std::fs::create_dir_all(&directory)?;
-
-        let filepath = format!("reward-info-{}-{}", epoch, parent_hash);
-        let filepath = directory.join(filepath);
-        let filepath_tmp = format!("tmp.reward-info-{}-{}", epoch, parent_hash);
-        let filepath_tmp = directory.join(filepath_tmp);
-
-        {
-            let file = File::create(&filepath_tmp)?;
-            let mut buf = BufWriter::new(file);
-            write!(&mut buf, "type,identifier,received,distributed\r\n")?;
-            write!(&mut buf, "drawn,,,{}\r\n", rewards_info.drawn.0)?;
-            write!(&mut buf, "fees,,,{}\r\n", rewards_info.fees.0)?;
-            write!(&mut buf, "treasury,,{},\r\n", rewards_info.treasury.0)?;
-
-            for (pool_id, (taxed, distr)) in rewards_info.stake_pools.iter() {
-                write!(&mut buf, "pool,{},{},{}\r\n", pool_id, taxed.0, distr.0)?;
-            }
-
-            for (account_id, received) in rewards_info.accounts.iter() {
-                write!(&mut buf, "account,{},{},\r\n", account_id, received.0)?;
-            }
-
-            buf.flush()?;
-        }
-
-        rename(filepath_tmp, filepath)?;
-    }
-    Ok(())
-}
-
 pub struct EpochLeadership {
     pub state: Arc<Ledger>,
     pub leadership: Arc<Leadership>,
@@ -885,29 +910,30 @@ pub fn new_epoch_leadership_from(
             .expect("protocol update should not fail");
 
         // 1. distribute the rewards (if any) This will give us the transition state
-        let (transition_state, epoch_rewards_info) =
-            if let Some(distribution) = parent.epoch_leadership_schedule().stake_distribution() {
-                let reward_info_dist = if rewards_report_all {
-                    RewardsInfoParameters::report_all()
-                } else {
-                    RewardsInfoParameters::default()
-                };
-
-                let (ledger, rewards_info) = ledger
-                    .distribute_rewards(
-                        distribution,
-                        parent.epoch_ledger_parameters(),
-                        reward_info_dist,
-                    )
-                    .expect("Distribution of rewards will not overflow");
-                if let Err(err) = write_reward_info(epoch, parent.hash(), &rewards_info) {
-                    panic!("Error while storing the reward dump, err {}", err)
-                }
-                (Arc::new(ledger), Some(Arc::new(rewards_info)))
+        let (transition_state, epoch_rewards_info) = if let Some(distribution) =
+            parent.epoch_leadership_schedule().stake_distribution()
+        {
+            let reward_info_dist = if rewards_report_all {
+                RewardsInfoParameters::report_all()
             } else {
-                (Arc::new(ledger), parent_epoch_rewards_info)
+                RewardsInfoParameters::default()
             };
 
+            let (ledger, rewards_info) = ledger
+                .distribute_rewards(
+                    distribution,
+                    parent.epoch_ledger_parameters(),
+                    reward_info_dist,
+                )
+                .expect("Distribution of rewards will not overflow");
+            if let Err(err) = rewards_export::dump_from_env(epoch, parent.hash(), &rewards_info) {
+                panic!("Error while storing the reward dump, err {}", err)
+            }
+            (Arc::new(ledger), Some(Arc::new(rewards_info)))
+        } else {
+            (Arc::new(ledger), parent_epoch_rewards_info)
+        };
+
         // 2. now that the rewards have been distributed, prepare the schedule
         //    for the next leader
         let epoch_state = if transition_state.consensus_version() == ConsensusVersion::GenesisPraos
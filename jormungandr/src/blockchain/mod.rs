@@ -1,3 +1,5 @@
+mod account_events;
+pub mod backup;
 mod branch;
 mod candidate;
 mod chain;
@@ -5,10 +7,13 @@ mod chain_selection;
 mod checkpoints;
 mod multiverse;
 mod process;
+mod producers;
 mod reference;
 mod reference_cache;
+mod rewards_export;
 mod storage;
 mod tip;
+mod vote_plan_events;
 
 // Constants
 
@@ -25,16 +30,21 @@ mod chunk_sizes {
 // Re-exports
 
 pub use self::{
+    account_events::{
+        AccountEvent, AccountEventsSubscription, SubscriptionError as AccountEventsError,
+    },
     branch::Branch,
     chain::{
-        new_epoch_leadership_from, Blockchain, CheckHeaderProof, EpochLeadership, Error,
-        LeadershipBlock, PreCheckedHeader, MAIN_BRANCH_TAG,
+        new_epoch_leadership_from, verify_header_proof, Blockchain, CheckHeaderProof,
+        EpochLeadership, Error, LeadershipBlock, PreCheckedHeader, MAIN_BRANCH_TAG,
     },
     chain_selection::{compare_against, ComparisonResult},
     checkpoints::Checkpoints,
     multiverse::Multiverse,
     process::{process_new_ref, Process},
+    producers::{ProducedBlock, ProducerIndex},
     reference::Ref,
     storage::{Error as StorageError, Storage},
     tip::Tip,
+    vote_plan_events::{VotePlanEvent, VotePlanPhase},
 };
@@ -2,7 +2,8 @@ use super::{
     candidate,
     chain::{self, AppliedBlock, CheckHeaderProof, LeadershipBlock},
     chain_selection::{self, ComparisonResult},
-    Blockchain, Error, PreCheckedHeader, Ref, Tip, MAIN_BRANCH_TAG,
+    rewards_export, vote_plan_events, Blockchain, Error, PreCheckedHeader, Ref, Tip,
+    MAIN_BRANCH_TAG,
 };
 use crate::{
     blockcfg::{Block, FragmentId, Header, HeaderHash},
@@ -369,6 +370,7 @@ pub async fn process_new_ref(
                 if let Some(ref mut tx_msg_box) = tx_msg_box {
                     try_request_fragment_removal(tx_msg_box, fragment_ids, &block.header())?;
                 }
+                blockchain.producers().push(candidate.header());
 
                 tip.update_ref(candidate).await;
             } else {
@@ -386,6 +388,7 @@ pub async fn process_new_ref(
                 if let Some(ref mut tx_msg_box) = tx_msg_box {
                     tx_msg_box.try_send(TransactionMsg::BranchSwitch(ancestor.date().into()))?;
                 }
+                blockchain.producers().rollback_after(ancestor.date());
 
                 while let Some(block) = stream.next().await {
                     let block = block?;
@@ -393,6 +396,7 @@ pub async fn process_new_ref(
                     if let Some(ref mut tx_msg_box) = tx_msg_box {
                         try_request_fragment_removal(tx_msg_box, fragment_ids, &block.header())?;
                     }
+                    blockchain.producers().push(&block.header());
                 }
 
                 tracing::info!(
@@ -409,6 +413,10 @@ pub async fn process_new_ref(
                 tip.swap(branch).await;
             }
 
+            export_epoch_rewards(blockchain, &tip_ref, &candidate);
+            notify_vote_plan_events(blockchain, &tip_ref, &candidate);
+            notify_account_events(blockchain, &tip_ref, &candidate);
+
             if let Some(mut msg_box) = explorer_msg_box {
                 msg_box
                     .send(ExplorerMsg::NewTip(candidate_hash))
@@ -423,6 +431,67 @@ pub async fn process_new_ref(
     Ok(())
 }
 
+/// write the rewards distributed when the main branch enters a new epoch,
+/// if the export is enabled in the node configuration.
+fn export_epoch_rewards(blockchain: &Blockchain, previous_tip: &Ref, new_tip: &Ref) {
+    let config = match blockchain.rewards_export() {
+        Some(config) => config,
+        None => return,
+    };
+
+    let epoch = new_tip.block_date().epoch;
+    if epoch <= previous_tip.block_date().epoch {
+        return;
+    }
+
+    // the rewards info is inherited from the previous epoch when no rewards
+    // were distributed at this transition, don't write the same one twice
+    let rewards_info = match (
+        new_tip.epoch_rewards_info(),
+        previous_tip.epoch_rewards_info(),
+    ) {
+        (Some(new), Some(previous)) if Arc::ptr_eq(new, previous) => return,
+        (Some(new), _) => new,
+        (None, _) => return,
+    };
+
+    if let Err(err) = rewards_export::export(config, epoch, rewards_info) {
+        tracing::error!(
+            "cannot export the rewards of epoch {} to {}: {}",
+            epoch,
+            config.dir.display(),
+            err
+        );
+    }
+}
+
+fn notify_vote_plan_events(blockchain: &Blockchain, previous_tip: &Ref, new_tip: &Ref) {
+    for event in vote_plan_events::vote_plan_events(previous_tip, new_tip) {
+        tracing::info!(
+            vote_plan_id = %event.vote_plan_id,
+            phase = %event.phase,
+            block = %event.block,
+            date = %event.date,
+            "vote plan entered a new phase"
+        );
+        blockchain.publish_vote_plan_event(event);
+    }
+    for vote_plan_id in vote_plan_events::tally_reminders(previous_tip, new_tip) {
+        tracing::warn!(
+            vote_plan_id = %vote_plan_id,
+            block = %new_tip.hash(),
+            date = %new_tip.block_date(),
+            "half of the tally phase of the vote plan has passed and no tally was submitted yet"
+        );
+    }
+}
+
+fn notify_account_events(blockchain: &Blockchain, previous_tip: &Arc<Ref>, new_tip: &Arc<Ref>) {
+    blockchain
+        .account_events()
+        .tip_changed(previous_tip, new_tip);
+}
+
 async fn process_and_propagate_new_ref(
     blockchain: &mut Blockchain,
     tip: Tip,
@@ -476,6 +545,8 @@ async fn process_leadership_block(
 
     // Track block as new new tip block
     stats_counter.set_tip_block(&block, &new_block_ref);
+    stats_counter
+        .set_vote_plans_awaiting_tally(vote_plan_events::vote_plans_awaiting_tally(&new_block_ref));
 
     if let Some(mut msg_box) = explorer_msg_box {
         msg_box.send(ExplorerMsg::NewBlock(block)).await?;
@@ -614,6 +685,9 @@ async fn process_network_blocks(
             if let Some(b) = latest_block {
                 stats_counter.set_tip_block(&b, &new_block_ref);
             };
+            stats_counter.set_vote_plans_awaiting_tally(
+                vote_plan_events::vote_plans_awaiting_tally(&new_block_ref),
+            );
             Ok(())
         }
         None => Ok(()),
@@ -0,0 +1,161 @@
+use crate::blockcfg::{BlockDate, ChainLength, Epoch, Header, HeaderHash};
+use chain_impl_mockchain::certificate::PoolId;
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+
+/// Number of epochs indexed, the epoch of the tip included. The blocks of
+/// older epochs are dropped when the tip enters a new epoch.
+pub const INDEXED_EPOCHS: Epoch = 8;
+
+/// A block of the main branch produced by a stake pool
+#[derive(Debug, Clone)]
+pub struct ProducedBlock {
+    pub hash: HeaderHash,
+    pub date: BlockDate,
+    pub chain_length: ChainLength,
+}
+
+#[derive(Default)]
+struct Producers {
+    /// the most recent epoch a block was indexed in
+    latest_epoch: Epoch,
+    blocks: HashMap<PoolId, Vec<ProducedBlock>>,
+}
+
+/// The blocks of the main branch produced by each stake pool in the last
+/// [`INDEXED_EPOCHS`] epochs, in the order of the branch. The index is updated
+/// as the tip moves and only keeps the hash, date and chain length of the
+/// blocks, so that the production of a pool can be checked without running
+/// the explorer.
+#[derive(Clone, Default)]
+pub struct ProducerIndex(Arc<RwLock<Producers>>);
+
+impl ProducerIndex {
+    /// Records a block appended to the main branch. Blocks not produced by a
+    /// stake pool (block0, BFT blocks) are ignored.
+    pub fn push(&self, header: &Header) {
+        let pool_id = match header.get_stakepool_id() {
+            Some(pool_id) => pool_id.clone(),
+            None => return,
+        };
+        self.insert(
+            pool_id,
+            ProducedBlock {
+                hash: header.hash(),
+                date: header.block_date(),
+                chain_length: header.chain_length(),
+            },
+        );
+    }
+
+    fn insert(&self, pool_id: PoolId, block: ProducedBlock) {
+        let mut index = self.0.write().unwrap();
+        let epoch = block.date.epoch;
+        index.blocks.entry(pool_id).or_default().push(block);
+        if epoch > index.latest_epoch {
+            index.latest_epoch = epoch;
+            let oldest = oldest_indexed_epoch(epoch);
+            for blocks in index.blocks.values_mut() {
+                let expired = blocks
+                    .iter()
+                    .position(|block| block.date.epoch >= oldest)
+                    .unwrap_or_else(|| blocks.len());
+                blocks.drain(..expired);
+            }
+            index.blocks.retain(|_, blocks| !blocks.is_empty());
+        }
+    }
+
+    /// Forgets the blocks after `date`, which were on the branch the tip
+    /// switched away from.
+    pub fn rollback_after(&self, date: BlockDate) {
+        let mut index = self.0.write().unwrap();
+        for blocks in index.blocks.values_mut() {
+            while blocks.last().map_or(false, |block| block.date > date) {
+                blocks.pop();
+            }
+        }
+        index.blocks.retain(|_, blocks| !blocks.is_empty());
+    }
+
+    /// The oldest epoch whose blocks are still indexed.
+    pub fn oldest_epoch(&self) -> Epoch {
+        oldest_indexed_epoch(self.0.read().unwrap().latest_epoch)
+    }
+
+    /// Blocks produced by the pool in the epoch, the oldest first.
+    pub fn blocks(&self, pool_id: &PoolId, epoch: Epoch) -> Vec<ProducedBlock> {
+        match self.0.read().unwrap().blocks.get(pool_id) {
+            Some(blocks) => blocks
+                .iter()
+                .filter(|block| block.date.epoch == epoch)
+                .cloned()
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Whether the pool produced any block of the main branch in the indexed
+    /// epochs.
+    pub fn contains(&self, pool_id: &PoolId) -> bool {
+        self.0.read().unwrap().blocks.contains_key(pool_id)
+    }
+}
+
+fn oldest_indexed_epoch(latest_epoch: Epoch) -> Epoch {
+    latest_epoch.saturating_sub(INDEXED_EPOCHS - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn pool(byte: u8) -> PoolId {
+        PoolId::from_str(&hex::encode([byte; 32])).unwrap()
+    }
+
+    fn block(epoch: Epoch, slot_id: u32) -> ProducedBlock {
+        ProducedBlock {
+            hash: HeaderHash::hash_bytes(&[epoch as u8, slot_id as u8]),
+            date: BlockDate { epoch, slot_id },
+            chain_length: ChainLength::from(epoch * 10 + slot_id),
+        }
+    }
+
+    #[test]
+    fn only_the_last_epochs_are_indexed() {
+        let index = ProducerIndex::default();
+        let (active, retired) = (pool(1), pool(2));
+        index.insert(retired.clone(), block(0, 1));
+        for epoch in 0..INDEXED_EPOCHS {
+            index.insert(active.clone(), block(epoch, 0));
+        }
+        assert_eq!(index.oldest_epoch(), 0);
+        assert!(index.contains(&retired));
+
+        index.insert(active.clone(), block(INDEXED_EPOCHS, 0));
+        assert_eq!(index.oldest_epoch(), 1);
+        assert!(!index.contains(&retired));
+        assert!(index.blocks(&active, 0).is_empty());
+        assert_eq!(index.blocks(&active, 1).len(), 1);
+    }
+
+    #[test]
+    fn blocks_of_abandoned_branches_are_forgotten() {
+        let index = ProducerIndex::default();
+        let (first, second) = (pool(1), pool(2));
+        index.insert(first.clone(), block(1, 0));
+        index.insert(second.clone(), block(1, 1));
+        index.insert(first.clone(), block(1, 2));
+
+        index.rollback_after(BlockDate {
+            epoch: 1,
+            slot_id: 0,
+        });
+        assert_eq!(index.blocks(&first, 1).len(), 1);
+        assert!(!index.contains(&second));
+    }
+}
@@ -1,6 +1,9 @@
-use crate::blockcfg::{
-    BlockDate, ChainLength, EpochRewardsInfo, Header, HeaderHash, Leadership, Ledger,
-    LedgerParameters,
+use crate::{
+    blockcfg::{
+        BlockDate, ChainLength, EpochRewardsInfo, Header, HeaderHash, Leadership, Ledger,
+        LedgerParameters,
+    },
+    utils::clock,
 };
 use chain_impl_mockchain::{multiverse, vote::VotePlanStatus};
 use chain_time::{
@@ -128,6 +131,14 @@ impl Ref {
         self.previous_epoch_state.as_ref()
     }
 
+    /// the last `Ref` of each of the previous epochs of this branch (that had
+    /// blocks), the most recent first
+    pub fn previous_epoch_states(&self) -> impl Iterator<Item = &Arc<Ref>> {
+        std::iter::successors(self.last_ref_previous_epoch(), |r| {
+            r.last_ref_previous_epoch()
+        })
+    }
+
     /// get the chain_time's `Slot`. This allows to compute an accurate
     /// block time via a given time_frame or a precise block time
     pub fn slot(&self) -> Slot {
@@ -157,7 +168,7 @@ impl Ref {
     /// retrieve the time of the slot of the block. If the block is set
     /// in the future, this function will return an error.
     pub fn elapsed(&self) -> Result<Duration, std::time::SystemTimeError> {
-        SystemTime::now().duration_since(self.time())
+        clock::now().duration_since(self.time())
     }
 
     /// clone all active vote plans at this given state
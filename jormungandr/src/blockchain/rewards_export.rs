@@ -0,0 +1,150 @@
+use crate::{
+    blockcfg::{Epoch, EpochRewardsInfo, HeaderHash},
+    settings::start::config::{RewardsExport, RewardsExportFormat},
+};
+use jormungandr_lib::interfaces::EpochRewardsInfo as EpochRewardsInfoExport;
+
+use std::{
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+
+/// Writes the rewards distributed at the beginning of `epoch` in the
+/// configured directory. The file is written in a temporary location first
+/// and then moved in place, so readers never see a partially written summary.
+pub fn export(
+    config: &RewardsExport,
+    epoch: Epoch,
+    rewards_info: &EpochRewardsInfo,
+) -> io::Result<()> {
+    fs::create_dir_all(&config.dir)?;
+
+    let extension = match config.format {
+        RewardsExportFormat::Csv => "csv",
+        RewardsExportFormat::Json => "json",
+    };
+    let filename = format!("rewards-epoch-{}.{}", epoch, extension);
+
+    write_atomically(&config.dir, &filename, |buf| match config.format {
+        RewardsExportFormat::Csv => write_csv(buf, rewards_info),
+        RewardsExportFormat::Json => {
+            let summary = EpochRewardsInfoExport::from(epoch, rewards_info);
+            serde_json::to_writer_pretty(buf, &summary).map_err(io::Error::from)
+        }
+    })
+}
+
+/// Legacy dump of the rewards, enabled with the `JORMUNGANDR_REWARD_DUMP_DIRECTORY`
+/// environment variable. One file is written for every evaluated epoch transition,
+/// including the ones happening on forks.
+pub fn dump_from_env(
+    epoch: Epoch,
+    parent_hash: HeaderHash,
+    rewards_info: &EpochRewardsInfo,
+) -> io::Result<()> {
+    if let Ok(directory) = std::env::var("JORMUNGANDR_REWARD_DUMP_DIRECTORY") {
+        let directory = Path::new(&directory);
+        fs::create_dir_all(directory)?;
+
+        let filename = format!("reward-info-{}-{}", epoch, parent_hash);
+        write_atomically(directory, &filename, |buf| write_csv(buf, rewards_info))?;
+    }
+    Ok(())
+}
+
+fn write_atomically<F>(directory: &Path, filename: &str, write: F) -> io::Result<()>
+where
+    F: FnOnce(&mut BufWriter<File>) -> io::Result<()>,
+{
+    let filepath = directory.join(filename);
+    let filepath_tmp = directory.join(format!("tmp.{}", filename));
+
+    {
+        let file = File::create(&filepath_tmp)?;
+        let mut buf = BufWriter::new(file);
+        write(&mut buf)?;
+        buf.flush()?;
+    }
+
+    fs::rename(filepath_tmp, filepath)
+}
+
+fn write_csv<W: Write>(buf: &mut W, rewards_info: &EpochRewardsInfo) -> io::Result<()> {
+    write!(buf, "type,identifier,received,distributed\r\n")?;
+    write!(buf, "drawn,,,{}\r\n", rewards_info.drawn.0)?;
+    write!(buf, "fees,,,{}\r\n", rewards_info.fees.0)?;
+    write!(buf, "treasury,,{},\r\n", rewards_info.treasury.0)?;
+
+    for (pool_id, (taxed, distr)) in rewards_info.stake_pools.iter() {
+        write!(buf, "pool,{},{},{}\r\n", pool_id, taxed.0, distr.0)?;
+    }
+
+    for (account_id, received) in rewards_info.accounts.iter() {
+        write!(buf, "account,{},{},\r\n", account_id, received.0)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chain_impl_mockchain::{certificate::PoolId, value::Value};
+    use std::str::FromStr;
+
+    fn rewards_info() -> EpochRewardsInfo {
+        let mut rewards_info = EpochRewardsInfo::new();
+        rewards_info.drawn = Value(100);
+        rewards_info.fees = Value(10);
+        rewards_info.treasury = Value(20);
+        let pool_id = PoolId::from_str(&hex::encode([1; 32])).unwrap();
+        rewards_info
+            .stake_pools
+            .insert(pool_id, (Value(30), Value(60)));
+        rewards_info
+    }
+
+    #[test]
+    fn one_summary_is_written_per_epoch() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = RewardsExport {
+            dir: dir.path().join("rewards"),
+            format: RewardsExportFormat::Csv,
+        };
+        export(&config, 3, &rewards_info()).unwrap();
+        export(
+            &RewardsExport {
+                format: RewardsExportFormat::Json,
+                ..config.clone()
+            },
+            4,
+            &rewards_info(),
+        )
+        .unwrap();
+
+        let mut files: Vec<_> = fs::read_dir(&config.dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect();
+        files.sort();
+        assert_eq!(files, vec!["rewards-epoch-3.csv", "rewards-epoch-4.json"]);
+
+        let csv = fs::read_to_string(config.dir.join("rewards-epoch-3.csv")).unwrap();
+        let lines: Vec<_> = csv.split("\r\n").collect();
+        assert_eq!(
+            &lines[..4],
+            &[
+                "type,identifier,received,distributed",
+                "drawn,,,100",
+                "fees,,,10",
+                "treasury,,20,"
+            ]
+        );
+        assert_eq!(lines[4], format!("pool,{},30,60", hex::encode([1; 32])));
+
+        let json = fs::read_to_string(config.dir.join("rewards-epoch-4.json")).unwrap();
+        let summary: EpochRewardsInfoExport = serde_json::from_str(&json).unwrap();
+        assert_eq!(summary.epoch(), 4);
+    }
+}
@@ -1,18 +1,25 @@
+use super::MAIN_BRANCH_TAG;
 use crate::{
-    blockcfg::{Block, HeaderHash},
+    blockcfg::{Block, Header, HeaderHash},
     intercom::{self, ReplySendError, ReplyStreamHandle},
 };
 use chain_core::property::{Deserialize, Serialize};
 use chain_storage::{BlockInfo, BlockStore, Error as StorageError};
 use futures::prelude::*;
+use lru::LruCache;
 use thiserror::Error;
 use tracing::Span;
 
 use std::convert::identity;
 use std::path::Path;
+use std::sync::{Arc, Mutex};
 
 const MINIMUM_BLOCKS_TO_FLUSH: usize = 256;
 
+// Number of decoded blocks kept in memory. Peers syncing from this node ask
+// for the same blocks, mostly the recent ones, within a short time.
+const BLOCK_CACHE_CAPACITY: usize = 1024;
+
 #[derive(Debug, Error)]
 pub enum Error {
     #[error("block not found")]
@@ -45,6 +52,7 @@ impl From<StorageError> for Error {
 #[derive(Clone)]
 pub struct Storage {
     storage: BlockStore,
+    blocks: Arc<Mutex<LruCache<HeaderHash, Block>>>,
     span: Span,
 }
 
@@ -64,12 +72,20 @@ enum StreamingError {
 impl Storage {
     pub fn file<P: AsRef<Path>>(path: P, span: Span) -> Result<Self, Error> {
         let storage = BlockStore::file(path, HeaderHash::zero_hash().as_bytes().to_vec())?;
-        Ok(Storage { storage, span })
+        Ok(Storage::new(storage, span))
     }
 
     pub fn memory(span: Span) -> Result<Self, Error> {
         let storage = BlockStore::memory(HeaderHash::zero_hash().as_bytes().to_vec())?;
-        Ok(Storage { storage, span })
+        Ok(Storage::new(storage, span))
+    }
+
+    fn new(storage: BlockStore, span: Span) -> Self {
+        Storage {
+            storage,
+            blocks: Arc::new(Mutex::new(LruCache::new(BLOCK_CACHE_CAPACITY))),
+            span,
+        }
     }
 
     pub fn get_tag(&self, tag: &str) -> Result<Option<HeaderHash>, Error> {
@@ -92,15 +108,33 @@ impl Storage {
     }
 
     pub fn get(&self, header_hash: HeaderHash) -> Result<Option<Block>, Error> {
+        if let Some(block) = self.blocks.lock().unwrap().get(&header_hash) {
+            return Ok(Some(block.clone()));
+        }
         match self.storage.get_block(header_hash.as_bytes()) {
-            Ok(block) => Block::deserialize(block.as_ref())
-                .map(Some)
-                .map_err(Error::Deserialize),
+            Ok(block) => {
+                let block = Block::deserialize(block.as_ref()).map_err(Error::Deserialize)?;
+                self.blocks.lock().unwrap().put(header_hash, block.clone());
+                Ok(Some(block))
+            }
             Err(StorageError::BlockNotFound) => Ok(None),
             Err(e) => Err(Error::BackendError(e)),
         }
     }
 
+    /// Decodes a block streamed from the storage. Only the header is decoded
+    /// when the block is in the cache. The streamed blocks are not added to
+    /// the cache: a peer syncing from far behind would evict the recent blocks.
+    fn decode_block(&self, raw_block: &[u8]) -> Result<Block, Error> {
+        let header_hash = Header::deserialize(raw_block)
+            .map_err(Error::Deserialize)?
+            .hash();
+        if let Some(block) = self.blocks.lock().unwrap().get(&header_hash) {
+            return Ok(block.clone());
+        }
+        Block::deserialize(raw_block).map_err(Error::Deserialize)
+    }
+
     pub fn block_exists(&self, header_hash: HeaderHash) -> Result<bool, Error> {
         self.storage
             .block_exists(header_hash.as_ref())
@@ -133,12 +167,16 @@ impl Storage {
             .map_err(Error::Serialize)?;
         let chain_length = block.header.chain_length().into();
         let block_info = BlockInfo::new(id, parent_id, chain_length);
-        self.storage
-            .put_block(
-                &block.serialize_as_vec().map_err(Error::Serialize)?[..],
-                block_info,
-            )
-            .map_err(Into::into)
+        self.storage.put_block(
+            &block.serialize_as_vec().map_err(Error::Serialize)?[..],
+            block_info,
+        )?;
+        // the blocks just added are the ones the peers are about to ask for
+        self.blocks
+            .lock()
+            .unwrap()
+            .put(block.header.hash(), block.clone());
+        Ok(())
     }
 
     /// Return values:
@@ -156,11 +194,10 @@ impl Storage {
             .is_ancestor(from.as_bytes(), to.as_bytes())?
             .ok_or(Error::CannotIterate)?;
 
+        let storage = self.clone();
         let stream = futures::stream::iter(self.storage.iter(to.as_bytes(), distance)?)
             .map_err(Into::into)
-            .and_then(|raw_block| async move {
-                Block::deserialize(raw_block.as_ref()).map_err(Error::Deserialize)
-            })
+            .and_then(move |raw_block| future::ready(storage.decode_block(raw_block.as_ref())))
             .map_err(Into::into);
 
         Ok(stream)
@@ -208,9 +245,9 @@ impl Storage {
 
         let mut stream = futures::stream::iter(iter)
             .map(|raw_block_result| {
-                raw_block_result.map_err(Into::into).and_then(|raw_block| {
-                    Block::deserialize(raw_block.as_ref()).map_err(Error::Deserialize)
-                })
+                raw_block_result
+                    .map_err(Into::into)
+                    .and_then(|raw_block| self.decode_block(raw_block.as_ref()))
             })
             .map_ok(transform)
             .map_err(Into::into)
@@ -278,6 +315,54 @@ impl Storage {
         .map_err(Error::Deserialize)
     }
 
+    /// Copies the blocks and the main branch tag into a new storage at `path`.
+    ///
+    /// The copy goes through the storage rather than its files, which are
+    /// written to in the background, so it is consistent without pausing the
+    /// writes: it holds the main branch as tagged when the copy started and
+    /// the other branches still present when they are reached.
+    pub fn copy_to<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+        let copy = BlockStore::file(path, HeaderHash::zero_hash().as_bytes().to_vec())?;
+        let head = match self.storage.get_tag(MAIN_BRANCH_TAG)? {
+            Some(head) => head,
+            None => return Ok(()),
+        };
+        self.copy_branch(&copy, head.as_ref())?;
+        for tip in self.storage.get_tips_ids()? {
+            match self.copy_branch(&copy, tip.as_ref()) {
+                // pruned by the garbage collection since the tips were listed
+                Err(Error::BlockNotFound) => {}
+                result => result?,
+            }
+        }
+        copy.put_tag(MAIN_BRANCH_TAG, head.as_ref())?;
+        Ok(())
+    }
+
+    /// Copies the blocks from `tip` back to the first one already in `copy`,
+    /// the parents first.
+    fn copy_branch(&self, copy: &BlockStore, tip: &[u8]) -> Result<(), Error> {
+        let root = HeaderHash::zero_hash();
+        let mut branch = Vec::new();
+        let mut id = tip.to_vec();
+        while id != root.as_bytes() && !copy.block_exists(&id)? {
+            let info = self.storage.get_block_info(&id)?;
+            let parent_id = info.parent_id().as_ref().to_vec();
+            branch.push(info);
+            id = parent_id;
+        }
+        for info in branch.into_iter().rev() {
+            let block = self.storage.get_block(info.id().as_ref())?;
+            let info = BlockInfo::new(
+                info.id().as_ref().to_vec(),
+                info.parent_id().as_ref().to_vec(),
+                info.chain_length(),
+            );
+            copy.put_block(block.as_ref(), info)?;
+        }
+        Ok(())
+    }
+
     pub fn gc(&self, threshold_depth: u32, main_branch_tip: &[u8]) -> Result<(), Error> {
         let _enter = self.span.enter();
         let main_info = self.storage.get_block_info(main_branch_tip)?;
@@ -293,6 +378,7 @@ impl Storage {
         );
 
         let tips_ids = self.storage.get_tips_ids()?;
+        let mut pruned = false;
 
         for id in tips_ids {
             let info = self.storage.get_block_info(id.as_ref())?;
@@ -302,6 +388,7 @@ impl Storage {
             }
 
             self.storage.prune_branch(id.as_ref())?;
+            pruned = true;
 
             tracing::debug!(
                 "removed branch with head {}",
@@ -309,6 +396,10 @@ impl Storage {
             );
         }
 
+        if pruned {
+            self.evict_removed_blocks()?;
+        }
+
         let to_block_info = self
             .storage
             .get_nth_ancestor(main_branch_tip, threshold_depth)?;
@@ -324,4 +415,134 @@ impl Storage {
 
         Ok(())
     }
+
+    /// Removes from the cache the blocks that are no longer in the storage.
+    fn evict_removed_blocks(&self) -> Result<(), Error> {
+        let mut blocks = self.blocks.lock().unwrap();
+        let cached: Vec<HeaderHash> = blocks.iter().map(|(id, _)| *id).collect();
+        for id in cached {
+            if !self.storage.block_exists(id.as_ref())? {
+                blocks.pop(&id);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use super::*;
+    use crate::blockcfg::{BlockDate, BlockVersion, ChainLength, Contents, HeaderBuilderNew};
+
+    /// An empty block, the first block of a chain if `parent` is `None`.
+    /// Sibling blocks need different slots to have different hashes.
+    pub fn block(parent: Option<&Block>, slot_id: u32) -> Block {
+        let (parent_id, chain_length) = match parent {
+            Some(parent) => (
+                parent.header.hash(),
+                parent.header.chain_length().increase(),
+            ),
+            None => (HeaderHash::zero_hash(), ChainLength::from(0)),
+        };
+        let contents = Contents::empty();
+        let header = HeaderBuilderNew::new(BlockVersion::Genesis, &contents)
+            .set_parent(&parent_id, chain_length)
+            .set_date(BlockDate { epoch: 0, slot_id })
+            .into_unsigned_header()
+            .unwrap()
+            .generalize();
+        Block { header, contents }
+    }
+
+    #[test]
+    fn copy_holds_every_branch() {
+        let storage = Storage::memory(Span::none()).unwrap();
+        let block0 = block(None, 0);
+        let main = block(Some(&block0), 1);
+        let fork = block(Some(&block0), 2);
+        for block in &[&block0, &main, &fork] {
+            storage.put_block(block).unwrap();
+        }
+        storage
+            .put_tag(MAIN_BRANCH_TAG, main.header.hash())
+            .unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        storage.copy_to(dir.path().join("copy")).unwrap();
+        let copy = Storage::file(dir.path().join("copy"), Span::none()).unwrap();
+
+        assert_eq!(
+            copy.get_tag(MAIN_BRANCH_TAG).unwrap(),
+            Some(main.header.hash())
+        );
+        for block in &[&block0, &main, &fork] {
+            assert!(copy.block_exists(block.header.hash()).unwrap());
+        }
+    }
+
+    fn chain(storage: &Storage, length: u32) -> Vec<Block> {
+        let mut blocks = vec![block(None, 0)];
+        for slot_id in 1..length {
+            let next = block(blocks.last(), slot_id);
+            blocks.push(next);
+        }
+        for block in &blocks {
+            storage.put_block(block).unwrap();
+        }
+        blocks
+    }
+
+    #[test]
+    fn streamed_blocks_are_not_cached() {
+        let storage = Storage::memory(Span::none()).unwrap();
+        let blocks = chain(&storage, 5);
+        storage.blocks.lock().unwrap().clear();
+
+        let stream = storage
+            .stream_from_to(blocks[0].header.hash(), blocks[4].header.hash())
+            .unwrap();
+        let streamed = futures::executor::block_on(stream.try_collect::<Vec<_>>()).unwrap();
+        assert!(!streamed.is_empty());
+        assert!(storage.blocks.lock().unwrap().is_empty());
+
+        let hash = blocks[2].header.hash();
+        assert!(storage.get(hash).unwrap().is_some());
+        assert!(storage.blocks.lock().unwrap().contains(&hash));
+    }
+
+    #[test]
+    fn cache_keeps_the_most_recently_used_blocks() {
+        let storage = Storage::memory(Span::none()).unwrap();
+        let blocks = chain(&storage, BLOCK_CACHE_CAPACITY as u32 + 1);
+        let oldest = blocks[0].header.hash();
+        assert_eq!(storage.blocks.lock().unwrap().len(), BLOCK_CACHE_CAPACITY);
+        assert!(!storage.blocks.lock().unwrap().contains(&oldest));
+
+        assert_eq!(storage.get(oldest).unwrap().unwrap().header.hash(), oldest);
+        assert!(storage.blocks.lock().unwrap().contains(&oldest));
+        assert!(!storage
+            .blocks
+            .lock()
+            .unwrap()
+            .contains(&blocks[1].header.hash()));
+    }
+
+    #[test]
+    fn pruned_blocks_are_evicted_from_the_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = Storage::file(dir.path().join("storage"), Span::none()).unwrap();
+        let main = chain(&storage, 5);
+        let fork = block(Some(&main[0]), 10);
+        storage.put_block(&fork).unwrap();
+        assert!(storage.blocks.lock().unwrap().contains(&fork.header.hash()));
+
+        let tip = main[4].header.hash();
+        storage.gc(2, tip.as_ref()).unwrap();
+
+        assert!(storage.get(fork.header.hash()).unwrap().is_none());
+        assert!(!storage.blocks.lock().unwrap().contains(&fork.header.hash()));
+        for block in &main {
+            assert!(storage.get(block.header.hash()).unwrap().is_some());
+        }
+    }
 }
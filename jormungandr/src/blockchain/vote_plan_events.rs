@@ -0,0 +1,241 @@
+use super::Ref;
+use crate::blockcfg::{BlockDate, HeaderHash};
+use chain_impl_mockchain::{
+    certificate::VotePlanId,
+    vote::{PayloadType, PrivateTallyState, Tally, VotePlanStatus},
+};
+use std::{collections::HashMap, fmt};
+
+/// Phase a vote plan has just entered
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VotePlanPhase {
+    /// the vote start date has been reached, votes can be cast
+    Voting,
+    /// the vote end date has been reached, the committee can submit the tally
+    Tally,
+    /// all the proposals of the vote plan have been tallied on chain
+    Tallied,
+}
+
+#[derive(Debug, Clone)]
+pub struct VotePlanEvent {
+    pub vote_plan_id: VotePlanId,
+    pub phase: VotePlanPhase,
+    /// the block in which the phase change was observed
+    pub block: HeaderHash,
+    pub date: BlockDate,
+}
+
+impl fmt::Display for VotePlanPhase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VotePlanPhase::Voting => f.write_str("voting"),
+            VotePlanPhase::Tally => f.write_str("tally"),
+            VotePlanPhase::Tallied => f.write_str("tallied"),
+        }
+    }
+}
+
+/// Lists the phase changes of the vote plans between two successive tips of the
+/// main branch. `previous_tip` may be more than one block behind `new_tip`, e.g.
+/// after a branch switch, in which case all the changes in between are reported
+/// once, at the date of the new tip.
+pub fn vote_plan_events(previous_tip: &Ref, new_tip: &Ref) -> Vec<VotePlanEvent> {
+    let previous_date = previous_tip.block_date();
+    let new_date = new_tip.block_date();
+
+    let previously_tallied: HashMap<VotePlanId, bool> = previous_tip
+        .active_vote_plans()
+        .iter()
+        .map(|status| (status.id.clone(), is_tallied(status)))
+        .collect();
+
+    let mut events = Vec::new();
+    for status in new_tip.active_vote_plans() {
+        let newly_tallied =
+            is_tallied(&status) && !previously_tallied.get(&status.id).copied().unwrap_or(false);
+        let phases = entered_phases(
+            previous_date,
+            new_date,
+            status.vote_start,
+            status.vote_end,
+            newly_tallied,
+        );
+
+        events.extend(phases.into_iter().map(|phase| VotePlanEvent {
+            vote_plan_id: status.id.clone(),
+            phase,
+            block: new_tip.hash(),
+            date: new_date,
+        }));
+    }
+    events
+}
+
+/// The phases entered by a vote plan when the tip moves from `previous_date` to
+/// `new_date`, in the order they were entered.
+fn entered_phases(
+    previous_date: BlockDate,
+    new_date: BlockDate,
+    vote_start: BlockDate,
+    vote_end: BlockDate,
+    newly_tallied: bool,
+) -> Vec<VotePlanPhase> {
+    let mut phases = Vec::new();
+    if previous_date < vote_start && vote_start <= new_date {
+        phases.push(VotePlanPhase::Voting);
+    }
+    if previous_date < vote_end && vote_end <= new_date {
+        phases.push(VotePlanPhase::Tally);
+    }
+    if newly_tallied {
+        phases.push(VotePlanPhase::Tallied);
+    }
+    phases
+}
+
+/// The private vote plans which reached the middle of their tally phase between
+/// two successive tips of the main branch, with no tally submitted yet.
+pub fn tally_reminders(previous_tip: &Ref, new_tip: &Ref) -> Vec<VotePlanId> {
+    let previous_date = previous_tip.block_date();
+    let new_date = new_tip.block_date();
+    let slots_per_epoch = slots_per_epoch(new_tip);
+    new_tip
+        .active_vote_plans()
+        .into_iter()
+        .filter(|status| {
+            tally_reminder_date(status, slots_per_epoch).map_or(false, |reminder| {
+                previous_date < reminder && awaits_tally(status, reminder, new_date)
+            })
+        })
+        .map(|status| status.id)
+        .collect()
+}
+
+/// Number of private vote plans past the middle of their tally phase for which
+/// the committee has not submitted any tally yet.
+pub fn vote_plans_awaiting_tally(tip: &Ref) -> usize {
+    let date = tip.block_date();
+    let slots_per_epoch = slots_per_epoch(tip);
+    tip.active_vote_plans()
+        .iter()
+        .filter(|status| {
+            tally_reminder_date(status, slots_per_epoch)
+                .map_or(false, |reminder| awaits_tally(status, reminder, date))
+        })
+        .count()
+}
+
+fn slots_per_epoch(tip: &Ref) -> u32 {
+    tip.epoch_leadership_schedule().era().slots_per_epoch()
+}
+
+/// The middle of the tally phase of a private vote plan, the date from which
+/// the committee is reminded to submit the tally.
+fn tally_reminder_date(status: &VotePlanStatus, slots_per_epoch: u32) -> Option<BlockDate> {
+    if !matches!(status.payload, PayloadType::Private) {
+        return None;
+    }
+    Some(middle_date(
+        status.vote_end,
+        status.committee_end,
+        slots_per_epoch,
+    ))
+}
+
+fn middle_date(start: BlockDate, end: BlockDate, slots_per_epoch: u32) -> BlockDate {
+    let slots_per_epoch = u64::from(slots_per_epoch);
+    let absolute_slot =
+        |date: BlockDate| u64::from(date.epoch) * slots_per_epoch + u64::from(date.slot_id);
+    let start = absolute_slot(start);
+    let middle = start + absolute_slot(end).saturating_sub(start) / 2;
+    BlockDate {
+        epoch: (middle / slots_per_epoch) as u32,
+        slot_id: (middle % slots_per_epoch) as u32,
+    }
+}
+
+fn awaits_tally(status: &VotePlanStatus, reminder: BlockDate, date: BlockDate) -> bool {
+    reminder <= date
+        && date < status.committee_end
+        && status
+            .proposals
+            .iter()
+            .all(|proposal| proposal.tally.is_none())
+}
+
+fn is_tallied(status: &VotePlanStatus) -> bool {
+    !status.proposals.is_empty()
+        && status.proposals.iter().all(|proposal| {
+            matches!(
+                proposal.tally,
+                Some(Tally::Public { .. })
+                    | Some(Tally::Private {
+                        state: PrivateTallyState::Decrypted { .. },
+                    })
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn date(epoch: u32, slot_id: u32) -> BlockDate {
+        BlockDate { epoch, slot_id }
+    }
+
+    fn vote_plan(payload: PayloadType) -> VotePlanStatus {
+        VotePlanStatus {
+            id: VotePlanId::from_str(&hex::encode([1; 32])).unwrap(),
+            vote_start: date(1, 0),
+            vote_end: date(2, 0),
+            committee_end: date(4, 0),
+            payload,
+            committee_public_keys: Vec::new(),
+            proposals: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn phases_are_entered_once_when_their_date_is_reached() {
+        let (start, end) = (date(1, 0), date(2, 0));
+        let entered = |previous, new| entered_phases(previous, new, start, end, false);
+        assert!(entered(date(0, 5), date(0, 9)).is_empty());
+        assert_eq!(entered(date(0, 9), date(1, 0)), vec![VotePlanPhase::Voting]);
+        assert!(entered(date(1, 0), date(1, 1)).is_empty());
+        assert_eq!(entered(date(1, 9), date(2, 0)), vec![VotePlanPhase::Tally]);
+        // a tip jumping over both dates reports both phases, in order
+        assert_eq!(
+            entered(date(0, 9), date(2, 3)),
+            vec![VotePlanPhase::Voting, VotePlanPhase::Tally]
+        );
+        assert_eq!(
+            entered_phases(date(2, 3), date(2, 4), start, end, true),
+            vec![VotePlanPhase::Tallied]
+        );
+    }
+
+    #[test]
+    fn reminders_are_in_the_middle_of_the_tally_phase() {
+        assert_eq!(middle_date(date(2, 0), date(4, 0), 10), date(3, 0));
+        assert_eq!(middle_date(date(2, 5), date(3, 0), 10), date(2, 7));
+        assert_eq!(middle_date(date(2, 9), date(3, 2), 10), date(3, 0));
+        // a committee end before the vote end leaves no tally phase
+        assert_eq!(middle_date(date(3, 0), date(2, 0), 10), date(3, 0));
+    }
+
+    #[test]
+    fn private_vote_plans_await_their_tally_until_the_committee_end() {
+        assert!(tally_reminder_date(&vote_plan(PayloadType::Public), 10).is_none());
+
+        let vote_plan = vote_plan(PayloadType::Private);
+        let reminder = tally_reminder_date(&vote_plan, 10).unwrap();
+        assert_eq!(reminder, date(3, 0));
+        assert!(!awaits_tally(&vote_plan, reminder, date(2, 9)));
+        assert!(awaits_tally(&vote_plan, reminder, date(3, 0)));
+        assert!(awaits_tally(&vote_plan, reminder, date(3, 9)));
+        assert!(!awaits_tally(&vote_plan, reminder, date(4, 0)));
+    }
+}
@@ -16,7 +16,7 @@ use self::error::ApiError;
 use self::scalars::{
     BlockCount, ChainLength, EpochNumber, ExternalProposalId, IndexCursor, NonZero, PayloadType,
     PoolCount, PoolId, PublicKey, Slot, TransactionCount, Value, VoteOptionRange, VotePlanId,
-    VotePlanStatusCount, Weight,
+    VotePlanPhase, VotePlanStatusCount, Weight,
 };
 use super::indexing::{
     BlockProducer, EpochData, ExplorerAddress, ExplorerBlock, ExplorerTransaction, StakePoolData,
@@ -1628,6 +1628,29 @@ impl Query {
     }
 }
 
+/// A vote plan entering a new phase on the node's main branch
+pub struct VotePlanEvent(crate::blockchain::VotePlanEvent);
+
+#[Object]
+impl VotePlanEvent {
+    pub async fn vote_plan_id(&self) -> VotePlanId {
+        VotePlanId(self.0.vote_plan_id.to_string())
+    }
+
+    pub async fn phase(&self) -> VotePlanPhase {
+        self.0.phase.into()
+    }
+
+    /// The block in which the phase change was observed
+    pub async fn block(&self) -> Block {
+        Block::from_valid_hash(self.0.block)
+    }
+
+    pub async fn date(&self) -> BlockDate {
+        self.0.date.into()
+    }
+}
+
 pub struct Subscription;
 
 #[Subscription]
@@ -1648,6 +1671,21 @@ impl Subscription {
                     .map(|(hash, state)| Branch::from_id_and_state(hash, state))
             })
     }
+
+    async fn vote_plan_events(
+        &self,
+        context: &Context<'_>,
+    ) -> impl futures::Stream<Item = VotePlanEvent> {
+        use futures::StreamExt;
+        context
+            .data_unchecked::<RestContext>()
+            .get()
+            .await
+            .unwrap()
+            .db
+            .vote_plan_events_subscription()
+            .filter_map(|event| async move { event.ok().map(VotePlanEvent) })
+    }
 }
 
 pub type Schema = async_graphql::Schema<Query, EmptyMutation, Subscription>;
@@ -1,5 +1,5 @@
 use super::error::ApiError;
-use crate::blockcfg;
+use crate::{blockcfg, blockchain};
 use async_graphql::{Enum, InputValueError, InputValueResult, Scalar, ScalarType, SimpleObject};
 use chain_crypto::bech32::Bech32;
 use chain_impl_mockchain::vote;
@@ -196,6 +196,13 @@ impl ScalarType for ExternalProposalId {
     }
 }
 
+#[derive(Clone, Copy, PartialEq, Eq, Enum)]
+pub enum VotePlanPhase {
+    Voting,
+    Tally,
+    Tallied,
+}
+
 #[derive(Clone, Copy, PartialEq, Eq, Enum)]
 pub enum PayloadType {
     Public,
@@ -337,6 +344,16 @@ impl From<vote::PayloadType> for PayloadType {
     }
 }
 
+impl From<blockchain::VotePlanPhase> for VotePlanPhase {
+    fn from(phase: blockchain::VotePlanPhase) -> Self {
+        match phase {
+            blockchain::VotePlanPhase::Voting => Self::Voting,
+            blockchain::VotePlanPhase::Tally => Self::Tally,
+            blockchain::VotePlanPhase::Tallied => Self::Tallied,
+        }
+    }
+}
+
 impl From<vote::Options> for VoteOptionRange {
     fn from(options: vote::Options) -> Self {
         let range = options.choice_range();
@@ -555,6 +555,17 @@ impl ExplorerDb {
     > {
         tokio_stream::wrappers::BroadcastStream::new(self.tip_broadcast.subscribe())
     }
+
+    pub(self) fn vote_plan_events_subscription(
+        &self,
+    ) -> impl Stream<
+        Item = std::result::Result<
+            blockchain::VotePlanEvent,
+            tokio_stream::wrappers::errors::BroadcastStreamRecvError,
+        >,
+    > {
+        tokio_stream::wrappers::BroadcastStream::new(self.blockchain().subscribe_vote_plan_events())
+    }
 }
 
 fn apply_block_to_transactions(
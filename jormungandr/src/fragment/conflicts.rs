@@ -0,0 +1,358 @@
+use crate::blockcfg::HeaderHash;
+use crate::fragment::{Fragment, FragmentId, Logs};
+use chain_core::property::Fragment as _;
+use chain_crypto::Verification;
+use chain_impl_mockchain::{
+    account::{self, SpendingCounter},
+    transaction::{
+        AccountIdentifier as TransactionAccountIdentifier, InputEnum, Transaction, UtxoPointer,
+        Witness, WitnessAccountData,
+    },
+};
+use jormungandr_lib::{
+    interfaces::{AccountIdentifier, ConflictingSpend, FragmentConflict, FragmentOrigin},
+    time::SystemTime,
+};
+use lru::LruCache;
+use std::collections::VecDeque;
+use std::net::SocketAddr;
+
+// Number of the most recently detected conflicts kept around for diagnostics.
+const RECENT_CONFLICTS_CAPACITY: usize = 1024;
+
+// Number of spending counters, from the one of the account on the tip, that
+// an account witness is checked against. Pending fragments of an account use
+// successive counters.
+const SPENDING_COUNTER_WINDOW: u32 = 16;
+
+/// Keeps track of what the fragments received by the node spend, UTxOs and account
+/// spending counters, in order to detect double spending attempts early, before the
+/// fragments reach the ledger.
+///
+/// The spending counter of an account input is not part of the fragment, it is found
+/// by checking the witness against the counters following the counter of the account
+/// on the tip. Inputs of multisig accounts, or with a witness matching none of these
+/// counters, are not tracked.
+pub struct ConflictDetector {
+    block0_hash: HeaderHash,
+    spenders: LruCache<Spent, Spender>,
+    recent: VecDeque<FragmentConflict>,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum Spent {
+    Utxo(UtxoPointer),
+    Account(account::Identifier, u32),
+}
+
+#[derive(Clone, Copy)]
+struct Spender {
+    id: FragmentId,
+    origin: FragmentOrigin,
+    peer: Option<SocketAddr>,
+}
+
+impl ConflictDetector {
+    pub fn new(max_entries: usize) -> Self {
+        Self {
+            block0_hash: HeaderHash::zero_hash(),
+            spenders: LruCache::new(max_entries),
+            recent: VecDeque::with_capacity(RECENT_CONFLICTS_CAPACITY),
+        }
+    }
+
+    /// Sets the hash of the block 0, which is signed by the account witnesses.
+    pub fn set_block0_hash(&mut self, block0_hash: HeaderHash) {
+        self.block0_hash = block0_hash;
+    }
+
+    /// Registers what the given fragment spends and returns the number of inputs
+    /// which are already spent by another fragment still known to `logs`.
+    /// `spending_counter` gives the counter of an account on the tip.
+    pub fn check<F>(
+        &mut self,
+        fragment: &Fragment,
+        origin: FragmentOrigin,
+        peer: Option<SocketAddr>,
+        logs: &Logs,
+        spending_counter: F,
+    ) -> usize
+    where
+        F: Fn(&account::Identifier) -> Option<u32>,
+    {
+        let id = fragment.id();
+        if logs.exists(id) {
+            return 0;
+        }
+
+        let spender = Spender { id, origin, peer };
+        let mut conflicts = 0;
+        for spent in spent_by(fragment, &self.block0_hash, &spending_counter) {
+            match self.spenders.get(&spent).copied() {
+                Some(first) if first.id == id => {}
+                Some(first) if is_live(logs, first.id) => {
+                    tracing::warn!(
+                        fragment_id = %id,
+                        conflicts_with = %first.id,
+                        origin = ?origin,
+                        peer = ?peer,
+                        "fragment spends an input already spent by another fragment"
+                    );
+                    self.record(conflict(&spender, &first, spent));
+                    conflicts += 1;
+                }
+                _ => {
+                    self.spenders.put(spent, spender);
+                }
+            }
+        }
+        conflicts
+    }
+
+    /// Conflicts recently detected, the oldest first.
+    pub fn recent(&self) -> Vec<FragmentConflict> {
+        self.recent.iter().cloned().collect()
+    }
+
+    fn record(&mut self, conflict: FragmentConflict) {
+        if self.recent.len() == RECENT_CONFLICTS_CAPACITY {
+            self.recent.pop_front();
+        }
+        self.recent.push_back(conflict);
+    }
+}
+
+fn conflict(spender: &Spender, first: &Spender, spent: Spent) -> FragmentConflict {
+    let spent = match spent {
+        Spent::Utxo(pointer) => ConflictingSpend::Utxo {
+            transaction_id: pointer.transaction_id.into(),
+            output_index: pointer.output_index,
+        },
+        Spent::Account(account, spending_counter) => ConflictingSpend::Account {
+            account: AccountIdentifier::from(TransactionAccountIdentifier::Single(account)),
+            spending_counter,
+        },
+    };
+    FragmentConflict {
+        fragment_id: spender.id.into(),
+        conflicts_with: first.id.into(),
+        spent,
+        received_from: spender.origin,
+        peer: spender.peer,
+        conflicts_with_received_from: first.origin,
+        conflicts_with_peer: first.peer,
+        detected_at: SystemTime::now(),
+    }
+}
+
+/// A previous spender only matters as long as it has a chance to be (or already is)
+/// in a block. Fragments which were rejected or evicted from the logs are ignored.
+fn is_live(logs: &Logs, fragment_id: FragmentId) -> bool {
+    logs.logs_by_ids(std::iter::once(fragment_id))
+        .get(&fragment_id)
+        .map(|log| !log.is_rejected())
+        .unwrap_or(false)
+}
+
+fn spent_by<F>(fragment: &Fragment, block0_hash: &HeaderHash, spending_counter: &F) -> Vec<Spent>
+where
+    F: Fn(&account::Identifier) -> Option<u32>,
+{
+    match fragment {
+        Fragment::Transaction(tx) => spent_by_transaction(tx, block0_hash, spending_counter),
+        Fragment::OwnerStakeDelegation(tx) => {
+            spent_by_transaction(tx, block0_hash, spending_counter)
+        }
+        Fragment::StakeDelegation(tx) => spent_by_transaction(tx, block0_hash, spending_counter),
+        Fragment::PoolRegistration(tx) => spent_by_transaction(tx, block0_hash, spending_counter),
+        Fragment::PoolRetirement(tx) => spent_by_transaction(tx, block0_hash, spending_counter),
+        Fragment::PoolUpdate(tx) => spent_by_transaction(tx, block0_hash, spending_counter),
+        Fragment::VotePlan(tx) => spent_by_transaction(tx, block0_hash, spending_counter),
+        Fragment::VoteCast(tx) => spent_by_transaction(tx, block0_hash, spending_counter),
+        Fragment::VoteTally(tx) => spent_by_transaction(tx, block0_hash, spending_counter),
+        Fragment::EncryptedVoteTally(tx) => spent_by_transaction(tx, block0_hash, spending_counter),
+        Fragment::Initial(_)
+        | Fragment::OldUtxoDeclaration(_)
+        | Fragment::UpdateProposal(_)
+        | Fragment::UpdateVote(_) => Vec::new(),
+    }
+}
+
+fn spent_by_transaction<E, F>(
+    tx: &Transaction<E>,
+    block0_hash: &HeaderHash,
+    spending_counter: &F,
+) -> Vec<Spent>
+where
+    F: Fn(&account::Identifier) -> Option<u32>,
+{
+    let tx = tx.as_slice();
+    let sign_data_hash = tx.transaction_sign_data_hash();
+    tx.inputs()
+        .iter()
+        .zip(tx.witnesses().iter())
+        .filter_map(|(input, witness)| match (input.to_enum(), witness) {
+            (InputEnum::UtxoInput(pointer), _) => Some(Spent::Utxo(pointer)),
+            (InputEnum::AccountInput(account, _), Witness::Account(signature)) => {
+                let account = account.to_single_account()?;
+                let tip_counter = spending_counter(&account)?;
+                (tip_counter..tip_counter.saturating_add(SPENDING_COUNTER_WINDOW))
+                    .find(|counter| {
+                        let data = WitnessAccountData::new(
+                            block0_hash,
+                            &sign_data_hash,
+                            SpendingCounter::from(*counter),
+                        );
+                        signature.verify(account.as_ref(), &data) == Verification::Success
+                    })
+                    .map(|counter| Spent::Account(account, counter))
+            }
+            (InputEnum::AccountInput(..), _) => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chain_crypto::{Ed25519, SecretKey};
+    use chain_impl_mockchain::{
+        block::BlockDate,
+        transaction::{Input, TxBuilder},
+        value::Value,
+    };
+    use jormungandr_lib::{
+        crypto::hash::Hash,
+        interfaces::{FragmentLog, FragmentStatus},
+    };
+
+    fn transaction(
+        inputs: &[Input],
+        witness: impl Fn(&chain_impl_mockchain::transaction::TransactionSignDataHash) -> Witness,
+    ) -> Fragment {
+        let builder = TxBuilder::new()
+            .set_nopayload()
+            .set_expiry_date(BlockDate {
+                epoch: 1,
+                slot_id: 0,
+            })
+            .set_ios(inputs, &[]);
+        let sign_data_hash = builder.get_auth_data_for_witness().hash();
+        let witnesses: Vec<_> = inputs.iter().map(|_| witness(&sign_data_hash)).collect();
+        Fragment::Transaction(builder.set_witnesses(&witnesses).set_payload_auth(&()))
+    }
+
+    /// Every call signs with a new key, so that the fragments differ
+    fn utxo_spend(pointer: UtxoPointer) -> Fragment {
+        let key = SecretKey::<Ed25519>::generate(rand::rngs::OsRng);
+        transaction(&[Input::from_utxo(pointer)], |sign_data_hash| {
+            Witness::new_utxo(&HeaderHash::zero_hash(), sign_data_hash, |data| {
+                key.sign(data)
+            })
+        })
+    }
+
+    fn account_spend(key: &SecretKey<Ed25519>, counter: u32, value: u64) -> Fragment {
+        let account = account::Identifier::from(key.to_public());
+        transaction(
+            &[Input::from_account_single(account, Value(value))],
+            |sign_data_hash| {
+                Witness::new_account(
+                    &HeaderHash::zero_hash(),
+                    sign_data_hash,
+                    SpendingCounter::from(counter),
+                    |data| key.sign(data),
+                )
+            },
+        )
+    }
+
+    /// Checks the fragment, then adds it to the logs as the pool does
+    fn receive(
+        detector: &mut ConflictDetector,
+        logs: &mut Logs,
+        fragment: &Fragment,
+        peer: Option<SocketAddr>,
+    ) -> usize {
+        let conflicts = detector.check(fragment, FragmentOrigin::Network, peer, logs, |_| Some(0));
+        logs.insert_pending(FragmentLog::new(fragment.id(), FragmentOrigin::Network));
+        conflicts
+    }
+
+    #[test]
+    fn utxo_spent_twice_is_a_conflict() {
+        let mut detector = ConflictDetector::new(16);
+        let mut logs = Logs::new(16);
+        let pointer = UtxoPointer::new(FragmentId::zero_hash(), 0, Value(10));
+        let first_peer = "127.0.0.1:3000".parse().ok();
+        let second_peer = "127.0.0.2:3000".parse().ok();
+
+        let first = utxo_spend(pointer);
+        let second = utxo_spend(pointer);
+        assert_eq!(receive(&mut detector, &mut logs, &first, first_peer), 0);
+        assert_eq!(receive(&mut detector, &mut logs, &second, second_peer), 1);
+        // receiving the same fragment again is not a conflict
+        assert_eq!(receive(&mut detector, &mut logs, &first, second_peer), 0);
+
+        let conflicts = detector.recent();
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].fragment_id, Hash::from(second.id()));
+        assert_eq!(conflicts[0].conflicts_with, Hash::from(first.id()));
+        assert_eq!(conflicts[0].peer, second_peer);
+        assert_eq!(conflicts[0].conflicts_with_peer, first_peer);
+        assert_eq!(
+            conflicts[0].spent,
+            ConflictingSpend::Utxo {
+                transaction_id: Hash::from(FragmentId::zero_hash()),
+                output_index: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn account_spending_counter_used_twice_is_a_conflict() {
+        let mut detector = ConflictDetector::new(16);
+        let mut logs = Logs::new(16);
+        let key = SecretKey::<Ed25519>::generate(rand::rngs::OsRng);
+
+        let first = account_spend(&key, 0, 1);
+        let next = account_spend(&key, 1, 1);
+        let replacement = account_spend(&key, 0, 2);
+        assert_eq!(receive(&mut detector, &mut logs, &first, None), 0);
+        // the following counter does not conflict
+        assert_eq!(receive(&mut detector, &mut logs, &next, None), 0);
+        assert_eq!(receive(&mut detector, &mut logs, &replacement, None), 1);
+
+        let conflicts = detector.recent();
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].conflicts_with, Hash::from(first.id()));
+        assert!(matches!(
+            conflicts[0].spent,
+            ConflictingSpend::Account {
+                spending_counter: 0,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn rejected_spender_is_not_a_conflict() {
+        let mut detector = ConflictDetector::new(16);
+        let mut logs = Logs::new(16);
+        let pointer = UtxoPointer::new(FragmentId::zero_hash(), 0, Value(10));
+
+        let first = utxo_spend(pointer);
+        assert_eq!(receive(&mut detector, &mut logs, &first, None), 0);
+        logs.modify(
+            first.id(),
+            FragmentStatus::Rejected {
+                reason: "invalid".to_owned(),
+            },
+            BlockDate::first(),
+        );
+        assert_eq!(
+            receive(&mut detector, &mut logs, &utxo_spend(pointer), None),
+            0
+        );
+    }
+}
@@ -4,19 +4,106 @@ use jormungandr_lib::{
     interfaces::{BlockDate, FragmentLog, FragmentOrigin, FragmentStatus},
 };
 use lru::LruCache;
+use serde::Deserialize;
 use std::collections::HashMap;
 
+/// Limits on the logs of processed fragments, enforced by [`Logs::gc`] on top
+/// of the overall capacity of the logs. Logs of pending fragments are never
+/// collected, they are bounded by the size of the pools.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LogGcPolicy {
+    pub max_age_epochs: Option<u32>,
+    pub max_in_block_entries: Option<usize>,
+    pub max_rejected_entries: Option<usize>,
+}
+
+impl LogGcPolicy {
+    fn is_disabled(&self) -> bool {
+        self.max_age_epochs.is_none()
+            && self.max_in_block_entries.is_none()
+            && self.max_rejected_entries.is_none()
+    }
+}
+
+/// status of the fragment logs to select, see [`LogsFilter`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LogStatusFilter {
+    Pending,
+    InBlock,
+    Rejected,
+}
+
+/// origin of the fragment logs to select, see [`LogsFilter`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LogOriginFilter {
+    Rest,
+    Network,
+}
+
+/// selection of the fragment logs, applied by the fragment task so that only
+/// the matching logs are sent back. All the logs match the default filter.
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+pub struct LogsFilter {
+    pub status: Option<LogStatusFilter>,
+    pub origin: Option<LogOriginFilter>,
+}
+
+impl LogStatusFilter {
+    fn matches(self, log: &FragmentLog) -> bool {
+        match self {
+            LogStatusFilter::Pending => log.is_pending(),
+            LogStatusFilter::InBlock => log.is_in_a_block(),
+            LogStatusFilter::Rejected => log.is_rejected(),
+        }
+    }
+}
+
+impl LogOriginFilter {
+    fn matches(self, origin: &FragmentOrigin) -> bool {
+        matches!(
+            (self, origin),
+            (LogOriginFilter::Rest, FragmentOrigin::Rest)
+                | (LogOriginFilter::Network, FragmentOrigin::Network)
+        )
+    }
+}
+
+impl LogsFilter {
+    pub fn matches(&self, log: &FragmentLog) -> bool {
+        self.status.map_or(true, |status| status.matches(log))
+            && self
+                .origin
+                .map_or(true, |origin| origin.matches(log.received_from()))
+    }
+}
+
 pub struct Logs {
     entries: LruCache<Hash, (FragmentLog, Option<BlockDate>)>,
+    gc_policy: LogGcPolicy,
+    evicted: usize,
 }
 
 impl Logs {
     pub fn new(max_entries: usize) -> Self {
         Logs {
             entries: LruCache::new(max_entries),
+            gc_policy: LogGcPolicy::default(),
+            evicted: 0,
         }
     }
 
+    pub fn set_gc_policy(&mut self, gc_policy: LogGcPolicy) {
+        self.gc_policy = gc_policy;
+    }
+
+    /// Returns the number of logs evicted since the last call, either because
+    /// the logs were full or by the garbage collection.
+    pub fn take_evicted(&mut self) -> usize {
+        std::mem::replace(&mut self.evicted, 0)
+    }
+
     pub fn exists(&self, fragment_id: FragmentId) -> bool {
         let fragment_id: Hash = fragment_id.into();
         self.entries.contains(&fragment_id)
@@ -36,7 +123,7 @@ impl Logs {
         if self.entries.contains(&fragment_id) {
             false
         } else {
-            self.entries.put(fragment_id, (log, None));
+            self.put(fragment_id, (log, None));
             true
         }
     }
@@ -78,7 +165,7 @@ impl Logs {
                 // state transition.
                 let mut entry = FragmentLog::new(fragment_id.into_hash(), FragmentOrigin::Network);
                 entry.modify(status);
-                self.entries.put(fragment_id, (entry, Some(ledger_date)));
+                self.put(fragment_id, (entry, Some(ledger_date)));
             }
         }
     }
@@ -115,6 +202,56 @@ impl Logs {
         self.entries.iter().map(|(_, (log, _date))| log)
     }
 
+    /// Removes the logs of processed fragments exceeding the limits of the
+    /// garbage collection policy. `current_date` is the date of the tip.
+    pub fn gc(&mut self, current_date: BlockDate) {
+        if self.gc_policy.is_disabled() {
+            return;
+        }
+        let min_epoch = self
+            .gc_policy
+            .max_age_epochs
+            .map(|max_age| current_date.epoch().saturating_sub(max_age));
+
+        let mut in_block = 0;
+        let mut rejected = 0;
+        let mut to_remove = Vec::new();
+        // iterating in most-recently used order, the logs exceeding the limits
+        // are the least recently updated ones
+        for (fragment_id, (log, date)) in self.entries.iter() {
+            let (count, max_entries) = match log.status() {
+                FragmentStatus::Pending => continue,
+                FragmentStatus::InABlock { .. } => {
+                    in_block += 1;
+                    (in_block, self.gc_policy.max_in_block_entries)
+                }
+                FragmentStatus::Rejected { .. } => {
+                    rejected += 1;
+                    (rejected, self.gc_policy.max_rejected_entries)
+                }
+            };
+            let too_old = match (min_epoch, date) {
+                (Some(min_epoch), Some(date)) => date.epoch() < min_epoch,
+                _ => false,
+            };
+            if too_old || max_entries.map_or(false, |max_entries| count > max_entries) {
+                to_remove.push(*fragment_id);
+            }
+        }
+
+        self.evicted += to_remove.len();
+        for fragment_id in to_remove {
+            self.entries.pop(&fragment_id);
+        }
+    }
+
+    fn put(&mut self, fragment_id: Hash, entry: (FragmentLog, Option<BlockDate>)) {
+        if self.entries.len() == self.entries.cap() && !self.entries.contains(&fragment_id) {
+            self.evicted += 1;
+        }
+        self.entries.put(fragment_id, entry);
+    }
+
     pub fn remove_logs_after_date(&mut self, target_date: BlockDate) {
         let mut to_remove = Vec::new();
         for (_, (log, date)) in self.entries.iter() {
@@ -137,3 +274,109 @@ impl Logs {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn in_a_block(epoch: u32) -> FragmentStatus {
+        FragmentStatus::InABlock {
+            date: BlockDate::new(epoch, 0),
+            block: Hash::from([0; 32]),
+        }
+    }
+
+    #[test]
+    fn gc_removes_old_logs() {
+        let mut logs = Logs::new(10);
+        logs.set_gc_policy(LogGcPolicy {
+            max_age_epochs: Some(2),
+            ..Default::default()
+        });
+        for (seed, epoch) in [(1, 1), (2, 3), (3, 5)].iter() {
+            logs.modify(
+                Hash::from([*seed; 32]).into_hash(),
+                in_a_block(*epoch),
+                BlockDate::new(*epoch, 0),
+            );
+        }
+
+        logs.gc(BlockDate::new(5, 0));
+
+        assert_eq!(logs.logs().count(), 2);
+        assert_eq!(logs.take_evicted(), 1);
+        assert_eq!(logs.take_evicted(), 0);
+    }
+
+    #[test]
+    fn gc_keeps_most_recent_logs_per_status() {
+        let mut logs = Logs::new(10);
+        logs.set_gc_policy(LogGcPolicy {
+            max_rejected_entries: Some(1),
+            ..Default::default()
+        });
+        for seed in 1..=3 {
+            let status = FragmentStatus::Rejected {
+                reason: "invalid".to_owned(),
+            };
+            logs.modify(
+                Hash::from([seed; 32]).into_hash(),
+                status,
+                BlockDate::new(0, 0),
+            );
+        }
+        logs.modify(
+            Hash::from([4; 32]).into_hash(),
+            in_a_block(0),
+            BlockDate::new(0, 0),
+        );
+
+        logs.gc(BlockDate::new(0, 0));
+
+        let remaining: Vec<_> = logs.logs().map(|log| *log.fragment_id()).collect();
+        assert_eq!(remaining.len(), 2);
+        assert!(remaining.contains(&Hash::from([3; 32])));
+        assert!(remaining.contains(&Hash::from([4; 32])));
+    }
+
+    #[test]
+    fn filter_selects_logs_by_status_and_origin() {
+        let pending_rest = FragmentLog::new(Hash::from([1; 32]).into_hash(), FragmentOrigin::Rest);
+        let mut rejected_network =
+            FragmentLog::new(Hash::from([2; 32]).into_hash(), FragmentOrigin::Network);
+        let mut in_block_rest =
+            FragmentLog::new(Hash::from([3; 32]).into_hash(), FragmentOrigin::Rest);
+        rejected_network.modify(FragmentStatus::Rejected {
+            reason: "invalid".to_owned(),
+        });
+        in_block_rest.modify(in_a_block(0));
+        let logs = [pending_rest, rejected_network, in_block_rest];
+        let select = |filter: LogsFilter| {
+            logs.iter()
+                .filter(|log| filter.matches(log))
+                .map(|log| *log.fragment_id())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(select(LogsFilter::default()).len(), 3);
+        assert_eq!(
+            select(LogsFilter {
+                status: Some(LogStatusFilter::Rejected),
+                origin: None,
+            }),
+            vec![Hash::from([2; 32])]
+        );
+        assert_eq!(
+            select(LogsFilter {
+                status: None,
+                origin: Some(LogOriginFilter::Rest),
+            }),
+            vec![Hash::from([1; 32]), Hash::from([3; 32])]
+        );
+        assert!(select(LogsFilter {
+            status: Some(LogStatusFilter::InBlock),
+            origin: Some(LogOriginFilter::Network),
+        })
+        .is_empty());
+    }
+}
@@ -1,12 +1,16 @@
+mod conflicts;
 mod entry;
 mod logs;
 mod pool;
 mod process;
 pub mod selection;
+mod snapshot;
+mod validity;
 
 pub use self::entry::PoolEntry;
-pub use self::logs::Logs;
+pub use self::logs::{LogGcPolicy, Logs, LogsFilter};
 pub use self::pool::Pools;
 pub use self::process::Process;
+pub use self::validity::ValidityWindow;
 
 pub use crate::blockcfg::{Fragment, FragmentId};
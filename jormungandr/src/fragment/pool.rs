@@ -1,27 +1,33 @@
 use crate::{
-    blockcfg::{ApplyBlockLedger, LedgerParameters},
+    blockcfg::{ApplyBlockLedger, HeaderHash, LedgerParameters},
     fragment::{
+        conflicts::ConflictDetector,
         selection::{FragmentSelectionAlgorithm, FragmentSelectionAlgorithmParams, OldestFirst},
-        Fragment, FragmentId, Logs,
+        Fragment, FragmentId, Logs, ValidityWindow,
     },
     intercom::{NetworkMsg, PropagateMsg},
+    metrics::PoolOccupancy,
     utils::async_msg::MessageBox,
 };
 use chain_core::property::Fragment as _;
-use chain_impl_mockchain::{block::BlockDate, fragment::Contents, transaction::Transaction};
+use chain_impl_mockchain::{
+    account, block::BlockDate, fragment::Contents, transaction::Transaction,
+};
 use futures::channel::mpsc::SendError;
 use futures::sink::SinkExt;
 use jormungandr_lib::{
     interfaces::{
-        BlockDate as BlockDateDto, FragmentLog, FragmentOrigin, FragmentRejectionReason,
-        FragmentStatus, FragmentsProcessingSummary, PersistentFragmentLog, RejectedFragmentInfo,
+        BlockDate as BlockDateDto, ExpiredFragment, FragmentConflict, FragmentLog, FragmentOrigin,
+        FragmentRejectionReason, FragmentStatus, FragmentsProcessingSummary, PersistentFragmentLog,
+        RejectedFragmentInfo,
     },
     time::SecondsSinceUnixEpoch,
 };
 use thiserror::Error;
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::mem;
+use std::net::SocketAddr;
 
 use tokio::fs::File;
 use tokio::io::{AsyncWriteExt, BufWriter};
@@ -32,12 +38,21 @@ use tokio::io::{AsyncWriteExt, BufWriter};
 // Its main purpose is to avoid unnecessary flushing while processing a single batch of fragments.
 const DEFAULT_BUF_SIZE: usize = 128 * 1024; // 128 KiB
 
+// Number of the most recently expired fragments kept around for diagnostics.
+const RECENTLY_EXPIRED_CAPACITY: usize = 1024;
+
 pub struct Pools {
     logs: Logs,
     pools: Vec<internal::Pool>,
     network_msg_box: MessageBox<NetworkMsg>,
     persistent_log: Option<BufWriter<File>>,
     last_block_date: BlockDate,
+    recently_expired: VecDeque<ExpiredFragment>,
+    conflicts: ConflictDetector,
+    validity_window: Option<ValidityWindow>,
+    /// fragments rejected by the ledger while building blocks since the last
+    /// call to `take_rejected`
+    rejected: usize,
 }
 
 #[derive(Debug, Error)]
@@ -67,6 +82,10 @@ impl Pools {
             persistent_log: persistent_log
                 .map(|file| BufWriter::with_capacity(DEFAULT_BUF_SIZE, file)),
             last_block_date: BlockDate::first(),
+            recently_expired: VecDeque::with_capacity(RECENTLY_EXPIRED_CAPACITY),
+            conflicts: ConflictDetector::new(max_entries * n_pools),
+            validity_window: None,
+            rejected: 0,
         }
     }
 
@@ -80,6 +99,11 @@ impl Pools {
         self.persistent_log = Some(BufWriter::with_capacity(DEFAULT_BUF_SIZE, file));
     }
 
+    /// Rejects the incoming fragments whose validity is out of the given window.
+    pub fn set_validity_window(&mut self, validity_window: ValidityWindow) {
+        self.validity_window = Some(validity_window);
+    }
+
     /// Synchronizes the persistent log file contents and metadata
     /// to the file system and closes the file.
     pub async fn close_persistent_log(&mut self) {
@@ -93,6 +117,40 @@ impl Pools {
         }
     }
 
+    /// Sets the hash of the block 0, against which the account witnesses of the
+    /// incoming fragments are checked for conflicts.
+    pub fn set_block0_hash(&mut self, block0_hash: HeaderHash) {
+        self.conflicts.set_block0_hash(block0_hash);
+    }
+
+    /// Checks the incoming fragments for UTxOs and account spending counters already
+    /// spent by other fragments known to the node and returns the number of detected
+    /// conflicts. `spending_counter` gives the counter of an account on the tip. This is
+    /// purely informational, conflicting fragments are still left for the ledger to reject.
+    pub fn detect_conflicts<F>(
+        &mut self,
+        origin: FragmentOrigin,
+        peer: Option<SocketAddr>,
+        fragments: &[Fragment],
+        spending_counter: F,
+    ) -> usize
+    where
+        F: Fn(&account::Identifier) -> Option<u32>,
+    {
+        fragments
+            .iter()
+            .map(|fragment| {
+                self.conflicts
+                    .check(fragment, origin, peer, &self.logs, &spending_counter)
+            })
+            .sum()
+    }
+
+    /// Conflicting fragments recently detected, the oldest first.
+    pub fn recent_conflicts(&self) -> Vec<FragmentConflict> {
+        self.conflicts.recent()
+    }
+
     /// Returns number of registered fragments. Setting `fail_fast` to `true` will force this
     /// method to reject all fragments after the first invalid fragments was met.
     pub async fn insert_and_propagate_all(
@@ -134,6 +192,12 @@ impl Pools {
                 continue;
             }
 
+            if let Some(reason) = self.check_validity_window(&fragment) {
+                tracing::debug!(?reason, "fragment validity is out of the accepted window");
+                rejected.push(RejectedFragmentInfo { id, reason });
+                continue;
+            }
+
             if !is_fragment_valid(&fragment) {
                 rejected.push(RejectedFragmentInfo {
                     id,
@@ -239,6 +303,67 @@ impl Pools {
         Ok(FragmentsProcessingSummary { accepted, rejected })
     }
 
+    fn check_validity_window(&self, fragment: &Fragment) -> Option<FragmentRejectionReason> {
+        let validity_window = self.validity_window.as_ref()?;
+        let valid_until = get_transaction_expiry_date(fragment)?;
+        validity_window
+            .check(valid_until, self.last_block_date)
+            .err()
+    }
+
+    /// Announces the fragments still waiting in the pools to the network again, so that
+    /// fragments which missed their initial propagation still have a chance to be
+    /// included in a block. Returns the number of propagated fragments.
+    pub async fn rebroadcast_pending(&mut self) -> Result<usize, Error> {
+        let pending = self.pending_fragments();
+
+        let count = pending.len();
+        let mut network_msg_box = self.network_msg_box.clone();
+        for fragment in pending {
+            network_msg_box
+                .send(NetworkMsg::Propagate(PropagateMsg::Fragment(fragment)))
+                .await
+                .map_err(Error::CannotPropagate)?;
+        }
+        Ok(count)
+    }
+
+    /// Unexpired fragments still waiting in the pools, each listed once and the
+    /// oldest first so that dependent fragments (e.g. consecutive spendings from
+    /// the same account) keep their order.
+    pub fn pending_fragments(&self) -> Vec<Fragment> {
+        let mut seen = HashSet::new();
+        let mut pending = Vec::new();
+        for pool in &self.pools {
+            for fragment in pool.fragments_oldest_first() {
+                if !check_fragment_expired(fragment, self.last_block_date)
+                    && seen.insert(fragment.id())
+                {
+                    pending.push(fragment.clone());
+                }
+            }
+        }
+        pending
+    }
+
+    /// Sets the date of the current tip, against which the expiry dates of the
+    /// incoming fragments are checked until the next block is processed.
+    pub fn set_tip_date(&mut self, block_date: BlockDate) {
+        if self.last_block_date < block_date {
+            self.last_block_date = block_date;
+        }
+    }
+
+    /// Puts back fragments saved from the pools of a previous run of the node.
+    /// The fragments go through the same checks as newly received ones.
+    pub async fn restore(
+        &mut self,
+        fragments: Vec<Fragment>,
+    ) -> Result<FragmentsProcessingSummary, Error> {
+        self.insert_and_propagate_all(FragmentOrigin::Rest, fragments, false)
+            .await
+    }
+
     pub fn remove_added_to_block(&mut self, fragment_ids: Vec<FragmentId>, status: FragmentStatus) {
         let date = if let FragmentStatus::InABlock { date, .. } = status {
             date
@@ -262,9 +387,15 @@ impl Pools {
         soft_deadline_future: futures::channel::oneshot::Receiver<()>,
         hard_deadline_future: futures::channel::oneshot::Receiver<()>,
     ) -> (Contents, ApplyBlockLedger) {
-        let Pools { logs, pools, .. } = self;
+        let Pools {
+            logs,
+            pools,
+            rejected,
+            ..
+        } = self;
         let pool = &mut pools[pool_idx];
-        match selection_alg {
+        let entries_before = pool.len();
+        let (contents, ledger) = match selection_alg {
             FragmentSelectionAlgorithmParams::OldestFirst => {
                 let mut selection_alg = OldestFirst::new();
                 selection_alg
@@ -278,7 +409,28 @@ impl Pools {
                     )
                     .await
             }
-        }
+        };
+        // the fragments neither selected nor returned to the pool were rejected
+        *rejected += entries_before - pool.len() - contents.iter().count();
+        (contents, ledger)
+    }
+
+    /// Returns the number of fragments dropped while building blocks since the
+    /// last call, because they were no longer accepted by the ledger.
+    pub fn take_rejected(&mut self) -> usize {
+        mem::replace(&mut self.rejected, 0)
+    }
+
+    /// Entries and size of the fullest pool.
+    pub fn occupancy(&self) -> PoolOccupancy {
+        self.pools
+            .iter()
+            .map(|pool| PoolOccupancy {
+                entries: pool.len(),
+                size_bytes: pool.size_bytes(),
+            })
+            .max_by_key(|occupancy| occupancy.entries)
+            .unwrap_or_default()
     }
 
     // Remove from logs fragments that were confirmed (or rejected) in a branch
@@ -286,17 +438,20 @@ impl Pools {
         self.logs.remove_logs_after_date(branch_date)
     }
 
-    pub fn remove_expired_txs(&mut self, block_date: BlockDate) {
-        if self.last_block_date < block_date {
-            self.last_block_date = block_date;
-        }
+    /// Removes fragments which are no longer valid at `block_date` from all pools
+    /// and returns the number of distinct fragments dropped.
+    pub fn remove_expired_txs(&mut self, block_date: BlockDate) -> usize {
+        self.set_tip_date(block_date);
         let mut fragment_ids = HashSet::new();
         for pool in &mut self.pools {
-            let fragment_ids_update = pool.remove_expired_txs(block_date);
-            for id in fragment_ids_update {
-                fragment_ids.insert(id);
+            let expired = pool.remove_expired_txs(block_date);
+            for (id, valid_until) in expired {
+                if fragment_ids.insert(id) {
+                    self.record_expired(id, valid_until, block_date);
+                }
             }
         }
+        let expired_count = fragment_ids.len();
         self.logs.modify_all(
             fragment_ids,
             FragmentStatus::Rejected {
@@ -304,6 +459,23 @@ impl Pools {
             },
             block_date.into(),
         );
+        expired_count
+    }
+
+    /// Fragments recently dropped because of their expiry date, the oldest first.
+    pub fn recently_expired(&self) -> Vec<ExpiredFragment> {
+        self.recently_expired.iter().cloned().collect()
+    }
+
+    fn record_expired(&mut self, id: FragmentId, valid_until: BlockDate, expired_at: BlockDate) {
+        if self.recently_expired.len() == RECENTLY_EXPIRED_CAPACITY {
+            self.recently_expired.pop_front();
+        }
+        self.recently_expired.push_back(ExpiredFragment {
+            fragment_id: id.into(),
+            valid_until: valid_until.into(),
+            expired_at: expired_at.into(),
+        });
     }
 }
 
@@ -319,9 +491,11 @@ fn is_fragment_valid(fragment: &Fragment) -> bool {
         Fragment::PoolRegistration(ref tx) => is_transaction_valid(tx),
         Fragment::PoolRetirement(ref tx) => is_transaction_valid(tx),
         Fragment::PoolUpdate(ref tx) => is_transaction_valid(tx),
+        // only accepted by the nodes built for the tests of the changes of the
+        // BFT leaders, the proposer and the voters are checked by the ledger
+        Fragment::UpdateProposal(_) => cfg!(feature = "update-proposals"),
+        Fragment::UpdateVote(_) => cfg!(feature = "update-proposals"),
         // vote stuff
-        Fragment::UpdateProposal(_) => false, // TODO: enable when ready
-        Fragment::UpdateVote(_) => false,     // TODO: enable when ready
         Fragment::VotePlan(ref tx) => is_transaction_valid(tx),
         Fragment::VoteCast(ref tx) => is_transaction_valid(tx),
         Fragment::VoteTally(ref tx) => is_transaction_valid(tx),
@@ -483,6 +657,15 @@ pub(super) mod internal {
         fn contains(&self, key: &K) -> bool {
             self.index.contains_key(&IndexedDequeueKeyRef(key))
         }
+
+        fn values_oldest_first(&self) -> impl Iterator<Item = &V> {
+            let mut cursor = self.tail as *const IndexedDequeueEntry<K, V>;
+            std::iter::from_fn(move || {
+                let entry = unsafe { cursor.as_ref() }?;
+                cursor = entry.prev;
+                Some(&entry.value)
+            })
+        }
     }
 
     unsafe impl<K: Send, V: Send> Send for IndexedDeqeue<K, V> {}
@@ -527,6 +710,7 @@ pub(super) mod internal {
         entries: IndexedDeqeue<FragmentId, Fragment>,
         timeout_queue: BTreeSet<TimeoutQueueItem>,
         max_entries: usize,
+        size_bytes: usize,
     }
 
     impl Pool {
@@ -537,9 +721,19 @@ pub(super) mod internal {
                 // out of their order in a queue. BinaryHeap does not allow that.
                 timeout_queue: BTreeSet::new(),
                 max_entries,
+                size_bytes: 0,
             }
         }
 
+        pub fn len(&self) -> usize {
+            self.entries.len()
+        }
+
+        /// Sum of the serialized sizes of the fragments in the pool
+        pub fn size_bytes(&self) -> usize {
+            self.size_bytes
+        }
+
         /// Returns clones of registered fragments
         pub fn insert_all(
             &mut self,
@@ -554,6 +748,7 @@ pub(super) mod internal {
                         false
                     } else {
                         self.timeout_queue_insert(fragment);
+                        self.size_bytes += fragment_size(fragment);
                         self.entries.push_front(fragment_id, fragment.clone());
                         true
                     }
@@ -562,11 +757,17 @@ pub(super) mod internal {
                 .collect()
         }
 
+        /// Fragments currently in the pool, in the order they were inserted
+        pub fn fragments_oldest_first(&self) -> impl Iterator<Item = &Fragment> {
+            self.entries.values_oldest_first()
+        }
+
         pub fn remove_all<'a>(&mut self, fragment_ids: impl IntoIterator<Item = &'a FragmentId>) {
             for fragment_id in fragment_ids {
                 let maybe_fragment = self.entries.remove(fragment_id);
                 if let Some(fragment) = maybe_fragment {
                     self.timeout_queue_remove(&fragment);
+                    self.size_bytes -= fragment_size(&fragment);
                 }
             }
         }
@@ -574,12 +775,14 @@ pub(super) mod internal {
         pub fn remove_oldest(&mut self) -> Option<Fragment> {
             let fragment = self.entries.pop_back().map(|(_, value)| value)?;
             self.timeout_queue_remove(&fragment);
+            self.size_bytes -= fragment_size(&fragment);
             Some(fragment)
         }
 
         pub fn return_to_pool(&mut self, fragments: impl IntoIterator<Item = Fragment>) {
             for fragment in fragments.into_iter() {
                 self.timeout_queue_insert(&fragment);
+                self.size_bytes += fragment_size(&fragment);
                 self.entries.push_back(fragment.id(), fragment);
             }
         }
@@ -604,7 +807,11 @@ pub(super) mod internal {
             }
         }
 
-        pub fn remove_expired_txs(&mut self, block_date: BlockDate) -> Vec<FragmentId> {
+        /// Returns ids of the removed fragments together with their expiry dates.
+        pub fn remove_expired_txs(
+            &mut self,
+            block_date: BlockDate,
+        ) -> Vec<(FragmentId, BlockDate)> {
             let to_remove: Vec<_> = self
                 .timeout_queue
                 .iter()
@@ -613,9 +820,14 @@ pub(super) mod internal {
                 .collect();
             for item in &to_remove {
                 self.timeout_queue.remove(item);
-                self.entries.remove(&item.id);
+                if let Some(fragment) = self.entries.remove(&item.id) {
+                    self.size_bytes -= fragment_size(&fragment);
+                }
             }
-            to_remove.into_iter().map(|x| x.id).collect()
+            to_remove
+                .into_iter()
+                .map(|x| (x.id, x.valid_until))
+                .collect()
             // TODO convert to something like this when .first() and .pop_first() are stabilized. This does not have unnecessary clones.
             // https://github.com/rust-lang/rust/issues/62924
             // loop {
@@ -633,6 +845,10 @@ pub(super) mod internal {
         }
     }
 
+    fn fragment_size(fragment: &Fragment) -> usize {
+        fragment.to_raw().size_bytes_plus_size()
+    }
+
     #[cfg(test)]
     mod tests {
         use super::*;
@@ -670,6 +886,21 @@ pub(super) mod internal {
             assert!(pool.remove_oldest().is_none());
         }
 
+        #[quickcheck]
+        fn size_follows_the_entries(fragments_in: (Fragment, Fragment)) {
+            let (fragment1, fragment2) = fragments_in;
+            let size1 = fragment_size(&fragment1);
+            let size2 = fragment_size(&fragment2);
+            let mut pool = Pool::new(2);
+            pool.insert_all(vec![fragment1.clone(), fragment2.clone()]);
+            assert_eq!(pool.size_bytes(), size1 + size2);
+            let oldest = pool.remove_oldest().unwrap();
+            assert_eq!(pool.size_bytes(), size2);
+            pool.return_to_pool(vec![oldest]);
+            pool.remove_all(&[fragment2.id()]);
+            assert_eq!(pool.size_bytes(), size1);
+        }
+
         #[test]
         fn expired_transactions_are_removed() {
             let mut pool = Pool::new(1);
@@ -710,6 +941,98 @@ pub(super) mod internal {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chain_impl_mockchain::transaction::TxBuilder;
+
+    fn transaction_valid_until(valid_until: BlockDate) -> Fragment {
+        Fragment::Transaction(
+            TxBuilder::new()
+                .set_nopayload()
+                .set_expiry_date(valid_until)
+                .set_ios(&[], &[])
+                .set_witnesses(&[])
+                .set_payload_auth(&()),
+        )
+    }
+
+    #[test]
+    fn expired_fragments_are_counted_once_and_recorded() {
+        let (fake_msgbox, _) = crate::async_msg::channel(1);
+        let mut pools = Pools::new(10, 2, Logs::new(10), fake_msgbox, None);
+        let valid_until = BlockDate {
+            epoch: 0,
+            slot_id: 1,
+        };
+        let tx = transaction_valid_until(valid_until);
+        for pool in &mut pools.pools {
+            pool.insert_all(vec![tx.clone()]);
+        }
+
+        assert_eq!(pools.remove_expired_txs(valid_until), 0);
+        assert!(pools.recently_expired().is_empty());
+
+        let expired_at = BlockDate {
+            epoch: 0,
+            slot_id: 2,
+        };
+        assert_eq!(pools.remove_expired_txs(expired_at), 1);
+        assert_eq!(
+            pools.recently_expired(),
+            vec![ExpiredFragment {
+                fragment_id: tx.id().into(),
+                valid_until: valid_until.into(),
+                expired_at: expired_at.into(),
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn pending_fragments_are_rebroadcast_once() {
+        use futures::{FutureExt, StreamExt};
+
+        let (msgbox, mut queue) = crate::async_msg::channel(10);
+        let mut pools = Pools::new(10, 2, Logs::new(10), msgbox, None);
+        let pending = transaction_valid_until(BlockDate {
+            epoch: 1,
+            slot_id: 0,
+        });
+        let expired = transaction_valid_until(BlockDate {
+            epoch: 0,
+            slot_id: 1,
+        });
+        for pool in &mut pools.pools {
+            pool.insert_all(vec![pending.clone(), expired.clone()]);
+        }
+        pools.set_tip_date(BlockDate {
+            epoch: 0,
+            slot_id: 2,
+        });
+
+        assert_eq!(pools.rebroadcast_pending().await.unwrap(), 1);
+        match queue.next().await {
+            Some(NetworkMsg::Propagate(PropagateMsg::Fragment(fragment))) => {
+                assert_eq!(fragment.id(), pending.id())
+            }
+            other => panic!("unexpected message {:?}", other),
+        }
+        assert!(queue.next().now_or_never().is_none());
+    }
+
+    #[test]
+    fn only_the_most_recently_expired_fragments_are_kept() {
+        let (fake_msgbox, _) = crate::async_msg::channel(1);
+        let mut pools = Pools::new(0, 1, Logs::new(1), fake_msgbox, None);
+        let date = BlockDate::first();
+        for i in 0..=RECENTLY_EXPIRED_CAPACITY {
+            pools.record_expired(FragmentId::hash_bytes(&i.to_be_bytes()), date, date);
+        }
+
+        let expired = pools.recently_expired();
+        assert_eq!(expired.len(), RECENTLY_EXPIRED_CAPACITY);
+        assert_eq!(
+            expired[0].fragment_id,
+            FragmentId::hash_bytes(&1usize.to_be_bytes()).into()
+        );
+    }
 
     #[test]
     fn correct_pools_number() {
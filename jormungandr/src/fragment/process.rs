@@ -1,7 +1,9 @@
 use crate::{
-    fragment::{Logs, Pools},
+    blockcfg::{BlockDate, HeaderHash},
+    blockchain::Tip,
+    fragment::{snapshot, LogGcPolicy, Logs, Pools, ValidityWindow},
     intercom::{NetworkMsg, TransactionMsg},
-    metrics::{Metrics, MetricsBackend},
+    metrics::{Metrics, MetricsBackend, PoolEviction},
     utils::{
         async_msg::{MessageBox, MessageQueue},
         task::TokioServiceInfo,
@@ -17,12 +19,20 @@ use chrono::{Duration, DurationRound, Utc};
 use futures::{future, TryFutureExt};
 use thiserror::Error;
 use tokio_stream::StreamExt;
+use tokio_util::sync::CancellationToken;
 use tracing::{span, Level};
 use tracing_futures::Instrument;
 
+// upper bound on the time spent writing the mempool snapshot on shutdown
+const SNAPSHOT_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
 pub struct Process {
     pool_max_entries: usize,
     logs_max_entries: usize,
+    log_gc_policy: LogGcPolicy,
+    rebroadcast_interval: Option<std::time::Duration>,
+    pool_snapshot: Option<PathBuf>,
+    validity_window: Option<ValidityWindow>,
     network_msg_box: MessageBox<NetworkMsg>,
 }
 
@@ -38,15 +48,29 @@ impl Process {
     pub fn new(
         pool_max_entries: usize,
         logs_max_entries: usize,
+        log_gc_policy: LogGcPolicy,
+        rebroadcast_interval: Option<std::time::Duration>,
+        pool_snapshot: Option<PathBuf>,
+        validity_window: Option<ValidityWindow>,
         network_msg_box: MessageBox<NetworkMsg>,
     ) -> Self {
         Process {
             pool_max_entries,
             logs_max_entries,
+            log_gc_policy,
+            rebroadcast_interval,
+            pool_snapshot,
+            validity_window,
             network_msg_box,
         }
     }
 
+    /// Runs the fragment task until either the input queue is closed or
+    /// `shutdown` is cancelled. `tip_date` is the date of the current tip,
+    /// against which incoming fragments are checked until the next block.
+    /// The ledger of `blockchain_tip` gives the spending counters of the
+    /// accounts, against which incoming fragments are checked for conflicts.
+    #[allow(clippy::too_many_arguments)]
     pub async fn start<P: AsRef<Path>>(
         self,
         n_pools: usize,
@@ -54,6 +78,10 @@ impl Process {
         stats_counter: Metrics,
         mut input: MessageQueue<TransactionMsg>,
         persistent_log_dir: Option<P>,
+        tip_date: BlockDate,
+        block0_hash: HeaderHash,
+        blockchain_tip: Tip,
+        shutdown: CancellationToken,
     ) -> Result<(), Error> {
         async fn hourly_wakeup(enabled: bool) {
             if enabled {
@@ -67,6 +95,15 @@ impl Process {
             }
         }
 
+        async fn rebroadcast_tick(interval: &mut Option<tokio::time::Interval>) {
+            match interval {
+                Some(interval) => {
+                    interval.tick().await;
+                }
+                None => future::pending().await,
+            }
+        }
+
         async fn open_log_file(dir: &Path) -> Result<File, Error> {
             let mut path: PathBuf = dir.into();
             if !path.exists() {
@@ -90,26 +127,41 @@ impl Process {
                 "Having 'log_max_entries' < 'pool_max_entries' * n_pools is not recommendend. Overriding 'log_max_entries' to {}", min_logs_size
             );
         }
-        let logs = Logs::new(std::cmp::max(self.logs_max_entries, min_logs_size));
+        let mut logs = Logs::new(std::cmp::max(self.logs_max_entries, min_logs_size));
+        logs.set_gc_policy(self.log_gc_policy);
 
         let mut wakeup = Box::pin(hourly_wakeup(persistent_log_dir.is_some()));
+        // the first tick of a tokio interval fires immediately, there is nothing to
+        // rebroadcast at startup though
+        let mut rebroadcast = self
+            .rebroadcast_interval
+            .map(|period| tokio::time::interval_at(tokio::time::Instant::now() + period, period));
 
         async move {
-            let persistent_log = match &persistent_log_dir {
-                None => None,
-                Some(dir) => {
-                    let file = open_log_file(dir.as_ref()).await?;
-                    Some(file)
-                }
-            };
-
             let mut pool = Pools::new(
                 self.pool_max_entries,
                 n_pools,
                 logs,
                 self.network_msg_box,
-                persistent_log,
+                None,
             );
+            pool.set_tip_date(tip_date);
+            pool.set_block0_hash(block0_hash);
+            if let Some(validity_window) = self.validity_window {
+                pool.set_validity_window(validity_window);
+            }
+
+            // restored fragments are already recorded in the persistent log,
+            // so it is only opened afterwards
+            if let Some(path) = &self.pool_snapshot {
+                restore_snapshot(&mut pool, path).await?;
+            }
+
+            if let Some(dir) = &persistent_log_dir {
+                let file = open_log_file(dir.as_ref()).await?;
+                pool.set_persistent_log(file);
+            }
+            stats_counter.set_pool_occupancy(pool.occupancy());
 
             loop {
                 tokio::select! {
@@ -117,7 +169,7 @@ impl Process {
                         match maybe_msg {
                             None => break,
                             Some(msg) => match msg {
-                                TransactionMsg::SendTransactions { origin, fragments, fail_fast, reply_handle } => {
+                                TransactionMsg::SendTransactions { origin, peer, fragments, fail_fast, reply_handle } => {
                                     // Note that we cannot use apply_block here, since we don't have a valid context to which to apply
                                     // those blocks. one valid tx in a given context, could be invalid in another. for example
                                     // fee calculations, existence utxo / account solvency.
@@ -131,11 +183,20 @@ impl Process {
 
                                     let stats_counter = stats_counter.clone();
 
+                                    let ledger = blockchain_tip.get_ref().await.ledger();
+                                    let conflicts = pool.detect_conflicts(origin, peer, &fragments, |account| {
+                                        ledger.accounts().get_state(account).ok().map(|state| state.get_counter())
+                                    });
+                                    stats_counter.add_tx_conflict_cnt(conflicts);
+
                                     let summary = pool
                             .insert_and_propagate_all(origin, fragments, fail_fast)
                             .await?;
 
                         stats_counter.add_tx_recv_cnt(summary.accepted.len());
+                        stats_counter.add_tx_duplicate_cnt(origin, summary.already_known());
+                        stats_counter.add_pool_evicted_cnt(PoolEviction::Full, summary.pool_overflows());
+                        stats_counter.set_pool_occupancy(pool.occupancy());
 
                         reply_handle.reply_ok(summary);
                                 }
@@ -146,12 +207,32 @@ impl Process {
                                         fragment_ids
                                     );
                                     pool.remove_added_to_block(fragment_ids, status);
-                                    pool.remove_expired_txs(block_date);
+                                    let expired = pool.remove_expired_txs(block_date);
+                                    stats_counter.add_tx_expired_cnt(expired);
+                                    stats_counter.add_pool_evicted_cnt(PoolEviction::Expired, expired);
+                                    stats_counter.set_pool_occupancy(pool.occupancy());
+                                    // also accounts for the logs evicted on overflow since the last block
+                                    pool.logs().gc(block_date.into());
+                                    stats_counter.add_fragment_log_evicted_cnt(pool.logs().take_evicted());
                                 }
-                                TransactionMsg::GetLogs(reply_handle) => {
-                                    let logs = pool.logs().logs().cloned().collect();
+                                TransactionMsg::GetLogs(filter, reply_handle) => {
+                                    let logs = pool
+                                        .logs()
+                                        .logs()
+                                        .filter(|log| filter.matches(log))
+                                        .cloned()
+                                        .collect();
                                     reply_handle.reply_ok(logs);
                                 }
+                                TransactionMsg::GetExpired(reply_handle) => {
+                                    reply_handle.reply_ok(pool.recently_expired());
+                                }
+                                TransactionMsg::GetConflicts(reply_handle) => {
+                                    reply_handle.reply_ok(pool.recent_conflicts());
+                                }
+                                TransactionMsg::GetPendingFragments(reply_handle) => {
+                                    reply_handle.reply_ok(pool.pending_fragments());
+                                }
                                 TransactionMsg::GetStatuses(fragment_ids, reply_handle) => {
                                     let mut statuses = HashMap::new();
                                     pool.logs().logs_by_ids(fragment_ids).into_iter().for_each(
@@ -183,6 +264,8 @@ impl Process {
                                             hard_deadline_future,
                                         )
                                         .await;
+                                    stats_counter.add_pool_evicted_cnt(PoolEviction::Rejected, pool.take_rejected());
+                                    stats_counter.set_pool_occupancy(pool.occupancy());
                                     reply_handle.reply_ok(contents);
                                 }
                             }
@@ -195,6 +278,21 @@ impl Process {
                         pool.set_persistent_log(file);
                         wakeup = Box::pin(hourly_wakeup(true));
                     }
+                    _ = rebroadcast_tick(&mut rebroadcast) => {
+                        let count = pool.rebroadcast_pending().await?;
+                        tracing::debug!("rebroadcast {} pending fragments", count);
+                    }
+                    _ = shutdown.cancelled() => break,
+                }
+            }
+
+            pool.close_persistent_log().await;
+            if let Some(path) = &self.pool_snapshot {
+                if tokio::time::timeout(SNAPSHOT_TIMEOUT, save_snapshot(&pool, path))
+                    .await
+                    .is_err()
+                {
+                    tracing::error!(path = %path.display(), "timed out saving the mempool snapshot");
                 }
             }
             Ok(())
@@ -203,3 +301,38 @@ impl Process {
         .await
     }
 }
+
+async fn restore_snapshot(pool: &mut Pools, path: &Path) -> Result<(), Error> {
+    let fragments = match snapshot::load(path).await {
+        Ok(fragments) => fragments,
+        Err(error) => {
+            tracing::error!(%error, path = %path.display(), "failed to load the mempool snapshot");
+            return Ok(());
+        }
+    };
+    if fragments.is_empty() {
+        return Ok(());
+    }
+    let total = fragments.len();
+    let summary = pool.restore(fragments).await?;
+    tracing::info!(
+        "restored {} of {} fragments from the mempool snapshot, {} rejected",
+        summary.accepted.len(),
+        total,
+        summary.rejected.len()
+    );
+    Ok(())
+}
+
+async fn save_snapshot(pool: &Pools, path: &Path) {
+    let fragments = pool.pending_fragments();
+    match snapshot::save(path, &fragments).await {
+        Ok(()) => tracing::info!(
+            "saved {} pending fragments to the mempool snapshot",
+            fragments.len()
+        ),
+        Err(error) => {
+            tracing::error!(%error, path = %path.display(), "failed to save the mempool snapshot")
+        }
+    }
+}
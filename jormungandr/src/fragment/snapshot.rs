@@ -0,0 +1,105 @@
+//! Snapshot of the fragments pending in the mempool, written when the node
+//! shuts down and read back when it starts again.
+//!
+//! The file uses the same encoding as the persistent fragment log, so the
+//! existing tooling for reading those logs works on snapshots as well.
+
+use crate::fragment::Fragment;
+use jormungandr_lib::{
+    interfaces::{FileFragments, FragmentLogDeserializeError, PersistentFragmentLog},
+    time::SecondsSinceUnixEpoch,
+};
+use thiserror::Error;
+
+use std::io;
+use std::path::Path;
+
+use tokio::fs;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("cannot access the mempool snapshot file")]
+    Io(#[from] io::Error),
+    #[error("cannot decode the mempool snapshot")]
+    Deserialize(#[from] FragmentLogDeserializeError),
+}
+
+/// Reads the fragments saved by the previous run of the node and removes the
+/// snapshot file, so that the same fragments are not restored twice. A missing
+/// file is not an error: there is simply nothing to restore.
+pub async fn load(path: &Path) -> Result<Vec<Fragment>, Error> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let fragments = FileFragments::from_path(path.to_path_buf())?
+        .map(|entry| entry.map(|entry| entry.fragment))
+        .collect::<Result<Vec<_>, _>>()?;
+    fs::remove_file(path).await?;
+    Ok(fragments)
+}
+
+/// Writes the given fragments to the snapshot file. The file is replaced
+/// atomically, a failure leaves the previous snapshot (if any) untouched.
+pub async fn save(path: &Path, fragments: &[Fragment]) -> Result<(), Error> {
+    use bincode::Options;
+
+    let codec = bincode::DefaultOptions::new().with_fixint_encoding();
+    let time = SecondsSinceUnixEpoch::now();
+    let mut serialized = Vec::new();
+    for fragment in fragments {
+        let entry = PersistentFragmentLog {
+            time,
+            fragment: fragment.clone(),
+        };
+        // same as for the persistent log, the fragment is serialized as a byte array
+        serialized.extend(codec.serialize(&entry).unwrap());
+    }
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).await?;
+    }
+    let tmp_path = path.with_extension("tmp");
+    fs::write(&tmp_path, serialized).await?;
+    fs::rename(&tmp_path, path).await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quickcheck::{Arbitrary, StdThreadGen};
+
+    fn fragments(count: usize) -> Vec<Fragment> {
+        let mut gen = StdThreadGen::new(10);
+        (0..count).map(|_| Fragment::arbitrary(&mut gen)).collect()
+    }
+
+    #[tokio::test]
+    async fn saved_fragments_are_restored_once() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("snapshots").join("mempool");
+        let fragments = fragments(5);
+
+        save(&path, &fragments).await.unwrap();
+        assert!(path.exists());
+        assert!(!path.with_extension("tmp").exists());
+
+        let restored = load(&path).await.unwrap();
+        assert_eq!(
+            fragments.iter().map(Fragment::hash).collect::<Vec<_>>(),
+            restored.iter().map(Fragment::hash).collect::<Vec<_>>()
+        );
+        assert!(!path.exists());
+        assert!(load(&path).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn saving_replaces_the_previous_snapshot() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("mempool");
+
+        save(&path, &fragments(3)).await.unwrap();
+        save(&path, &[]).await.unwrap();
+        assert!(load(&path).await.unwrap().is_empty());
+    }
+}
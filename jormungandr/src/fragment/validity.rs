@@ -0,0 +1,81 @@
+use chain_impl_mockchain::block::BlockDate;
+use jormungandr_lib::interfaces::FragmentRejectionReason;
+
+/// Mempool policy on the validity of incoming fragments: fragments which stay
+/// valid for too long would occupy the pool for that long, fragments about to
+/// expire are unlikely to make it into a block in time.
+#[derive(Debug, Clone, Copy)]
+pub struct ValidityWindow {
+    max_epochs_ahead: Option<u32>,
+    min_slots_before_expiry: Option<u32>,
+    slots_per_epoch: u32,
+}
+
+impl ValidityWindow {
+    pub fn new(
+        max_epochs_ahead: Option<u32>,
+        min_slots_before_expiry: Option<u32>,
+        slots_per_epoch: u32,
+    ) -> Self {
+        ValidityWindow {
+            max_epochs_ahead,
+            min_slots_before_expiry,
+            slots_per_epoch,
+        }
+    }
+
+    /// Checks the expiry date of a fragment against the date of the current tip.
+    pub fn check(
+        &self,
+        valid_until: BlockDate,
+        current: BlockDate,
+    ) -> Result<(), FragmentRejectionReason> {
+        if let Some(max_epochs_ahead) = self.max_epochs_ahead {
+            if valid_until.epoch > current.epoch.saturating_add(max_epochs_ahead) {
+                return Err(FragmentRejectionReason::FragmentExpiryTooFar);
+            }
+        }
+        if let Some(min_slots_before_expiry) = self.min_slots_before_expiry {
+            let remaining = self
+                .absolute_slot(valid_until)
+                .saturating_sub(self.absolute_slot(current));
+            if remaining < u64::from(min_slots_before_expiry) {
+                return Err(FragmentRejectionReason::FragmentExpiresTooSoon);
+            }
+        }
+        Ok(())
+    }
+
+    fn absolute_slot(&self, date: BlockDate) -> u64 {
+        u64::from(date.epoch) * u64::from(self.slots_per_epoch) + u64::from(date.slot_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(epoch: u32, slot_id: u32) -> BlockDate {
+        BlockDate { epoch, slot_id }
+    }
+
+    #[test]
+    fn rejects_fragments_valid_too_far_ahead() {
+        let window = ValidityWindow::new(Some(2), None, 100);
+        assert_eq!(window.check(date(12, 99), date(10, 50)), Ok(()));
+        assert_eq!(
+            window.check(date(13, 0), date(10, 50)),
+            Err(FragmentRejectionReason::FragmentExpiryTooFar)
+        );
+    }
+
+    #[test]
+    fn rejects_fragments_expiring_too_soon() {
+        let window = ValidityWindow::new(None, Some(10), 100);
+        assert_eq!(window.check(date(11, 5), date(10, 95)), Ok(()));
+        assert_eq!(
+            window.check(date(11, 4), date(10, 95)),
+            Err(FragmentRejectionReason::FragmentExpiresTooSoon)
+        );
+    }
+}
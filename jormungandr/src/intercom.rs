@@ -2,14 +2,15 @@ use crate::blockcfg::{
     ApplyBlockLedger, Block, Fragment, FragmentId, Header, HeaderHash, LedgerParameters,
 };
 use crate::blockchain::{Checkpoints, LeadershipBlock, StorageError};
-use crate::fragment::selection::FragmentSelectionAlgorithmParams;
+use crate::fragment::{selection::FragmentSelectionAlgorithmParams, LogsFilter};
 use crate::network::p2p::{comm::PeerInfo, Address};
 use crate::topology::{Gossips, NodeId, Peer, PeerInfo as TopologyPeerInfo, View};
 use crate::utils::async_msg::{self, MessageBox, MessageQueue};
 use chain_impl_mockchain::fragment::Contents as FragmentContents;
 use chain_network::error as net_error;
 use jormungandr_lib::interfaces::{
-    BlockDate, FragmentLog, FragmentOrigin, FragmentStatus, FragmentsProcessingSummary,
+    BlockDate, ExpiredFragment, FragmentConflict, FragmentLog, FragmentOrigin, FragmentStatus,
+    FragmentsProcessingSummary,
 };
 use poldercast::layer::Selection;
 
@@ -504,6 +505,8 @@ pub fn stream_request<T, R>(
 pub enum TransactionMsg {
     SendTransactions {
         origin: FragmentOrigin,
+        /// the peer the fragments were received from, for the network origin
+        peer: Option<std::net::SocketAddr>,
         fragments: Vec<Fragment>,
         fail_fast: bool,
         reply_handle: ReplyHandle<FragmentsProcessingSummary>,
@@ -514,7 +517,10 @@ pub enum TransactionMsg {
         chain_impl_mockchain::block::BlockDate,
     ),
     BranchSwitch(BlockDate),
-    GetLogs(ReplyHandle<Vec<FragmentLog>>),
+    GetLogs(LogsFilter, ReplyHandle<Vec<FragmentLog>>),
+    GetExpired(ReplyHandle<Vec<ExpiredFragment>>),
+    GetConflicts(ReplyHandle<Vec<FragmentConflict>>),
+    GetPendingFragments(ReplyHandle<Vec<Fragment>>),
     GetStatuses(
         Vec<FragmentId>,
         ReplyHandle<HashMap<FragmentId, FragmentStatus>>,
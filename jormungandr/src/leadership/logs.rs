@@ -1,8 +1,54 @@
 pub use jormungandr_lib::interfaces::LeadershipLogStatus;
 use jormungandr_lib::interfaces::{LeadershipLog, LeadershipLogId};
+use serde::Deserialize;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
+/// status of the leadership logs to select, see [`LogsQuery`]
+///
+/// [`LogsQuery`]: ./struct.LogsQuery.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogStatusFilter {
+    Pending,
+    Rejected,
+    Block,
+}
+
+/// selection of the leadership logs. Matching logs are ordered from the most
+/// recently scheduled one and `offset`/`limit` are applied on this order.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct LogsQuery {
+    pub status: Option<LogStatusFilter>,
+    pub epoch: Option<u32>,
+    pub offset: Option<usize>,
+    pub limit: Option<usize>,
+}
+
+impl LogStatusFilter {
+    fn matches(self, status: &LeadershipLogStatus) -> bool {
+        matches!(
+            (self, status),
+            (LogStatusFilter::Pending, LeadershipLogStatus::Pending)
+                | (
+                    LogStatusFilter::Rejected,
+                    LeadershipLogStatus::Rejected { .. }
+                )
+                | (LogStatusFilter::Block, LeadershipLogStatus::Block { .. })
+        )
+    }
+}
+
+impl LogsQuery {
+    fn matches(&self, log: &LeadershipLog) -> bool {
+        self.status
+            .map_or(true, |status| status.matches(log.status()))
+            && self
+                .epoch
+                .map_or(true, |epoch| log.scheduled_at_date().epoch() == epoch)
+    }
+}
+
 /// all leadership logs, allow for following up on the different entity
 /// of the blockchain
 #[derive(Clone)]
@@ -53,10 +99,14 @@ impl LeadershipLogHandle {
 
 impl Logs {
     /// create a Leadership Logs. Logs will be removed once the `Logs` passed
-    /// beyond a certain number of entries.
+    /// beyond a certain number of entries, or once they were scheduled more than
+    /// `retention_epochs` epochs before the most recently scheduled log.
     ///
-    pub fn new(cap: usize) -> Self {
-        Logs(Arc::new(RwLock::new(internal::Logs::new(cap))))
+    pub fn new(cap: usize, retention_epochs: Option<u32>) -> Self {
+        Logs(Arc::new(RwLock::new(internal::Logs::new(
+            cap,
+            retention_epochs,
+        ))))
     }
 
     pub async fn insert(&self, log: LeadershipLog) -> Result<LeadershipLogHandle, ()> {
@@ -83,10 +133,23 @@ impl Logs {
         inner.write().await.mark_finished(&leadership_log_id);
     }
 
-    pub async fn logs(&self) -> Vec<LeadershipLog> {
+    pub async fn query(&self, query: &LogsQuery) -> Vec<LeadershipLog> {
         let inner = self.0.clone();
         let guard = inner.read().await;
-        guard.logs().cloned().collect()
+        let mut logs: Vec<_> = guard
+            .logs()
+            .filter(|log| query.matches(log))
+            .cloned()
+            .collect();
+        logs.sort_by(|a, b| {
+            b.scheduled_at_date()
+                .cmp(a.scheduled_at_date())
+                .then_with(|| a.enclave_leader_id().cmp(b.enclave_leader_id()))
+        });
+        logs.into_iter()
+            .skip(query.offset.unwrap_or(0))
+            .take(query.limit.unwrap_or(usize::MAX))
+            .collect()
     }
 }
 
@@ -96,22 +159,48 @@ pub(super) mod internal {
 
     pub struct Logs {
         entries: LruCache<LeadershipLogId, LeadershipLog>,
+        retention_epochs: Option<u32>,
+        last_epoch: u32,
     }
 
     impl Logs {
-        pub fn new(cap: usize) -> Self {
+        pub fn new(cap: usize, retention_epochs: Option<u32>) -> Self {
             Logs {
                 entries: LruCache::new(cap),
+                retention_epochs,
+                last_epoch: 0,
             }
         }
 
         pub fn insert(&mut self, log: LeadershipLog) -> LeadershipLogId {
             let id = log.leadership_log_id();
+            let epoch = log.scheduled_at_date().epoch();
 
             self.entries.put(id, log);
+            if epoch > self.last_epoch {
+                self.last_epoch = epoch;
+                self.remove_outdated();
+            }
             id
         }
 
+        fn remove_outdated(&mut self) {
+            let retention_epochs = match self.retention_epochs {
+                Some(retention_epochs) => retention_epochs,
+                None => return,
+            };
+            let oldest_epoch = self.last_epoch.saturating_sub(retention_epochs);
+            let outdated: Vec<_> = self
+                .entries
+                .iter()
+                .filter(|(_, log)| log.scheduled_at_date().epoch() < oldest_epoch)
+                .map(|(id, _)| *id)
+                .collect();
+            for id in outdated {
+                self.entries.pop(&id);
+            }
+        }
+
         pub fn mark_wake(&mut self, leadership_log_id: &LeadershipLogId) {
             if let Some(ref mut log) = self.entries.get_mut(leadership_log_id) {
                 log.mark_wake();
@@ -139,3 +228,85 @@ pub(super) mod internal {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jormungandr_lib::{
+        interfaces::{BlockDate, EnclaveLeaderId},
+        time::SystemTime,
+    };
+
+    fn log(epoch: u32, slot_id: u32, status: LeadershipLogStatus) -> LeadershipLog {
+        let mut log = LeadershipLog::new(
+            EnclaveLeaderId::new(),
+            BlockDate::new(epoch, slot_id),
+            SystemTime::now(),
+        );
+        log.set_status(status);
+        log
+    }
+
+    fn rejected() -> LeadershipLogStatus {
+        LeadershipLogStatus::Rejected {
+            reason: "test".to_owned(),
+        }
+    }
+
+    fn dates(logs: &[LeadershipLog]) -> Vec<(u32, u32)> {
+        logs.iter()
+            .map(|log| {
+                let date = log.scheduled_at_date();
+                (date.epoch(), date.slot())
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn logs_are_filtered_then_paginated_from_the_most_recent() {
+        let logs = Logs::new(16, None);
+        for (epoch, slot_id) in &[(1, 2), (2, 0), (1, 5), (2, 3)] {
+            logs.insert(log(*epoch, *slot_id, LeadershipLogStatus::Pending))
+                .await
+                .unwrap();
+        }
+        logs.insert(log(2, 4, rejected())).await.unwrap();
+
+        let all = logs.query(&LogsQuery::default()).await;
+        assert_eq!(dates(&all), vec![(2, 4), (2, 3), (2, 0), (1, 5), (1, 2)]);
+
+        let pending = logs
+            .query(&LogsQuery {
+                status: Some(LogStatusFilter::Pending),
+                epoch: Some(2),
+                ..Default::default()
+            })
+            .await;
+        assert_eq!(dates(&pending), vec![(2, 3), (2, 0)]);
+
+        let page = logs
+            .query(&LogsQuery {
+                offset: Some(1),
+                limit: Some(2),
+                ..Default::default()
+            })
+            .await;
+        assert_eq!(dates(&page), vec![(2, 3), (2, 0)]);
+    }
+
+    #[test]
+    fn logs_beyond_the_retention_are_removed() {
+        let mut logs = internal::Logs::new(16, Some(2));
+        for epoch in 0..4 {
+            logs.insert(log(epoch, 0, LeadershipLogStatus::Pending));
+        }
+        // a log scheduled late in an older epoch does not bring the old ones back
+        logs.insert(log(2, 1, LeadershipLogStatus::Pending));
+        let mut epochs: Vec<_> = logs
+            .logs()
+            .map(|log| log.scheduled_at_date().epoch())
+            .collect();
+        epochs.sort_unstable();
+        assert_eq!(epochs, vec![1, 2, 2, 3]);
+    }
+}
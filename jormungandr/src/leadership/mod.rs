@@ -62,5 +62,5 @@ mod logs;
 mod process;
 
 pub use self::enclave::{Enclave, EnclaveError, LeaderEvent};
-pub use self::logs::{LeadershipLogHandle, Logs};
+pub use self::logs::{LeadershipLogHandle, LogStatusFilter, Logs, LogsQuery};
 pub use self::process::{Module, ModuleConfig};
@@ -9,7 +9,7 @@ use crate::{
         enclave::{Enclave, EnclaveError, LeaderEvent, Schedule},
         LeadershipLogHandle, Logs,
     },
-    utils::{async_msg::MessageBox, task::TokioServiceInfo},
+    utils::{async_msg::MessageBox, clock, task::TokioServiceInfo},
 };
 use chain_time::{
     era::{EpochPosition, EpochSlotOffset},
@@ -119,7 +119,7 @@ impl Module {
     fn current_slot(&self) -> Result<Slot, LeadershipError> {
         let time_frame = self.tip_ref.time_frame();
 
-        let now = SystemTime::now();
+        let now = SystemTime::from(clock::now());
         if let Some(current_slot) = time_frame.slot_at(now.as_ref()) {
             Ok(current_slot)
         } else {
@@ -153,17 +153,15 @@ impl Module {
     fn epoch_instant(&self, epoch: Epoch) -> Result<Instant, LeadershipError> {
         let epoch_time = self.epoch_time(epoch)?;
 
-        match epoch_time.as_ref().duration_since(SystemTime::now().into()) {
-            Err(err) => {
-                // only possible if `epoch_time` is earlier than now. I.e. if the next
-                // epoch is in the past.
-
-                unreachable!(
-                    "next epoch is in the past. This is not possible, but it seems it append. {}",
-                    err
-                )
+        match epoch_time.as_ref().duration_since(clock::now()) {
+            Err(_err) => {
+                // the next epoch is already in the past, this happens when the
+                // clock is stepped or accelerated: don't wait any further, the
+                // schedule is computed again for the current epoch
+                tracing::debug!("next epoch has already started");
+                Ok(Instant::now())
             }
-            Ok(duration) => Ok(Instant::now() + duration),
+            Ok(duration) => Ok(clock::instant_in(duration)),
         }
     }
 
@@ -221,18 +219,18 @@ impl Module {
     fn slot_instant(&self, epoch: Epoch, slot: EpochSlotOffset) -> Option<Instant> {
         let slot_time = self.slot_time(epoch, slot);
 
-        match slot_time.as_ref().duration_since(SystemTime::now().into()) {
+        match slot_time.as_ref().duration_since(clock::now()) {
             Err(_err) => {
                 // this may happen if the epoch/slot is long gone
                 None
             }
-            Ok(duration) => Some(Instant::now() + duration),
+            Ok(duration) => Some(clock::instant_in(duration)),
         }
     }
 
     async fn wait(mut self) -> Result<Self, LeadershipError> {
         let deadline = self.wait_peek_deadline().await?;
-        tokio::time::sleep_until(tokio::time::Instant::from_std(deadline)).await;
+        clock::sleep_until(deadline).await;
         let tip = self.tip.clone();
         self.tip_ref = tip.get_ref().await;
         Ok(self)
@@ -317,7 +315,7 @@ impl Module {
     }
 
     async fn action_run_entry(self, entry: Entry) -> Result<Self, LeadershipError> {
-        let now = SystemTime::now();
+        let now = SystemTime::from(clock::now());
         let event_start = self.event_slot_time(&entry.event);
         let event_end = self.event_following_slot_time(&entry.event);
         let event_end_hard =
@@ -357,7 +355,7 @@ impl Module {
                     );
 
                     // await the right_time before starting the action
-                    tokio::time::sleep_until(tokio::time::Instant::from_std(right_time)).await;
+                    clock::sleep_until(right_time).await;
                     self.action_run_entry_in_bound(entry, event_end, event_end_hard)
                         .await
                 } else {
@@ -382,7 +380,7 @@ impl Module {
     ) -> Result<Self, LeadershipError> {
         use futures::future::{select, Either};
 
-        let now = SystemTime::now();
+        let now = SystemTime::from(clock::now());
 
         // we can safely unwrap here as we just proved that `now <= event_end`
         // so that `now` is earlier to `event_end`.
@@ -393,12 +391,12 @@ impl Module {
         let remaining_time = event_end
             .duration_since(now)
             .expect("event end in the future");
-        let deadline = Instant::now() + remaining_time.into();
+        let deadline = clock::instant_in(remaining_time.into());
 
         let remaining_time_hard = event_end_hard
             .duration_since(now)
             .expect("event end in the future");
-        let hard_deadline = Instant::now() + remaining_time_hard.into();
+        let hard_deadline = clock::instant_in(remaining_time_hard.into());
 
         // handle to the current span, created in `action_run_entry`
         let parent_span = Span::current();
@@ -8,7 +8,7 @@ extern crate lazy_static;
 extern crate serde_derive;
 
 use crate::{
-    blockcfg::{HeaderHash, Leader},
+    blockcfg::{Block0DataSource as _, HeaderHash, Leader},
     blockchain::Blockchain,
     diagnostic::Diagnostic,
     metrics::MetricsBackend,
@@ -68,6 +68,7 @@ pub struct BootstrappedNode {
     rest_context: Option<rest::ContextLock>,
     services: Services,
     initial_peers: Vec<topology::Peer>,
+    cancellation_token: CancellationToken,
     _logger_guards: Vec<WorkerGuard>,
 }
 
@@ -78,6 +79,9 @@ const EXPLORER_TASK_QUEUE_LEN: usize = 32;
 const CLIENT_TASK_QUEUE_LEN: usize = 32;
 const TOPOLOGY_TASK_QUEUE_LEN: usize = 32;
 const BOOTSTRAP_RETRY_WAIT: Duration = Duration::from_secs(5);
+// time left to the services (the fragment task saving the mempool snapshot)
+// to wind down before the node exits after a shutdown signal
+const SHUTDOWN_GRACE_PERIOD: Duration = Duration::from_secs(15);
 
 fn start_services(bootstrapped_node: BootstrappedNode) -> Result<(), start_up::Error> {
     if let Some(context) = bootstrapped_node.rest_context.as_ref() {
@@ -99,8 +103,10 @@ fn start_services(bootstrapped_node: BootstrappedNode) -> Result<(), start_up::E
     let (topology_msgbox, topology_queue) = async_msg::channel(TOPOLOGY_TASK_QUEUE_LEN);
     let blockchain_tip = bootstrapped_node.blockchain_tip;
     let blockchain = bootstrapped_node.blockchain;
-    let leadership_logs =
-        leadership::Logs::new(bootstrapped_node.settings.leadership.logs_capacity);
+    let leadership_logs = leadership::Logs::new(
+        bootstrapped_node.settings.leadership.logs_capacity,
+        bootstrapped_node.settings.leadership.logs_retention_epochs,
+    );
 
     let metrics_builder = crate::metrics::Metrics::builder();
 
@@ -120,11 +126,18 @@ fn start_services(bootstrapped_node: BootstrappedNode) -> Result<(), start_up::E
 
     let stats_counter = metrics_builder.build();
 
-    {
+    let (tip_date, slots_per_epoch) = {
         let block_ref = services.block_on_task("get_tip_block", |_| blockchain_tip.get_ref());
         let block = blockchain.storage().get(block_ref.hash()).unwrap().unwrap();
         stats_counter.set_tip_block(&block, &block_ref);
-    }
+        (
+            block_ref.block_date(),
+            block_ref
+                .epoch_leadership_schedule()
+                .era()
+                .slots_per_epoch(),
+        )
+    };
 
     let explorer = {
         if bootstrapped_node.settings.explorer {
@@ -182,6 +195,13 @@ fn start_services(bootstrapped_node: BootstrappedNode) -> Result<(), start_up::E
         });
     }
 
+    if let Some(backup) = bootstrapped_node.settings.storage_backup.clone() {
+        let storage = blockchain.storage().clone();
+        services.spawn_future("storage_backup", move |_| {
+            blockchain::backup::run(storage, backup)
+        });
+    }
+
     // FIXME: reduce state sharing across services
     let network_state = Arc::new(network::GlobalState::new(
         bootstrapped_node.block0_hash,
@@ -218,6 +238,7 @@ fn start_services(bootstrapped_node: BootstrappedNode) -> Result<(), start_up::E
             initial_peers: bootstrapped_node.initial_peers,
             topology_queue,
             stats_counter: stats_counter.clone(),
+            peer_events: network_state.peer_events().clone(),
         };
 
         services.spawn_future("topology", move |_| topology::start(task_data));
@@ -290,9 +311,39 @@ fn start_services(bootstrapped_node: BootstrappedNode) -> Result<(), start_up::E
     }
 
     {
+        let stats_counter = stats_counter.clone();
+        let blockchain_tip = blockchain_tip.clone();
+        let block0_hash = bootstrapped_node.block0_hash;
         let process = fragment::Process::new(
             bootstrapped_node.settings.mempool.pool_max_entries.into(),
             bootstrapped_node.settings.mempool.log_max_entries.into(),
+            bootstrapped_node
+                .settings
+                .mempool
+                .log_gc
+                .map(|gc| fragment::LogGcPolicy {
+                    max_age_epochs: gc.max_age_epochs,
+                    max_in_block_entries: gc.max_in_block_entries,
+                    max_rejected_entries: gc.max_rejected_entries,
+                })
+                .unwrap_or_default(),
+            bootstrapped_node
+                .settings
+                .mempool
+                .rebroadcast_interval
+                .map(Into::into),
+            bootstrapped_node.settings.mempool.snapshot.map(|s| s.path),
+            bootstrapped_node
+                .settings
+                .mempool
+                .validity_window
+                .map(|window| {
+                    fragment::ValidityWindow::new(
+                        window.max_epochs_ahead,
+                        window.min_slots_before_expiry,
+                        slots_per_epoch,
+                    )
+                }),
             network_msgbox.clone(),
         );
         let fragment_log_dir = bootstrapped_node
@@ -300,6 +351,7 @@ fn start_services(bootstrapped_node: BootstrappedNode) -> Result<(), start_up::E
             .mempool
             .persistent_log
             .map(|s| s.dir);
+        let shutdown = bootstrapped_node.cancellation_token.clone();
 
         services.spawn_try_future("fragment", move |info| {
             process.start(
@@ -308,6 +360,10 @@ fn start_services(bootstrapped_node: BootstrappedNode) -> Result<(), start_up::E
                 stats_counter,
                 fragment_queue,
                 fragment_log_dir,
+                tip_date,
+                block0_hash,
+                blockchain_tip,
+                shutdown,
             )
         });
     };
@@ -328,6 +384,8 @@ fn start_services(bootstrapped_node: BootstrappedNode) -> Result<(), start_up::E
         block_on(async {
             let mut rest_context = rest_context.write().await;
             rest_context.set_full(full_context);
+            rest_context.set_node_stopper(bootstrapped_node.cancellation_token);
+            rest_context.set_metrics(stats_counter);
             rest_context.set_node_state(NodeState::Running);
         })
     };
@@ -382,6 +440,17 @@ fn bootstrap(initialized_node: InitializedNode) -> Result<BootstrappedNode, star
         _logger_guards,
     } = initialized_node;
 
+    #[cfg(feature = "simulation-clock")]
+    if let Some(simulation_clock) = settings.simulation_clock {
+        use crate::blockcfg::Block0DataSource as _;
+
+        tracing::warn!(
+            acceleration = %simulation_clock.acceleration,
+            "the clock of the node is simulated, the node cannot join a live network"
+        );
+        utils::clock::simulate(block0.start_time()?, simulation_clock.acceleration);
+    }
+
     let BootstrapData {
         blockchain,
         blockchain_tip,
@@ -397,7 +466,7 @@ fn bootstrap(initialized_node: InitializedNode) -> Result<BootstrappedNode, star
             block0,
             storage,
             settings,
-            cancellation_token,
+            cancellation_token.clone(),
         )
     })?;
 
@@ -410,6 +479,7 @@ fn bootstrap(initialized_node: InitializedNode) -> Result<BootstrappedNode, star
         rest_context,
         services,
         initial_peers,
+        cancellation_token,
         _logger_guards,
     })
 }
@@ -449,9 +519,14 @@ async fn bootstrap_internal(
 
     let cache_capacity = 102_400;
 
-    let (blockchain, blockchain_tip) =
-        start_up::load_blockchain(block0, storage, cache_capacity, settings.rewards_report_all)
-            .await?;
+    let (blockchain, blockchain_tip) = start_up::load_blockchain(
+        block0,
+        storage,
+        cache_capacity,
+        settings.rewards_report_all,
+        settings.rewards_export.clone(),
+    )
+    .await?;
 
     if let Some(context) = &rest_context {
         let mut context = context.write().await;
@@ -460,6 +535,11 @@ async fn bootstrap_internal(
         context.set_bootstrap_stopper(cancellation_token.clone());
     };
 
+    let bootstrap_progress = match &rest_context {
+        Some(context) => context.read().await.bootstrap_progress().clone(),
+        None => network::bootstrap::Progress::default(),
+    };
+
     let mut bootstrap_attempt: usize = 0;
 
     let network_res = loop {
@@ -473,17 +553,20 @@ async fn bootstrap_internal(
                 break None; // maximum bootstrap attempts exceeded, exit loop
             };
         }
+        bootstrap_progress.start_attempt();
 
         // Will return true if we successfully bootstrap or skip_bootstrap is set.
         let res = network::bootstrap(
             &settings.network,
             blockchain.clone(),
             blockchain_tip.clone(),
+            &bootstrap_progress,
             cancellation_token.clone(),
             &span,
         )
         .await?;
         if res.bootstrapped {
+            bootstrap_progress.complete();
             break Some(res); // bootstrap succeeded, exit loop
         }
 
@@ -544,9 +627,15 @@ fn init_os_signal_watchers(services: &mut Services, token: CancellationToken) {
 
     let token_1 = token.clone();
 
+    // The node exits once a watcher finishes. After cancelling the token the
+    // services are given a grace period to wind down, a second signal ends it early.
     async fn recv_signal_and_cancel(mut signal: signal::unix::Signal, token: CancellationToken) {
         if let Some(()) = signal.recv().await {
             token.cancel();
+            futures::select! {
+                _ = tokio::time::sleep(SHUTDOWN_GRACE_PERIOD).fuse() => {},
+                _ = signal.recv().fuse() => {},
+            }
         }
     }
 
@@ -576,10 +665,16 @@ fn init_os_signal_watchers(services: &mut Services, token: CancellationToken) {
     use signal::ctrl_c;
 
     services.spawn_future("ctrl_c_watcher", move |_info| {
+        // see the unix version for the grace period
         ctrl_c().then(move |result| match result {
             Ok(()) => {
                 token.cancel();
-                future::ready(()).left_future()
+                future::select(
+                    Box::pin(tokio::time::sleep(SHUTDOWN_GRACE_PERIOD)),
+                    Box::pin(ctrl_c()),
+                )
+                .map(|_| ())
+                .left_future()
             }
             Err(e) => {
                 tracing::warn!(reason = %e, "ctrl+c watcher failed");
@@ -656,6 +751,8 @@ fn initialize_node() -> Result<InitializedNode, start_up::Error> {
                 listen: rest.listen,
                 tls: rest.tls,
                 cors: rest.cors,
+                max_body_size: rest.max_body_size.unwrap_or(rest::DEFAULT_MAX_BODY_SIZE),
+                request_timeout: rest.request_timeout.map(Into::into),
                 enable_explorer: settings.explorer,
                 #[cfg(feature = "prometheus-metrics")]
                 enable_prometheus: settings.prometheus,
@@ -708,6 +805,20 @@ fn initialize_node() -> Result<InitializedNode, start_up::Error> {
     }.instrument(async_span)
     })?;
 
+    let configuration = settings.summary(block0.consensus_version()?);
+    tracing::info!(
+        configuration = %serde_json::to_string(&configuration).unwrap(),
+        "effective configuration"
+    );
+    if let Some(context) = rest_context.as_ref() {
+        block_on(async {
+            context
+                .write()
+                .await
+                .set_configuration_summary(configuration)
+        })
+    }
+
     Ok(InitializedNode {
         settings,
         block0,
@@ -1,17 +1,21 @@
-use crate::metrics::MetricsBackend;
+use crate::metrics::{MetricsBackend, PoolEviction, PoolOccupancy, StakeSummary};
 
 use chain_impl_mockchain::block::BlockContentHash;
 use chain_impl_mockchain::fragment::Fragment;
 use chain_impl_mockchain::transaction::Transaction;
 use chain_impl_mockchain::value::{Value, ValueError};
+use jormungandr_lib::interfaces::FragmentOrigin;
 
 use std::convert::TryInto;
 use std::sync::Arc;
-use std::time::SystemTime;
+use std::time::{Duration, SystemTime};
 
 use arc_swap::ArcSwapOption;
 use prometheus::core::{AtomicU64, GenericGauge};
-use prometheus::{Encoder, IntCounter, Registry, TextEncoder};
+use prometheus::{
+    Encoder, Gauge, HistogramOpts, HistogramVec, IntCounter, IntCounterVec, Opts, Registry,
+    TextEncoder,
+};
 
 type UIntGauge = GenericGauge<AtomicU64>;
 
@@ -19,6 +23,14 @@ pub struct Prometheus {
     registry: Registry,
 
     tx_recv_cnt: IntCounter,
+    tx_expired_cnt: IntCounter,
+    tx_conflict_cnt: IntCounter,
+    tx_duplicate_cnt: IntCounterVec,
+    fragment_log_evicted_cnt: IntCounter,
+    pool_entry_cnt: UIntGauge,
+    pool_size_bytes: UIntGauge,
+    pool_entry_max_cnt: UIntGauge,
+    pool_evicted_cnt: IntCounterVec,
     block_recv_cnt: IntCounter,
     peer_connected_cnt: UIntGauge,
     peer_quarantined_cnt: UIntGauge,
@@ -34,6 +46,13 @@ pub struct Prometheus {
     block_chain_length: UIntGauge,
     block_time: UIntGauge,
     block_hash: Vec<UIntGauge>,
+    vote_plans_awaiting_tally: UIntGauge,
+    active_stake: UIntGauge,
+    stake_pool_cnt: UIntGauge,
+    delegation_participation: Gauge,
+    rest_request_cnt: IntCounterVec,
+    rest_error_cnt: IntCounterVec,
+    rest_request_duration: HistogramVec,
 
     block_hash_value: ArcSwapOption<BlockContentHash>,
 }
@@ -74,6 +93,37 @@ impl Default for Prometheus {
 
         let tx_recv_cnt = IntCounter::new("txRecvCnt", "txRecvCnt").unwrap();
         registry.register(Box::new(tx_recv_cnt.clone())).unwrap();
+        let tx_expired_cnt = IntCounter::new("txExpiredCnt", "txExpiredCnt").unwrap();
+        registry.register(Box::new(tx_expired_cnt.clone())).unwrap();
+        let tx_conflict_cnt = IntCounter::new("txConflictCnt", "txConflictCnt").unwrap();
+        registry
+            .register(Box::new(tx_conflict_cnt.clone()))
+            .unwrap();
+        let tx_duplicate_cnt =
+            IntCounterVec::new(Opts::new("txDuplicateCnt", "txDuplicateCnt"), &["origin"]).unwrap();
+        registry
+            .register(Box::new(tx_duplicate_cnt.clone()))
+            .unwrap();
+        let fragment_log_evicted_cnt =
+            IntCounter::new("fragmentLogEvictedCnt", "fragmentLogEvictedCnt").unwrap();
+        registry
+            .register(Box::new(fragment_log_evicted_cnt.clone()))
+            .unwrap();
+        let pool_entry_cnt = UIntGauge::new("poolEntryCnt", "poolEntryCnt").unwrap();
+        registry.register(Box::new(pool_entry_cnt.clone())).unwrap();
+        let pool_size_bytes = UIntGauge::new("poolSizeBytes", "poolSizeBytes").unwrap();
+        registry
+            .register(Box::new(pool_size_bytes.clone()))
+            .unwrap();
+        let pool_entry_max_cnt = UIntGauge::new("poolEntryMaxCnt", "poolEntryMaxCnt").unwrap();
+        registry
+            .register(Box::new(pool_entry_max_cnt.clone()))
+            .unwrap();
+        let pool_evicted_cnt =
+            IntCounterVec::new(Opts::new("poolEvictedCnt", "poolEvictedCnt"), &["reason"]).unwrap();
+        registry
+            .register(Box::new(pool_evicted_cnt.clone()))
+            .unwrap();
         let block_recv_cnt = IntCounter::new("blockRecvCnt", "blockRecvCnt").unwrap();
         registry.register(Box::new(block_recv_cnt.clone())).unwrap();
         let peer_connected_cnt = UIntGauge::new("peerConnectedCnt", "peerConnectedCnt").unwrap();
@@ -131,9 +181,53 @@ impl Default for Prometheus {
             pcs
         };
 
+        let vote_plans_awaiting_tally =
+            UIntGauge::new("votePlansAwaitingTally", "votePlansAwaitingTally").unwrap();
+        registry
+            .register(Box::new(vote_plans_awaiting_tally.clone()))
+            .unwrap();
+
+        let active_stake = UIntGauge::new("activeStake", "activeStake").unwrap();
+        registry.register(Box::new(active_stake.clone())).unwrap();
+        let stake_pool_cnt = UIntGauge::new("stakePoolCnt", "stakePoolCnt").unwrap();
+        registry.register(Box::new(stake_pool_cnt.clone())).unwrap();
+        let delegation_participation =
+            Gauge::new("delegationParticipation", "delegationParticipation").unwrap();
+        registry
+            .register(Box::new(delegation_participation.clone()))
+            .unwrap();
+
+        let rest_request_cnt =
+            IntCounterVec::new(Opts::new("restRequestCnt", "restRequestCnt"), &["route"]).unwrap();
+        registry
+            .register(Box::new(rest_request_cnt.clone()))
+            .unwrap();
+        let rest_error_cnt = IntCounterVec::new(
+            Opts::new("restErrorCnt", "restErrorCnt"),
+            &["route", "status"],
+        )
+        .unwrap();
+        registry.register(Box::new(rest_error_cnt.clone())).unwrap();
+        let rest_request_duration = HistogramVec::new(
+            HistogramOpts::new("restRequestDuration", "restRequestDuration"),
+            &["route"],
+        )
+        .unwrap();
+        registry
+            .register(Box::new(rest_request_duration.clone()))
+            .unwrap();
+
         Self {
             registry,
             tx_recv_cnt,
+            tx_expired_cnt,
+            tx_conflict_cnt,
+            tx_duplicate_cnt,
+            fragment_log_evicted_cnt,
+            pool_entry_cnt,
+            pool_size_bytes,
+            pool_entry_max_cnt,
+            pool_evicted_cnt,
             block_recv_cnt,
             peer_connected_cnt,
             peer_quarantined_cnt,
@@ -149,6 +243,13 @@ impl Default for Prometheus {
             block_chain_length,
             block_time,
             block_hash,
+            vote_plans_awaiting_tally,
+            active_stake,
+            stake_pool_cnt,
+            delegation_participation,
+            rest_request_cnt,
+            rest_error_cnt,
+            rest_request_duration,
             block_hash_value: Default::default(),
         }
     }
@@ -160,6 +261,48 @@ impl MetricsBackend for Prometheus {
         self.tx_recv_cnt.inc_by(count);
     }
 
+    fn add_tx_expired_cnt(&self, count: usize) {
+        let count = count.try_into().unwrap();
+        self.tx_expired_cnt.inc_by(count);
+    }
+
+    fn add_tx_conflict_cnt(&self, count: usize) {
+        let count = count.try_into().unwrap();
+        self.tx_conflict_cnt.inc_by(count);
+    }
+
+    fn add_tx_duplicate_cnt(&self, origin: FragmentOrigin, count: usize) {
+        let origin = match origin {
+            FragmentOrigin::Rest => "rest",
+            FragmentOrigin::Network => "network",
+        };
+        self.tx_duplicate_cnt
+            .with_label_values(&[origin])
+            .inc_by(count.try_into().unwrap());
+    }
+
+    fn add_fragment_log_evicted_cnt(&self, count: usize) {
+        let count = count.try_into().unwrap();
+        self.fragment_log_evicted_cnt.inc_by(count);
+    }
+
+    fn set_pool_occupancy(&self, occupancy: PoolOccupancy) {
+        let entries = occupancy.entries.try_into().unwrap();
+        self.pool_entry_cnt.set(entries);
+        self.pool_size_bytes
+            .set(occupancy.size_bytes.try_into().unwrap());
+        // only set from the fragment task, so the read and the write do not race
+        if entries > self.pool_entry_max_cnt.get() {
+            self.pool_entry_max_cnt.set(entries);
+        }
+    }
+
+    fn add_pool_evicted_cnt(&self, reason: PoolEviction, count: usize) {
+        self.pool_evicted_cnt
+            .with_label_values(&[reason.as_str()])
+            .inc_by(count.try_into().unwrap());
+    }
+
     fn add_block_recv_cnt(&self, count: usize) {
         let count = count.try_into().unwrap();
         self.block_recv_cnt.inc_by(count);
@@ -265,4 +408,26 @@ impl MetricsBackend for Prometheus {
         let block_hash = block.header.hash();
         self.block_hash_value.store(Some(Arc::new(block_hash)));
     }
+
+    fn set_stake_summary(&self, summary: &StakeSummary) {
+        self.active_stake.set(summary.active_stake);
+        self.stake_pool_cnt.set(summary.registered_pools as u64);
+        self.delegation_participation.set(summary.participation());
+    }
+
+    fn set_vote_plans_awaiting_tally(&self, count: usize) {
+        self.vote_plans_awaiting_tally.set(count as u64);
+    }
+
+    fn add_rest_request(&self, route: &str, status: u16, elapsed: Duration) {
+        self.rest_request_cnt.with_label_values(&[route]).inc();
+        if status >= 400 {
+            self.rest_error_cnt
+                .with_label_values(&[route, &status.to_string()])
+                .inc();
+        }
+        self.rest_request_duration
+            .with_label_values(&[route])
+            .observe(elapsed.as_secs_f64());
+    }
 }
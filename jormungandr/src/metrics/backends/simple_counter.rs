@@ -1,28 +1,41 @@
 use crate::blockchain::Ref;
-use crate::metrics::MetricsBackend;
+use crate::metrics::{MetricsBackend, PoolEviction, PoolOccupancy, StakeSummary};
 
 use chain_impl_mockchain::block::Block;
 use chain_impl_mockchain::fragment::Fragment;
 use chain_impl_mockchain::transaction::Transaction;
 use chain_impl_mockchain::value::{Value, ValueError};
-use jormungandr_lib::interfaces::NodeStats;
+use jormungandr_lib::interfaces::{FragmentOrigin, NodeStats};
 use jormungandr_lib::time::{SecondsSinceUnixEpoch, SystemTime};
 
 use std::convert::TryInto;
 use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use arc_swap::ArcSwapOption;
 
 pub struct SimpleCounter {
     tx_recv_cnt: AtomicUsize,
+    tx_expired_cnt: AtomicUsize,
+    tx_conflict_cnt: AtomicUsize,
+    tx_duplicate_rest_cnt: AtomicUsize,
+    tx_duplicate_network_cnt: AtomicUsize,
+    fragment_log_evicted_cnt: AtomicUsize,
+    pool_entry_cnt: AtomicUsize,
+    pool_size_bytes: AtomicUsize,
+    pool_entry_max_cnt: AtomicUsize,
+    pool_full_evicted_cnt: AtomicUsize,
+    pool_rejected_evicted_cnt: AtomicUsize,
+    rest_request_cnt: AtomicUsize,
+    rest_error_cnt: AtomicUsize,
     block_recv_cnt: AtomicUsize,
     slot_start_time: AtomicU64,
     peers_connected_cnt: AtomicUsize,
     peers_quarantined_cnt: AtomicUsize,
     peers_available_cnt: AtomicUsize,
     tip_block: ArcSwapOption<BlockCounters>,
+    stake: ArcSwapOption<StakeSummary>,
     start_time: Instant,
 }
 
@@ -49,6 +62,8 @@ impl SimpleCounter {
 
         let block_data = self.tip_block.load();
         let block_data = block_data.as_deref();
+        let stake = self.stake.load();
+        let stake = stake.as_deref();
 
         NodeStats {
             block_recv_cnt: self
@@ -72,6 +87,63 @@ impl SimpleCounter {
             peer_quarantined_cnt,
             peer_total_cnt,
             tx_recv_cnt: self.tx_recv_cnt.load(Ordering::Relaxed).try_into().unwrap(),
+            tx_expired_cnt: self
+                .tx_expired_cnt
+                .load(Ordering::Relaxed)
+                .try_into()
+                .unwrap(),
+            tx_conflict_cnt: self
+                .tx_conflict_cnt
+                .load(Ordering::Relaxed)
+                .try_into()
+                .unwrap(),
+            tx_duplicate_rest_cnt: self
+                .tx_duplicate_rest_cnt
+                .load(Ordering::Relaxed)
+                .try_into()
+                .unwrap(),
+            tx_duplicate_network_cnt: self
+                .tx_duplicate_network_cnt
+                .load(Ordering::Relaxed)
+                .try_into()
+                .unwrap(),
+            fragment_log_evicted_cnt: self
+                .fragment_log_evicted_cnt
+                .load(Ordering::Relaxed)
+                .try_into()
+                .unwrap(),
+            pool_entry_cnt: self.pool_entry_cnt.load(Ordering::Relaxed),
+            pool_size_bytes: self
+                .pool_size_bytes
+                .load(Ordering::Relaxed)
+                .try_into()
+                .unwrap(),
+            pool_entry_max_cnt: self.pool_entry_max_cnt.load(Ordering::Relaxed),
+            pool_full_evicted_cnt: self
+                .pool_full_evicted_cnt
+                .load(Ordering::Relaxed)
+                .try_into()
+                .unwrap(),
+            pool_rejected_evicted_cnt: self
+                .pool_rejected_evicted_cnt
+                .load(Ordering::Relaxed)
+                .try_into()
+                .unwrap(),
+            rest_request_cnt: self
+                .rest_request_cnt
+                .load(Ordering::Relaxed)
+                .try_into()
+                .unwrap(),
+            rest_error_cnt: self
+                .rest_error_cnt
+                .load(Ordering::Relaxed)
+                .try_into()
+                .unwrap(),
+            active_stake: stake.map(|stake| stake.active_stake).unwrap_or_default(),
+            stake_pool_cnt: stake
+                .map(|stake| stake.registered_pools)
+                .unwrap_or_default(),
+            delegation_participation: stake.map(StakeSummary::participation),
             uptime: Some(self.start_time.elapsed().as_secs()),
         }
     }
@@ -81,12 +153,25 @@ impl Default for SimpleCounter {
     fn default() -> Self {
         Self {
             tx_recv_cnt: Default::default(),
+            tx_expired_cnt: Default::default(),
+            tx_conflict_cnt: Default::default(),
+            tx_duplicate_rest_cnt: Default::default(),
+            tx_duplicate_network_cnt: Default::default(),
+            fragment_log_evicted_cnt: Default::default(),
+            pool_entry_cnt: Default::default(),
+            pool_size_bytes: Default::default(),
+            pool_entry_max_cnt: Default::default(),
+            pool_full_evicted_cnt: Default::default(),
+            pool_rejected_evicted_cnt: Default::default(),
+            rest_request_cnt: Default::default(),
+            rest_error_cnt: Default::default(),
             block_recv_cnt: Default::default(),
             slot_start_time: Default::default(),
             peers_connected_cnt: Default::default(),
             peers_quarantined_cnt: Default::default(),
             peers_available_cnt: Default::default(),
             tip_block: Default::default(),
+            stake: Default::default(),
             start_time: Instant::now(),
         }
     }
@@ -97,6 +182,46 @@ impl MetricsBackend for SimpleCounter {
         self.tx_recv_cnt.fetch_add(count, Ordering::SeqCst);
     }
 
+    fn add_tx_expired_cnt(&self, count: usize) {
+        self.tx_expired_cnt.fetch_add(count, Ordering::SeqCst);
+    }
+
+    fn add_tx_conflict_cnt(&self, count: usize) {
+        self.tx_conflict_cnt.fetch_add(count, Ordering::SeqCst);
+    }
+
+    fn add_tx_duplicate_cnt(&self, origin: FragmentOrigin, count: usize) {
+        let counter = match origin {
+            FragmentOrigin::Rest => &self.tx_duplicate_rest_cnt,
+            FragmentOrigin::Network => &self.tx_duplicate_network_cnt,
+        };
+        counter.fetch_add(count, Ordering::SeqCst);
+    }
+
+    fn add_fragment_log_evicted_cnt(&self, count: usize) {
+        self.fragment_log_evicted_cnt
+            .fetch_add(count, Ordering::SeqCst);
+    }
+
+    fn set_pool_occupancy(&self, occupancy: PoolOccupancy) {
+        self.pool_entry_cnt
+            .store(occupancy.entries, Ordering::SeqCst);
+        self.pool_size_bytes
+            .store(occupancy.size_bytes, Ordering::SeqCst);
+        self.pool_entry_max_cnt
+            .fetch_max(occupancy.entries, Ordering::SeqCst);
+    }
+
+    fn add_pool_evicted_cnt(&self, reason: PoolEviction, count: usize) {
+        let counter = match reason {
+            PoolEviction::Full => &self.pool_full_evicted_cnt,
+            PoolEviction::Rejected => &self.pool_rejected_evicted_cnt,
+            // already counted by `tx_expired_cnt`
+            PoolEviction::Expired => return,
+        };
+        counter.fetch_add(count, Ordering::SeqCst);
+    }
+
     fn add_block_recv_cnt(&self, count: usize) {
         self.block_recv_cnt.fetch_add(count, Ordering::SeqCst);
     }
@@ -181,4 +306,17 @@ impl MetricsBackend for SimpleCounter {
 
         self.tip_block.store(Some(Arc::new(block_data)));
     }
+
+    fn set_stake_summary(&self, summary: &StakeSummary) {
+        self.stake.store(Some(Arc::new(*summary)));
+    }
+
+    fn set_vote_plans_awaiting_tally(&self, _count: usize) {}
+
+    fn add_rest_request(&self, _route: &str, status: u16, _elapsed: Duration) {
+        self.rest_request_cnt.fetch_add(1, Ordering::SeqCst);
+        if status >= 400 {
+            self.rest_error_cnt.fetch_add(1, Ordering::SeqCst);
+        }
+    }
 }
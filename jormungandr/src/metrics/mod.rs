@@ -1,14 +1,26 @@
 use crate::blockchain::Ref;
 
 use chain_impl_mockchain::block::Block;
-use jormungandr_lib::time::SecondsSinceUnixEpoch;
+use jormungandr_lib::{interfaces::FragmentOrigin, time::SecondsSinceUnixEpoch};
 
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 
 pub mod backends;
 
 pub trait MetricsBackend {
     fn add_tx_recv_cnt(&self, count: usize);
+    fn add_tx_expired_cnt(&self, count: usize);
+    fn add_tx_conflict_cnt(&self, count: usize);
+    /// fragments received while already in the fragment logs
+    fn add_tx_duplicate_cnt(&self, origin: FragmentOrigin, count: usize);
+    fn add_fragment_log_evicted_cnt(&self, count: usize);
+    /// current occupancy of the fragment pool, the backends keep track of the
+    /// highest number of entries
+    fn set_pool_occupancy(&self, occupancy: PoolOccupancy);
+    /// fragments which left the fragment pool without being included in a block
+    fn add_pool_evicted_cnt(&self, reason: PoolEviction, count: usize);
     fn add_block_recv_cnt(&self, count: usize);
     fn add_peer_connected_cnt(&self, count: usize);
     fn sub_peer_connected_cnt(&self, count: usize);
@@ -18,11 +30,97 @@ pub trait MetricsBackend {
     fn sub_peer_available_cnt(&self, count: usize);
     fn set_slot_start_time(&self, time: SecondsSinceUnixEpoch);
     fn set_tip_block(&self, block: &Block, block_ref: &Ref);
+    fn set_stake_summary(&self, summary: &StakeSummary);
+    /// private vote plans past the middle of their tally phase with no tally
+    /// submitted yet
+    fn set_vote_plans_awaiting_tally(&self, count: usize);
+    /// `route` is the path of the request with the parameters left out
+    fn add_rest_request(&self, route: &str, status: u16, elapsed: Duration);
+}
+
+/// Entries and size of the fullest fragment pool, to be compared with the
+/// `pool_max_entries` setting of the mempool.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PoolOccupancy {
+    pub entries: usize,
+    /// sum of the serialized sizes of the entries
+    pub size_bytes: usize,
+}
+
+/// Why fragments left the fragment pool, or never entered it, without being
+/// included in a block
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PoolEviction {
+    /// refused on arrival because the pool was full
+    Full,
+    /// their `valid_until` date has passed
+    Expired,
+    /// rejected by the ledger when building a block, typically because
+    /// another fragment spent the same funds first
+    Rejected,
+}
+
+impl PoolEviction {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PoolEviction::Full => "full",
+            PoolEviction::Expired => "expired",
+            PoolEviction::Rejected => "rejected",
+        }
+    }
+}
+
+/// Stake figures of the epoch of the tip, computed from the stake distribution
+/// of the epoch.
+#[derive(Debug, Clone, Copy)]
+pub struct StakeSummary {
+    /// stake delegated to the stake pools
+    pub active_stake: u64,
+    /// all the stake in the ledger, delegated or not
+    pub total_stake: u64,
+    pub registered_pools: usize,
+}
+
+impl StakeSummary {
+    pub fn new(block_ref: &Ref) -> Self {
+        let ledger = block_ref.ledger();
+        // the leadership only keeps the distribution in genesis praos
+        let computed;
+        let distribution = match block_ref.epoch_leadership_schedule().stake_distribution() {
+            Some(distribution) => distribution,
+            None => {
+                computed = ledger.get_stake_distribution();
+                &computed
+            }
+        };
+        let active_stake: u64 = distribution
+            .to_pools
+            .values()
+            .map(|pool| u64::from(pool.stake.total))
+            .sum();
+        let inactive_stake = u64::from(distribution.unassigned) + u64::from(distribution.dangling);
+        StakeSummary {
+            active_stake,
+            total_stake: active_stake + inactive_stake,
+            registered_pools: ledger.delegation().stake_pool_ids().count(),
+        }
+    }
+
+    /// share of the stake delegated to a stake pool, between 0 and 1
+    pub fn participation(&self) -> f64 {
+        if self.total_stake == 0 {
+            return 0.0;
+        }
+        self.active_stake as f64 / self.total_stake as f64
+    }
 }
 
 #[derive(Clone)]
 pub struct Metrics {
     backends: Vec<Arc<dyn MetricsBackend + Send + Sync + 'static>>,
+    /// epoch of the last stake summary, the summary is only refreshed on
+    /// epoch transitions
+    stake_epoch: Arc<AtomicU64>,
 }
 
 impl Metrics {
@@ -45,6 +143,7 @@ impl MetricsBuilder {
     pub fn build(self) -> Metrics {
         Metrics {
             backends: self.backends,
+            stake_epoch: Arc::new(AtomicU64::new(u64::MAX)),
         }
     }
 }
@@ -67,6 +166,9 @@ macro_rules! metrics_count_method {
 
 impl MetricsBackend for Metrics {
     metrics_count_method!(add_tx_recv_cnt);
+    metrics_count_method!(add_tx_expired_cnt);
+    metrics_count_method!(add_tx_conflict_cnt);
+    metrics_count_method!(add_fragment_log_evicted_cnt);
     metrics_count_method!(add_block_recv_cnt);
     metrics_count_method!(add_peer_connected_cnt);
     metrics_count_method!(sub_peer_connected_cnt);
@@ -74,11 +176,108 @@ impl MetricsBackend for Metrics {
     metrics_count_method!(sub_peer_quarantined_cnt);
     metrics_count_method!(add_peer_available_cnt);
     metrics_count_method!(sub_peer_available_cnt);
+    metrics_count_method!(set_vote_plans_awaiting_tally);
     metrics_method!(set_slot_start_time, SecondsSinceUnixEpoch);
+    metrics_method!(set_pool_occupancy, PoolOccupancy);
 
     fn set_tip_block(&self, block: &Block, block_ref: &Ref) {
         for backend in &self.backends {
             backend.set_tip_block(block, block_ref);
         }
+
+        // the epoch may also go back on a branch switch
+        let epoch = u64::from(block_ref.block_date().epoch);
+        if self.stake_epoch.swap(epoch, Ordering::SeqCst) != epoch {
+            self.set_stake_summary(&StakeSummary::new(block_ref));
+        }
+    }
+
+    fn add_tx_duplicate_cnt(&self, origin: FragmentOrigin, count: usize) {
+        for backend in &self.backends {
+            backend.add_tx_duplicate_cnt(origin, count);
+        }
+    }
+
+    fn add_pool_evicted_cnt(&self, reason: PoolEviction, count: usize) {
+        for backend in &self.backends {
+            backend.add_pool_evicted_cnt(reason, count);
+        }
+    }
+
+    fn set_stake_summary(&self, summary: &StakeSummary) {
+        for backend in &self.backends {
+            backend.set_stake_summary(summary);
+        }
+    }
+
+    fn add_rest_request(&self, route: &str, status: u16, elapsed: Duration) {
+        for backend in &self.backends {
+            backend.add_rest_request(route, status, elapsed);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use backends::SimpleCounter;
+
+    #[test]
+    fn participation_is_the_share_of_delegated_stake() {
+        let summary = StakeSummary {
+            active_stake: 300,
+            total_stake: 400,
+            registered_pools: 2,
+        };
+        assert!((summary.participation() - 0.75).abs() < f64::EPSILON);
+
+        let empty = StakeSummary {
+            active_stake: 0,
+            total_stake: 0,
+            registered_pools: 0,
+        };
+        assert!(empty.participation().abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn stake_summary_is_reported_in_the_stats() {
+        let counter = Arc::new(SimpleCounter::default());
+        let stats = counter.get_stats();
+        assert_eq!(stats.active_stake, 0);
+        assert_eq!(stats.delegation_participation, None);
+
+        let metrics = Metrics::builder().add_backend(counter.clone()).build();
+        metrics.set_stake_summary(&StakeSummary {
+            active_stake: 100,
+            total_stake: 400,
+            registered_pools: 3,
+        });
+        let stats = counter.get_stats();
+        assert_eq!(stats.active_stake, 100);
+        assert_eq!(stats.stake_pool_cnt, 3);
+        assert_eq!(stats.delegation_participation, Some(0.25));
+    }
+
+    #[test]
+    fn pool_occupancy_keeps_the_high_water_mark() {
+        let counter = SimpleCounter::default();
+        for entries in &[3, 5, 1] {
+            counter.set_pool_occupancy(PoolOccupancy {
+                entries: *entries,
+                size_bytes: entries * 100,
+            });
+        }
+        counter.add_pool_evicted_cnt(PoolEviction::Full, 2);
+        counter.add_pool_evicted_cnt(PoolEviction::Rejected, 1);
+        counter.add_pool_evicted_cnt(PoolEviction::Expired, 4);
+
+        let stats = counter.get_stats();
+        assert_eq!(stats.pool_entry_cnt, 1);
+        assert_eq!(stats.pool_size_bytes, 100);
+        assert_eq!(stats.pool_entry_max_cnt, 5);
+        assert_eq!(stats.pool_full_evicted_cnt, 2);
+        assert_eq!(stats.pool_rejected_evicted_cnt, 1);
+        // the expired fragments are only counted by `tx_expired_cnt`
+        assert_eq!(stats.tx_expired_cnt, 0);
     }
 }
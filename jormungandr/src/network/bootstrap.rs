@@ -1,18 +1,22 @@
 use super::grpc;
-use crate::blockcfg::{Block, HeaderDesc, HeaderHash};
+use crate::blockcfg::{Block, HeaderDesc, HeaderHash, Leadership};
 use crate::blockchain::{self, Blockchain, Error as BlockchainError, PreCheckedHeader, Ref, Tip};
 use crate::network::convert::Decode;
 use crate::settings::start::network::Peer;
 use crate::topology;
+use crate::utils::clock;
 use chain_core::property::{Deserialize, HasHeader};
 use chain_network::data as net_data;
 use chain_network::error::Error as NetworkError;
 use futures::{prelude::*, stream, task::Poll};
+use jormungandr_lib::interfaces::BootstrapStatus;
 use tokio_util::sync::CancellationToken;
 
 use std::fmt::Debug;
+use std::net::SocketAddr;
 use std::pin::Pin;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
@@ -32,6 +36,8 @@ pub enum Error {
     PeerDecodingFailed(#[source] NetworkError),
     #[error("decoding of a block failed")]
     BlockDecodingFailed(#[source] <Block as Deserialize>::Error),
+    #[error("the block verification task failed")]
+    BlockCheckTaskFailed(#[source] tokio::task::JoinError),
     #[error("block header check failed")]
     HeaderCheckFailed(#[source] BlockchainError),
     #[error(
@@ -56,6 +62,110 @@ pub enum Error {
 
 const MAX_BOOTSTRAP_PEERS: u32 = 32;
 
+// Number of blocks decoded and verified on the blocking thread pool ahead of
+// the block being applied to the ledger
+const BLOCK_PIPELINE_DEPTH: usize = 32;
+
+// How often the progress of the bootstrap is logged while blocks are applied
+const PROGRESS_LOG_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Progress of the bootstrap, updated by the bootstrap process and read by
+/// the REST API so that a bootstrapping node can be followed from the outside.
+#[derive(Clone, Default)]
+pub struct Progress {
+    inner: Arc<Mutex<ProgressState>>,
+}
+
+#[derive(Default)]
+struct ProgressState {
+    status: BootstrapStatus,
+    // when the blocks started to be downloaded from the current peer
+    peer_started: Option<Instant>,
+    blocks_from_peer: u64,
+    last_logged: Option<Instant>,
+}
+
+impl Progress {
+    pub fn start_attempt(&self) {
+        self.inner.lock().unwrap().status.attempt += 1;
+    }
+
+    pub fn set_peer(&self, peer: SocketAddr) {
+        let mut state = self.inner.lock().unwrap();
+        state.status.peer = Some(peer);
+        state.status.network_chain_length = None;
+        state.peer_started = Some(Instant::now());
+        state.blocks_from_peer = 0;
+        state.update_estimates();
+    }
+
+    /// Records the chain lengths of the tip of the node and of the tip
+    /// announced by the peer.
+    pub fn set_tips(&self, chain_length: u32, network_chain_length: u32) {
+        let mut state = self.inner.lock().unwrap();
+        state.status.chain_length = chain_length;
+        state.status.network_chain_length = Some(network_chain_length);
+        state.update_estimates();
+    }
+
+    pub fn block_applied(&self, chain_length: u32) {
+        let mut state = self.inner.lock().unwrap();
+        state.status.blocks_applied += 1;
+        state.status.chain_length = chain_length;
+        state.blocks_from_peer += 1;
+        state.update_estimates();
+
+        let now = Instant::now();
+        if state.last_logged.map_or(true, |last| {
+            now.duration_since(last) >= PROGRESS_LOG_INTERVAL
+        }) {
+            state.last_logged = Some(now);
+            let status = &state.status;
+            let estimated_remaining_time = status
+                .estimated_remaining_time
+                .map_or_else(|| "unknown".to_owned(), |time| time.to_string());
+            tracing::info!(
+                peer = ?status.peer,
+                blocks_applied = status.blocks_applied,
+                chain_length = status.chain_length,
+                network_chain_length = ?status.network_chain_length,
+                remaining_blocks = ?status.remaining_blocks,
+                estimated_remaining_time = %estimated_remaining_time,
+                "bootstrap progress"
+            );
+        }
+    }
+
+    pub fn complete(&self) {
+        let mut state = self.inner.lock().unwrap();
+        state.status.completed = true;
+        state.status.peer = None;
+        state.update_estimates();
+    }
+
+    pub fn status(&self) -> BootstrapStatus {
+        self.inner.lock().unwrap().status.clone()
+    }
+}
+
+impl ProgressState {
+    fn update_estimates(&mut self) {
+        let remaining_blocks = self
+            .status
+            .network_chain_length
+            .map(|network| network.saturating_sub(self.status.chain_length));
+        self.status.remaining_blocks = remaining_blocks;
+        // the rate is only known once some blocks were received from the peer
+        self.status.estimated_remaining_time = match (remaining_blocks, self.peer_started) {
+            (Some(remaining), Some(started)) if self.blocks_from_peer > 0 => {
+                let per_block = started.elapsed().as_secs_f64() / self.blocks_from_peer as f64;
+                Some(Duration::from_secs_f64(per_block * f64::from(remaining)).into())
+            }
+            _ => None,
+        };
+    }
+}
+
 pub async fn peers_from_trusted_peer(peer: &Peer) -> Result<Vec<topology::Peer>, Error> {
     tracing::info!("getting peers from bootstrap peer {}", peer.connection);
 
@@ -80,6 +190,7 @@ pub async fn bootstrap_from_peer(
     peer: &Peer,
     blockchain: Blockchain,
     tip: Tip,
+    progress: &Progress,
     cancellation_token: CancellationToken,
 ) -> Result<(), Error> {
     use chain_network::data::BlockId;
@@ -107,10 +218,15 @@ pub async fn bootstrap_from_peer(
         let remote_tip = with_cancellation_token(client.tip().boxed(), &cancellation_token)
             .await?
             .and_then(|header| header.decode())
-            .map_err(Error::TipFailed)?
-            .id();
+            .map_err(Error::TipFailed)?;
+        let local_tip = tip.get_ref().await;
+        progress.set_tips(
+            local_tip.chain_length().into(),
+            remote_tip.chain_length().into(),
+        );
+        let remote_tip = remote_tip.id();
 
-        if remote_tip == tip.get_ref().await.hash() {
+        if remote_tip == local_tip.hash() {
             break Ok(());
         }
 
@@ -136,6 +252,7 @@ pub async fn bootstrap_from_peer(
             blockchain.clone(),
             tip.clone(),
             stream,
+            progress,
             cancellation_token.clone(),
         )
         .await?;
@@ -152,7 +269,7 @@ struct BootstrapInfo {
 
 impl BootstrapInfo {
     pub fn new() -> Self {
-        let now = std::time::SystemTime::now();
+        let now = clock::now();
         let lbd: Option<HeaderDesc> = None;
         BootstrapInfo {
             last_reported: now,
@@ -180,7 +297,7 @@ impl BootstrapInfo {
                 format!("{:.2}b", n)
             }
         }
-        let current = std::time::SystemTime::now();
+        let current = clock::now();
         let time_diff = current.duration_since(self.last_reported);
         let bytes_diff = self.bytes_received - self.last_bytes_received;
 
@@ -210,6 +327,7 @@ async fn bootstrap_from_stream<S>(
     mut blockchain: Blockchain,
     branch: Tip,
     stream: S,
+    progress: &Progress,
     cancellation_token: CancellationToken,
 ) -> Result<(), Error>
 where
@@ -239,29 +357,50 @@ where
         }
     });
 
+    // The blocks have to be applied to the ledger one after the other, but the
+    // proofs of their headers only depend on the leadership schedule of their
+    // epoch: the next blocks of the epoch of the last applied block are decoded
+    // and verified in parallel while a block is applied.
+    let last_applied = Arc::new(Mutex::new(None));
+    let mut stream = stream
+        .map({
+            let last_applied = Arc::clone(&last_applied);
+            move |block_result| {
+                let last_applied = last_applied.lock().unwrap().clone();
+                async move {
+                    let block = block_result?;
+                    tokio::task::spawn_blocking(move || {
+                        decode_and_verify_block(block, last_applied)
+                    })
+                    .await
+                    .map_err(Error::BlockCheckTaskFailed)?
+                }
+            }
+        })
+        .buffered(BLOCK_PIPELINE_DEPTH);
+
     while let Some(block_result) = stream.next().await {
         let result = match block_result {
-            Ok(block) => {
-                let block =
-                    Block::deserialize(block.as_bytes()).map_err(Error::BlockDecodingFailed)?;
-
-                if block.header.hash() == block0 {
+            Ok(pulled) => {
+                if pulled.block.header.hash() == block0 {
                     continue;
                 }
 
-                bootstrap_info.append_block(&block);
+                bootstrap_info.append_block(&pulled.block);
 
                 if bootstrap_info.block_received % PROCESS_LOGGING_DISTANCE == 0 {
                     bootstrap_info.report();
                 }
 
-                handle_block(&blockchain, block).await
+                handle_block(&blockchain, pulled).await
             }
             Err(err) => Err(err),
         };
 
         match result {
             Ok(parent_tip) => {
+                progress.block_applied(parent_tip.chain_length().into());
+                *last_applied.lock().unwrap() = Some(Arc::clone(&parent_tip));
                 maybe_parent_tip = Some(parent_tip);
             }
             Err(err) => {
@@ -293,7 +432,35 @@ where
     }
 }
 
-async fn handle_block(blockchain: &Blockchain, block: Block) -> Result<Arc<Ref>, Error> {
+/// A block pulled from the peer, along with the leadership schedule its header
+/// proof was verified against ahead of its application, if any.
+struct PulledBlock {
+    block: Block,
+    verified_with: Option<Arc<Leadership>>,
+}
+
+fn decode_and_verify_block(
+    block: net_data::Block,
+    last_applied: Option<Arc<Ref>>,
+) -> Result<PulledBlock, Error> {
+    let block = Block::deserialize(block.as_bytes()).map_err(Error::BlockDecodingFailed)?;
+    // a failure is not final: the block may not be a child of the last
+    // applied one, the proof is then verified again when the block is applied
+    let verified_with = last_applied
+        .filter(|applied| applied.block_date().epoch == block.header.block_date().epoch)
+        .map(|applied| Arc::clone(applied.epoch_leadership_schedule()))
+        .filter(|leadership| blockchain::verify_header_proof(&block.header, leadership).is_ok());
+    Ok(PulledBlock {
+        block,
+        verified_with,
+    })
+}
+
+async fn handle_block(blockchain: &Blockchain, pulled: PulledBlock) -> Result<Arc<Ref>, Error> {
+    let PulledBlock {
+        block,
+        verified_with,
+    } = pulled;
     let header = block.header();
     let pre_checked = blockchain
         .pre_check_header(header, true)
@@ -312,8 +479,19 @@ async fn handle_block(blockchain: &Blockchain, block: Block) -> Result<Arc<Ref>,
             Err(Error::BlockMissingParent(header.hash()))
         }
         PreCheckedHeader::HeaderWithCache { header, parent_ref } => {
+            // within an epoch, the header is verified against the leadership
+            // schedule of its parent
+            let check_header_proof = match verified_with {
+                Some(leadership)
+                    if parent_ref.block_date().epoch == header.block_date().epoch
+                        && Arc::ptr_eq(&leadership, parent_ref.epoch_leadership_schedule()) =>
+                {
+                    blockchain::CheckHeaderProof::SkipVerifiedAhead
+                }
+                _ => blockchain::CheckHeaderProof::Enabled,
+            };
             let post_checked = blockchain
-                .post_check_header(header, parent_ref, blockchain::CheckHeaderProof::Enabled)
+                .post_check_header(header, parent_ref, check_header_proof)
                 .await
                 .map_err(Error::HeaderCheckFailed)?;
 
@@ -330,3 +508,39 @@ async fn handle_block(blockchain: &Blockchain, block: Block) -> Result<Arc<Ref>,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn progress_follows_the_blocks_applied() {
+        let progress = Progress::default();
+        progress.start_attempt();
+        let peer = SocketAddr::from(([127, 0, 0, 1], 3000));
+        progress.set_peer(peer);
+        let status = progress.status();
+        assert_eq!(status.attempt, 1);
+        assert_eq!(status.peer, Some(peer));
+        assert_eq!(status.remaining_blocks, None);
+
+        progress.set_tips(10, 20);
+        let status = progress.status();
+        assert_eq!(status.remaining_blocks, Some(10));
+        // no block was received from the peer yet
+        assert_eq!(status.estimated_remaining_time, None);
+
+        progress.block_applied(11);
+        progress.block_applied(12);
+        let status = progress.status();
+        assert_eq!(status.blocks_applied, 2);
+        assert_eq!(status.chain_length, 12);
+        assert_eq!(status.remaining_blocks, Some(8));
+        assert!(status.estimated_remaining_time.is_some());
+
+        progress.complete();
+        let status = progress.status();
+        assert!(status.completed);
+        assert_eq!(status.peer, None);
+    }
+}
@@ -0,0 +1,115 @@
+//! gRPC methods for the services running on the same host as the node, such
+//! as vote collectors, that submit fragments at a higher rate than the REST
+//! interface allows. The service is not served along the peer-to-peer
+//! protocol but on a separate listener, bound to the loopback interface.
+
+use crate::intercom::{self, TransactionMsg};
+use crate::utils::async_msg::MessageBox;
+use chain_core::property::Deserialize;
+use chain_impl_mockchain::fragment::Fragment;
+use jormungandr_lib::interfaces::{
+    FragmentOrigin, FragmentRejectionReason, FragmentsProcessingSummary, RejectedFragmentInfo,
+};
+use tonic::{Request, Response, Status};
+
+mod proto {
+    tonic::include_proto!("iohk.jormungandr.local");
+}
+
+use proto::{
+    local_node_server::{LocalNode, LocalNodeServer},
+    PostFragmentsRequest, PostFragmentsResponse, RejectedFragment, RejectionReason,
+};
+
+pub struct LocalService {
+    transaction_box: MessageBox<TransactionMsg>,
+}
+
+impl LocalService {
+    pub fn new(transaction_box: MessageBox<TransactionMsg>) -> LocalNodeServer<Self> {
+        LocalNodeServer::new(LocalService { transaction_box })
+    }
+}
+
+#[tonic::async_trait]
+impl LocalNode for LocalService {
+    async fn post_fragments(
+        &self,
+        request: Request<PostFragmentsRequest>,
+    ) -> Result<Response<PostFragmentsResponse>, Status> {
+        let request = request.into_inner();
+        let fragments = request
+            .fragments
+            .iter()
+            .enumerate()
+            .map(|(index, bytes)| {
+                Fragment::deserialize(bytes.as_slice()).map_err(|e| {
+                    Status::invalid_argument(format!("fragment {} is malformed: {}", index, e))
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let (reply_handle, reply_future) = intercom::unary_reply();
+        // the clients are services of the node operator, like the REST clients
+        let msg = TransactionMsg::SendTransactions {
+            origin: FragmentOrigin::Rest,
+            peer: None,
+            fragments,
+            fail_fast: request.fail_fast,
+            reply_handle,
+        };
+        self.transaction_box.clone().try_send(msg).map_err(|e| {
+            if e.is_full() {
+                Status::resource_exhausted("the fragment task is busy")
+            } else {
+                Status::unavailable("the fragment task is not running")
+            }
+        })?;
+        let summary = reply_future
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+        Ok(Response::new(summary.into()))
+    }
+}
+
+impl From<FragmentsProcessingSummary> for PostFragmentsResponse {
+    fn from(summary: FragmentsProcessingSummary) -> Self {
+        PostFragmentsResponse {
+            accepted: summary
+                .accepted
+                .iter()
+                .map(|id| id.as_ref().to_vec())
+                .collect(),
+            rejected: summary.rejected.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<RejectedFragmentInfo> for RejectedFragment {
+    fn from(info: RejectedFragmentInfo) -> Self {
+        let (reason, pool_number) = match info.reason {
+            FragmentRejectionReason::FragmentAlreadyInLog => {
+                (RejectionReason::FragmentAlreadyInLog, 0)
+            }
+            FragmentRejectionReason::FragmentInvalid => (RejectionReason::FragmentInvalid, 0),
+            FragmentRejectionReason::PreviousFragmentInvalid => {
+                (RejectionReason::PreviousFragmentInvalid, 0)
+            }
+            FragmentRejectionReason::PoolOverflow { pool_number } => {
+                (RejectionReason::PoolOverflow, pool_number as u32)
+            }
+            FragmentRejectionReason::FragmentExpired => (RejectionReason::FragmentExpired, 0),
+            FragmentRejectionReason::FragmentExpiryTooFar => {
+                (RejectionReason::FragmentExpiryTooFar, 0)
+            }
+            FragmentRejectionReason::FragmentExpiresTooSoon => {
+                (RejectionReason::FragmentExpiresTooSoon, 0)
+            }
+        };
+        RejectedFragment {
+            id: info.id.as_ref().to_vec(),
+            reason: reason as i32,
+            pool_number,
+        }
+    }
+}
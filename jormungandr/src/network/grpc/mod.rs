@@ -1,5 +1,6 @@
 pub(super) mod client;
+mod local;
 mod server;
 
 pub use self::client::{connect, fetch_block, Client, ConnectError, FetchBlockError};
-pub use self::server::run_listen_socket;
+pub use self::server::{run_listen_socket, run_local_listen_socket};
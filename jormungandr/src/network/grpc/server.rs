@@ -2,10 +2,13 @@ use super::super::{
     concurrency_limits, keepalive_durations, service::NodeService, Channels, GlobalStateR,
     ListenError,
 };
+use super::local::LocalService;
 use crate::settings::start::network::Listen;
 use chain_network::grpc;
 
-use tonic::transport::Server;
+use std::net::SocketAddr;
+use tonic::transport::{NamedService, Server};
+use tonic_health::ServingStatus;
 use tracing::{span, Level};
 
 use tracing_futures::Instrument;
@@ -21,9 +24,24 @@ pub async fn run_listen_socket(
         tracing::info!("listening and accepting gRPC connections");
         let service = grpc::server::Builder::new().build(NodeService::new(channels, state));
 
+        // standard gRPC health checking, for load balancers and probes, the
+        // empty service name standing for the server as a whole
+        let (mut health_reporter, health_service) = tonic_health::server::health_reporter();
+        for name in &["", service_name(&service)] {
+            health_reporter
+                .set_service_status(name, ServingStatus::Serving)
+                .await;
+        }
+
         Server::builder()
             .concurrency_limit_per_connection(concurrency_limits::SERVER_REQUESTS)
             .tcp_keepalive(Some(keepalive_durations::TCP))
+            .add_service(health_service)
+            .add_service(
+                reflection()
+                    .build()
+                    .expect("invalid gRPC service descriptors"),
+            )
             .add_service(service)
             .serve(sockaddr)
             .await
@@ -32,3 +50,50 @@ pub async fn run_listen_socket(
     .instrument(span)
     .await
 }
+
+/// Serves the gRPC services reserved to the clients on the host of the node.
+/// The address is a loopback one, the peers cannot reach these services.
+pub async fn run_local_listen_socket(
+    sockaddr: SocketAddr,
+    state: GlobalStateR,
+    channels: Channels,
+) -> Result<(), ListenError> {
+    let span = span!(parent: &state.span, Level::TRACE, "local_listen_socket", local_addr = %sockaddr.to_string());
+    async {
+        tracing::info!("listening and accepting local gRPC connections");
+        Server::builder()
+            .concurrency_limit_per_connection(concurrency_limits::SERVER_REQUESTS)
+            .add_service(LocalService::new(channels.transaction_box))
+            .serve(sockaddr)
+            .await
+            .map_err(|cause| ListenError { cause, sockaddr })
+    }
+    .instrument(span)
+    .await
+}
+
+fn service_name<S: NamedService>(_service: &S) -> &'static str {
+    S::NAME
+}
+
+/// gRPC reflection of the services of the P2P endpoint, for tools such as
+/// grpcurl
+fn reflection() -> tonic_reflection::server::Builder<'static> {
+    tonic_reflection::server::Builder::configure()
+        .register_encoded_file_descriptor_set(tonic::include_file_descriptor_set!(
+            "node_descriptor"
+        ))
+        .register_encoded_file_descriptor_set(
+            tonic_health::proto::GRPC_HEALTH_V1_FILE_DESCRIPTOR_SET,
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn service_descriptors_are_valid() {
+        reflection().build().unwrap();
+    }
+}
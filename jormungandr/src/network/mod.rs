@@ -71,17 +71,22 @@ mod security_params {
     pub const NONCE_LEN: usize = 32;
 }
 
+// How often the connections with the preferred peers are checked
+const PREFERRED_PEERS_CHECK_INTERVAL: Duration = Duration::from_secs(10);
+
 use self::client::ConnectError;
-use self::p2p::comm::Peers;
+use self::p2p::{comm::Peers, events::PeerEventLog};
 use crate::blockcfg::{Block, HeaderHash};
 use crate::blockchain::{Blockchain as NewBlockchain, Tip};
 use crate::intercom::{BlockMsg, ClientMsg, NetworkMsg, PropagateMsg, TopologyMsg, TransactionMsg};
 use crate::metrics::{Metrics, MetricsBackend};
-use crate::settings::start::network::{Configuration, Peer, Protocol};
+use crate::settings::start::network::{BlockPropagation, Configuration, Peer, Protocol};
 use crate::topology::{self, NodeId};
 use crate::utils::async_msg::{MessageBox, MessageQueue};
-use chain_network::data::NodeKeyPair;
+use chain_network::data::{Fragment as NetworkFragment, Header as NetworkHeader, NodeKeyPair};
+use jormungandr_lib::interfaces::{ConnectionDirection, PeerEventKind};
 use rand::seq::SliceRandom;
+use tokio::time::MissedTickBehavior;
 use tonic::transport;
 use tracing::{span, Level, Span};
 use tracing_futures::Instrument;
@@ -154,6 +159,7 @@ pub struct GlobalState {
     config: Configuration,
     stats_counter: Metrics,
     peers: Peers,
+    peer_events: PeerEventLog,
     keypair: NodeKeyPair,
     span: Span,
 
@@ -172,6 +178,11 @@ impl GlobalState {
     ) -> Self {
         let peers = Peers::new(
             config.max_connections,
+            config
+                .preferred_peers
+                .iter()
+                .map(|peer| peer.addr)
+                .collect(),
             span!(parent: &span, Level::TRACE, "peers"),
         );
 
@@ -186,6 +197,7 @@ impl GlobalState {
             config,
             stats_counter,
             peers,
+            peer_events: PeerEventLog::default(),
             keypair,
             span,
             connected_count: AtomicUsize::new(0),
@@ -200,6 +212,10 @@ impl GlobalState {
         self.config.public_address
     }
 
+    pub fn peer_events(&self) -> &PeerEventLog {
+        &self.peer_events
+    }
+
     pub fn spawn<F>(&self, f: F)
     where
         F: Future<Output = ()> + Send + 'static,
@@ -300,8 +316,49 @@ pub async fn start(params: TaskParams) {
         }
     };
 
+    // the services reserved to the clients on the host of the node
+    let local_state = global_state.clone();
+    let local_channels = channels.clone();
+    let local_listener = async move {
+        if let Some(sockaddr) = local_state.config.local_listen_address {
+            grpc::run_local_listen_socket(sockaddr, local_state, local_channels)
+                .await
+                .unwrap_or_else(|e| {
+                    tracing::error!(
+                        reason = %e,
+                        "failed to listen for local gRPC connections at {}", sockaddr
+                    );
+                });
+        }
+    };
+
     let handle_cmds = handle_network_input(input, global_state.clone(), channels.clone());
-    future::join(listener, handle_cmds).await;
+    let preferred_peers = keep_preferred_peers_connected(global_state.clone(), channels.clone());
+    future::join4(listener, local_listener, handle_cmds, preferred_peers).await;
+}
+
+/// Connects to the preferred peers the node is not connected to. The check is
+/// repeated periodically so that dropped connections are established again.
+async fn keep_preferred_peers_connected(state: GlobalStateR, channels: Channels) {
+    if state.config.preferred_peers.is_empty() {
+        return;
+    }
+    let mut interval = tokio::time::interval(PREFERRED_PEERS_CHECK_INTERVAL);
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    loop {
+        interval.tick().await;
+        for peer in &state.config.preferred_peers {
+            if !state.peers.contains(peer.addr).await {
+                connect_and_propagate(
+                    peer.addr,
+                    peer.id.clone(),
+                    state.clone(),
+                    channels.clone(),
+                    p2p::comm::ConnectOptions::default(),
+                );
+            }
+        }
+    }
 }
 
 async fn handle_network_input(
@@ -341,22 +398,28 @@ async fn handle_network_input(
     }
 }
 
+/// The peers of the current view of the topology for the given selection
+async fn view_peers(
+    sel: poldercast::layer::Selection,
+    mbox: &mut MessageBox<TopologyMsg>,
+) -> Result<Vec<topology::Peer>, PropagateError> {
+    let (reply_handle, reply_future) = crate::intercom::unary_reply();
+    mbox.send(TopologyMsg::View(sel, reply_handle)).await?;
+    let peers = reply_future.await.map(|view| view.peers)?;
+    Ok(peers)
+}
+
 // propagate message to every peer and return the ones that we could not contact
-async fn propagate_message<F, Fut, E, T>(
+async fn propagate_to_peers<F, Fut, E, T>(
     f: F,
-    sel: poldercast::layer::Selection,
+    peers: impl IntoIterator<Item = topology::Peer>,
     arg: T,
-    mbox: &mut MessageBox<TopologyMsg>,
-) -> Result<Vec<topology::Peer>, PropagateError>
+) -> Vec<topology::Peer>
 where
     T: Clone,
     F: Fn(SocketAddr, T) -> Fut,
     Fut: Future<Output = Result<(), E>>,
 {
-    let (reply_handle, reply_future) = crate::intercom::unary_reply();
-    mbox.send(TopologyMsg::View(sel, reply_handle)).await?;
-    let peers = reply_future.await.map(|view| view.peers)?;
-
     // FIXME: this is a workaround because we need to know also the id of the nodes that failed to connect,
     // it should not be less efficient, just less clean. Remove this once we decided what to do with peers
     // and ids.
@@ -366,7 +429,130 @@ where
             res.push(peer);
         }
     }
-    Ok(res)
+    res
+}
+
+/// Announces a block header to the peers selected by the topology, starting with the
+/// configured preferred and priority peers and limiting the number of other recipients
+/// to the configured fan-out.
+async fn propagate_block(
+    state: &GlobalStateR,
+    header: NetworkHeader,
+    mbox: &mut MessageBox<TopologyMsg>,
+) -> Result<Vec<topology::Peer>, PropagateError> {
+    use poldercast::layer::Selection;
+
+    let peers = view_peers(
+        Selection::Topic {
+            topic: crate::topology::topic::BLOCKS,
+        },
+        mbox,
+    )
+    .await?;
+    let (outside_view, recipients) = block_recipients(peers, &state.config.block_propagation);
+
+    // preferred and priority peers which are not part of the current view can
+    // still be reached if there is an established connection with them
+    for addr in outside_view {
+        if state
+            .peers
+            .propagate_block(addr, header.clone())
+            .await
+            .is_err()
+        {
+            tracing::debug!(
+                peer = %addr,
+                "peer is not connected, skipping block announcement"
+            );
+        }
+    }
+
+    Ok(propagate_to_peers(
+        |addr, header| state.peers.propagate_block(addr, header),
+        recipients,
+        header,
+    )
+    .await)
+}
+
+/// Selects the recipients of a block announcement: the preferred peers, then
+/// the priority peers, in the configured order, then the other peers of the
+/// view, at most `fan_out` peers in total besides the preferred peers. The
+/// preferred and priority peers which are not part of the view are returned
+/// apart, the priority ones are counted within the fan-out as well.
+fn block_recipients(
+    peers: Vec<topology::Peer>,
+    config: &BlockPropagation,
+) -> (Vec<SocketAddr>, Vec<topology::Peer>) {
+    let fan_out = config.fan_out.unwrap_or(usize::MAX);
+    let is_selected = |addr: &SocketAddr| {
+        config.preferred_peers.contains(addr) || config.priority_peers.contains(addr)
+    };
+    let (mut selected, others): (Vec<_>, Vec<_>) = peers
+        .into_iter()
+        .partition(|peer| is_selected(&peer.address()));
+
+    let priority_peers = config
+        .priority_peers
+        .iter()
+        .filter(|addr| !config.preferred_peers.contains(addr))
+        .take(fan_out);
+    let mut outside_view = Vec::new();
+    let mut recipients = Vec::new();
+    for addr in config.preferred_peers.iter().chain(priority_peers) {
+        match selected.iter().position(|peer| peer.address() == *addr) {
+            Some(index) => recipients.push(selected.swap_remove(index)),
+            None => outside_view.push(*addr),
+        }
+    }
+    let counted = outside_view.len() + recipients.len() - config.preferred_peers.len();
+    let others_max = fan_out.saturating_sub(counted);
+    recipients.extend(others.into_iter().take(others_max));
+    (outside_view, recipients)
+}
+
+/// Sends a fragment to the peers selected by the topology and to the preferred
+/// peers, which are reached even when they are not part of the current view.
+async fn propagate_fragment(
+    state: &GlobalStateR,
+    fragment: NetworkFragment,
+    mbox: &mut MessageBox<TopologyMsg>,
+) -> Result<Vec<topology::Peer>, PropagateError> {
+    use poldercast::layer::Selection;
+
+    let peers = view_peers(
+        Selection::Topic {
+            topic: crate::topology::topic::MESSAGES,
+        },
+        mbox,
+    )
+    .await?;
+
+    for preferred in state
+        .config
+        .preferred_peers
+        .iter()
+        .filter(|preferred| !peers.iter().any(|peer| peer.address() == preferred.addr))
+    {
+        if state
+            .peers
+            .propagate_fragment(preferred.addr, fragment.clone())
+            .await
+            .is_err()
+        {
+            tracing::debug!(
+                peer = %preferred.addr,
+                "preferred peer is not connected, skipping fragment propagation"
+            );
+        }
+    }
+
+    Ok(propagate_to_peers(
+        |addr, fragment| state.peers.propagate_fragment(addr, fragment),
+        peers,
+        fragment,
+    )
+    .await)
 }
 
 async fn handle_propagation_msg(
@@ -374,35 +560,18 @@ async fn handle_propagation_msg(
     state: GlobalStateR,
     mut channels: Channels,
 ) -> Result<(), PropagateError> {
-    use poldercast::layer::Selection;
     async {
         let prop_state = state.clone();
         let unreached_nodes = match &msg {
             PropagateMsg::Block(header) => {
                 tracing::debug!(hash = %header.hash(), "block to propagate");
                 let header = header.encode();
-                propagate_message(
-                    |addr, header| prop_state.peers.propagate_block(addr, header),
-                    Selection::Topic {
-                        topic: crate::topology::topic::BLOCKS,
-                    },
-                    header,
-                    &mut channels.topology_box,
-                )
-                .await?
+                propagate_block(&prop_state, header, &mut channels.topology_box).await?
             }
             PropagateMsg::Fragment(fragment) => {
                 tracing::debug!(hash = %fragment.hash(), "fragment to propagate");
                 let fragment = fragment.encode();
-                propagate_message(
-                    |addr, fragment| prop_state.peers.propagate_fragment(addr, fragment),
-                    Selection::Topic {
-                        topic: crate::topology::topic::MESSAGES,
-                    },
-                    fragment,
-                    &mut channels.topology_box,
-                )
-                .await?
+                propagate_fragment(&prop_state, fragment, &mut channels.topology_box).await?
             }
             PropagateMsg::Gossip(peer, gossips) => {
                 tracing::debug!("gossip to propagate");
@@ -439,7 +608,7 @@ async fn handle_propagation_msg(
                     }
                 };
                 let (addr, id) = (peer.address(), peer.id());
-                connect_and_propagate(addr, id, state.clone(), channels.clone(), options);
+                connect_and_propagate(addr, Some(id), state.clone(), channels.clone(), options);
             }
         }
         Ok(())
@@ -452,7 +621,7 @@ async fn handle_propagation_msg(
 // the public key
 fn connect_and_propagate(
     node_addr: SocketAddr,
-    node_id: NodeId,
+    node_id: Option<NodeId>,
     state: GlobalStateR,
     mut channels: Channels,
     mut options: p2p::comm::ConnectOptions,
@@ -465,6 +634,16 @@ fn connect_and_propagate(
             return;
         }
     }
+    if state.config.preferred_peers_only
+        && !state
+            .config
+            .preferred_peers
+            .iter()
+            .any(|peer| peer.addr == node_addr)
+    {
+        tracing::debug!(peer = %node_addr, "not a preferred peer, not connecting");
+        return;
+    }
     drop(_enter);
     let peer = Peer::new(node_addr);
     let conn_span = span!(parent: &state.span, Level::TRACE, "peer", node = %node_addr);
@@ -475,34 +654,49 @@ fn connect_and_propagate(
     let spawn_state = state.clone();
     let cf = async move {
         state.peers.add_connecting(node_addr, handle, options).await;
+        let peer_id = node_id.as_ref().map(|id| id.as_ref().to_string());
         match connecting.await {
             Err(e) => {
-                let benign = match e {
+                let event = match e {
                     ConnectError::Transport(e) => {
                         tracing::info!(reason = %e, "gRPC connection to peer failed");
-                        false
+                        Some(PeerEventKind::ConnectionFailed {
+                            reason: e.to_string(),
+                        })
                     }
                     ConnectError::Handshake(e) => {
                         tracing::info!(reason = %e, "protocol handshake with peer failed");
-                        false
+                        Some(PeerEventKind::HandshakeFailed {
+                            direction: ConnectionDirection::Outbound,
+                            reason: e.to_string(),
+                        })
                     }
                     ConnectError::Canceled => {
                         tracing::debug!("connection to peer has been canceled");
-                        true
+                        None
                     }
                     _ => {
                         tracing::info!(error = ?e, "connection to peer failed");
-                        false
+                        Some(PeerEventKind::HandshakeFailed {
+                            direction: ConnectionDirection::Outbound,
+                            reason: e.to_string(),
+                        })
                     }
                 };
+                let benign = event.is_none();
+                if let Some(event) = event {
+                    state.peer_events.record(node_addr, peer_id, event);
+                }
                 if !benign {
-                    channels
-                        .topology_box
-                        .send(TopologyMsg::DemotePeer(node_id))
-                        .await
-                        .unwrap_or_else(|e| {
-                            tracing::error!("Error sending message to topology task: {}", e)
-                        });
+                    if let Some(node_id) = node_id {
+                        channels
+                            .topology_box
+                            .send(TopologyMsg::DemotePeer(node_id))
+                            .await
+                            .unwrap_or_else(|e| {
+                                tracing::error!("Error sending message to topology task: {}", e)
+                            });
+                    }
                     state.peers.remove_peer(node_addr).await;
                 }
             }
@@ -512,17 +706,42 @@ fn connect_and_propagate(
                 state.peers.update_entry(node_addr).await;
 
                 state.inc_client_count();
+                state.peer_events.record(
+                    node_addr,
+                    peer_id.clone(),
+                    PeerEventKind::Connected {
+                        direction: ConnectionDirection::Outbound,
+                    },
+                );
 
-                channels
-                    .topology_box
-                    .send(TopologyMsg::PromotePeer(node_id))
-                    .await
-                    .unwrap_or_else(|e| {
-                        tracing::error!("Error sending message to topology task: {}", e)
-                    });
+                if let Some(node_id) = node_id {
+                    channels
+                        .topology_box
+                        .send(TopologyMsg::PromotePeer(node_id))
+                        .await
+                        .unwrap_or_else(|e| {
+                            tracing::error!("Error sending message to topology task: {}", e)
+                        });
+                }
                 tracing::debug!(client_count = state.client_count(), "connected to peer");
                 client.await;
                 state.dec_client_count();
+                if state
+                    .config
+                    .preferred_peers
+                    .iter()
+                    .any(|peer| peer.addr == node_addr)
+                {
+                    // let the preferred peers task connect again
+                    state.peers.remove_peer(node_addr).await;
+                }
+                state.peer_events.record(
+                    node_addr,
+                    peer_id,
+                    PeerEventKind::Disconnected {
+                        reason: "client connection closed".to_owned(),
+                    },
+                );
             }
         }
     }
@@ -606,6 +825,7 @@ pub async fn bootstrap(
     config: &Configuration,
     blockchain: NewBlockchain,
     branch: Tip,
+    progress: &bootstrap::Progress,
     cancellation_token: CancellationToken,
     span: &Span,
 ) -> Result<NetworkBootstrapResult, bootstrap::Error> {
@@ -648,10 +868,12 @@ pub async fn bootstrap(
     for peer in &bootstrap_peers {
         let span =
             span!(parent: span, Level::TRACE, "bootstrap", peer_addr = %peer.address().to_string());
+        progress.set_peer(peer.address());
         let res = bootstrap::bootstrap_from_peer(
             &Peer::new(peer.address()),
             blockchain.clone(),
             branch.clone(),
+            progress,
             cancellation_token.clone(),
         )
         .await;
@@ -779,3 +1001,80 @@ pub enum FetchBlockError {
     #[error("could not download block hash {block}")]
     CouldNotDownloadBlock { block: HeaderHash },
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    fn peer(port: u16) -> topology::Peer {
+        topology::Peer::from(poldercast::Gossip::new(
+            SocketAddr::from(([127, 0, 0, 1], port)),
+            &keynesis::key::ed25519::SecretKey::new(rand_chacha::ChaChaRng::from_seed(
+                [port as u8; 32],
+            )),
+            poldercast::Subscriptions::new().as_slice(),
+        ))
+    }
+
+    fn addrs(ports: &[u16]) -> Vec<SocketAddr> {
+        ports
+            .iter()
+            .map(|port| SocketAddr::from(([127, 0, 0, 1], *port)))
+            .collect()
+    }
+
+    fn config(fan_out: Option<usize>, priority_peers: &[u16]) -> BlockPropagation {
+        BlockPropagation {
+            fan_out,
+            priority_peers: addrs(priority_peers),
+            preferred_peers: Vec::new(),
+        }
+    }
+
+    fn ports(peers: &[topology::Peer]) -> Vec<u16> {
+        peers.iter().map(|peer| peer.address().port()).collect()
+    }
+
+    #[test]
+    fn priority_peers_are_announced_first() {
+        let view = (1..=5).map(peer).collect();
+        let (outside_view, recipients) = block_recipients(view, &config(None, &[4, 2]));
+        assert!(outside_view.is_empty());
+        assert_eq!(ports(&recipients), vec![4, 2, 1, 3, 5]);
+    }
+
+    #[test]
+    fn priority_peers_are_counted_within_the_fan_out() {
+        let view = (1..=5).map(peer).collect();
+        let (outside_view, recipients) = block_recipients(view, &config(Some(3), &[4, 9]));
+        assert_eq!(outside_view, vec![SocketAddr::from(([127, 0, 0, 1], 9))]);
+        assert_eq!(ports(&recipients), vec![4, 1]);
+
+        let view = (1..=5).map(peer).collect();
+        let (outside_view, recipients) = block_recipients(view, &config(Some(2), &[9, 4, 3]));
+        assert_eq!(outside_view.len(), 1);
+        assert_eq!(ports(&recipients), vec![4]);
+    }
+
+    #[test]
+    fn preferred_peers_are_announced_outside_of_the_fan_out() {
+        let view = (1..=5).map(peer).collect();
+        let propagation = BlockPropagation {
+            preferred_peers: addrs(&[5, 8, 4]),
+            ..config(Some(2), &[4, 3, 2])
+        };
+        let (outside_view, recipients) = block_recipients(view, &propagation);
+        assert_eq!(outside_view, addrs(&[8]));
+        assert_eq!(ports(&recipients), vec![5, 4, 3, 2]);
+
+        let view = (1..=5).map(peer).collect();
+        let propagation = BlockPropagation {
+            preferred_peers: addrs(&[3]),
+            ..config(Some(1), &[])
+        };
+        let (outside_view, recipients) = block_recipients(view, &propagation);
+        assert!(outside_view.is_empty());
+        assert_eq!(ports(&recipients), vec![3, 1]);
+    }
+}
@@ -12,6 +12,7 @@ use rand::Rng;
 use std::fmt::Debug;
 use tracing::Span;
 
+use std::collections::HashSet;
 use std::fmt;
 use std::mem;
 use std::net::SocketAddr;
@@ -490,9 +491,10 @@ pub struct Peers {
 }
 
 impl Peers {
-    pub fn new(capacity: usize, span: Span) -> Self {
+    /// The `pinned` peers are never evicted to make room for other peers.
+    pub fn new(capacity: usize, pinned: HashSet<Address>, span: Span) -> Self {
         Peers {
-            mutex: Mutex::new(PeerMap::new(capacity)),
+            mutex: Mutex::new(PeerMap::new(capacity, pinned)),
             span,
         }
     }
@@ -533,6 +535,11 @@ impl Peers {
         .await
     }
 
+    /// Whether the peer is connected or a connection to it is in progress.
+    pub async fn contains(&self, peer: Address) -> bool {
+        self.inner().await.entry(peer).is_some()
+    }
+
     pub async fn update_entry(&self, peer: Address) {
         if let Some(ref mut peer) = self.inner().await.entry(peer) {
             peer.update_comm_status();
@@ -8,9 +8,12 @@ use crate::network::{
 use chain_network::data::NodeId;
 use linked_hash_map::LinkedHashMap;
 
+use std::collections::HashSet;
+
 pub struct PeerMap {
     map: LinkedHashMap<Address, PeerData>,
     capacity: usize,
+    pinned: HashSet<Address>,
 }
 
 #[derive(Default)]
@@ -73,10 +76,11 @@ impl<'a> CommStatus<'a> {
 }
 
 impl PeerMap {
-    pub fn new(capacity: usize) -> Self {
+    pub fn new(capacity: usize, pinned: HashSet<Address>) -> Self {
         PeerMap {
             map: LinkedHashMap::new(),
             capacity,
+            pinned,
         }
     }
 
@@ -152,10 +156,13 @@ impl PeerMap {
     }
 
     pub fn evict_clients(&mut self, num: usize) {
+        let pinned = &self.pinned;
         for entry in self
             .map
             .entries()
-            .filter(|entry| entry.get().comms.has_client_subscriptions())
+            .filter(|entry| {
+                !pinned.contains(entry.key()) && entry.get().comms.has_client_subscriptions()
+            })
             .take(num)
         {
             entry.remove();
@@ -164,7 +171,14 @@ impl PeerMap {
 
     fn evict_if_full(&mut self) {
         if self.map.len() >= self.capacity {
-            self.map.pop_front();
+            let pinned = &self.pinned;
+            if let Some(entry) = self
+                .map
+                .entries()
+                .find(|entry| !pinned.contains(entry.key()))
+            {
+                entry.remove();
+            }
         }
     }
 }
@@ -186,3 +200,31 @@ impl<'a> Entry<'a> {
         self.inner.remove();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn address(port: u16) -> Address {
+        Address::from(([127, 0, 0, 1], port))
+    }
+
+    fn addresses(map: &PeerMap) -> Vec<Address> {
+        map.infos()
+            .into_iter()
+            .filter_map(|info| info.addr)
+            .collect()
+    }
+
+    #[test]
+    fn pinned_peers_are_not_evicted() {
+        let mut map = PeerMap::new(2, vec![address(1)].into_iter().collect());
+        map.server_comms(address(1));
+        map.server_comms(address(2));
+        map.server_comms(address(3));
+        assert_eq!(addresses(&map), vec![address(1), address(3)]);
+
+        map.server_comms(address(4));
+        assert_eq!(addresses(&map), vec![address(1), address(4)]);
+    }
+}
@@ -0,0 +1,65 @@
+//! Recent events in the lifecycle of the peer connections, kept in memory so
+//! that the history of the connections can be inspected after an incident
+//! without enabling trace logs.
+
+use super::Address;
+use jormungandr_lib::{
+    interfaces::{PeerEvent, PeerEventKind},
+    time::SystemTime,
+};
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+// Number of the most recent events kept, older events are dropped.
+const PEER_EVENTS_CAPACITY: usize = 1024;
+
+#[derive(Clone, Default)]
+pub struct PeerEventLog {
+    events: Arc<Mutex<VecDeque<PeerEvent>>>,
+}
+
+impl PeerEventLog {
+    pub fn record(&self, address: Address, node_id: Option<String>, kind: PeerEventKind) {
+        let event = PeerEvent {
+            time: SystemTime::now(),
+            address,
+            node_id,
+            kind,
+        };
+        let mut events = self.events.lock().unwrap();
+        if events.len() == PEER_EVENTS_CAPACITY {
+            events.pop_front();
+        }
+        events.push_back(event);
+    }
+
+    /// Events recorded so far, the oldest first.
+    pub fn events(&self) -> Vec<PeerEvent> {
+        self.events.lock().unwrap().iter().cloned().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn address(port: u16) -> Address {
+        Address::from(([127, 0, 0, 1], port))
+    }
+
+    #[test]
+    fn only_the_most_recent_events_are_kept() {
+        let log = PeerEventLog::default();
+        for port in 0..PEER_EVENTS_CAPACITY as u16 + 2 {
+            log.record(address(port), None, PeerEventKind::Quarantined);
+        }
+        let events = log.events();
+        assert_eq!(events.len(), PEER_EVENTS_CAPACITY);
+        assert_eq!(events[0].address, address(2));
+        assert_eq!(
+            events.last().unwrap().address,
+            address(PEER_EVENTS_CAPACITY as u16 + 1)
+        );
+    }
+}
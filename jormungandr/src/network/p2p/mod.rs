@@ -10,5 +10,6 @@
 /// Leaving this to when we will introduce identity verification, since requirements
 /// are likely to change.
 pub mod comm;
+pub mod events;
 
 pub type Address = std::net::SocketAddr;
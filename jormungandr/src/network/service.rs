@@ -14,6 +14,7 @@ use chain_network::data::{
     Block, BlockId, BlockIds, Fragment, FragmentIds, Gossip, HandshakeResponse, Header,
 };
 use chain_network::error::{Code as ErrorCode, Error};
+use jormungandr_lib::interfaces::{ConnectionDirection, PeerEventKind};
 
 use async_trait::async_trait;
 use futures::prelude::*;
@@ -81,8 +82,25 @@ impl Node for NodeService {
                 "nonce is missing, perform Handshake first",
             )
         })?;
-        auth.verify(&nonce[..])?;
+        if let Err(e) = auth.verify(&nonce[..]) {
+            self.global_state.peer_events().record(
+                addr,
+                None,
+                PeerEventKind::HandshakeFailed {
+                    direction: ConnectionDirection::Inbound,
+                    reason: e.to_string(),
+                },
+            );
+            return Err(e.into());
+        }
         self.global_state.peers.set_node_id(addr, auth.into()).await;
+        self.global_state.peer_events().record(
+            addr,
+            None,
+            PeerEventKind::Connected {
+                direction: ConnectionDirection::Inbound,
+            },
+        );
         Ok(())
     }
 
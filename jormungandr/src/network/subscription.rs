@@ -351,6 +351,7 @@ impl FragmentProcessor {
         self.mbox
             .start_send(TransactionMsg::SendTransactions {
                 origin: FragmentOrigin::Network,
+                peer: Some(self.node_id),
                 fragments,
                 fail_fast: false,
                 reply_handle,
@@ -0,0 +1,171 @@
+//! Merkle trees of the single accounts of the ledger at the end of an epoch,
+//! served as balance proofs by the REST API.
+
+use crate::blockcfg::HeaderHash;
+use crate::blockchain::Ref;
+
+use chain_impl_mockchain::account::Identifier;
+use jormungandr_lib::interfaces::{AccountsMerkleTree, AccountsSnapshot, BalanceProof, Value};
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+// the proofs of a snapshot are usually requested in batches, possibly for a
+// few epochs at the same time: the trees of the last requested epochs are kept
+const CACHED_TREES: usize = 4;
+
+/// Merkle tree of the single accounts of the ledger at the end of an epoch
+pub struct AccountsTree {
+    epoch: u32,
+    block: HeaderHash,
+    /// the accounts in the order of the leaves, by public key
+    accounts: Vec<(Identifier, Value)>,
+    tree: AccountsMerkleTree,
+}
+
+impl AccountsTree {
+    /// Sorts and hashes all the accounts of the ledger, to be run on a
+    /// blocking thread.
+    pub fn new(epoch: u32, reference: &Ref) -> Self {
+        let accounts = reference
+            .ledger()
+            .accounts()
+            .iter()
+            .map(|(identifier, state)| (identifier.clone(), state.value().into()))
+            .collect();
+        Self::from_accounts(epoch, reference.hash(), accounts)
+    }
+
+    fn from_accounts(
+        epoch: u32,
+        block: HeaderHash,
+        mut accounts: Vec<(Identifier, Value)>,
+    ) -> Self {
+        accounts.sort_by(|(a, _), (b, _)| key_bytes(a).cmp(key_bytes(b)));
+        let tree = AccountsMerkleTree::new(
+            accounts
+                .iter()
+                .map(|(identifier, value)| (key_bytes(identifier), *value)),
+        );
+        AccountsTree {
+            epoch,
+            block,
+            accounts,
+            tree,
+        }
+    }
+
+    pub fn snapshot(&self) -> AccountsSnapshot {
+        AccountsSnapshot {
+            epoch: self.epoch,
+            block: self.block.into(),
+            root: self.tree.root(),
+            accounts: self.tree.len() as u64,
+        }
+    }
+
+    pub fn proof(&self, account_id: &Identifier) -> Option<BalanceProof> {
+        let index = self
+            .accounts
+            .binary_search_by(|(identifier, _)| key_bytes(identifier).cmp(key_bytes(account_id)))
+            .ok()?;
+        Some(BalanceProof {
+            snapshot: self.snapshot(),
+            account_id: hex::encode(key_bytes(account_id)),
+            value: self.accounts[index].1,
+            index: index as u64,
+            path: self.tree.path(index),
+        })
+    }
+}
+
+fn key_bytes(identifier: &Identifier) -> &[u8] {
+    identifier.as_ref().as_ref()
+}
+
+/// The trees of the last requested epochs, the most recently used last.
+#[derive(Default)]
+pub struct AccountsTrees {
+    trees: Mutex<VecDeque<Arc<AccountsTree>>>,
+}
+
+impl AccountsTrees {
+    /// The tree of `epoch`, if it was built from the state at `block`. The
+    /// epoch can end with another block after a switch of branch.
+    pub fn get(&self, epoch: u32, block: HeaderHash) -> Option<Arc<AccountsTree>> {
+        let mut trees = self.trees.lock().unwrap();
+        let position = trees
+            .iter()
+            .position(|tree| tree.epoch == epoch && tree.block == block)?;
+        let tree = trees.remove(position).unwrap();
+        trees.push_back(Arc::clone(&tree));
+        Some(tree)
+    }
+
+    pub fn insert(&self, tree: Arc<AccountsTree>) {
+        let mut trees = self.trees.lock().unwrap();
+        trees.retain(|cached| cached.epoch != tree.epoch);
+        if trees.len() == CACHED_TREES {
+            trees.pop_front();
+        }
+        trees.push_back(tree);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chain_crypto::{Ed25519, SecretKey};
+
+    fn tree(epoch: u32, count: u64) -> AccountsTree {
+        let accounts = (0..count)
+            .map(|i| {
+                let key = SecretKey::<Ed25519>::generate(rand::rngs::OsRng).to_public();
+                (Identifier::from(key), Value::from(i * 100))
+            })
+            .collect();
+        AccountsTree::from_accounts(
+            epoch,
+            HeaderHash::hash_bytes(&epoch.to_be_bytes()),
+            accounts,
+        )
+    }
+
+    #[test]
+    fn proofs_of_ledger_accounts_verify() {
+        let tree = tree(1, 7);
+        for (identifier, value) in &tree.accounts {
+            let proof = tree.proof(identifier).unwrap();
+            assert_eq!(proof.value, *value);
+            assert_eq!(proof.snapshot, tree.snapshot());
+            proof.verify().unwrap();
+        }
+
+        let unknown = SecretKey::<Ed25519>::generate(rand::rngs::OsRng).to_public();
+        assert!(tree.proof(&Identifier::from(unknown)).is_none());
+    }
+
+    #[test]
+    fn trees_of_several_epochs_are_cached() {
+        let trees = AccountsTrees::default();
+        let cached: Vec<_> = (0..=CACHED_TREES as u32)
+            .map(|epoch| Arc::new(tree(epoch, 2)))
+            .collect();
+        for tree in &cached[..CACHED_TREES] {
+            trees.insert(Arc::clone(tree));
+        }
+        // alternating between epochs does not rebuild their trees
+        assert!(trees.get(0, cached[0].block).is_some());
+        assert!(trees.get(1, cached[1].block).is_some());
+        assert!(trees.get(0, cached[0].block).is_some());
+        assert!(trees.get(1, cached[0].block).is_none());
+
+        // the least recently used tree is the one evicted
+        trees.insert(Arc::clone(&cached[CACHED_TREES]));
+        assert!(trees.get(2, cached[2].block).is_none());
+        assert!(trees.get(0, cached[0].block).is_some());
+        assert!(trees
+            .get(CACHED_TREES as u32, cached[CACHED_TREES].block)
+            .is_some());
+    }
+}
@@ -5,13 +5,13 @@ use crate::{
     diagnostic::Diagnostic,
     intercom::{NetworkMsg, TopologyMsg, TransactionMsg},
     leadership::Logs as LeadershipLogs,
-    metrics::backends::SimpleCounter,
-    network::GlobalStateR as NetworkStateR,
-    rest::ServerStopper,
+    metrics::{backends::SimpleCounter, Metrics},
+    network::{bootstrap::Progress as BootstrapProgress, GlobalStateR as NetworkStateR},
+    rest::{accounts_tree::AccountsTrees, metrics::RequestMetrics, ServerStopper},
     secure::enclave::Enclave,
     utils::async_msg::MessageBox,
 };
-use jormungandr_lib::interfaces::NodeState;
+use jormungandr_lib::interfaces::{ConfigurationSummary, NodeState};
 
 use tokio::sync::RwLock;
 use tokio_util::sync::CancellationToken;
@@ -25,9 +25,14 @@ pub struct Context {
     node_state: NodeState,
     span: Option<Span>,
     diagnostic: Option<Diagnostic>,
+    configuration_summary: Option<ConfigurationSummary>,
     blockchain: Option<Blockchain>,
     blockchain_tip: Option<Tip>,
     bootstrap_stopper: Option<CancellationToken>,
+    node_stopper: Option<CancellationToken>,
+    request_metrics: Arc<RequestMetrics>,
+    accounts_trees: Arc<AccountsTrees>,
+    bootstrap_progress: BootstrapProgress,
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -44,6 +49,8 @@ pub enum Error {
     BlockchainTip,
     #[error("Diagnostic data not set in REST context")]
     Diagnostic,
+    #[error("Configuration summary not set in REST context")]
+    ConfigurationSummary,
 }
 
 impl warp::reject::Reject for Error {}
@@ -62,9 +69,14 @@ impl Context {
             node_state: NodeState::StartingRestServer,
             span: Default::default(),
             diagnostic: Default::default(),
+            configuration_summary: Default::default(),
             blockchain: Default::default(),
             blockchain_tip: Default::default(),
             bootstrap_stopper: Default::default(),
+            node_stopper: Default::default(),
+            request_metrics: Default::default(),
+            accounts_trees: Default::default(),
+            bootstrap_progress: Default::default(),
         }
     }
 
@@ -108,6 +120,16 @@ impl Context {
         self.diagnostic.as_ref().ok_or(Error::Diagnostic)
     }
 
+    pub fn set_configuration_summary(&mut self, configuration_summary: ConfigurationSummary) {
+        self.configuration_summary = Some(configuration_summary);
+    }
+
+    pub fn configuration_summary(&self) -> Result<&ConfigurationSummary, Error> {
+        self.configuration_summary
+            .as_ref()
+            .ok_or(Error::ConfigurationSummary)
+    }
+
     pub fn set_blockchain(&mut self, blockchain: Blockchain) {
         self.blockchain = Some(blockchain)
     }
@@ -137,6 +159,40 @@ impl Context {
             cancellation_token.cancel();
         }
     }
+
+    pub fn bootstrap_progress(&self) -> &BootstrapProgress {
+        &self.bootstrap_progress
+    }
+
+    /// Starts recording the statistics of the handled requests.
+    pub fn set_metrics(&self, metrics: Metrics) {
+        self.request_metrics.set_metrics(metrics);
+    }
+
+    pub(super) fn request_metrics(&self) -> Arc<RequestMetrics> {
+        Arc::clone(&self.request_metrics)
+    }
+
+    /// The Merkle trees of the accounts of the last requested epochs.
+    pub(super) fn accounts_trees(&self) -> Arc<AccountsTrees> {
+        Arc::clone(&self.accounts_trees)
+    }
+
+    pub fn set_node_stopper(&mut self, node_stopper: CancellationToken) {
+        self.node_stopper = Some(node_stopper);
+    }
+
+    /// Requests a graceful shutdown of the running node. Returns `false` if
+    /// the node services are not started yet.
+    pub fn stop_node(&mut self) -> bool {
+        match self.node_stopper.take() {
+            Some(cancellation_token) => {
+                cancellation_token.cancel();
+                true
+            }
+            None => false,
+        }
+    }
 }
 
 pub struct FullContext {
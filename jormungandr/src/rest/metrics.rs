@@ -0,0 +1,186 @@
+//! Per route statistics of the REST API, forwarded to the metrics backends
+//! of the node.
+
+use crate::metrics::{Metrics, MetricsBackend};
+
+use arc_swap::ArcSwapOption;
+
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+
+// Paths of unknown routes are made of arbitrary segments, the number of
+// distinct routes is bounded to keep the metrics from growing without limit.
+const MAX_ROUTES: usize = 128;
+const OTHER_ROUTE: &str = "other";
+const PARAMETER_SEGMENT: &str = "{param}";
+
+// The fixed segments of the paths served by the REST API, in sorted order. The
+// other segments are the parameters of the routes: a segment missing here is
+// reported as a parameter, it does not add routes to the metrics.
+const ROUTE_SEGMENTS: &[&str] = &[
+    "account",
+    "account-votes",
+    "accounts",
+    "active",
+    "api",
+    "available",
+    "balance-proof",
+    "block",
+    "blocks",
+    "bootstrap",
+    "clock",
+    "committees",
+    "configuration",
+    "conflicts",
+    "dashboard",
+    "diagnostic",
+    "epoch",
+    "events",
+    "expired",
+    "explorer",
+    "fragment",
+    "fragments",
+    "graphql",
+    "history",
+    "leaders",
+    "logs",
+    "message",
+    "network",
+    "next_id",
+    "node",
+    "non_public",
+    "openapi.json",
+    "p2p",
+    "plan",
+    "plans",
+    "playground",
+    "projection",
+    "prometheus",
+    "quarantined",
+    "rewards",
+    "settings",
+    "shutdown",
+    "snapshot",
+    "stake",
+    "stake-pools",
+    "stake_pool",
+    "stake_pools",
+    "state",
+    "stats",
+    "status",
+    "statuses",
+    "step",
+    "subscription",
+    "tip",
+    "utxo",
+    "v0",
+    "v1",
+    "view",
+    "vote",
+    "votes",
+];
+
+/// The REST server is started before the metrics backends are created, the
+/// requests handled until then are not recorded.
+#[derive(Default)]
+pub struct RequestMetrics {
+    metrics: ArcSwapOption<Metrics>,
+    routes: Mutex<HashSet<String>>,
+}
+
+impl RequestMetrics {
+    pub fn set_metrics(&self, metrics: Metrics) {
+        self.metrics.store(Some(Arc::new(metrics)));
+    }
+
+    pub fn record(&self, info: warp::log::Info) {
+        if let Some(metrics) = &*self.metrics.load() {
+            let route = self.route(info.path());
+            metrics.add_rest_request(&route, info.status().as_u16(), info.elapsed());
+        }
+    }
+
+    /// Replaces the parameters of the path (identifiers, addresses, numbers...)
+    /// so that all the requests to the same endpoint share the same route.
+    fn route(&self, path: &str) -> String {
+        let route = path
+            .split('/')
+            .map(|segment| {
+                if is_static_segment(segment) {
+                    segment
+                } else {
+                    PARAMETER_SEGMENT
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("/");
+
+        let mut routes = self.routes.lock().unwrap();
+        if routes.contains(&route) {
+            route
+        } else if routes.len() < MAX_ROUTES {
+            routes.insert(route.clone());
+            route
+        } else {
+            OTHER_ROUTE.to_owned()
+        }
+    }
+}
+
+fn is_static_segment(segment: &str) -> bool {
+    segment.is_empty() || ROUTE_SEGMENTS.binary_search(&segment).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn route_segments_are_sorted() {
+        let mut sorted = ROUTE_SEGMENTS.to_vec();
+        sorted.sort_unstable();
+        sorted.dedup();
+        assert_eq!(ROUTE_SEGMENTS, &sorted[..]);
+    }
+
+    #[test]
+    fn parameters_are_replaced_in_routes() {
+        let metrics = RequestMetrics::default();
+        let cases = [
+            ("/api/v0/node/stats", "/api/v0/node/stats"),
+            ("/api/v1/node/stats", "/api/v1/node/stats"),
+            (
+                "/api/v0/account/ed25519_pk1qqqsyqcyq5rqwzqfpg9scrgwpugpzysn",
+                "/api/v0/account/{param}",
+            ),
+            (
+                "/api/v1/accounts/0123abcd/balance-proof",
+                "/api/v1/accounts/{param}/balance-proof",
+            ),
+            (
+                "/api/v0/block/deadbeef/next_id",
+                "/api/v0/block/{param}/next_id",
+            ),
+            ("/api/v0/utxo/abcdef/1", "/api/v0/utxo/{param}/{param}"),
+            ("/api/v1/votes/plan/active", "/api/v1/votes/plan/active"),
+            ("/api/v1/votes/plan/somename", "/api/v1/votes/plan/{param}"),
+            ("/api/v2/node/stats", "/api/{param}/node/stats"),
+            ("/api/openapi.json", "/api/openapi.json"),
+        ];
+        for (path, route) in cases.iter() {
+            assert_eq!(&metrics.route(path), route, "path {}", path);
+        }
+    }
+
+    #[test]
+    fn the_number_of_routes_is_bounded() {
+        let metrics = RequestMetrics::default();
+        for i in 0..MAX_ROUTES {
+            let path = format!("/api{}/v0", "/v1".repeat(i));
+            assert_eq!(metrics.route(&path), path);
+        }
+        assert_eq!(metrics.route("/api/v0/unknown/v1"), OTHER_ROUTE);
+        assert_eq!(metrics.route("/api"), OTHER_ROUTE);
+        assert_eq!(metrics.route("/api/v0"), "/api/v0");
+    }
+}
@@ -1,9 +1,13 @@
 //! REST API of the node
 
+mod accounts_tree;
 pub mod context;
 pub mod explorer;
+mod metrics;
+mod openapi;
 #[cfg(feature = "prometheus-metrics")]
 mod prometheus;
+mod timeout;
 pub mod v0;
 mod v1;
 
@@ -15,10 +19,16 @@ use futures::{channel::mpsc, prelude::*};
 use std::{error::Error, net::SocketAddr, time::Duration};
 use warp::Filter;
 
+/// Used when the node configuration does not limit the size of request bodies.
+/// It leaves plenty of room for large fragment batches.
+pub const DEFAULT_MAX_BODY_SIZE: u64 = 16 * 1024 * 1024;
+
 pub struct Config {
     pub listen: SocketAddr,
     pub tls: Option<Tls>,
     pub cors: Option<Cors>,
+    pub max_body_size: u64,
+    pub request_timeout: Option<Duration>,
     pub enable_explorer: bool,
     #[cfg(feature = "prometheus-metrics")]
     pub enable_prometheus: bool,
@@ -40,9 +50,24 @@ pub async fn start_rest_server(config: Config, context: ContextLock) {
         .write()
         .await
         .set_server_stopper(ServerStopper(stopper_tx));
+    let request_metrics = context.read().await.request_metrics();
+
+    // the explorer is not bounded by the request timeout on purpose, its
+    // subscriptions are long lived
+    let routes = openapi::filter()
+        .or(v0::filter(
+            context.clone(),
+            config.max_body_size,
+            config.request_timeout,
+        ))
+        .or(v1::filter(
+            context.clone(),
+            config.max_body_size,
+            config.request_timeout,
+        ));
 
     let api = warp::path!("api" / ..)
-        .and(v0::filter(context.clone()).or(v1::filter(context.clone())))
+        .and(routes)
         .with(warp::filters::trace::trace(|info| {
             use http_zipkin::get_trace_context;
             use tracing::field::Empty;
@@ -68,7 +93,8 @@ pub async fn start_rest_server(config: Config, context: ContextLock) {
                 }
             }
             span
-        }));
+        }))
+        .with(warp::log::custom(move |info| request_metrics.record(info)));
 
     setup_prometheus(api, config, context, stopper_rx).await;
 }
@@ -130,13 +156,20 @@ async fn setup_cors<App>(
     App::Extract: warp::Reply,
 {
     if let Some(cors_config) = config.cors {
-        let allowed_origins: Vec<&str> = cors_config
-            .allowed_origins
-            .iter()
-            .map(AsRef::as_ref)
-            .collect();
-
-        let mut cors = warp::cors().allow_origins(allowed_origins);
+        // the content type header lets browsers post JSON bodies
+        let mut cors = warp::cors().allow_header("content-type");
+
+        cors = if cors_config.allowed_origins.is_empty() {
+            cors.allow_any_origin()
+        } else {
+            cors.allow_origins(cors_config.allowed_origins.iter().map(AsRef::as_ref))
+        };
+
+        cors = if cors_config.allowed_methods.is_empty() {
+            cors.allow_methods(vec!["GET", "POST"])
+        } else {
+            cors.allow_methods(cors_config.allowed_methods.iter().map(AsRef::as_ref))
+        };
 
         if let Some(max_age) = cors_config.max_age_secs {
             cors = cors.max_age(Duration::from_secs(max_age));
@@ -0,0 +1,184 @@
+//! OpenAPI document of the REST API, served at `/api/openapi.json`.
+//!
+//! It is assembled from the v0 and v1 specifications of `doc/api`, the ones
+//! the integration tests check the responses of the node against, so that
+//! clients can be generated from the node itself.
+
+use serde_json::{Map, Value};
+use warp::{http::header::CONTENT_TYPE, Filter, Rejection, Reply};
+
+const V0: &str = include_str!("../../../doc/api/v0.yaml");
+const V1: &str = include_str!("../../../doc/api/v1.yaml");
+
+lazy_static! {
+    static ref DOCUMENT: String =
+        serde_json::to_string(&document().expect("the OpenAPI specifications cannot be merged"))
+            .unwrap();
+}
+
+pub fn filter() -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
+    warp::path!("openapi.json")
+        .and(warp::get())
+        .map(|| warp::reply::with_header(DOCUMENT.as_str(), CONTENT_TYPE, "application/json"))
+}
+
+/// The v0 specification extended with the paths, tags and components of the
+/// v1 one. The two versions may describe a type differently under the same
+/// name, so the components are prefixed with their version, e.g.
+/// `V1FragmentsProcessingSummary`.
+fn document() -> Result<Value, String> {
+    let mut document: Value = serde_yaml::from_str(V0).map_err(|e| e.to_string())?;
+    let mut v1: Value = serde_yaml::from_str(V1).map_err(|e| e.to_string())?;
+    namespace_components(&mut document, "V0")?;
+    namespace_components(&mut v1, "V1")?;
+
+    document["info"]["title"] = "Jörmungandr REST API".into();
+    document["info"]["description"] = "Jörmungandr REST API v0 and v1".into();
+    document["info"]["version"] = env!("CARGO_PKG_VERSION").into();
+
+    let paths = object_mut(&mut document, "paths")?;
+    for (path, item) in object(&v1, "paths")? {
+        if paths.insert(path.clone(), item.clone()).is_some() {
+            return Err(format!("path '{}' is defined by both v0 and v1", path));
+        }
+    }
+
+    let tags = document["tags"]
+        .as_array_mut()
+        .ok_or("the v0 tags are not a list")?;
+    for tag in v1["tags"].as_array().ok_or("the v1 tags are not a list")? {
+        if !tags.iter().any(|known| known["name"] == tag["name"]) {
+            tags.push(tag.clone());
+        }
+    }
+
+    let components = object_mut(&mut document, "components")?;
+    for (kind, definitions) in object(&v1, "components")? {
+        let merged = components
+            .entry(kind.clone())
+            .or_insert_with(|| Value::Object(Map::new()))
+            .as_object_mut()
+            .ok_or_else(|| format!("the v0 components '{}' are not a map", kind))?;
+        for (name, definition) in definitions
+            .as_object()
+            .ok_or_else(|| format!("the v1 components '{}' are not a map", kind))?
+        {
+            if merged.insert(name.clone(), definition.clone()).is_some() {
+                return Err(format!("component '{}' is defined by both v0 and v1", name));
+            }
+        }
+    }
+
+    Ok(document)
+}
+
+/// Prefixes the names of the components of a specification, and the
+/// references to them.
+fn namespace_components(document: &mut Value, prefix: &str) -> Result<(), String> {
+    for definitions in object_mut(document, "components")?.values_mut() {
+        let definitions = definitions
+            .as_object_mut()
+            .ok_or("the components are not maps")?;
+        *definitions = std::mem::take(definitions)
+            .into_iter()
+            .map(|(name, definition)| (format!("{}{}", prefix, name), definition))
+            .collect();
+    }
+    prefix_references(document, prefix);
+    Ok(())
+}
+
+fn prefix_references(value: &mut Value, prefix: &str) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                match value {
+                    Value::String(reference) if key == "$ref" => {
+                        if let Some(name_start) = reference.rfind('/') {
+                            reference.insert_str(name_start + 1, prefix);
+                        }
+                    }
+                    value => prefix_references(value, prefix),
+                }
+            }
+        }
+        Value::Array(values) => {
+            for value in values {
+                prefix_references(value, prefix);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn object<'a>(document: &'a Value, key: &str) -> Result<&'a Map<String, Value>, String> {
+    document[key]
+        .as_object()
+        .ok_or_else(|| format!("'{}' is not a map", key))
+}
+
+fn object_mut<'a>(
+    document: &'a mut Value,
+    key: &str,
+) -> Result<&'a mut Map<String, Value>, String> {
+    document[key]
+        .as_object_mut()
+        .ok_or_else(|| format!("'{}' is not a map", key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn specifications_merge() {
+        let document = document().unwrap();
+        let paths = document["paths"].as_object().unwrap();
+        assert!(paths.contains_key("/api/v0/settings"));
+        assert!(paths.contains_key("/api/v1/fragments"));
+    }
+
+    #[test]
+    fn components_of_both_versions_are_kept() {
+        let document = document().unwrap();
+        let schemas = document["components"]["schemas"].as_object().unwrap();
+        assert!(schemas.contains_key("V0FragmentsProcessingSummary"));
+        assert!(schemas.contains_key("V1FragmentsProcessingSummary"));
+
+        let mut references = Vec::new();
+        collect_references(&document, &mut references);
+        assert!(!references.is_empty());
+        for reference in references {
+            let name = reference
+                .strip_prefix("#/components/schemas/")
+                .unwrap_or_else(|| panic!("unexpected reference {}", reference));
+            assert!(schemas.contains_key(name), "{} is not defined", reference);
+        }
+        assert_eq!(
+            document["paths"]["/api/v1/fragments"]["post"]["responses"]["200"]["content"]
+                ["application/json"]["schema"]["$ref"],
+            "#/components/schemas/V1FragmentsProcessingSummary"
+        );
+    }
+
+    fn collect_references(value: &Value, references: &mut Vec<String>) {
+        match value {
+            Value::Object(map) => {
+                for (key, value) in map {
+                    match value {
+                        Value::String(reference) if key == "$ref" => {
+                            references.push(reference.clone())
+                        }
+                        value => collect_references(value, references),
+                    }
+                }
+            }
+            Value::Array(values) => {
+                for value in values {
+                    collect_references(value, references);
+                }
+            }
+            _ => {}
+        }
+    }
+}
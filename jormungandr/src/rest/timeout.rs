@@ -0,0 +1,82 @@
+//! warp does not provide a way to bound the time spent on a request. The
+//! routes produce the future of their handler instead of awaiting it, and the
+//! future is then driven with a deadline, so the request itself is left as is.
+
+use std::{future::Future, time::Duration};
+use warp::{http::StatusCode, reply::Response, Filter, Rejection, Reply};
+
+/// Extracts the timeout of the requests, given to [`bounded`] after the
+/// future of the handler.
+pub fn with_timeout(
+    timeout: Option<Duration>,
+) -> impl Filter<Extract = (Option<Duration>,), Error = std::convert::Infallible> + Clone {
+    warp::any().map(move || timeout)
+}
+
+/// Runs the handler of a request, answering with `408 Request Timeout` if it
+/// takes longer than `timeout`.
+pub async fn bounded<H, R>(handler: H, timeout: Option<Duration>) -> Result<Response, Rejection>
+where
+    H: Future<Output = Result<R, Rejection>>,
+    R: Reply,
+{
+    let timeout = match timeout {
+        Some(timeout) => timeout,
+        None => return handler.await.map(Reply::into_response),
+    };
+    match tokio::time::timeout(timeout, handler).await {
+        Ok(reply) => reply.map(Reply::into_response),
+        Err(_) => {
+            tracing::warn!("REST request timed out");
+            Ok(
+                warp::reply::with_status("request timed out", StatusCode::REQUEST_TIMEOUT)
+                    .into_response(),
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn route(
+        delay: Duration,
+        timeout: Option<Duration>,
+    ) -> impl Filter<Extract = (Response,), Error = Rejection> + Clone {
+        warp::path!("slow")
+            .and(warp::header::<String>("x-client"))
+            .map(move |client: String| async move {
+                tokio::time::sleep(delay).await;
+                Ok::<_, Rejection>(client)
+            })
+            .and(with_timeout(timeout))
+            .and_then(bounded)
+    }
+
+    #[tokio::test]
+    async fn handlers_running_out_of_time_are_answered_with_408() {
+        let response = warp::test::request()
+            .path("/slow")
+            .header("x-client", "test")
+            .reply(&route(
+                Duration::from_secs(10),
+                Some(Duration::from_millis(10)),
+            ))
+            .await;
+        assert_eq!(response.status(), StatusCode::REQUEST_TIMEOUT);
+    }
+
+    #[tokio::test]
+    async fn handlers_in_time_see_the_request_as_sent() {
+        for timeout in vec![None, Some(Duration::from_secs(10))] {
+            let response = warp::test::request()
+                .path("/slow")
+                .header("x-client", "test")
+                .reply(&route(Duration::from_millis(1), timeout))
+                .await;
+            assert_eq!(response.status(), StatusCode::OK);
+            assert_eq!(response.body(), "test");
+        }
+    }
+}
@@ -1,4 +1,6 @@
 use crate::{
+    fragment::LogsFilter,
+    leadership::LogsQuery,
     rest::{v0::logic, ContextLock},
     secure::NodeSecret,
 };
@@ -18,9 +20,12 @@ pub async fn get_account_state(
         .ok_or_else(warp::reject::not_found)
 }
 
-pub async fn get_message_logs(context: ContextLock) -> Result<impl Reply, Rejection> {
+pub async fn get_message_logs(
+    filter: LogsFilter,
+    context: ContextLock,
+) -> Result<impl Reply, Rejection> {
     let context = context.read().await;
-    logic::get_message_logs(&context)
+    logic::get_message_logs(&context, filter)
         .await
         .map_err(warp::reject::custom)
         .map(|r| warp::reply::json(&r))
@@ -143,9 +148,12 @@ pub async fn delete_leaders(leader_id: u32, context: ContextLock) -> Result<impl
         .ok_or_else(warp::reject::not_found)
 }
 
-pub async fn get_leaders_logs(context: ContextLock) -> Result<impl Reply, Rejection> {
+pub async fn get_leaders_logs(
+    query: LogsQuery,
+    context: ContextLock,
+) -> Result<impl Reply, Rejection> {
     let context = context.read().await;
-    logic::get_leaders_logs(&context)
+    logic::get_leaders_logs(&context, &query)
         .await
         .map(|r| warp::reply::json(&r))
         .map_err(warp::reject::custom)
@@ -281,3 +289,13 @@ pub async fn get_active_vote_plans(context: ContextLock) -> Result<impl Reply, R
         .map(|r| warp::reply::json(&r))
         .map_err(warp::reject::custom)
 }
+
+#[cfg(feature = "simulation-clock")]
+pub async fn step_clock(seconds: u64) -> Result<impl Reply, Rejection> {
+    if crate::utils::clock::step(std::time::Duration::from_secs(seconds)) {
+        Ok(warp::reply())
+    } else {
+        // the clock of the node is not simulated
+        Err(warp::reject::not_found())
+    }
+}
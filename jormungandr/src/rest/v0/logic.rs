@@ -8,9 +8,11 @@
 use std::net::SocketAddr;
 
 use crate::{
-    blockchain::StorageError,
+    blockchain::{Ref, StorageError},
     diagnostic::Diagnostic,
+    fragment::LogsFilter,
     intercom::{self, NetworkMsg, TopologyMsg, TransactionMsg},
+    leadership::LogsQuery,
     rest::Context,
     secure::NodeSecret,
     topology::PeerInfo,
@@ -77,6 +79,8 @@ pub enum Error {
     Hex(#[from] hex::FromHexError),
     #[error("Could not process fragment")]
     Fragment(FragmentsProcessingSummary),
+    #[error("Fragment already known")]
+    FragmentAlreadyKnown(FragmentsProcessingSummary),
 }
 
 fn parse_account_id(id_hex: &str) -> Result<Identifier, Error> {
@@ -113,12 +117,15 @@ pub async fn get_account_state(
         .map(Into::into))
 }
 
-pub async fn get_message_logs(context: &Context) -> Result<Vec<FragmentLog>, Error> {
+pub async fn get_message_logs(
+    context: &Context,
+    filter: LogsFilter,
+) -> Result<Vec<FragmentLog>, Error> {
     let span = span!(parent: context.span()?, Level::TRACE, "message_logs");
     async move {
         let (reply_handle, reply_future) = intercom::unary_reply();
         let mut mbox = context.try_full()?.transaction_task.clone();
-        mbox.send(TransactionMsg::GetLogs(reply_handle))
+        mbox.send(TransactionMsg::GetLogs(filter, reply_handle))
             .await
             .map_err(|e| {
                 tracing::debug!(reason = %e, "error getting message logs");
@@ -138,6 +145,7 @@ pub async fn post_message(
     let (reply_handle, reply_future) = intercom::unary_reply();
     let msg = TransactionMsg::SendTransactions {
         origin: FragmentOrigin::Rest,
+        peer: None,
         fragments: vec![fragment],
         fail_fast: true,
         reply_handle,
@@ -146,6 +154,8 @@ pub async fn post_message(
     let reply = reply_future.await?;
     if reply.is_error() {
         Err(Error::Fragment(reply))
+    } else if reply.is_already_known() {
+        Err(Error::FragmentAlreadyKnown(reply))
     } else {
         Ok(reply)
     }
@@ -280,41 +290,53 @@ pub async fn get_stake_distribution_at(
 pub async fn get_settings(context: &Context) -> Result<SettingsDto, Error> {
     let full_context = context.try_full()?;
     let blockchain_tip = context.blockchain_tip()?.get_ref().await;
-    let ledger = blockchain_tip.ledger();
+    let curr_slot_start_time = full_context
+        .stats_counter
+        .get_stats()
+        .last_block_time
+        .map(SystemTime::from);
+    Ok(settings_at(&blockchain_tip, curr_slot_start_time))
+}
+
+/// The settings that applied in the epoch of the given block. Protocol updates
+/// only take effect on epoch transitions, so they are the same for all the
+/// blocks of the epoch.
+pub fn settings_at(reference: &Ref, curr_slot_start_time: Option<SystemTime>) -> SettingsDto {
+    let ledger = reference.ledger();
     let static_params = ledger.get_static_parameters();
     let consensus_version = ledger.consensus_version();
-    let current_params = blockchain_tip.epoch_ledger_parameters();
+    let current_params = reference.epoch_ledger_parameters();
     let fees = current_params.fees;
     let block_content_max_size = current_params.block_content_max_size;
     let epoch_stability_depth = current_params.epoch_stability_depth;
-    let slots_per_epoch = blockchain_tip
+    let slots_per_epoch = reference
         .epoch_leadership_schedule()
         .era()
         .slots_per_epoch();
-    Ok(SettingsDto {
+    SettingsDto {
         block0_hash: static_params.block0_initial_hash.to_string(),
         block0_time: SystemTime::from_secs_since_epoch(static_params.block0_start_time.0),
-        curr_slot_start_time: full_context
-            .stats_counter
-            .get_stats()
-            .last_block_time
-            .map(SystemTime::from),
+        curr_slot_start_time,
         consensus_version: consensus_version.to_string(),
         fees,
         block_content_max_size,
         epoch_stability_depth,
-        slot_duration: blockchain_tip.time_frame().slot_duration(),
+        slot_duration: reference.time_frame().slot_duration(),
         slots_per_epoch,
         treasury_tax: current_params.treasury_tax,
         reward_params: current_params.reward_params.clone(),
         discrimination: static_params.discrimination,
         tx_max_expiry_epochs: ledger.settings().transaction_max_expiry_epochs,
-    })
+    }
 }
 
 pub async fn shutdown(context: &mut Context) -> Result<(), Error> {
     context.stop_bootstrap();
-    context.server_stopper()?.stop();
+    // once the node is running, the REST server goes down together with
+    // the other services after they had a chance to save their state
+    if !context.stop_node() {
+        context.server_stopper()?.stop();
+    }
     Ok(())
 }
 
@@ -344,8 +366,11 @@ pub async fn delete_leaders(
     }
 }
 
-pub async fn get_leaders_logs(context: &Context) -> Result<Vec<LeadershipLog>, Error> {
-    Ok(context.try_full()?.leadership_logs.logs().await)
+pub async fn get_leaders_logs(
+    context: &Context,
+    query: &LogsQuery,
+) -> Result<Vec<LeadershipLog>, Error> {
+    Ok(context.try_full()?.leadership_logs.query(query).await)
 }
 
 pub async fn get_stake_pools(context: &Context) -> Result<Vec<String>, Error> {
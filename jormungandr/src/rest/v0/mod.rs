@@ -1,26 +1,34 @@
 mod handlers;
 pub mod logic;
 
-use crate::rest::{display_internal_server_error, ContextLock};
+use crate::rest::{display_internal_server_error, timeout, ContextLock};
 
+use std::time::Duration;
 use warp::{http::StatusCode, Filter, Rejection, Reply};
 
 pub fn filter(
     context: ContextLock,
+    max_body_size: u64,
+    request_timeout: Option<Duration>,
 ) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
     let with_context = warp::any().map(move || context.clone());
+    let with_timeout = timeout::with_timeout(request_timeout);
     let root = warp::path!("v0" / ..);
 
     let shutdown = warp::path!("shutdown")
         .and(warp::get().or(warp::post()))
         .and(with_context.clone())
-        .and_then(|_, context| handlers::shutdown(context))
+        .map(|_, context| handlers::shutdown(context))
+        .and(with_timeout.clone())
+        .and_then(timeout::bounded)
         .boxed();
 
     let account = warp::path!("account" / String)
         .and(warp::get())
         .and(with_context.clone())
-        .and_then(handlers::get_account_state)
+        .map(handlers::get_account_state)
+        .and(with_timeout.clone())
+        .and_then(timeout::bounded)
         .boxed();
 
     let block = {
@@ -29,14 +37,18 @@ pub fn filter(
         let get = warp::path!(String)
             .and(warp::get())
             .and(with_context.clone())
-            .and_then(handlers::get_block_id)
+            .map(handlers::get_block_id)
+            .and(with_timeout.clone())
+            .and_then(timeout::bounded)
             .boxed();
 
         let get_next = warp::path!(String / "next_id")
             .and(warp::get())
             .and(warp::query())
             .and(with_context.clone())
-            .and_then(handlers::get_block_next_id)
+            .map(handlers::get_block_next_id)
+            .and(with_timeout.clone())
+            .and_then(timeout::bounded)
             .boxed();
 
         root.and(get.or(get_next)).boxed()
@@ -47,8 +59,11 @@ pub fn filter(
 
         let logs = warp::path!("logs")
             .and(warp::get())
+            .and(warp::query())
             .and(with_context.clone())
-            .and_then(handlers::get_message_logs)
+            .map(handlers::get_message_logs)
+            .and(with_timeout.clone())
+            .and_then(timeout::bounded)
             .boxed();
 
         root.and(logs).boxed()
@@ -60,26 +75,36 @@ pub fn filter(
         let get = warp::path::end()
             .and(warp::get())
             .and(with_context.clone())
-            .and_then(handlers::get_leaders)
+            .map(handlers::get_leaders)
+            .and(with_timeout.clone())
+            .and_then(timeout::bounded)
             .boxed();
 
         let post = warp::path::end()
             .and(warp::post())
+            .and(warp::body::content_length_limit(max_body_size))
             .and(warp::body::json())
             .and(with_context.clone())
-            .and_then(handlers::post_leaders)
+            .map(handlers::post_leaders)
+            .and(with_timeout.clone())
+            .and_then(timeout::bounded)
             .boxed();
 
         let logs = warp::path!("logs")
             .and(warp::get())
+            .and(warp::query())
             .and(with_context.clone())
-            .and_then(handlers::get_leaders_logs)
+            .map(handlers::get_leaders_logs)
+            .and(with_timeout.clone())
+            .and_then(timeout::bounded)
             .boxed();
 
         let delete = warp::path!(u32)
             .and(warp::delete())
             .and(with_context.clone())
-            .and_then(handlers::delete_leaders)
+            .map(handlers::delete_leaders)
+            .and(with_timeout.clone())
+            .and_then(timeout::bounded)
             .boxed();
 
         root.and(get.or(post).or(logs).or(delete)).boxed()
@@ -91,19 +116,25 @@ pub fn filter(
         let quarantined = warp::path!("quarantined")
             .and(warp::get())
             .and(with_context.clone())
-            .and_then(handlers::get_network_p2p_quarantined)
+            .map(handlers::get_network_p2p_quarantined)
+            .and(with_timeout.clone())
+            .and_then(timeout::bounded)
             .boxed();
 
         let non_public = warp::path!("non_public")
             .and(warp::get())
             .and(with_context.clone())
-            .and_then(handlers::get_network_p2p_non_public)
+            .map(handlers::get_network_p2p_non_public)
+            .and(with_timeout.clone())
+            .and_then(timeout::bounded)
             .boxed();
 
         let available = warp::path!("available")
             .and(warp::get())
             .and(with_context.clone())
-            .and_then(handlers::get_network_p2p_available)
+            .map(handlers::get_network_p2p_available)
+            .and(with_timeout.clone())
+            .and_then(timeout::bounded)
             .boxed();
 
         let view = {
@@ -112,13 +143,17 @@ pub fn filter(
             let view = warp::path::end()
                 .and(warp::get())
                 .and(with_context.clone())
-                .and_then(handlers::get_network_p2p_view)
+                .map(handlers::get_network_p2p_view)
+                .and(with_timeout.clone())
+                .and_then(timeout::bounded)
                 .boxed();
 
             let view_topic = warp::path!(String)
                 .and(warp::get())
                 .and(with_context.clone())
-                .and_then(handlers::get_network_p2p_view_topic)
+                .map(handlers::get_network_p2p_view_topic)
+                .and(with_timeout.clone())
+                .and_then(timeout::bounded)
                 .boxed();
 
             root.and(view.or(view_topic)).boxed()
@@ -134,7 +169,9 @@ pub fn filter(
         let stats = warp::path!("stats")
             .and(warp::get())
             .and(with_context.clone())
-            .and_then(handlers::get_network_stats)
+            .map(handlers::get_network_stats)
+            .and(with_timeout.clone())
+            .and_then(timeout::bounded)
             .boxed();
 
         root.and(stats.or(p2p)).boxed()
@@ -143,7 +180,9 @@ pub fn filter(
     let settings = warp::path!("settings")
         .and(warp::get())
         .and(with_context.clone())
-        .and_then(handlers::get_settings)
+        .map(handlers::get_settings)
+        .and(with_timeout.clone())
+        .and_then(timeout::bounded)
         .boxed();
 
     let stake = {
@@ -152,13 +191,17 @@ pub fn filter(
         let get = warp::path::end()
             .and(warp::get())
             .and(with_context.clone())
-            .and_then(handlers::get_stake_distribution)
+            .map(handlers::get_stake_distribution)
+            .and(with_timeout.clone())
+            .and_then(timeout::bounded)
             .boxed();
 
         let get_at = warp::path!(u32)
             .and(warp::get())
             .and(with_context.clone())
-            .and_then(handlers::get_stake_distribution_at)
+            .map(handlers::get_stake_distribution_at)
+            .and(with_timeout.clone())
+            .and_then(timeout::bounded)
             .boxed();
 
         root.and(get.or(get_at)).boxed()
@@ -167,32 +210,43 @@ pub fn filter(
     let stake_pools = warp::path!("stake_pools")
         .and(warp::get())
         .and(with_context.clone())
-        .and_then(handlers::get_stake_pools)
+        .map(handlers::get_stake_pools)
+        .and(with_timeout.clone())
+        .and_then(timeout::bounded)
         .boxed();
 
     let stake_pool = warp::path!("stake_pool" / String)
         .and(warp::get())
         .and(with_context.clone())
-        .and_then(handlers::get_stake_pool)
+        .map(handlers::get_stake_pool)
+        .and(with_timeout.clone())
+        .and_then(timeout::bounded)
         .boxed();
 
     let message = warp::path!("message")
         .and(warp::post())
+        .and(warp::body::content_length_limit(max_body_size))
         .and(warp::body::bytes())
         .and(with_context.clone())
-        .and_then(handlers::post_message)
+        .map(handlers::post_message)
+        .and(with_timeout.clone())
+        .and_then(timeout::bounded)
         .boxed();
 
     let node_stats = warp::path!("node" / "stats")
         .and(warp::get())
         .and(with_context.clone())
-        .and_then(handlers::get_stats_counter)
+        .map(handlers::get_stats_counter)
+        .and(with_timeout.clone())
+        .and_then(timeout::bounded)
         .boxed();
 
     let tip = warp::path!("tip")
         .and(warp::get())
         .and(with_context.clone())
-        .and_then(handlers::get_tip)
+        .map(handlers::get_tip)
+        .and(with_timeout.clone())
+        .and_then(timeout::bounded)
         .boxed();
 
     let rewards = {
@@ -201,13 +255,17 @@ pub fn filter(
         let history = warp::path!("history" / usize)
             .and(warp::get())
             .and(with_context.clone())
-            .and_then(handlers::get_rewards_info_history)
+            .map(handlers::get_rewards_info_history)
+            .and(with_timeout.clone())
+            .and_then(timeout::bounded)
             .boxed();
 
         let epoch = warp::path!("epoch" / u32)
             .and(warp::get())
             .and(with_context.clone())
-            .and_then(handlers::get_rewards_info_epoch)
+            .map(handlers::get_rewards_info_epoch)
+            .and(with_timeout.clone())
+            .and_then(timeout::bounded)
             .boxed();
 
         root.and(history.or(epoch)).boxed()
@@ -216,13 +274,17 @@ pub fn filter(
     let utxo = warp::path!("utxo" / String / u8)
         .and(warp::get())
         .and(with_context.clone())
-        .and_then(handlers::get_utxo)
+        .map(handlers::get_utxo)
+        .and(with_timeout.clone())
+        .and_then(timeout::bounded)
         .boxed();
 
     let diagnostic = warp::path!("diagnostic")
         .and(warp::get())
         .and(with_context.clone())
-        .and_then(handlers::get_diagnostic)
+        .map(handlers::get_diagnostic)
+        .and(with_timeout.clone())
+        .and_then(timeout::bounded)
         .boxed();
 
     let votes = {
@@ -230,13 +292,17 @@ pub fn filter(
         let committees = warp::path!("committees")
             .and(warp::get())
             .and(with_context.clone())
-            .and_then(handlers::get_committees)
+            .map(handlers::get_committees)
+            .and(with_timeout.clone())
+            .and_then(timeout::bounded)
             .boxed();
 
         let vote_plans = warp::path!("plans")
             .and(warp::get())
             .and(with_context)
-            .and_then(handlers::get_active_vote_plans)
+            .map(handlers::get_active_vote_plans)
+            .and(with_timeout)
+            .and_then(timeout::bounded)
             .boxed();
         root.and(committees.or(vote_plans)).boxed()
     };
@@ -260,6 +326,15 @@ pub fn filter(
         .or(votes)
         .boxed();
 
+    #[cfg(feature = "simulation-clock")]
+    let routes = {
+        let clock = warp::path!("clock" / "step" / u64)
+            .and(warp::post())
+            .and_then(handlers::step_clock)
+            .boxed();
+        routes.or(clock).boxed()
+    };
+
     root.and(routes).recover(handle_rejection).boxed()
 }
 
@@ -274,6 +349,10 @@ async fn handle_rejection(err: Rejection) -> Result<impl Reply, Rejection> {
                 serde_json::to_string(&summary).unwrap(),
                 StatusCode::BAD_REQUEST,
             ),
+            logic::Error::FragmentAlreadyKnown(summary) => (
+                serde_json::to_string(&summary).unwrap(),
+                StatusCode::CONFLICT,
+            ),
             err => (
                 display_internal_server_error(err),
                 StatusCode::INTERNAL_SERVER_ERROR,
@@ -1,6 +1,11 @@
-use crate::rest::{v1::logic, ContextLock};
+use crate::{
+    fragment::LogsFilter,
+    rest::{v1::logic, ContextLock},
+};
+use futures::StreamExt;
 use jormungandr_lib::interfaces::{Address, FragmentsBatch, VotePlanId};
-use warp::{reject::Reject, Rejection, Reply};
+use tokio::sync::broadcast::error::RecvError;
+use warp::{reject::Reject, sse::Event, Rejection, Reply};
 
 impl Reject for logic::Error {}
 
@@ -32,9 +37,28 @@ pub async fn get_fragment_statuses(
         .map(|r| warp::reply::json(&r))
 }
 
-pub async fn get_fragment_logs(context: ContextLock) -> Result<impl Reply, Rejection> {
+pub async fn get_fragment_logs(
+    filter: LogsFilter,
+    context: ContextLock,
+) -> Result<impl Reply, Rejection> {
+    let context = context.read().await;
+    logic::get_fragment_logs(&context, filter)
+        .await
+        .map_err(warp::reject::custom)
+        .map(|r| warp::reply::json(&r))
+}
+
+pub async fn get_expired_fragments(context: ContextLock) -> Result<impl Reply, Rejection> {
     let context = context.read().await;
-    logic::get_fragment_logs(&context)
+    logic::get_expired_fragments(&context)
+        .await
+        .map_err(warp::reject::custom)
+        .map(|r| warp::reply::json(&r))
+}
+
+pub async fn get_fragment_conflicts(context: ContextLock) -> Result<impl Reply, Rejection> {
+    let context = context.read().await;
+    logic::get_fragment_conflicts(&context)
         .await
         .map_err(warp::reject::custom)
         .map(|r| warp::reply::json(&r))
@@ -52,3 +76,176 @@ pub async fn get_account_votes(
         .ok_or_else(warp::reject::not_found)
         .map(|r| warp::reply::json(&r))
 }
+
+#[derive(Deserialize)]
+pub struct AccountStateQuery {
+    at: Option<String>,
+}
+
+pub async fn get_account_state(
+    account_id: String,
+    query: AccountStateQuery,
+    context: ContextLock,
+) -> Result<impl Reply, Rejection> {
+    let context = context.read().await;
+    logic::get_account_state_at(&context, &account_id, query.at.as_deref())
+        .await
+        .map_err(warp::reject::custom)?
+        .ok_or_else(warp::reject::not_found)
+        .map(|r| warp::reply::json(&r))
+}
+
+#[derive(Deserialize)]
+pub struct AccountEventsQuery {
+    account_ids: String,
+}
+
+/// Server-sent events of the accounts: an `account` event with the new state
+/// whenever the balance or the spending counter of one of them changes, and a
+/// `lagged` event with the number of missed events when the client does not
+/// keep up.
+pub async fn get_account_events(
+    query: AccountEventsQuery,
+    context: ContextLock,
+) -> Result<impl Reply, Rejection> {
+    let context = context.read().await;
+    let subscription = logic::subscribe_account_events(&context, query.account_ids.split(','))
+        .map_err(warp::reject::custom)?;
+    let events = futures::stream::unfold(subscription, |mut subscription| async move {
+        let event = match subscription.recv().await {
+            Ok(event) => Event::default()
+                .event("account")
+                .json_data(logic::account_event(event)),
+            Err(RecvError::Lagged(missed)) => {
+                Ok(Event::default().event("lagged").data(missed.to_string()))
+            }
+            Err(RecvError::Closed) => return None,
+        };
+        Some((event, subscription))
+    });
+    Ok(warp::sse::reply(warp::sse::keep_alive().stream(events)))
+}
+
+#[derive(Deserialize)]
+pub struct ClockEventsQuery {
+    #[serde(default)]
+    epochs_only: bool,
+}
+
+pub async fn get_clock_events(
+    query: ClockEventsQuery,
+    context: ContextLock,
+) -> Result<impl Reply, Rejection> {
+    let context = context.read().await;
+    let events = logic::slot_starts(&context, query.epochs_only)
+        .map_err(warp::reject::custom)?
+        .map(|slot_start| {
+            let event = if slot_start.epoch_start {
+                "epoch"
+            } else {
+                "slot"
+            };
+            Event::default().event(event).json_data(slot_start)
+        });
+    Ok(warp::sse::reply(warp::sse::keep_alive().stream(events)))
+}
+
+#[derive(Deserialize)]
+pub struct EpochQuery {
+    epoch: Option<u32>,
+}
+
+pub async fn get_accounts_snapshot(
+    query: EpochQuery,
+    context: ContextLock,
+) -> Result<impl Reply, Rejection> {
+    let context = context.read().await;
+    logic::get_accounts_snapshot(&context, query.epoch)
+        .await
+        .map_err(warp::reject::custom)?
+        .ok_or_else(warp::reject::not_found)
+        .map(|r| warp::reply::json(&r))
+}
+
+pub async fn get_balance_proof(
+    account_id: String,
+    query: EpochQuery,
+    context: ContextLock,
+) -> Result<impl Reply, Rejection> {
+    let context = context.read().await;
+    logic::get_balance_proof(&context, &account_id, query.epoch)
+        .await
+        .map_err(warp::reject::custom)?
+        .ok_or_else(warp::reject::not_found)
+        .map(|r| warp::reply::json(&r))
+}
+
+#[derive(Deserialize)]
+pub struct SettingsQuery {
+    epoch: Option<u32>,
+}
+
+pub async fn get_settings(
+    query: SettingsQuery,
+    context: ContextLock,
+) -> Result<impl Reply, Rejection> {
+    let context = context.read().await;
+    logic::get_settings_at(&context, query.epoch)
+        .await
+        .map_err(warp::reject::custom)?
+        .ok_or_else(warp::reject::not_found)
+        .map(|r| warp::reply::json(&r))
+}
+
+pub async fn get_stake_pool_dashboard(
+    pool_id: String,
+    context: ContextLock,
+) -> Result<impl Reply, Rejection> {
+    let context = context.read().await;
+    logic::get_stake_pool_dashboard(&context, &pool_id)
+        .await
+        .map_err(warp::reject::custom)?
+        .ok_or_else(warp::reject::not_found)
+        .map(|r| warp::reply::json(&r))
+}
+
+pub async fn get_stake_pool_blocks(
+    pool_id: String,
+    query: EpochQuery,
+    context: ContextLock,
+) -> Result<impl Reply, Rejection> {
+    let context = context.read().await;
+    logic::get_stake_pool_blocks(&context, &pool_id, query.epoch)
+        .await
+        .map_err(warp::reject::custom)?
+        .ok_or_else(warp::reject::not_found)
+        .map(|r| warp::reply::json(&r))
+}
+
+pub async fn get_rewards_projection(context: ContextLock) -> Result<impl Reply, Rejection> {
+    let context = context.read().await;
+    logic::get_rewards_projection(&context)
+        .await
+        .map_err(warp::reject::custom)
+        .map(|r| warp::reply::json(&r))
+}
+
+pub async fn get_network_events(context: ContextLock) -> Result<impl Reply, Rejection> {
+    let context = context.read().await;
+    logic::get_network_events(&context)
+        .await
+        .map_err(warp::reject::custom)
+        .map(|r| warp::reply::json(&r))
+}
+
+pub async fn get_bootstrap_status(context: ContextLock) -> Result<impl Reply, Rejection> {
+    let context = context.read().await;
+    Ok(warp::reply::json(&logic::get_bootstrap_status(&context)))
+}
+
+pub async fn get_configuration_summary(context: ContextLock) -> Result<impl Reply, Rejection> {
+    let context = context.read().await;
+    logic::get_configuration_summary(&context)
+        .map_err(warp::reject::custom)
+        .map(|r| warp::reply::json(&r))
+}
@@ -1,16 +1,44 @@
 use crate::{
-    blockchain::StorageError,
+    blockcfg::{Fragment, HeaderHash, Ledger},
+    blockchain::{AccountEvent, AccountEventsSubscription, Ref, StorageError, Tip},
+    fragment::LogsFilter,
     intercom::{self, TransactionMsg},
-    rest::Context,
+    leadership::{LogStatusFilter, LogsQuery},
+    rest::{accounts_tree::AccountsTree, v0, Context},
+    utils::clock,
+};
+use chain_crypto::{
+    digest::Error as DigestError, hash::Error as HashError, PublicKey, PublicKeyFromStrError,
+};
+use chain_impl_mockchain::{
+    account::{AccountAlg, Identifier},
+    block::BlockDate,
+    certificate::PoolId,
+    fragment::FragmentId,
+    transaction::AccountIdentifier,
+    value::ValueError,
+};
+use chain_time::{
+    era::{EpochPosition, EpochSlotOffset},
+    Epoch, Slot,
 };
-use chain_crypto::{digest::Error as DigestError, hash::Error as HashError, PublicKeyFromStrError};
-use chain_impl_mockchain::{fragment::FragmentId, value::ValueError};
 use futures::{channel::mpsc::SendError, channel::mpsc::TrySendError, prelude::*};
-use jormungandr_lib::interfaces::{
-    Address, FragmentLog, FragmentOrigin, FragmentStatus, FragmentsBatch,
-    FragmentsProcessingSummary, VotePlanId,
+use jormungandr_lib::{
+    interfaces::{
+        self, epoch_rewards, tax_cut, AccountStateAt, AccountsSnapshot, Address, BalanceProof,
+        BootstrapStatus, ConfigurationSummary, ExpiredFragment, FragmentConflict, FragmentLog,
+        FragmentOrigin, FragmentStatus, FragmentsBatch, FragmentsProcessingSummary, PeerEvent,
+        PoolRewardsProjection, RewardsProjection, SettingsDto, SlotStart, StakePoolBlock,
+        StakePoolDashboard, Value, VotePlanId,
+    },
+    time::SystemTime,
+};
+use std::{
+    collections::{HashMap, HashSet},
+    convert::TryInto,
+    str::FromStr,
+    sync::Arc,
 };
-use std::{collections::HashMap, convert::TryInto, str::FromStr};
 use tracing::{span, Level};
 use tracing_futures::Instrument;
 
@@ -36,11 +64,21 @@ pub enum Error {
     #[error(transparent)]
     Storage(#[from] StorageError),
     #[error(transparent)]
+    Blockchain(#[from] crate::blockchain::Error),
+    #[error(transparent)]
     Hex(#[from] hex::FromHexError),
     #[error("Could not process all fragments")]
     Fragments(FragmentsProcessingSummary),
+    #[error("Fragments already known")]
+    FragmentsAlreadyKnown(FragmentsProcessingSummary),
     #[error("Unexpected address type")]
     UnexpectedAddressType,
+    #[error("epoch {epoch} is no longer indexed, the oldest indexed epoch is {oldest}")]
+    EpochNotIndexed { epoch: u32, oldest: u32 },
+    #[error(transparent)]
+    AccountEvents(#[from] crate::blockchain::AccountEventsError),
+    #[error("Failed to build the accounts tree")]
+    AccountsTree(#[from] tokio::task::JoinError),
 }
 
 pub async fn get_fragment_statuses<'a>(
@@ -84,6 +122,7 @@ pub async fn post_fragments(
     let (reply_handle, reply_future) = intercom::unary_reply();
     let msg = TransactionMsg::SendTransactions {
         origin: FragmentOrigin::Rest,
+        peer: None,
         fragments: batch.fragments,
         fail_fast: batch.fail_fast,
         reply_handle,
@@ -92,18 +131,23 @@ pub async fn post_fragments(
     let reply = reply_future.await?;
     if reply.is_error() {
         Err(Error::Fragments(reply))
+    } else if reply.is_already_known() {
+        Err(Error::FragmentsAlreadyKnown(reply))
     } else {
         Ok(reply)
     }
 }
 
-pub async fn get_fragment_logs(context: &Context) -> Result<Vec<FragmentLog>, Error> {
+pub async fn get_fragment_logs(
+    context: &Context,
+    filter: LogsFilter,
+) -> Result<Vec<FragmentLog>, Error> {
     let span =
         span!(parent: context.span()?, Level::TRACE, "fragment_logs", request = "fragment_logs");
     async move {
         let (reply_handle, reply_future) = intercom::unary_reply();
         let mut mbox = context.try_full()?.transaction_task.clone();
-        mbox.send(TransactionMsg::GetLogs(reply_handle))
+        mbox.send(TransactionMsg::GetLogs(filter, reply_handle))
             .await
             .map_err(|e| {
                 tracing::debug!(reason = %e, "error getting fragment logs");
@@ -115,6 +159,50 @@ pub async fn get_fragment_logs(context: &Context) -> Result<Vec<FragmentLog>, Er
     .await
 }
 
+pub async fn get_expired_fragments(context: &Context) -> Result<Vec<ExpiredFragment>, Error> {
+    let span = span!(
+        parent: context.span()?,
+        Level::TRACE,
+        "expired_fragments",
+        request = "expired_fragments"
+    );
+    async move {
+        let (reply_handle, reply_future) = intercom::unary_reply();
+        let mut mbox = context.try_full()?.transaction_task.clone();
+        mbox.send(TransactionMsg::GetExpired(reply_handle))
+            .await
+            .map_err(|e| {
+                tracing::debug!(reason = %e, "error getting expired fragments");
+                Error::MsgSend(e)
+            })?;
+        reply_future.await.map_err(Into::into)
+    }
+    .instrument(span)
+    .await
+}
+
+pub async fn get_fragment_conflicts(context: &Context) -> Result<Vec<FragmentConflict>, Error> {
+    let span = span!(
+        parent: context.span()?,
+        Level::TRACE,
+        "fragment_conflicts",
+        request = "fragment_conflicts"
+    );
+    async move {
+        let (reply_handle, reply_future) = intercom::unary_reply();
+        let mut mbox = context.try_full()?.transaction_task.clone();
+        mbox.send(TransactionMsg::GetConflicts(reply_handle))
+            .await
+            .map_err(|e| {
+                tracing::debug!(reason = %e, "error getting fragment conflicts");
+                Error::MsgSend(e)
+            })?;
+        reply_future.await.map_err(Into::into)
+    }
+    .instrument(span)
+    .await
+}
+
 pub async fn get_account_votes(
     context: &Context,
     vote_plan_id: VotePlanId,
@@ -160,3 +248,500 @@ pub async fn get_account_votes(
     .instrument(span)
     .await
 }
+
+/// Looks up the state of an account right after the block given by `at`, which
+/// is either a block hash or an epoch number, in which case the state at the
+/// end of that epoch is used. The current tip is used if `at` is not given.
+///
+/// Past states are only available for the blocks the node keeps in memory: the
+/// recent blocks and the last block of each of the previous epochs.
+pub async fn get_account_state_at(
+    context: &Context,
+    account_id: &str,
+    at: Option<&str>,
+) -> Result<Option<AccountStateAt>, Error> {
+    let account_id: Identifier = PublicKey::<AccountAlg>::from_str(account_id)?.into();
+    let span = span!(parent: context.span()?, Level::TRACE, "account_state_at", request = "account_state_at");
+
+    async move {
+        let tip = context.blockchain_tip()?.get_ref().await;
+        let reference = match at {
+            None => Some(tip),
+            Some(at) => match at.parse::<u32>() {
+                Ok(epoch) => epoch_end_ref(tip, epoch),
+                Err(_) => block_ref(context, tip, HeaderHash::from_str(at)?).await?,
+            },
+        };
+
+        Ok(reference.and_then(|reference| {
+            reference
+                .ledger()
+                .accounts()
+                .get_state(&account_id)
+                .ok()
+                .map(|state| AccountStateAt {
+                    block: reference.hash().into(),
+                    date: reference.block_date().into(),
+                    state: state.into(),
+                })
+        }))
+    }
+    .instrument(span)
+    .await
+}
+
+/// Follows the changes of balance and spending counter of the accounts, given
+/// as hex-encoded public keys.
+pub fn subscribe_account_events<'a>(
+    context: &Context,
+    account_ids: impl IntoIterator<Item = &'a str>,
+) -> Result<AccountEventsSubscription, Error> {
+    let accounts = account_ids
+        .into_iter()
+        .map(|account_id| PublicKey::<AccountAlg>::from_str(account_id).map(Identifier::from))
+        .collect::<Result<HashSet<_>, _>>()?;
+    Ok(context.blockchain()?.subscribe_account_events(accounts)?)
+}
+
+pub fn account_event(event: AccountEvent) -> interfaces::AccountEvent {
+    interfaces::AccountEvent {
+        account_id: event.account.as_ref().to_string(),
+        block: event.block.into(),
+        date: event.date.into(),
+        value: event.value.into(),
+        counter: event.counter,
+    }
+}
+
+/// The starts of the slots from now on, or only of the epochs when
+/// `epochs_only` is set.
+pub fn slot_starts(
+    context: &Context,
+    epochs_only: bool,
+) -> Result<impl Stream<Item = SlotStart>, Error> {
+    let tip = context.blockchain_tip()?.clone();
+    Ok(stream::unfold(tip, move |tip| async move {
+        let slot_start = next_slot_start(&tip, epochs_only).await;
+        Some((slot_start, tip))
+    }))
+}
+
+/// Waits for the start of the next slot (or epoch), described with the era of
+/// the tip at that time. If the clock jumps over several slots, only the
+/// expected one is reported.
+async fn next_slot_start(tip: &Tip, epochs_only: bool) -> SlotStart {
+    loop {
+        let tip_ref = tip.get_ref().await;
+        let era = tip_ref.epoch_leadership_schedule().era();
+        let time_frame = tip_ref.time_frame();
+
+        let position = match time_frame.slot_at(&clock::now()) {
+            Some(current) if epochs_only => EpochPosition {
+                epoch: Epoch(era.from_slot_to_era(current).unwrap().epoch.0 + 1),
+                slot: EpochSlotOffset(0),
+            },
+            Some(current) => era
+                .from_slot_to_era(Slot::from(u64::from(current) + 1))
+                .unwrap(),
+            // the blockchain has not started yet
+            None => EpochPosition {
+                epoch: Epoch(0),
+                slot: EpochSlotOffset(0),
+            },
+        };
+        let date = BlockDate {
+            epoch: position.epoch.0,
+            slot_id: position.slot.0,
+        };
+        let start = time_frame
+            .slot_to_systemtime(era.from_era_to_slot(position))
+            .unwrap();
+
+        if let Ok(duration) = start.duration_since(clock::now()) {
+            clock::sleep_until(clock::instant_in(duration)).await;
+        }
+        // the simulated clock may wake us up early
+        if clock::now() < start {
+            continue;
+        }
+
+        return SlotStart {
+            date: date.into(),
+            time: start.into(),
+            epoch_start: date.slot_id == 0,
+            slot_duration: time_frame.slot_duration(),
+            slots_per_epoch: era.slots_per_epoch(),
+        };
+    }
+}
+
+/// The accounts at the end of `epoch`, by default the last completed epoch.
+/// The ongoing epoch has no snapshot yet.
+async fn accounts_tree(
+    context: &Context,
+    epoch: Option<u32>,
+) -> Result<Option<Arc<AccountsTree>>, Error> {
+    let tip = context.blockchain_tip()?.get_ref().await;
+    let tip_epoch = tip.block_date().epoch;
+    let epoch = match epoch.or_else(|| tip_epoch.checked_sub(1)) {
+        Some(epoch) if epoch < tip_epoch => epoch,
+        _ => return Ok(None),
+    };
+    let reference = match epoch_end_ref(tip, epoch) {
+        Some(reference) => reference,
+        None => return Ok(None),
+    };
+
+    let trees = context.accounts_trees();
+    if let Some(tree) = trees.get(epoch, reference.hash()) {
+        return Ok(Some(tree));
+    }
+    let tree =
+        Arc::new(tokio::task::spawn_blocking(move || AccountsTree::new(epoch, &reference)).await?);
+    trees.insert(Arc::clone(&tree));
+    Ok(Some(tree))
+}
+
+/// Root of the single accounts of the ledger at the end of an epoch, by
+/// default the last completed one.
+pub async fn get_accounts_snapshot(
+    context: &Context,
+    epoch: Option<u32>,
+) -> Result<Option<AccountsSnapshot>, Error> {
+    let span = span!(parent: context.span()?, Level::TRACE, "accounts_snapshot", request = "accounts_snapshot");
+    async move {
+        Ok(accounts_tree(context, epoch)
+            .await?
+            .map(|tree| tree.snapshot()))
+    }
+    .instrument(span)
+    .await
+}
+
+/// Proof of the balance of an account against the root returned by
+/// [`get_accounts_snapshot`] for the same epoch.
+pub async fn get_balance_proof(
+    context: &Context,
+    account_id: &str,
+    epoch: Option<u32>,
+) -> Result<Option<BalanceProof>, Error> {
+    let account_id: Identifier = PublicKey::<AccountAlg>::from_str(account_id)?.into();
+    let span =
+        span!(parent: context.span()?, Level::TRACE, "balance_proof", request = "balance_proof");
+    async move {
+        Ok(accounts_tree(context, epoch)
+            .await?
+            .and_then(|tree| tree.proof(&account_id)))
+    }
+    .instrument(span)
+    .await
+}
+
+fn epoch_end_ref(tip: Arc<Ref>, epoch: u32) -> Option<Arc<Ref>> {
+    if tip.block_date().epoch <= epoch {
+        // the epoch is not over yet, the latest state is the best answer
+        return if tip.block_date().epoch == epoch {
+            Some(tip)
+        } else {
+            None
+        };
+    }
+    tip.previous_epoch_states()
+        .find(|reference| reference.block_date().epoch <= epoch)
+        .cloned()
+}
+
+async fn block_ref(
+    context: &Context,
+    tip: Arc<Ref>,
+    block: HeaderHash,
+) -> Result<Option<Arc<Ref>>, Error> {
+    if tip.hash() == block {
+        return Ok(Some(tip));
+    }
+    if let Some(reference) = tip
+        .previous_epoch_states()
+        .find(|reference| reference.hash() == block)
+    {
+        return Ok(Some(Arc::clone(reference)));
+    }
+    context
+        .blockchain()?
+        .get_ref(block)
+        .await
+        .map_err(Into::into)
+}
+
+pub async fn get_stake_pool_dashboard(
+    context: &Context,
+    pool_id_hex: &str,
+) -> Result<Option<StakePoolDashboard>, Error> {
+    let pool_id: PoolId = pool_id_hex.parse()?;
+    let span = span!(
+        parent: context.span()?,
+        Level::TRACE,
+        "stake_pool_dashboard",
+        request = "stake_pool_dashboard"
+    );
+    async move {
+        let tip = context.blockchain_tip()?.get_ref().await;
+        let ledger = tip.ledger();
+        let pool = match ledger.delegation().lookup(&pool_id) {
+            Some(pool) => pool,
+            None => return Ok(None),
+        };
+        let epoch = tip.block_date().epoch;
+
+        let distribution = ledger.get_stake_distribution();
+        let pool_stake = distribution.to_pools.get(&pool_id);
+        let total_stake: u64 = pool_stake.map(|pool| pool.stake.total.into()).unwrap_or(0);
+        let delegators = pool_stake
+            .map(|pool| pool.stake.accounts.len() as u64)
+            .unwrap_or(0);
+
+        let reward_account_balance = match &pool.registration.reward_account {
+            Some(AccountIdentifier::Single(account_id)) => ledger
+                .accounts()
+                .get_state(account_id)
+                .ok()
+                .map(|state| state.value().0),
+            // multisig reward accounts are not tracked by the dashboard
+            _ => None,
+        };
+
+        let full_context = context.try_full()?;
+        let scheduled_slots_remaining =
+            match full_context.enclave.get_pool_leader_id(&pool_id).await {
+                Some(leader_id) => {
+                    let query = LogsQuery {
+                        status: Some(LogStatusFilter::Pending),
+                        epoch: Some(epoch),
+                        ..Default::default()
+                    };
+                    let remaining = full_context
+                        .leadership_logs
+                        .query(&query)
+                        .await
+                        .iter()
+                        .filter(|log| *log.enclave_leader_id() == leader_id)
+                        .count();
+                    Some(remaining as u64)
+                }
+                None => None,
+            };
+
+        let (reply_handle, reply_future) = intercom::unary_reply();
+        let mut mbox = full_context.transaction_task.clone();
+        mbox.send(TransactionMsg::GetPendingFragments(reply_handle))
+            .await
+            .map_err(|e| {
+                tracing::debug!(reason = %e, "error getting pending fragments");
+                Error::MsgSend(e)
+            })?;
+        let pending_retirement = reply_future
+            .await?
+            .iter()
+            .any(|fragment| retires_pool(fragment, &pool_id));
+
+        Ok(Some(StakePoolDashboard {
+            epoch,
+            total_stake,
+            delegators,
+            blocks_current_epoch: blocks_produced(&ledger, &pool_id),
+            blocks_previous_epoch: tip
+                .last_ref_previous_epoch()
+                .map(|previous_epoch| blocks_produced(&previous_epoch.ledger(), &pool_id)),
+            scheduled_slots_remaining,
+            pending_retirement,
+            reward_account_balance,
+        }))
+    }
+    .instrument(span)
+    .await
+}
+
+/// Blocks of the main branch produced by the stake pool in the given epoch, by
+/// default the epoch of the tip. `None` if the pool is neither registered nor
+/// known to have produced blocks in the indexed epochs.
+pub async fn get_stake_pool_blocks(
+    context: &Context,
+    pool_id_hex: &str,
+    epoch: Option<u32>,
+) -> Result<Option<Vec<StakePoolBlock>>, Error> {
+    let pool_id: PoolId = pool_id_hex.parse()?;
+    let span = span!(
+        parent: context.span()?,
+        Level::TRACE,
+        "stake_pool_blocks",
+        request = "stake_pool_blocks"
+    );
+    async move {
+        let tip = context.blockchain_tip()?.get_ref().await;
+        let producers = context.blockchain()?.producers().clone();
+        let registered = tip.ledger().delegation().lookup(&pool_id).is_some();
+        if !registered && !producers.contains(&pool_id) {
+            return Ok(None);
+        }
+        let epoch = epoch.unwrap_or_else(|| tip.block_date().epoch);
+        let oldest = producers.oldest_epoch();
+        if epoch < oldest {
+            return Err(Error::EpochNotIndexed { epoch, oldest });
+        }
+        let blocks = producers
+            .blocks(&pool_id, epoch)
+            .into_iter()
+            .map(|block| StakePoolBlock {
+                hash: block.hash.into(),
+                date: block.date.into(),
+                chain_length: block.chain_length.into(),
+            })
+            .collect();
+        Ok(Some(blocks))
+    }
+    .instrument(span)
+    .await
+}
+
+/// Rewards expected at the end of the epoch of the tip, from the stake
+/// distribution and the reward settings of the epoch.
+pub async fn get_rewards_projection(context: &Context) -> Result<RewardsProjection, Error> {
+    let span = span!(
+        parent: context.span()?,
+        Level::TRACE,
+        "rewards_projection",
+        request = "rewards_projection"
+    );
+    async move {
+        let tip = context.blockchain_tip()?.get_ref().await;
+        let ledger = tip.ledger();
+        let params = tip.epoch_ledger_parameters();
+        let epoch = tip.block_date().epoch;
+
+        // the leadership only keeps the distribution in genesis praos
+        let computed;
+        let distribution = match tip.epoch_leadership_schedule().stake_distribution() {
+            Some(distribution) => distribution,
+            None => {
+                computed = ledger.get_stake_distribution();
+                &computed
+            }
+        };
+        let active_stake: u64 = distribution
+            .to_pools
+            .values()
+            .map(|pool| u64::from(pool.stake.total))
+            .sum();
+
+        let remaining_rewards = ledger.remaining_rewards().0;
+        let drawn =
+            epoch_rewards(epoch, &params.reward_params, active_stake).min(remaining_rewards);
+        let (treasury, pools_total) = tax_cut(drawn, &params.treasury_tax);
+
+        let mut pools: Vec<PoolRewardsProjection> = distribution
+            .to_pools
+            .iter()
+            .map(|(pool_id, pool)| {
+                let stake = u64::from(pool.stake.total);
+                let total = share(pools_total, stake, active_stake);
+                let (operators, delegators) = match ledger.delegation().lookup(pool_id) {
+                    Some(state) => tax_cut(total, &state.registration.rewards),
+                    None => (0, total),
+                };
+                PoolRewardsProjection {
+                    pool_id: pool_id.to_string(),
+                    stake,
+                    blocks_produced: blocks_produced(&ledger, pool_id),
+                    total,
+                    operators,
+                    delegators,
+                    delegators_per_million: share(delegators, 1_000_000, stake),
+                }
+            })
+            .collect();
+        pools.sort_by(|a, b| {
+            b.delegators_per_million
+                .cmp(&a.delegators_per_million)
+                .then_with(|| a.pool_id.cmp(&b.pool_id))
+        });
+
+        Ok(RewardsProjection {
+            epoch,
+            remaining_rewards,
+            drawn,
+            treasury,
+            pools_total,
+            active_stake,
+            pools,
+        })
+    }
+    .instrument(span)
+    .await
+}
+
+/// `value * part / total`, 0 if the total is 0
+fn share(value: u64, part: u64, total: u64) -> u64 {
+    if total == 0 {
+        return 0;
+    }
+    (u128::from(value) * u128::from(part) / u128::from(total)) as u64
+}
+
+pub fn get_bootstrap_status(context: &Context) -> BootstrapStatus {
+    context.bootstrap_progress().status()
+}
+
+pub fn get_configuration_summary(context: &Context) -> Result<ConfigurationSummary, Error> {
+    Ok(context.configuration_summary()?.clone())
+}
+
+/// Settings that applied in the given epoch, the current ones by default.
+///
+/// Past settings are taken from the last block of the epoch the node keeps in
+/// memory, so they are only available for the epochs that had blocks.
+pub async fn get_settings_at(
+    context: &Context,
+    epoch: Option<u32>,
+) -> Result<Option<SettingsDto>, Error> {
+    let tip = context.blockchain_tip()?.get_ref().await;
+    let tip_epoch = tip.block_date().epoch;
+    match epoch {
+        None => {}
+        Some(epoch) if epoch == tip_epoch => {}
+        Some(epoch) if epoch > tip_epoch => return Ok(None),
+        Some(epoch) => {
+            return Ok(tip
+                .previous_epoch_states()
+                .find(|reference| reference.block_date().epoch <= epoch)
+                .filter(|reference| reference.block_date().epoch == epoch)
+                .map(|reference| v0::logic::settings_at(reference, None)))
+        }
+    }
+    let curr_slot_start_time = context
+        .try_full()?
+        .stats_counter
+        .get_stats()
+        .last_block_time
+        .map(SystemTime::from);
+    Ok(Some(v0::logic::settings_at(&tip, curr_slot_start_time)))
+}
+
+pub async fn get_network_events(context: &Context) -> Result<Vec<PeerEvent>, Error> {
+    Ok(context.try_full()?.network_state.peer_events().events())
+}
+
+/// Number of blocks created by the pool in the epoch of the given ledger state.
+fn blocks_produced(ledger: &Ledger, pool_id: &PoolId) -> u32 {
+    ledger
+        .leaders_log()
+        .iter()
+        .find(|(id, _)| *id == pool_id)
+        .map(|(_, blocks)| *blocks)
+        .unwrap_or(0)
+}
+
+fn retires_pool(fragment: &Fragment, pool_id: &PoolId) -> bool {
+    match fragment {
+        Fragment::PoolRetirement(tx) => &tx.as_slice().payload().into_payload().pool_id == pool_id,
+        _ => false,
+    }
+}
@@ -1,16 +1,20 @@
 mod handlers;
 mod logic;
 
-use crate::rest::{display_internal_server_error, ContextLock};
+use crate::rest::{display_internal_server_error, timeout, ContextLock};
 
 use jormungandr_lib::interfaces::{Address, VotePlanId};
 
+use std::time::Duration;
 use warp::{http::StatusCode, Filter, Rejection, Reply};
 
 pub fn filter(
     context: ContextLock,
+    max_body_size: u64,
+    request_timeout: Option<Duration>,
 ) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
     let with_context = warp::any().map(move || context.clone());
+    let with_timeout = timeout::with_timeout(request_timeout);
     let root = warp::path!("v1" / ..);
 
     let fragments = {
@@ -18,33 +22,178 @@ pub fn filter(
 
         let post = warp::path::end()
             .and(warp::post())
+            .and(warp::body::content_length_limit(max_body_size))
             .and(warp::body::json())
             .and(with_context.clone())
-            .and_then(handlers::post_fragments)
+            .map(handlers::post_fragments)
+            .and(with_timeout.clone())
+            .and_then(timeout::bounded)
             .boxed();
 
         let status = warp::path!("statuses")
             .and(warp::get())
             .and(warp::query())
             .and(with_context.clone())
-            .and_then(handlers::get_fragment_statuses)
+            .map(handlers::get_fragment_statuses)
+            .and(with_timeout.clone())
+            .and_then(timeout::bounded)
             .boxed();
 
         let logs = warp::path!("logs")
+            .and(warp::get())
+            .and(warp::query())
+            .and(with_context.clone())
+            .map(handlers::get_fragment_logs)
+            .and(with_timeout.clone())
+            .and_then(timeout::bounded)
+            .boxed();
+
+        let expired = warp::path!("expired")
+            .and(warp::get())
+            .and(with_context.clone())
+            .map(handlers::get_expired_fragments)
+            .and(with_timeout.clone())
+            .and_then(timeout::bounded)
+            .boxed();
+
+        let conflicts = warp::path!("conflicts")
+            .and(warp::get())
+            .and(with_context.clone())
+            .map(handlers::get_fragment_conflicts)
+            .and(with_timeout.clone())
+            .and_then(timeout::bounded)
+            .boxed();
+
+        root.and(post.or(status).or(logs).or(expired).or(conflicts))
+            .boxed()
+    };
+
+    let accounts = warp::path!("accounts" / String / "state")
+        .and(warp::get())
+        .and(warp::query())
+        .and(with_context.clone())
+        .map(handlers::get_account_state)
+        .and(with_timeout.clone())
+        .and_then(timeout::bounded)
+        .boxed();
+
+    let accounts_snapshot = warp::path!("accounts" / "snapshot")
+        .and(warp::get())
+        .and(warp::query())
+        .and(with_context.clone())
+        .map(handlers::get_accounts_snapshot)
+        .and(with_timeout.clone())
+        .and_then(timeout::bounded)
+        .boxed();
+
+    let balance_proof = warp::path!("accounts" / String / "balance-proof")
+        .and(warp::get())
+        .and(warp::query())
+        .and(with_context.clone())
+        .map(handlers::get_balance_proof)
+        .and(with_timeout.clone())
+        .and_then(timeout::bounded)
+        .boxed();
+
+    let account_events = warp::path!("accounts" / "events")
+        .and(warp::get())
+        .and(warp::query())
+        .and(with_context.clone())
+        .map(handlers::get_account_events)
+        .and(with_timeout.clone())
+        .and_then(timeout::bounded)
+        .boxed();
+
+    let stake_pools = {
+        let dashboard = warp::path!("stake-pools" / String / "dashboard")
             .and(warp::get())
             .and(with_context.clone())
-            .and_then(handlers::get_fragment_logs)
+            .map(handlers::get_stake_pool_dashboard)
+            .and(with_timeout.clone())
+            .and_then(timeout::bounded)
             .boxed();
 
-        root.and(post.or(status).or(logs)).boxed()
+        let blocks = warp::path!("stake-pools" / String / "blocks")
+            .and(warp::get())
+            .and(warp::query())
+            .and(with_context.clone())
+            .map(handlers::get_stake_pool_blocks)
+            .and(with_timeout.clone())
+            .and_then(timeout::bounded)
+            .boxed();
+
+        dashboard.or(blocks).boxed()
     };
 
+    let rewards_projection = warp::path!("rewards" / "projection")
+        .and(warp::get())
+        .and(with_context.clone())
+        .map(handlers::get_rewards_projection)
+        .and(with_timeout.clone())
+        .and_then(timeout::bounded)
+        .boxed();
+
+    let settings = warp::path!("settings")
+        .and(warp::get())
+        .and(warp::query())
+        .and(with_context.clone())
+        .map(handlers::get_settings)
+        .and(with_timeout.clone())
+        .and_then(timeout::bounded)
+        .boxed();
+
+    let bootstrap_status = warp::path!("bootstrap" / "status")
+        .and(warp::get())
+        .and(with_context.clone())
+        .map(handlers::get_bootstrap_status)
+        .and(with_timeout.clone())
+        .and_then(timeout::bounded)
+        .boxed();
+
+    let configuration = warp::path!("node" / "configuration")
+        .and(warp::get())
+        .and(with_context.clone())
+        .map(handlers::get_configuration_summary)
+        .and(with_timeout.clone())
+        .and_then(timeout::bounded)
+        .boxed();
+
+    let clock_events = warp::path!("clock" / "events")
+        .and(warp::get())
+        .and(warp::query())
+        .and(with_context.clone())
+        .map(handlers::get_clock_events)
+        .and(with_timeout.clone())
+        .and_then(timeout::bounded)
+        .boxed();
+
+    let network_events = warp::path!("network" / "events")
+        .and(warp::get())
+        .and(with_context.clone())
+        .map(handlers::get_network_events)
+        .and(with_timeout.clone())
+        .and_then(timeout::bounded)
+        .boxed();
+
     let votes = warp::path!("votes" / "plan" / VotePlanId / "account-votes" / Address)
         .and(warp::get())
         .and(with_context)
-        .and_then(handlers::get_account_votes);
+        .map(handlers::get_account_votes)
+        .and(with_timeout)
+        .and_then(timeout::bounded);
 
-    let routes = fragments;
+    let routes = fragments
+        .or(accounts)
+        .or(account_events)
+        .or(accounts_snapshot)
+        .or(balance_proof)
+        .or(settings)
+        .or(stake_pools)
+        .or(rewards_projection)
+        .or(bootstrap_status)
+        .or(configuration)
+        .or(clock_events)
+        .or(network_events);
 
     root.and(routes.or(votes)).recover(handle_rejection).boxed()
 }
@@ -53,13 +202,20 @@ pub fn filter(
 async fn handle_rejection(err: Rejection) -> Result<impl Reply, Rejection> {
     if let Some(err) = err.find::<logic::Error>() {
         let (body, code) = match err {
-            logic::Error::PublicKey(_) | logic::Error::Hash(_) | logic::Error::Hex(_) => {
-                (err.to_string(), StatusCode::BAD_REQUEST)
-            }
+            logic::Error::PublicKey(_)
+            | logic::Error::Hash(_)
+            | logic::Error::Digest(_)
+            | logic::Error::Hex(_)
+            | logic::Error::EpochNotIndexed { .. } => (err.to_string(), StatusCode::BAD_REQUEST),
             logic::Error::Fragments(summary) => (
                 serde_json::to_string(&summary).unwrap(),
                 StatusCode::BAD_REQUEST,
             ),
+            logic::Error::FragmentsAlreadyKnown(summary) => (
+                serde_json::to_string(&summary).unwrap(),
+                StatusCode::CONFLICT,
+            ),
+            logic::Error::AccountEvents(_) => (err.to_string(), StatusCode::SERVICE_UNAVAILABLE),
             err => (
                 display_internal_server_error(err),
                 StatusCode::INTERNAL_SERVER_ERROR,
@@ -71,3 +227,28 @@ async fn handle_rejection(err: Rejection) -> Result<impl Reply, Rejection> {
 
     Err(err)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rest::Context;
+    use std::sync::Arc;
+    use tokio::sync::RwLock;
+
+    #[tokio::test]
+    async fn oversized_bodies_are_rejected_with_413() {
+        let context = Arc::new(RwLock::new(Context::new()));
+        let routes = filter(context, 64, None);
+        let response = warp::test::request()
+            .method("POST")
+            .path("/v1/fragments")
+            .header("content-type", "application/json")
+            .body(format!(
+                r#"{{"fail_fast":false,"fragments":["{}"]}}"#,
+                "00".repeat(64)
+            ))
+            .reply(&routes)
+            .await;
+        assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    }
+}
@@ -2,7 +2,10 @@ use crate::blockcfg::{
     BlockDate, HeaderBft, HeaderBftBuilder, HeaderGenesisPraos, HeaderGenesisPraosBuilder,
     HeaderSetConsensusSignature,
 };
-use chain_impl_mockchain::leadership::{Leader, LeaderOutput, Leadership};
+use chain_impl_mockchain::{
+    certificate::PoolId,
+    leadership::{Leader, LeaderOutput, Leadership},
+};
 use chain_time::Epoch;
 use jormungandr_lib::interfaces::EnclaveLeaderId as LeaderId;
 use std::collections::{BTreeMap, HashMap};
@@ -118,6 +121,22 @@ impl Enclave {
         self.leaders_data.read().await.get_leader_ids()
     }
 
+    /// the enclave ID of the leader running the given stake pool, if any
+    pub async fn get_pool_leader_id(&self, pool_id: &PoolId) -> Option<LeaderId> {
+        self.leaders_data
+            .read()
+            .await
+            .leaders
+            .iter()
+            .find(|(_, leader)| {
+                leader
+                    .genesis_leader
+                    .as_ref()
+                    .map_or(false, |genesis_leader| &genesis_leader.node_id == pool_id)
+            })
+            .map(|(leader_id, _)| *leader_id)
+    }
+
     pub async fn add_leader(&self, leader: Leader) -> LeaderId {
         self.leaders_data.write().await.add(leader)
     }
@@ -4,7 +4,11 @@ use crate::{
     settings::LOG_FILTER_LEVEL_POSSIBLE_VALUES,
     topology::QuarantineConfig,
 };
-pub use jormungandr_lib::interfaces::{Cors, LayersConfig, Rest, Tls, TrustedPeer};
+#[cfg(feature = "simulation-clock")]
+pub use jormungandr_lib::interfaces::SimulationClock;
+pub use jormungandr_lib::interfaces::{
+    BlockPropagationConfig, Cors, LayersConfig, Rest, Tls, TrustedPeer,
+};
 use jormungandr_lib::{interfaces::Mempool, time::Duration};
 
 use multiaddr::Multiaddr;
@@ -52,6 +56,16 @@ pub struct Config {
     pub skip_bootstrap: bool,
 
     pub block_hard_deadline: Option<u32>,
+
+    /// write a summary of the rewards distribution at every epoch transition
+    pub rewards_export: Option<RewardsExport>,
+
+    /// periodically back up the block storage
+    pub storage_backup: Option<StorageBackup>,
+
+    /// run the clock of the node faster than the system clock, for tests
+    #[cfg(feature = "simulation-clock")]
+    pub simulation_clock: Option<SimulationClock>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -75,6 +89,11 @@ pub struct P2pConfig {
     /// all network interfaces.
     pub listen: Option<Address>,
 
+    /// The port on which the gRPC services for the clients running on the
+    /// same host as the node, such as vote collectors, are served. The
+    /// listener is bound to 127.0.0.1 only, it is not started if not set.
+    pub local_listen_port: Option<u16>,
+
     /// File with the secret key used to advertise and authenticate the node
     #[serde(skip_serializing_if = "Option::is_none")]
     pub node_key_file: Option<PathBuf>,
@@ -134,6 +153,16 @@ pub struct P2pConfig {
     /// gossip with the trusted peers if any are defined.
     #[serde(default)]
     pub max_bootstrap_attempts: Option<usize>,
+
+    /// settings for announcing new blocks to the peers: how many peers receive
+    /// the announcement and which of them are served first.
+    #[serde(default)]
+    pub block_propagation: BlockPropagationConfig,
+
+    /// peers the node always stays connected to, whatever the topology
+    /// selects, and which always receive the new blocks and fragments.
+    #[serde(default)]
+    pub preferred_peers: Vec<TrustedPeer>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
@@ -143,12 +172,90 @@ pub struct Leadership {
     /// the least recently used log will be erased from the logs for a new one
     /// to be inserted.
     pub logs_capacity: usize,
+
+    /// the number of epochs for which the leadership logs are kept, counting
+    /// back from the most recently scheduled log. Logs are only limited by
+    /// `logs_capacity` if not set.
+    #[serde(default)]
+    pub logs_retention_epochs: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct RewardsExport {
+    /// the directory in which one file per epoch is written
+    pub dir: PathBuf,
+
+    /// the format of the written files, CSV if not set.
+    #[serde(default)]
+    pub format: RewardsExportFormat,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum RewardsExportFormat {
+    Csv,
+    Json,
+}
+
+impl Default for RewardsExportFormat {
+    fn default() -> Self {
+        RewardsExportFormat::Csv
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(deny_unknown_fields)]
+pub struct StorageBackup {
+    /// the directory in which the backups are written
+    pub dir: PathBuf,
+
+    /// the time between two backups
+    pub interval: Duration,
+
+    /// the number of backups kept, the oldest ones are removed
+    #[serde(default = "default_storage_backup_retain")]
+    pub retain: usize,
+
+    /// how the backups are written, storage directories if not set.
+    #[serde(default)]
+    pub mode: StorageBackupMode,
+}
+
+fn default_storage_backup_retain() -> usize {
+    3
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum StorageBackupMode {
+    /// a storage directory, which can be used as is by a node
+    Directory,
+    /// a tar archive of the storage directory
+    Tar,
+}
+
+impl Default for StorageBackupMode {
+    fn default() -> Self {
+        StorageBackupMode::Directory
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct Explorer {
     pub enabled: bool,
+    #[serde(default)]
+    pub indexer: Option<ExplorerIndexer>,
+}
+
+/// Runs the node as an explorer indexer: its blocks are only pulled from
+/// the upstream node, which serves the P2P network on its own, so that the
+/// explorer queries do not compete with the upstream node for resources.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ExplorerIndexer {
+    pub upstream: TrustedPeer,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -162,6 +269,7 @@ impl Default for P2pConfig {
         P2pConfig {
             public_address: None,
             listen: None,
+            local_listen_port: None,
             node_key_file: None,
             trusted_peers: None,
             max_connections: None,
@@ -173,6 +281,8 @@ impl Default for P2pConfig {
             gossip_interval: None,
             network_stuck_check: None,
             max_bootstrap_attempts: None,
+            block_propagation: BlockPropagationConfig::default(),
+            preferred_peers: Vec::new(),
         }
     }
 }
@@ -181,6 +291,7 @@ impl Default for Leadership {
     fn default() -> Self {
         Leadership {
             logs_capacity: 1_024,
+            logs_retention_epochs: None,
         }
     }
 }
@@ -1,16 +1,19 @@
 pub mod config;
 pub mod network;
 
-use self::config::{Config, Leadership};
+use self::config::{Config, Leadership, RewardsExport, StorageBackup};
 use self::network::{Protocol, TrustedPeer};
+use crate::blockcfg::ConsensusVersion;
 use crate::settings::logging::{LogFormat, LogInfoMsg, LogOutput, LogSettings, LogSettingsEntry};
 use crate::settings::{command_arguments::*, Block0Info};
-use crate::topology::layers::{self, LayersConfig, PreferredListConfig, RingsConfig};
+use crate::topology::layers::{self, LayerSize, LayersConfig, PreferredListConfig, RingsConfig};
 use chain_crypto::Ed25519;
 use jormungandr_lib::crypto::key::SigningKey;
+use jormungandr_lib::interfaces::{ConfigurationSummary, MempoolLimits, StorageMode, Subsystems};
 pub use jormungandr_lib::interfaces::{Cors, Mempool, Rest, Tls};
 use jormungandr_lib::multiaddr;
 use std::convert::TryFrom;
+use std::net::{Ipv4Addr, SocketAddr};
 use std::{fs::File, path::PathBuf};
 use thiserror::Error;
 use tracing::level_filters::LevelFilter;
@@ -40,6 +43,8 @@ pub enum Error {
     InvalidKey(#[from] chain_crypto::bech32::Error),
     #[error(transparent)]
     InvalidLayersConfig(#[from] layers::ParseError),
+    #[error(transparent)]
+    InvalidLayerSize(#[from] layers::LayerSizeError),
 }
 
 /// Overall Settings for node
@@ -51,12 +56,51 @@ pub struct Settings {
     pub rest: Option<Rest>,
     pub mempool: Mempool,
     pub rewards_report_all: bool,
+    pub rewards_export: Option<RewardsExport>,
+    pub storage_backup: Option<StorageBackup>,
     pub leadership: Leadership,
     pub explorer: bool,
     #[cfg(feature = "prometheus-metrics")]
     pub prometheus: bool,
     pub no_blockchain_updates_warning_interval: std::time::Duration,
     pub block_hard_deadline: u32,
+    #[cfg(feature = "simulation-clock")]
+    pub simulation_clock: Option<config::SimulationClock>,
+}
+
+impl Settings {
+    /// Summary of the settings for the operators, the consensus is the one
+    /// found in block0.
+    pub fn summary(&self, consensus: ConsensusVersion) -> ConfigurationSummary {
+        ConfigurationSummary {
+            version: env!("FULL_VERSION").to_owned(),
+            consensus,
+            listen_address: self.network.listen_address,
+            public_address: self.network.public_address,
+            rest_listen_address: self.rest.as_ref().map(|rest| rest.listen),
+            trusted_peers: self.network.trusted_peers.len(),
+            max_connections: self.network.max_connections,
+            mempool: MempoolLimits {
+                pool_max_entries: self.mempool.pool_max_entries.into(),
+                log_max_entries: self.mempool.log_max_entries.into(),
+            },
+            storage: match &self.storage {
+                Some(_) => StorageMode::Persistent,
+                None => StorageMode::InMemory,
+            },
+            subsystems: Subsystems {
+                explorer: self.explorer,
+                #[cfg(feature = "prometheus-metrics")]
+                prometheus: self.prometheus,
+                #[cfg(not(feature = "prometheus-metrics"))]
+                prometheus: false,
+                rewards_export: self.rewards_export.is_some(),
+                storage_backup: self.storage_backup.is_some(),
+                fragment_persistent_log: self.mempool.persistent_log.is_some(),
+                mempool_snapshot: self.mempool.snapshot.is_some(),
+            },
+        }
+    }
 }
 
 pub struct RawSettings {
@@ -149,6 +193,8 @@ impl RawSettings {
                 listen: cmd_listen,
                 tls: None,
                 cors: None,
+                max_body_size: None,
+                request_timeout: None,
             }),
             (None, None) => None,
         }
@@ -219,6 +265,8 @@ impl RawSettings {
             network,
             secrets,
             rewards_report_all: command_line.rewards_report_all,
+            rewards_export: config.as_ref().and_then(|cfg| cfg.rewards_export.clone()),
+            storage_backup: config.as_ref().and_then(|cfg| cfg.storage_backup.clone()),
             rest,
             mempool: config
                 .as_ref()
@@ -240,6 +288,8 @@ impl RawSettings {
                 .as_ref()
                 .and_then(|config| config.block_hard_deadline)
                 .unwrap_or(DEFAULT_BLOCK_HARD_DEADLINE),
+            #[cfg(feature = "simulation-clock")]
+            simulation_clock: config.as_ref().and_then(|cfg| cfg.simulation_clock),
         })
     }
 }
@@ -268,6 +318,16 @@ fn resolve_trusted_peers(peers: &[jormungandr_lib::interfaces::TrustedPeer]) ->
         .collect()
 }
 
+/// Appends the peers missing from the priority peers, keeping the configured
+/// order.
+fn append_priority_peers(priority_peers: &mut Vec<SocketAddr>, peers: &[TrustedPeer]) {
+    for peer in peers {
+        if !priority_peers.contains(&peer.addr) {
+            priority_peers.push(peer.addr);
+        }
+    }
+}
+
 #[allow(deprecated)]
 fn generate_network(
     command_arguments: &StartArguments,
@@ -310,6 +370,27 @@ fn generate_network(
         .map(RingsConfig::try_from)
         .transpose()?
         .unwrap_or_default();
+    let rings_size = LayerSize::rings(p2p.layers.rings)?;
+    let cyclon_size = LayerSize::cyclon(p2p.layers.cyclon)?;
+
+    let mut priority_peers: Vec<_> = resolve_trusted_peers(&p2p.block_propagation.priority_peers)
+        .into_iter()
+        .map(|peer| peer.addr)
+        .collect();
+    if p2p.block_propagation.prioritize_trusted_peers {
+        append_priority_peers(&mut priority_peers, &trusted_peers);
+    }
+    // preferred peers are always announced new blocks, whatever the fan-out
+    let mut preferred_peers = Vec::new();
+    append_priority_peers(
+        &mut preferred_peers,
+        &resolve_trusted_peers(&p2p.preferred_peers),
+    );
+    let block_propagation = network::BlockPropagation {
+        fan_out: p2p.block_propagation.fan_out,
+        priority_peers,
+        preferred_peers,
+    };
 
     // TODO: do we want to check that we end up with a valid address?
     // Is it possible for a node to specify no public address?
@@ -334,8 +415,13 @@ fn generate_network(
         .or(p2p_listen_address)
         .cloned();
 
+    let local_listen_address = p2p
+        .local_listen_port
+        .map(|port| SocketAddr::from((Ipv4Addr::LOCALHOST, port)));
+
     let mut network = network::Configuration {
         listen_address,
+        local_listen_address,
         public_address,
         trusted_peers,
         node_key,
@@ -344,6 +430,8 @@ fn generate_network(
         layers: LayersConfig {
             preferred_list,
             rings,
+            rings_size,
+            cyclon_size,
         },
         max_connections: p2p
             .max_connections
@@ -365,8 +453,34 @@ fn generate_network(
         http_fetch_block0_service,
         bootstrap_from_trusted_peers,
         skip_bootstrap,
+        block_propagation,
+        preferred_peers,
+        preferred_peers_only: false,
     };
 
+    let indexer = config
+        .as_ref()
+        .and_then(|cfg| cfg.explorer.as_ref())
+        .filter(|explorer| explorer.enabled)
+        .and_then(|explorer| explorer.indexer.as_ref());
+    if let Some(indexer) = indexer {
+        // an indexer only talks to its upstream node and is not a part of the
+        // P2P network: it does not accept connections nor advertise itself
+        if network.listen().is_some() {
+            tracing::warn!(
+                "the P2P listen and public addresses are ignored by an explorer indexer"
+            );
+        }
+        let upstream = resolve_trusted_peers(std::slice::from_ref(&indexer.upstream));
+        network.listen_address = None;
+        network.public_address = None;
+        network.block_propagation.priority_peers = upstream.iter().map(|peer| peer.addr).collect();
+        network.trusted_peers = upstream.clone();
+        network.preferred_peers = upstream;
+        network.preferred_peers_only = true;
+        network.bootstrap_from_trusted_peers = true;
+    }
+
     if network.max_inbound_connections > network.max_connections {
         tracing::warn!(
             "p2p.max_inbound_connections is larger than p2p.max_connections, decreasing from {} to {}",
@@ -378,3 +492,53 @@ fn generate_network(
 
     Ok(network)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use structopt::StructOpt;
+
+    fn addr(port: u16) -> SocketAddr {
+        SocketAddr::from(([127, 0, 0, 1], port))
+    }
+
+    #[test]
+    fn priority_peers_are_appended_once_in_order() {
+        let peers: Vec<_> = [3, 1, 4, 3]
+            .iter()
+            .map(|port| TrustedPeer {
+                addr: addr(*port),
+                id: None,
+            })
+            .collect();
+        let mut priority_peers = vec![addr(1), addr(2)];
+        append_priority_peers(&mut priority_peers, &peers);
+        assert_eq!(priority_peers, vec![addr(1), addr(2), addr(3), addr(4)]);
+    }
+
+    #[test]
+    fn explorer_indexer_only_follows_its_upstream_node() {
+        let config: Config = serde_yaml::from_str(
+            r#"
+explorer:
+  enabled: true
+  indexer:
+    upstream:
+      address: /ip4/127.0.0.1/tcp/3100
+"#,
+        )
+        .unwrap();
+        let arguments = StartArguments::from_iter(&["jormungandr"]);
+        let network = generate_network(&arguments, &Some(config)).unwrap();
+
+        assert!(network.listen().is_none());
+        assert!(network.public_address.is_none());
+        let upstream = vec![addr(3100)];
+        let addrs = |peers: &[TrustedPeer]| peers.iter().map(|peer| peer.addr).collect::<Vec<_>>();
+        assert_eq!(addrs(&network.trusted_peers), upstream);
+        assert_eq!(addrs(&network.preferred_peers), upstream);
+        assert_eq!(network.block_propagation.priority_peers, upstream);
+        assert!(network.preferred_peers_only);
+        assert!(network.bootstrap_from_trusted_peers);
+    }
+}
@@ -59,6 +59,10 @@ pub struct Configuration {
     /// network interfaces.
     pub listen_address: Option<SocketAddr>,
 
+    /// Loopback socket address serving the gRPC services reserved to the
+    /// clients on the host of the node, if enabled.
+    pub local_listen_address: Option<SocketAddr>,
+
     pub public_address: Option<Address>,
 
     // Secret key used to authenticate gossips, the public part is used as an identifier of the node
@@ -99,6 +103,28 @@ pub struct Configuration {
     pub skip_bootstrap: bool,
 
     pub http_fetch_block0_service: Vec<String>,
+
+    pub block_propagation: BlockPropagation,
+
+    /// Peers the node keeps connected to and always propagates blocks and
+    /// fragments to.
+    pub preferred_peers: Vec<TrustedPeer>,
+
+    /// Whether the node only connects to the preferred peers, the peers
+    /// learned through gossip are not connected to.
+    pub preferred_peers_only: bool,
+}
+
+/// Settings for announcing new blocks to the peers.
+#[derive(Clone, Default)]
+pub struct BlockPropagation {
+    /// Maximum number of peers a block is announced to, priority peers included.
+    pub fan_out: Option<usize>,
+    /// Peers which are announced new blocks first.
+    pub priority_peers: Vec<SocketAddr>,
+    /// Peers which are always announced new blocks, before the priority peers
+    /// and outside of the fan-out.
+    pub preferred_peers: Vec<SocketAddr>,
 }
 
 /// Trusted peer with DNS address resolved.
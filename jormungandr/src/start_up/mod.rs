@@ -7,7 +7,7 @@ use crate::{
     blockcfg::{Block, HeaderId},
     blockchain::{Blockchain, Error as BlockchainError, Storage, Tip},
     network,
-    settings::start::Settings,
+    settings::start::{config::RewardsExport, Settings},
 };
 
 /// prepare the block storage from the given settings
@@ -151,12 +151,14 @@ pub async fn load_blockchain(
     storage: Storage,
     cache_capacity: usize,
     rewards_report_all: bool,
+    rewards_export: Option<RewardsExport>,
 ) -> Result<(Blockchain, Tip), Error> {
     let blockchain = Blockchain::new(
         block0.header.hash(),
         storage,
         cache_capacity,
         rewards_report_all,
+        rewards_export,
     );
 
     let main_branch = match blockchain.load_from_block0(block0.clone()).await {
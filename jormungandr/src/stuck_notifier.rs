@@ -1,9 +1,9 @@
-use crate::blockchain;
+use crate::{blockchain, utils::clock};
 use chain_time::{
     era::{EpochPosition, EpochSlotOffset},
     Epoch,
 };
-use std::time::{Duration, SystemTime};
+use std::time::Duration;
 use tokio::time::interval;
 
 pub async fn check_last_block_time(blockchain_tip: blockchain::Tip, check_interval: Duration) {
@@ -19,7 +19,7 @@ pub async fn check_last_block_time(blockchain_tip: blockchain::Tip, check_interv
         let tip = blockchain_tip.get_ref().await;
         let era = tip.epoch_leadership_schedule().era();
 
-        let now = SystemTime::now();
+        let now = clock::now();
 
         let tip_date = tip.block_date();
         let tip_slot = era.from_era_to_slot(EpochPosition {
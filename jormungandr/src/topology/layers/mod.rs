@@ -6,8 +6,126 @@ pub(super) use self::preferred_list::PreferredListLayer;
 pub(super) use self::rings::Rings;
 pub use self::rings::{ParseError, RingsConfig};
 
+use jormungandr_lib::interfaces::LayerSizeConfig;
+use thiserror::Error;
+
+// Larger views only increase the number of connections and the size of the
+// gossips without improving the selection of the neighbors.
+const MAX_LAYER_SIZE: usize = 256;
+
 #[derive(Clone)]
 pub struct LayersConfig {
     pub preferred_list: PreferredListConfig,
     pub rings: RingsConfig,
+    pub rings_size: LayerSize,
+    pub cyclon_size: LayerSize,
+}
+
+#[derive(Error, Debug)]
+#[error("the {size_name} of the {layer} layer must be between 1 and {max}, found {size}")]
+pub struct LayerSizeError {
+    layer: &'static str,
+    size_name: &'static str,
+    size: usize,
+    max: usize,
+}
+
+/// Number of entries a layer contributes to the view and to the gossips.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LayerSize {
+    pub view: usize,
+    pub gossip: usize,
+}
+
+impl LayerSize {
+    // Default values from poldercast
+    const RINGS_DEFAULT: Self = LayerSize {
+        view: 4,
+        gossip: 10,
+    };
+    const CYCLON_DEFAULT: Self = LayerSize {
+        view: 20,
+        gossip: 10,
+    };
+
+    /// Completes the configured sizes with the defaults of the layer and
+    /// checks that they are within `1..=max`.
+    fn from_config(
+        layer: &'static str,
+        config: LayerSizeConfig,
+        default: Self,
+        max: usize,
+    ) -> Result<Self, LayerSizeError> {
+        let check = |size_name, size: usize| {
+            if size == 0 || size > max {
+                Err(LayerSizeError {
+                    layer,
+                    size_name,
+                    size,
+                    max,
+                })
+            } else {
+                Ok(size)
+            }
+        };
+        Ok(LayerSize {
+            view: check("view size", config.view_size.unwrap_or(default.view))?,
+            gossip: check("gossip size", config.gossip_size.unwrap_or(default.gossip))?,
+        })
+    }
+
+    pub fn rings(config: Option<LayerSizeConfig>) -> Result<Self, LayerSizeError> {
+        // the rings layer of poldercast counts the entries on a byte
+        let max = MAX_LAYER_SIZE.min(u8::MAX.into());
+        Self::from_config(
+            "rings",
+            config.unwrap_or_default(),
+            Self::RINGS_DEFAULT,
+            max,
+        )
+    }
+
+    pub fn cyclon(config: Option<LayerSizeConfig>) -> Result<Self, LayerSizeError> {
+        Self::from_config(
+            "cyclon",
+            config.unwrap_or_default(),
+            Self::CYCLON_DEFAULT,
+            MAX_LAYER_SIZE,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn layer_sizes_default_to_poldercast_values() {
+        assert_eq!(LayerSize::rings(None).unwrap(), LayerSize::RINGS_DEFAULT);
+        let config = LayerSizeConfig {
+            view_size: Some(8),
+            gossip_size: None,
+        };
+        assert_eq!(
+            LayerSize::cyclon(Some(config)).unwrap(),
+            LayerSize {
+                view: 8,
+                gossip: LayerSize::CYCLON_DEFAULT.gossip,
+            }
+        );
+    }
+
+    #[test]
+    fn layer_sizes_out_of_bounds_are_rejected() {
+        let zero = LayerSizeConfig {
+            view_size: Some(0),
+            gossip_size: None,
+        };
+        assert!(LayerSize::cyclon(Some(zero)).is_err());
+        let too_large = LayerSizeConfig {
+            view_size: None,
+            gossip_size: Some(300),
+        };
+        assert!(LayerSize::rings(Some(too_large)).is_err());
+    }
 }
@@ -1,6 +1,7 @@
 use super::{Gossip, Gossips, P2pTopology, Peer};
 use crate::intercom::{NetworkMsg, PropagateMsg, TopologyMsg};
 use crate::metrics::Metrics;
+use crate::network::p2p::events::PeerEventLog;
 use crate::settings::start::network::Configuration;
 use crate::utils::async_msg::{MessageBox, MessageQueue};
 use std::time::Duration;
@@ -25,6 +26,7 @@ pub struct TaskData {
     pub initial_peers: Vec<Peer>,
     pub config: Configuration,
     pub stats_counter: Metrics,
+    pub peer_events: PeerEventLog,
 }
 
 pub async fn start(task_data: TaskData) {
@@ -34,9 +36,10 @@ pub async fn start(task_data: TaskData) {
         initial_peers,
         config,
         stats_counter,
+        peer_events,
     } = task_data;
 
-    let mut topology = P2pTopology::new(&config, stats_counter);
+    let mut topology = P2pTopology::new(&config, stats_counter, peer_events);
 
     topology.accept_gossips(Gossips::from(
         initial_peers
@@ -1,15 +1,16 @@
 //! module defining the p2p topology management objects
 //!
 use super::{
-    layers::{self, LayersConfig},
+    layers::{self, LayerSize, LayersConfig},
     quarantine::ReportNodeStatus,
     topic, Gossips, NodeId, Peer, PeerInfo, ReportRecords,
 };
 
 use crate::metrics::{Metrics, MetricsBackend};
+use crate::network::p2p::events::PeerEventLog;
 use crate::settings::start::network::Configuration;
 use chain_crypto::Ed25519;
-use jormungandr_lib::crypto::key::SigningKey;
+use jormungandr_lib::{crypto::key::SigningKey, interfaces::PeerEventKind};
 use poldercast::{
     layer::{self as poldercast_layer, Layer, LayerBuilder},
     Profile, Topology,
@@ -40,20 +41,13 @@ pub struct P2pTopology {
     quarantine: ReportRecords,
     key: keynesis::key::ed25519::SecretKey,
     stats_counter: Metrics,
+    peer_events: PeerEventLog,
 }
 
 struct CustomLayerBuilder {
     config: LayersConfig,
 }
 
-impl CustomLayerBuilder {
-    // Default values from poldercast
-    const RINGS_VIEW_SIZE: u8 = 4;
-    const VICINITY_VIEW_SIZE: usize = 20;
-    const CYCLON_VIEW_SIZE: usize = 20;
-    const GOSSIP_SIZE: u8 = 10;
-}
-
 impl From<LayersConfig> for CustomLayerBuilder {
     fn from(config: LayersConfig) -> Self {
         Self { config }
@@ -61,16 +55,21 @@ impl From<LayersConfig> for CustomLayerBuilder {
 }
 
 impl CustomLayerBuilder {
-    fn build_layers(&self, rings: u8, _vicinity: usize, cyclon: usize) -> Vec<Box<dyn Layer>> {
+    fn build_layers(&self, size: impl Fn(LayerSize) -> usize) -> Vec<Box<dyn Layer>> {
         let mut layers: Vec<Box<dyn Layer>> = Vec::with_capacity(4);
 
+        // the size of the rings layer is checked against u8::MAX when the
+        // settings are loaded
+        let rings = size(self.config.rings_size).try_into().unwrap();
         layers.push(Box::new(layers::Rings::new(
             self.config.rings.clone(),
             poldercast_layer::Rings::new(rings),
         )));
         // disabled until https://github.com/primetype/poldercast/pull/36 is fixed and merged
         //layers.push(Box::new(poldercast_layer::Vicinity::new(vicinity)));
-        layers.push(Box::new(poldercast_layer::Cyclon::new(cyclon)));
+        layers.push(Box::new(poldercast_layer::Cyclon::new(size(
+            self.config.cyclon_size,
+        ))));
 
         let mut seed = [0; 32];
         rand::thread_rng().fill(&mut seed);
@@ -85,24 +84,16 @@ impl CustomLayerBuilder {
 
 impl LayerBuilder for CustomLayerBuilder {
     fn build_for_view(&self) -> Vec<Box<dyn Layer>> {
-        self.build_layers(
-            Self::RINGS_VIEW_SIZE,
-            Self::VICINITY_VIEW_SIZE,
-            Self::CYCLON_VIEW_SIZE,
-        )
+        self.build_layers(|size| size.view)
     }
 
     fn build_for_gossip(&self) -> Vec<Box<dyn Layer>> {
-        self.build_layers(
-            Self::GOSSIP_SIZE,
-            Self::GOSSIP_SIZE.into(),
-            Self::GOSSIP_SIZE.into(),
-        )
+        self.build_layers(|size| size.gossip)
     }
 }
 
 impl P2pTopology {
-    pub fn new(config: &Configuration, stats_counter: Metrics) -> Self {
+    pub fn new(config: &Configuration, stats_counter: Metrics, peer_events: PeerEventLog) -> Self {
         let addr = config.public_address.or(Some(*LOCAL_ADDR)).unwrap();
         let key = secret_key_into_keynesis(config.node_key.clone());
 
@@ -116,6 +107,7 @@ impl P2pTopology {
             quarantine,
             key,
             stats_counter,
+            peer_events,
         }
     }
 
@@ -207,14 +199,19 @@ impl P2pTopology {
     #[instrument(skip(self), level = "debug")]
     pub fn report_node(&mut self, node_id: &NodeId) {
         if let Some(node) = self.topology.get(node_id.as_ref()).cloned() {
-            let result = self
-                .quarantine
-                .report_node(&mut self.topology, Peer::from(node.gossip().clone()));
+            let peer = Peer::from(node.gossip().clone());
+            let address = peer.address();
+            let result = self.quarantine.report_node(&mut self.topology, peer);
             if let ReportNodeStatus::Quarantine | ReportNodeStatus::SoftReport = result {
                 self.stats_counter.sub_peer_available_cnt(1);
             }
             if let ReportNodeStatus::Quarantine = result {
                 self.stats_counter.add_peer_quarantined_cnt(1);
+                self.peer_events.record(
+                    address,
+                    Some(node_id.as_ref().to_string()),
+                    PeerEventKind::Quarantined,
+                );
             }
         }
     }
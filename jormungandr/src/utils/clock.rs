@@ -0,0 +1,148 @@
+//! The clock from which the node computes the current slot.
+//!
+//! It is the system clock, unless the node is built with the
+//! `simulation-clock` feature and configured to simulate the clock: the clock
+//! then runs `acceleration` times faster than the system clock from the start
+//! of the blockchain, and it can be stepped forward at runtime. This lets the
+//! tests go through epoch transitions without waiting for them in real time.
+//!
+//! All the nodes of a network must simulate the same clock, they would
+//! otherwise disagree on the current slot.
+
+#[cfg(not(feature = "simulation-clock"))]
+use std::time::SystemTime;
+use std::time::{Duration, Instant};
+
+#[cfg(feature = "simulation-clock")]
+pub use self::simulation::{now, simulate, step};
+
+/// The current time of the node.
+#[cfg(not(feature = "simulation-clock"))]
+pub fn now() -> SystemTime {
+    SystemTime::now()
+}
+
+/// The instant at which the clock of the node will have advanced by `duration`.
+pub fn instant_in(duration: Duration) -> Instant {
+    #[cfg(feature = "simulation-clock")]
+    let duration = simulation::real_duration(duration);
+    Instant::now() + duration
+}
+
+/// Waits until `deadline`. With a simulated clock, it also returns as soon as
+/// the clock is stepped, since the deadline may have been passed.
+pub async fn sleep_until(deadline: Instant) {
+    let sleep = tokio::time::sleep_until(tokio::time::Instant::from_std(deadline));
+    #[cfg(feature = "simulation-clock")]
+    futures::future::select(Box::pin(sleep), Box::pin(simulation::stepped())).await;
+    #[cfg(not(feature = "simulation-clock"))]
+    sleep.await;
+}
+
+#[cfg(feature = "simulation-clock")]
+mod simulation {
+    use std::{
+        num::NonZeroU32,
+        sync::RwLock,
+        time::{Duration, SystemTime},
+    };
+    use tokio::sync::Notify;
+
+    struct Simulation {
+        /// the time at which the system clock and the simulated clock agree
+        start: SystemTime,
+        acceleration: NonZeroU32,
+        /// the sum of the steps taken so far
+        offset: Duration,
+    }
+
+    lazy_static! {
+        static ref SIMULATION: RwLock<Option<Simulation>> = RwLock::new(None);
+        static ref STEPPED: Notify = Notify::new();
+    }
+
+    /// Simulates the clock from `start`, the start time of the blockchain.
+    pub fn simulate(start: SystemTime, acceleration: NonZeroU32) {
+        *SIMULATION.write().unwrap() = Some(Simulation {
+            start,
+            acceleration,
+            offset: Duration::from_secs(0),
+        });
+    }
+
+    /// Moves the clock forward by `duration`. Returns `false` if the clock is
+    /// not simulated.
+    pub fn step(duration: Duration) -> bool {
+        match SIMULATION.write().unwrap().as_mut() {
+            Some(simulation) => simulation.offset += duration,
+            None => return false,
+        }
+        STEPPED.notify_waiters();
+        true
+    }
+
+    /// The current time of the node.
+    pub fn now() -> SystemTime {
+        let now = SystemTime::now();
+        match SIMULATION.read().unwrap().as_ref() {
+            Some(simulation) => match now.duration_since(simulation.start) {
+                Ok(elapsed) => {
+                    simulation.start + elapsed * simulation.acceleration.get() + simulation.offset
+                }
+                // the blockchain has not started yet
+                Err(_) => now + simulation.offset,
+            },
+            None => now,
+        }
+    }
+
+    pub(super) fn real_duration(duration: Duration) -> Duration {
+        match SIMULATION.read().unwrap().as_ref() {
+            Some(simulation) => duration / simulation.acceleration.get(),
+            None => duration,
+        }
+    }
+
+    pub(super) async fn stepped() {
+        STEPPED.notified().await
+    }
+}
+
+#[cfg(all(test, feature = "simulation-clock"))]
+mod tests {
+    use super::*;
+    use chain_time::{SlotDuration, TimeFrame, Timeline};
+    use std::{num::NonZeroU32, time::SystemTime};
+
+    const SLOT_DURATION: u64 = 10;
+    const SLOTS_PER_EPOCH: u64 = 6;
+
+    fn current_epoch(time_frame: &TimeFrame) -> u64 {
+        u64::from(time_frame.slot_at(&now()).unwrap()) / SLOTS_PER_EPOCH
+    }
+
+    // a single test, the simulated clock is global to the node
+    #[test]
+    fn simulated_clock_drives_epochs() {
+        let start = SystemTime::now() - Duration::from_secs(SLOT_DURATION);
+        let time_frame = TimeFrame::new(
+            Timeline::new(start),
+            SlotDuration::from_secs(SLOT_DURATION as u32),
+        );
+        let acceleration = NonZeroU32::new(SLOT_DURATION as u32).unwrap();
+        simulate(start, acceleration);
+
+        // the elapsed slot runs `acceleration` times faster
+        assert!(now().duration_since(start).unwrap() >= Duration::from_secs(100));
+        assert_eq!(current_epoch(&time_frame), 1);
+        let real_duration = instant_in(Duration::from_secs(SLOT_DURATION * 100)) - Instant::now();
+        assert!(real_duration <= Duration::from_secs(100));
+        assert!(real_duration > Duration::from_secs(99));
+
+        let epoch_duration = Duration::from_secs(SLOT_DURATION * SLOTS_PER_EPOCH);
+        for epoch in 2..5 {
+            assert!(step(epoch_duration));
+            assert_eq!(current_epoch(&time_frame), epoch);
+        }
+    }
+}
@@ -1,3 +1,4 @@
 pub mod async_msg;
+pub mod clock;
 pub mod fire_forget_scheduler;
 pub mod task;
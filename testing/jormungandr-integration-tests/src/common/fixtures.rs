@@ -0,0 +1,163 @@
+//! Setups shared by the tests running against a single node: wallets funded in
+//! block0, the running node and the fragments prepared between the wallets.
+//!
+//! A test file declares its `world()` fixture on top of [`WorldBuilder`]:
+//!
+//! ```ignore
+//! #[fixture]
+//! fn world() -> World {
+//!     WorldBuilder::new().with_wallets(3).build()
+//! }
+//! ```
+
+use crate::common::{
+    jormungandr::{ConfigurationBuilder, JormungandrProcess},
+    startup,
+};
+use chain_impl_mockchain::{block::BlockDate, chaintypes::ConsensusVersion, fragment::Fragment};
+use jormungandr_lib::interfaces::{Mempool, Value};
+use jormungandr_testing_utils::{
+    stake_pool::StakePool, testing::fragments::FaultyTransactionBuilder, wallet::Wallet,
+};
+
+/// A running node and the wallets of a test. The funded wallets come first,
+/// then the wallets unknown to block0.
+pub struct World {
+    pub jormungandr: JormungandrProcess,
+    pub wallets: Vec<Wallet>,
+    /// the stake pools of the first wallets, empty with BFT consensus
+    pub stake_pools: Vec<StakePool>,
+}
+
+impl World {
+    pub fn wallet(&self, index: usize) -> &Wallet {
+        &self.wallets[index]
+    }
+
+    /// Transaction of `value` from the wallet at `from` to the wallet at `to`,
+    /// valid until the start of the second epoch. It is not sent: the spending
+    /// counter of the sender is confirmed once it is.
+    pub fn transaction(&mut self, from: usize, to: usize, value: Value) -> Fragment {
+        let address = self.wallets[to].address();
+        self.wallets[from]
+            .transaction_to(
+                &self.jormungandr.genesis_block_hash(),
+                &self.jormungandr.fees(),
+                BlockDate::first().next_epoch(),
+                address,
+                value,
+            )
+            .unwrap()
+    }
+
+    /// Transaction from the wallet at `from` to the wallet at `to` whose
+    /// inputs and outputs do not balance, rejected as soon as it is received.
+    pub fn unbalanced_transaction(&self, from: usize, to: usize) -> Fragment {
+        FaultyTransactionBuilder::new(
+            self.jormungandr.genesis_block_hash(),
+            self.jormungandr.fees(),
+            BlockDate::first().next_epoch(),
+        )
+        .unbalanced(&self.wallets[from], &self.wallets[to])
+    }
+}
+
+/// Starts a node for a [`World`]. By default, two account wallets are funded
+/// and the first one owns the only stake pool of a genesis praos network.
+pub struct WorldBuilder {
+    wallets: usize,
+    unfunded_wallets: usize,
+    stake_pools: usize,
+    consensus: ConsensusVersion,
+    config_builder: ConfigurationBuilder,
+}
+
+impl Default for WorldBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WorldBuilder {
+    pub fn new() -> Self {
+        Self {
+            wallets: 2,
+            unfunded_wallets: 0,
+            stake_pools: 1,
+            consensus: ConsensusVersion::GenesisPraos,
+            config_builder: ConfigurationBuilder::new(),
+        }
+    }
+
+    /// Number of account wallets funded in block0.
+    pub fn with_wallets(mut self, wallets: usize) -> Self {
+        self.wallets = wallets;
+        self
+    }
+
+    /// Number of account wallets without funds, following the funded ones.
+    pub fn with_unfunded_wallets(mut self, unfunded_wallets: usize) -> Self {
+        self.unfunded_wallets = unfunded_wallets;
+        self
+    }
+
+    /// Number of funded wallets, taken first, owning a stake pool. Only used
+    /// with genesis praos consensus.
+    pub fn with_stake_pools(mut self, stake_pools: usize) -> Self {
+        self.stake_pools = stake_pools;
+        self
+    }
+
+    pub fn with_consensus(mut self, consensus: ConsensusVersion) -> Self {
+        self.consensus = consensus;
+        self
+    }
+
+    pub fn with_mempool(mut self, mempool: Mempool) -> Self {
+        self.config_builder.with_mempool(mempool);
+        self
+    }
+
+    /// Base configuration of the node, for the settings not covered by the
+    /// builder. The funds, the consensus and the leaders are set on top of it.
+    pub fn with_config(mut self, config_builder: ConfigurationBuilder) -> Self {
+        self.config_builder = config_builder;
+        self
+    }
+
+    pub fn build(mut self) -> World {
+        assert!(
+            self.stake_pools <= self.wallets,
+            "{} stake pools for {} funded wallets",
+            self.stake_pools,
+            self.wallets
+        );
+
+        let mut wallets: Vec<Wallet> = std::iter::repeat_with(startup::create_new_account_address)
+            .take(self.wallets)
+            .collect();
+
+        let (jormungandr, stake_pools) = match self.consensus {
+            ConsensusVersion::GenesisPraos => startup::start_stake_pool(
+                &wallets[..self.stake_pools],
+                &wallets[self.stake_pools..],
+                &mut self.config_builder,
+            )
+            .unwrap(),
+            ConsensusVersion::Bft => (
+                startup::start_bft(wallets.iter().collect(), &mut self.config_builder).unwrap(),
+                Vec::new(),
+            ),
+        };
+
+        wallets.extend(
+            std::iter::repeat_with(startup::create_new_account_address).take(self.unfunded_wallets),
+        );
+
+        World {
+            jormungandr,
+            wallets,
+            stake_pools,
+        }
+    }
+}
@@ -190,6 +190,67 @@ impl Certificate {
             .as_single_line()
     }
 
+    pub fn ownership_proof<P: AsRef<Path>, Q: AsRef<Path>>(
+        self,
+        identity: &str,
+        signing_keys: &[P],
+        certificate: Q,
+    ) -> String {
+        println!("Running create ownership proof...");
+        let temp_file = NamedTempFile::new("ownership_proof.json").unwrap();
+        self.command
+            .ownership_proof(identity, signing_keys, certificate, temp_file.path())
+            .build()
+            .assert()
+            .success();
+        file::read_file(temp_file.path())
+    }
+
+    pub fn ownership_proof_expect_fail<P: AsRef<Path>, Q: AsRef<Path>>(
+        self,
+        identity: &str,
+        signing_keys: &[P],
+        certificate: Q,
+        expected_msg: &str,
+    ) {
+        let temp_file = NamedTempFile::new("ownership_proof.json").unwrap();
+        self.command
+            .ownership_proof(identity, signing_keys, certificate, temp_file.path())
+            .build()
+            .assert()
+            .failure()
+            .stderr(predicates::str::contains(expected_msg));
+    }
+
+    pub fn verify_ownership_proof<P: AsRef<Path>, Q: AsRef<Path>>(
+        self,
+        proof: P,
+        certificate: Q,
+        identity: &str,
+    ) {
+        println!("Running verify ownership proof...");
+        self.command
+            .verify_ownership_proof(proof, certificate, identity)
+            .build()
+            .assert()
+            .success();
+    }
+
+    pub fn verify_ownership_proof_expect_fail<P: AsRef<Path>, Q: AsRef<Path>>(
+        self,
+        proof: P,
+        certificate: Q,
+        identity: &str,
+        expected_msg: &str,
+    ) {
+        self.command
+            .verify_ownership_proof(proof, certificate, identity)
+            .build()
+            .assert()
+            .failure()
+            .stderr(predicates::str::contains(expected_msg));
+    }
+
     pub fn new_stake_pool_retirement(self, stake_pool_id: &str) -> String {
         println!("Running create retirement certification...");
         self.command
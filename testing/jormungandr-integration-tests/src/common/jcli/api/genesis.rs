@@ -80,4 +80,26 @@ impl Genesis {
             .get_output()
             .as_lossy_string()
     }
+
+    pub fn diff<P: AsRef<Path>, Q: AsRef<Path>>(self, before: P, after: Q) -> String {
+        self.genesis_command
+            .diff(before, after)
+            .assert()
+            .success()
+            .get_output()
+            .as_lossy_string()
+    }
+
+    pub fn diff_expect_fail<P: AsRef<Path>, Q: AsRef<Path>>(
+        self,
+        before: P,
+        after: Q,
+        expected_msg: &str,
+    ) {
+        self.genesis_command
+            .diff(before, after)
+            .assert()
+            .failure()
+            .stderr(predicates::str::contains(expected_msg));
+    }
 }
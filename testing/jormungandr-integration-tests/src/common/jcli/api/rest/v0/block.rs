@@ -22,6 +22,16 @@ impl Block {
             .as_lossy_string()
     }
 
+    pub fn get_decoded<P: Into<String>, S: Into<String>>(self, block_id: P, host: S) -> String {
+        self.block_command
+            .get_decoded(block_id, host)
+            .build()
+            .assert()
+            .success()
+            .get_output()
+            .as_lossy_string()
+    }
+
     pub fn get_expect_fail<P: Into<String>, S: Into<String>>(
         self,
         block_id: P,
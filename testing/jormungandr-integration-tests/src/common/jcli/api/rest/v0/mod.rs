@@ -131,6 +131,14 @@ impl RestV0 {
         serde_yaml::from_str(&content).unwrap()
     }
 
+    pub fn support_bundle<S: Into<String>, P: AsRef<std::path::Path>>(self, host: S, output: P) {
+        self.v0_command
+            .support_bundle(host, output)
+            .build()
+            .assert()
+            .success();
+    }
+
     pub fn shutdown<S: Into<String>>(self, host: S) {
         self.v0_command.shutdown(host).build().assert().success();
     }
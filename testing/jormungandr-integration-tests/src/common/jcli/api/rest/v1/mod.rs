@@ -0,0 +1,55 @@
+use crate::common::jcli::command::rest::V1Command;
+use assert_cmd::assert::OutputAssertExt;
+use jortestkit::prelude::ProcessOutput;
+
+pub struct RestV1 {
+    v1_command: V1Command,
+}
+
+impl RestV1 {
+    pub fn new(v1_command: V1Command) -> Self {
+        Self { v1_command }
+    }
+
+    pub fn stake_pool_dashboard<S: Into<String>, P: Into<String>>(
+        self,
+        stake_pool_id: S,
+        host: P,
+    ) -> serde_yaml::Value {
+        let content = self
+            .v1_command
+            .stake_pool_dashboard(stake_pool_id, host)
+            .build()
+            .assert()
+            .success()
+            .get_output()
+            .as_lossy_string();
+        serde_yaml::from_str(&content).expect("Failed to parse stake pool dashboard")
+    }
+
+    pub fn vote_plans_summary<S: Into<String>>(self, host: S) -> serde_json::Value {
+        let content = self
+            .v1_command
+            .vote_plans_summary(host)
+            .build()
+            .assert()
+            .success()
+            .get_output()
+            .as_lossy_string();
+        serde_json::from_str(&content).expect("Failed to parse vote plans summary")
+    }
+
+    pub fn stake_pool_dashboard_expect_fail<S: Into<String>, P: Into<String>>(
+        self,
+        stake_pool_id: S,
+        host: P,
+        expected_msg: &str,
+    ) {
+        self.v1_command
+            .stake_pool_dashboard(stake_pool_id, host)
+            .build()
+            .assert()
+            .failure()
+            .stderr(predicates::str::contains(expected_msg));
+    }
+}
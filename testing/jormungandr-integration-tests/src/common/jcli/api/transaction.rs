@@ -230,6 +230,7 @@ impl Transaction {
                 None,
                 staging_file,
             ),
+            Wallet::Multisig(_) => unimplemented!(),
         }
     }
 
@@ -279,6 +280,22 @@ impl Transaction {
         self.command.seal(staging_file).build().assert().success();
     }
 
+    pub fn export<P: AsRef<Path>, Q: AsRef<Path>>(self, format: &str, output: P, staging_file: Q) {
+        self.command
+            .export(format, output, staging_file)
+            .build()
+            .assert()
+            .success();
+    }
+
+    pub fn import<P: AsRef<Path>, Q: AsRef<Path>>(self, format: &str, input: P, staging_file: Q) {
+        self.command
+            .import(format, input, staging_file)
+            .build()
+            .assert()
+            .success();
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn make_transaction(
         self,
@@ -368,4 +385,26 @@ impl Transaction {
             .assert()
             .success();
     }
+
+    pub fn set_expiry_date_valid_for<P: AsRef<Path>>(self, validity: &[String], staging_file: P) {
+        self.command
+            .set_expiry_date_valid_for(validity, staging_file)
+            .build()
+            .assert()
+            .success();
+    }
+
+    pub fn set_expiry_date_valid_for_expect_fail<P: AsRef<Path>>(
+        self,
+        validity: &[String],
+        staging_file: P,
+        expected_msg: &str,
+    ) {
+        self.command
+            .set_expiry_date_valid_for(validity, staging_file)
+            .build()
+            .assert()
+            .failure()
+            .stderr(predicates::str::contains(expected_msg));
+    }
 }
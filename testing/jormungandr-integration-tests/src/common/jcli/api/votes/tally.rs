@@ -59,6 +59,38 @@ impl Tally {
             .stderr(predicates::str::contains(expected_msg));
     }
 
+    pub fn verify_share<P: AsRef<Path>, R: AsRef<Path>, S: Into<String>>(
+        self,
+        vote_plan: P,
+        vote_plan_id: S,
+        shares: R,
+        member_public_key: S,
+    ) -> String {
+        self.tally_command
+            .verify_share(vote_plan, vote_plan_id, shares, member_public_key)
+            .build()
+            .assert()
+            .success()
+            .get_output()
+            .as_lossy_string()
+    }
+
+    pub fn verify_share_expect_fail<P: AsRef<Path>, R: AsRef<Path>, S: Into<String>>(
+        self,
+        vote_plan: P,
+        vote_plan_id: S,
+        shares: R,
+        member_public_key: S,
+        expected_msg: &str,
+    ) {
+        self.tally_command
+            .verify_share(vote_plan, vote_plan_id, shares, member_public_key)
+            .build()
+            .assert()
+            .failure()
+            .stderr(predicates::str::contains(expected_msg));
+    }
+
     pub fn merge_shares<P: AsRef<Path>>(self, shares_to_merge: Vec<P>) -> String {
         self.tally_command
             .merge_shares(shares_to_merge)
@@ -219,6 +219,47 @@ impl CertificateCommand {
         self
     }
 
+    pub fn ownership_proof<P: AsRef<Path>, Q: AsRef<Path>, R: AsRef<Path>>(
+        mut self,
+        identity: &str,
+        signing_keys: &[P],
+        certificate: Q,
+        output_file: R,
+    ) -> Self {
+        self.command
+            .arg("ownership-proof")
+            .arg("create")
+            .arg("--identity")
+            .arg(identity);
+        for signing_key in signing_keys {
+            self.command.arg("--key").arg(signing_key.as_ref());
+        }
+        self.command
+            .arg("--certificate")
+            .arg(certificate.as_ref())
+            .arg("--output")
+            .arg(output_file.as_ref());
+        self
+    }
+
+    pub fn verify_ownership_proof<P: AsRef<Path>, Q: AsRef<Path>>(
+        mut self,
+        proof: P,
+        certificate: Q,
+        identity: &str,
+    ) -> Self {
+        self.command
+            .arg("ownership-proof")
+            .arg("verify")
+            .arg("--proof")
+            .arg(proof.as_ref())
+            .arg("--certificate")
+            .arg(certificate.as_ref())
+            .arg("--identity")
+            .arg(identity);
+        self
+    }
+
     pub fn build(self) -> Command {
         self.command
     }
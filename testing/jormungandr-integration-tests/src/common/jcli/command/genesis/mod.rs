@@ -5,6 +5,7 @@ mod hash;
 pub use decode::GenesisDecodeCommand;
 pub use encode::GenesisEncodeCommand;
 pub use hash::GenesisHashCommand;
+use std::path::Path;
 use std::process::Command;
 pub struct GenesisCommand {
     command: Command,
@@ -34,4 +35,12 @@ impl GenesisCommand {
         self.command.arg("init");
         self.command
     }
+
+    pub fn diff<P: AsRef<Path>, Q: AsRef<Path>>(mut self, before: P, after: Q) -> Command {
+        self.command
+            .arg("diff")
+            .arg(before.as_ref())
+            .arg(after.as_ref());
+        self.command
+    }
 }
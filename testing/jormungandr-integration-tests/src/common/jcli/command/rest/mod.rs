@@ -1,6 +1,8 @@
 pub mod v0;
+pub mod v1;
 
 pub use v0::V0Command;
+pub use v1::V1Command;
 
 use std::process::Command;
 
@@ -17,4 +19,9 @@ impl RestCommand {
         self.command.arg("v0");
         V0Command::new(self.command)
     }
+
+    pub fn v1(mut self) -> V1Command {
+        self.command.arg("v1");
+        V1Command::new(self.command)
+    }
 }
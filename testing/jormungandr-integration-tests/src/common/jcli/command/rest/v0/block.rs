@@ -17,6 +17,17 @@ impl BlockCommand {
             .arg(host.into());
         self
     }
+
+    pub fn get_decoded<P: Into<String>, S: Into<String>>(mut self, block_id: P, host: S) -> Self {
+        self.command
+            .arg(block_id.into())
+            .arg("get")
+            .arg("--decode")
+            .arg("-h")
+            .arg(host.into());
+        self
+    }
+
     pub fn next<P: Into<String>, S: Into<String>>(
         mut self,
         block_id: P,
@@ -122,6 +122,20 @@ impl V0Command {
         self
     }
 
+    pub fn support_bundle<S: Into<String>, P: AsRef<std::path::Path>>(
+        mut self,
+        host: S,
+        output: P,
+    ) -> Self {
+        self.command
+            .arg("support-bundle")
+            .arg("--host")
+            .arg(host.into())
+            .arg("--output")
+            .arg(output.as_ref());
+        self
+    }
+
     pub fn build(self) -> Command {
         println!("{:?}", self.command);
         self.command
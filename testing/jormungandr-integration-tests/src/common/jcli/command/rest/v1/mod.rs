@@ -0,0 +1,42 @@
+use std::process::Command;
+
+pub struct V1Command {
+    command: Command,
+}
+
+impl V1Command {
+    pub fn new(command: Command) -> Self {
+        Self { command }
+    }
+
+    pub fn stake_pool_dashboard<S: Into<String>, P: Into<String>>(
+        mut self,
+        stake_pool_id: S,
+        host: P,
+    ) -> Self {
+        self.command
+            .arg("stake-pool")
+            .arg("dashboard")
+            .arg(stake_pool_id.into())
+            .arg("--host")
+            .arg(host.into());
+        self
+    }
+
+    pub fn vote_plans_summary<S: Into<String>>(mut self, host: S) -> Self {
+        self.command
+            .arg("vote")
+            .arg("plans")
+            .arg("list")
+            .arg("--summary")
+            .arg("--json")
+            .arg("--host")
+            .arg(host.into());
+        self
+    }
+
+    pub fn build(self) -> Command {
+        println!("{:?}", self.command);
+        self.command
+    }
+}
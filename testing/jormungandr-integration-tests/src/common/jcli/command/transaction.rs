@@ -168,6 +168,40 @@ impl TransactionCommand {
         self
     }
 
+    pub fn export<P: AsRef<Path>, Q: AsRef<Path>>(
+        mut self,
+        format: &str,
+        output: P,
+        staging_file: Q,
+    ) -> Self {
+        self.command
+            .arg("export")
+            .arg("--format")
+            .arg(format)
+            .arg("--output")
+            .arg(output.as_ref())
+            .arg("--staging")
+            .arg(staging_file.as_ref());
+        self
+    }
+
+    pub fn import<P: AsRef<Path>, Q: AsRef<Path>>(
+        mut self,
+        format: &str,
+        input: P,
+        staging_file: Q,
+    ) -> Self {
+        self.command
+            .arg("import")
+            .arg("--format")
+            .arg(format)
+            .arg("--input")
+            .arg(input.as_ref())
+            .arg("--staging")
+            .arg(staging_file.as_ref());
+        self
+    }
+
     pub fn id<P: AsRef<Path>>(mut self, staging_file: P) -> Self {
         self.command
             .arg("data-for-witness")
@@ -233,6 +267,19 @@ impl TransactionCommand {
         self
     }
 
+    pub fn set_expiry_date_valid_for<P: AsRef<Path>>(
+        mut self,
+        validity: &[String],
+        staging_file: P,
+    ) -> Self {
+        self.command
+            .arg("set-expiry-date")
+            .args(validity)
+            .arg("--staging")
+            .arg(staging_file.as_ref());
+        self
+    }
+
     pub fn build(self) -> Command {
         println!("{:?}", self.command);
         self.command
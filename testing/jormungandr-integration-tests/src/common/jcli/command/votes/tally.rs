@@ -47,6 +47,28 @@ impl TallyCommand {
         self
     }
 
+    pub fn verify_share<P: AsRef<Path>, R: AsRef<Path>, S: Into<String>>(
+        mut self,
+        vote_plan: P,
+        vote_plan_id: S,
+        shares: R,
+        member_public_key: S,
+    ) -> Self {
+        self.command
+            .arg("verify-share")
+            .arg("--vote-plan")
+            .arg(vote_plan.as_ref())
+            .arg("--vote-plan-id")
+            .arg(vote_plan_id.into())
+            .arg("--shares")
+            .arg(shares.as_ref())
+            .arg("--key")
+            .arg(member_public_key.into())
+            .arg("--output-format")
+            .arg("json");
+        self
+    }
+
     pub fn merge_shares<P: AsRef<Path>>(mut self, shares: Vec<P>) -> Self {
         self.command.arg("merge-shares");
 
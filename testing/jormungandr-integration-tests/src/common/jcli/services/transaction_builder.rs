@@ -249,6 +249,7 @@ impl TransactionBuilder {
                 "utxo",
                 None,
             ),
+            Wallet::Multisig(_) => unimplemented!(),
         }
     }
 
@@ -294,6 +295,20 @@ impl TransactionBuilder {
         self
     }
 
+    pub fn export<P: AsRef<Path>>(&self, format: &str, output: P) -> &Self {
+        self.jcli
+            .transaction()
+            .export(format, output, self.staging_file().path());
+        self
+    }
+
+    pub fn import<P: AsRef<Path>>(&mut self, format: &str, input: P) -> &mut Self {
+        self.jcli
+            .transaction()
+            .import(format, input, self.staging_file().path());
+        self
+    }
+
     pub fn to_message(&self) -> String {
         self.jcli
             .transaction()
@@ -8,7 +8,7 @@ use chain_crypto::Ed25519;
 use chain_impl_mockchain::{chaintypes::ConsensusVersion, fee::LinearFee};
 use jormungandr_lib::crypto::key::KeyPair;
 use jormungandr_lib::interfaces::{
-    ActiveSlotCoefficient, CommitteeIdDef, ConsensusLeaderId, EpochStabilityDepth, FeesGoTo,
+    ActiveSlotCoefficient, CommitteeIdDef, ConsensusLeaderId, Cors, EpochStabilityDepth, FeesGoTo,
     Initial, InitialUTxO, KesUpdateSpeed, Log, LogEntry, LogOutput, Mempool, NodeConfig,
     NodeSecret, NumberOfSlotsPerEpoch, Policy, SignedCertificate, SlotDuration, Tls, TrustedPeer,
     Value,
@@ -22,7 +22,7 @@ use jormungandr_testing_utils::{
     },
     wallet::Wallet,
 };
-use std::path::PathBuf;
+use std::{num::NonZeroU32, path::PathBuf};
 
 #[derive(Clone, Debug)]
 pub struct ConfigurationBuilder {
@@ -47,6 +47,7 @@ pub struct ConfigurationBuilder {
     configure_default_log: bool,
     committee_ids: Vec<CommitteeIdDef>,
     leader_key_pair: Option<KeyPair<Ed25519>>,
+    standby_leaders: Vec<KeyPair<Ed25519>>,
     discrimination: Discrimination,
     tx_max_expiry_epochs: Option<u8>,
 }
@@ -78,6 +79,7 @@ impl ConfigurationBuilder {
             configure_default_log: true,
             committee_ids: vec![],
             leader_key_pair: None,
+            standby_leaders: vec![],
             fees_go_to: None,
             treasury: None,
             total_reward_supply: None,
@@ -155,6 +157,17 @@ impl ConfigurationBuilder {
         self
     }
 
+    pub fn with_rest_cors_config(&mut self, cors: Cors) -> &mut Self {
+        self.node_config_builder.with_rest_cors_config(cors);
+        self
+    }
+
+    pub fn with_rest_max_body_size(&mut self, max_body_size: u64) -> &mut Self {
+        self.node_config_builder
+            .with_rest_max_body_size(max_body_size);
+        self
+    }
+
     pub fn with_block_content_max_size(&mut self, block_content_max_size: u32) -> &mut Self {
         self.block_content_max_size = block_content_max_size;
         self
@@ -236,6 +249,11 @@ impl ConfigurationBuilder {
         self
     }
 
+    pub fn with_simulation_clock(&mut self, acceleration: NonZeroU32) -> &mut Self {
+        self.node_config_builder.with_simulation_clock(acceleration);
+        self
+    }
+
     pub fn with_trusted_peers(&mut self, trusted_peers: Vec<TrustedPeer>) -> &mut Self {
         self.node_config_builder.with_trusted_peers(trusted_peers);
         self
@@ -258,6 +276,11 @@ impl ConfigurationBuilder {
         self
     }
 
+    pub fn with_local_listen_port(&mut self, port: u16) -> &mut Self {
+        self.node_config_builder.with_local_listen_port(port);
+        self
+    }
+
     pub fn with_block_hash(&mut self, block0_hash: impl Into<String>) -> &mut Self {
         self.block0_hash = Some(block0_hash.into());
         self
@@ -273,6 +296,14 @@ impl ConfigurationBuilder {
         self
     }
 
+    /// BFT leaders joining the leader set during the test, through update
+    /// proposals. The node is given their keys, but they are not leaders in
+    /// block0.
+    pub fn with_standby_leaders(&mut self, standby_leaders: Vec<KeyPair<Ed25519>>) -> &mut Self {
+        self.standby_leaders = standby_leaders;
+        self
+    }
+
     pub fn with_treasury(&mut self, treasury: Value) -> &mut Self {
         self.treasury = Some(treasury);
         self
@@ -368,7 +399,7 @@ impl ConfigurationBuilder {
             output_file.path().to_path_buf()
         }
 
-        let secret_model_paths = if self.secrets.is_empty() {
+        let mut secret_model_paths = if self.secrets.is_empty() {
             let secret = SecretModelFactory::bft(leader_key_pair.signing_key());
             let output_file = temp_dir.child("node_secret.yaml");
             vec![write_secret(&secret, output_file)]
@@ -382,6 +413,11 @@ impl ConfigurationBuilder {
                 })
                 .collect()
         };
+        secret_model_paths.extend(self.standby_leaders.iter().enumerate().map(|(i, x)| {
+            let secret = SecretModelFactory::bft(x.signing_key());
+            let output_file = temp_dir.child(&format!("node_secret-standby-{}.yaml", i));
+            write_secret(&secret, output_file)
+        }));
 
         let config_file = temp_dir.child("node_config.yaml");
 
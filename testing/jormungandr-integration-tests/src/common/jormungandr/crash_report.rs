@@ -0,0 +1,172 @@
+use std::fmt;
+use std::fs::{self, File};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus};
+
+/// File of the node directory the standard error of the node is written to,
+/// where the panic message and backtrace end up.
+pub const PANIC_OUTPUT_FILE: &str = "stderr.log";
+
+/// Prefix of the core dump files with the default `core` pattern of the
+/// kernel. Other patterns (e.g. systemd-coredump) put the dumps elsewhere.
+const CORE_DUMP_PREFIX: &str = "core";
+
+/// Makes the node write its standard error to the node directory with full
+/// backtraces. With `core_dumps`, the core file size limit of the node is
+/// raised to the hard limit and the node runs in its directory, so that a core
+/// dump lands there.
+pub fn capture_crash_output(command: &mut Command, dir: &Path, core_dumps: bool) -> io::Result<()> {
+    command
+        .env("RUST_BACKTRACE", "full")
+        .stderr(File::create(dir.join(PANIC_OUTPUT_FILE))?);
+    if core_dumps {
+        command.current_dir(dir);
+        enable_core_dumps(command);
+    }
+    Ok(())
+}
+
+#[cfg(unix)]
+fn enable_core_dumps(command: &mut Command) {
+    use std::os::unix::process::CommandExt;
+
+    // only async-signal-safe calls between fork and exec
+    unsafe {
+        command.pre_exec(|| {
+            let mut limit = libc::rlimit {
+                rlim_cur: 0,
+                rlim_max: 0,
+            };
+            if libc::getrlimit(libc::RLIMIT_CORE, &mut limit) != 0 {
+                return Err(io::Error::last_os_error());
+            }
+            limit.rlim_cur = limit.rlim_max;
+            if libc::setrlimit(libc::RLIMIT_CORE, &limit) != 0 {
+                return Err(io::Error::last_os_error());
+            }
+            Ok(())
+        });
+    }
+}
+
+#[cfg(not(unix))]
+fn enable_core_dumps(_command: &mut Command) {}
+
+/// What a node that exited on its own with a failure left behind: the panic
+/// message and backtrace it printed, and its core dumps if they were enabled.
+#[derive(Debug, Clone)]
+pub struct CrashReport {
+    pub alias: String,
+    pub status: ExitStatus,
+    pub panic_output: String,
+    pub core_dumps: Vec<PathBuf>,
+}
+
+impl CrashReport {
+    pub fn collect(alias: String, status: ExitStatus, dir: &Path) -> Self {
+        let panic_output = fs::read_to_string(dir.join(PANIC_OUTPUT_FILE))
+            .unwrap_or_else(|e| format!("cannot read {}: {}", PANIC_OUTPUT_FILE, e));
+        let core_dumps = fs::read_dir(dir)
+            .map(|entries| {
+                entries
+                    .filter_map(Result::ok)
+                    .map(|entry| entry.path())
+                    .filter(|path| {
+                        path.file_name()
+                            .and_then(|name| name.to_str())
+                            .map_or(false, |name| name.starts_with(CORE_DUMP_PREFIX))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        CrashReport {
+            alias,
+            status,
+            panic_output,
+            core_dumps,
+        }
+    }
+
+    /// The files of the report once the node directory `from` is moved to `to`.
+    pub fn relocate(&mut self, from: &Path, to: &Path) {
+        for path in &mut self.core_dumps {
+            if let Ok(relative) = path.strip_prefix(from) {
+                *path = to.join(relative);
+            }
+        }
+    }
+}
+
+impl fmt::Display for CrashReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "node {} crashed: {}", self.alias, self.status)?;
+        writeln!(f, "standard error:")?;
+        writeln!(f, "{}", self.panic_output.trim_end())?;
+        if self.core_dumps.is_empty() {
+            write!(f, "no core dump")
+        } else {
+            write!(f, "core dumps:")?;
+            for path in &self.core_dumps {
+                write!(f, "\n  {}", path.display())?;
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use assert_fs::{prelude::*, TempDir};
+    use std::os::unix::process::ExitStatusExt;
+
+    fn failure() -> ExitStatus {
+        ExitStatus::from_raw(101 << 8)
+    }
+
+    #[test]
+    fn report_has_the_panic_output_and_the_core_dumps() {
+        let dir = TempDir::new().unwrap();
+        dir.child(PANIC_OUTPUT_FILE)
+            .write_str("thread 'main' panicked at 'boom'\n")
+            .unwrap();
+        dir.child("core.1234").touch().unwrap();
+        dir.child("node_config.yaml").touch().unwrap();
+
+        let report = CrashReport::collect("leader".to_owned(), failure(), dir.path());
+        assert_eq!(report.panic_output, "thread 'main' panicked at 'boom'\n");
+        assert_eq!(report.core_dumps, vec![dir.path().join("core.1234")]);
+    }
+
+    #[test]
+    fn missing_panic_output_is_reported() {
+        let dir = TempDir::new().unwrap();
+        let report = CrashReport::collect("leader".to_owned(), failure(), dir.path());
+        assert!(report
+            .panic_output
+            .starts_with(&format!("cannot read {}", PANIC_OUTPUT_FILE)));
+        assert!(report.core_dumps.is_empty());
+        assert!(report.to_string().ends_with("no core dump"));
+    }
+
+    #[test]
+    fn relocated_core_dumps_are_listed() {
+        let mut report = CrashReport {
+            alias: "leader".to_owned(),
+            status: failure(),
+            panic_output: "boom\n".to_owned(),
+            core_dumps: vec![PathBuf::from("/tmp/node/core.1234")],
+        };
+        report.relocate(Path::new("/tmp/node"), Path::new("/logs/leader"));
+        assert_eq!(
+            report.core_dumps,
+            vec![PathBuf::from("/logs/leader/core.1234")]
+        );
+
+        let text = report.to_string();
+        assert!(text.starts_with("node leader crashed: "));
+        assert!(text.contains("standard error:\nboom\n"));
+        assert!(text.ends_with("core dumps:\n  /logs/leader/core.1234"));
+    }
+}
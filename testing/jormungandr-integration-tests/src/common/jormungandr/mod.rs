@@ -1,10 +1,12 @@
 mod configuration_builder;
+pub mod crash_report;
 pub mod process;
 pub mod starter;
 use chain_core::property::Fragment as _;
 use chain_impl_mockchain::fragment::Fragment;
 use chain_impl_mockchain::fragment::FragmentId;
 pub use configuration_builder::ConfigurationBuilder;
+pub use crash_report::CrashReport;
 use jormungandr_lib::crypto::hash::Hash;
 use jormungandr_lib::interfaces::BlockDate;
 use jormungandr_lib::interfaces::FragmentLog;
@@ -1,4 +1,4 @@
-use super::{starter::StartupError, JormungandrError};
+use super::{starter::StartupError, CrashReport, JormungandrError};
 use crate::common::jcli::{JCli, JCliCommand};
 use ::multiaddr::Multiaddr;
 use assert_fs::TempDir;
@@ -23,7 +23,7 @@ use jormungandr_testing_utils::testing::{
     FragmentChainSender, FragmentSender, FragmentSenderSetup,
 };
 use std::net::SocketAddr;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process::Child;
 use std::process::Stdio;
 use std::str::FromStr;
@@ -45,6 +45,8 @@ pub struct JormungandrProcess {
     pub child: Child,
     pub logger: JormungandrLogger,
     temp_dir: Option<TempDir>,
+    /// directory of the configuration of the node, where its crash output is
+    crash_dir: PathBuf,
     alias: String,
     p2p_public_address: Multiaddr,
     rest_socket_addr: SocketAddr,
@@ -65,6 +67,11 @@ impl JormungandrProcess {
         Ok(JormungandrProcess {
             child,
             temp_dir,
+            crash_dir: params
+                .node_config_path()
+                .parent()
+                .expect("node configuration path has a parent")
+                .to_path_buf(),
             alias,
             logger: JormungandrLogger::new(stdout),
             p2p_public_address: node_config.p2p_public_address(),
@@ -101,7 +108,7 @@ impl JormungandrProcess {
     }
 
     pub fn wait_for_bootstrap(
-        &self,
+        &mut self,
         verification_mode: &StartupVerificationMode,
         timeout: Duration,
     ) -> Result<(), StartupError> {
@@ -113,6 +120,9 @@ impl JormungandrProcess {
                     log_content: self.logger.get_log_content(),
                 });
             }
+            if let Some(report) = self.crash_report() {
+                return Err(StartupError::Crashed(report));
+            }
             match self.status(verification_mode) {
                 Status::Running => {
                     println!("jormungandr is up");
@@ -255,6 +265,19 @@ impl JormungandrProcess {
         &self.block0_configuration
     }
 
+    /// The report of the crash of the node, `None` while it is running or if
+    /// it exited successfully.
+    pub fn crash_report(&mut self) -> Option<CrashReport> {
+        match self.child.try_wait() {
+            Ok(Some(status)) if !status.success() => Some(CrashReport::collect(
+                self.alias.clone(),
+                status,
+                &self.crash_dir,
+            )),
+            _ => None,
+        }
+    }
+
     pub fn pid(&self) -> u32 {
         self.child.id()
     }
@@ -305,6 +328,7 @@ impl JormungandrProcess {
 
 impl Drop for JormungandrProcess {
     fn drop(&mut self) {
+        let mut crash_report = self.crash_report();
         // There's no kill like overkill
         let _ = self.child.kill();
         // FIXME: These should be better done in a test harness
@@ -328,13 +352,24 @@ impl Drop for JormungandrProcess {
                     content_only: true,
                     ..Default::default()
                 };
-                move_dir(dir.path(), &logs_dir, &options)
-                    .map(|_| ())
-                    .unwrap_or_else(|e| eprintln!("Could not move files to new dir: {}", e));
+                match move_dir(dir.path(), &logs_dir, &options) {
+                    Ok(_) => {
+                        if let Some(report) = crash_report.as_mut() {
+                            report.relocate(dir.path(), &logs_dir);
+                        }
+                    }
+                    Err(e) => eprintln!("Could not move files to new dir: {}", e),
+                }
             }
 
             std::fs::write(logs_dir.join("node.log"), self.log_content())
                 .unwrap_or_else(|e| eprint!("Could not write node logs to disk: {}", e));
+
+            if let Some(report) = crash_report {
+                println!("{}", report);
+                std::fs::write(logs_dir.join("crash_report.txt"), report.to_string())
+                    .unwrap_or_else(|e| eprint!("Could not write crash report to disk: {}", e));
+            }
         }
     }
 }
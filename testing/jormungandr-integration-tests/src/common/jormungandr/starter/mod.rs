@@ -4,6 +4,7 @@ pub use commands::{get_command, CommandBuilder};
 use super::process::StartupVerificationMode;
 use super::ConfigurationBuilder;
 use super::JormungandrError;
+use super::{crash_report::capture_crash_output, CrashReport};
 use crate::common::{configuration::get_jormungandr_app, jormungandr::process::JormungandrProcess};
 use assert_cmd::assert::OutputAssertExt;
 use assert_fs::{fixture::FixtureError, TempDir};
@@ -42,6 +43,8 @@ pub enum StartupError {
     JormungandrError(#[from] JormungandrError),
     #[error("expected message not found: {entry} in logs: {log_content}")]
     EntryNotFoundInLogs { entry: String, log_content: String },
+    #[error("{0}")]
+    Crashed(CrashReport),
     #[error("too many failures while attempting to start jormungandr")]
     TooManyAttempts,
     #[error("Block0 hash is not valid")]
@@ -102,6 +105,7 @@ pub struct Starter {
     config: Option<JormungandrParams>,
     benchmark: Option<SpeedBenchmarkDef>,
     verbose: bool,
+    core_dumps: bool,
 }
 
 impl Default for Starter {
@@ -126,6 +130,7 @@ impl Starter {
             benchmark: None,
             jormungandr_app_path: None,
             verbose: true,
+            core_dumps: false,
         }
     }
 
@@ -202,6 +207,14 @@ impl Starter {
         self
     }
 
+    /// Keep the core dump of the node in its directory if it crashes. Where
+    /// the dump is written in the end depends on the core pattern of the
+    /// kernel, the node directory is only used with a relative pattern.
+    pub fn core_dumps(&mut self, core_dumps: bool) -> &mut Self {
+        self.core_dumps = core_dumps;
+        self
+    }
+
     fn build_configuration(
         &mut self,
     ) -> Result<(JormungandrParams, Option<TempDir>), StartupError> {
@@ -389,6 +402,14 @@ where
             self.starter.from_genesis,
         );
 
+        let crash_dir = self
+            .params
+            .node_config_path()
+            .parent()
+            .expect("node configuration path has a parent");
+        capture_crash_output(&mut command, crash_dir, self.starter.core_dumps)
+            .expect("cannot capture the crash output of the node");
+
         cond_println!(verbose, "Running start command: {:?}", command);
         cond_println!(verbose, "Bootstrapping...");
         command
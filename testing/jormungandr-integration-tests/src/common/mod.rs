@@ -3,6 +3,7 @@
 extern crate serde_derive;
 
 pub mod configuration;
+pub mod fixtures;
 pub mod jcli;
 pub mod jormungandr;
 pub mod network;
@@ -150,7 +150,10 @@ impl Controller {
 
         let log_file_path = dir.child("node.log").path().to_path_buf();
         config.log = Some(Log(LogEntry {
-            format: "json".into(),
+            format: spawn_params
+                .log_format
+                .clone()
+                .unwrap_or_else(|| String::from("json")),
             level: spawn_params
                 .log_level
                 .map(|l| l.to_string())
@@ -0,0 +1,161 @@
+use crate::common::{jcli::JCli, startup::create_new_key_pair};
+use assert_fs::{fixture::ChildPath, prelude::*, TempDir};
+use chain_crypto::{Ed25519, RistrettoGroup2HashDh, SumEd25519_12};
+use jormungandr_lib::crypto::key::KeyPair;
+
+const IDENTITY: &str = "https://pool.example.com";
+
+fn registration(jcli: &JCli, temp_dir: &TempDir, owner: &KeyPair<Ed25519>) -> ChildPath {
+    let kes = create_new_key_pair::<SumEd25519_12>();
+    let vrf = create_new_key_pair::<RistrettoGroup2HashDh>();
+    let certificate = jcli.certificate().new_stake_pool_registration(
+        &kes.identifier().to_bech32_str(),
+        &vrf.identifier().to_bech32_str(),
+        0,
+        1,
+        &owner.identifier().to_bech32_str(),
+        None,
+    );
+    let file = temp_dir.child(format!("{}.cert", owner.identifier().to_hex()));
+    file.write_str(&certificate).unwrap();
+    file
+}
+
+fn secret_key(temp_dir: &TempDir, owner: &KeyPair<Ed25519>) -> ChildPath {
+    let file = temp_dir.child(format!("{}.sk", owner.identifier().to_hex()));
+    file.write_str(&owner.signing_key().to_bech32_str())
+        .unwrap();
+    file
+}
+
+fn ownership_proof(
+    jcli: &JCli,
+    temp_dir: &TempDir,
+    owner: &KeyPair<Ed25519>,
+    certificate: &ChildPath,
+) -> ChildPath {
+    let proof = jcli.certificate().ownership_proof(
+        IDENTITY,
+        &[secret_key(temp_dir, owner).path()],
+        certificate.path(),
+    );
+    let file = temp_dir.child(format!("{}.proof", owner.identifier().to_hex()));
+    file.write_str(&proof).unwrap();
+    file
+}
+
+#[test]
+pub fn jcli_verifies_ownership_proof_of_pool_owner() {
+    let jcli: JCli = Default::default();
+    let temp_dir = TempDir::new().unwrap();
+    let owner = create_new_key_pair::<Ed25519>();
+
+    let certificate = registration(&jcli, &temp_dir, &owner);
+    let proof = ownership_proof(&jcli, &temp_dir, &owner, &certificate);
+    jcli.certificate()
+        .verify_ownership_proof(proof.path(), certificate.path(), IDENTITY);
+}
+
+#[test]
+pub fn jcli_rejects_ownership_proof_for_another_identity() {
+    let jcli: JCli = Default::default();
+    let temp_dir = TempDir::new().unwrap();
+    let owner = create_new_key_pair::<Ed25519>();
+
+    let certificate = registration(&jcli, &temp_dir, &owner);
+    let proof = ownership_proof(&jcli, &temp_dir, &owner, &certificate);
+    jcli.certificate().verify_ownership_proof_expect_fail(
+        proof.path(),
+        certificate.path(),
+        "https://other.example.com",
+        "ownership proof is bound to the identity",
+    );
+}
+
+#[test]
+pub fn jcli_rejects_ownership_proof_of_another_pool() {
+    let jcli: JCli = Default::default();
+    let temp_dir = TempDir::new().unwrap();
+    let owner = create_new_key_pair::<Ed25519>();
+    let other_owner = create_new_key_pair::<Ed25519>();
+
+    let other_certificate = registration(&jcli, &temp_dir, &other_owner);
+    let proof = ownership_proof(&jcli, &temp_dir, &other_owner, &other_certificate);
+    jcli.certificate().verify_ownership_proof_expect_fail(
+        proof.path(),
+        registration(&jcli, &temp_dir, &owner).path(),
+        IDENTITY,
+        "ownership proof is for the stake pool",
+    );
+}
+
+#[test]
+pub fn jcli_rejects_ownership_proof_signed_by_non_owner() {
+    let jcli: JCli = Default::default();
+    let temp_dir = TempDir::new().unwrap();
+    let owner = create_new_key_pair::<Ed25519>();
+    let impostor = create_new_key_pair::<Ed25519>();
+
+    let certificate = registration(&jcli, &temp_dir, &owner);
+    let pool_id = jcli.certificate().stake_pool_id(certificate.path());
+    let message = format!(
+        "jormungandr stake pool ownership proof\npool id: {}\nidentity: {}",
+        pool_id, IDENTITY
+    );
+    let proof = serde_json::json!({
+        "pool_id": pool_id,
+        "identity": IDENTITY,
+        "message": message,
+        "signatures": [{
+            "public_key": impostor.identifier().to_bech32_str(),
+            "signature": impostor.signing_key().sign(&message).to_bech32_str(),
+        }],
+    });
+    let proof_file = temp_dir.child("forged.proof");
+    proof_file.write_str(&proof.to_string()).unwrap();
+
+    jcli.certificate().verify_ownership_proof_expect_fail(
+        proof_file.path(),
+        certificate.path(),
+        IDENTITY,
+        "is not from an owner of the stake pool",
+    );
+}
+
+#[test]
+pub fn jcli_does_not_sign_ownership_proof_with_non_owner_key() {
+    let jcli: JCli = Default::default();
+    let temp_dir = TempDir::new().unwrap();
+    let owner = create_new_key_pair::<Ed25519>();
+    let impostor = create_new_key_pair::<Ed25519>();
+
+    let certificate = registration(&jcli, &temp_dir, &owner);
+    jcli.certificate().ownership_proof_expect_fail(
+        IDENTITY,
+        &[secret_key(&temp_dir, &impostor).path()],
+        certificate.path(),
+        "secret key number 0 matching the expected public key has not been found",
+    );
+}
+
+#[test]
+pub fn jcli_rejects_ownership_proof_with_altered_message() {
+    let jcli: JCli = Default::default();
+    let temp_dir = TempDir::new().unwrap();
+    let owner = create_new_key_pair::<Ed25519>();
+
+    let certificate = registration(&jcli, &temp_dir, &owner);
+    let proof = ownership_proof(&jcli, &temp_dir, &owner, &certificate);
+    let mut altered: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(proof.path()).unwrap()).unwrap();
+    altered["message"] = serde_json::Value::from("jormungandr stake pool ownership proof");
+    let altered_file = temp_dir.child("altered.proof");
+    altered_file.write_str(&altered.to_string()).unwrap();
+
+    jcli.certificate().verify_ownership_proof_expect_fail(
+        altered_file.path(),
+        certificate.path(),
+        IDENTITY,
+        "the message does not match the stake pool id and identity",
+    );
+}
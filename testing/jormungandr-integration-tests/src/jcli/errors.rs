@@ -0,0 +1,63 @@
+use crate::common::jcli::JCli;
+
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::prelude::*;
+use assert_fs::TempDir;
+use std::process::Command;
+
+const GENESIS_EXIT_CODE: i32 = 12;
+
+fn genesis_hash(jcli: &JCli, error_format: Option<&str>, input: &std::path::Path) -> Command {
+    let mut command = Command::new(jcli.path());
+    if let Some(error_format) = error_format {
+        command.arg("--error-format").arg(error_format);
+    }
+    command.arg("genesis").arg("hash").arg("--input").arg(input);
+    command
+}
+
+#[test]
+pub fn test_errors_are_reported_as_json_on_request() {
+    let jcli: JCli = Default::default();
+    let temp_dir = TempDir::new().unwrap();
+    let block_file = temp_dir.child("block-0.bin");
+
+    let assert = genesis_hash(&jcli, Some("json"), block_file.path())
+        .assert()
+        .failure()
+        .code(GENESIS_EXIT_CODE);
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr);
+    let error: serde_json::Value = serde_json::from_str(stderr.trim()).unwrap();
+    assert_eq!(error["command"], "genesis");
+    assert_eq!(error["code"], "genesis.InputInvalid");
+    assert!(error["message"]
+        .as_str()
+        .unwrap()
+        .contains("invalid input file path"));
+    assert!(!error["causes"].as_array().unwrap().is_empty());
+}
+
+#[test]
+pub fn test_exit_code_identifies_the_command_with_text_errors() {
+    let jcli: JCli = Default::default();
+    let temp_dir = TempDir::new().unwrap();
+    let block_file = temp_dir.child("block-0.bin");
+
+    genesis_hash(&jcli, None, block_file.path())
+        .assert()
+        .failure()
+        .code(GENESIS_EXIT_CODE)
+        .stderr(predicates::str::contains("invalid input file path"));
+}
+
+#[test]
+pub fn test_unknown_error_format_is_rejected() {
+    let jcli: JCli = Default::default();
+    let temp_dir = TempDir::new().unwrap();
+    let block_file = temp_dir.child("block-0.bin");
+
+    genesis_hash(&jcli, Some("xml"), block_file.path())
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("unknown error format 'xml'"));
+}
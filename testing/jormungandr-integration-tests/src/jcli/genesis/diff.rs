@@ -0,0 +1,66 @@
+use crate::common::jcli::JCli;
+
+use assert_fs::prelude::*;
+use assert_fs::TempDir;
+
+#[test]
+pub fn test_genesis_file_and_its_block_have_no_differences() {
+    let jcli: JCli = Default::default();
+    let content = jcli.genesis().init();
+    let temp_dir = TempDir::new().unwrap();
+    let yaml_file = temp_dir.child("init_file.yaml");
+    yaml_file.write_str(&content).unwrap();
+    let block_file = temp_dir.child("block-0.bin");
+    jcli.genesis().encode(yaml_file.path(), &block_file);
+
+    let diff: serde_yaml::Value =
+        serde_yaml::from_str(&jcli.genesis().diff(yaml_file.path(), block_file.path())).unwrap();
+    assert_eq!(diff, serde_yaml::Value::Mapping(Default::default()));
+}
+
+#[test]
+pub fn test_changed_parameters_are_reported() {
+    let jcli: JCli = Default::default();
+    let content = jcli.genesis().init();
+    let temp_dir = TempDir::new().unwrap();
+    let yaml_file = temp_dir.child("init_file.yaml");
+    yaml_file.write_str(&content).unwrap();
+
+    let mut genesis: serde_yaml::Value = serde_yaml::from_str(&content).unwrap();
+    genesis["blockchain_configuration"]["slots_per_epoch"] = 42.into();
+    let changed_yaml_file = temp_dir.child("changed_file.yaml");
+    changed_yaml_file
+        .write_str(&serde_yaml::to_string(&genesis).unwrap())
+        .unwrap();
+    let changed_block_file = temp_dir.child("block-0.bin");
+    jcli.genesis()
+        .encode(changed_yaml_file.path(), &changed_block_file);
+
+    let diff: serde_yaml::Value = serde_yaml::from_str(
+        &jcli
+            .genesis()
+            .diff(yaml_file.path(), changed_block_file.path()),
+    )
+    .unwrap();
+    let change = &diff["parameters"]["slots_per_epoch"];
+    assert_eq!(change["after"], 42.into());
+    assert_ne!(change["before"], change["after"]);
+    assert!(diff.get("funds").is_none());
+}
+
+#[test]
+pub fn test_correct_error_is_returned_for_unrecognized_input() {
+    let jcli: JCli = Default::default();
+    let content = jcli.genesis().init();
+    let temp_dir = TempDir::new().unwrap();
+    let yaml_file = temp_dir.child("init_file.yaml");
+    yaml_file.write_str(&content).unwrap();
+    let invalid_file = temp_dir.child("invalid.bin");
+    invalid_file.write_binary(&[0xff; 32]).unwrap();
+
+    jcli.genesis().diff_expect_fail(
+        yaml_file.path(),
+        invalid_file.path(),
+        "is neither a genesis file nor a block 0",
+    );
+}
@@ -1,3 +1,4 @@
+pub mod diff;
 pub mod encode;
 pub mod hash;
 pub mod init;
@@ -1,5 +1,6 @@
 pub mod address;
 pub mod certificate;
+pub mod errors;
 pub mod genesis;
 pub mod key;
 pub mod rest;
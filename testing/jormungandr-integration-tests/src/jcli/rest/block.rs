@@ -9,6 +9,26 @@ pub fn test_non_empty_hash_is_returned_for_block0() {
     jcli.rest().v0().block().get(block_id, rest_uri);
 }
 
+#[test]
+pub fn test_block_is_decoded_on_request() {
+    let jcli: JCli = Default::default();
+    let jormungandr = Starter::new().start().unwrap();
+    let rest_uri = jormungandr.rest_uri();
+    let block_id = jcli.rest().v0().tip(&rest_uri);
+
+    let encoded = jcli
+        .rest()
+        .v0()
+        .block()
+        .get(block_id.clone(), rest_uri.clone());
+    assert!(hex::decode(encoded.trim()).is_ok());
+
+    let decoded = jcli.rest().v0().block().get_decoded(block_id, rest_uri);
+    assert!(hex::decode(decoded.trim()).is_err());
+    assert!(decoded.contains("header"));
+    assert!(decoded.contains("contents"));
+}
+
 #[test]
 pub fn test_correct_error_is_returned_for_incorrect_block_id() {
     let jcli: JCli = Default::default();
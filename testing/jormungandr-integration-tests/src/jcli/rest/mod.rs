@@ -1,4 +1,7 @@
 pub mod block;
 pub mod host;
+pub mod stake_pool;
+pub mod support_bundle;
 pub mod tip;
 pub mod utxo;
+pub mod vote_plans;
@@ -0,0 +1,32 @@
+use crate::common::{
+    fixtures::{World, WorldBuilder},
+    jcli::JCli,
+};
+use rstest::*;
+
+#[fixture]
+fn world() -> World {
+    WorldBuilder::new().build()
+}
+
+#[rstest]
+pub fn dashboard_of_a_pool_is_reported(world: World) {
+    let jcli: JCli = Default::default();
+    let pool_id = world.stake_pools[0].id().to_string();
+
+    let dashboard = jcli
+        .rest()
+        .v1()
+        .stake_pool_dashboard(&pool_id, world.jormungandr.rest_uri());
+    assert_eq!(dashboard["pool_id"].as_str(), Some(pool_id.as_str()));
+    assert!(dashboard["total_stake"].as_u64().unwrap() > 0);
+    assert_eq!(dashboard["pending_retirement"].as_bool(), Some(false));
+    assert!(dashboard["tax"].is_mapping());
+    assert!(dashboard["last_rewards"].is_mapping());
+
+    jcli.rest().v1().stake_pool_dashboard_expect_fail(
+        hex::encode([0; 32]),
+        world.jormungandr.rest_uri(),
+        "404",
+    );
+}
@@ -0,0 +1,66 @@
+use crate::common::{configuration::NodeConfigBuilder, jcli::JCli, jormungandr::starter::Starter};
+use assert_fs::{prelude::*, TempDir};
+use flate2::read::GzDecoder;
+use std::{fs::File, io::Read, path::Path};
+
+const BUNDLE_FILES: &[&str] = &[
+    "node-stats.json",
+    "settings.json",
+    "network-stats.json",
+    "leadership-logs.json",
+    "fragment-logs.json",
+    "diagnostic.json",
+];
+
+fn bundle_files(path: &Path) -> Vec<(String, String)> {
+    let mut archive = tar::Archive::new(GzDecoder::new(File::open(path).unwrap()));
+    archive
+        .entries()
+        .unwrap()
+        .map(|entry| {
+            let mut entry = entry.unwrap();
+            let name = entry.path().unwrap().display().to_string();
+            let mut content = String::new();
+            entry.read_to_string(&mut content).unwrap();
+            (name, content)
+        })
+        .collect()
+}
+
+#[test]
+pub fn support_bundle_holds_the_state_of_the_node() {
+    let jcli: JCli = Default::default();
+    let jormungandr = Starter::new().start().unwrap();
+    let temp_dir = TempDir::new().unwrap();
+    let bundle = temp_dir.child("bundle.tar.gz");
+
+    jcli.rest()
+        .v0()
+        .support_bundle(jormungandr.rest_uri(), bundle.path());
+
+    let files = bundle_files(bundle.path());
+    let names: Vec<&str> = files.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(names, BUNDLE_FILES);
+    for (name, content) in &files {
+        serde_json::from_str::<serde_json::Value>(content)
+            .unwrap_or_else(|_| panic!("{} is not valid JSON", name));
+    }
+}
+
+#[test]
+pub fn support_bundle_records_the_unreachable_endpoints() {
+    let jcli: JCli = Default::default();
+    let config = NodeConfigBuilder::new().build();
+    let uri = format!("http://{}/api", config.rest.listen);
+    let temp_dir = TempDir::new().unwrap();
+    let bundle = temp_dir.child("bundle.tar.gz");
+
+    jcli.rest().v0().support_bundle(uri, bundle.path());
+
+    let files = bundle_files(bundle.path());
+    assert_eq!(files.len(), BUNDLE_FILES.len());
+    for ((name, content), expected) in files.iter().zip(BUNDLE_FILES) {
+        assert_eq!(name, &format!("{}.error", expected));
+        assert!(!content.is_empty());
+    }
+}
@@ -0,0 +1,57 @@
+use crate::common::{
+    jcli::JCli,
+    jormungandr::{ConfigurationBuilder, Starter},
+};
+use assert_fs::TempDir;
+use chain_core::property::BlockDate as _;
+use chain_impl_mockchain::{block::BlockDate, certificate::VoteAction, chaintypes::ConsensusType};
+use jormungandr_testing_utils::{
+    testing::{node::time, vote_plan_cert, VotePlanBuilder},
+    wallet::Wallet,
+};
+
+#[test]
+pub fn summary_of_the_active_vote_plans_is_reported() {
+    let jcli: JCli = Default::default();
+    let temp_dir = TempDir::new().unwrap();
+    let alice = Wallet::new_account(&mut rand::thread_rng());
+
+    let vote_plan = VotePlanBuilder::new()
+        .proposals_count(3)
+        .action_type(VoteAction::OffChain)
+        .with_vote_start(BlockDate::from_epoch_slot_id(0, 0))
+        .with_tally_start(BlockDate::from_epoch_slot_id(1, 0))
+        .with_tally_end(BlockDate::from_epoch_slot_id(2, 0))
+        .public()
+        .build();
+    let vote_plan_cert = vote_plan_cert(&alice, BlockDate::first().next_epoch(), &vote_plan).into();
+
+    let config = ConfigurationBuilder::new()
+        .with_funds(vec![alice.to_initial_fund(1_000_000)])
+        .with_block0_consensus(ConsensusType::Bft)
+        .with_committees(&[&alice])
+        .with_certs(vec![vote_plan_cert])
+        .with_slots_per_epoch(60)
+        .with_slot_duration(1)
+        .build(&temp_dir);
+    let jormungandr = Starter::new()
+        .temp_dir(temp_dir)
+        .config(config)
+        .start()
+        .unwrap();
+    // the phase is computed at the date of the last block
+    time::wait_for_date(
+        BlockDate::from_epoch_slot_id(0, 2).into(),
+        jormungandr.rest(),
+    );
+
+    let summary = jcli.rest().v1().vote_plans_summary(jormungandr.rest_uri());
+    let plans = summary.as_array().unwrap();
+    assert_eq!(plans.len(), 1);
+    let plan = &plans[0];
+    assert_eq!(plan["id"], vote_plan.to_id().to_string());
+    assert_eq!(plan["phase"], "vote");
+    assert_eq!(plan["payload"], "public");
+    assert_eq!(plan["proposals"], 3);
+    assert_eq!(plan["votes_cast"], 0);
+}
@@ -0,0 +1,49 @@
+use crate::common::{jcli::JCli, startup};
+use assert_fs::{prelude::*, TempDir};
+use jormungandr_lib::{crypto::hash::Hash, interfaces::BlockDate};
+
+lazy_static! {
+    static ref FAKE_INPUT_TRANSACTION_ID: Hash = {
+        "19c9852ca0a68f15d0f7de5d1a26acd67a3a3251640c6066bdb91d22e2000193"
+            .parse()
+            .unwrap()
+    };
+    static ref FAKE_GENESIS_HASH: Hash = {
+        "19c9852ca0a68f15d0f7de5d1a26acd67a3a3251640c6066bdb91d22e2000193"
+            .parse()
+            .unwrap()
+    };
+}
+
+fn assert_sealed_transaction_round_trip(format: &str, file_name: &str) {
+    let temp_dir = TempDir::new().unwrap();
+    let export = temp_dir.child(file_name);
+    let sender = startup::create_new_utxo_address();
+    let receiver = startup::create_new_utxo_address();
+    let jcli: JCli = Default::default();
+
+    let mut transaction_builder = jcli.transaction_builder(*FAKE_GENESIS_HASH);
+    transaction_builder
+        .new_transaction()
+        .add_input(&FAKE_INPUT_TRANSACTION_ID, 0, "100")
+        .add_output(&receiver.address().to_string(), 100.into())
+        .set_expiry_date(BlockDate::new(1, 0))
+        .finalize()
+        .seal_with_witness_for_address(&sender)
+        .export(format, export.path());
+
+    let mut imported = jcli.transaction_builder(*FAKE_GENESIS_HASH);
+    imported.import(format, export.path());
+
+    assert_eq!(transaction_builder.to_message(), imported.to_message());
+}
+
+#[test]
+pub fn test_sealed_transaction_survives_cbor_export() {
+    assert_sealed_transaction_round_trip("cbor", "transaction.cbor");
+}
+
+#[test]
+pub fn test_sealed_transaction_survives_qr_code_export() {
+    assert_sealed_transaction_round_trip("qr", "transaction.png");
+}
@@ -1,4 +1,6 @@
 pub mod e2e;
+pub mod exchange;
 pub mod finalize;
 pub mod input;
 pub mod simplified;
+pub mod validity;
@@ -0,0 +1,75 @@
+use crate::common::{jcli::JCli, jormungandr::starter::Starter};
+use assert_fs::{prelude::*, TempDir};
+use jormungandr_lib::time::SecondsSinceUnixEpoch;
+
+fn validity(args: &[&str]) -> Vec<String> {
+    args.iter().map(|arg| arg.to_string()).collect()
+}
+
+fn offline_era(block0_time: u64) -> Vec<String> {
+    validity(&[
+        "--block0-time",
+        &block0_time.to_string(),
+        "--slot-duration",
+        "1",
+        "--slots-per-epoch",
+        "60",
+    ])
+}
+
+#[test]
+pub fn expiry_date_is_computed_from_offline_era_parameters() {
+    let jcli: JCli = Default::default();
+    let temp_dir = TempDir::new().unwrap();
+    let staging = temp_dir.child("staging");
+    jcli.transaction().new_transaction(staging.path());
+
+    let started = SecondsSinceUnixEpoch::now().to_secs() - 100;
+    let mut args = validity(&["--valid-for-slots", "10"]);
+    args.extend(offline_era(started));
+    jcli.transaction()
+        .set_expiry_date_valid_for(&args, staging.path());
+
+    let not_started = SecondsSinceUnixEpoch::now().to_secs() + 100;
+    let mut args = validity(&["--valid-for-epochs", "1"]);
+    args.extend(offline_era(not_started));
+    jcli.transaction().set_expiry_date_valid_for_expect_fail(
+        &args,
+        staging.path(),
+        "the blockchain has not started yet",
+    );
+}
+
+#[test]
+pub fn expiry_date_needs_the_era_parameters() {
+    let jcli: JCli = Default::default();
+    let temp_dir = TempDir::new().unwrap();
+    let staging = temp_dir.child("staging");
+    jcli.transaction().new_transaction(staging.path());
+
+    jcli.transaction().set_expiry_date_valid_for_expect_fail(
+        &validity(&["--valid-for-slots", "10"]),
+        staging.path(),
+        "the era parameters are needed to compute the expiry date",
+    );
+}
+
+#[test]
+pub fn expiry_date_is_bounded_by_the_settings_of_the_node() {
+    let jcli: JCli = Default::default();
+    let jormungandr = Starter::new().start().unwrap();
+    let temp_dir = TempDir::new().unwrap();
+    let staging = temp_dir.child("staging");
+    jcli.transaction().new_transaction(staging.path());
+
+    let host = jormungandr.rest_uri();
+    jcli.transaction().set_expiry_date_valid_for(
+        &validity(&["--valid-for-slots", "1", "--settings-host", &host]),
+        staging.path(),
+    );
+    jcli.transaction().set_expiry_date_valid_for_expect_fail(
+        &validity(&["--valid-for-epochs", "1000", "--settings-host", &host]),
+        staging.path(),
+        "the transaction cannot be valid for more than",
+    );
+}
@@ -34,6 +34,10 @@ pub fn test_mempool_pool_max_entries_limit() {
         .with_mempool(Mempool {
             pool_max_entries: 1.into(),
             log_max_entries: 100.into(),
+            rebroadcast_interval: None,
+            snapshot: None,
+            validity_window: None,
+            log_gc: None,
             persistent_log: None,
         })
         .build(&temp_dir);
@@ -107,6 +111,10 @@ pub fn test_mempool_pool_max_entries_equal_0() {
         .with_mempool(Mempool {
             pool_max_entries: 0.into(),
             log_max_entries: 100.into(),
+            rebroadcast_interval: None,
+            snapshot: None,
+            validity_window: None,
+            log_gc: None,
             persistent_log: None,
         })
         .build(&temp_dir);
@@ -164,6 +172,10 @@ pub fn test_mempool_log_max_entries_only_one_fragment() {
         .with_mempool(Mempool {
             pool_max_entries: 1.into(),
             log_max_entries: 1.into(),
+            rebroadcast_interval: None,
+            snapshot: None,
+            validity_window: None,
+            log_gc: None,
             persistent_log: None,
         })
         .build(&temp_dir);
@@ -237,6 +249,10 @@ pub fn test_mempool_log_max_entries_equals_0() {
         .with_mempool(Mempool {
             pool_max_entries: 0.into(),
             log_max_entries: 0.into(),
+            rebroadcast_interval: None,
+            snapshot: None,
+            validity_window: None,
+            log_gc: None,
             persistent_log: None,
         })
         .build(&temp_dir);
@@ -293,6 +309,10 @@ pub fn test_mempool_pool_max_entries_overrides_log_max_entries() {
         .with_mempool(Mempool {
             pool_max_entries: 2.into(),
             log_max_entries: 0.into(),
+            rebroadcast_interval: None,
+            snapshot: None,
+            validity_window: None,
+            log_gc: None,
             persistent_log: None,
         })
         .build(&temp_dir);
@@ -36,6 +36,10 @@ pub fn test_mempool_pool_max_entries_limit() {
         .with_mempool(Mempool {
             pool_max_entries: 1.into(),
             log_max_entries: 100.into(),
+            rebroadcast_interval: None,
+            snapshot: None,
+            validity_window: None,
+            log_gc: None,
             persistent_log: None,
         })
         .build(&temp_dir);
@@ -108,6 +112,12 @@ pub fn test_mempool_pool_max_entries_limit() {
     verifier
         .value_moved_between_wallets(&sender, &receiver, 1.into())
         .unwrap();
+
+    let stats = jormungandr.rest().stats().unwrap().stats.unwrap();
+    assert_eq!(stats.pool_entry_max_cnt, 1);
+    assert_eq!(stats.pool_full_evicted_cnt, 1);
+    assert_eq!(stats.pool_entry_cnt, 0);
+    assert_eq!(stats.pool_size_bytes, 0);
 }
 
 #[test]
@@ -132,6 +142,10 @@ pub fn test_mempool_pool_max_entries_equal_0() {
         .with_mempool(Mempool {
             pool_max_entries: 0.into(),
             log_max_entries: 100.into(),
+            rebroadcast_interval: None,
+            snapshot: None,
+            validity_window: None,
+            log_gc: None,
             persistent_log: None,
         })
         .build(&temp_dir);
@@ -216,6 +230,10 @@ pub fn test_mempool_log_max_entries_only_one_fragment() {
         .with_mempool(Mempool {
             pool_max_entries: 1.into(),
             log_max_entries: 1.into(),
+            rebroadcast_interval: None,
+            snapshot: None,
+            validity_window: None,
+            log_gc: None,
             persistent_log: None,
         })
         .build(&temp_dir);
@@ -312,6 +330,10 @@ pub fn test_mempool_log_max_entries_equals_0() {
         .with_mempool(Mempool {
             pool_max_entries: 0.into(),
             log_max_entries: 0.into(),
+            rebroadcast_interval: None,
+            snapshot: None,
+            validity_window: None,
+            log_gc: None,
             persistent_log: None,
         })
         .build(&temp_dir);
@@ -397,6 +419,10 @@ pub fn test_mempool_pool_max_entries_overrides_log_max_entries() {
         .with_mempool(Mempool {
             pool_max_entries: 2.into(),
             log_max_entries: 0.into(),
+            rebroadcast_interval: None,
+            snapshot: None,
+            validity_window: None,
+            log_gc: None,
             persistent_log: None,
         })
         .build(&temp_dir);
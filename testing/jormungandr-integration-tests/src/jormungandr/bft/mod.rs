@@ -1,2 +1,3 @@
 pub mod mempool;
+pub mod rotation;
 pub mod start_node;
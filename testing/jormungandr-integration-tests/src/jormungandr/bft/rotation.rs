@@ -0,0 +1,95 @@
+#![cfg(feature = "update-proposals")]
+
+use crate::common::{
+    jormungandr::{ConfigurationBuilder, JormungandrProcess, Starter},
+    startup,
+};
+use assert_fs::TempDir;
+use chain_core::property::Fragment as _;
+use chain_crypto::Ed25519;
+use chain_impl_mockchain::{
+    block::BlockDate,
+    config::{ConfigParam, ConfigParams},
+};
+use jormungandr_lib::interfaces::{BlockDate as BlockDateDto, ConsensusLeaderId, EnclaveLeaderId};
+use jormungandr_testing_utils::testing::{FragmentBuilder, FragmentNode, FragmentVerifier};
+use std::{collections::HashSet, time::Duration};
+
+#[test]
+pub fn bft_leader_replaced_through_update_proposal() {
+    let temp_dir = TempDir::new().unwrap();
+    let leader = startup::create_new_key_pair::<Ed25519>();
+    let standby_leader = startup::create_new_key_pair::<Ed25519>();
+
+    let config = ConfigurationBuilder::new()
+        .with_slots_per_epoch(20)
+        .with_leader_key_pair(leader.clone())
+        .with_standby_leaders(vec![standby_leader.clone()])
+        .build(&temp_dir);
+    let jormungandr = Starter::new()
+        .temp_dir(temp_dir)
+        .config(config)
+        .start()
+        .unwrap();
+
+    let leader_id: ConsensusLeaderId = leader.identifier().into();
+    let standby_leader_id: ConsensusLeaderId = standby_leader.identifier().into();
+    let mut changes = ConfigParams::new();
+    changes.push(ConfigParam::AddBftLeader(standby_leader_id.0));
+    changes.push(ConfigParam::RemoveBftLeader(leader_id.0.clone()));
+
+    let builder = FragmentBuilder::new(
+        &jormungandr.genesis_block_hash(),
+        &jormungandr.fees(),
+        BlockDate::first().next_epoch(),
+    );
+    let proposal = builder.update_proposal(&leader_id, changes);
+    let proposal_id = proposal.id();
+    let check = jormungandr.send_fragment(proposal).unwrap();
+    FragmentVerifier::wait_and_verify_is_in_block(Duration::from_secs(2), check, &jormungandr)
+        .unwrap();
+
+    let check = jormungandr
+        .send_fragment(builder.update_vote(proposal_id, &leader_id))
+        .unwrap();
+    FragmentVerifier::wait_and_verify_is_in_block(Duration::from_secs(2), check, &jormungandr)
+        .unwrap();
+
+    let rotation_epoch = last_block_epoch(&jormungandr) + 1;
+    while last_block_epoch(&jormungandr) <= rotation_epoch {
+        std::thread::sleep(Duration::from_secs(1));
+    }
+
+    let leaders_before = scheduled_leaders(&jormungandr, 0);
+    let leaders_after = scheduled_leaders(&jormungandr, rotation_epoch);
+    assert_eq!(leaders_before.len(), 1);
+    assert_eq!(leaders_after.len(), 1);
+    assert_ne!(
+        leaders_before, leaders_after,
+        "the standby leader should replace the leader after the rotation"
+    );
+}
+
+fn last_block_epoch(jormungandr: &JormungandrProcess) -> u32 {
+    let last_block_date: BlockDateDto = jormungandr
+        .rest()
+        .stats()
+        .unwrap()
+        .stats
+        .and_then(|stats| stats.last_block_date)
+        .unwrap()
+        .parse()
+        .unwrap();
+    last_block_date.epoch()
+}
+
+fn scheduled_leaders(jormungandr: &JormungandrProcess, epoch: u32) -> HashSet<EnclaveLeaderId> {
+    jormungandr
+        .rest()
+        .leaders_log()
+        .unwrap()
+        .iter()
+        .filter(|log| log.scheduled_at_date().epoch() == epoch)
+        .map(|log| *log.enclave_leader_id())
+        .collect()
+}
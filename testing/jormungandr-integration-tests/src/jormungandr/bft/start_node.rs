@@ -1,8 +1,11 @@
-use crate::common::jormungandr::{ConfigurationBuilder, Starter};
+use crate::common::jormungandr::{
+    ConfigurationBuilder, Starter, StartupError, StartupVerificationMode,
+};
 use jormungandr_lib::interfaces::{Log, LogEntry, LogOutput};
 
 use assert_fs::prelude::*;
 use assert_fs::TempDir;
+use std::time::Duration;
 
 #[test]
 pub fn test_jormungandr_leader_node_starts_successfully() {
@@ -53,6 +56,30 @@ pub fn test_jormungandr_passive_node_without_trusted_peers_fails_to_start() {
         .start_fail("no trusted peers specified")
 }
 
+#[test]
+pub fn test_jormungandr_exiting_at_startup_is_reported_as_crashed() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let config = ConfigurationBuilder::new()
+        .with_trusted_peers(vec![])
+        .build(&temp_dir);
+
+    let mut jormungandr = Starter::new()
+        .temp_dir(temp_dir)
+        .config(config)
+        .passive()
+        .start_async()
+        .unwrap();
+
+    match jormungandr.wait_for_bootstrap(&StartupVerificationMode::Log, Duration::from_secs(30)) {
+        Err(StartupError::Crashed(report)) => {
+            assert!(!report.status.success());
+            assert!(report.panic_output.contains("no trusted peers specified"));
+        }
+        other => panic!("unexpected startup result {:?}", other),
+    }
+}
+
 #[test]
 pub fn test_jormungandr_without_initial_funds_starts_sucessfully() {
     let temp_dir = TempDir::new().unwrap();
@@ -6,9 +6,8 @@ use chain_impl_mockchain::fragment::FragmentId;
 use chain_impl_mockchain::key::Hash;
 use jormungandr_lib::interfaces::ActiveSlotCoefficient;
 use jormungandr_testing_utils::stake_pool::StakePool;
-use jormungandr_testing_utils::testing::node::Explorer;
+use jormungandr_testing_utils::testing::node::{Explorer, TypedExplorer};
 use jortestkit::process::Wait;
-use std::str::FromStr;
 use std::time::Duration;
 
 /// test checks if there is upto date schema
@@ -86,41 +85,42 @@ pub fn explorer_sanity_test() {
         .assert_in_block_with_wait(&wait);
 
     let explorer = jormungandr.explorer();
+    let typed_explorer = explorer.typed();
 
-    transaction_by_id(&explorer, fragment_id);
-    blocks(&explorer, jormungandr.logger.get_created_blocks_hashes());
-    stake_pools(&explorer, &initial_stake_pools);
-    stake_pool(&explorer, &initial_stake_pools);
-    block_at_chain_length(&explorer, jormungandr.logger.get_created_blocks_hashes());
+    transaction_by_id(&typed_explorer, fragment_id);
+    blocks(
+        &typed_explorer,
+        jormungandr.logger.get_created_blocks_hashes(),
+    );
+    stake_pools(&typed_explorer, &initial_stake_pools);
+    stake_pool(&typed_explorer, &initial_stake_pools);
+    block_at_chain_length(
+        &typed_explorer,
+        jormungandr.logger.get_created_blocks_hashes(),
+    );
     epoch(&explorer);
 }
 
-fn transaction_by_id(explorer: &Explorer, fragment_id: FragmentId) {
+fn transaction_by_id(explorer: &TypedExplorer, fragment_id: FragmentId) {
     let explorer_transaction = explorer
         .transaction(fragment_id.into())
         .expect("non existing transaction");
 
     assert_eq!(
         fragment_id,
-        Hash::from_str(&explorer_transaction.data.unwrap().transaction.id).unwrap(),
+        explorer_transaction.into_hash(),
         "incorrect fragment id"
     );
 }
 
-fn blocks(explorer: &Explorer, blocks_from_logs: Vec<Hash>) {
-    let blocks = explorer.blocks(1000).unwrap();
-
+fn blocks(explorer: &TypedExplorer, blocks_from_logs: Vec<Hash>) {
     // we are skipping first block because log doesn't contains genesis block
-    let explorer_blocks = blocks
-        .data
-        .unwrap()
-        .tip
-        .blocks
-        .edges
+    let explorer_blocks = explorer
+        .blocks(1000)
         .unwrap()
         .iter()
         .skip(1)
-        .map(|x| Hash::from_str(&x.as_ref().unwrap().node.id).unwrap())
+        .map(|block| block.id.into_hash())
         .collect::<Vec<Hash>>();
 
     let mut common_blocks = blocks_from_logs.clone();
@@ -136,10 +136,8 @@ fn blocks(explorer: &Explorer, blocks_from_logs: Vec<Hash>) {
     );
 }
 
-fn stake_pools(explorer: &Explorer, initial_stake_pools: &[StakePool]) {
-    let stake_pools = explorer.stake_pools(1000).unwrap();
-    let explorer_stake_pools = stake_pools.data.unwrap().tip.all_stake_pools.edges.unwrap();
-    // we are skipping first block because log doesn't contains genesis block
+fn stake_pools(explorer: &TypedExplorer, initial_stake_pools: &[StakePool]) {
+    let explorer_stake_pools = explorer.stake_pools(1000).unwrap();
     assert_eq!(
         initial_stake_pools
             .iter()
@@ -147,31 +145,31 @@ fn stake_pools(explorer: &Explorer, initial_stake_pools: &[StakePool]) {
             .collect::<Vec<String>>(),
         explorer_stake_pools
             .iter()
-            .map(|x| x.as_ref().unwrap().node.id.clone())
+            .map(ToString::to_string)
             .collect::<Vec<String>>(),
         "blocks are empty"
     );
 }
 
-fn stake_pool(explorer: &Explorer, initial_stake_pools: &[StakePool]) {
-    let stake_pool_id = initial_stake_pools.first().unwrap().id().to_string();
-    let stake_pool = explorer.stake_pool(stake_pool_id, 100).unwrap();
-    let explorer_stake_pool_id = stake_pool.data.unwrap().stake_pool.id;
+fn stake_pool(explorer: &TypedExplorer, initial_stake_pools: &[StakePool]) {
+    let stake_pool_id = initial_stake_pools.first().unwrap().id().into();
+    let explorer_stake_pool = explorer.stake_pool(stake_pool_id, 100).unwrap();
 
     assert!(
         initial_stake_pools
             .iter()
-            .any(|x| x.id().to_string() == explorer_stake_pool_id),
+            .any(|x| x.id().to_string() == explorer_stake_pool.id.to_string()),
         "stake pool id"
     );
+    assert!(explorer_stake_pool.registered, "stake pool not registered");
 }
 
-fn block_at_chain_length(explorer: &Explorer, blocks_from_logs: Vec<Hash>) {
-    let block = explorer.blocks_at_chain_length(1).unwrap();
+fn block_at_chain_length(explorer: &TypedExplorer, blocks_from_logs: Vec<Hash>) {
+    let blocks = explorer.blocks_at_chain_length(1).unwrap();
 
     assert_eq!(
-        blocks_from_logs.first().unwrap().to_string(),
-        block.data.unwrap().blocks_by_chain_length[0].id,
+        *blocks_from_logs.first().unwrap(),
+        blocks[0].into_hash(),
         "can't find block"
     );
 }
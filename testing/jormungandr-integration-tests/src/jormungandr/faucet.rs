@@ -0,0 +1,49 @@
+use crate::common::fixtures::{World, WorldBuilder};
+use jormungandr_lib::interfaces::Value;
+use jormungandr_testing_utils::testing::{Faucet, FaucetError, FragmentVerifier};
+use rstest::*;
+use std::time::Duration;
+
+#[fixture]
+fn world() -> World {
+    WorldBuilder::new().with_unfunded_wallets(2).build()
+}
+
+#[rstest]
+pub fn faucet_dispenses_to_several_wallets_in_a_row(world: World) {
+    let faucet = Faucet::new(world.wallet(1).clone(), world.jormungandr.rest()).unwrap();
+    let receivers = [world.wallet(2), world.wallet(3)];
+
+    let checks = faucet.dispense_to_many(&receivers, 100.into()).unwrap();
+    FragmentVerifier::wait_and_verify_all_are_in_block(
+        Duration::from_secs(2),
+        checks,
+        &world.jormungandr,
+    )
+    .unwrap();
+
+    for receiver in &receivers {
+        let state = world.jormungandr.rest().account_state(receiver).unwrap();
+        assert_eq!(*state.value(), Value::from(100));
+    }
+}
+
+#[rstest]
+pub fn faucet_refuses_requests_it_cannot_serve(world: World) {
+    let faucet = Faucet::new(world.wallet(1).clone(), world.jormungandr.rest())
+        .unwrap()
+        .with_limit(1_000.into());
+
+    assert!(matches!(
+        faucet.dispense(world.wallet(2).address(), 1_001.into()),
+        Err(FaucetError::AboveLimit { .. })
+    ));
+
+    let balance = faucet.balance().unwrap();
+    let faucet = faucet.with_limit(balance);
+    assert!(matches!(
+        faucet.dispense(world.wallet(2).address(), balance),
+        Err(FaucetError::NotEnoughFunds { .. })
+    ));
+    assert!(world.jormungandr.rest().fragment_logs().unwrap().is_empty());
+}
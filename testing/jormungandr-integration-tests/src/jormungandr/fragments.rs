@@ -32,6 +32,10 @@ pub fn send_all_fragments() {
             .with_mempool(Mempool {
                 pool_max_entries: 1_000_000usize.into(),
                 log_max_entries: 1_000_000usize.into(),
+                rebroadcast_interval: None,
+                snapshot: None,
+                validity_window: None,
+                log_gc: None,
                 persistent_log: None,
             }),
     )
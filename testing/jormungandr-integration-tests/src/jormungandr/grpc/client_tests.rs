@@ -1,6 +1,7 @@
 use super::setup;
 use crate::common::{
-    jcli::JCli, jormungandr::ConfigurationBuilder, startup, transaction_utils::TransactionHash,
+    configuration, jcli::JCli, jormungandr::ConfigurationBuilder, startup,
+    transaction_utils::TransactionHash,
 };
 use chain_core::property::FromStr;
 use chain_crypto::{Ed25519, PublicKey, Signature, Verification};
@@ -15,10 +16,16 @@ use chain_impl_mockchain::{
 };
 use chain_time::{Epoch, TimeEra};
 use jormungandr_lib::interfaces::InitialUTxO;
-use jormungandr_testing_utils::testing::node::grpc::client::MockClientError;
+use jormungandr_testing_utils::testing::node::grpc::{
+    client::MockClientError, JormungandrLocalClient,
+};
 
 use rand::Rng;
+use std::net::{Ipv4Addr, SocketAddr};
 use std::time::Duration;
+use tonic_health::proto::{
+    health_check_response::ServingStatus, health_client::HealthClient, HealthCheckRequest,
+};
 
 const CHAIN_GROWTH_TIMEOUT: Duration = Duration::from_secs(60);
 
@@ -397,3 +404,108 @@ pub fn pull_blocks_hashes_wrong_order() {
 
     assert!(result.is_err());
 }
+
+// L1024 PostFragments from the host of the node
+#[test]
+pub fn post_fragments_locally() {
+    let mut sender = startup::create_new_account_address();
+    let receiver = startup::create_new_account_address();
+    let local_port = configuration::get_available_port();
+    let config = ConfigurationBuilder::new()
+        .with_slot_duration(4)
+        .with_local_listen_port(local_port)
+        .with_funds(vec![InitialUTxO {
+            address: sender.address(),
+            value: 100.into(),
+        }])
+        .to_owned();
+
+    let setup = setup::client::bootstrap(config);
+    let local_client =
+        JormungandrLocalClient::new(SocketAddr::from((Ipv4Addr::LOCALHOST, local_port)));
+    let fragment = sender
+        .transaction_to(
+            &setup.server.genesis_block_hash(),
+            &setup.server.fees(),
+            BlockDate::first().next_epoch(),
+            receiver.address(),
+            1.into(),
+        )
+        .unwrap();
+
+    let summary = local_client
+        .post_fragments(&[fragment.clone()], true)
+        .unwrap();
+    assert_eq!(summary.accepted, vec![fragment.id()]);
+    assert!(summary.rejected.is_empty());
+
+    let summary = local_client.post_fragments(&[fragment], true).unwrap();
+    assert!(summary.is_already_known());
+}
+
+// L1025 PostFragments is not served on the P2P endpoint
+#[test]
+pub fn post_fragments_is_not_served_to_peers() {
+    let mut sender = startup::create_new_account_address();
+    let receiver = startup::create_new_account_address();
+    let config = ConfigurationBuilder::new()
+        .with_slot_duration(4)
+        .with_local_listen_port(configuration::get_available_port())
+        .with_funds(vec![InitialUTxO {
+            address: sender.address(),
+            value: 100.into(),
+        }])
+        .to_owned();
+
+    let setup = setup::client::bootstrap(config);
+    let p2p_client = JormungandrLocalClient::new(SocketAddr::from((
+        Ipv4Addr::LOCALHOST,
+        setup.config.get_p2p_listen_port(),
+    )));
+    let fragment = sender
+        .transaction_to(
+            &setup.server.genesis_block_hash(),
+            &setup.server.fees(),
+            BlockDate::first().next_epoch(),
+            receiver.address(),
+            1.into(),
+        )
+        .unwrap();
+
+    assert!(p2p_client.post_fragments(&[fragment], true).is_err());
+}
+
+// L1026 Health checking on the P2P endpoint
+#[test]
+pub fn health_of_the_p2p_endpoint() {
+    let setup = setup::client::default();
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let endpoint = format!(
+        "http://{}:{}",
+        Ipv4Addr::LOCALHOST,
+        setup.config.get_p2p_listen_port()
+    );
+
+    rt.block_on(async {
+        let mut client = HealthClient::connect(endpoint).await.unwrap();
+        // the server as a whole, then the node service
+        for service in &["", "iohk.chain.node.Node"] {
+            let response = client
+                .check(HealthCheckRequest {
+                    service: service.to_string(),
+                })
+                .await
+                .unwrap()
+                .into_inner();
+            assert_eq!(response.status, ServingStatus::Serving as i32);
+        }
+
+        let status = client
+            .check(HealthCheckRequest {
+                service: "iohk.chain.node.Unknown".to_string(),
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::NotFound);
+    });
+}
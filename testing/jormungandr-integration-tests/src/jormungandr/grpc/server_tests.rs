@@ -1,6 +1,9 @@
 use super::setup;
 use jormungandr_testing_utils::testing::node::{
-    grpc::server::{MethodType, MockBuilder, MockExitCode, ProtocolVersion},
+    grpc::server::{
+        AdversaryBehaviour, AdversaryNodeBuilder, MethodType, MockBuilder, MockExitCode,
+        ProtocolVersion,
+    },
     LogLevel,
 };
 
@@ -108,3 +111,63 @@ pub fn handshake_ok() {
             && x.reason_contains("unsupported protocol version")
     }));
 }
+
+fn assert_adversary_is_quarantined_and_ignored(behaviour: AdversaryBehaviour, method: MethodType) {
+    let setup = setup::server::default();
+    let genesis_hash = setup.server.genesis_block_hash().into_hash();
+
+    let adversary = AdversaryNodeBuilder::new(behaviour)
+        .with_port(setup.mock_port)
+        .with_genesis_hash(genesis_hash)
+        .build();
+
+    setup.wait_server_online();
+
+    let adversary_block_ids = adversary.block_ids().to_vec();
+    let adversary_result = adversary.finish_and_verify_that(move |mock_verifier| {
+        mock_verifier.method_executed_at_least_once(method)
+    });
+
+    assert_eq!(
+        adversary_result,
+        MockExitCode::Success,
+        "node never requested data from adversary"
+    );
+
+    let tip = setup.server.rest().tip().unwrap().into_hash();
+    assert!(
+        !adversary_block_ids.contains(&tip),
+        "node accepted block from adversary"
+    );
+
+    let quarantined = setup.server.rest().p2p_quarantined().unwrap();
+    setup.server.shutdown();
+    assert!(
+        !quarantined.is_empty(),
+        "adversary node was not quarantined"
+    );
+}
+
+#[test]
+pub fn adversary_serving_invalid_blocks_is_quarantined() {
+    assert_adversary_is_quarantined_and_ignored(
+        AdversaryBehaviour::InvalidBlocks,
+        MethodType::PullBlocks,
+    );
+}
+
+#[test]
+pub fn adversary_serving_contradictory_headers_is_quarantined() {
+    assert_adversary_is_quarantined_and_ignored(
+        AdversaryBehaviour::ContradictoryHeaders,
+        MethodType::PullHeaders,
+    );
+}
+
+#[test]
+pub fn adversary_serving_garbage_gossip_is_quarantined() {
+    assert_adversary_is_quarantined_and_ignored(
+        AdversaryBehaviour::GarbageGossip,
+        MethodType::GossipSubscription,
+    );
+}
@@ -26,7 +26,7 @@ fn verify_bft_leadership_logs_parent_hash() {
     verify_leadership_logs_parent_hash(jormungandr);
 }
 
-fn verify_leadership_logs_parent_hash(jormungandr: JormungandrProcess) {
+fn verify_leadership_logs_parent_hash(mut jormungandr: JormungandrProcess) {
     jormungandr
         .wait_for_bootstrap(&StartupVerificationMode::Rest, Duration::from_secs(10))
         .unwrap();
@@ -4,18 +4,16 @@ use assert_fs::fixture::PathChild;
 use assert_fs::TempDir;
 use chain_impl_mockchain::{block::BlockDate, chaintypes::ConsensusVersion};
 use jormungandr_lib::interfaces::InitialUTxO;
-use jormungandr_lib::interfaces::PersistentLog;
 use jormungandr_lib::interfaces::{BlockDate as BlockDateDto, Mempool};
-use jormungandr_testing_utils::testing::fragments::FragmentExporter;
+use jormungandr_lib::interfaces::{PersistentLog, PoolSnapshot};
 use jormungandr_testing_utils::testing::fragments::PersistentLogViewer;
 use jormungandr_testing_utils::testing::{
-    node::time, FragmentGenerator, FragmentSender, FragmentSenderSetup, FragmentVerifier,
-    MemPoolCheck,
+    node::{time, PersistenceVerifier},
+    FragmentGenerator, FragmentSender, FragmentSenderSetup, FragmentVerifier, MemPoolCheck,
 };
 use jormungandr_testing_utils::testing::{AdversaryFragmentSender, AdversaryFragmentSenderSetup};
 use jortestkit::prelude::Wait;
 use std::fs::metadata;
-use std::path::Path;
 use std::thread::sleep;
 use std::time::Duration;
 
@@ -37,6 +35,10 @@ pub fn dump_send_correct_fragments() {
             .with_mempool(Mempool {
                 pool_max_entries: 1_000_000usize.into(),
                 log_max_entries: 1_000_000usize.into(),
+                rebroadcast_interval: None,
+                snapshot: None,
+                validity_window: None,
+                log_gc: None,
                 persistent_log: Some(PersistentLog {
                     dir: persistent_log_path.path().to_path_buf(),
                 }),
@@ -82,7 +84,7 @@ pub fn dump_send_correct_fragments() {
     )
     .unwrap();
 
-    assert_all_fragment_are_persisted(dump_folder.path(), persistent_log_path.path());
+    PersistenceVerifier::new(persistent_log_path.path()).assert_matches_dump(dump_folder.path());
 }
 
 #[test]
@@ -102,6 +104,10 @@ pub fn dump_send_invalid_fragments() {
             .with_mempool(Mempool {
                 pool_max_entries: 1_000_000usize.into(),
                 log_max_entries: 1_000_000usize.into(),
+                rebroadcast_interval: None,
+                snapshot: None,
+                validity_window: None,
+                log_gc: None,
                 persistent_log: Some(PersistentLog {
                     dir: persistent_log_path.path().to_path_buf(),
                 }),
@@ -122,16 +128,7 @@ pub fn dump_send_invalid_fragments() {
 
     sleep(Duration::from_secs(1));
 
-    assert_all_fragment_are_persisted(dump_folder.path(), persistent_log_path.path());
-}
-
-fn assert_all_fragment_are_persisted<P: AsRef<Path>, R: AsRef<Path>>(left: P, right: R) {
-    let exporter = FragmentExporter::new(left.as_ref().to_path_buf()).unwrap();
-    let fragments = exporter.read_as_bytes().unwrap();
-
-    let persistent_log_viewer = PersistentLogViewer::new(right.as_ref().to_path_buf());
-    assert_eq!(fragments.len(), persistent_log_viewer.get_all().len());
-    assert_eq!(fragments, persistent_log_viewer.get_bin());
+    PersistenceVerifier::new(persistent_log_path.path()).assert_matches_dump(dump_folder.path());
 }
 
 #[test]
@@ -151,6 +148,10 @@ pub fn non_existing_folder() {
             .with_mempool(Mempool {
                 pool_max_entries: 1_000_000usize.into(),
                 log_max_entries: 1_000_000usize.into(),
+                rebroadcast_interval: None,
+                snapshot: None,
+                validity_window: None,
+                log_gc: None,
                 persistent_log: Some(PersistentLog {
                     dir: persistent_log_path.path().to_path_buf(),
                 }),
@@ -175,6 +176,10 @@ pub fn invalid_folder() {
         .with_mempool(Mempool {
             pool_max_entries: 1_000_000usize.into(),
             log_max_entries: 1_000_000usize.into(),
+            rebroadcast_interval: None,
+            snapshot: None,
+            validity_window: None,
+            log_gc: None,
             persistent_log: Some(PersistentLog {
                 dir: persistent_log_path.path().to_path_buf(),
             }),
@@ -203,6 +208,10 @@ pub fn fragment_which_reached_mempool_should_be_persisted() {
             .with_mempool(Mempool {
                 pool_max_entries: 1usize.into(),
                 log_max_entries: 1000usize.into(),
+                rebroadcast_interval: None,
+                snapshot: None,
+                validity_window: None,
+                log_gc: None,
                 persistent_log: Some(PersistentLog {
                     dir: persistent_log_path.path().to_path_buf(),
                 }),
@@ -223,7 +232,9 @@ pub fn fragment_which_reached_mempool_should_be_persisted() {
 
     sleep(Duration::from_secs(1));
 
-    assert_all_fragment_are_persisted(dump_folder.path(), persistent_log_path.path());
+    PersistenceVerifier::new(persistent_log_path.path())
+        .assert_matches_dump(dump_folder.path())
+        .assert_contains_fragment_logs(&jormungandr.rest());
 }
 
 #[test]
@@ -243,6 +254,10 @@ pub fn fragment_which_is_not_in_fragment_log_should_be_persisted() {
             .with_mempool(Mempool {
                 pool_max_entries: 1000usize.into(),
                 log_max_entries: 1usize.into(),
+                rebroadcast_interval: None,
+                snapshot: None,
+                validity_window: None,
+                log_gc: None,
                 persistent_log: Some(PersistentLog {
                     dir: persistent_log_path.path().to_path_buf(),
                 }),
@@ -263,7 +278,7 @@ pub fn fragment_which_is_not_in_fragment_log_should_be_persisted() {
 
     sleep(Duration::from_secs(1));
 
-    assert_all_fragment_are_persisted(dump_folder.path(), persistent_log_path.path());
+    PersistenceVerifier::new(persistent_log_path.path()).assert_matches_dump(dump_folder.path());
 }
 
 #[test]
@@ -283,6 +298,10 @@ pub fn pending_fragment_should_be_persisted() {
             .with_mempool(Mempool {
                 pool_max_entries: 10usize.into(),
                 log_max_entries: 10usize.into(),
+                rebroadcast_interval: None,
+                snapshot: None,
+                validity_window: None,
+                log_gc: None,
                 persistent_log: Some(PersistentLog {
                     dir: persistent_log_path.path().to_path_buf(),
                 }),
@@ -328,6 +347,10 @@ pub fn node_should_pickup_log_after_restart() {
         .with_mempool(Mempool {
             pool_max_entries: 1usize.into(),
             log_max_entries: 1000usize.into(),
+            rebroadcast_interval: None,
+            snapshot: None,
+            validity_window: None,
+            log_gc: None,
             persistent_log: Some(PersistentLog {
                 dir: persistent_log_path.path().to_path_buf(),
             }),
@@ -390,3 +413,73 @@ pub fn node_should_pickup_log_after_restart() {
 
     assert_eq!(20, persistent_log_viewer.get_all().len());
 }
+
+#[test]
+pub fn pending_fragments_are_restored_after_restart() {
+    let temp_dir = TempDir::new().unwrap();
+    let snapshot_path = temp_dir.child("mempool_snapshot");
+    let receiver = startup::create_new_account_address();
+    let mut sender = startup::create_new_account_address();
+
+    // blocks are too far apart for the fragment to leave the mempool
+    let config = ConfigurationBuilder::new()
+        .with_slots_per_epoch(5)
+        .with_slot_duration(60)
+        .with_mempool(Mempool {
+            snapshot: Some(PoolSnapshot {
+                path: snapshot_path.path().to_path_buf(),
+            }),
+            ..Default::default()
+        })
+        .with_block0_consensus(ConsensusVersion::Bft)
+        .with_funds(vec![
+            InitialUTxO {
+                address: sender.address(),
+                value: 1_000_000.into(),
+            },
+            InitialUTxO {
+                address: receiver.address(),
+                value: 1_000_000.into(),
+            },
+        ])
+        .build(&temp_dir);
+
+    let jormungandr = Starter::new()
+        .config(config.clone())
+        .role(Role::Leader)
+        .start()
+        .unwrap();
+
+    let fragment_sender = FragmentSender::new(
+        jormungandr.genesis_block_hash(),
+        jormungandr.fees(),
+        BlockDate::first().next_epoch(),
+        FragmentSenderSetup::no_verify(),
+    );
+    let check = fragment_sender
+        .send_transaction(&mut sender, &receiver, &jormungandr, 1.into())
+        .unwrap();
+
+    jormungandr.shutdown();
+    let mut wait = Wait::new(Duration::from_secs(1), 20);
+    while !snapshot_path.path().exists() {
+        wait.check_timeout()
+            .expect("the mempool snapshot was not written");
+        wait.advance();
+    }
+    jormungandr.stop();
+
+    let jormungandr = Starter::new()
+        .temp_dir(temp_dir)
+        .config(config)
+        .role(Role::Leader)
+        .start()
+        .unwrap();
+
+    let fragment_logs = jormungandr.rest().fragment_logs().unwrap();
+    let restored = fragment_logs
+        .get(check.fragment_id())
+        .expect("the pending fragment was not restored");
+    assert!(restored.is_pending());
+    assert!(!snapshot_path.path().exists());
+}
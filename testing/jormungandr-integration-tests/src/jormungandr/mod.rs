@@ -1,5 +1,6 @@
 pub mod bft;
 pub mod explorer;
+pub mod faucet;
 pub mod fragments;
 pub mod genesis;
 pub mod grpc;
@@ -8,6 +9,7 @@ pub mod legacy;
 pub mod mempool;
 pub mod recovery;
 pub mod rest;
+pub mod simulation_clock;
 pub mod tls;
 pub mod transactions;
 pub mod vit;
@@ -0,0 +1,72 @@
+use crate::common::fixtures::{World, WorldBuilder};
+use chain_core::property::Fragment as _;
+use chain_impl_mockchain::account::Identifier;
+use jormungandr_lib::{
+    client::{blocking::RestClient, Error},
+    interfaces::{FragmentStatus, FragmentsBatch},
+};
+use jormungandr_testing_utils::{testing::FragmentVerifier, wallet::Wallet};
+use rstest::*;
+use std::time::Duration;
+
+#[fixture]
+fn world() -> World {
+    WorldBuilder::new().build()
+}
+
+fn client(world: &World) -> RestClient {
+    RestClient::new(world.jormungandr.rest_uri().parse().unwrap())
+}
+
+fn balance(client: &RestClient, wallet: &Wallet) -> u64 {
+    let account: Identifier = wallet.identifier().into_public_key().into();
+    (*client.account_state(&account).unwrap().value()).into()
+}
+
+#[rstest]
+pub fn typed_client_decodes_the_node_responses(mut world: World) {
+    let client = client(&world);
+
+    let settings = client.settings().unwrap();
+    assert_eq!(
+        settings.block0_hash,
+        world.jormungandr.genesis_block_hash().to_string()
+    );
+
+    let initial_balance = balance(&client, world.wallet(1));
+    let fragment = world.transaction(0, 1, 100.into());
+    let summary = client.send_fragment(&fragment).unwrap();
+    assert_eq!(summary.accepted, vec![fragment.id()]);
+
+    FragmentVerifier::wait_for_all_fragments(Duration::from_secs(10), &world.jormungandr).unwrap();
+
+    let statuses = client.fragment_statuses(&[fragment.id()]).unwrap();
+    assert!(matches!(
+        statuses[&fragment.id()],
+        FragmentStatus::InABlock { .. }
+    ));
+    assert!(client
+        .fragment_logs()
+        .unwrap()
+        .iter()
+        .any(|log| *log.fragment_id() == fragment.id().into()));
+
+    assert_eq!(balance(&client, world.wallet(1)), initial_balance + 100);
+}
+
+#[rstest]
+pub fn typed_client_reports_rejected_fragments(world: World) {
+    let client = client(&world);
+    let batch = FragmentsBatch {
+        fail_fast: false,
+        fragments: vec![world.unbalanced_transaction(0, 1)],
+    };
+
+    match client.send_fragments(&batch) {
+        Err(Error::FragmentsRejected(summary)) => {
+            assert!(summary.accepted.is_empty());
+            assert_eq!(summary.rejected.len(), 1);
+        }
+        other => panic!("unexpected response {:?}", other),
+    }
+}
@@ -0,0 +1,58 @@
+use crate::common::fixtures::{World, WorldBuilder};
+use crate::common::jormungandr::ConfigurationBuilder;
+use reqwest::{
+    blocking::{Client, Response},
+    header::{ACCESS_CONTROL_ALLOW_METHODS, ACCESS_CONTROL_ALLOW_ORIGIN},
+    Method, StatusCode,
+};
+use rstest::*;
+
+const ALLOWED_ORIGIN: &str = "http://127.0.0.1:8080";
+
+#[fixture]
+fn world() -> World {
+    let mut config = ConfigurationBuilder::new();
+    config.with_rest_cors_config(
+        serde_yaml::from_str(&format!(
+            "allowed_origins: [\"{}\"]\nallowed_methods: [get]\n",
+            ALLOWED_ORIGIN
+        ))
+        .unwrap(),
+    );
+    WorldBuilder::new().with_config(config).build()
+}
+
+fn preflight(world: &World, origin: &str, method: &str) -> Response {
+    Client::new()
+        .request(
+            Method::OPTIONS,
+            &format!("{}/v0/node/stats", world.jormungandr.rest_uri()),
+        )
+        .header("Origin", origin)
+        .header("Access-Control-Request-Method", method)
+        .send()
+        .unwrap()
+}
+
+#[rstest]
+pub fn allowed_origins_and_methods_pass_the_preflight(world: World) {
+    let response = preflight(&world, ALLOWED_ORIGIN, "GET");
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()[ACCESS_CONTROL_ALLOW_ORIGIN],
+        ALLOWED_ORIGIN
+    );
+    assert!(response.headers()[ACCESS_CONTROL_ALLOW_METHODS]
+        .to_str()
+        .unwrap()
+        .contains("GET"));
+}
+
+#[rstest]
+pub fn other_origins_and_methods_are_refused(world: World) {
+    let response = preflight(&world, ALLOWED_ORIGIN, "POST");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = preflight(&world, "http://127.0.0.1:9090", "GET");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
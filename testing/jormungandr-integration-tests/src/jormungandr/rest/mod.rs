@@ -1,2 +1,4 @@
+mod client;
+mod cors;
 mod v0;
 mod v1;
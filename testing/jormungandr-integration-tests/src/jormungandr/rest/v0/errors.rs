@@ -1,40 +1,19 @@
-use crate::common::jormungandr::JormungandrProcess;
-use crate::common::{jormungandr::ConfigurationBuilder, startup};
+use crate::common::fixtures::{World, WorldBuilder};
 use chain_core::property::Fragment;
-use chain_impl_mockchain::block::BlockDate;
-use jormungandr_lib::interfaces::FragmentsProcessingSummary;
-use jormungandr_testing_utils::wallet::Wallet;
+use jormungandr_lib::interfaces::{
+    FragmentRejectionReason, FragmentsProcessingSummary, RejectedFragmentInfo,
+};
 use rstest::*;
 
 #[fixture]
-fn world() -> (JormungandrProcess, Wallet, Wallet, Wallet) {
-    let alice = startup::create_new_account_address();
-    let bob = startup::create_new_account_address();
-    let clarice = startup::create_new_account_address();
-
-    let (jormungandr, _stake_pools) = startup::start_stake_pool(
-        &[alice.clone()],
-        &[bob.clone()],
-        &mut ConfigurationBuilder::new(),
-    )
-    .unwrap();
-
-    (jormungandr, alice, bob, clarice)
+fn world() -> World {
+    WorldBuilder::new().build()
 }
 
 #[rstest]
-pub fn fragment_already_in_log(world: (JormungandrProcess, Wallet, Wallet, Wallet)) {
-    let (jormungandr, mut alice, bob, _) = world;
-
-    let alice_fragment = alice
-        .transaction_to(
-            &jormungandr.genesis_block_hash(),
-            &jormungandr.fees(),
-            BlockDate::first().next_epoch(),
-            bob.address(),
-            100.into(),
-        )
-        .unwrap();
+pub fn fragment_already_in_log(mut world: World) {
+    let alice_fragment = world.transaction(0, 1, 100.into());
+    let jormungandr = world.jormungandr;
 
     let response = jormungandr
         .rest()
@@ -48,3 +27,30 @@ pub fn fragment_already_in_log(world: (JormungandrProcess, Wallet, Wallet, Walle
     assert_eq!(summary.accepted, vec![alice_fragment.id()]);
     assert_eq!(summary.rejected, vec![]);
 }
+
+#[rstest]
+pub fn fragment_already_known(mut world: World) {
+    let alice_fragment = world.transaction(0, 1, 100.into());
+    let jormungandr = world.jormungandr;
+
+    let rest = jormungandr.rest();
+    let response = rest.raw().send_fragment(alice_fragment.clone()).unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+
+    let response = rest.raw().send_fragment(alice_fragment.clone()).unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::CONFLICT);
+    let summary: FragmentsProcessingSummary =
+        serde_json::from_str(&response.text().unwrap()).unwrap();
+    assert_eq!(summary.accepted, vec![]);
+    assert_eq!(
+        summary.rejected,
+        vec![RejectedFragmentInfo {
+            id: alice_fragment.id(),
+            reason: FragmentRejectionReason::FragmentAlreadyInLog,
+        }]
+    );
+    assert_eq!(
+        rest.stats().unwrap().stats.unwrap().tx_duplicate_rest_cnt,
+        1
+    );
+}
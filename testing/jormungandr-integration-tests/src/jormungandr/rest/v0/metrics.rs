@@ -0,0 +1,41 @@
+use crate::common::fixtures::{World, WorldBuilder};
+use jormungandr_testing_utils::testing::FragmentVerifier;
+use rstest::*;
+use std::time::Duration;
+
+#[fixture]
+fn world() -> World {
+    WorldBuilder::new().build()
+}
+
+#[rstest]
+pub fn requests_and_errors_are_counted(mut world: World) {
+    let alice_fragment = world.transaction(0, 1, 100.into());
+    let rest = world.jormungandr.rest();
+    let before = rest.stats().unwrap().stats.unwrap();
+
+    let response = rest.raw().send_fragment(alice_fragment.clone()).unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+    let response = rest.raw().send_fragment(alice_fragment).unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::CONFLICT);
+
+    let after = rest.stats().unwrap().stats.unwrap();
+    // the first statistics request is counted as well
+    assert!(after.rest_request_cnt >= before.rest_request_cnt + 3);
+    assert_eq!(after.rest_error_cnt, before.rest_error_cnt + 1);
+}
+
+#[rstest]
+pub fn stake_of_the_pools_is_reported(mut world: World) {
+    // the stake figures are computed once the node has a tip
+    let fragment = world.transaction(0, 1, 100.into());
+    world.jormungandr.rest().send_fragment(fragment).unwrap();
+    FragmentVerifier::wait_for_all_fragments(Duration::from_secs(10), &world.jormungandr).unwrap();
+
+    let stats = world.jormungandr.rest().stats().unwrap().stats.unwrap();
+    // only the first wallet delegates, to its own stake pool
+    assert_eq!(stats.stake_pool_cnt, 1);
+    assert!(stats.active_stake > 0);
+    let participation = stats.delegation_participation.unwrap();
+    assert!(participation > 0.0 && participation < 1.0);
+}
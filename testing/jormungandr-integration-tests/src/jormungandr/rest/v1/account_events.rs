@@ -0,0 +1,58 @@
+use crate::common::fixtures::{World, WorldBuilder};
+use jormungandr_lib::interfaces::AccountEvent;
+use reqwest::StatusCode;
+use rstest::*;
+use std::io::{BufRead, BufReader};
+
+#[fixture]
+fn world() -> World {
+    WorldBuilder::new().build()
+}
+
+#[rstest]
+pub fn balance_changes_of_subscribed_accounts_are_streamed(mut world: World) {
+    let receiver_id = world.wallet(1).identifier().into_public_key().to_string();
+    let initial_value: u64 = (*world
+        .jormungandr
+        .rest()
+        .account_state(world.wallet(1))
+        .unwrap()
+        .value())
+    .into();
+
+    let response = world
+        .jormungandr
+        .rest()
+        .raw()
+        .account_events(&[receiver_id.clone()])
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let fragment = world.transaction(0, 1, 100.into());
+    world.jormungandr.rest().send_fragment(fragment).unwrap();
+
+    // the stream is made of `event:` and `data:` lines, with keep-alive
+    // comments in between
+    let mut lines = BufReader::new(response).lines().map(Result::unwrap);
+    lines
+        .by_ref()
+        .find(|line| line.trim_start_matches("event:").trim() == "account")
+        .expect("no account event before the end of the stream");
+    let data = lines.next().unwrap();
+    let event: AccountEvent =
+        serde_json::from_str(data.trim_start_matches("data:").trim()).unwrap();
+
+    assert_eq!(event.account_id, receiver_id);
+    assert_eq!(u64::from(event.value), initial_value + 100);
+}
+
+#[rstest]
+pub fn subscriptions_to_malformed_accounts_are_rejected(world: World) {
+    let response = world
+        .jormungandr
+        .rest()
+        .raw()
+        .account_events(&["not-a-key".to_string()])
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
@@ -0,0 +1,46 @@
+use crate::common::fixtures::{World, WorldBuilder};
+use jormungandr_testing_utils::testing::FragmentSenderSetup;
+use rstest::*;
+
+#[fixture]
+fn world() -> World {
+    WorldBuilder::new().build()
+}
+
+#[rstest]
+pub fn account_state_is_served_at_past_blocks(mut world: World) {
+    let alice_fragment = world.transaction(0, 1, 100.into());
+    let jormungandr = world.jormungandr;
+    let rest = jormungandr.rest();
+
+    let block_before = rest.tip().unwrap();
+    let state_before = rest.account_state(&world.wallets[0]).unwrap();
+
+    let transaction_sender = jormungandr.fragment_sender(FragmentSenderSetup::resend_3_times());
+    let check = transaction_sender
+        .send_fragment(&mut world.wallets[0], alice_fragment, &jormungandr)
+        .unwrap();
+    transaction_sender.verify(&check, &jormungandr).unwrap();
+
+    let current = rest.account_state_at(&world.wallets[0], None).unwrap();
+    assert_eq!(current.state.counter(), state_before.counter() + 1);
+    assert_ne!(current.block, block_before);
+
+    let past = rest
+        .account_state_at(&world.wallets[0], Some(&block_before.to_string()))
+        .unwrap();
+    assert_eq!(past.block, block_before);
+    assert_eq!(past.state, state_before);
+
+    // the current epoch is not over, its latest state is served
+    let epoch = rest
+        .account_state_at(&world.wallets[0], Some(&current.date.epoch().to_string()))
+        .unwrap();
+    assert_eq!(epoch.state.counter(), current.state.counter());
+
+    let response = rest
+        .raw()
+        .account_state_at(&world.wallets[0], Some("1000"))
+        .unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,62 @@
+use crate::common::fixtures::{World, WorldBuilder};
+use crate::common::jormungandr::ConfigurationBuilder;
+use jormungandr_testing_utils::testing::{
+    BatchLimits, FragmentSenderSetup, FragmentSenderSetupBuilder, FragmentVerifier,
+};
+use reqwest::StatusCode;
+use rstest::*;
+use std::time::Duration;
+
+const MAX_BODY_SIZE: u64 = 1024;
+
+#[fixture]
+fn world() -> World {
+    let mut config = ConfigurationBuilder::new();
+    config.with_rest_max_body_size(MAX_BODY_SIZE);
+    WorldBuilder::new()
+        .with_wallets(5)
+        .with_config(config)
+        .build()
+}
+
+#[rstest]
+pub fn oversized_fragment_batches_are_rejected(mut world: World) {
+    let fragment = world.transaction(0, 1, 100.into());
+    let rest = world.jormungandr.rest();
+
+    let response = rest
+        .raw()
+        .send_fragment_batch(vec![fragment.clone(); 10], false)
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    assert!(rest.fragment_logs().unwrap().is_empty());
+
+    let response = rest
+        .raw()
+        .send_fragment_batch(vec![fragment], false)
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[rstest]
+pub fn fragment_sender_splits_oversized_batches(mut world: World) {
+    let fragments: Vec<_> = (1..5)
+        .map(|from| world.transaction(from, 0, 100.into()))
+        .collect();
+
+    let mut setup = FragmentSenderSetupBuilder::from(FragmentSenderSetup::no_verify());
+    setup.batch_limits(BatchLimits::default().with_max_body_size(MAX_BODY_SIZE));
+    let checks = world
+        .jormungandr
+        .fragment_sender(setup.into())
+        .send_batch_fragments(fragments.clone(), false, &world.jormungandr)
+        .unwrap();
+    assert_eq!(checks.len(), fragments.len());
+
+    FragmentVerifier::wait_and_verify_all_are_in_block(
+        Duration::from_secs(10),
+        checks,
+        &world.jormungandr,
+    )
+    .unwrap();
+}
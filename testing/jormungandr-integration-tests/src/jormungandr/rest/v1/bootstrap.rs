@@ -0,0 +1,16 @@
+use crate::common::fixtures::{World, WorldBuilder};
+use rstest::*;
+
+#[fixture]
+fn world() -> World {
+    WorldBuilder::new().build()
+}
+
+#[rstest]
+pub fn bootstrap_of_a_node_without_peers_is_completed(world: World) {
+    let status = world.jormungandr.rest().bootstrap_status().unwrap();
+    assert!(status.completed);
+    assert_eq!(status.attempt, 1);
+    assert_eq!(status.peer, None);
+    assert_eq!(status.blocks_applied, 0);
+}
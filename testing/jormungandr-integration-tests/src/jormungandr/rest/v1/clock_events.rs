@@ -0,0 +1,71 @@
+use crate::common::fixtures::{World, WorldBuilder};
+use crate::common::jormungandr::ConfigurationBuilder;
+use jormungandr_lib::interfaces::SlotStart;
+use reqwest::{blocking::Response, StatusCode};
+use rstest::*;
+use std::io::{BufRead, BufReader};
+
+const SLOTS_PER_EPOCH: u32 = 5;
+
+#[fixture]
+fn world() -> World {
+    let mut config = ConfigurationBuilder::new();
+    config
+        .with_slots_per_epoch(SLOTS_PER_EPOCH)
+        .with_slot_duration(1);
+    WorldBuilder::new().with_config(config).build()
+}
+
+/// The `event:` names and the slot starts of the stream, skipping the
+/// keep-alive comments
+fn slot_starts(response: Response) -> impl Iterator<Item = (String, SlotStart)> {
+    let mut lines = BufReader::new(response).lines().map(Result::unwrap);
+    std::iter::from_fn(move || {
+        let event = lines
+            .by_ref()
+            .find(|line| line.starts_with("event:"))?
+            .trim_start_matches("event:")
+            .trim()
+            .to_string();
+        let data = lines.next()?;
+        let slot_start = serde_json::from_str(data.trim_start_matches("data:").trim()).unwrap();
+        Some((event, slot_start))
+    })
+}
+
+#[rstest]
+pub fn start_of_each_slot_is_announced(world: World) {
+    let response = world.jormungandr.rest().raw().clock_events(false).unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let starts: Vec<(String, SlotStart)> = slot_starts(response).take(3).collect();
+    assert_eq!(starts.len(), 3);
+    for (event, start) in &starts {
+        assert_eq!(start.slot_duration, 1);
+        assert_eq!(start.slots_per_epoch, SLOTS_PER_EPOCH);
+        assert_eq!(start.epoch_start, start.date.slot() == 0);
+        assert_eq!(event, if start.epoch_start { "epoch" } else { "slot" });
+    }
+    for pair in starts.windows(2) {
+        let (previous, next) = (&pair[0].1, &pair[1].1);
+        assert!(next.time > previous.time);
+        let previous_slot = previous.date.epoch() * SLOTS_PER_EPOCH + previous.date.slot();
+        let next_slot = next.date.epoch() * SLOTS_PER_EPOCH + next.date.slot();
+        assert_eq!(next_slot, previous_slot + 1);
+    }
+}
+
+#[rstest]
+pub fn start_of_each_epoch_is_announced_on_request(world: World) {
+    let response = world.jormungandr.rest().raw().clock_events(true).unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let starts: Vec<(String, SlotStart)> = slot_starts(response).take(2).collect();
+    assert_eq!(starts.len(), 2);
+    for (event, start) in &starts {
+        assert_eq!(event, "epoch");
+        assert!(start.epoch_start);
+        assert_eq!(start.date.slot(), 0);
+    }
+    assert_eq!(starts[1].1.date.epoch(), starts[0].1.date.epoch() + 1);
+}
@@ -0,0 +1,44 @@
+use crate::common::fixtures::{World, WorldBuilder};
+use chain_impl_mockchain::chaintypes::ConsensusVersion;
+use jormungandr_lib::interfaces::{ConfigurationSummary, Mempool, StorageMode};
+use rstest::*;
+
+#[fixture]
+fn world() -> World {
+    WorldBuilder::new()
+        .with_mempool(Mempool {
+            pool_max_entries: 100.into(),
+            log_max_entries: 200.into(),
+            ..Default::default()
+        })
+        .build()
+}
+
+#[rstest]
+pub fn effective_configuration_is_served(world: World) {
+    let summary = world.jormungandr.rest().configuration_summary().unwrap();
+    assert_eq!(summary.consensus, ConsensusVersion::GenesisPraos);
+    assert!(world
+        .jormungandr
+        .rest_uri()
+        .contains(&summary.rest_listen_address.unwrap().to_string()));
+    assert_eq!(summary.mempool.pool_max_entries, 100);
+    assert_eq!(summary.mempool.log_max_entries, 200);
+    assert_eq!(summary.storage, StorageMode::InMemory);
+    assert!(!summary.subsystems.explorer);
+}
+
+#[rstest]
+pub fn effective_configuration_is_logged_at_startup(world: World) {
+    let served = world.jormungandr.rest().configuration_summary().unwrap();
+    let logged: ConfigurationSummary = world
+        .jormungandr
+        .logger
+        .get_lines()
+        .into_iter()
+        .find(|entry| entry.message() == "effective configuration")
+        .and_then(|entry| entry.fields.get("configuration").cloned())
+        .map(|configuration| serde_json::from_str(&configuration).unwrap())
+        .expect("no configuration summary in the log");
+    assert_eq!(logged, served);
+}
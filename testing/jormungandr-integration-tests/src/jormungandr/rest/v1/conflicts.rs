@@ -0,0 +1,35 @@
+use crate::common::fixtures::{World, WorldBuilder};
+use jormungandr_lib::interfaces::{ConflictingSpend, FragmentOrigin};
+use rstest::*;
+
+#[fixture]
+fn world() -> World {
+    WorldBuilder::new().build()
+}
+
+#[rstest]
+pub fn fragments_spending_the_same_counter_are_listed_as_conflicting(mut world: World) {
+    // both transactions are built with the same spending counter of alice
+    let first = world.transaction(0, 1, 100.into());
+    let second = world.transaction(0, 1, 200.into());
+    let rest = world.jormungandr.rest();
+
+    rest.send_fragment(first.clone()).unwrap();
+    rest.send_fragment(second.clone()).unwrap();
+
+    let conflicts = rest.fragment_conflicts().unwrap();
+    assert_eq!(conflicts.len(), 1);
+    let conflict = &conflicts[0];
+    assert_eq!(conflict.fragment_id, second.id().into());
+    assert_eq!(conflict.conflicts_with, first.id().into());
+    assert!(matches!(
+        conflict.spent,
+        ConflictingSpend::Account {
+            spending_counter: 0,
+            ..
+        }
+    ));
+    assert_eq!(conflict.received_from, FragmentOrigin::Rest);
+    assert_eq!(conflict.conflicts_with_received_from, FragmentOrigin::Rest);
+    assert!(conflict.peer.is_none());
+}
@@ -0,0 +1,53 @@
+use crate::common::fixtures::{World, WorldBuilder};
+use jormungandr_lib::interfaces::FragmentsProcessingSummary;
+use reqwest::{blocking::Response, StatusCode};
+use rstest::*;
+
+#[fixture]
+fn world() -> World {
+    WorldBuilder::new().build()
+}
+
+fn summary(response: Response) -> FragmentsProcessingSummary {
+    serde_json::from_str(&response.text().unwrap()).unwrap()
+}
+
+#[rstest]
+pub fn batches_of_known_fragments_are_answered_with_409(mut world: World) {
+    let fragments = vec![world.transaction(0, 1, 100.into())];
+    let rest = world.jormungandr.rest();
+
+    let response = rest
+        .raw()
+        .send_fragment_batch(fragments.clone(), false)
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = rest.raw().send_fragment_batch(fragments, false).unwrap();
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    let summary = summary(response);
+    assert!(summary.is_already_known());
+    assert_eq!(summary.already_known(), 1);
+}
+
+#[rstest]
+pub fn batches_with_new_fragments_are_accepted(mut world: World) {
+    let known = world.transaction(0, 1, 100.into());
+    let new = world.transaction(1, 0, 100.into());
+    let rest = world.jormungandr.rest();
+
+    rest.send_fragment(known.clone()).unwrap();
+    let response = rest
+        .raw()
+        .send_fragment_batch(vec![known, new], false)
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let summary = summary(response);
+    assert!(!summary.is_already_known());
+    assert_eq!(summary.accepted.len(), 1);
+    assert_eq!(summary.already_known(), 1);
+
+    let stats = rest.stats().unwrap().stats.unwrap();
+    assert_eq!(stats.tx_duplicate_rest_cnt, 1);
+    assert_eq!(stats.tx_duplicate_network_cnt, 0);
+}
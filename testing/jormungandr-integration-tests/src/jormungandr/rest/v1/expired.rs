@@ -0,0 +1,24 @@
+use crate::common::fixtures::{World, WorldBuilder};
+use jormungandr_testing_utils::testing::FragmentSenderSetup;
+use rstest::*;
+
+#[fixture]
+fn world() -> World {
+    WorldBuilder::new().build()
+}
+
+#[rstest]
+pub fn fragments_in_blocks_are_not_reported_as_expired(mut world: World) {
+    let alice_fragment = world.transaction(0, 1, 100.into());
+    let jormungandr = world.jormungandr;
+    let transaction_sender = jormungandr.fragment_sender(FragmentSenderSetup::resend_3_times());
+
+    let check = transaction_sender
+        .send_fragment(&mut world.wallets[0], alice_fragment, &jormungandr)
+        .unwrap();
+    transaction_sender.verify(&check, &jormungandr).unwrap();
+
+    let rest = jormungandr.rest();
+    assert!(rest.expired_fragments().unwrap().is_empty());
+    assert_eq!(rest.stats().unwrap().stats.unwrap().tx_expired_cnt, 0);
+}
@@ -1,7 +1,6 @@
+use crate::common::fixtures::WorldBuilder;
 use crate::common::jormungandr::JormungandrProcess;
-use crate::common::{jormungandr::ConfigurationBuilder, startup};
-use chain_impl_mockchain::{block::BlockDate, fragment::Fragment};
-use jormungandr_testing_utils::testing::fragments::FaultyTransactionBuilder;
+use chain_impl_mockchain::fragment::Fragment;
 use jormungandr_testing_utils::testing::node::assert_bad_request;
 use jormungandr_testing_utils::testing::FragmentSenderSetup;
 use jormungandr_testing_utils::testing::FragmentVerifier;
@@ -17,66 +16,19 @@ fn world() -> (
     Fragment,
     Fragment,
 ) {
-    let mut alice = startup::create_new_account_address();
-    let mut bob = startup::create_new_account_address();
-    let mut clarice = startup::create_new_account_address();
-    let mut david = startup::create_new_account_address();
+    let mut world = WorldBuilder::new()
+        .with_wallets(3)
+        .with_unfunded_wallets(1)
+        .build();
 
-    let (jormungandr, _stake_pools) = startup::start_stake_pool(
-        &[alice.clone()],
-        &[bob.clone(), clarice.clone()],
-        &mut ConfigurationBuilder::new(),
-    )
-    .unwrap();
-
-    let alice_fragment = alice
-        .transaction_to(
-            &jormungandr.genesis_block_hash(),
-            &jormungandr.fees(),
-            BlockDate::first().next_epoch(),
-            bob.address(),
-            100.into(),
-        )
-        .unwrap();
-
-    let bob_fragment = bob
-        .transaction_to(
-            &jormungandr.genesis_block_hash(),
-            &jormungandr.fees(),
-            BlockDate::first().next_epoch(),
-            alice.address(),
-            100.into(),
-        )
-        .unwrap();
-    let clarice_fragment = clarice
-        .transaction_to(
-            &jormungandr.genesis_block_hash(),
-            &jormungandr.fees(),
-            BlockDate::first().next_epoch(),
-            alice.address(),
-            100.into(),
-        )
-        .unwrap();
-
-    let late_invalid_fragment = david
-        .transaction_to(
-            &jormungandr.genesis_block_hash(),
-            &jormungandr.fees(),
-            BlockDate::first().next_epoch(),
-            alice.address(),
-            100.into(),
-        )
-        .unwrap();
-
-    let faulty_tx_builder = FaultyTransactionBuilder::new(
-        jormungandr.genesis_block_hash(),
-        jormungandr.fees(),
-        BlockDate::first().next_epoch(),
-    );
-    let early_invalid_fragment = faulty_tx_builder.unbalanced(&alice, &bob);
+    let alice_fragment = world.transaction(0, 1, 100.into());
+    let bob_fragment = world.transaction(1, 0, 100.into());
+    let clarice_fragment = world.transaction(2, 0, 100.into());
+    let late_invalid_fragment = world.transaction(3, 0, 100.into());
+    let early_invalid_fragment = world.unbalanced_transaction(0, 1);
 
     (
-        jormungandr,
+        world.jormungandr,
         alice_fragment,
         bob_fragment,
         clarice_fragment,
@@ -1,2 +1,13 @@
+pub mod account_events;
+pub mod account_state;
+pub mod body_size;
+pub mod bootstrap;
+pub mod clock_events;
+pub mod configuration;
+pub mod conflicts;
+pub mod duplicates;
+pub mod expired;
 pub mod fail_fast;
+pub mod settings;
+pub mod stake_pools;
 pub mod statuses;
@@ -0,0 +1,32 @@
+use crate::common::fixtures::{World, WorldBuilder};
+use crate::common::jormungandr::ConfigurationBuilder;
+use jormungandr_testing_utils::testing::node::time;
+use reqwest::StatusCode;
+use rstest::*;
+
+#[fixture]
+fn world() -> World {
+    let mut config = ConfigurationBuilder::new();
+    config.with_slots_per_epoch(10).with_slot_duration(1);
+    WorldBuilder::new().with_config(config).build()
+}
+
+#[rstest]
+pub fn settings_of_past_epochs_are_served(world: World) {
+    let rest = world.jormungandr.rest();
+    time::wait_for_epoch(2, world.jormungandr.rest());
+
+    let current = rest.settings().unwrap();
+    let past = rest.settings_at(1).unwrap();
+    assert_eq!(past.block0_hash, current.block0_hash);
+    assert_eq!(past.fees, current.fees);
+    assert_eq!(past.slots_per_epoch, current.slots_per_epoch);
+    // the start of the current slot is only known for the tip
+    assert!(past.curr_slot_start_time.is_none());
+}
+
+#[rstest]
+pub fn settings_of_future_epochs_are_not_found(world: World) {
+    let response = world.jormungandr.rest().raw().settings_at(1000).unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,54 @@
+use crate::common::fixtures::{World, WorldBuilder};
+use jormungandr_lib::interfaces::BlockDate;
+use jormungandr_testing_utils::testing::node::time;
+use rstest::*;
+
+#[fixture]
+fn world() -> World {
+    WorldBuilder::new().build()
+}
+
+#[rstest]
+pub fn dashboard_of_a_pool_run_by_the_node(world: World) {
+    let rest = world.jormungandr.rest();
+    let pool_id = world.stake_pools[0].id().to_string();
+
+    let dashboard = rest.stake_pool_dashboard(&pool_id).unwrap();
+    assert!(dashboard.total_stake > 0);
+    // the owner delegates its funds to the pool in block0
+    assert!(dashboard.delegators >= 1);
+    assert!(dashboard.scheduled_slots_remaining.is_some());
+    assert!(!dashboard.pending_retirement);
+
+    let response = rest
+        .raw()
+        .stake_pool_dashboard(&hex::encode([0; 32]))
+        .unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::NOT_FOUND);
+}
+
+#[rstest]
+pub fn blocks_produced_by_a_pool_run_by_the_node(world: World) {
+    let rest = world.jormungandr.rest();
+    let pool_id = world.stake_pools[0].id().to_string();
+    time::wait_for_date(BlockDate::new(0, 5), world.jormungandr.rest());
+
+    // the pool is the only leader, so it produces every block after block0
+    let blocks = rest.stake_pool_blocks(&pool_id, None).unwrap();
+    assert!(!blocks.is_empty());
+    assert!(blocks.iter().all(|block| block.date.epoch() == 0));
+    assert!(blocks
+        .windows(2)
+        .all(|pair| pair[0].chain_length < pair[1].chain_length));
+    // the tip may have moved since, but the earlier blocks are the same
+    assert!(rest
+        .stake_pool_blocks(&pool_id, Some(0))
+        .unwrap()
+        .starts_with(&blocks));
+
+    let response = rest
+        .raw()
+        .stake_pool_blocks(&hex::encode([0; 32]), None)
+        .unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::NOT_FOUND);
+}
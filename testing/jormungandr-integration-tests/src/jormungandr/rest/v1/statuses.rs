@@ -1,47 +1,23 @@
+use crate::common::fixtures::WorldBuilder;
 use crate::common::jormungandr::JormungandrProcess;
-use crate::common::{jormungandr::ConfigurationBuilder, startup};
-use chain_impl_mockchain::{block::BlockDate, fragment::FragmentId};
+use chain_impl_mockchain::fragment::FragmentId;
 use jormungandr_testing_utils::testing::FragmentSenderSetup;
 use rstest::*;
 
 #[fixture]
 fn world() -> (JormungandrProcess, FragmentId, FragmentId, FragmentId) {
-    let mut alice = startup::create_new_account_address();
-    let mut bob = startup::create_new_account_address();
-    let mut clarice = startup::create_new_account_address();
+    let mut world = WorldBuilder::new().with_unfunded_wallets(1).build();
 
-    let (jormungandr, _stake_pools) = startup::start_stake_pool(
-        &[alice.clone()],
-        &[bob.clone()],
-        &mut ConfigurationBuilder::new(),
-    )
-    .unwrap();
+    let alice_fragment = world.transaction(0, 1, 100.into());
+    let bob_fragment = world.transaction(1, 0, 100.into());
+    let clarice_fragment = world.transaction(2, 1, 100.into());
 
+    let jormungandr = world.jormungandr;
     let transaction_sender = jormungandr.fragment_sender(FragmentSenderSetup::resend_3_times());
 
-    let alice_fragment = alice
-        .transaction_to(
-            &jormungandr.genesis_block_hash(),
-            &jormungandr.fees(),
-            BlockDate::first().next_epoch(),
-            bob.address(),
-            100.into(),
-        )
-        .unwrap();
-
-    let bob_fragment = bob
-        .transaction_to(
-            &jormungandr.genesis_block_hash(),
-            &jormungandr.fees(),
-            BlockDate::first().next_epoch(),
-            alice.address(),
-            100.into(),
-        )
-        .unwrap();
-
     let clarice_tx = transaction_sender
         .clone_with_setup(FragmentSenderSetup::no_verify())
-        .send_transaction(&mut clarice, &bob, &jormungandr, 100.into())
+        .send_fragment(&mut world.wallets[2], clarice_fragment, &jormungandr)
         .unwrap();
 
     let tx_ids = transaction_sender
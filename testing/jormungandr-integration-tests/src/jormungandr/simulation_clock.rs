@@ -0,0 +1,71 @@
+#![cfg(feature = "simulation-clock")]
+
+use crate::common::{jormungandr::ConfigurationBuilder, startup};
+use jormungandr_lib::interfaces::{ActiveSlotCoefficient, BlockDate};
+use jormungandr_testing_utils::testing::node::{time::step_to_epoch, RestError};
+use std::{
+    num::NonZeroU32,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+const SLOTS_PER_EPOCH: u32 = 10;
+const SLOT_DURATION: u8 = 5;
+
+#[test]
+pub fn simulated_clock_steps_through_epochs() {
+    let sender = startup::create_new_account_address();
+    let receiver = startup::create_new_account_address();
+
+    let (jormungandr, _) = startup::start_stake_pool(
+        &[sender],
+        &[receiver],
+        ConfigurationBuilder::new()
+            .with_slots_per_epoch(SLOTS_PER_EPOCH)
+            .with_slot_duration(SLOT_DURATION)
+            .with_consensus_genesis_praos_active_slot_coeff(ActiveSlotCoefficient::MAXIMUM)
+            .with_simulation_clock(NonZeroU32::new(1).unwrap()),
+    )
+    .unwrap();
+
+    let started = Instant::now();
+    let target_epoch = 3;
+    step_to_epoch(target_epoch, jormungandr.rest());
+
+    let last_block_date = jormungandr
+        .rest()
+        .stats()
+        .unwrap()
+        .stats
+        .unwrap()
+        .last_block_date
+        .unwrap();
+    assert!(BlockDate::from_str(&last_block_date).unwrap().epoch() >= target_epoch);
+
+    // the epochs were not waited for in real time
+    let epochs_duration =
+        Duration::from_secs(u64::from(SLOT_DURATION) * u64::from(SLOTS_PER_EPOCH * target_epoch));
+    assert!(started.elapsed() < epochs_duration);
+}
+
+#[test]
+pub fn clock_of_a_node_on_real_time_cannot_be_stepped() {
+    let sender = startup::create_new_account_address();
+    let receiver = startup::create_new_account_address();
+
+    let (jormungandr, _) = startup::start_stake_pool(
+        &[sender],
+        &[receiver],
+        ConfigurationBuilder::new()
+            .with_slots_per_epoch(SLOTS_PER_EPOCH)
+            .with_slot_duration(SLOT_DURATION),
+    )
+    .unwrap();
+
+    match jormungandr.rest().step_clock(Duration::from_secs(60)) {
+        Err(RestError::NonSuccessErrorCode { status, .. }) => {
+            assert_eq!(status, reqwest::StatusCode::NOT_FOUND)
+        }
+        other => panic!("unexpected result {:?}", other),
+    }
+}
@@ -4,9 +4,15 @@ use crate::common::{
     startup::{self},
     transaction_utils::TransactionHash,
 };
+use chain_addr::Discrimination;
+use chain_core::property::Fragment as _;
 use chain_impl_mockchain::{block::BlockDate, fee::LinearFee};
 use jormungandr_lib::interfaces::{ActiveSlotCoefficient, Mempool, Value};
-use jormungandr_testing_utils::testing::node::time::wait_for_epoch;
+use jormungandr_testing_utils::{
+    testing::{node::time::wait_for_epoch, FragmentVerifier, MemPoolCheck},
+    wallet::{multisig, Wallet},
+};
+use std::time::Duration;
 
 #[test]
 pub fn accounts_funds_are_updated_after_transaction() {
@@ -27,6 +33,10 @@ pub fn accounts_funds_are_updated_after_transaction() {
             .with_mempool(Mempool {
                 pool_max_entries: 1_000_000usize.into(),
                 log_max_entries: 1_000_000usize.into(),
+                rebroadcast_interval: None,
+                snapshot: None,
+                validity_window: None,
+                log_gc: None,
                 persistent_log: None,
             }),
     )
@@ -110,6 +120,10 @@ fn expired_transactions_rejected() {
             .with_mempool(Mempool {
                 pool_max_entries: 1_000.into(),
                 log_max_entries: 1_000.into(),
+                rebroadcast_interval: None,
+                snapshot: None,
+                validity_window: None,
+                log_gc: None,
                 persistent_log: None,
             }),
     )
@@ -170,6 +184,10 @@ fn transactions_with_long_time_to_live_rejected() {
             .with_mempool(Mempool {
                 pool_max_entries: 1_000.into(),
                 log_max_entries: 1_000.into(),
+                rebroadcast_interval: None,
+                snapshot: None,
+                validity_window: None,
+                log_gc: None,
                 persistent_log: None,
             })
             .with_tx_max_expiry_epochs(MAX_EXPIRY_EPOCHS),
@@ -214,3 +232,62 @@ fn transactions_with_long_time_to_live_rejected() {
         .send(&expired_transaction)
         .assert_rejected("Transaction validity is too far in the future");
 }
+
+#[test]
+pub fn multisig_account_spends_with_the_signatures_of_the_threshold() {
+    let receiver = startup::create_new_account_address();
+    let mut multisig =
+        multisig::Wallet::generate(&mut rand::thread_rng(), 2, 3, Discrimination::Test);
+    let jormungandr = startup::start_bft(
+        vec![&Wallet::Multisig(multisig.clone()), &receiver],
+        &mut ConfigurationBuilder::new(),
+    )
+    .unwrap();
+
+    // a single owner does not reach the threshold
+    multisig.set_signers(vec![1]);
+    let mut sender = Wallet::Multisig(multisig.clone());
+    let fragment = sender
+        .transaction_to(
+            &jormungandr.genesis_block_hash(),
+            &jormungandr.fees(),
+            BlockDate::first().next_epoch(),
+            receiver.address(),
+            100.into(),
+        )
+        .unwrap();
+    jormungandr
+        .rest()
+        .raw()
+        .send_fragment(fragment.clone())
+        .unwrap();
+    FragmentVerifier::wait_and_verify_is_rejected(
+        Duration::from_secs(10),
+        MemPoolCheck::new(fragment.id()),
+        &jormungandr,
+    )
+    .unwrap();
+
+    multisig.set_signers(vec![0, 2]);
+    let mut sender = Wallet::Multisig(multisig);
+    let fragment = sender
+        .transaction_to(
+            &jormungandr.genesis_block_hash(),
+            &jormungandr.fees(),
+            BlockDate::first().next_epoch(),
+            receiver.address(),
+            100.into(),
+        )
+        .unwrap();
+    jormungandr
+        .rest()
+        .raw()
+        .send_fragment(fragment.clone())
+        .unwrap();
+    FragmentVerifier::wait_and_verify_is_in_block(
+        Duration::from_secs(10),
+        MemPoolCheck::new(fragment.id()),
+        &jormungandr,
+    )
+    .unwrap();
+}
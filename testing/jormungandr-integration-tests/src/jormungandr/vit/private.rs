@@ -226,6 +226,16 @@ pub fn jcli_e2e_flow_private_vote() {
     let decryption_share_file = temp_dir.child("decryption_share.json");
     decryption_share_file.write_str(&decryption_shares).unwrap();
 
+    let verification: serde_json::Value = serde_json::from_str(&jcli.votes().tally().verify_share(
+        active_plans_file.path(),
+        vote_plan_id.clone(),
+        decryption_share_file.path(),
+        member_pk.clone(),
+    ))
+    .unwrap();
+    assert_eq!(verification["verified"], 1);
+    assert!(verification["invalid"].as_array().unwrap().is_empty());
+
     let merged_shares = jcli
         .votes()
         .tally()
@@ -416,6 +426,14 @@ pub fn jcli_private_vote_invalid_proof() {
     let decryption_share_file = temp_dir.child("decryption_share.json");
     decryption_share_file.write_str(&decryption_shares).unwrap();
 
+    jcli.votes().tally().verify_share_expect_fail(
+        active_plans_file.path(),
+        vote_plan_id.clone(),
+        decryption_share_file.path(),
+        member_pk,
+        "1 decryption shares are invalid",
+    );
+
     let merged_shares = jcli
         .votes()
         .tally()
@@ -30,8 +30,10 @@ use jormungandr_testing_utils::testing::asserts::VotePlanStatusAssert;
 use jormungandr_testing_utils::testing::VotePlanExtension;
 use jormungandr_testing_utils::{
     testing::{
+        fragments::FragmentExporter,
         node::time::{self, wait_for_epoch},
-        vote_plan_cert, FragmentSender, FragmentSenderSetup,
+        vote_plan_cert, FragmentSender, FragmentSenderSetup, FragmentSenderSetupBuilder,
+        TallyVerifier,
     },
     wallet::Wallet,
 };
@@ -141,6 +143,7 @@ pub fn test_vote_flow_bft() {
     let rewards_increase = 10u64;
     let initial_fund_per_wallet = 1_000_000;
     let temp_dir = TempDir::new().unwrap();
+    let dump_dir = TempDir::new().unwrap();
 
     let mut rng = OsRng;
     let mut alice = Wallet::new_account(&mut rng);
@@ -191,6 +194,8 @@ pub fn test_vote_flow_bft() {
         .start()
         .unwrap();
 
+    let mut setup = FragmentSenderSetupBuilder::from(FragmentSenderSetup::resend_3_times());
+    setup.dump_fragments_into(dump_dir.path().to_path_buf());
     let transaction_sender = FragmentSender::new(
         jormungandr.genesis_block_hash(),
         jormungandr.fees(),
@@ -198,7 +203,7 @@ pub fn test_vote_flow_bft() {
             epoch: 1,
             slot_id: 0,
         },
-        FragmentSenderSetup::resend_3_times(),
+        setup.build(),
     );
 
     transaction_sender
@@ -248,6 +253,23 @@ pub fn test_vote_flow_bft() {
         jormungandr.rest().vote_plan_statuses().unwrap(),
     );
 
+    let sent_fragments = FragmentExporter::new(dump_dir.path().to_path_buf())
+        .unwrap()
+        .read()
+        .unwrap();
+    TallyVerifier::from_fragments(&sent_fragments)
+        .voting_power_from_node(&[&alice, &bob, &clarice], &jormungandr.rest())
+        .unwrap()
+        .verify(
+            jormungandr
+                .rest()
+                .vote_plan_statuses()
+                .unwrap()
+                .first()
+                .unwrap(),
+        )
+        .unwrap();
+
     let rewards_after = jormungandr.explorer().last_block().unwrap().rewards();
 
     assert!(
@@ -395,6 +417,78 @@ pub fn test_vote_flow_praos() {
     );
 }
 
+#[test]
+pub fn votes_cast_in_one_batch_are_recorded() {
+    let yes_choice = Choice::new(1);
+    let no_choice = Choice::new(2);
+
+    let mut rng = OsRng;
+    let alice = Wallet::new_account(&mut rng);
+    let bob = Wallet::new_account(&mut rng);
+    let clarice = Wallet::new_account(&mut rng);
+
+    let vote_plan = VotePlanBuilder::new().proposals_count(3).public().build();
+
+    let vote_plan_cert = vote_plan_cert(
+        &alice,
+        chain_impl_mockchain::block::BlockDate {
+            epoch: 1,
+            slot_id: 0,
+        },
+        &vote_plan,
+    )
+    .into();
+    let mut config = ConfigurationBuilder::new();
+    config
+        .with_committees(&[&alice, &bob, &clarice])
+        .with_slots_per_epoch(60)
+        .with_certs(vec![vote_plan_cert])
+        .with_slot_duration(1);
+
+    let (jormungandr, _stake_pools) = start_stake_pool(
+        &[alice.clone()],
+        &[bob.clone(), clarice.clone()],
+        &mut config,
+    )
+    .unwrap();
+
+    let mut setup = FragmentSenderSetupBuilder::from(FragmentSenderSetup::resend_3_times());
+    setup.fail_fast();
+    let transaction_sender = FragmentSender::new(
+        jormungandr.genesis_block_hash(),
+        jormungandr.fees(),
+        chain_impl_mockchain::block::BlockDate {
+            epoch: 1,
+            slot_id: 0,
+        },
+        setup.build(),
+    );
+
+    let mut voters = vec![alice, bob, clarice];
+    let checks = transaction_sender
+        .send_vote_casts_batch(
+            &mut voters,
+            &vote_plan,
+            &[(0, yes_choice), (0, yes_choice), (1, no_choice)],
+            &jormungandr,
+        )
+        .unwrap();
+    assert_eq!(checks.len(), voters.len());
+    for check in &checks {
+        transaction_sender.verify(check, &jormungandr).unwrap();
+    }
+
+    for (voter, proposals) in voters.iter().zip(vec![vec![0], vec![0], vec![1]]) {
+        assert_eq!(
+            proposals,
+            jormungandr
+                .rest()
+                .vote_plan_account_info(vote_plan.to_id().into(), voter.address())
+                .unwrap()
+        );
+    }
+}
+
 #[test]
 pub fn jcli_e2e_flow() {
     let jcli: JCli = Default::default();
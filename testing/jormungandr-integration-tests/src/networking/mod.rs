@@ -1,4 +1,5 @@
 pub mod communication;
 pub mod p2p;
+pub mod propagation;
 pub mod stats;
 pub mod testnet;
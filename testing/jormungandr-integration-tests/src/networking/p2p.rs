@@ -4,11 +4,13 @@ use crate::common::{
 };
 
 use jormungandr_lib::{
-    interfaces::{PeerRecord, Policy, PreferredListConfig, TrustedPeer},
+    interfaces::{
+        ConnectionDirection, PeerEventKind, PeerRecord, Policy, PreferredListConfig, TrustedPeer,
+    },
     time::Duration,
 };
-use jormungandr_testing_utils::testing::FragmentNode;
-use jortestkit::process as process_utils;
+use jormungandr_testing_utils::testing::{FragmentNode, SyncNode};
+use jortestkit::{prelude::Wait, process as process_utils};
 use tracing::Level;
 
 const CLIENT: &str = "CLIENT";
@@ -466,6 +468,74 @@ fn max_bootstrap_attempts() {
     );
 }
 
+#[test]
+pub fn connections_are_recorded_in_the_peer_events() {
+    let mut network_controller = NetworkBuilder::default()
+        .single_trust_direction(CLIENT, SERVER)
+        .initials(vec![
+            WalletTemplateBuilder::new("delegated1")
+                .with(1_000_000)
+                .delegated_to(CLIENT),
+            WalletTemplateBuilder::new("delegated2")
+                .with(1_000_000)
+                .delegated_to(SERVER),
+        ])
+        .build()
+        .unwrap();
+
+    let server = network_controller.spawn_and_wait(SERVER);
+    let client = network_controller.spawn_and_wait(CLIENT);
+
+    let connected = |node: &JormungandrProcess, direction: ConnectionDirection| {
+        node.rest()
+            .network_events()
+            .expect("cannot list peer events")
+            .into_iter()
+            .find(|event| event.kind == PeerEventKind::Connected { direction })
+    };
+
+    let mut wait = Wait::new(std::time::Duration::from_secs(1), 30);
+    let outbound = loop {
+        if let Some(event) = connected(&client, ConnectionDirection::Outbound) {
+            break event;
+        }
+        wait.check_timeout()
+            .expect("the connection to the server was not recorded");
+        wait.advance();
+    };
+    assert_eq!(outbound.address, server.address());
+    assert!(connected(&server, ConnectionDirection::Inbound).is_some());
+
+    let events = server.rest().network_events().unwrap();
+    assert!(events.windows(2).all(|pair| pair[0].time <= pair[1].time));
+}
+
+#[test]
+pub fn blocks_are_applied_one_after_the_other_during_bootstrap() {
+    let mut network_controller = NetworkBuilder::default()
+        .single_trust_direction(CLIENT, SERVER)
+        .initials(vec![WalletTemplateBuilder::new("delegated")
+            .with(1_000_000)
+            .delegated_to(SERVER)])
+        .build()
+        .unwrap();
+
+    let server = network_controller.spawn_and_wait(SERVER);
+    let mut wait = Wait::new(std::time::Duration::from_secs(1), 60);
+    while server.last_block_height() < 5 {
+        wait.check_timeout()
+            .expect("the server did not produce enough blocks");
+        wait.advance();
+    }
+
+    let client = network_controller.spawn_and_wait(CLIENT);
+    let status = client.rest().bootstrap_status().unwrap();
+    assert!(status.completed);
+    assert!(status.blocks_applied >= 5);
+    assert!(client.last_block_height() >= 5);
+    client.assert_no_errors_in_log();
+}
+
 fn parse_timestamp(log: &str) -> u64 {
     let re = regex::Regex::new("([0-9]+):([0-9]+):([0-9]+)").unwrap();
 
@@ -0,0 +1,57 @@
+use crate::common::network::{NetworkBuilder, WalletTemplateBuilder};
+use chain_impl_mockchain::{block::BlockDate, chaintypes::ConsensusVersion, milli::Milli};
+use jormungandr_lib::interfaces::{
+    ActiveSlotCoefficient, KesUpdateSpeed, NumberOfSlotsPerEpoch, SlotDuration,
+};
+use jormungandr_testing_utils::testing::{
+    network_builder::Blockchain, FragmentSender, FragmentSenderSetup, PropagationVerifier,
+};
+use std::time::Duration;
+
+const PASSIVE: &str = "PASSIVE";
+const LEADER: &str = "LEADER";
+
+#[test]
+pub fn fragment_sent_to_a_passive_node_propagates_to_the_leader() {
+    let mut network_controller = NetworkBuilder::default()
+        .blockchain_config(Blockchain::new(
+            ConsensusVersion::GenesisPraos,
+            NumberOfSlotsPerEpoch::new(60).expect("valid number of slots per epoch"),
+            SlotDuration::new(2).expect("valid slot duration in seconds"),
+            KesUpdateSpeed::new(46800).expect("valid kes update speed in seconds"),
+            ActiveSlotCoefficient::new(Milli::from_millis(999))
+                .expect("active slot coefficient in millis"),
+        ))
+        .single_trust_direction(PASSIVE, LEADER)
+        .initials(vec![
+            WalletTemplateBuilder::new("alice")
+                .with(1_000_000)
+                .delegated_to(LEADER),
+            WalletTemplateBuilder::new("bob").with(1_000_000),
+        ])
+        .build()
+        .unwrap();
+
+    let leader = network_controller.spawn_and_wait(LEADER);
+    let passive = network_controller.spawn_as_passive_and_wait(PASSIVE);
+
+    let mut alice = network_controller.wallet("alice").unwrap();
+    let bob = network_controller.wallet("bob").unwrap();
+
+    let check = FragmentSender::new(
+        leader.genesis_block_hash(),
+        leader.fees(),
+        BlockDate::first().next_epoch(),
+        FragmentSenderSetup::no_verify(),
+    )
+    .send_transaction(&mut alice, &bob, &passive, 100.into())
+    .unwrap();
+
+    let report =
+        PropagationVerifier::new(Duration::from_secs(60)).measure(&check, &[&passive, &leader]);
+    report.print();
+    report.verify_in_logs(Duration::from_secs(30)).unwrap();
+    report.verify_in_block(Duration::from_secs(60)).unwrap();
+    assert_eq!(report.nodes.len(), 2);
+    assert!(report.max_in_block().unwrap() >= report.max_in_logs().unwrap());
+}
@@ -1,9 +1,12 @@
 use crate::common::jormungandr::{ConfigurationBuilder, Starter, StartupVerificationMode};
-use assert_fs::fixture::PathChild;
+use assert_fs::fixture::{PathChild, PathCreateDir};
 use assert_fs::TempDir;
 use jormungandr_lib::interfaces::ActiveSlotCoefficient;
 use jormungandr_testing_utils::testing::node::{download_last_n_releases, get_jormungandr_bin};
-use jormungandr_testing_utils::testing::{BranchCount, StopCriteria, StorageBuilder};
+use jormungandr_testing_utils::testing::{
+    measure_bootstrap_sync, BootstrapSyncThresholds, BranchCount, StopCriteria, StorageBuilder,
+    SyncNode,
+};
 use std::time::Duration;
 
 #[test]
@@ -130,3 +133,58 @@ pub fn legacy_bootstrap_from_1_gb_storage() {
         .start()
         .unwrap();
 }
+
+#[test]
+pub fn bootstrap_from_peer_with_100_blocks() {
+    let blocks_count = 100;
+    let temp_dir = TempDir::new().unwrap();
+
+    let leader_dir = temp_dir.child("leader");
+    leader_dir.create_dir_all().unwrap();
+    let leader_storage = leader_dir.child("storage");
+    let leader_config = ConfigurationBuilder::new()
+        .with_slot_duration(1)
+        .with_slots_per_epoch(60)
+        .with_consensus_genesis_praos_active_slot_coeff(ActiveSlotCoefficient::MAXIMUM)
+        .with_storage(&leader_storage)
+        .build(&leader_dir);
+
+    let leader = Starter::new()
+        .config(leader_config.clone())
+        .start()
+        .unwrap();
+
+    while leader.last_block_height() < blocks_count {
+        std::thread::sleep(Duration::from_secs(1));
+    }
+
+    let passive_dir = temp_dir.child("passive");
+    passive_dir.create_dir_all().unwrap();
+    let passive_config = ConfigurationBuilder::new()
+        .with_trusted_peers(vec![leader.to_trusted_peer()])
+        .with_block_hash(leader_config.genesis_block_hash())
+        .build(&passive_dir);
+
+    let thresholds = BootstrapSyncThresholds::new(1.0, 1_000.0, Duration::from_secs(5 * 60));
+
+    let (passive, report) = measure_bootstrap_sync(
+        &format!("bootstrap from peer with {} blocks", blocks_count),
+        &leader,
+        leader_storage.path(),
+        &thresholds,
+        || {
+            Starter::new()
+                .config(passive_config)
+                .passive()
+                .start()
+                .unwrap()
+        },
+    )
+    .unwrap();
+
+    println!("{}", report.to_json());
+    report.verify(&thresholds).unwrap();
+
+    passive.assert_no_errors_in_log();
+    leader.assert_no_errors_in_log();
+}
@@ -8,6 +8,7 @@ use jormungandr_testing_utils::testing::node::time;
 use jormungandr_testing_utils::testing::FragmentSender;
 use jormungandr_testing_utils::testing::{
     BatchFragmentGenerator, FragmentGenerator, FragmentSenderSetup, FragmentStatusProvider,
+    TpsLoadConfig, TpsLoadDriver,
 };
 pub use jortestkit::console::progress_bar::{parse_progress_bar_mode_from_str, ProgressBarMode};
 use jortestkit::load::{self, Configuration, Monitor};
@@ -61,6 +62,7 @@ pub fn fragment_load_test() {
     use crate::common::jcli::FragmentsCheck;
     use crate::common::jcli::JCli;
 
+    println!("fragment generator seed: {}", request_generator.seed());
     request_generator.prepare(BlockDateDto::new(0, 19));
 
     let jcli: JCli = Default::default();
@@ -167,3 +169,37 @@ pub fn transaction_load_test() {
         "Wallet backend load test",
     );
 }
+
+#[test]
+pub fn transaction_target_tps_load_test() {
+    let mut faucet = startup::create_new_account_address();
+
+    let (jormungandr, _) = startup::start_stake_pool(
+        &[faucet.clone()],
+        &[],
+        ConfigurationBuilder::new()
+            .with_slots_per_epoch(60)
+            .with_consensus_genesis_praos_active_slot_coeff(ActiveSlotCoefficient::MAXIMUM)
+            .with_slot_duration(2)
+            .with_epoch_stability_depth(10)
+            .with_kes_update_speed(KesUpdateSpeed::new(43200).unwrap()),
+    )
+    .unwrap();
+
+    let mut request_generator = TransactionGenerator::new(
+        FragmentSenderSetup::no_verify(),
+        jormungandr.to_remote(),
+        jormungandr.genesis_block_hash(),
+        jormungandr.fees(),
+        BlockDate::first().next_epoch(),
+    );
+    request_generator.fill_from_faucet(&mut faucet);
+
+    let config = TpsLoadConfig::new(10, Duration::from_secs(60));
+    let report =
+        TpsLoadDriver::new(config, vec![request_generator]).run(&[&jormungandr.to_remote()]);
+    report.print();
+
+    assert_eq!(report.rejected, 0, "some fragments were rejected");
+    assert_eq!(report.pending, 0, "some fragments were not put in block");
+}
@@ -27,6 +27,10 @@ pub fn persistent_log_load_test() {
             .with_mempool(Mempool {
                 pool_max_entries: 1_000_000usize.into(),
                 log_max_entries: 1_000_000usize.into(),
+                rebroadcast_interval: None,
+                snapshot: None,
+                validity_window: None,
+                log_gc: None,
                 persistent_log: Some(PersistentLog {
                     dir: persistent_log_path.path().to_path_buf(),
                 }),
@@ -25,6 +25,10 @@ pub fn test_blocks_are_being_created_for_7_hours() {
             .with_mempool(Mempool {
                 pool_max_entries: 1_000_000usize.into(),
                 log_max_entries: 1_000_000usize.into(),
+                rebroadcast_interval: None,
+                snapshot: None,
+                validity_window: None,
+                log_gc: None,
                 persistent_log: None,
             }),
     )
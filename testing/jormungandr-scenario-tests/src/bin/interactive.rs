@@ -1,8 +1,8 @@
 use jormungandr_scenario_tests::{
     programs::prepare_command,
-    scenario::{parse_progress_bar_mode_from_str, Context, ProgressBarMode, Seed},
+    scenario::{parse_progress_bar_mode_from_str, Context, ExternalNetwork, ProgressBarMode, Seed},
 };
-use std::path::PathBuf;
+use std::{net::SocketAddr, path::PathBuf};
 use structopt::StructOpt;
 
 #[derive(StructOpt, Debug)]
@@ -48,6 +48,35 @@ struct CommandArgs {
     /// level for all nodes
     #[structopt(long = "log-level", default_value = "info")]
     log_level: String,
+
+    /// path or url of block0 of an already running network. If set, no nodes
+    /// are spawned and console is attached to nodes given by `--node` option
+    #[structopt(long = "block0")]
+    block0: Option<String>,
+
+    /// rest endpoint of a node in already running network in format: alias=ip:port.
+    /// Can be used multiple times
+    #[structopt(long = "node", parse(try_from_str = parse_remote_node))]
+    nodes: Vec<(String, SocketAddr)>,
+
+    /// path to file with console commands (one per line) which are executed
+    /// non-interactively. Execution stops at first failed command and process
+    /// exits with non-zero code
+    #[structopt(long = "script")]
+    script: Option<PathBuf>,
+}
+
+fn parse_remote_node(node: &str) -> Result<(String, SocketAddr), String> {
+    let mut parts = node.splitn(2, '=');
+    let alias = parts.next().filter(|alias| !alias.is_empty());
+    let address = parts.next().and_then(|address| address.parse().ok());
+    match (alias, address) {
+        (Some(alias), Some(address)) => Ok((alias.to_string(), address)),
+        _ => Err(format!(
+            "invalid node '{}', expected format: alias=ip:port",
+            node
+        )),
+    }
 }
 
 fn main() -> Result<(), jormungandr_scenario_tests::test::Error> {
@@ -76,5 +105,27 @@ fn main() -> Result<(), jormungandr_scenario_tests::test::Error> {
     );
 
     jormungandr_scenario_tests::introduction::print(&context, "INTERACTIVE SCENARIO");
-    jormungandr_scenario_tests::interactive::interactive(context).map(|_| ())
+
+    if let Some(block0) = command_args.block0 {
+        let network = command_args
+            .nodes
+            .into_iter()
+            .fold(ExternalNetwork::new(block0), |network, (alias, address)| {
+                network.node(alias, address)
+            });
+        return jormungandr_scenario_tests::interactive::attach(
+            context,
+            network,
+            command_args.script.as_deref(),
+        )
+        .map(|_| ());
+    }
+
+    match command_args.script {
+        Some(script) => {
+            jormungandr_scenario_tests::interactive::interactive_script(context, &script)
+                .map(|_| ())
+        }
+        None => jormungandr_scenario_tests::interactive::interactive(context).map(|_| ()),
+    }
 }
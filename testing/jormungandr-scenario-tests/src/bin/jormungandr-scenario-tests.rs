@@ -1,6 +1,6 @@
 use jormungandr_scenario_tests::{
     programs::prepare_command,
-    report::Reporter,
+    report::{HtmlReport, JUnitReport, Reporter},
     scenario::{
         parse_progress_bar_mode_from_str,
         repository::{parse_tag_from_str, ScenariosRepository, Tag},
@@ -89,6 +89,15 @@ struct CommandArgs {
     /// print junit like report to output
     #[structopt(short = "r", long = "print-report")]
     report: bool,
+
+    /// write junit xml report (per scenario timing, status and node summary)
+    /// to given file
+    #[structopt(long = "junit")]
+    junit: Option<PathBuf>,
+
+    /// write static html report to given file
+    #[structopt(long = "html")]
+    html: Option<PathBuf>,
 }
 
 fn main() {
@@ -145,6 +154,18 @@ fn main() {
         reporter.print()
     }
 
+    if let Some(junit) = command_args.junit {
+        JUnitReport::new("jormungandr-scenario-tests", &scenario_suite_result)
+            .write_to(&junit)
+            .unwrap_or_else(|e| panic!("cannot write junit report to {:?}: {}", junit, e));
+    }
+
+    if let Some(html) = command_args.html {
+        HtmlReport::new("jormungandr scenario tests", &scenario_suite_result)
+            .write_to(&html)
+            .unwrap_or_else(|e| panic!("cannot write html report to {:?}: {}", html, e));
+    }
+
     if command_args.set_exit_code {
         std::process::exit(if scenario_suite_result.is_failed() {
             1
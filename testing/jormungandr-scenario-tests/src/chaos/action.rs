@@ -0,0 +1,85 @@
+use super::{Error, Result};
+use crate::node::NodeAlias;
+use std::fmt;
+use std::path::Path;
+use std::process::Command;
+use std::time::Duration;
+
+pub const DISK_FILL_FILE: &str = "chaos_disk_fill.bin";
+
+/// Single disruption which can be applied on the node by the chaos controller
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChaosAction {
+    /// kill node process with SIGKILL. Node stays down until it is restarted
+    Kill(NodeAlias),
+    /// kill node process with SIGKILL and spawn it again right away
+    Restart(NodeAlias),
+    /// suspend process with SIGSTOP for given period, then resume it with SIGCONT
+    Pause {
+        alias: NodeAlias,
+        duration: Duration,
+    },
+    /// create file of given size in node working directory
+    FillDisk { alias: NodeAlias, size_in_mb: u64 },
+    /// remove file created by `FillDisk` action
+    FreeDisk(NodeAlias),
+}
+
+impl ChaosAction {
+    pub fn alias(&self) -> &NodeAlias {
+        match self {
+            Self::Kill(alias) | Self::Restart(alias) | Self::FreeDisk(alias) => alias,
+            Self::Pause { alias, .. } | Self::FillDisk { alias, .. } => alias,
+        }
+    }
+}
+
+impl fmt::Display for ChaosAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Kill(alias) => write!(f, "kill '{}'", alias),
+            Self::Restart(alias) => write!(f, "restart '{}'", alias),
+            Self::Pause { alias, duration } => {
+                write!(f, "pause '{}' for {} s", alias, duration.as_secs())
+            }
+            Self::FillDisk { alias, size_in_mb } => {
+                write!(f, "fill disk of '{}' with {} MB", alias, size_in_mb)
+            }
+            Self::FreeDisk(alias) => write!(f, "free disk of '{}'", alias),
+        }
+    }
+}
+
+pub fn send_signal(pid: u32, signal: &str) -> Result<()> {
+    let status = Command::new("kill")
+        .arg(format!("-{}", signal))
+        .arg(pid.to_string())
+        .status()?;
+    if !status.success() {
+        return Err(Error::SignalFailed {
+            pid,
+            signal: signal.to_string(),
+        });
+    }
+    Ok(())
+}
+
+pub fn fill_disk<P: AsRef<Path>>(node_dir: P, size_in_mb: u64) -> Result<()> {
+    use std::io::Write;
+
+    let chunk = vec![0u8; 1024 * 1024];
+    let mut file = std::fs::File::create(node_dir.as_ref().join(DISK_FILL_FILE))?;
+    for _ in 0..size_in_mb {
+        file.write_all(&chunk)?;
+    }
+    file.sync_all()?;
+    Ok(())
+}
+
+pub fn free_disk<P: AsRef<Path>>(node_dir: P) -> Result<()> {
+    let path = node_dir.as_ref().join(DISK_FILL_FILE);
+    if path.exists() {
+        std::fs::remove_file(path)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,280 @@
+mod action;
+mod schedule;
+
+pub use action::{ChaosAction, DISK_FILL_FILE};
+pub use schedule::{ChaosActionKind, ChaosSchedule};
+
+use crate::{
+    node::{LeadershipMode, NodeAlias, NodeController, PersistenceMode},
+    scenario::Controller,
+};
+use jormungandr_testing_utils::testing::{
+    ensure_nodes_are_in_sync, SyncNode, SyncWaitParams, VerificationError,
+};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Node(#[from] crate::node::Error),
+    #[error(transparent)]
+    Scenario(#[from] crate::scenario::Error),
+    #[error(transparent)]
+    Verification(#[from] VerificationError),
+    #[error("cannot send signal '{signal}' to process {pid}")]
+    SignalFailed { pid: u32, signal: String },
+    #[error("node '{0}' is not managed by chaos controller")]
+    UnknownNode(NodeAlias),
+    #[error("node '{0}' is down while it should be running")]
+    NodeIsDown(NodeAlias),
+    #[error("network is not producing blocks for {} s. Block height stuck at: {height}", .duration.as_secs())]
+    NetworkIsStuck { height: u32, duration: Duration },
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+struct ChaosNode {
+    leadership_mode: LeadershipMode,
+    controller: Option<NodeController>,
+    paused_until: Option<Instant>,
+}
+
+impl ChaosNode {
+    fn is_available(&self) -> bool {
+        self.controller.is_some() && self.paused_until.is_none()
+    }
+}
+
+/// Applies disruptions (kill, restart, pause, disk fill) on the nodes according to
+/// the schedule while continuously checking that the network stays alive,
+/// and verifies that all nodes agree on the tip once the chaos is over.
+pub struct ChaosController<'a> {
+    controller: &'a mut Controller,
+    nodes: HashMap<NodeAlias, ChaosNode>,
+    persistence_mode: PersistenceMode,
+    check_interval: Duration,
+    liveness_timeout: Duration,
+    log: Vec<String>,
+}
+
+impl<'a> ChaosController<'a> {
+    pub fn new(controller: &'a mut Controller) -> Self {
+        Self {
+            controller,
+            nodes: HashMap::new(),
+            persistence_mode: PersistenceMode::Persistent,
+            check_interval: Duration::from_secs(5),
+            liveness_timeout: Duration::from_secs(120),
+            log: Vec::new(),
+        }
+    }
+
+    pub fn with_node(mut self, node: NodeController, leadership_mode: LeadershipMode) -> Self {
+        self.nodes.insert(
+            node.alias().clone(),
+            ChaosNode {
+                leadership_mode,
+                controller: Some(node),
+                paused_until: None,
+            },
+        );
+        self
+    }
+
+    /// spawn node through underlying controller and put it under chaos control
+    pub fn spawn_node(
+        &mut self,
+        alias: &str,
+        leadership_mode: LeadershipMode,
+    ) -> Result<&NodeController> {
+        self.nodes.insert(
+            alias.to_string(),
+            ChaosNode {
+                leadership_mode,
+                controller: None,
+                paused_until: None,
+            },
+        );
+        self.spawn(alias)?;
+        Ok(self.nodes[alias].controller.as_ref().unwrap())
+    }
+
+    pub fn with_check_interval(mut self, check_interval: Duration) -> Self {
+        self.check_interval = check_interval;
+        self
+    }
+
+    pub fn with_liveness_timeout(mut self, liveness_timeout: Duration) -> Self {
+        self.liveness_timeout = liveness_timeout;
+        self
+    }
+
+    /// list of actions applied so far together with the time they were applied at
+    pub fn log(&self) -> &[String] {
+        &self.log
+    }
+
+    pub fn run(&mut self, schedule: ChaosSchedule, duration: Duration) -> Result<()> {
+        let mut aliases: Vec<NodeAlias> = self.nodes.keys().cloned().collect();
+        aliases.sort();
+        let mut plan = schedule.plan(&aliases).into_iter().peekable();
+
+        let start = Instant::now();
+        let mut last_progress = Instant::now();
+        let mut best_height = self.best_height();
+
+        while start.elapsed() < duration {
+            while let Some((offset, _)) = plan.peek() {
+                if *offset > start.elapsed() {
+                    break;
+                }
+                let (offset, action) = plan.next().unwrap();
+                self.log
+                    .push(format!("[{} s] {}", offset.as_secs(), action));
+                self.apply(action)?;
+            }
+
+            self.resume_expired_pauses()?;
+            self.verify_liveness()?;
+
+            let height = self.best_height();
+            if height > best_height {
+                best_height = height;
+                last_progress = Instant::now();
+            } else if last_progress.elapsed() > self.liveness_timeout {
+                return Err(Error::NetworkIsStuck {
+                    height: best_height,
+                    duration: last_progress.elapsed(),
+                });
+            }
+
+            std::thread::sleep(self.check_interval);
+        }
+        Ok(())
+    }
+
+    pub fn apply(&mut self, action: ChaosAction) -> Result<()> {
+        let alias = action.alias().clone();
+        if !self.nodes.contains_key(&alias) {
+            return Err(Error::UnknownNode(alias));
+        }
+        let node_dir = self.controller.node_dir(&alias);
+
+        match action {
+            ChaosAction::Kill(_) => {
+                self.kill(&alias)?;
+            }
+            ChaosAction::Restart(_) => {
+                self.kill(&alias)?;
+                self.spawn(&alias)?;
+            }
+            ChaosAction::Pause { duration, .. } => {
+                let node = self.nodes.get_mut(&alias).unwrap();
+                if let Some(controller) = &node.controller {
+                    action::send_signal(controller.pid(), "STOP")?;
+                    node.paused_until = Some(Instant::now() + duration);
+                }
+            }
+            ChaosAction::FillDisk { size_in_mb, .. } => {
+                action::fill_disk(node_dir.path(), size_in_mb)?;
+            }
+            ChaosAction::FreeDisk(_) => {
+                action::free_disk(node_dir.path())?;
+            }
+        }
+        Ok(())
+    }
+
+    fn kill(&mut self, alias: &str) -> Result<()> {
+        let node = self.nodes.get_mut(alias).unwrap();
+        if let Some(controller) = node.controller.take() {
+            if node.paused_until.take().is_some() {
+                action::send_signal(controller.pid(), "CONT")?;
+            }
+            action::send_signal(controller.pid(), "KILL")?;
+        }
+        Ok(())
+    }
+
+    fn spawn(&mut self, alias: &str) -> Result<()> {
+        let leadership_mode = self.nodes.get(alias).unwrap().leadership_mode;
+        let controller =
+            self.controller
+                .spawn_node(alias, leadership_mode, self.persistence_mode)?;
+        controller.wait_for_bootstrap()?;
+        self.nodes.get_mut(alias).unwrap().controller = Some(controller);
+        Ok(())
+    }
+
+    fn resume_expired_pauses(&mut self) -> Result<()> {
+        let now = Instant::now();
+        for node in self.nodes.values_mut() {
+            if let (Some(paused_until), Some(controller)) = (node.paused_until, &node.controller) {
+                if paused_until <= now {
+                    action::send_signal(controller.pid(), "CONT")?;
+                    node.paused_until = None;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn verify_liveness(&self) -> Result<()> {
+        for (alias, node) in self.nodes.iter().filter(|(_, node)| node.is_available()) {
+            let controller = node.controller.as_ref().unwrap();
+            if !controller.check_running() {
+                return Err(Error::NodeIsDown(alias.clone()));
+            }
+        }
+        Ok(())
+    }
+
+    fn best_height(&self) -> u32 {
+        self.nodes
+            .values()
+            .filter(|node| node.is_available())
+            .filter_map(|node| node.controller.as_ref())
+            .filter(|controller| controller.is_up())
+            .map(|controller| controller.last_block_height())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Brings every node back (resumes paused ones, respawns killed ones and frees disk)
+    /// and verifies all of them converge on the same tip
+    pub fn restore_and_verify(&mut self, sync_wait: SyncWaitParams) -> Result<()> {
+        let aliases: Vec<NodeAlias> = self.nodes.keys().cloned().collect();
+        for alias in aliases {
+            let node_dir = self.controller.node_dir(&alias);
+            action::free_disk(node_dir.path())?;
+
+            let node = self.nodes.get_mut(&alias).unwrap();
+            if node.paused_until.take().is_some() {
+                if let Some(controller) = &node.controller {
+                    action::send_signal(controller.pid(), "CONT")?;
+                }
+            }
+            if node.controller.is_none() {
+                self.spawn(&alias)?;
+            }
+        }
+
+        let controllers: Vec<&NodeController> = self
+            .nodes
+            .values()
+            .filter_map(|node| node.controller.as_ref())
+            .collect();
+        ensure_nodes_are_in_sync(sync_wait, &controllers)?;
+        Ok(())
+    }
+
+    pub fn into_nodes(self) -> Vec<NodeController> {
+        self.nodes
+            .into_iter()
+            .filter_map(|(_, node)| node.controller)
+            .collect()
+    }
+}
@@ -0,0 +1,92 @@
+use super::ChaosAction;
+use crate::node::NodeAlias;
+use jormungandr_testing_utils::testing::network_builder::{Random, Seed};
+use rand::Rng;
+use rand_chacha::ChaChaRng;
+use std::time::Duration;
+
+/// Defines when and which disruptions are applied on the network
+pub enum ChaosSchedule {
+    /// actions are executed at given offsets from the start of the chaos run
+    Fixed(Vec<(Duration, ChaosAction)>),
+    /// every `interval` random action is drawn and applied on a random node
+    Random {
+        seed: Seed,
+        interval: Duration,
+        duration: Duration,
+        kinds: Vec<ChaosActionKind>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChaosActionKind {
+    Restart,
+    Pause,
+    FillDisk,
+}
+
+impl ChaosSchedule {
+    pub fn fixed() -> Self {
+        Self::Fixed(Vec::new())
+    }
+
+    pub fn random(seed: Seed, interval: Duration, duration: Duration) -> Self {
+        Self::Random {
+            seed,
+            interval,
+            duration,
+            kinds: vec![
+                ChaosActionKind::Restart,
+                ChaosActionKind::Pause,
+                ChaosActionKind::FillDisk,
+            ],
+        }
+    }
+
+    pub fn at(mut self, offset: Duration, action: ChaosAction) -> Self {
+        if let Self::Fixed(actions) = &mut self {
+            actions.push((offset, action));
+        }
+        self
+    }
+
+    /// resolve schedule into ordered list of actions for given set of nodes
+    pub fn plan(&self, aliases: &[NodeAlias]) -> Vec<(Duration, ChaosAction)> {
+        let mut plan = match self {
+            Self::Fixed(actions) => actions.clone(),
+            Self::Random {
+                seed,
+                interval,
+                duration,
+                kinds,
+            } => {
+                if aliases.is_empty() || kinds.is_empty() {
+                    return Vec::new();
+                }
+                let mut random = Random::<ChaChaRng>::new(*seed);
+                let rng = random.rng_mut();
+                let mut plan = Vec::new();
+                let mut offset = *interval;
+                while offset < *duration {
+                    let alias = aliases[rng.gen_range(0..aliases.len())].clone();
+                    let action = match kinds[rng.gen_range(0..kinds.len())] {
+                        ChaosActionKind::Restart => ChaosAction::Restart(alias),
+                        ChaosActionKind::Pause => ChaosAction::Pause {
+                            alias,
+                            duration: Duration::from_secs(rng.gen_range(5..30)),
+                        },
+                        ChaosActionKind::FillDisk => ChaosAction::FillDisk {
+                            alias,
+                            size_in_mb: rng.gen_range(10..100),
+                        },
+                    };
+                    plan.push((offset, action));
+                    offset += *interval;
+                }
+                plan
+            }
+        };
+        plan.sort_by_key(|(offset, _)| *offset);
+        plan
+    }
+}
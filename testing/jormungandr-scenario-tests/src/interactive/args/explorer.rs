@@ -1,22 +1,62 @@
 use super::UserInteractionController;
-use crate::test::Result;
+use crate::{style, test::Result};
+use jormungandr_lib::crypto::hash::Hash;
+use jormungandr_testing_utils::testing::node::Explorer as ExplorerClient;
 use jortestkit::prelude::InteractiveCommandError;
+use std::fmt::Debug;
+use std::str::FromStr;
 use structopt::StructOpt;
 
 #[derive(StructOpt, Debug)]
 pub enum Explorer {
-    /// Sends transaction
+    /// Prints last block
     Tip(ExplorerTip),
+    /// Prints last blocks
+    Blocks(ExplorerBlocks),
+    /// Prints blocks at given chain length
+    Block(ExplorerBlock),
+    /// Prints transaction details
+    Transaction(ExplorerTransaction),
+    /// Prints registered stake pools
+    StakePools(ExplorerStakePools),
+    /// Prints stake pool details
+    StakePool(ExplorerStakePool),
 }
 
 impl Explorer {
     pub fn exec(&self, controller: &mut UserInteractionController) -> Result<()> {
         match self {
             Explorer::Tip(tip) => tip.exec(controller),
+            Explorer::Blocks(blocks) => blocks.exec(controller),
+            Explorer::Block(block) => block.exec(controller),
+            Explorer::Transaction(transaction) => transaction.exec(controller),
+            Explorer::StakePools(stake_pools) => stake_pools.exec(controller),
+            Explorer::StakePool(stake_pool) => stake_pool.exec(controller),
         }
     }
 }
 
+fn explorer(controller: &UserInteractionController, alias: &str) -> Result<ExplorerClient> {
+    let node = controller
+        .nodes()
+        .iter()
+        .find(|x| *x.alias() == alias)
+        .ok_or_else(|| InteractiveCommandError::UserError(format!("Node '{}' not found", alias)))?;
+    let mut explorer = node.explorer();
+    explorer.disable_logs();
+    Ok(explorer)
+}
+
+fn print_response<T: Debug, E: Debug>(data: Option<T>, errors: Option<E>) {
+    if let Some(errors) = errors {
+        println!("{}", style::error.apply_to(format!("{:#?}", errors)));
+    }
+    match data {
+        Some(data) => println!("{:#?}", data),
+        None => println!("{}", style::warning.apply_to("no data")),
+    }
+}
+
 #[derive(StructOpt, Debug)]
 pub struct ExplorerTip {
     #[structopt(short = "a", long = "alias")]
@@ -25,14 +65,98 @@ pub struct ExplorerTip {
 
 impl ExplorerTip {
     pub fn exec(&self, controller: &mut UserInteractionController) -> Result<()> {
-        let node = controller
-            .nodes
-            .iter()
-            .find(|x| *x.alias() == self.alias)
-            .ok_or_else(|| {
-                InteractiveCommandError::UserError(format!("Node '{}' not found", self.alias))
-            })?;
-        println!("{:#?}", node.explorer().last_block()?);
+        println!("{:#?}", explorer(controller, &self.alias)?.last_block()?);
+        Ok(())
+    }
+}
+
+#[derive(StructOpt, Debug)]
+pub struct ExplorerBlocks {
+    #[structopt(short = "a", long = "alias")]
+    pub alias: String,
+    #[structopt(short = "l", long = "limit", default_value = "10")]
+    pub limit: i64,
+}
+
+impl ExplorerBlocks {
+    pub fn exec(&self, controller: &mut UserInteractionController) -> Result<()> {
+        let response = explorer(controller, &self.alias)?.blocks(self.limit)?;
+        print_response(response.data, response.errors);
+        Ok(())
+    }
+}
+
+#[derive(StructOpt, Debug)]
+pub struct ExplorerBlock {
+    #[structopt(short = "a", long = "alias")]
+    pub alias: String,
+    #[structopt(short = "c", long = "chain-length")]
+    pub chain_length: u32,
+}
+
+impl ExplorerBlock {
+    pub fn exec(&self, controller: &mut UserInteractionController) -> Result<()> {
+        let response =
+            explorer(controller, &self.alias)?.blocks_at_chain_length(self.chain_length)?;
+        print_response(response.data, response.errors);
+        Ok(())
+    }
+}
+
+#[derive(StructOpt, Debug)]
+pub struct ExplorerTransaction {
+    #[structopt(short = "a", long = "alias")]
+    pub alias: String,
+    #[structopt(short = "i", long = "id")]
+    pub id: String,
+}
+
+impl ExplorerTransaction {
+    pub fn exec(&self, controller: &mut UserInteractionController) -> Result<()> {
+        let id = Hash::from_str(&self.id).map_err(|err| {
+            InteractiveCommandError::UserError(format!(
+                "invalid transaction id '{}': {}",
+                self.id, err
+            ))
+        })?;
+        let response = explorer(controller, &self.alias)?.transaction(id)?;
+        print_response(response.data, response.errors);
+        Ok(())
+    }
+}
+
+#[derive(StructOpt, Debug)]
+pub struct ExplorerStakePools {
+    #[structopt(short = "a", long = "alias")]
+    pub alias: String,
+    #[structopt(short = "l", long = "limit", default_value = "10")]
+    pub limit: i64,
+}
+
+impl ExplorerStakePools {
+    pub fn exec(&self, controller: &mut UserInteractionController) -> Result<()> {
+        let response = explorer(controller, &self.alias)?.stake_pools(self.limit)?;
+        print_response(response.data, response.errors);
+        Ok(())
+    }
+}
+
+#[derive(StructOpt, Debug)]
+pub struct ExplorerStakePool {
+    #[structopt(short = "a", long = "alias")]
+    pub alias: String,
+    #[structopt(short = "i", long = "id")]
+    pub id: String,
+    /// limit of registrations and retirements to print
+    #[structopt(short = "l", long = "limit", default_value = "10")]
+    pub limit: i64,
+}
+
+impl ExplorerStakePool {
+    pub fn exec(&self, controller: &mut UserInteractionController) -> Result<()> {
+        let response =
+            explorer(controller, &self.alias)?.stake_pool(self.id.clone(), self.limit)?;
+        print_response(response.data, response.errors);
         Ok(())
     }
 }
@@ -2,7 +2,7 @@ use crate::{legacy::LegacyNodeController, test::Result};
 use crate::{node::NodeController, scenario::Controller};
 use chain_impl_mockchain::vote::Choice;
 use jormungandr_lib::interfaces::Value;
-use jormungandr_testing_utils::wallet::Wallet;
+use jormungandr_testing_utils::{testing::RemoteJormungandr, wallet::Wallet};
 use jortestkit::prelude::InteractiveCommandError;
 use structopt::{clap::AppSettings, StructOpt};
 
@@ -11,6 +11,8 @@ pub mod explorer;
 pub mod send;
 pub mod show;
 pub mod spawn;
+pub mod stop;
+pub mod vote;
 
 pub struct UserInteractionController {
     controller: Controller,
@@ -53,6 +55,10 @@ impl UserInteractionController {
         &mut self.nodes
     }
 
+    pub fn remote_nodes(&self) -> &[RemoteJormungandr] {
+        self.controller.remote_nodes()
+    }
+
     pub fn controller(&self) -> &Controller {
         &self.controller
     }
@@ -81,23 +87,27 @@ impl UserInteractionController {
 
         let node = self.nodes.iter().find(|x| x.alias() == node_alias);
         let legacy_node = self.legacy_nodes.iter().find(|x| x.alias() == node_alias);
+        let remote_node = self.controller.remote_node(node_alias).ok();
 
-        let check = match (node, legacy_node) {
-            (Some(node), None) => self.controller.fragment_sender().send_public_vote_tally(
-                committee,
-                &vote_plan_def.into(),
-                node,
-            )?,
-            (None, Some(node)) => self.controller.fragment_sender().send_public_vote_tally(
-                committee,
-                &vote_plan_def.into(),
-                node,
-            )?,
-            _ => Err(InteractiveCommandError::UserError(format!(
-                "alias not found {}",
-                node_alias
-            )))?,
-        };
+        let check =
+            match (node, legacy_node, remote_node) {
+                (Some(node), None, None) => self
+                    .controller
+                    .fragment_sender()
+                    .send_public_vote_tally(committee, &vote_plan_def.into(), node)?,
+                (None, Some(node), None) => self
+                    .controller
+                    .fragment_sender()
+                    .send_public_vote_tally(committee, &vote_plan_def.into(), node)?,
+                (None, None, Some(node)) => self
+                    .controller
+                    .fragment_sender()
+                    .send_public_vote_tally(committee, &vote_plan_def.into(), node)?,
+                _ => Err(InteractiveCommandError::UserError(format!(
+                    "alias not found {}",
+                    node_alias
+                )))?,
+            };
 
         *self.wallets_mut() = temp_wallets;
         Ok(check)
@@ -125,16 +135,24 @@ impl UserInteractionController {
 
         let node = self.nodes.iter().find(|x| x.alias() == node_alias);
         let legacy_node = self.legacy_nodes.iter().find(|x| x.alias() == node_alias);
+        let remote_node = self.controller.remote_node(node_alias).ok();
 
-        let check = match (node, legacy_node) {
-            (Some(node), None) => self.controller.fragment_sender().send_vote_cast(
+        let check = match (node, legacy_node, remote_node) {
+            (Some(node), None, None) => self.controller.fragment_sender().send_vote_cast(
+                wallet,
+                &vote_plan_def.into(),
+                proposal_index as u8,
+                &Choice::new(choice),
+                node,
+            )?,
+            (None, Some(node), None) => self.controller.fragment_sender().send_vote_cast(
                 wallet,
                 &vote_plan_def.into(),
                 proposal_index as u8,
                 &Choice::new(choice),
                 node,
             )?,
-            (None, Some(node)) => self.controller.fragment_sender().send_vote_cast(
+            (None, None, Some(node)) => self.controller.fragment_sender().send_vote_cast(
                 wallet,
                 &vote_plan_def.into(),
                 proposal_index as u8,
@@ -179,13 +197,18 @@ impl UserInteractionController {
 
         let node = self.nodes.iter().find(|x| x.alias() == node_alias);
         let legacy_node = self.legacy_nodes.iter().find(|x| x.alias() == node_alias);
+        let remote_node = self.controller.remote_node(node_alias).ok();
 
-        let check = match (node, legacy_node) {
-            (Some(node), None) => self
+        let check = match (node, legacy_node, remote_node) {
+            (Some(node), None, None) => self
+                .controller
+                .fragment_sender()
+                .send_transaction(from, &to, node, value)?,
+            (None, Some(node), None) => self
                 .controller
                 .fragment_sender()
                 .send_transaction(from, &to, node, value)?,
-            (None, Some(node)) => self
+            (None, None, Some(node)) => self
                 .controller
                 .fragment_sender()
                 .send_transaction(from, &to, node, value)?,
@@ -211,6 +234,8 @@ pub enum InteractiveCommand {
     Show(show::Show),
     /// Spawn leader or passive node (also legacy)
     Spawn(spawn::Spawn),
+    /// Stops running node (also legacy)
+    Stop(stop::Stop),
     /// Sends Explorer queries
     Explorer(explorer::Explorer),
     /// Exit interactive mode
@@ -219,14 +244,22 @@ pub enum InteractiveCommand {
     Describe(describe::Describe),
     /// send fragments
     Send(send::Send),
+    /// Casts votes, tallies vote plans and prints their status
+    Vote(vote::Vote),
 }
 
-fn do_for_all_alias<F: Fn(&NodeController), G: Fn(&LegacyNodeController)>(
+fn do_for_all_alias<
+    F: Fn(&NodeController),
+    G: Fn(&LegacyNodeController),
+    H: Fn(&RemoteJormungandr),
+>(
     alias: &Option<String>,
     nodes: &[NodeController],
     legacy_nodes: &[LegacyNodeController],
+    remote_nodes: &[RemoteJormungandr],
     f: F,
     g: G,
+    h: H,
 ) {
     if let Some(alias) = alias {
         if let Some(node) = nodes.iter().find(|x| *x.alias() == *alias) {
@@ -235,6 +268,9 @@ fn do_for_all_alias<F: Fn(&NodeController), G: Fn(&LegacyNodeController)>(
         if let Some(node) = legacy_nodes.iter().find(|x| *x.alias() == *alias) {
             g(node)
         }
+        if let Some(node) = remote_nodes.iter().find(|x| *x.alias() == *alias) {
+            h(node)
+        }
         return;
     }
 
@@ -244,4 +280,7 @@ fn do_for_all_alias<F: Fn(&NodeController), G: Fn(&LegacyNodeController)>(
     for node in legacy_nodes.iter() {
         g(node);
     }
+    for node in remote_nodes.iter() {
+        h(node);
+    }
 }
@@ -1,12 +1,11 @@
-mod cast;
-mod tally;
 mod tx;
 
-use cast::CastVote;
-use tally::VoteTally;
 use tx::SendTransaction;
 
-use super::UserInteractionController;
+use super::{
+    vote::{CastVote, VoteTally},
+    UserInteractionController,
+};
 use crate::test::Result;
 use structopt::StructOpt;
 
@@ -14,9 +13,9 @@ use structopt::StructOpt;
 pub enum Send {
     /// Sends transaction
     Tx(SendTransaction),
-    /// Tally the vote
+    /// Tally the vote (same as `vote tally`)
     Tally(VoteTally),
-    /// Send the vote
+    /// Send the vote (same as `vote cast`)
     Vote(CastVote),
 }
 
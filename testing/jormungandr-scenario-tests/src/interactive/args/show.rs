@@ -1,5 +1,8 @@
 use super::{do_for_all_alias, UserInteractionController};
-use jormungandr_testing_utils::testing::node::{JormungandrLogger, LogLevel};
+use jormungandr_testing_utils::testing::{
+    node::{JormungandrLogger, LogLevel},
+    SyncNode,
+};
 use structopt::StructOpt;
 
 #[derive(StructOpt, Debug)]
@@ -74,8 +77,10 @@ impl ActiveVotePlans {
             &self.alias,
             controller.nodes(),
             controller.legacy_nodes(),
+            controller.remote_nodes(),
             |node| println!("{}: {:#?}", node.alias(), node.vote_plans()),
             |node| println!("{}: {:#?}", node.alias(), node.vote_plans()),
+            |node| println!("{}: {:#?}", node.alias(), node.rest().vote_plan_statuses()),
         )
     }
 }
@@ -98,6 +103,8 @@ impl ShowStatus {
             &self.alias,
             controller.nodes(),
             controller.legacy_nodes(),
+            controller.remote_nodes(),
+            |node| println!("{} is up", node.alias()),
             |node| println!("{} is up", node.alias()),
             |node| println!("{} is up", node.alias()),
         )
@@ -110,6 +117,7 @@ impl ShowFragmentCount {
             &self.alias,
             controller.nodes(),
             controller.legacy_nodes(),
+            controller.remote_nodes(),
             |node| {
                 println!(
                     "{}: {:#?}",
@@ -118,6 +126,13 @@ impl ShowFragmentCount {
                 )
             },
             |node| println!("{}: {}", node.alias(), node.fragment_logs().unwrap().len()),
+            |node| {
+                println!(
+                    "{}: {}",
+                    node.alias(),
+                    node.rest().fragment_logs().unwrap().len()
+                )
+            },
         )
     }
 }
@@ -128,6 +143,7 @@ impl ShowFragments {
             &self.alias,
             controller.nodes(),
             controller.legacy_nodes(),
+            controller.remote_nodes(),
             |node| println!("{}: {:#?}", node.alias(), node.fragment_logs().unwrap()),
             |node| {
                 println!(
@@ -136,6 +152,13 @@ impl ShowFragments {
                     node.fragment_logs().unwrap().len()
                 )
             },
+            |node| {
+                println!(
+                    "{}: {:#?}",
+                    node.alias(),
+                    node.rest().fragment_logs().unwrap()
+                )
+            },
         )
     }
 }
@@ -146,6 +169,7 @@ impl ShowBlockHeight {
             &self.alias,
             controller.nodes(),
             controller.legacy_nodes(),
+            controller.remote_nodes(),
             |node| {
                 println!(
                     "{}: {:?}",
@@ -160,6 +184,7 @@ impl ShowBlockHeight {
                     node.stats().unwrap()["stats"]["last_block_height"].to_owned()
                 )
             },
+            |node| println!("{}: {}", node.alias(), node.last_block_height()),
         )
     }
 }
@@ -170,6 +195,8 @@ impl ShowPeerStats {
             &self.alias,
             controller.nodes(),
             controller.legacy_nodes(),
+            controller.remote_nodes(),
+            |node| println!("{} is up", node.alias()),
             |node| println!("{} is up", node.alias()),
             |node| println!("{} is up", node.alias()),
         )
@@ -182,8 +209,10 @@ impl ShowNodeStats {
             &self.alias,
             controller.nodes(),
             controller.legacy_nodes(),
+            controller.remote_nodes(),
             |node| println!("{}: {:#?}", node.alias(), node.stats()),
             |node| println!("{}: {:#?}", node.alias(), node.stats()),
+            |node| println!("{}: {:#?}", node.alias(), node.rest().stats()),
         )
     }
 }
@@ -233,6 +262,7 @@ impl ShowLogs {
             &self.alias,
             controller.nodes(),
             controller.legacy_nodes(),
+            controller.remote_nodes(),
             |node| {
                 show_logs_for(
                     self.only_errors,
@@ -251,6 +281,7 @@ impl ShowLogs {
                     node.logger(),
                 )
             },
+            |node| println!("{}: log not available", node.alias()),
         )
     }
 }
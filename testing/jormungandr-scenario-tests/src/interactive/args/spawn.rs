@@ -1,5 +1,6 @@
 use super::UserInteractionController;
 use crate::{style, test::Result};
+use jormungandr_lib::time::Duration;
 use jormungandr_testing_utils::{
     testing::{
         network_builder::{LeadershipMode, PersistenceMode, SpawnParams},
@@ -8,7 +9,9 @@ use jormungandr_testing_utils::{
     Version,
 };
 use jortestkit::console::InteractiveCommandError;
+use std::path::PathBuf;
 use structopt::StructOpt;
+use tracing::Level;
 
 #[derive(StructOpt, Debug)]
 pub enum Spawn {
@@ -35,6 +38,8 @@ pub struct SpawnPassiveNode {
     pub wait: bool,
     #[structopt(short = "a", long = "alias")]
     pub alias: String,
+    #[structopt(flatten)]
+    pub config: SpawnConfig,
 }
 
 impl SpawnPassiveNode {
@@ -46,6 +51,7 @@ impl SpawnPassiveNode {
             &self.alias,
             self.legacy.as_ref().map(|x| Version::parse(x).unwrap()),
             self.wait,
+            &self.config,
         )
     }
 }
@@ -60,6 +66,58 @@ pub struct SpawnLeaderNode {
     pub wait: bool,
     #[structopt(short = "a", long = "alias")]
     pub alias: String,
+    #[structopt(flatten)]
+    pub config: SpawnConfig,
+}
+
+/// Node specific settings which override the defaults shared by all nodes in scenario
+#[derive(StructOpt, Debug)]
+pub struct SpawnConfig {
+    /// maximum number of entries in the mempool
+    #[structopt(long = "pool-max-entries")]
+    pub pool_max_entries: Option<usize>,
+    /// maximum number of entries in the fragment logs
+    #[structopt(long = "log-max-entries")]
+    pub log_max_entries: Option<usize>,
+    /// directory for the persistent log of all incoming fragments
+    #[structopt(long = "persistent-log")]
+    pub persistent_log: Option<PathBuf>,
+    #[structopt(long = "log-level")]
+    pub log_level: Option<Level>,
+    /// "json" or "plain". Logs in other format than json cannot be filtered by `show logs`
+    #[structopt(long = "log-format")]
+    pub log_format: Option<String>,
+    /// gossip interval, e.g. "10s"
+    #[structopt(long = "gossip-interval")]
+    pub gossip_interval: Option<Duration>,
+    #[structopt(long = "max-connections")]
+    pub max_connections: Option<u32>,
+}
+
+impl SpawnConfig {
+    pub fn apply(&self, spawn_params: &mut SpawnParams) {
+        if let Some(pool_max_entries) = self.pool_max_entries {
+            spawn_params.pool_max_entries(pool_max_entries);
+        }
+        if let Some(log_max_entries) = self.log_max_entries {
+            spawn_params.log_max_entries(log_max_entries);
+        }
+        if let Some(persistent_log) = &self.persistent_log {
+            spawn_params.persistent_fragment_log(persistent_log);
+        }
+        if let Some(log_level) = self.log_level {
+            spawn_params.log_level(log_level);
+        }
+        if let Some(log_format) = &self.log_format {
+            spawn_params.log_format(log_format);
+        }
+        if let Some(gossip_interval) = self.gossip_interval {
+            spawn_params.gossip_interval(gossip_interval);
+        }
+        if let Some(max_connections) = self.max_connections {
+            spawn_params.max_connections(max_connections);
+        }
+    }
 }
 
 fn spawn_node(
@@ -69,6 +127,7 @@ fn spawn_node(
     alias: &str,
     legacy: Option<Version>,
     wait: bool,
+    config: &SpawnConfig,
 ) -> Result<()> {
     let persistence_mode = {
         if storage {
@@ -82,6 +141,7 @@ fn spawn_node(
     spawn_params
         .persistence_mode(persistence_mode)
         .leadership_mode(leadership_mode);
+    config.apply(&mut spawn_params);
 
     if let Some(version) = legacy {
         let releases = download_last_n_releases(5);
@@ -147,6 +207,7 @@ impl SpawnLeaderNode {
             &self.alias,
             self.legacy.as_ref().map(|x| Version::parse(x).unwrap()),
             self.wait,
+            &self.config,
         )
     }
 }
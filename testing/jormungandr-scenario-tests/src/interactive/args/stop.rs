@@ -0,0 +1,42 @@
+use super::UserInteractionController;
+use crate::{style, test::Result};
+use jortestkit::console::InteractiveCommandError;
+use structopt::StructOpt;
+
+#[derive(StructOpt, Debug)]
+pub struct Stop {
+    #[structopt(short = "a", long = "alias")]
+    pub alias: String,
+}
+
+impl Stop {
+    pub fn exec(&self, controller: &mut UserInteractionController) -> Result<()> {
+        if let Some(index) = controller
+            .nodes()
+            .iter()
+            .position(|x| *x.alias() == self.alias)
+        {
+            let node = controller.nodes_mut().remove(index);
+            node.shutdown()?;
+        } else if let Some(index) = controller
+            .legacy_nodes()
+            .iter()
+            .position(|x| *x.alias() == self.alias)
+        {
+            let node = controller.legacy_nodes_mut().remove(index);
+            node.shutdown()?;
+        } else {
+            return Err(InteractiveCommandError::UserError(format!(
+                "alias not found {}",
+                self.alias
+            ))
+            .into());
+        }
+
+        println!(
+            "{}",
+            style::info.apply_to(format!("node '{}' stopped", self.alias))
+        );
+        Ok(())
+    }
+}
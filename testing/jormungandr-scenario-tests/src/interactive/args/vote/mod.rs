@@ -0,0 +1,45 @@
+mod cast;
+mod status;
+mod tally;
+
+pub use cast::CastVote;
+pub use status::VotePlanStatus;
+pub use tally::VoteTally;
+
+use super::UserInteractionController;
+use crate::test::Result;
+use structopt::StructOpt;
+
+#[derive(StructOpt, Debug)]
+pub enum Vote {
+    /// Casts vote on proposal
+    Cast(CastVote),
+    /// Tally the vote
+    Tally(VoteTally),
+    /// Vote plan related queries
+    Plan(VotePlan),
+}
+
+impl Vote {
+    pub fn exec(&self, controller: &mut UserInteractionController) -> Result<()> {
+        match self {
+            Vote::Cast(cast_vote) => cast_vote.exec(controller),
+            Vote::Tally(vote_tally) => vote_tally.exec(controller),
+            Vote::Plan(vote_plan) => vote_plan.exec(controller),
+        }
+    }
+}
+
+#[derive(StructOpt, Debug)]
+pub enum VotePlan {
+    /// Prints vote plan status (proposals, votes cast and tally) as seen by node
+    Status(VotePlanStatus),
+}
+
+impl VotePlan {
+    pub fn exec(&self, controller: &mut UserInteractionController) -> Result<()> {
+        match self {
+            VotePlan::Status(status) => status.exec(controller),
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use super::UserInteractionController;
+use crate::{style, test::Result};
+use chain_impl_mockchain::certificate::VotePlan;
+use jormungandr_lib::{crypto::hash::Hash, interfaces::VotePlanStatus as VotePlanStatusDto};
+use jortestkit::console::InteractiveCommandError;
+use structopt::StructOpt;
+
+#[derive(StructOpt, Debug)]
+pub struct VotePlanStatus {
+    /// vote plan alias. If not provided, all vote plans are printed
+    #[structopt(short = "p", long = "vote-plan")]
+    pub vote_plan: Option<String>,
+    #[structopt(short = "v", long = "via")]
+    pub via: String,
+}
+
+impl VotePlanStatus {
+    pub fn exec(&self, controller: &mut UserInteractionController) -> Result<()> {
+        let statuses = self.statuses(controller)?;
+
+        let statuses = match &self.vote_plan {
+            Some(alias) => {
+                let vote_plan: VotePlan = controller.controller().vote_plan(alias)?.into();
+                let id: Hash = vote_plan.to_id().into();
+                let statuses: Vec<VotePlanStatusDto> =
+                    statuses.into_iter().filter(|x| x.id == id).collect();
+                if statuses.is_empty() {
+                    return Err(InteractiveCommandError::UserError(format!(
+                        "vote plan '{}' not found on node '{}'",
+                        alias, self.via
+                    ))
+                    .into());
+                }
+                statuses
+            }
+            None => statuses,
+        };
+
+        for status in statuses {
+            println!(
+                "{}",
+                style::info.apply_to(format!(
+                    "vote plan {} (start: {}, end: {}, committee end: {})",
+                    status.id, status.vote_start, status.vote_end, status.committee_end
+                ))
+            );
+            for proposal in status.proposals {
+                println!(
+                    "\t{}. {} - votes cast: {}, tally: {:?}",
+                    proposal.index, proposal.proposal_id, proposal.votes_cast, proposal.tally
+                );
+            }
+        }
+        Ok(())
+    }
+
+    fn statuses(&self, controller: &UserInteractionController) -> Result<Vec<VotePlanStatusDto>> {
+        if let Some(node) = controller.nodes().iter().find(|x| *x.alias() == self.via) {
+            return Ok(node.vote_plans()?);
+        }
+        if let Some(node) = controller
+            .remote_nodes()
+            .iter()
+            .find(|x| *x.alias() == self.via)
+        {
+            return node.rest().vote_plan_statuses().map_err(|err| {
+                InteractiveCommandError::UserError(format!(
+                    "cannot get vote plans from '{}': {}",
+                    self.via, err
+                ))
+                .into()
+            });
+        }
+        if let Some(node) = controller
+            .legacy_nodes()
+            .iter()
+            .find(|x| *x.alias() == self.via)
+        {
+            return Err(InteractiveCommandError::UserError(format!(
+                "vote plan status is not supported for legacy node '{}', use 'show vote-plans' instead",
+                node.alias()
+            ))
+            .into());
+        }
+        Err(InteractiveCommandError::UserError(format!("alias not found {}", self.via)).into())
+    }
+}
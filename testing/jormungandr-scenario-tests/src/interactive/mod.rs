@@ -1,8 +1,9 @@
 pub mod args;
+mod script;
 
 pub use crate::interactive::args::{InteractiveCommand, UserInteractionController};
 use crate::{
-    scenario::{repository::ScenarioResult, Context},
+    scenario::{repository::ScenarioResult, Context, Controller, ExternalNetwork},
     test::Result,
 };
 use function_name::named;
@@ -10,12 +11,46 @@ use jortestkit::prelude::{
     ConsoleWriter, InteractiveCommandError, InteractiveCommandExec, UserInteraction,
 };
 use rand_chacha::ChaChaRng;
-use std::ffi::OsStr;
+pub use script::Script;
+use std::{ffi::OsStr, path::Path};
 use structopt::StructOpt;
 
 #[named]
-pub fn interactive(mut context: Context<ChaChaRng>) -> Result<ScenarioResult> {
+pub fn interactive(context: Context<ChaChaRng>) -> Result<ScenarioResult> {
     let name = function_name!();
+    let controller = interactive_controller(name, context)?;
+    run_console(controller)?;
+    Ok(ScenarioResult::passed(name))
+}
+
+/// Executes console commands from script file against the same network
+/// as `interactive` scenario. Fails on first command which failed
+pub fn interactive_script(context: Context<ChaChaRng>, script: &Path) -> Result<ScenarioResult> {
+    let name = "interactive_script";
+    let script = Script::from_file(script)?;
+    let controller = interactive_controller(name, context)?;
+    run_script(controller, &script)?;
+    Ok(ScenarioResult::passed(name))
+}
+
+/// Runs interactive console (or script if provided) against already running network,
+/// without spawning any node
+pub fn attach(
+    context: Context<ChaChaRng>,
+    network: ExternalNetwork,
+    script: Option<&Path>,
+) -> Result<ScenarioResult> {
+    let name = "attach";
+    let script = script.map(Script::from_file).transpose()?;
+    let controller = Controller::attach(name, context, network)?;
+    match script {
+        Some(script) => run_script(controller, &script)?,
+        None => run_console(controller)?,
+    }
+    Ok(ScenarioResult::passed(name))
+}
+
+fn interactive_controller(name: &str, mut context: Context<ChaChaRng>) -> Result<Controller> {
     let scenario_settings = prepare_scenario! {
         name,
         &mut context,
@@ -39,7 +74,10 @@ pub fn interactive(mut context: Context<ChaChaRng>) -> Result<ScenarioResult> {
         }
     };
 
-    let controller = scenario_settings.build(context).unwrap();
+    Ok(scenario_settings.build(context)?)
+}
+
+fn run_console(controller: Controller) -> Result<()> {
     let user_integration = jormungandr_user_interaction();
 
     let mut interactive_commands = JormungandrInteractiveCommandExec {
@@ -48,8 +86,17 @@ pub fn interactive(mut context: Context<ChaChaRng>) -> Result<ScenarioResult> {
 
     user_integration.interact(&mut interactive_commands)?;
     interactive_commands.tear_down();
+    Ok(())
+}
 
-    Ok(ScenarioResult::passed(name))
+fn run_script(controller: Controller, script: &Script) -> Result<()> {
+    let mut interactive_commands = JormungandrInteractiveCommandExec {
+        controller: UserInteractionController::new(controller),
+    };
+
+    let result = script.run(&mut interactive_commands);
+    interactive_commands.tear_down();
+    result
 }
 
 fn jormungandr_user_interaction() -> UserInteraction {
@@ -74,6 +121,22 @@ pub struct JormungandrInteractiveCommandExec {
 }
 
 impl JormungandrInteractiveCommandExec {
+    pub fn exec(&mut self, interactive: InteractiveCommand) -> Result<()> {
+        match interactive {
+            InteractiveCommand::Show(show) => {
+                show.exec(&mut self.controller);
+                Ok(())
+            }
+            InteractiveCommand::Spawn(spawn) => spawn.exec(&mut self.controller),
+            InteractiveCommand::Stop(stop) => stop.exec(&mut self.controller),
+            InteractiveCommand::Exit => Ok(()),
+            InteractiveCommand::Describe(describe) => describe.exec(&mut self.controller),
+            InteractiveCommand::Send(send) => send.exec(&mut self.controller),
+            InteractiveCommand::Vote(vote) => vote.exec(&mut self.controller),
+            InteractiveCommand::Explorer(explorer) => explorer.exec(&mut self.controller),
+        }
+    }
+
     pub fn tear_down(self) {
         self.controller.finalize();
     }
@@ -87,23 +150,7 @@ impl InteractiveCommandExec for JormungandrInteractiveCommandExec {
     ) -> std::result::Result<(), InteractiveCommandError> {
         match InteractiveCommand::from_iter_safe(&mut tokens.iter().map(|x| OsStr::new(x))) {
             Ok(interactive) => {
-                if let Err(err) = {
-                    match interactive {
-                        InteractiveCommand::Show(show) => {
-                            show.exec(&mut self.controller);
-                            Ok(())
-                        }
-                        InteractiveCommand::Spawn(spawn) => spawn.exec(&mut self.controller),
-                        InteractiveCommand::Exit => Ok(()),
-                        InteractiveCommand::Describe(describe) => {
-                            describe.exec(&mut self.controller)
-                        }
-                        InteractiveCommand::Send(send) => send.exec(&mut self.controller),
-                        InteractiveCommand::Explorer(explorer) => {
-                            explorer.exec(&mut self.controller)
-                        }
-                    }
-                } {
+                if let Err(err) = self.exec(interactive) {
                     console.format_error(InteractiveCommandError::UserError(err.to_string()));
                 }
             }
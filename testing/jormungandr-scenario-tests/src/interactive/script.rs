@@ -0,0 +1,54 @@
+use super::{InteractiveCommand, JormungandrInteractiveCommandExec};
+use crate::test::{Error, Result};
+use std::ffi::OsStr;
+use std::path::Path;
+use structopt::StructOpt;
+
+/// Sequence of interactive console commands, one per line. Empty lines
+/// and lines starting with '#' are ignored
+#[derive(Debug, Clone)]
+pub struct Script {
+    lines: Vec<(usize, String)>,
+}
+
+impl Script {
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let content = std::fs::read_to_string(path)?;
+        Ok(Self::parse(&content))
+    }
+
+    pub fn parse(content: &str) -> Self {
+        let lines = content
+            .lines()
+            .enumerate()
+            .map(|(index, line)| (index + 1, line.trim().to_string()))
+            .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+            .collect();
+        Self { lines }
+    }
+
+    /// Executes commands in order and stops at first failure or at `exit` command
+    pub fn run(&self, commands: &mut JormungandrInteractiveCommandExec) -> Result<()> {
+        for (line, command) in &self.lines {
+            println!("> {}", command);
+            let script_error = |message: String| Error::ScriptCommandFailed {
+                line: *line,
+                command: command.clone(),
+                message,
+            };
+
+            let interactive =
+                InteractiveCommand::from_iter_safe(command.split_whitespace().map(OsStr::new))
+                    .map_err(|err| script_error(err.to_string()))?;
+
+            if let InteractiveCommand::Exit = interactive {
+                return Ok(());
+            }
+
+            commands
+                .exec(interactive)
+                .map_err(|err| script_error(err.to_string()))?;
+        }
+        Ok(())
+    }
+}
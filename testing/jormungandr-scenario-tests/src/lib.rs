@@ -1,3 +1,4 @@
+pub mod chaos;
 pub mod introduction;
 pub mod legacy;
 pub mod node;
@@ -2,6 +2,7 @@
 
 use crate::{
     legacy::{LegacyNode, LegacySettings},
+    report::{FragmentStatistics, NodeReport, NodeReports},
     scenario::ProgressBarMode,
     style, Context,
 };
@@ -26,7 +27,8 @@ pub use jormungandr_testing_utils::testing::{
     },
     node::{
         grpc::{client::MockClientError, JormungandrClient},
-        uri_from_socket_addr, JormungandrLogger, JormungandrRest, RestError,
+        uri_from_socket_addr, JormungandrLogger, JormungandrRest, LogLevel, NetworkConditionsProxy,
+        RestError,
     },
     FragmentNode, MemPoolCheck, NamedProcess,
 };
@@ -36,6 +38,7 @@ use indicatif::ProgressBar;
 use rand_core::RngCore;
 use std::net::SocketAddr;
 
+use std::cell::Cell;
 use std::collections::HashMap;
 use std::io::{self, BufRead, BufReader};
 use std::path::{Path, PathBuf};
@@ -161,6 +164,12 @@ pub struct NodeController {
     status: Arc<Mutex<Status>>,
     process_id: u32,
     logger: JormungandrLogger,
+    leadership_mode: LeadershipMode,
+    persistence_mode: PersistenceMode,
+    network_conditions_proxy: Option<NetworkConditionsProxy>,
+    dir: PathBuf,
+    reports: NodeReports,
+    fragment_statistics: Cell<Option<FragmentStatistics>>,
 }
 
 /// Node is going to be used by the `Controller` to monitor the node process
@@ -169,20 +178,23 @@ pub struct NodeController {
 pub struct Node {
     alias: NodeAlias,
 
-    #[allow(unused)]
     dir: PathBuf,
+    reports: NodeReports,
 
     process: Child,
 
     progress_bar: ProgressBarController,
     node_settings: NodeSetting,
     status: Arc<Mutex<Status>>,
+    leadership_mode: LeadershipMode,
+    persistence_mode: PersistenceMode,
 }
 
 const NODE_CONFIG: &str = "node_config.yaml";
 const NODE_SECRET: &str = "node_secret.yaml";
 const NODE_TOPOLOGY_KEY: &str = "node_topology_key";
 const NODE_STORAGE: &str = "storage.db";
+const NODE_LOG: &str = "node.log";
 
 impl NodeController {
     pub fn alias(&self) -> &NodeAlias {
@@ -197,6 +209,24 @@ impl NodeController {
         self.status() == Status::Running
     }
 
+    pub fn pid(&self) -> u32 {
+        self.process_id
+    }
+
+    pub fn leadership_mode(&self) -> LeadershipMode {
+        self.leadership_mode
+    }
+
+    pub fn persistence_mode(&self) -> PersistenceMode {
+        self.persistence_mode
+    }
+
+    /// route incoming p2p traffic through the proxy, which is stopped together with the controller
+    pub fn with_network_conditions_proxy(mut self, proxy: NetworkConditionsProxy) -> Self {
+        self.network_conditions_proxy = Some(proxy);
+        self
+    }
+
     fn path(&self, path: &str) -> String {
         format!("{}/{}", self.base_url(), path)
     }
@@ -485,6 +515,11 @@ impl NodeController {
     }
 
     pub fn shutdown(&self) -> Result<()> {
+        if let Ok(fragment_logs) = self.fragment_logs() {
+            self.fragment_statistics
+                .set(Some(FragmentStatistics::from_logs(&fragment_logs)));
+        }
+
         let result = self.get("shutdown")?.text()?;
 
         if result.is_empty() {
@@ -510,6 +545,23 @@ impl NodeController {
     pub fn log_content(&self) -> String {
         self.logger().get_log_content()
     }
+
+    fn write_log_file(&self) -> Option<PathBuf> {
+        let log_file = self.dir.join(NODE_LOG);
+        std::fs::write(&log_file, self.log_content()).ok()?;
+        Some(log_file)
+    }
+}
+
+impl Drop for NodeController {
+    fn drop(&mut self) {
+        self.reports.push(NodeReport {
+            alias: self.alias().to_string(),
+            log_file: self.write_log_file(),
+            error_lines: self.logger().get_lines_with_level(LogLevel::ERROR).count(),
+            fragments: self.fragment_statistics.get(),
+        });
+    }
 }
 
 impl Node {
@@ -534,6 +586,12 @@ impl Node {
             status: self.status,
             progress_bar: self.progress_bar,
             process_id: self.process.id(),
+            leadership_mode: self.leadership_mode,
+            persistence_mode: self.persistence_mode,
+            network_conditions_proxy: None,
+            dir: self.dir,
+            reports: self.reports,
+            fragment_statistics: Cell::new(None),
         }
     }
 
@@ -686,12 +744,15 @@ pub struct SpawnBuilder<'a, R: RngCore, N> {
 
 impl<'a, R: RngCore, N> SpawnBuilder<'a, R, N> {
     pub fn new(context: &'a Context<R>, node_settings: &'a mut NodeSetting) -> Self {
-        // Ensure that logs are set in JSON format and output to stdout
-        // when spawning a new Node.
-        let format = "json";
-        let level = context.log_level();
+        // Ensure that logs are output to stdout when spawning a new Node.
+        // Unless overridden per node (see SpawnParams), logs are set in JSON
+        // format with the level taken from context.
+        let (format, level) = match &node_settings.config.log {
+            Some(Log(log)) => (log.format.clone(), log.level.clone()),
+            None => ("json".to_string(), context.log_level()),
+        };
         node_settings.config.log = Some(Log(LogEntry {
-            format: format.to_string(),
+            format,
             level,
             output: LogOutput::Stdout,
         }));
@@ -848,10 +909,16 @@ impl<'a, R: RngCore> SpawnBuilder<'a, R, Node> {
         let node = Node {
             alias: self.alias.clone(),
             dir,
+            reports: self.context.node_reports(),
             process,
             progress_bar,
             node_settings: self.node_settings.clone(),
             status: Arc::new(Mutex::new(Status::Running)),
+            leadership_mode: match self.block0 {
+                NodeBlock0::File(_) => LeadershipMode::Leader,
+                NodeBlock0::Hash(_) => LeadershipMode::Passive,
+            },
+            persistence_mode: self.peristence_mode,
         };
 
         node.progress_bar_start();
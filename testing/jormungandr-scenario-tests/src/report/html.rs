@@ -0,0 +1,112 @@
+use super::escape;
+use crate::scenario::repository::{ScenarioResult, ScenarioStatus, ScenarioSuiteResult};
+use std::fmt::Write as _;
+use std::path::Path;
+
+const STYLE: &str = "body { font-family: sans-serif; margin: 2em; }
+table { border-collapse: collapse; margin-bottom: 1em; }
+th, td { border: 1px solid #ccc; padding: 4px 8px; text-align: left; vertical-align: top; }
+.passed { color: #2e7d32; }
+.failed { color: #c62828; }
+.ignored { color: #757575; }
+pre { white-space: pre-wrap; margin: 0; }";
+
+/// Static, self contained html page summarizing scenario suite run
+pub struct HtmlReport<'a> {
+    title: String,
+    scenario_suite_result: &'a ScenarioSuiteResult,
+}
+
+impl<'a> HtmlReport<'a> {
+    pub fn new<S: Into<String>>(title: S, scenario_suite_result: &'a ScenarioSuiteResult) -> Self {
+        Self {
+            title: title.into(),
+            scenario_suite_result,
+        }
+    }
+
+    pub fn write_to<P: AsRef<Path>>(&self, path: P) -> std::io::Result<()> {
+        std::fs::write(path, self.to_html())
+    }
+
+    pub fn to_html(&self) -> String {
+        let mut html = String::new();
+        writeln!(
+            html,
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n<style>\n{style}\n</style>\n</head>\n<body>\n<h1>{title}</h1>",
+            title = escape(&self.title),
+            style = STYLE
+        )
+        .unwrap();
+        writeln!(
+            html,
+            "<p>{}</p>",
+            escape(&self.scenario_suite_result.result_string())
+        )
+        .unwrap();
+
+        html.push_str(
+            "<table>\n<tr><th>scenario</th><th>status</th><th>duration</th><th>nodes</th></tr>\n",
+        );
+        for result in self.scenario_suite_result.results() {
+            write_scenario_row(&mut html, result);
+        }
+        html.push_str("</table>\n</body>\n</html>\n");
+        html
+    }
+}
+
+fn write_scenario_row(html: &mut String, result: &ScenarioResult) {
+    let (class, status) = match result.scenario_status() {
+        ScenarioStatus::Passed => ("passed", "passed".to_string()),
+        ScenarioStatus::Ignored => ("ignored", "ignored".to_string()),
+        ScenarioStatus::Failed(reason) => ("failed", format!("failed: {}", reason)),
+    };
+
+    writeln!(
+        html,
+        "<tr><td>{}</td><td class=\"{}\"><pre>{}</pre></td><td>{:.3}s</td><td>",
+        escape(&result.name()),
+        class,
+        escape(&status),
+        result.duration().as_secs_f64()
+    )
+    .unwrap();
+
+    if !result.nodes().is_empty() {
+        html.push_str("<table>\n<tr><th>node</th><th>errors</th><th>in block</th><th>pending</th><th>rejected</th><th>log</th></tr>\n");
+        for node in result.nodes() {
+            let fragments = node
+                .fragments
+                .map(|fragments| {
+                    [fragments.in_block, fragments.pending, fragments.rejected]
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_else(|| vec!["-".to_string(); 3]);
+            let log = node
+                .log_file
+                .as_ref()
+                .map(|log_file| {
+                    let log_file = escape(&log_file.display().to_string());
+                    format!("<a href=\"file://{0}\">{0}</a>", log_file)
+                })
+                .unwrap_or_else(|| "-".to_string());
+            writeln!(
+                html,
+                "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+                escape(&node.alias),
+                node.error_lines,
+                fragments[0],
+                fragments[1],
+                fragments[2],
+                log
+            )
+            .unwrap();
+        }
+        html.push_str("</table>\n");
+    }
+
+    html.push_str("</td></tr>\n");
+}
@@ -0,0 +1,100 @@
+use super::escape;
+use crate::scenario::repository::{ScenarioResult, ScenarioStatus, ScenarioSuiteResult};
+use std::fmt::Write as _;
+use std::path::Path;
+use std::time::Duration;
+
+/// JUnit XML report, understood by most of the CI servers
+pub struct JUnitReport<'a> {
+    suite_name: String,
+    scenario_suite_result: &'a ScenarioSuiteResult,
+}
+
+impl<'a> JUnitReport<'a> {
+    pub fn new<S: Into<String>>(
+        suite_name: S,
+        scenario_suite_result: &'a ScenarioSuiteResult,
+    ) -> Self {
+        Self {
+            suite_name: suite_name.into(),
+            scenario_suite_result,
+        }
+    }
+
+    pub fn write_to<P: AsRef<Path>>(&self, path: P) -> std::io::Result<()> {
+        std::fs::write(path, self.to_xml())
+    }
+
+    pub fn to_xml(&self) -> String {
+        let results = self.scenario_suite_result.results();
+        let total_duration: Duration = results.iter().map(ScenarioResult::duration).sum();
+
+        let attributes = format!(
+            "name=\"{}\" tests=\"{}\" failures=\"{}\" skipped=\"{}\" time=\"{:.3}\"",
+            escape(&self.suite_name),
+            results.len(),
+            self.scenario_suite_result.count_failed(),
+            self.scenario_suite_result.count_ignored(),
+            total_duration.as_secs_f64(),
+        );
+
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        writeln!(xml, "<testsuites {}>", attributes).unwrap();
+        writeln!(xml, "  <testsuite {}>", attributes).unwrap();
+
+        for result in results {
+            write_test_case(&mut xml, &self.suite_name, result);
+        }
+
+        xml.push_str("  </testsuite>\n</testsuites>\n");
+        xml
+    }
+}
+
+fn write_test_case(xml: &mut String, suite_name: &str, result: &ScenarioResult) {
+    writeln!(
+        xml,
+        "    <testcase name=\"{}\" classname=\"{}\" time=\"{:.3}\">",
+        escape(&result.name()),
+        escape(suite_name),
+        result.duration().as_secs_f64()
+    )
+    .unwrap();
+
+    match result.scenario_status() {
+        ScenarioStatus::Passed => {}
+        ScenarioStatus::Ignored => xml.push_str("      <skipped/>\n"),
+        ScenarioStatus::Failed(reason) => {
+            writeln!(
+                xml,
+                "      <failure message=\"{}\">{}</failure>",
+                escape(reason),
+                escape(reason)
+            )
+            .unwrap();
+        }
+    }
+
+    if !result.nodes().is_empty() {
+        xml.push_str("      <system-out>");
+        for node in result.nodes() {
+            let mut line = format!("{}: {} error line(s)", node.alias, node.error_lines);
+            if let Some(fragments) = &node.fragments {
+                write!(
+                    line,
+                    ", fragments: {} in block, {} pending, {} rejected",
+                    fragments.in_block, fragments.pending, fragments.rejected
+                )
+                .unwrap();
+            }
+            if let Some(log_file) = &node.log_file {
+                write!(line, ", log: {}", log_file.display()).unwrap();
+            }
+            xml.push_str(&escape(&line));
+            xml.push('\n');
+        }
+        xml.push_str("</system-out>\n");
+    }
+
+    xml.push_str("    </testcase>\n");
+}
@@ -1,3 +1,11 @@
+mod html;
+mod junit;
+mod node;
+
+pub use html::HtmlReport;
+pub use junit::JUnitReport;
+pub use node::{FragmentStatistics, NodeReport, NodeReports};
+
 use crate::scenario::repository::{ScenarioStatus, ScenarioSuiteResult};
 
 use json::object;
@@ -99,3 +107,19 @@ impl Reporter {
         }
     }
 }
+
+/// escapes characters which cannot appear verbatim in xml/html text and attributes
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
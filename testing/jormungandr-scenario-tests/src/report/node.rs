@@ -0,0 +1,58 @@
+use chain_impl_mockchain::fragment::FragmentId;
+use jormungandr_lib::interfaces::FragmentLog;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+/// Fragment counts as seen by the node, grouped by status
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FragmentStatistics {
+    pub pending: usize,
+    pub in_block: usize,
+    pub rejected: usize,
+}
+
+impl FragmentStatistics {
+    pub fn from_logs(fragment_logs: &HashMap<FragmentId, FragmentLog>) -> Self {
+        let mut statistics = Self::default();
+        for fragment_log in fragment_logs.values() {
+            if fragment_log.is_in_a_block() {
+                statistics.in_block += 1;
+            } else if fragment_log.is_rejected() {
+                statistics.rejected += 1;
+            } else {
+                statistics.pending += 1;
+            }
+        }
+        statistics
+    }
+
+    pub fn total(&self) -> usize {
+        self.pending + self.in_block + self.rejected
+    }
+}
+
+/// Summary of a single node run, recorded when node controller is dropped
+#[derive(Debug, Clone)]
+pub struct NodeReport {
+    pub alias: String,
+    pub log_file: Option<PathBuf>,
+    pub error_lines: usize,
+    /// collected on node shutdown, missing if node was not shut down gracefully
+    pub fragments: Option<FragmentStatistics>,
+}
+
+/// Node reports collected during single scenario run. Shared between
+/// the scenario context and all the nodes spawned within it
+#[derive(Debug, Clone, Default)]
+pub struct NodeReports(Arc<Mutex<Vec<NodeReport>>>);
+
+impl NodeReports {
+    pub fn push(&self, report: NodeReport) {
+        self.0.lock().unwrap().push(report);
+    }
+
+    pub fn take(&self) -> Vec<NodeReport> {
+        std::mem::take(&mut *self.0.lock().unwrap())
+    }
+}
@@ -9,7 +9,7 @@ use std::path::{Path, PathBuf};
 use std::sync::atomic::{self, AtomicU16};
 use std::sync::Arc;
 
-use crate::scenario::ProgressBarMode;
+use crate::{report::NodeReports, scenario::ProgressBarMode};
 use jormungandr_testing_utils::testing::network_builder::{Random, Seed};
 
 pub type ContextChaCha = Context<ChaChaRng>;
@@ -36,6 +36,7 @@ pub struct Context<RNG: RngCore + Sized = ChaChaRng> {
     generate_documentation: bool,
     progress_bar_mode: ProgressBarMode,
     log_level: String,
+    node_reports: NodeReports,
 }
 
 impl Context<ChaChaRng> {
@@ -66,11 +67,13 @@ impl Context<ChaChaRng> {
             generate_documentation,
             progress_bar_mode,
             log_level,
+            node_reports: NodeReports::default(),
         }
     }
 
     /// derive the Context into a new context, seeding a new RNG from the original
-    /// Context (so reproducibility is still available).
+    /// Context (so reproducibility is still available). Node reports are shared
+    /// with the original Context.
     pub fn derive(&mut self) -> Self {
         let seed = Seed::generate(self.rng.rng_mut());
         let rng = Random::<ChaChaRng>::new(seed);
@@ -85,6 +88,7 @@ impl Context<ChaChaRng> {
             generate_documentation: self.generate_documentation,
             progress_bar_mode: self.progress_bar_mode,
             log_level: self.log_level.clone(),
+            node_reports: self.node_reports.clone(),
         }
     }
 
@@ -120,6 +124,10 @@ impl<RNG: RngCore> Context<RNG> {
         self.log_level.clone()
     }
 
+    pub fn node_reports(&self) -> NodeReports {
+        self.node_reports.clone()
+    }
+
     pub fn generate_new_rest_listen_address(&mut self) -> SocketAddr {
         use std::net::{IpAddr, Ipv4Addr};
 
@@ -3,13 +3,14 @@ use crate::{
     legacy::{LegacyNode, LegacyNodeController},
     prepare_command,
     scenario::{
-        settings::{Dotifier, PrepareSettings},
+        settings::{Dotifier, PrepareNodeSettings, PrepareSettings},
         ContextChaCha, Error, ProgressBarMode, Result,
     },
     style, Node, NodeBlock0, NodeController,
 };
 use assert_fs::fixture::ChildPath;
 use assert_fs::prelude::*;
+use chain_core::property::Fragment as _;
 use chain_impl_mockchain::block::BlockDate;
 use chain_impl_mockchain::certificate::{VoteAction, VotePlan};
 use chain_impl_mockchain::header::HeaderId;
@@ -19,26 +20,31 @@ use chain_impl_mockchain::ledger::governance::{
 use chain_impl_mockchain::testing::scenario::template::{
     ProposalDefBuilder, VotePlanDef, VotePlanDefBuilder,
 };
+use chain_impl_mockchain::vote::Choice;
 use indicatif::{MultiProgress, ProgressBar};
-use jormungandr_lib::crypto::hash::Hash;
+use jormungandr_lib::{crypto::hash::Hash, interfaces::ConsensusLeaderId, multiaddr};
 use jormungandr_testing_utils::{
     stake_pool::StakePool,
     testing::{
         benchmark_consumption,
         fragments::DummySyncNode,
         network_builder::{
-            Blockchain, LeadershipMode, NodeAlias, NodeSetting, PersistenceMode, SpawnParams,
-            Topology, Wallet as WalletSetting, WalletAlias,
+            Blockchain, LeadershipMode, NodeAlias, NodeSetting, Partition, PersistenceMode,
+            SpawnParams, Topology, Wallet as WalletSetting, WalletAlias,
         },
-        ConsumptionBenchmarkRun, FragmentSender, FragmentSenderSetup, FragmentSenderSetupBuilder,
-        SyncNode,
+        node::NetworkConditionsProxy,
+        ConsumptionBenchmarkRun, FragmentBuilder, FragmentNode, FragmentSender,
+        FragmentSenderSetup, FragmentSenderSetupBuilder, FragmentVerifier, MemPoolCheck,
+        RemoteJormungandr, SyncNode,
     },
     wallet::Wallet,
     Version,
 };
 use std::{
+    collections::HashMap,
     path::{Path, PathBuf},
     sync::Arc,
+    time::Duration,
 };
 
 pub struct ControllerBuilder {
@@ -65,6 +71,8 @@ pub struct Controller {
 
     topology: Topology,
     blockchain: Blockchain,
+
+    remote_nodes: Vec<RemoteJormungandr>,
 }
 
 impl ControllerBuilder {
@@ -163,7 +171,7 @@ impl ControllerBuilder {
 }
 
 impl Controller {
-    fn new(
+    pub(super) fn new(
         settings: Settings,
         context: ContextChaCha,
         working_directory: ChildPath,
@@ -190,6 +198,7 @@ impl Controller {
             working_directory,
             blockchain,
             topology,
+            remote_nodes: Vec::new(),
         })
     }
 
@@ -201,6 +210,26 @@ impl Controller {
         }
     }
 
+    /// true if controller was attached to an external network, see `Controller::attach`
+    pub fn is_attached(&self) -> bool {
+        !self.remote_nodes.is_empty()
+    }
+
+    pub fn add_remote_node(&mut self, node: RemoteJormungandr) {
+        self.remote_nodes.push(node);
+    }
+
+    pub fn remote_nodes(&self) -> &[RemoteJormungandr] {
+        &self.remote_nodes
+    }
+
+    pub fn remote_node(&self, alias: &str) -> Result<&RemoteJormungandr> {
+        self.remote_nodes
+            .iter()
+            .find(|node| node.alias() == alias)
+            .ok_or_else(|| Error::NodeNotFound(alias.to_string()))
+    }
+
     pub fn working_directory(&self) -> &ChildPath {
         &self.working_directory
     }
@@ -292,6 +321,107 @@ impl Controller {
         wallets
     }
 
+    /// Casts through `node` the votes declared in the wallet templates. The
+    /// vote plans have to be in their voting phase.
+    pub fn cast_declared_votes<A: FragmentNode + SyncNode + Sized + Send>(
+        &mut self,
+        node: &A,
+    ) -> Result<Vec<MemPoolCheck>> {
+        let mut aliases: Vec<WalletAlias> = self
+            .settings
+            .network_settings
+            .wallets
+            .iter()
+            .filter(|(_, wallet)| !wallet.template().votes().is_empty())
+            .map(|(alias, _)| alias.clone())
+            .collect();
+        aliases.sort();
+
+        let mut checks = Vec::new();
+        for alias in aliases {
+            let mut wallet = self.wallet(&alias)?;
+            let votes = self.settings.network_settings.wallets[&alias]
+                .template()
+                .votes()
+                .to_vec();
+            for vote in votes {
+                let vote_plan: VotePlan = self.vote_plan(&vote.vote_plan)?.into();
+                checks.push(self.fragment_sender().send_vote_cast(
+                    &mut wallet,
+                    &vote_plan,
+                    vote.proposal_index,
+                    &Choice::new(vote.choice),
+                    node,
+                )?);
+                self.settings
+                    .network_settings
+                    .wallets
+                    .get_mut(&alias)
+                    .unwrap()
+                    .confirm_transaction();
+            }
+        }
+        Ok(checks)
+    }
+
+    /// Proposes through `node` the planned BFT rotation `alias` on behalf of
+    /// the leader `proposer`, then, once the proposal is in a block, votes for
+    /// it on behalf of each of the `voters`. The rotation happens at the start
+    /// of the epoch following the one in which more than half of the current
+    /// leaders voted for it.
+    pub fn propose_bft_rotation<A: FragmentNode + ?Sized>(
+        &self,
+        alias: &str,
+        proposer: &str,
+        voters: &[&str],
+        node: &A,
+    ) -> Result<Vec<MemPoolCheck>> {
+        let changes = self
+            .settings
+            .network_settings
+            .bft_rotations
+            .get(alias)
+            .cloned()
+            .ok_or_else(|| Error::BftRotationNotFound(alias.to_owned()))?;
+
+        let builder = FragmentBuilder::new(
+            &Hash::from_hash(self.block0_hash),
+            &self
+                .settings
+                .network_settings
+                .block0
+                .blockchain_configuration
+                .linear_fees,
+            BlockDate::first().next_epoch(),
+        );
+
+        let proposal = builder.update_proposal(&self.bft_leader_id(proposer)?, changes);
+        let proposal_id = proposal.id();
+        let check = node.send_fragment(proposal)?;
+        FragmentVerifier::wait_and_verify_is_in_block(Duration::from_secs(2), check, node)?;
+
+        let mut checks = Vec::new();
+        for voter in voters {
+            let vote = builder.update_vote(proposal_id, &self.bft_leader_id(voter)?);
+            checks.push(node.send_fragment(vote)?);
+        }
+        Ok(checks)
+    }
+
+    fn bft_leader_id(&self, node_alias: &str) -> Result<ConsensusLeaderId> {
+        let node = self
+            .settings
+            .network_settings
+            .nodes
+            .get(node_alias)
+            .ok_or_else(|| Error::NodeNotFound(node_alias.to_owned()))?;
+        node.secret
+            .bft
+            .as_ref()
+            .map(|bft| bft.signing_key.identifier().into())
+            .ok_or_else(|| Error::NotABftLeader(node_alias.to_owned()))
+    }
+
     pub fn topology(&self) -> &Topology {
         &self.topology
     }
@@ -308,6 +438,10 @@ impl Controller {
         self.progress_bar.add(pb)
     }
 
+    pub fn block0_hash(&self) -> Hash {
+        Hash::from_hash(self.block0_hash)
+    }
+
     pub fn block0_file(&self) -> PathBuf {
         self.block0_file.clone()
     }
@@ -337,7 +471,7 @@ impl Controller {
         spawn_params
     }
 
-    fn node_dir(&self, alias: &str) -> ChildPath {
+    pub fn node_dir(&self, alias: &str) -> ChildPath {
         self.working_directory.child(alias)
     }
 
@@ -399,6 +533,23 @@ impl Controller {
         let mut node_setting_overriden = node_setting.clone();
         params.override_settings(&mut node_setting_overriden.config);
 
+        // node listens on a new port, while the proxy takes over its public address,
+        // so all peers reach the node through the proxy
+        let network_conditions_proxy = match params.get_network_conditions() {
+            Some(conditions) => {
+                let p2p = &mut node_setting_overriden.config.p2p;
+                let proxy_address = multiaddr::to_tcp_socket_addr(&p2p.public_address).unwrap();
+                let node_address = self.context.generate_new_rest_listen_address();
+                p2p.listen = Some(node_address);
+                Some(NetworkConditionsProxy::start(
+                    proxy_address,
+                    node_address,
+                    conditions,
+                )?)
+            }
+            None => None,
+        };
+
         // remove all id from trusted peers for current version
         for trusted_peer in node_setting_overriden.config.p2p.trusted_peers.iter_mut() {
             trusted_peer.id = None;
@@ -430,7 +581,11 @@ impl Controller {
         }
         let node = spawn_builder.build()?;
 
-        Ok(node.controller())
+        let controller = node.controller();
+        Ok(match network_conditions_proxy {
+            Some(proxy) => controller.with_network_conditions_proxy(proxy),
+            None => controller,
+        })
     }
 
     pub fn spawn_node(
@@ -451,12 +606,124 @@ impl Controller {
         leadership_mode: LeadershipMode,
         persistence_mode: PersistenceMode,
     ) -> Result<NodeController> {
+        let alias = node.alias().clone();
         node.shutdown()?;
-        let new_node = self.spawn_node(node.alias(), leadership_mode, persistence_mode)?;
+        drop(node);
+        let new_node = self.spawn_node(&alias, leadership_mode, persistence_mode)?;
         new_node.wait_for_bootstrap()?;
         Ok(new_node)
     }
 
+    /// Splits running network into isolated groups of nodes. All nodes are shut down and
+    /// then spawned again with trusted peers limited to the members of their own group,
+    /// so no connection can cross the partition boundary until `heal_partition` is called.
+    pub fn partition(
+        &mut self,
+        nodes: Vec<NodeController>,
+        partition: &Partition,
+    ) -> Result<Vec<NodeController>> {
+        let mut spawn_params = Vec::new();
+        for node in nodes.iter() {
+            let trusted_peers = partition
+                .trusted_peers(node.alias(), &self.settings.network_settings.nodes)
+                .ok_or_else(|| Error::NodeNotFound(node.alias().to_string()))?;
+            let mut params = self.respawn_params(node);
+            params
+                .skip_bootstrap(trusted_peers.is_empty())
+                .bootstrap_from_peers(!trusted_peers.is_empty())
+                .trusted_peers(trusted_peers);
+            spawn_params.push(params);
+        }
+        self.respawn_nodes(nodes, spawn_params)
+    }
+
+    /// Adds the nodes of `topology_delta` to the running network and spawns
+    /// them as passive nodes, waiting for each of them to bootstrap. The
+    /// delta is built with `TopologyBuilder::build_on(controller.topology())`
+    /// so that the new nodes can trust the nodes already in the network.
+    pub fn grow(&mut self, topology_delta: Topology) -> Result<Vec<NodeController>> {
+        let spawn_params = self
+            .extend_topology(topology_delta)
+            .iter()
+            .map(|alias| {
+                let mut params = self.new_spawn_params(alias);
+                params
+                    .leadership_mode(LeadershipMode::Passive)
+                    .persistence_mode(PersistenceMode::InMemory);
+                params
+            })
+            .collect();
+        self.spawn_nodes(spawn_params)
+    }
+
+    /// Generates the settings of the nodes of `topology_delta` without
+    /// spawning them, returns their aliases. Use it instead of `grow` to
+    /// spawn the new nodes with custom parameters.
+    pub fn extend_topology(&mut self, topology_delta: Topology) -> Vec<NodeAlias> {
+        let nodes: HashMap<NodeAlias, NodeSetting> = topology_delta
+            .clone()
+            .into_iter()
+            .map(|(alias, template)| {
+                (
+                    alias.clone(),
+                    NodeSetting::prepare(alias, &mut self.context, &template),
+                )
+            })
+            .collect();
+        let mut aliases: Vec<NodeAlias> = nodes.keys().cloned().collect();
+        aliases.sort();
+
+        self.settings
+            .network_settings
+            .add_nodes(nodes, self.context.random());
+        self.topology.extend(topology_delta);
+        aliases
+    }
+
+    /// Reverts `partition` by restarting all nodes with trusted peers defined in the topology
+    pub fn heal_partition(&mut self, nodes: Vec<NodeController>) -> Result<Vec<NodeController>> {
+        let spawn_params = nodes.iter().map(|node| self.respawn_params(node)).collect();
+        self.respawn_nodes(nodes, spawn_params)
+    }
+
+    pub(super) fn respawn_params(&self, node: &NodeController) -> SpawnParams {
+        let mut params = self.new_spawn_params(node.alias());
+        params
+            .leadership_mode(node.leadership_mode())
+            .persistence_mode(node.persistence_mode());
+        params
+    }
+
+    fn respawn_nodes(
+        &mut self,
+        nodes: Vec<NodeController>,
+        spawn_params: Vec<SpawnParams>,
+    ) -> Result<Vec<NodeController>> {
+        // every node needs to be down before first one is spawned again, otherwise
+        // nodes which are not restarted yet would reconnect to already restarted ones
+        // controllers are dropped right after shutdown, so resources bound to them
+        // (like network conditions proxy) are released before node is spawned again
+        for node in nodes {
+            node.shutdown()?;
+        }
+        self.spawn_nodes(spawn_params)
+    }
+
+    /// Spawns all nodes first and then waits for each of them to bootstrap
+    pub(super) fn spawn_nodes(
+        &mut self,
+        mut spawn_params: Vec<SpawnParams>,
+    ) -> Result<Vec<NodeController>> {
+        let mut spawned = Vec::new();
+        for params in spawn_params.iter_mut() {
+            spawned.push(self.spawn_node_custom(params)?);
+        }
+        for node in spawned.iter() {
+            node.wait_for_bootstrap()?;
+        }
+        Ok(spawned)
+    }
+
     pub fn monitor_nodes(&mut self) {
         if let ProgressBarMode::Monitor = self.context.progress_bar_mode() {
             let pb = Arc::clone(&self.progress_bar);
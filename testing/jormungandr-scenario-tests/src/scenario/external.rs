@@ -0,0 +1,80 @@
+use crate::scenario::{settings::Settings, ContextChaCha, Controller, Result};
+use assert_fs::prelude::*;
+use jormungandr_testing_utils::testing::{
+    block0::get_block,
+    network_builder::{Blockchain, NodeAlias, Settings as NetworkBuilderSettings, TopologyBuilder},
+    RemoteJormungandrBuilder,
+};
+use std::collections::HashMap;
+use std::net::SocketAddr;
+
+/// Already running network (e.g. staging) described by its block0 and the REST
+/// endpoints of its nodes
+#[derive(Debug, Clone)]
+pub struct ExternalNetwork {
+    block0: String,
+    nodes: Vec<(NodeAlias, SocketAddr)>,
+}
+
+impl ExternalNetwork {
+    /// `block0` can be either a path to the block0 file or url to download it from
+    pub fn new<S: Into<String>>(block0: S) -> Self {
+        Self {
+            block0: block0.into(),
+            nodes: Vec::new(),
+        }
+    }
+
+    pub fn node<S: Into<NodeAlias>>(mut self, alias: S, rest_address: SocketAddr) -> Self {
+        self.nodes.push((alias.into(), rest_address));
+        self
+    }
+}
+
+impl Controller {
+    /// Creates controller which does not spawn any process, but attaches to nodes of
+    /// an existing network. Fragment senders, verifiers and interactive console work
+    /// with the remote nodes, while all attempts to spawn node fail with `NodeNotFound`
+    pub fn attach(title: &str, context: ContextChaCha, network: ExternalNetwork) -> Result<Self> {
+        let working_directory = context.child_directory(title);
+        working_directory.create_dir_all()?;
+
+        let block0 = get_block(network.block0)?;
+        let blockchain_configuration = &block0.blockchain_configuration;
+        let blockchain = Blockchain::new(
+            blockchain_configuration.block0_consensus,
+            blockchain_configuration.slots_per_epoch,
+            blockchain_configuration.slot_duration,
+            blockchain_configuration.kes_update_speed,
+            blockchain_configuration.consensus_genesis_praos_active_slot_coeff,
+        );
+
+        let settings = Settings {
+            network_settings: NetworkBuilderSettings {
+                nodes: HashMap::new(),
+                wallets: HashMap::new(),
+                legacy_wallets: HashMap::new(),
+                block0,
+                stake_pools: HashMap::new(),
+                vote_plans: HashMap::new(),
+                bft_rotations: HashMap::new(),
+            },
+            private_vote_plans: HashMap::new(),
+        };
+
+        let mut controller = Controller::new(
+            settings,
+            context,
+            working_directory,
+            blockchain,
+            TopologyBuilder::new().build(),
+        )?;
+
+        for (alias, rest_address) in network.nodes {
+            let mut builder = RemoteJormungandrBuilder::new(alias);
+            builder.with_rest(rest_address);
+            controller.add_remote_node(builder.build());
+        }
+        Ok(controller)
+    }
+}
@@ -1,12 +1,18 @@
 mod context;
 mod controller;
+mod external;
 mod fragment_node;
 pub mod repository;
 pub mod settings;
+mod snapshot;
+mod upgrade;
 
 pub use self::{
     context::{Context, ContextChaCha},
     controller::{Controller, ControllerBuilder},
+    external::ExternalNetwork,
+    snapshot::{NetworkSnapshot, NodeSnapshot, WalletSnapshot},
+    upgrade::UpgradableNetwork,
 };
 pub use chain_impl_mockchain::{
     block::Block, chaintypes::ConsensusVersion, header::HeaderId, milli::Milli, value::Value,
@@ -41,6 +47,21 @@ pub enum Error {
     #[error(transparent)]
     BlockFormatError(#[from] chain_core::mempack::ReadError),
 
+    #[error(transparent)]
+    Block0(#[from] jormungandr_testing_utils::testing::block0::GetBlock0Error),
+
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+
+    #[error(transparent)]
+    FragmentSender(#[from] jormungandr_testing_utils::testing::FragmentSenderError),
+
+    #[error(transparent)]
+    FragmentNode(#[from] jormungandr_testing_utils::testing::FragmentNodeError),
+
+    #[error(transparent)]
+    FragmentVerifier(#[from] jormungandr_testing_utils::testing::FragmentVerifierError),
+
     #[error("No node with alias {0}")]
     NodeNotFound(String),
 
@@ -52,6 +73,24 @@ pub enum Error {
 
     #[error("VotePlan '{0}' was not found. Used before or never initialize")]
     VotePlanNotFound(String),
+
+    #[error("BFT rotation '{0}' was not found. Used before or never initialize")]
+    BftRotationNotFound(String),
+
+    #[error("node '{0}' has no BFT leader key")]
+    NotABftLeader(String),
+
+    #[error("snapshot was made for block0 {expected}, while current network block0 is {actual}")]
+    SnapshotMismatch { expected: String, actual: String },
+
+    #[error("only account wallets can be saved in snapshot, got wallet with address {0}")]
+    UnsupportedSnapshotWallet(String),
+
+    #[error("network did not produce any block on top of height {height} in {} s", .duration.as_secs())]
+    NoBlockProduction {
+        height: u32,
+        duration: std::time::Duration,
+    },
 }
 
 pub type Result<T> = ::core::result::Result<T, Error>;
@@ -70,7 +109,10 @@ macro_rules! prepare_scenario {
             slot_duration = $slot_duration:tt,
             leaders = [ $($node_leader:tt),* $(,)* ],
             initials = [
-                $($wallet_type:tt $initial_wallet_name:tt with $initial_wallet_funds:tt $(delegates to $initial_wallet_delegate_to:tt)* ),+ $(,)*
+                $($wallet_type:tt $initial_wallet_name:tt with $initial_wallet_funds:tt $(delegates to $initial_wallet_delegate_to:tt)*
+                    $(splits delegation [ $($initial_wallet_split_to:tt weight $initial_wallet_split_weight:tt),+ $(,)* ])?
+                    $(votes [ $($initial_wallet_vote_plan:tt proposal $initial_wallet_vote_proposal:tt choice $initial_wallet_vote_choice:tt),+ $(,)* ])?
+                ),+ $(,)*
             ] $(,)*
             $(committees = [ $($committe_wallet_name:tt),* $(,)* ] $(,)*)?
             $(legacy = [
@@ -81,6 +123,9 @@ macro_rules! prepare_scenario {
                     $(proposal adds $action_value:tt to $action_target:tt with $proposal_options_count:tt vote options),+ $(,)*
                 ]
             )*],)?
+            $(bft_rotations = [
+                $($bft_rotation_name:tt adds [ $($bft_rotation_added:tt),* $(,)* ] removes [ $($bft_rotation_removed:tt),* $(,)* ]),* $(,)*
+            ] $(,)*)?
         }
     ) => {{
         let mut builder = $crate::scenario::ControllerBuilder::new($title);
@@ -128,6 +173,20 @@ macro_rules! prepare_scenario {
                         );
                         *wallet.delegate_mut() = Some($initial_wallet_delegate_to.to_owned());
                     )*
+                    $(
+                        $(
+                            wallet.delegation_ratios_mut().push(($initial_wallet_split_to.to_owned(), $initial_wallet_split_weight));
+                        )+
+                    )?
+                    $(
+                        $(
+                            wallet.votes_mut().push(jormungandr_testing_utils::testing::network_builder::WalletVote {
+                                vote_plan: $initial_wallet_vote_plan.to_owned(),
+                                proposal_index: $initial_wallet_vote_proposal,
+                                choice: $initial_wallet_vote_choice,
+                            });
+                        )+
+                    )?
                     wallet
                 } else if $wallet_type == "utxo" {
                     #[allow(unused_mut)]
@@ -203,6 +262,20 @@ macro_rules! prepare_scenario {
             )*
         )?
 
+        $(
+            $(
+                #[allow(unused_mut)]
+                let mut bft_rotation = jormungandr_testing_utils::testing::network_builder::BftRotation::new($bft_rotation_name);
+                $(
+                    bft_rotation.add_leader($bft_rotation_added);
+                )*
+                $(
+                    bft_rotation.remove_leader($bft_rotation_removed);
+                )*
+                blockchain.add_bft_rotation(bft_rotation);
+            )*
+        )?
+
         builder.set_blockchain(blockchain);
 
         builder.build_settings($context);
@@ -18,20 +18,26 @@ use crate::{
             explorer::passive_node_explorer, leader_promotion::*,
             leadership_log::leader_restart_preserves_leadership_log, p2p::*,
             stake_pool::retire::retire_stake_pool_explorer,
+            wallet_templates::declared_delegations_and_votes,
         },
         legacy,
         network::{
-            bft::{bft_cascade, bft_passive_propagation},
+            bft::{bft_cascade, bft_leaders_rotation, bft_passive_propagation},
+            grow::grow_running_network,
+            partition::partition_and_heal,
             real::{real_bft_network, real_praos_network},
-            topology::scenarios::*,
+            snapshot::snapshot_and_restore,
+            topology::{generated::*, scenarios::*},
+            wan::wan_like_network,
         },
-        non_functional::{disruption::*, soak::*},
+        non_functional::{chaos::*, disruption::*, soak::*},
         Result,
     },
     Context,
 };
 
 use rand_chacha::ChaChaRng;
+use std::time::Instant;
 type ScenarioMethod = fn(Context<ChaChaRng>) -> Result<ScenarioResult>;
 
 pub struct ScenariosRepository {
@@ -135,14 +141,19 @@ impl ScenariosRepository {
 
         println!("Running '{}' scenario", scenario.name());
 
+        let scenario_context = context.clone().derive();
+        let node_reports = scenario_context.node_reports();
+        let started = Instant::now();
         let result = {
             if self.print_panics {
-                Ok(Ok(scenario_to_run(context.clone().derive()).unwrap()))
+                Ok(Ok(scenario_to_run(scenario_context).unwrap()))
             } else {
-                std::panic::catch_unwind(|| scenario_to_run(context.clone().derive()))
+                std::panic::catch_unwind(move || scenario_to_run(scenario_context))
             }
         };
-        let scenario_result = ScenarioResult::from_result(scenario.name(), result);
+        let scenario_result = ScenarioResult::from_result(scenario.name(), result)
+            .with_duration(started.elapsed())
+            .with_nodes(node_reports.take());
         println!("Scenario '{}' {}", scenario.name(), scenario_result);
         scenario_result
     }
@@ -190,6 +201,26 @@ fn scenarios_repository() -> Vec<Scenario> {
             vec![Tag::Short],
         ),
         Scenario::new("fully_connected", fully_connected, vec![Tag::Short]),
+        Scenario::new("partition_and_heal", partition_and_heal, vec![Tag::Long]),
+        Scenario::new(
+            "grow_running_network",
+            grow_running_network,
+            vec![Tag::Long],
+        ),
+        Scenario::new(
+            "snapshot_and_restore",
+            snapshot_and_restore,
+            vec![Tag::Long],
+        ),
+        Scenario::new("wan_like_network", wan_like_network, vec![Tag::Long]),
+        Scenario::new("generated_ring", generated_ring, vec![Tag::Long]),
+        Scenario::new("generated_star", generated_star, vec![Tag::Long]),
+        Scenario::new("generated_mesh", generated_mesh, vec![Tag::Long]),
+        Scenario::new(
+            "generated_random_graph",
+            generated_random_graph,
+            vec![Tag::Long],
+        ),
         Scenario::new("star", star, vec![Tag::Short]),
         Scenario::new("mesh", mesh, vec![Tag::Short]),
         Scenario::new("point_to_point", point_to_point, vec![Tag::Short]),
@@ -250,6 +281,11 @@ fn scenarios_repository() -> Vec<Scenario> {
             retire_stake_pool_explorer,
             vec![Tag::Short],
         ),
+        Scenario::new(
+            "declared_delegations_and_votes",
+            declared_delegations_and_votes,
+            vec![Tag::Short],
+        ),
         Scenario::new(
             "current_node_legacy_fragment_propagation",
             legacy::current_node_legacy_fragment_propagation,
@@ -310,6 +346,11 @@ fn scenarios_repository() -> Vec<Scenario> {
             |ctx| legacy::disruption_last_nth_release(ctx, 1),
             vec![Tag::Short, Tag::Unstable],
         ),
+        Scenario::new(
+            legacy::rolling_upgrade_from_last_nth_release_title(1),
+            |ctx| legacy::rolling_upgrade_from_last_nth_release(ctx, 1),
+            vec![Tag::Long, Tag::Unstable],
+        ),
         Scenario::new("relay_soak", relay_soak, vec![Tag::Long, Tag::Unstable]),
         Scenario::new(
             "p2p_stats_test",
@@ -335,7 +376,22 @@ fn scenarios_repository() -> Vec<Scenario> {
             bft_passive_propagation,
             vec![Tag::Short],
         ),
+        Scenario::new(
+            "bft_leaders_rotation",
+            bft_leaders_rotation,
+            vec![Tag::UpdateProposals],
+        ),
         Scenario::new("mesh_disruption", mesh_disruption, vec![Tag::Short]),
+        Scenario::new(
+            "chaos_scheduled_disruption",
+            chaos_scheduled_disruption,
+            vec![Tag::Chaos, Tag::Long],
+        ),
+        Scenario::new(
+            "chaos_random_disruption",
+            chaos_random_disruption,
+            vec![Tag::Chaos, Tag::Long, Tag::Unstable],
+        ),
         Scenario::new(
             "newest_node_enters_legacy_network",
             legacy::newest_node_enters_legacy_network,
@@ -1,10 +1,12 @@
-use crate::{scenario::repository::ScenarioStatus, test::Result};
-use std::{any::Any, fmt};
+use crate::{report::NodeReport, scenario::repository::ScenarioStatus, test::Result};
+use std::{any::Any, fmt, time::Duration};
 
 #[derive(Clone, Debug)]
 pub struct ScenarioResult {
     pub name: String,
     pub scenario_status: ScenarioStatus,
+    pub duration: Duration,
+    pub nodes: Vec<NodeReport>,
 }
 
 impl ScenarioResult {
@@ -12,6 +14,8 @@ impl ScenarioResult {
         ScenarioResult {
             name: name.into(),
             scenario_status: ScenarioStatus::Passed,
+            duration: Duration::from_secs(0),
+            nodes: Vec::new(),
         }
     }
 
@@ -19,6 +23,8 @@ impl ScenarioResult {
         ScenarioResult {
             name: name.into(),
             scenario_status: ScenarioStatus::Failed(reason.into()),
+            duration: Duration::from_secs(0),
+            nodes: Vec::new(),
         }
     }
 
@@ -26,9 +32,29 @@ impl ScenarioResult {
         ScenarioResult {
             name: name.into(),
             scenario_status: ScenarioStatus::Ignored,
+            duration: Duration::from_secs(0),
+            nodes: Vec::new(),
         }
     }
 
+    pub fn with_duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    pub fn with_nodes(mut self, nodes: Vec<NodeReport>) -> Self {
+        self.nodes = nodes;
+        self
+    }
+
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    pub fn nodes(&self) -> &[NodeReport] {
+        &self.nodes
+    }
+
     pub fn scenario_status(&self) -> &ScenarioStatus {
         &self.scenario_status
     }
@@ -13,6 +13,10 @@ pub enum Tag {
     Example,
     /// Requires libfaketime to be installed in the host machine
     Desync,
+    /// Nodes are killed, paused or run out of disk space during the test
+    Chaos,
+    /// Requires the nodes to be built with the `update-proposals` feature
+    UpdateProposals,
 }
 
 pub fn parse_tag_from_str(tag: &str) -> Result<Tag> {
@@ -26,6 +30,8 @@ pub fn parse_tag_from_str(tag: &str) -> Result<Tag> {
         "interactive" => Ok(Tag::Interactive),
         "example" => Ok(Tag::Example),
         "desync" => Ok(Tag::Desync),
+        "chaos" => Ok(Tag::Chaos),
+        "updateproposals" => Ok(Tag::UpdateProposals),
         _ => Ok(Tag::All),
     }
 }
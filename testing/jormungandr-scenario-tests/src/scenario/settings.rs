@@ -208,6 +208,17 @@ impl Dotifier {
                     self.dot_node_label(trusted_peer)
                 )?;
             }
+
+            for (node, weight) in template.delegation_ratios() {
+                let trusted_peer = settings.network_settings.nodes.get(node).unwrap();
+                writeln!(
+                    &mut w,
+                    "  {} -> {} [ label = \"delegates {}\"; style = dashed ; color = red ]",
+                    &label,
+                    self.dot_node_label(trusted_peer),
+                    weight
+                )?;
+            }
         }
 
         writeln!(&mut w, "}}")?;
@@ -310,6 +321,7 @@ impl Prepare for NodeConfig {
             explorer: Explorer::prepare(context),
             bootstrap_from_trusted_peers: None,
             skip_bootstrap: None,
+            simulation_clock: None,
         }
     }
 }
@@ -323,6 +335,8 @@ impl Prepare for Rest {
             listen: context.generate_new_rest_listen_address(),
             tls: None,
             cors: None,
+            max_body_size: None,
+            request_timeout: None,
         }
     }
 }
@@ -341,7 +355,10 @@ impl Prepare for Explorer {
     where
         RNG: RngCore,
     {
-        Explorer { enabled: false }
+        Explorer {
+            enabled: false,
+            indexer: None,
+        }
     }
 }
 
@@ -355,17 +372,22 @@ impl Prepare for P2p {
             trusted_peers: Vec::new(),
             allow_private_addresses: true,
             listen: None,
+            local_listen_port: None,
             max_connections: None,
             max_inbound_connections: None,
             policy: Some(Policy::prepare(context)),
             layers: Some(LayersConfig {
                 preferred_list: None,
                 topics_of_interest: Some(TopicsOfInterest::prepare(context)),
+                rings: None,
+                cyclon: None,
             }),
             node_key_file: None,
             gossip_interval: None,
             max_bootstrap_attempts: None,
             network_stuck_check: None,
+            block_propagation: None,
+            preferred_peers: None,
         }
     }
 }
@@ -0,0 +1,178 @@
+use crate::{
+    node::NodeController,
+    scenario::{Controller, Error, Result},
+};
+use jormungandr_testing_utils::{
+    testing::network_builder::{LeadershipMode, NodeAlias, PersistenceMode},
+    wallet::Wallet,
+};
+use serde_derive::{Deserialize, Serialize};
+use std::path::Path;
+
+const SNAPSHOT_MANIFEST: &str = "snapshot.json";
+const SNAPSHOT_BLOCK0: &str = "block0.bin";
+const SNAPSHOT_NODES: &str = "nodes";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NodeSnapshot {
+    pub alias: NodeAlias,
+    pub leader: bool,
+    pub persistent: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WalletSnapshot {
+    pub signing_key: String,
+    pub spending_counter: u32,
+}
+
+/// Description of the network state saved by `Controller::snapshot`. Node configs
+/// and storages are kept next to it, in `nodes/<alias>` subdirectories
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NetworkSnapshot {
+    pub block0_hash: String,
+    pub nodes: Vec<NodeSnapshot>,
+    pub wallets: Vec<WalletSnapshot>,
+}
+
+impl NetworkSnapshot {
+    pub fn load<P: AsRef<Path>>(dir: P) -> Result<Self> {
+        let file = std::fs::File::open(dir.as_ref().join(SNAPSHOT_MANIFEST))?;
+        Ok(serde_json::from_reader(file)?)
+    }
+
+    fn save<P: AsRef<Path>>(&self, dir: P) -> Result<()> {
+        let file = std::fs::File::create(dir.as_ref().join(SNAPSHOT_MANIFEST))?;
+        Ok(serde_json::to_writer_pretty(file, self)?)
+    }
+}
+
+impl WalletSnapshot {
+    fn new(wallet: &Wallet) -> Result<Self> {
+        match wallet {
+            Wallet::Account(account) => Ok(Self {
+                signing_key: wallet.signing_key_to_string(),
+                spending_counter: account.internal_counter().into(),
+            }),
+            _ => Err(Error::UnsupportedSnapshotWallet(
+                wallet.address().to_string(),
+            )),
+        }
+    }
+
+    fn restore(&self) -> Wallet {
+        Wallet::from_existing_account(&self.signing_key, Some(self.spending_counter))
+    }
+}
+
+impl Controller {
+    /// Saves running network (node configs and storages, block0 and wallets state) into `dir`.
+    /// Nodes are shut down while their storages are copied and spawned again afterwards,
+    /// so returned controllers should be used instead of the provided ones.
+    ///
+    /// Only account wallets are supported. Storage of in-memory nodes is not saved,
+    /// so after restore they bootstrap from peers.
+    pub fn snapshot<P: AsRef<Path>>(
+        &mut self,
+        nodes: Vec<NodeController>,
+        wallets: &[Wallet],
+        dir: P,
+    ) -> Result<(Vec<NodeController>, NetworkSnapshot)> {
+        let dir = dir.as_ref();
+        let snapshot = NetworkSnapshot {
+            block0_hash: self.block0_hash().to_string(),
+            nodes: nodes
+                .iter()
+                .map(|node| NodeSnapshot {
+                    alias: node.alias().to_string(),
+                    leader: node.leadership_mode() == LeadershipMode::Leader,
+                    persistent: node.persistence_mode() == PersistenceMode::Persistent,
+                })
+                .collect(),
+            wallets: wallets
+                .iter()
+                .map(WalletSnapshot::new)
+                .collect::<Result<_>>()?,
+        };
+        let spawn_params = nodes.iter().map(|node| self.respawn_params(node)).collect();
+
+        for node in nodes {
+            node.shutdown()?;
+        }
+
+        std::fs::create_dir_all(dir)?;
+        std::fs::copy(self.block0_file(), dir.join(SNAPSHOT_BLOCK0))?;
+        for node in &snapshot.nodes {
+            copy_dir(
+                self.node_dir(&node.alias).path(),
+                &dir.join(SNAPSHOT_NODES).join(&node.alias),
+            )?;
+        }
+        snapshot.save(dir)?;
+
+        let nodes = self.spawn_nodes(spawn_params)?;
+        Ok((nodes, snapshot))
+    }
+
+    /// Spawns nodes from the state saved by `Controller::snapshot` and returns them
+    /// together with restored wallets. Controller has to be prepared from the same
+    /// settings (and seed) as the one used for the snapshot, so block0 matches
+    pub fn restore<P: AsRef<Path>>(
+        &mut self,
+        dir: P,
+    ) -> Result<(Vec<NodeController>, Vec<Wallet>)> {
+        let dir = dir.as_ref();
+        let snapshot = NetworkSnapshot::load(dir)?;
+
+        let block0_hash = self.block0_hash().to_string();
+        if snapshot.block0_hash != block0_hash {
+            return Err(Error::SnapshotMismatch {
+                expected: snapshot.block0_hash,
+                actual: block0_hash,
+            });
+        }
+
+        let mut spawn_params = Vec::new();
+        for node in &snapshot.nodes {
+            let (leadership_mode, persistence_mode) = match (node.leader, node.persistent) {
+                (true, true) => (LeadershipMode::Leader, PersistenceMode::Persistent),
+                (true, false) => (LeadershipMode::Leader, PersistenceMode::InMemory),
+                (false, true) => (LeadershipMode::Passive, PersistenceMode::Persistent),
+                (false, false) => (LeadershipMode::Passive, PersistenceMode::InMemory),
+            };
+            if node.persistent {
+                copy_dir(
+                    &dir.join(SNAPSHOT_NODES).join(&node.alias),
+                    self.node_dir(&node.alias).path(),
+                )?;
+            }
+            let mut params = self.new_spawn_params(&node.alias);
+            params
+                .leadership_mode(leadership_mode)
+                .persistence_mode(persistence_mode);
+            spawn_params.push(params);
+        }
+
+        let nodes = self.spawn_nodes(spawn_params)?;
+        let wallets = snapshot
+            .wallets
+            .iter()
+            .map(WalletSnapshot::restore)
+            .collect();
+        Ok((nodes, wallets))
+    }
+}
+
+fn copy_dir(from: &Path, to: &Path) -> Result<()> {
+    std::fs::create_dir_all(to)?;
+    for entry in std::fs::read_dir(from)? {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir(&entry.path(), &target)?;
+        } else {
+            std::fs::copy(entry.path(), target)?;
+        }
+    }
+    Ok(())
+}
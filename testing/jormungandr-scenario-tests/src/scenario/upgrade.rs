@@ -0,0 +1,216 @@
+use crate::{
+    legacy::LegacyNodeController,
+    scenario::{Controller, Error, Result},
+    NodeController,
+};
+use jormungandr_testing_utils::{
+    testing::{
+        network_builder::{LeadershipMode, NodeAlias, PersistenceMode},
+        node::{download_last_n_releases, get_jormungandr_bin},
+        SyncNode,
+    },
+    Version,
+};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+const NODE_STORAGE: &str = "storage.db";
+
+enum UpgradableNodeController {
+    Legacy(LegacyNodeController),
+    Current(NodeController),
+}
+
+impl UpgradableNodeController {
+    fn as_sync_node(&self) -> &dyn SyncNode {
+        match self {
+            Self::Legacy(node) => node,
+            Self::Current(node) => node,
+        }
+    }
+
+    fn is_up(&self) -> bool {
+        match self {
+            Self::Legacy(node) => node.is_up(),
+            Self::Current(node) => node.is_up(),
+        }
+    }
+}
+
+struct UpgradableNode {
+    alias: NodeAlias,
+    leadership_mode: LeadershipMode,
+    controller: UpgradableNodeController,
+}
+
+/// Network started on the legacy version of jormungandr, which nodes are
+/// later rolled one-by-one to the version under test
+pub struct UpgradableNetwork {
+    version: Version,
+    legacy_app: PathBuf,
+    persistence_mode: PersistenceMode,
+    block_production_timeout: Duration,
+    nodes: Vec<UpgradableNode>,
+}
+
+impl UpgradableNetwork {
+    pub fn legacy_version(&self) -> &Version {
+        &self.version
+    }
+
+    pub fn legacy_app(&self) -> &PathBuf {
+        &self.legacy_app
+    }
+
+    pub fn block_production_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.block_production_timeout = timeout;
+        self
+    }
+
+    pub fn legacy_aliases(&self) -> Vec<NodeAlias> {
+        self.nodes
+            .iter()
+            .filter(|node| matches!(node.controller, UpgradableNodeController::Legacy(_)))
+            .map(|node| node.alias.clone())
+            .collect()
+    }
+
+    pub fn is_upgraded(&self) -> bool {
+        self.legacy_aliases().is_empty()
+    }
+
+    pub fn sync_nodes(&self) -> Vec<&dyn SyncNode> {
+        self.nodes
+            .iter()
+            .map(|node| node.controller.as_sync_node())
+            .collect()
+    }
+
+    fn best_height(&self) -> u32 {
+        self.nodes
+            .iter()
+            .filter(|node| node.controller.is_up())
+            .map(|node| node.controller.as_sync_node().last_block_height())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// waits until network produces block on top of the given height
+    pub fn verify_block_production(&self, height: u32) -> Result<u32> {
+        let start = Instant::now();
+        loop {
+            let current = self.best_height();
+            if current > height {
+                return Ok(current);
+            }
+            if start.elapsed() > self.block_production_timeout {
+                return Err(Error::NoBlockProduction {
+                    height,
+                    duration: self.block_production_timeout,
+                });
+            }
+            std::thread::sleep(Duration::from_secs(1));
+        }
+    }
+
+    /// nodes which are already running current version. Legacy ones are shut down
+    pub fn into_nodes(self) -> Result<Vec<NodeController>> {
+        let mut nodes = Vec::new();
+        for node in self.nodes {
+            match node.controller {
+                UpgradableNodeController::Legacy(legacy) => legacy.shutdown()?,
+                UpgradableNodeController::Current(current) => nodes.push(current),
+            }
+        }
+        Ok(nodes)
+    }
+}
+
+impl Controller {
+    /// downloads n-th last release and spawns all given nodes on it, in the provided order
+    pub fn spawn_upgradable_network(
+        &mut self,
+        nth_release: u32,
+        nodes: &[(&str, LeadershipMode)],
+        persistence_mode: PersistenceMode,
+    ) -> Result<UpgradableNetwork> {
+        let releases = download_last_n_releases(nth_release);
+        let release = releases.last().unwrap();
+        let legacy_app = get_jormungandr_bin(release, self.working_directory());
+        let version = release.version();
+
+        let mut network = UpgradableNetwork {
+            version,
+            legacy_app,
+            persistence_mode,
+            block_production_timeout: Duration::from_secs(120),
+            nodes: Vec::new(),
+        };
+
+        for (alias, leadership_mode) in nodes {
+            let mut params = self.new_spawn_params(alias);
+            params
+                .leadership_mode(*leadership_mode)
+                .persistence_mode(persistence_mode)
+                .jormungandr(network.legacy_app.clone());
+            let node = self.spawn_legacy_node(&mut params, &network.version)?;
+            node.wait_for_bootstrap()?;
+            network.nodes.push(UpgradableNode {
+                alias: alias.to_string(),
+                leadership_mode: *leadership_mode,
+                controller: UpgradableNodeController::Legacy(node),
+            });
+        }
+        Ok(network)
+    }
+
+    /// stops legacy node and starts it again using version under test. Storage of
+    /// the legacy node is reused, so the upgraded node continues from the same chain.
+    /// Fails if network does not produce any block during the upgrade
+    pub fn upgrade_node(&mut self, network: &mut UpgradableNetwork, alias: &str) -> Result<()> {
+        let index = network
+            .nodes
+            .iter()
+            .position(|node| node.alias == alias)
+            .ok_or_else(|| Error::NodeNotFound(alias.to_string()))?;
+
+        let height = network.best_height();
+        let leadership_mode = network.nodes[index].leadership_mode;
+
+        if let UpgradableNodeController::Legacy(legacy) = &network.nodes[index].controller {
+            legacy.shutdown()?;
+            if network.persistence_mode == PersistenceMode::Persistent {
+                let node_dir = self.node_dir(alias);
+                let legacy_storage = node_dir.path().join(alias).join(NODE_STORAGE);
+                if legacy_storage.exists() {
+                    std::fs::rename(legacy_storage, node_dir.path().join(NODE_STORAGE))?;
+                }
+            }
+        } else {
+            return Ok(());
+        }
+
+        let node = self.spawn_node(alias, leadership_mode, network.persistence_mode)?;
+        node.wait_for_bootstrap()?;
+        network.nodes[index].controller = UpgradableNodeController::Current(node);
+
+        network.verify_block_production(height)?;
+        Ok(())
+    }
+
+    /// upgrades all legacy nodes one-by-one, waiting `settle_time` between upgrades
+    /// and asserting that blocks are produced after each step
+    pub fn rolling_upgrade(
+        &mut self,
+        network: &mut UpgradableNetwork,
+        settle_time: Duration,
+    ) -> Result<()> {
+        for alias in network.legacy_aliases() {
+            self.upgrade_node(network, &alias)?;
+            std::thread::sleep(settle_time);
+            let height = network.best_height();
+            network.verify_block_production(height)?;
+        }
+        Ok(())
+    }
+}
@@ -57,7 +57,10 @@ pub fn passive_node_explorer(mut context: Context<ChaChaRng>) -> Result<Scenario
             .new_spawn_params(PASSIVE)
             .passive()
             .in_memory()
-            .explorer(Explorer { enabled: true }),
+            .explorer(Explorer {
+                enabled: true,
+                indexer: None,
+            }),
     )?;
     passive.wait_for_bootstrap()?;
 
@@ -74,14 +77,11 @@ pub fn passive_node_explorer(mut context: Context<ChaChaRng>) -> Result<Scenario
 
     let transaction_id = passive
         .explorer()
-        .transaction((*mem_pool_check.fragment_id()).into())?
-        .data
-        .unwrap()
-        .transaction
-        .id;
+        .typed()
+        .transaction((*mem_pool_check.fragment_id()).into())?;
     utils::assert_equals(
         &transaction_id,
-        &mem_pool_check.fragment_id().to_string(),
+        &(*mem_pool_check.fragment_id()).into(),
         "Wrong transaction id in explorer",
     )?;
 
@@ -3,3 +3,4 @@ pub mod leader_promotion;
 pub mod leadership_log;
 pub mod p2p;
 pub mod stake_pool;
+pub mod wallet_templates;
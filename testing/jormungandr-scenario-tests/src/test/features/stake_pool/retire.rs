@@ -44,7 +44,10 @@ pub fn retire_stake_pool_explorer(mut context: Context<ChaChaRng>) -> Result<Sce
             .new_spawn_params(LEADER_1)
             .leader()
             .in_memory()
-            .explorer(Explorer { enabled: true }),
+            .explorer(Explorer {
+                enabled: true,
+                indexer: None,
+            }),
     )?;
     leader_1.wait_for_bootstrap()?;
 
@@ -64,18 +67,12 @@ pub fn retire_stake_pool_explorer(mut context: Context<ChaChaRng>) -> Result<Sce
 
     std::thread::sleep(std::time::Duration::from_secs(30));
 
-    let explorer = leader_1.explorer();
+    let explorer = leader_1.explorer().typed();
     let stake_pool_3 = controller.stake_pool(LEADER_3)?;
 
-    let stake_pool_state_before =
-        explorer.stake_pool(stake_pool_3.info().to_id().to_string(), 0)?;
+    let stake_pool_state_before = explorer.stake_pool(stake_pool_3.info().to_id().into(), 0)?;
     utils::assert(
-        stake_pool_state_before
-            .data
-            .unwrap()
-            .stake_pool
-            .retirement
-            .is_none(),
+        !stake_pool_state_before.retired,
         "retirement field in explorer should be empty",
     )?;
 
@@ -96,14 +93,9 @@ pub fn retire_stake_pool_explorer(mut context: Context<ChaChaRng>) -> Result<Sce
     let start_time_no_block = std::time::SystemTime::now();
 
     // proof 1: explorer shows as retired
-    let stake_pool_state_after = explorer.stake_pool(stake_pool_3.id().to_string(), 0)?;
+    let stake_pool_state_after = explorer.stake_pool(stake_pool_3.id().into(), 0)?;
     utils::assert(
-        stake_pool_state_after
-            .data
-            .unwrap()
-            .stake_pool
-            .retirement
-            .is_none(),
+        !stake_pool_state_after.retired,
         "retirement field in explorer should not be empty",
     )?;
 
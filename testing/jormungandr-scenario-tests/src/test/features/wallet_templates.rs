@@ -0,0 +1,91 @@
+use crate::{
+    node::{LeadershipMode, PersistenceMode},
+    test::{utils, Result},
+    Context, ScenarioResult,
+};
+use function_name::named;
+use jormungandr_lib::crypto::hash::Hash;
+use rand_chacha::ChaChaRng;
+
+const LEADER_1: &str = "Leader_1";
+const LEADER_2: &str = "Leader_2";
+const FUND: &str = "fund1";
+
+#[named]
+pub fn declared_delegations_and_votes(mut context: Context<ChaChaRng>) -> Result<ScenarioResult> {
+    let name = function_name!();
+    let scenario_settings = prepare_scenario! {
+        name,
+        &mut context,
+        topology [
+            LEADER_1,
+            LEADER_2 -> LEADER_1,
+        ]
+        blockchain {
+            consensus = GenesisPraos,
+            number_of_slots_per_epoch = 60,
+            slot_duration = 1,
+            leaders = [ LEADER_1 ],
+            initials = [
+                "account" "alice" with 2_000_000_000 delegates to LEADER_1,
+                "account" "bob" with 2_000_000_000 delegates to LEADER_2,
+                "account" "clarice" with 1_000_000_000
+                    splits delegation [ LEADER_1 weight 1, LEADER_2 weight 3 ]
+                    votes [ FUND proposal 0 choice 1, FUND proposal 1 choice 0 ],
+            ],
+            committees = [ "alice" ],
+            vote_plans = [
+                FUND from "alice" through epochs 0->1->2 as "public" contains proposals = [
+                    proposal adds 100 to "rewards" with 3 vote options,
+                    proposal adds 100 to "rewards" with 3 vote options,
+                ]
+            ],
+        }
+    };
+
+    let mut controller = scenario_settings.build(context)?;
+
+    let leader_1 =
+        controller.spawn_node(LEADER_1, LeadershipMode::Leader, PersistenceMode::InMemory)?;
+    leader_1.wait_for_bootstrap()?;
+    let leader_2 =
+        controller.spawn_node(LEADER_2, LeadershipMode::Leader, PersistenceMode::InMemory)?;
+    leader_2.wait_for_bootstrap()?;
+
+    // the split delegation is registered in block0
+    let clarice = controller.wallet("clarice")?;
+    let delegation = leader_1.rest().account_state(&clarice).unwrap();
+    let mut expected = vec![
+        (Hash::from(controller.stake_pool(LEADER_1)?.id()), 1),
+        (Hash::from(controller.stake_pool(LEADER_2)?.id()), 3),
+    ];
+    expected.sort();
+    let mut pools = delegation.delegation().pools();
+    pools.sort();
+    utils::assert_equals(&expected, &pools, "split delegation of clarice")?;
+
+    // the declared votes are cast in the voting phase
+    let checks = controller.cast_declared_votes(&leader_1)?;
+    utils::assert_equals(&2, &checks.len(), "votes declared by clarice")?;
+    utils::wait(20);
+
+    let vote_plan_status = leader_1
+        .rest()
+        .vote_plan_statuses()
+        .unwrap()
+        .into_iter()
+        .next()
+        .unwrap();
+    for proposal in vote_plan_status.proposals {
+        utils::assert_equals(
+            &1,
+            &proposal.votes_cast,
+            &format!("votes cast on proposal {}", proposal.index),
+        )?;
+    }
+
+    leader_1.shutdown()?;
+    leader_2.shutdown()?;
+    controller.finalize();
+    Ok(ScenarioResult::passed(name))
+}
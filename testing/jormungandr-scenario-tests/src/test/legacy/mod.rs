@@ -1,5 +1,6 @@
 pub mod disruption;
 pub mod fragment_propagation;
+pub mod upgrade;
 
 const PASSIVE: &str = "Passive";
 const LEADER: &str = "Leader";
@@ -10,3 +11,4 @@ const LEADER_4: &str = "Leader4";
 
 pub use disruption::*;
 pub use fragment_propagation::*;
+pub use upgrade::*;
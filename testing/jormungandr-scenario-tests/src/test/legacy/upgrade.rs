@@ -0,0 +1,74 @@
+use crate::{
+    node::{LeadershipMode, PersistenceMode},
+    test::{
+        utils::{SyncNode, SyncWaitParams},
+        Result,
+    },
+    Context, ScenarioResult,
+};
+use jormungandr_testing_utils::testing::ensure_nodes_are_in_sync;
+use rand_chacha::ChaChaRng;
+use std::borrow::Cow;
+use std::time::Duration;
+
+use super::{LEADER_1, LEADER_2, LEADER_3, LEADER_4};
+
+pub fn rolling_upgrade_from_last_nth_release_title(n: u32) -> Cow<'static, str> {
+    format!("legacy_rolling_upgrade_from_last_{}_release", n).into()
+}
+
+pub fn rolling_upgrade_from_last_nth_release(
+    mut context: Context<ChaChaRng>,
+    n: u32,
+) -> Result<ScenarioResult> {
+    let title = rolling_upgrade_from_last_nth_release_title(n);
+    let name: &str = &title;
+    let scenario_settings = prepare_scenario! {
+        name,
+        &mut context,
+        topology [
+            LEADER_1,
+            LEADER_2 -> LEADER_1,
+            LEADER_3 -> LEADER_1,
+            LEADER_4 -> LEADER_2, LEADER_3,
+        ]
+        blockchain {
+            consensus = GenesisPraos,
+            number_of_slots_per_epoch = 60,
+            slot_duration = 1,
+            leaders = [ LEADER_1 ],
+            initials = [
+                "account" "delegated1" with  2_000_000_000 delegates to LEADER_1,
+                "account" "delegated2" with  2_000_000_000 delegates to LEADER_2,
+                "account" "delegated3" with  2_000_000_000 delegates to LEADER_3,
+                "account" "delegated4" with  2_000_000_000 delegates to LEADER_4,
+            ],
+        }
+    };
+
+    let mut controller = scenario_settings.build(context)?;
+    controller.monitor_nodes();
+
+    let mut network = controller.spawn_upgradable_network(
+        n,
+        &[
+            (LEADER_1, LeadershipMode::Leader),
+            (LEADER_2, LeadershipMode::Leader),
+            (LEADER_3, LeadershipMode::Leader),
+            (LEADER_4, LeadershipMode::Leader),
+        ],
+        PersistenceMode::Persistent,
+    )?;
+
+    controller.rolling_upgrade(&mut network, Duration::from_secs(30))?;
+
+    let nodes = network.into_nodes()?;
+    let sync_nodes: Vec<&dyn SyncNode> = nodes.iter().map(|node| node as &dyn SyncNode).collect();
+    ensure_nodes_are_in_sync(SyncWaitParams::network_size(4, 2), &sync_nodes)?;
+
+    for node in nodes {
+        node.shutdown()?;
+    }
+    controller.finalize();
+    Ok(ScenarioResult::passed(name))
+}
@@ -29,6 +29,9 @@ pub enum Error {
     #[error(transparent)]
     FragmentVerifier(#[from] jormungandr_testing_utils::testing::FragmentVerifierError),
 
+    #[error(transparent)]
+    Propagation(#[from] jormungandr_testing_utils::testing::PropagationError),
+
     #[error(transparent)]
     VerificationFailed(#[from] jormungandr_testing_utils::testing::VerificationError),
 
@@ -41,9 +44,19 @@ pub enum Error {
     #[error(transparent)]
     Scenario(#[from] crate::scenario::Error),
 
+    #[error(transparent)]
+    Chaos(#[from] crate::chaos::Error),
+
     #[error("synchronization for nodes has failed. {info}. Timeout was: {} s", timeout.as_secs())]
     SyncTimeoutOccurred { info: String, timeout: Duration },
 
+    #[error("script command '{command}' at line {line} failed: {message}")]
+    ScriptCommandFailed {
+        line: usize,
+        command: String,
+        message: String,
+    },
+
     #[error("assertion failed: {0}")]
     AssertionFailed(String),
 
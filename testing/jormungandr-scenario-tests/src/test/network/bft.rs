@@ -1,5 +1,5 @@
 use crate::{
-    node::{LeadershipMode, PersistenceMode},
+    node::{LeadershipMode, NodeController, PersistenceMode},
     test::{
         utils::{self, MeasurementReportInterval, SyncWaitParams},
         Result,
@@ -7,7 +7,8 @@ use crate::{
     Context, ScenarioResult,
 };
 use function_name::named;
-use jormungandr_testing_utils::testing::FragmentVerifier;
+use jormungandr_lib::interfaces::BlockDate;
+use jormungandr_testing_utils::testing::{FragmentVerifier, PropagationVerifier};
 use rand_chacha::ChaChaRng;
 use std::time::Duration;
 
@@ -174,25 +175,10 @@ pub fn bft_passive_propagation(mut context: Context<ChaChaRng>) -> Result<Scenar
         1_000.into(),
     )?;
 
-    FragmentVerifier::wait_and_verify_is_in_block(
-        Duration::new(2, 0),
-        mem_pool_check.clone(),
-        &leader1,
-    )?;
-
-    FragmentVerifier::wait_and_verify_is_in_block(
-        Duration::new(2, 0),
-        mem_pool_check.clone(),
-        &leader2,
-    )?;
-
-    FragmentVerifier::wait_and_verify_is_in_block(
-        Duration::new(2, 0),
-        mem_pool_check.clone(),
-        &leader3,
-    )?;
-
-    FragmentVerifier::wait_and_verify_is_in_block(Duration::new(2, 0), mem_pool_check, &passive)?;
+    let report =
+        PropagationVerifier::new(Duration::from_secs(100)).measure(&mem_pool_check, &nodes);
+    report.print();
+    report.verify_in_block(Duration::from_secs(100))?;
 
     leader1.shutdown()?;
     leader2.shutdown()?;
@@ -201,3 +187,86 @@ pub fn bft_passive_propagation(mut context: Context<ChaChaRng>) -> Result<Scenar
 
     Ok(ScenarioResult::passed(name))
 }
+
+#[named]
+pub fn bft_leaders_rotation(mut context: Context<ChaChaRng>) -> Result<ScenarioResult> {
+    let name = function_name!();
+    let scenario_settings = prepare_scenario! {
+        name,
+        &mut context,
+        topology [
+            LEADER_1,
+            LEADER_2 -> LEADER_1,
+            LEADER_3 -> LEADER_1 -> LEADER_2,
+        ]
+        blockchain {
+            consensus = Bft,
+            number_of_slots_per_epoch = 30,
+            slot_duration = 1,
+            leaders = [ LEADER_1, LEADER_2 ],
+            initials = [
+                "account" ALICE with 500_000_000,
+            ],
+            bft_rotations = [
+                "swap" adds [ LEADER_3 ] removes [ LEADER_2 ],
+            ],
+        }
+    };
+
+    let mut controller = scenario_settings.build(context)?;
+
+    let leader1 =
+        controller.spawn_node(LEADER_1, LeadershipMode::Leader, PersistenceMode::InMemory)?;
+    leader1.wait_for_bootstrap()?;
+
+    let leader2 =
+        controller.spawn_node(LEADER_2, LeadershipMode::Leader, PersistenceMode::InMemory)?;
+    leader2.wait_for_bootstrap()?;
+
+    let leader3 =
+        controller.spawn_node(LEADER_3, LeadershipMode::Leader, PersistenceMode::InMemory)?;
+    leader3.wait_for_bootstrap()?;
+
+    controller.monitor_nodes();
+
+    let votes =
+        controller.propose_bft_rotation("swap", LEADER_1, &[LEADER_1, LEADER_2], &leader1)?;
+    FragmentVerifier::wait_and_verify_all_are_in_block(Duration::from_secs(2), votes, &leader1)?;
+
+    let rotation_epoch = last_block_epoch(&leader1)? + 1;
+    while last_block_epoch(&leader1)? <= rotation_epoch {
+        utils::wait(1);
+    }
+
+    let scheduled_in_rotation_epoch = |node: &NodeController| -> Result<bool> {
+        Ok(node
+            .leadership_log()?
+            .iter()
+            .any(|log| log.scheduled_at_date().epoch() == rotation_epoch))
+    };
+
+    utils::assert(
+        scheduled_in_rotation_epoch(&leader3)?,
+        "the added leader should create blocks after the rotation",
+    )?;
+    utils::assert(
+        !scheduled_in_rotation_epoch(&leader2)?,
+        "the removed leader should not create blocks after the rotation",
+    )?;
+
+    leader3.shutdown()?;
+    leader2.shutdown()?;
+    leader1.shutdown()?;
+    controller.finalize();
+    Ok(ScenarioResult::passed(name))
+}
+
+fn last_block_epoch(node: &NodeController) -> Result<u32> {
+    let last_block_date = node
+        .stats()?
+        .stats
+        .and_then(|stats| stats.last_block_date)
+        .expect("node does not report the date of its last block");
+    let last_block_date: BlockDate = last_block_date.parse().expect("invalid block date");
+    Ok(last_block_date.epoch())
+}
@@ -0,0 +1,79 @@
+use crate::{
+    node::{LeadershipMode, PersistenceMode},
+    test::{
+        utils::{self, SyncWaitParams},
+        Result,
+    },
+    Context, ScenarioResult,
+};
+use function_name::named;
+use jormungandr_testing_utils::testing::{
+    ensure_nodes_are_in_sync,
+    network_builder::{Node, TopologyBuilder},
+};
+use rand_chacha::ChaChaRng;
+
+const LEADER_1: &str = "Leader1";
+const LEADER_2: &str = "Leader2";
+const PASSIVE_1: &str = "Passive1";
+const PASSIVE_2: &str = "Passive2";
+const PASSIVE_3: &str = "Passive3";
+
+#[named]
+pub fn grow_running_network(mut context: Context<ChaChaRng>) -> Result<ScenarioResult> {
+    let name = function_name!();
+    let scenario_settings = prepare_scenario! {
+        name,
+        &mut context,
+        topology [
+            LEADER_1,
+            LEADER_2 -> LEADER_1,
+        ]
+        blockchain {
+            consensus = GenesisPraos,
+            number_of_slots_per_epoch = 60,
+            slot_duration = 1,
+            leaders = [ LEADER_1 ],
+            initials = [
+                "account" "delegated1" with  2_000_000_000 delegates to LEADER_1,
+                "account" "delegated2" with  2_000_000_000 delegates to LEADER_2,
+            ],
+        }
+    };
+
+    let mut controller = scenario_settings.build(context)?;
+
+    let mut nodes = Vec::new();
+    for alias in &[LEADER_1, LEADER_2] {
+        let node =
+            controller.spawn_node(alias, LeadershipMode::Leader, PersistenceMode::InMemory)?;
+        node.wait_for_bootstrap()?;
+        nodes.push(node);
+    }
+    utils::wait(20);
+
+    // late joiners trusting the running nodes as well as each other
+    let mut delta = TopologyBuilder::new();
+    let mut passive_1 = Node::new(PASSIVE_1);
+    passive_1.add_trusted_peer(LEADER_1);
+    delta.register_node(passive_1);
+    let mut passive_2 = Node::new(PASSIVE_2);
+    passive_2.add_trusted_peer(LEADER_2);
+    delta.register_node(passive_2);
+    let mut passive_3 = Node::new(PASSIVE_3);
+    passive_3.add_trusted_peer(PASSIVE_1);
+    passive_3.add_trusted_peer(PASSIVE_2);
+    delta.register_node(passive_3);
+
+    let late_joiners = controller.grow(delta.build_on(controller.topology()))?;
+    nodes.extend(late_joiners);
+
+    let node_refs: Vec<_> = nodes.iter().collect();
+    ensure_nodes_are_in_sync(SyncWaitParams::nodes_restart(5), &node_refs)?;
+
+    for node in nodes {
+        node.shutdown()?;
+    }
+    controller.finalize();
+    Ok(ScenarioResult::passed(name))
+}
@@ -1,3 +1,7 @@
 pub mod bft;
+pub mod grow;
+pub mod partition;
 pub mod real;
+pub mod snapshot;
 pub mod topology;
+pub mod wan;
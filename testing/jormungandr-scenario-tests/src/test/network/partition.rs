@@ -0,0 +1,79 @@
+use crate::{
+    node::{LeadershipMode, PersistenceMode},
+    test::{
+        utils::{self, SyncWaitParams},
+        Result,
+    },
+    Context, ScenarioResult,
+};
+use function_name::named;
+use jormungandr_testing_utils::testing::{
+    ensure_partitions_converged, ensure_partitions_diverged, network_builder::Partition,
+};
+use rand_chacha::ChaChaRng;
+
+const LEADER_1: &str = "Leader1";
+const LEADER_2: &str = "Leader2";
+const LEADER_3: &str = "Leader3";
+const LEADER_4: &str = "Leader4";
+
+#[named]
+pub fn partition_and_heal(mut context: Context<ChaChaRng>) -> Result<ScenarioResult> {
+    let name = function_name!();
+    let scenario_settings = prepare_scenario! {
+        name,
+        &mut context,
+        topology [
+            LEADER_1,
+            LEADER_2 -> LEADER_1,
+            LEADER_3 -> LEADER_1,
+            LEADER_4 -> LEADER_2, LEADER_3,
+        ]
+        blockchain {
+            consensus = GenesisPraos,
+            number_of_slots_per_epoch = 60,
+            slot_duration = 1,
+            leaders = [ LEADER_1 ],
+            initials = [
+                "account" "delegated1" with  2_000_000_000 delegates to LEADER_1,
+                "account" "delegated2" with  2_000_000_000 delegates to LEADER_2,
+                "account" "delegated3" with  2_000_000_000 delegates to LEADER_3,
+                "account" "delegated4" with  2_000_000_000 delegates to LEADER_4,
+            ],
+        }
+    };
+
+    let mut controller = scenario_settings.build(context)?;
+
+    let mut nodes = Vec::new();
+    for alias in &[LEADER_1, LEADER_2, LEADER_3, LEADER_4] {
+        let node =
+            controller.spawn_node(alias, LeadershipMode::Leader, PersistenceMode::Persistent)?;
+        node.wait_for_bootstrap()?;
+        nodes.push(node);
+    }
+    utils::wait(10);
+
+    let partition = Partition::new()
+        .group(vec![LEADER_1, LEADER_2])
+        .group(vec![LEADER_3, LEADER_4]);
+    let nodes = controller.partition(nodes, &partition)?;
+    utils::wait(60);
+
+    let (left, right): (Vec<_>, Vec<_>) = nodes
+        .iter()
+        .partition(|node| !partition.are_isolated(LEADER_1, node.alias()));
+    ensure_partitions_diverged(&[left, right])?;
+
+    let nodes = controller.heal_partition(nodes)?;
+    let (left, right): (Vec<_>, Vec<_>) = nodes
+        .iter()
+        .partition(|node| !partition.are_isolated(LEADER_1, node.alias()));
+    ensure_partitions_converged(SyncWaitParams::nodes_restart(4), &[left, right])?;
+
+    for node in nodes {
+        node.shutdown()?;
+    }
+    controller.finalize();
+    Ok(ScenarioResult::passed(name))
+}
@@ -0,0 +1,101 @@
+use crate::{
+    node::{LeadershipMode, PersistenceMode},
+    test::{
+        utils::{self, SyncWaitParams},
+        Error, Result,
+    },
+    Context, ScenarioResult,
+};
+use function_name::named;
+use jormungandr_testing_utils::testing::{ensure_nodes_are_in_sync, FragmentVerifier, SyncNode};
+use rand_chacha::ChaChaRng;
+use std::time::Duration;
+
+const ALICE: &str = "Alice";
+const BOB: &str = "Bob";
+const LEADER_1: &str = "Leader1";
+const LEADER_2: &str = "Leader2";
+
+#[named]
+pub fn snapshot_and_restore(mut context: Context<ChaChaRng>) -> Result<ScenarioResult> {
+    let name = function_name!();
+    let scenario_settings = prepare_scenario! {
+        name,
+        &mut context,
+        topology [
+            LEADER_1,
+            LEADER_2 -> LEADER_1,
+        ]
+        blockchain {
+            consensus = GenesisPraos,
+            number_of_slots_per_epoch = 60,
+            slot_duration = 1,
+            leaders = [ LEADER_1 ],
+            initials = [
+                "account" ALICE with  2_000_000_000 delegates to LEADER_1,
+                "account" BOB with  2_000_000_000 delegates to LEADER_2,
+            ],
+        }
+    };
+
+    let mut controller = scenario_settings.build(context)?;
+    let snapshot_dir = controller.working_directory().path().join("snapshot");
+
+    let mut nodes = Vec::new();
+    for alias in &[LEADER_1, LEADER_2] {
+        let node =
+            controller.spawn_node(alias, LeadershipMode::Leader, PersistenceMode::Persistent)?;
+        node.wait_for_bootstrap()?;
+        nodes.push(node);
+    }
+    utils::wait(20);
+
+    let wallets = vec![controller.wallet(ALICE)?, controller.wallet(BOB)?];
+    let (nodes, _) = controller.snapshot(nodes, &wallets, &snapshot_dir)?;
+    let snapshot_height = nodes[0].last_block_height();
+
+    // move the network further, so restore has something to revert
+    let mut alice = wallets[0].clone();
+    controller.fragment_sender().send_transaction(
+        &mut alice,
+        &wallets[1],
+        &nodes[0],
+        1_000.into(),
+    )?;
+    utils::wait(20);
+    for node in nodes {
+        node.shutdown()?;
+    }
+
+    let (nodes, mut wallets) = controller.restore(&snapshot_dir)?;
+    if nodes
+        .iter()
+        .any(|node| node.last_block_height() < snapshot_height)
+    {
+        return Err(Error::AssertionFailed(format!(
+            "restored network is behind the snapshot height {}",
+            snapshot_height
+        )));
+    }
+    let node_refs: Vec<_> = nodes.iter().collect();
+    ensure_nodes_are_in_sync(SyncWaitParams::two_nodes(), &node_refs)?;
+
+    // wallet counter was restored along with the chain, so it is still valid
+    let bob = wallets.pop().unwrap();
+    let mut alice = wallets.pop().unwrap();
+    let mem_pool_check =
+        controller
+            .fragment_sender()
+            .send_transaction(&mut alice, &bob, &nodes[0], 1_000.into())?;
+    FragmentVerifier::wait_and_verify_is_in_block(
+        Duration::from_secs(10),
+        mem_pool_check,
+        &nodes[1],
+    )?;
+
+    for node in nodes {
+        node.shutdown()?;
+    }
+    controller.finalize();
+    Ok(ScenarioResult::passed(name))
+}
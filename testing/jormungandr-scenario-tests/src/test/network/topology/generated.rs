@@ -0,0 +1,120 @@
+use crate::{
+    node::{LeadershipMode, PersistenceMode},
+    scenario::{
+        repository::ScenarioResult, ActiveSlotCoefficient, ConsensusVersion, ControllerBuilder,
+        KesUpdateSpeed, Milli, NumberOfSlotsPerEpoch, SlotDuration, Value,
+    },
+    test::{
+        utils::{self, MeasurementReportInterval, SyncWaitParams},
+        Result,
+    },
+    Context,
+};
+use jormungandr_testing_utils::testing::network_builder::{
+    Blockchain, TopologyGenerator, WalletTemplate,
+};
+use rand_chacha::ChaChaRng;
+
+const NODES_COUNT: usize = 10;
+
+fn wallet_name(i: usize) -> String {
+    format!("wallet_{}", i)
+}
+
+fn prepare_generated_scenario(
+    title: &str,
+    generator: &TopologyGenerator,
+    context: &Context<ChaChaRng>,
+) -> ControllerBuilder {
+    let mut builder = ControllerBuilder::new(title);
+    builder.set_topology(generator.build());
+
+    let mut blockchain = Blockchain::new(
+        ConsensusVersion::GenesisPraos,
+        NumberOfSlotsPerEpoch::new(60).expect("valid number of slots per epoch"),
+        SlotDuration::new(1).expect("valid slot duration in seconds"),
+        KesUpdateSpeed::new(46800).expect("valid kes update speed in seconds"),
+        ActiveSlotCoefficient::new(Milli::from_millis(700))
+            .expect("active slot coefficient in millis"),
+    );
+
+    for (i, alias) in generator.aliases().into_iter().enumerate() {
+        blockchain.add_leader(alias.clone());
+        let mut wallet = WalletTemplate::new_account(
+            wallet_name(i),
+            Value(1_000_000_000),
+            blockchain.discrimination(),
+        );
+        *wallet.delegate_mut() = Some(alias);
+        blockchain.add_wallet(wallet);
+    }
+
+    builder.set_blockchain(blockchain);
+    builder.build_settings(&mut context.clone());
+    builder
+}
+
+fn generated_topology(
+    context: Context<ChaChaRng>,
+    name: &str,
+    generator: TopologyGenerator,
+) -> Result<ScenarioResult> {
+    let scenario_settings = prepare_generated_scenario(name, &generator, &context);
+    let mut controller = scenario_settings.build(context)?;
+    controller.monitor_nodes();
+
+    let mut nodes = Vec::new();
+    for alias in generator.aliases() {
+        let node =
+            controller.spawn_node(&alias, LeadershipMode::Leader, PersistenceMode::InMemory)?;
+        node.wait_for_bootstrap()?;
+        nodes.push(node);
+    }
+
+    let sync_nodes: Vec<_> = nodes.iter().collect();
+    utils::measure_and_log_sync_time(
+        &sync_nodes,
+        SyncWaitParams::large_network(nodes.len() as u64).into(),
+        &format!("{}_sync", name),
+        MeasurementReportInterval::Standard,
+    )?;
+
+    for node in nodes {
+        node.shutdown()?;
+    }
+    controller.finalize();
+    Ok(ScenarioResult::passed(name))
+}
+
+pub fn generated_ring(context: Context<ChaChaRng>) -> Result<ScenarioResult> {
+    generated_topology(
+        context,
+        "generated_ring",
+        TopologyGenerator::ring(NODES_COUNT),
+    )
+}
+
+pub fn generated_star(context: Context<ChaChaRng>) -> Result<ScenarioResult> {
+    generated_topology(
+        context,
+        "generated_star",
+        TopologyGenerator::star(NODES_COUNT),
+    )
+}
+
+pub fn generated_mesh(context: Context<ChaChaRng>) -> Result<ScenarioResult> {
+    generated_topology(
+        context,
+        "generated_mesh",
+        TopologyGenerator::mesh(NODES_COUNT),
+    )
+}
+
+pub fn generated_random_graph(context: Context<ChaChaRng>) -> Result<ScenarioResult> {
+    let seed = *context.seed();
+    generated_topology(
+        context,
+        "generated_random_graph",
+        TopologyGenerator::random(NODES_COUNT, 0.2).seed(seed),
+    )
+}
@@ -1 +1,2 @@
+pub mod generated;
 pub mod scenarios;
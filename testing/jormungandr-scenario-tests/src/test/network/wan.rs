@@ -0,0 +1,93 @@
+use crate::{
+    node::{LeadershipMode, PersistenceMode},
+    test::{
+        utils::{self, MeasurementReportInterval, SyncWaitParams},
+        Result,
+    },
+    Context, ScenarioResult,
+};
+use function_name::named;
+use jormungandr_testing_utils::testing::network_builder::NetworkConditions;
+use rand_chacha::ChaChaRng;
+use std::time::Duration;
+
+const LEADER_1: &str = "Leader1";
+const LEADER_2: &str = "Leader2";
+const LEADER_3: &str = "Leader3";
+const PASSIVE: &str = "Passive";
+
+#[named]
+pub fn wan_like_network(mut context: Context<ChaChaRng>) -> Result<ScenarioResult> {
+    let name = function_name!();
+    let scenario_settings = prepare_scenario! {
+        name,
+        &mut context,
+        topology [
+            LEADER_1,
+            LEADER_2 -> LEADER_1,
+            LEADER_3 -> LEADER_1,
+            PASSIVE -> LEADER_2, LEADER_3,
+        ]
+        blockchain {
+            consensus = GenesisPraos,
+            number_of_slots_per_epoch = 60,
+            slot_duration = 2,
+            leaders = [ LEADER_1 ],
+            initials = [
+                "account" "delegated1" with  2_000_000_000 delegates to LEADER_1,
+                "account" "delegated2" with  2_000_000_000 delegates to LEADER_2,
+                "account" "delegated3" with  2_000_000_000 delegates to LEADER_3,
+            ],
+        }
+    };
+
+    let mut controller = scenario_settings.build(context)?;
+
+    let leader1 =
+        controller.spawn_node(LEADER_1, LeadershipMode::Leader, PersistenceMode::InMemory)?;
+    leader1.wait_for_bootstrap()?;
+
+    let mut params = controller.new_spawn_params(LEADER_2);
+    params.in_memory().network_conditions(
+        NetworkConditions::new()
+            .latency(Duration::from_millis(150))
+            .jitter(Duration::from_millis(50)),
+    );
+    let leader2 = controller.spawn_node_custom(&mut params)?;
+
+    let mut params = controller.new_spawn_params(LEADER_3);
+    params.in_memory().network_conditions(
+        NetworkConditions::new()
+            .latency(Duration::from_millis(300))
+            .packet_loss(0.05),
+    );
+    let leader3 = controller.spawn_node_custom(&mut params)?;
+
+    let mut params = controller.new_spawn_params(PASSIVE);
+    params.passive().in_memory().network_conditions(
+        NetworkConditions::new()
+            .latency(Duration::from_millis(100))
+            .jitter(Duration::from_millis(100))
+            .packet_loss(0.02),
+    );
+    let passive = controller.spawn_node_custom(&mut params)?;
+
+    leader2.wait_for_bootstrap()?;
+    leader3.wait_for_bootstrap()?;
+    passive.wait_for_bootstrap()?;
+
+    utils::wait(60);
+    utils::measure_and_log_sync_time(
+        &[&leader1, &leader2, &leader3, &passive],
+        SyncWaitParams::network_size(4, 3).into(),
+        name,
+        MeasurementReportInterval::Standard,
+    )?;
+
+    leader1.shutdown()?;
+    leader2.shutdown()?;
+    leader3.shutdown()?;
+    passive.shutdown()?;
+    controller.finalize();
+    Ok(ScenarioResult::passed(name))
+}
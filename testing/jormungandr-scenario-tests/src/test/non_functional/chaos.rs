@@ -0,0 +1,125 @@
+use crate::{
+    chaos::{ChaosAction, ChaosController, ChaosSchedule},
+    node::LeadershipMode,
+    scenario::repository::ScenarioResult,
+    test::{non_functional::*, utils::SyncWaitParams, Result},
+    Context,
+};
+use function_name::named;
+use rand_chacha::ChaChaRng;
+use std::time::Duration;
+
+#[named]
+pub fn chaos_scheduled_disruption(mut context: Context<ChaChaRng>) -> Result<ScenarioResult> {
+    let name = function_name!();
+    let scenario_settings = prepare_scenario! {
+        name,
+        &mut context,
+        topology [
+            LEADER_1,
+            LEADER_2 -> LEADER_1,
+            LEADER_3 -> LEADER_1,
+            PASSIVE -> LEADER_2 -> LEADER_3,
+        ]
+        blockchain {
+            consensus = GenesisPraos,
+            number_of_slots_per_epoch = 60,
+            slot_duration = 1,
+            leaders = [ LEADER_1 ],
+            initials = [
+                "account" "delegated1" with  2_000_000_000 delegates to LEADER_1,
+                "account" "delegated2" with  2_000_000_000 delegates to LEADER_2,
+                "account" "delegated3" with  2_000_000_000 delegates to LEADER_3,
+            ],
+        }
+    };
+
+    let mut controller = scenario_settings.build(context)?;
+
+    let mut chaos = ChaosController::new(&mut controller);
+    chaos.spawn_node(LEADER_1, LeadershipMode::Leader)?;
+    chaos.spawn_node(LEADER_2, LeadershipMode::Leader)?;
+    chaos.spawn_node(LEADER_3, LeadershipMode::Leader)?;
+    chaos.spawn_node(PASSIVE, LeadershipMode::Passive)?;
+
+    let schedule = ChaosSchedule::fixed()
+        .at(
+            Duration::from_secs(10),
+            ChaosAction::Restart(LEADER_2.to_string()),
+        )
+        .at(
+            Duration::from_secs(20),
+            ChaosAction::Pause {
+                alias: LEADER_3.to_string(),
+                duration: Duration::from_secs(15),
+            },
+        )
+        .at(
+            Duration::from_secs(30),
+            ChaosAction::FillDisk {
+                alias: PASSIVE.to_string(),
+                size_in_mb: 50,
+            },
+        )
+        .at(
+            Duration::from_secs(40),
+            ChaosAction::Kill(PASSIVE.to_string()),
+        );
+
+    chaos.run(schedule, Duration::from_secs(60))?;
+    chaos.restore_and_verify(SyncWaitParams::nodes_restart(4))?;
+
+    for node in chaos.into_nodes() {
+        node.shutdown()?;
+    }
+    controller.finalize();
+    Ok(ScenarioResult::passed(name))
+}
+
+#[named]
+pub fn chaos_random_disruption(mut context: Context<ChaChaRng>) -> Result<ScenarioResult> {
+    let name = function_name!();
+    let seed = *context.seed();
+    let scenario_settings = prepare_scenario! {
+        name,
+        &mut context,
+        topology [
+            LEADER_1,
+            LEADER_2 -> LEADER_1,
+            LEADER_3 -> LEADER_1 -> LEADER_2,
+            LEADER_4 -> LEADER_2 -> LEADER_3,
+        ]
+        blockchain {
+            consensus = GenesisPraos,
+            number_of_slots_per_epoch = 60,
+            slot_duration = 1,
+            leaders = [ LEADER_1 ],
+            initials = [
+                "account" "delegated1" with  2_000_000_000 delegates to LEADER_1,
+                "account" "delegated2" with  2_000_000_000 delegates to LEADER_2,
+                "account" "delegated3" with  2_000_000_000 delegates to LEADER_3,
+                "account" "delegated4" with  2_000_000_000 delegates to LEADER_4,
+            ],
+        }
+    };
+
+    let mut controller = scenario_settings.build(context)?;
+
+    let mut chaos = ChaosController::new(&mut controller);
+    for alias in &[LEADER_1, LEADER_2, LEADER_3, LEADER_4] {
+        chaos.spawn_node(alias, LeadershipMode::Leader)?;
+    }
+
+    let duration = Duration::from_secs(5 * 60);
+    chaos.run(
+        ChaosSchedule::random(seed, Duration::from_secs(20), duration),
+        duration,
+    )?;
+    chaos.restore_and_verify(SyncWaitParams::nodes_restart(4))?;
+
+    for node in chaos.into_nodes() {
+        node.shutdown()?;
+    }
+    controller.finalize();
+    Ok(ScenarioResult::passed(name))
+}
@@ -1,3 +1,4 @@
+pub mod chaos;
 pub mod desync;
 pub mod disruption;
 pub mod soak;
@@ -1,3 +1,5 @@
 fn main() {
-    tonic_build::compile_protos("proto/node.proto").unwrap();
+    // the protocols are the ones of the node itself
+    tonic_build::compile_protos("../../jormungandr/proto/node.proto").unwrap();
+    tonic_build::compile_protos("../../jormungandr/proto/local.proto").unwrap();
 }
@@ -7,6 +7,7 @@ use chain_impl_mockchain::{
     value::Value as ValueLib,
 };
 use jormungandr_lib::crypto::key::KeyPair;
+use rand_core::{CryptoRng, OsRng, RngCore};
 use std::num::NonZeroU64;
 
 #[derive(Clone, Debug)]
@@ -18,7 +19,17 @@ pub struct StakePool {
 
 impl StakePool {
     pub fn new(owner: &Wallet) -> Self {
-        let leader = KeyPair::<Ed25519>::generate(rand::rngs::OsRng);
+        Self::new_with_rng(owner, &mut OsRng)
+    }
+
+    /// Same as `new`, but the leader key is generated from `rng`. The VRF and
+    /// KES keys, and so the pool id, are still generated by the stake pool
+    /// builder of the ledger from the OS random source.
+    pub fn new_with_rng<RNG>(owner: &Wallet, rng: &mut RNG) -> Self
+    where
+        RNG: RngCore + CryptoRng,
+    {
+        let leader = KeyPair::<Ed25519>::generate(rng);
 
         let stake_pool = StakePoolBuilder::new()
             .with_owners(vec![owner.identifier().into_public_key()])
@@ -75,3 +86,24 @@ impl From<StakePool> for StakePoolLib {
         stake_pool.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::network_builder::{Random, Seed};
+    use std::str::FromStr;
+
+    #[test]
+    fn leader_keys_are_reproduced_from_the_seed() {
+        let seed = Seed::from_str(&hex::encode([7; 32])).unwrap();
+        let generate = |seed: Seed| {
+            let mut random = Random::new(seed);
+            let owner = Wallet::new_account(random.rng_mut());
+            let stake_pool = StakePool::new_with_rng(&owner, random.rng_mut());
+            (owner.identifier(), stake_pool.leader().identifier())
+        };
+
+        assert_eq!(generate(seed), generate(seed));
+        assert_ne!(generate(seed), generate(Seed::generate(OsRng)));
+    }
+}
@@ -0,0 +1,255 @@
+//! Dispenses funds from an account to the wallets of a test, so that the
+//! crates setting up a network (or a demo) can request funds uniformly
+//! instead of each one keeping track of the spending counter of the funding
+//! account.
+
+use crate::{
+    testing::{
+        node::{JormungandrRest, RestError},
+        MemPoolCheck,
+    },
+    wallet::{Wallet, WalletError},
+};
+use chain_core::property::Fragment as _;
+use chain_impl_mockchain::{
+    fee::{FeeAlgorithm, LinearFee},
+    fragment::FragmentId,
+};
+use jormungandr_lib::{
+    crypto::hash::Hash,
+    interfaces::{Address, BlockDate, FragmentLog, Value},
+};
+use std::{collections::HashMap, str::FromStr, sync::Mutex};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum FaucetError {
+    #[error("requested {requested} but the faucet dispenses at most {limit} per request")]
+    AboveLimit { requested: Value, limit: Value },
+    #[error("the faucet cannot dispense {requested}, only {available} is left after the fees and the pending transfers")]
+    NotEnoughFunds { requested: Value, available: Value },
+    #[error("the node does not report the date of its last block")]
+    UnknownBlockDate,
+    #[error("cannot parse the date of the last block '{0}'")]
+    InvalidBlockDate(String),
+    #[error("cannot parse the block0 hash")]
+    InvalidBlock0Hash(#[from] chain_crypto::hash::Error),
+    #[error(transparent)]
+    Rest(#[from] RestError),
+    #[error(transparent)]
+    Wallet(#[from] WalletError),
+}
+
+/// Account wallet dispensing funds through a node. The spending counter of
+/// the account is managed by the faucet: it is read from the node when the
+/// faucet is created and again once none of its transfers is pending, so
+/// that the faucet recovers from fragments rejected by the node.
+///
+/// The faucet can be shared between threads, the requests are served one at
+/// a time.
+pub struct Faucet {
+    state: Mutex<FaucetState>,
+    rest: JormungandrRest,
+    block0_hash: Hash,
+    fees: LinearFee,
+    limit: Option<Value>,
+}
+
+struct FaucetState {
+    wallet: Wallet,
+    /// the transfers sent to the node and not yet in a block, with the
+    /// amount they take from the account, fees included
+    pending: Vec<(FragmentId, u64)>,
+}
+
+impl Faucet {
+    pub fn new(mut wallet: Wallet, rest: JormungandrRest) -> Result<Self, FaucetError> {
+        let settings = rest.settings()?;
+        wallet.update_counter(rest.account_state(&wallet)?.counter());
+        Ok(Self {
+            state: Mutex::new(FaucetState {
+                wallet,
+                pending: Vec::new(),
+            }),
+            block0_hash: Hash::from_str(&settings.block0_hash)?,
+            fees: settings.fees,
+            rest,
+            limit: None,
+        })
+    }
+
+    /// Maximum value dispensed by a single request, unlimited by default.
+    pub fn with_limit(mut self, limit: Value) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn address(&self) -> Address {
+        self.state.lock().unwrap().wallet.address()
+    }
+
+    /// Funds left in the account of the faucet, according to the node.
+    pub fn balance(&self) -> Result<Value, FaucetError> {
+        let state = self.state.lock().unwrap();
+        Ok(*self.rest.account_state(&state.wallet)?.value())
+    }
+
+    /// Sends `value` to the given address. The transaction is valid until the
+    /// start of the next epoch, the caller can wait for it to be in a block
+    /// with the returned check.
+    pub fn dispense(&self, address: Address, value: Value) -> Result<MemPoolCheck, FaucetError> {
+        if let Some(limit) = self.limit {
+            if value > limit {
+                return Err(FaucetError::AboveLimit {
+                    requested: value,
+                    limit,
+                });
+            }
+        }
+
+        let mut state = self.state.lock().unwrap();
+        // the logs are read first: a transfer reaching a block in between is
+        // counted twice rather than not at all
+        let logs = self.rest.fragment_logs()?;
+        let account = self.rest.account_state(&state.wallet)?;
+        if settle_pending(&mut state.pending, &logs) && state.pending.is_empty() {
+            // some transfers were rejected: the counter of the node is the
+            // one to follow, which is only known once nothing is in flight
+            state.wallet.update_counter(account.counter());
+        }
+
+        let fee = self.fees.calculate(None, 1, 1).0;
+        let available = available_funds((*account.value()).into(), &state.pending, fee);
+        if u64::from(value) > available {
+            return Err(FaucetError::NotEnoughFunds {
+                requested: value,
+                available: available.into(),
+            });
+        }
+
+        let valid_until = self.current_date()?.next_epoch();
+        let fragment = state.wallet.transaction_to(
+            &self.block0_hash,
+            &self.fees,
+            valid_until.into(),
+            address,
+            value,
+        )?;
+        let id = fragment.id();
+        // on error the fragment is not counted as pending and the local
+        // counter is kept, it is resynchronised once nothing is in flight
+        let check = self.rest.send_fragment(fragment)?;
+        state.wallet.confirm_transaction();
+        state.pending.push((id, u64::from(value) + fee));
+        Ok(check)
+    }
+
+    /// Sends `value` to each of the wallets.
+    pub fn dispense_to_many(
+        &self,
+        wallets: &[&Wallet],
+        value: Value,
+    ) -> Result<Vec<MemPoolCheck>, FaucetError> {
+        wallets
+            .iter()
+            .map(|wallet| self.dispense(wallet.address(), value))
+            .collect()
+    }
+
+    fn current_date(&self) -> Result<BlockDate, FaucetError> {
+        let last_block_date = self
+            .rest
+            .stats()?
+            .stats
+            .and_then(|stats| stats.last_block_date)
+            .ok_or(FaucetError::UnknownBlockDate)?;
+        BlockDate::from_str(&last_block_date)
+            .map_err(|_| FaucetError::InvalidBlockDate(last_block_date))
+    }
+}
+
+/// Forgets the pending transfers which are in a block, and so accounted for
+/// by the balance of the account, or which were rejected or are not known to
+/// the node anymore. Tells whether any of them was dropped without being in
+/// a block.
+fn settle_pending(
+    pending: &mut Vec<(FragmentId, u64)>,
+    logs: &HashMap<FragmentId, FragmentLog>,
+) -> bool {
+    let mut failed = false;
+    pending.retain(|(id, _)| match logs.get(id) {
+        Some(log) if log.is_pending() => true,
+        Some(log) if log.is_in_a_block() => false,
+        _ => {
+            failed = true;
+            false
+        }
+    });
+    failed
+}
+
+/// The value the faucet can still dispense with a single transfer paying
+/// `fee`, once the pending transfers are in a block.
+fn available_funds(balance: u64, pending: &[(FragmentId, u64)], fee: u64) -> u64 {
+    let pending: u64 = pending.iter().map(|(_, amount)| amount).sum();
+    balance.saturating_sub(pending).saturating_sub(fee)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jormungandr_lib::interfaces::{FragmentOrigin, FragmentStatus};
+
+    fn log(id: FragmentId, status: Option<FragmentStatus>) -> (FragmentId, FragmentLog) {
+        let mut log = FragmentLog::new(id, FragmentOrigin::Rest);
+        if let Some(status) = status {
+            log.modify(status);
+        }
+        (id, log)
+    }
+
+    fn ids(count: u8) -> Vec<FragmentId> {
+        (0..count).map(|i| FragmentId::hash_bytes(&[i])).collect()
+    }
+
+    #[test]
+    fn fees_and_pending_transfers_are_not_available() {
+        let ids = ids(2);
+        assert_eq!(available_funds(100, &[], 1), 99);
+        assert_eq!(available_funds(100, &[(ids[0], 30), (ids[1], 20)], 1), 49);
+        assert_eq!(available_funds(40, &[(ids[0], 30), (ids[1], 20)], 1), 0);
+    }
+
+    #[test]
+    fn settled_transfers_are_no_longer_pending() {
+        let ids = ids(3);
+        let logs: HashMap<_, _> = vec![
+            log(ids[0], None),
+            log(
+                ids[1],
+                Some(FragmentStatus::InABlock {
+                    date: chain_impl_mockchain::block::BlockDate::first().into(),
+                    block: FragmentId::hash_bytes(&[42]).into(),
+                }),
+            ),
+        ]
+        .into_iter()
+        .collect();
+
+        let mut pending = vec![(ids[0], 10), (ids[1], 10)];
+        assert!(!settle_pending(&mut pending, &logs));
+        assert_eq!(pending, vec![(ids[0], 10)]);
+
+        let logs: HashMap<_, _> = vec![log(
+            ids[0],
+            Some(FragmentStatus::Rejected {
+                reason: "invalid".to_string(),
+            }),
+        )]
+        .into_iter()
+        .collect();
+        let mut pending = vec![(ids[0], 10), (ids[2], 10)];
+        assert!(settle_pending(&mut pending, &logs));
+        assert!(pending.is_empty());
+    }
+}
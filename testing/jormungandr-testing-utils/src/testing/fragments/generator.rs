@@ -4,7 +4,10 @@ use crate::testing::FragmentVerifier;
 use crate::testing::SyncNode;
 use crate::{
     stake_pool::StakePool,
-    testing::{RemoteJormungandr, VotePlanBuilder},
+    testing::{
+        network_builder::{Random, Seed},
+        RemoteJormungandr, VotePlanBuilder,
+    },
     wallet::Wallet,
 };
 use chain_core::property::FromStr;
@@ -16,6 +19,7 @@ use chain_time::TimeEra;
 use jormungandr_lib::interfaces::BlockDate as BlockDateDto;
 use jortestkit::load::{Request, RequestFailure, RequestGenerator};
 use rand::RngCore;
+use rand_chacha::ChaChaRng;
 use rand_core::OsRng;
 use std::iter;
 use std::time::Duration;
@@ -28,7 +32,7 @@ pub struct FragmentGenerator<'a, S: SyncNode + Send> {
     vote_plans_for_casting: Vec<VotePlan>,
     vote_plans_for_tally: Vec<VotePlan>,
     node: RemoteJormungandr,
-    rand: OsRng,
+    rand: Random<ChaChaRng>,
     vote_cast_register: Option<VoteCastCounter>,
     slots_per_epoch: u32,
     fragment_sender: FragmentSender<'a, S>,
@@ -61,7 +65,7 @@ impl<'a, S: SyncNode + Send> FragmentGenerator<'a, S> {
             vote_plans_for_tally: vec![],
             node,
             vote_cast_register: None,
-            rand: OsRng,
+            rand: Random::new(Seed::generate(OsRng)),
             slots_per_epoch,
             fragment_sender,
             stake_pools_count,
@@ -70,6 +74,20 @@ impl<'a, S: SyncNode + Send> FragmentGenerator<'a, S> {
         }
     }
 
+    /// Replaces the random seed, so that a run can be reproduced: the kinds
+    /// of the fragments sent, their targets and the leader keys of the stake
+    /// pools are drawn from the seed.
+    pub fn with_seed(mut self, seed: Seed) -> Self {
+        self.rand = Random::new(seed);
+        self
+    }
+
+    /// The seed of the random choices, to be reported with the results of a
+    /// run.
+    pub fn seed(&self) -> &Seed {
+        self.rand.seed()
+    }
+
     pub fn active_stake_pools(&self) -> Vec<StakePool> {
         self.active_stake_pools.clone()
     }
@@ -78,9 +96,12 @@ impl<'a, S: SyncNode + Send> FragmentGenerator<'a, S> {
         let time_era = start_block_date.time_era(self.slots_per_epoch);
 
         let sender = self.sender.clone();
+        let stake_pools: Vec<StakePool> = (0..self.stake_pools_count)
+            .map(|_| StakePool::new_with_rng(&sender, self.rand.rng_mut()))
+            .collect();
 
-        let stake_pools: Vec<StakePool> = iter::from_fn(|| Some(StakePool::new(&sender)))
-            .take(self.stake_pools_count)
+        let stake_pools: Vec<StakePool> = stake_pools
+            .into_iter()
             .map(|stake_pool| {
                 let fragment = self
                     .fragment_sender
@@ -166,7 +187,7 @@ impl<'a, S: SyncNode + Send> FragmentGenerator<'a, S> {
     }
 
     pub fn send_random(&mut self) -> Result<MemPoolCheck, FragmentSenderError> {
-        let rand = self.rand.next_u32() as u8;
+        let rand = self.rand.rng_mut().next_u32() as u8;
         self.send_one(rand)
     }
 
@@ -187,7 +208,7 @@ impl<'a, S: SyncNode + Send> FragmentGenerator<'a, S> {
                 1.into(),
             ),
             1 => {
-                let index = self.rand.next_u32() as usize % self.active_stake_pools.len();
+                let index = self.rand.rng_mut().next_u32() as usize % self.active_stake_pools.len();
                 let stake_pool = self.active_stake_pools.get(index).unwrap();
 
                 self.fragment_sender
@@ -203,14 +224,14 @@ impl<'a, S: SyncNode + Send> FragmentGenerator<'a, S> {
                 )
             }
             3 => {
-                let index = self.rand.next_u32() as usize % self.active_stake_pools.len();
+                let index = self.rand.rng_mut().next_u32() as usize % self.active_stake_pools.len();
                 let stake_pool = self.active_stake_pools.get(index).unwrap();
 
                 self.fragment_sender
                     .send_owner_delegation(&mut self.sender, stake_pool, &self.node)
             }
             4 => {
-                let stake_pool = StakePool::new(&self.sender);
+                let stake_pool = StakePool::new_with_rng(&self.sender, self.rand.rng_mut());
                 self.active_stake_pools.push(stake_pool.clone());
                 self.fragment_sender.send_pool_registration(
                     &mut self.sender,
@@ -219,7 +240,7 @@ impl<'a, S: SyncNode + Send> FragmentGenerator<'a, S> {
                 )
             }
             5 => {
-                let index = self.rand.next_u32() as usize % self.active_stake_pools.len();
+                let index = self.rand.rng_mut().next_u32() as usize % self.active_stake_pools.len();
                 let stake_pool = self.active_stake_pools.get(index).unwrap();
                 self.fragment_sender.send_pool_update(
                     &mut self.sender,
@@ -229,7 +250,7 @@ impl<'a, S: SyncNode + Send> FragmentGenerator<'a, S> {
                 )
             }
             6 => {
-                let index = self.rand.next_u32() as usize % self.active_stake_pools.len();
+                let index = self.rand.rng_mut().next_u32() as usize % self.active_stake_pools.len();
                 let stake_pool = self.active_stake_pools.remove(index);
 
                 self.fragment_sender
@@ -287,7 +308,8 @@ impl<'a, S: SyncNode + Send> FragmentGenerator<'a, S> {
                 )
             }
             9 => {
-                let index = self.rand.next_u32() as usize % self.vote_plans_for_tally.len();
+                let index =
+                    self.rand.rng_mut().next_u32() as usize % self.vote_plans_for_tally.len();
                 let vote_plan = self.vote_plans_for_tally.get(index).unwrap();
 
                 self.fragment_sender.send_vote_tally(
@@ -1,5 +1,6 @@
 use crate::{stake_pool::StakePool, wallet::Wallet};
 use chain_impl_mockchain::{
+    account::{DelegationRatio, DelegationType},
     block::BlockDate,
     certificate::{
         PoolId, PoolOwnersSigned, PoolSignature, SignedCertificate, StakeDelegation, VotePlan,
@@ -12,10 +13,27 @@ pub fn signed_delegation_cert(
     wallet: &Wallet,
     valid_until: BlockDate,
     pool_id: PoolId,
+) -> SignedCertificate {
+    signed_stake_delegation(wallet, valid_until, DelegationType::Full(pool_id))
+}
+
+/// Delegation of the stake of `wallet` split between several stake pools.
+pub fn signed_split_delegation_cert(
+    wallet: &Wallet,
+    valid_until: BlockDate,
+    ratio: DelegationRatio,
+) -> SignedCertificate {
+    signed_stake_delegation(wallet, valid_until, DelegationType::Ratio(ratio))
+}
+
+fn signed_stake_delegation(
+    wallet: &Wallet,
+    valid_until: BlockDate,
+    delegation: DelegationType,
 ) -> SignedCertificate {
     let stake_delegation = StakeDelegation {
         account_id: wallet.stake_key().unwrap(),
-        delegation: chain_impl_mockchain::account::DelegationType::Full(pool_id),
+        delegation,
     };
     let txb = TxBuilder::new()
         .set_payload(&stake_delegation)
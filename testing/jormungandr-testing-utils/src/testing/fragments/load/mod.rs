@@ -1,13 +1,20 @@
 mod adversary_generator;
 mod adversary_vote_casts_generator;
 mod batch_generator;
+mod replay;
 mod status_provider;
+mod tps;
 mod transaction_generator;
 mod vote_casts_generator;
 
 pub use adversary_generator::AdversaryFragmentGenerator;
 pub use adversary_vote_casts_generator::AdversaryVoteCastsGenerator;
 pub use batch_generator::BatchFragmentGenerator;
+pub use replay::{
+    FragmentRemapper, PersistentLogReplay, ReplayConfig, ReplayEntry, ReplayError, ReplayReport,
+    ValidityRemap,
+};
 pub use status_provider::FragmentStatusProvider;
+pub use tps::{LatencySummary, TpsLoadConfig, TpsLoadDriver, TpsLoadReport};
 pub use transaction_generator::TransactionGenerator;
 pub use vote_casts_generator::VoteCastsGenerator;
@@ -0,0 +1,322 @@
+use crate::testing::{
+    fragments::{node::FragmentNode, FragmentBuilder},
+    VoteCastCounter,
+};
+use crate::wallet::Wallet;
+use chain_impl_mockchain::{
+    block::BlockDate,
+    certificate::VotePlan,
+    fee::LinearFee,
+    fragment::Fragment,
+    vote::{Choice, Payload, PayloadType},
+};
+use jormungandr_lib::{
+    crypto::hash::Hash,
+    interfaces::{load_persistent_fragments_logs_from_folder_path, FragmentLogDeserializeError},
+};
+use serde::Serialize;
+use std::path::Path;
+use std::time::{Duration, Instant};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ReplayError {
+    #[error("cannot read persistent log")]
+    Io(#[from] std::io::Error),
+    #[error("cannot deserialize persistent log entry")]
+    Deserialize(#[from] FragmentLogDeserializeError),
+    #[error("persistent log does not contain any fragment")]
+    EmptyLog,
+}
+
+/// Single fragment from the persistent log together with the moment it was
+/// accepted, relative to the first entry in the log
+pub struct ReplayEntry {
+    pub offset: Duration,
+    pub fragment: Fragment,
+}
+
+/// How validity dates of the replayed fragments are mapped into the test network
+#[derive(Debug, Clone, Copy)]
+pub enum ValidityRemap {
+    /// all fragments get the same expiry date
+    Fixed(BlockDate),
+    /// the earliest expiry epoch found in the log is mapped to given date, later ones
+    /// keep their distance (in epochs) from it
+    Shift(BlockDate),
+}
+
+impl ValidityRemap {
+    fn remap(&self, original: Option<BlockDate>, earliest_epoch: u32) -> BlockDate {
+        match (self, original) {
+            (ValidityRemap::Shift(origin), Some(original)) => BlockDate {
+                epoch: origin.epoch + original.epoch.saturating_sub(earliest_epoch),
+                slot_id: origin.slot_id,
+            },
+            (ValidityRemap::Shift(date), None) | (ValidityRemap::Fixed(date), _) => *date,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ReplayConfig {
+    /// replay speed multiplier, 1.0 keeps original pace, 2.0 replays twice as fast
+    pub speedup: f64,
+    pub validity: ValidityRemap,
+}
+
+impl ReplayConfig {
+    pub fn new(validity: ValidityRemap) -> Self {
+        Self {
+            speedup: 1.0,
+            validity,
+        }
+    }
+
+    pub fn speedup(mut self, speedup: f64) -> Self {
+        assert!(speedup > 0.0, "speedup must be positive");
+        self.speedup = speedup;
+        self
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ReplayReport {
+    pub total: usize,
+    pub sent: usize,
+    pub failed: usize,
+    /// fragments which have no equivalent in the test network (e.g. pool certificates)
+    pub skipped: usize,
+    pub original_duration_secs: f64,
+    pub duration_secs: f64,
+    /// sent fragments which were submitted later than scheduled by more than one second
+    pub late: usize,
+}
+
+impl ReplayReport {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+
+    pub fn print(&self) {
+        println!("{}", self.to_json());
+    }
+}
+
+/// Recreates production fragments using test network wallets. Signatures of the
+/// original fragments are bound to production keys and block0, so fragments are
+/// rebuilt (preserving kind, vote proposal and choice where possible)
+/// instead of being resent verbatim.
+pub struct FragmentRemapper {
+    block0_hash: Hash,
+    fees: LinearFee,
+    wallets: Vec<Wallet>,
+    vote_plan: Option<VotePlan>,
+    votes_register: Option<VoteCastCounter>,
+    send_marker: usize,
+}
+
+impl FragmentRemapper {
+    pub fn new(block0_hash: Hash, fees: LinearFee, wallets: Vec<Wallet>) -> Self {
+        assert!(
+            wallets.len() > 1,
+            "at least two wallets are required to replay transactions"
+        );
+        Self {
+            block0_hash,
+            fees,
+            wallets,
+            vote_plan: None,
+            votes_register: None,
+            send_marker: 0,
+        }
+    }
+
+    /// vote plan on which all replayed vote casts are going to be issued
+    pub fn with_vote_plan(mut self, vote_plan: VotePlan) -> Self {
+        self.votes_register = Some(VoteCastCounter::from_vote_plan(
+            self.wallets.len(),
+            &vote_plan,
+        ));
+        self.vote_plan = Some(vote_plan);
+        self
+    }
+
+    pub fn wallets(&self) -> &[Wallet] {
+        &self.wallets
+    }
+
+    fn next_wallet_idx(&mut self) -> usize {
+        self.send_marker = (self.send_marker + 1) % self.wallets.len();
+        self.send_marker
+    }
+
+    pub fn remap(&mut self, fragment: &Fragment, valid_until: BlockDate) -> Option<Fragment> {
+        let builder = FragmentBuilder::new(&self.block0_hash, &self.fees, valid_until);
+        match fragment {
+            Fragment::Transaction(_) => {
+                let from_idx = self.next_wallet_idx();
+                let to_idx = (from_idx + 1) % self.wallets.len();
+                let address = self.wallets[to_idx].address();
+                let fragment = builder
+                    .transaction(&self.wallets[from_idx], address, 1.into())
+                    .ok()?;
+                self.wallets[from_idx].confirm_transaction();
+                Some(fragment)
+            }
+            Fragment::VoteCast(tx) => {
+                let vote_cast = tx.as_slice().payload().into_payload();
+                let choice = match vote_cast.payload() {
+                    Payload::Public { choice } => *choice,
+                    Payload::Private { .. } => Choice::new(0),
+                };
+                let vote_plan = self.vote_plan.as_ref()?;
+                let votes_register = self.votes_register.as_mut()?;
+
+                for _ in 0..self.wallets.len() {
+                    self.send_marker = (self.send_marker + 1) % self.wallets.len();
+                    let proposal_index = match votes_register.advance_single(self.send_marker) {
+                        Ok(votes_to_cast) => votes_to_cast.get(0).unwrap().first(),
+                        Err(_) => continue,
+                    };
+                    let voter = &mut self.wallets[self.send_marker];
+                    let fragment = match vote_plan.payload_type() {
+                        PayloadType::Public => {
+                            builder.public_vote_cast(voter, vote_plan, proposal_index, &choice)
+                        }
+                        PayloadType::Private => {
+                            builder.private_vote_cast(voter, vote_plan, proposal_index, &choice)
+                        }
+                    };
+                    voter.confirm_transaction();
+                    return Some(fragment);
+                }
+                None
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Workload captured in the persistent fragment log of a (production) node
+pub struct PersistentLogReplay {
+    entries: Vec<ReplayEntry>,
+    earliest_validity_epoch: u32,
+}
+
+impl PersistentLogReplay {
+    pub fn from_dir<P: AsRef<Path>>(dir: P) -> Result<Self, ReplayError> {
+        let mut logs = Vec::new();
+        for entry in load_persistent_fragments_logs_from_folder_path(dir.as_ref())? {
+            logs.push(entry?);
+        }
+        logs.sort_by_key(|entry| entry.time.to_secs());
+
+        let start = logs.first().ok_or(ReplayError::EmptyLog)?.time.to_secs();
+        let earliest_validity_epoch = logs
+            .iter()
+            .filter_map(|entry| valid_until(&entry.fragment))
+            .map(|date| date.epoch)
+            .min()
+            .unwrap_or(0);
+
+        let entries = logs
+            .into_iter()
+            .map(|entry| ReplayEntry {
+                offset: Duration::from_secs(entry.time.to_secs() - start),
+                fragment: entry.fragment,
+            })
+            .collect();
+
+        Ok(Self {
+            entries,
+            earliest_validity_epoch,
+        })
+    }
+
+    pub fn entries(&self) -> &[ReplayEntry] {
+        &self.entries
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// time span between first and last fragment in the log
+    pub fn duration(&self) -> Duration {
+        self.entries
+            .last()
+            .map(|entry| entry.offset)
+            .unwrap_or_default()
+    }
+
+    /// Replays the workload against given node, keeping the original distribution of
+    /// fragments in time (scaled by `config.speedup`)
+    pub fn run<N: FragmentNode + ?Sized>(
+        &self,
+        config: &ReplayConfig,
+        remapper: &mut FragmentRemapper,
+        node: &N,
+    ) -> ReplayReport {
+        let mut report = ReplayReport {
+            total: self.entries.len(),
+            original_duration_secs: self.duration().as_secs_f64(),
+            ..Default::default()
+        };
+
+        let start = Instant::now();
+        for entry in &self.entries {
+            let scheduled = entry.offset.div_f64(config.speedup);
+            let elapsed = start.elapsed();
+            if scheduled > elapsed {
+                std::thread::sleep(scheduled - elapsed);
+            } else if elapsed - scheduled > Duration::from_secs(1) {
+                report.late += 1;
+            }
+
+            let valid_until = config
+                .validity
+                .remap(valid_until(&entry.fragment), self.earliest_validity_epoch);
+            let fragment = match remapper.remap(&entry.fragment, valid_until) {
+                Some(fragment) => fragment,
+                None => {
+                    report.skipped += 1;
+                    continue;
+                }
+            };
+
+            match node.send_fragment(fragment) {
+                Ok(_) => report.sent += 1,
+                Err(err) => {
+                    println!("fragment replay failed: {:?}", err);
+                    report.failed += 1;
+                }
+            }
+        }
+        report.duration_secs = start.elapsed().as_secs_f64();
+        report
+    }
+}
+
+fn valid_until(fragment: &Fragment) -> Option<BlockDate> {
+    match fragment {
+        Fragment::Transaction(tx) => Some(tx.as_slice().valid_until()),
+        Fragment::OwnerStakeDelegation(tx) => Some(tx.as_slice().valid_until()),
+        Fragment::StakeDelegation(tx) => Some(tx.as_slice().valid_until()),
+        Fragment::PoolRegistration(tx) => Some(tx.as_slice().valid_until()),
+        Fragment::PoolRetirement(tx) => Some(tx.as_slice().valid_until()),
+        Fragment::PoolUpdate(tx) => Some(tx.as_slice().valid_until()),
+        Fragment::VotePlan(tx) => Some(tx.as_slice().valid_until()),
+        Fragment::VoteCast(tx) => Some(tx.as_slice().valid_until()),
+        Fragment::VoteTally(tx) => Some(tx.as_slice().valid_until()),
+        Fragment::EncryptedVoteTally(tx) => Some(tx.as_slice().valid_until()),
+        Fragment::Initial(_)
+        | Fragment::OldUtxoDeclaration(_)
+        | Fragment::UpdateProposal(_)
+        | Fragment::UpdateVote(_) => None,
+    }
+}
@@ -0,0 +1,213 @@
+use crate::testing::fragments::node::FragmentNode;
+use jortestkit::load::RequestGenerator;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant, SystemTime};
+
+#[derive(Debug, Clone)]
+pub struct TpsLoadConfig {
+    /// Fragments per second which driver is trying to sustain
+    pub tps: u32,
+    /// For how long fragments are submitted
+    pub duration: Duration,
+    /// How long to wait for all submitted fragments to end up in block
+    /// after submission phase is finished
+    pub status_timeout: Duration,
+    pub status_poll_interval: Duration,
+}
+
+impl TpsLoadConfig {
+    pub fn new(tps: u32, duration: Duration) -> Self {
+        Self {
+            tps,
+            duration,
+            status_timeout: Duration::from_secs(120),
+            status_poll_interval: Duration::from_secs(1),
+        }
+    }
+
+    pub fn status_timeout(mut self, status_timeout: Duration) -> Self {
+        self.status_timeout = status_timeout;
+        self
+    }
+
+    pub fn status_poll_interval(mut self, status_poll_interval: Duration) -> Self {
+        self.status_poll_interval = status_poll_interval;
+        self
+    }
+
+    fn interval(&self) -> Duration {
+        Duration::from_secs(1) / self.tps.max(1)
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct LatencySummary {
+    pub min_ms: u128,
+    pub p50_ms: u128,
+    pub p95_ms: u128,
+    pub p99_ms: u128,
+    pub max_ms: u128,
+}
+
+impl LatencySummary {
+    pub fn from_latencies(mut latencies: Vec<Duration>) -> Self {
+        if latencies.is_empty() {
+            return Self::default();
+        }
+        latencies.sort();
+        let percentile = |p: usize| {
+            let index = ((latencies.len() * p + 99) / 100).max(1) - 1;
+            latencies[index.min(latencies.len() - 1)].as_millis()
+        };
+        Self {
+            min_ms: latencies.first().unwrap().as_millis(),
+            p50_ms: percentile(50),
+            p95_ms: percentile(95),
+            p99_ms: percentile(99),
+            max_ms: latencies.last().unwrap().as_millis(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TpsLoadReport {
+    pub target_tps: u32,
+    pub achieved_tps: f64,
+    pub duration_secs: f64,
+    pub submitted: usize,
+    pub submission_failures: usize,
+    pub in_block: usize,
+    pub rejected: usize,
+    pub pending: usize,
+    pub latency: LatencySummary,
+}
+
+impl TpsLoadReport {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+
+    pub fn print(&self) {
+        println!("{}", self.to_json());
+    }
+}
+
+/// Load driver which submits fragments at a fixed rate using provided request
+/// generators (in round robin manner) and measures the time between submission
+/// and the moment fragment was put in a block.
+pub struct TpsLoadDriver<G: RequestGenerator> {
+    config: TpsLoadConfig,
+    generators: Vec<G>,
+}
+
+impl<G: RequestGenerator> TpsLoadDriver<G> {
+    pub fn new(config: TpsLoadConfig, generators: Vec<G>) -> Self {
+        assert!(
+            !generators.is_empty(),
+            "at least one request generator is required"
+        );
+        Self { config, generators }
+    }
+
+    pub fn run<N: FragmentNode + ?Sized>(mut self, nodes: &[&N]) -> TpsLoadReport {
+        let mut submitted: HashMap<String, SystemTime> = HashMap::new();
+        let mut submission_failures = 0;
+
+        let interval = self.config.interval();
+        let start = Instant::now();
+        let mut next_tick = start;
+        let mut counter = 0usize;
+
+        while start.elapsed() < self.config.duration {
+            let now = Instant::now();
+            if next_tick > now {
+                std::thread::sleep(next_tick - now);
+            }
+            next_tick += interval;
+
+            let generator_idx = counter % self.generators.len();
+            counter += 1;
+            let submitted_at = SystemTime::now();
+            match self.generators[generator_idx].next() {
+                Ok(request) => {
+                    for id in request.ids.into_iter().flatten() {
+                        submitted.insert(id, submitted_at);
+                    }
+                }
+                Err(err) => {
+                    println!("fragment submission failed: {:?}", err);
+                    submission_failures += 1;
+                }
+            }
+        }
+        let submission_duration = start.elapsed();
+
+        let (latencies, rejected) = self.collect_latencies(&submitted, nodes);
+
+        TpsLoadReport {
+            target_tps: self.config.tps,
+            achieved_tps: submitted.len() as f64 / submission_duration.as_secs_f64(),
+            duration_secs: submission_duration.as_secs_f64(),
+            submitted: submitted.len(),
+            submission_failures,
+            in_block: latencies.len(),
+            rejected,
+            pending: submitted.len() - latencies.len() - rejected,
+            latency: LatencySummary::from_latencies(latencies),
+        }
+    }
+
+    fn collect_latencies<N: FragmentNode + ?Sized>(
+        &self,
+        submitted: &HashMap<String, SystemTime>,
+        nodes: &[&N],
+    ) -> (Vec<Duration>, usize) {
+        let mut in_block: HashMap<String, Duration> = HashMap::new();
+        let mut rejected: HashSet<String> = HashSet::new();
+        let start = Instant::now();
+
+        loop {
+            for node in nodes {
+                let fragment_logs = match node.fragment_logs() {
+                    Ok(fragment_logs) => fragment_logs,
+                    Err(err) => {
+                        println!("cannot get fragment logs from {}: {:?}", node.alias(), err);
+                        continue;
+                    }
+                };
+
+                for (id, fragment_log) in fragment_logs {
+                    let id = id.to_string();
+                    let submitted_at = match submitted.get(&id) {
+                        Some(submitted_at) => submitted_at,
+                        None => continue,
+                    };
+                    if fragment_log.is_in_a_block() {
+                        let in_block_at: SystemTime = (*fragment_log.last_updated_at()).into();
+                        let latency = in_block_at
+                            .duration_since(*submitted_at)
+                            .unwrap_or_else(|_| Duration::from_secs(0));
+                        // the earliest node which put fragment in block wins
+                        in_block
+                            .entry(id)
+                            .and_modify(|existing| *existing = (*existing).min(latency))
+                            .or_insert(latency);
+                    } else if fragment_log.is_rejected() {
+                        rejected.insert(id);
+                    }
+                }
+            }
+
+            rejected.retain(|id| !in_block.contains_key(id));
+            if in_block.len() + rejected.len() >= submitted.len()
+                || start.elapsed() > self.config.status_timeout
+            {
+                break;
+            }
+            std::thread::sleep(self.config.status_poll_interval);
+        }
+
+        (in_block.values().cloned().collect(), rejected.len())
+    }
+}
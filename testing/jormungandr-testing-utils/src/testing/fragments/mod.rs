@@ -6,34 +6,53 @@ pub use self::{
     chain_sender::FragmentChainSender,
     export::{FragmentExporter, FragmentExporterError},
     generator::FragmentGenerator,
-    initial_certificates::{signed_delegation_cert, signed_stake_pool_cert, vote_plan_cert},
+    initial_certificates::{
+        signed_delegation_cert, signed_split_delegation_cert, signed_stake_pool_cert,
+        vote_plan_cert,
+    },
     node::{FragmentNode, FragmentNodeError, MemPoolCheck},
     persistent_log::PersistentLogViewer,
+    propagation::{NodePropagation, PropagationError, PropagationReport, PropagationVerifier},
     sender::{FragmentSender, FragmentSenderError},
     setup::DummySyncNode,
-    setup::{FragmentSenderSetup, FragmentSenderSetupBuilder, VerifyStrategy},
-    transaction::{transaction_to, transaction_to_many},
+    setup::{BatchLimits, FragmentSenderSetup, FragmentSenderSetupBuilder, VerifyStrategy},
+    tally::{TallyVerifier, TallyVerifierError},
+    transaction::{
+        certificate_auth_data, signed_certificate, transaction_to, transaction_to_many,
+        transaction_to_many_with_values,
+    },
     verifier::{ExitStrategy as VerifyExitStrategy, FragmentVerifier, FragmentVerifierError},
 };
 use crate::{stake_pool::StakePool, wallet::Wallet};
-use chain_impl_mockchain::{block::BlockDate, certificate::VoteTallyPayload};
 use chain_impl_mockchain::{
-    certificate::{EncryptedVoteTally, PoolId, VoteCast, VotePlan, VoteTally},
+    account::DelegationRatio, block::BlockDate, certificate::VoteTallyPayload,
+};
+use chain_impl_mockchain::{
+    certificate::{
+        Certificate, EncryptedVoteTally, PoolId, SignedCertificate, VoteCast, VotePlan, VoteTally,
+    },
+    config::ConfigParams,
     fee::LinearFee,
-    fragment::Fragment,
+    fragment::{Fragment, FragmentId},
     testing::{
         data::{StakePool as StakePoolLib, Wallet as WalletLib},
         scenario::FragmentFactory,
     },
+    update::{
+        SignedUpdateProposal, SignedUpdateVote, UpdateProposal, UpdateProposalWithProposer,
+        UpdateVote,
+    },
     vote::{Choice, Payload},
 };
 use jormungandr_lib::{
     crypto::hash::Hash,
-    interfaces::{Address, Initial, Value},
+    interfaces::{Address, ConsensusLeaderId, Initial, Value},
 };
 pub use load::{
     AdversaryFragmentGenerator, AdversaryVoteCastsGenerator, BatchFragmentGenerator,
-    FragmentStatusProvider, TransactionGenerator, VoteCastsGenerator,
+    FragmentRemapper, FragmentStatusProvider, LatencySummary, PersistentLogReplay, ReplayConfig,
+    ReplayEntry, ReplayError, ReplayReport, TpsLoadConfig, TpsLoadDriver, TpsLoadReport,
+    TransactionGenerator, ValidityRemap, VoteCastsGenerator,
 };
 use rand_chacha::ChaCha20Rng;
 use rand_core::SeedableRng;
@@ -47,8 +66,10 @@ mod initial_certificates;
 mod load;
 mod node;
 mod persistent_log;
+mod propagation;
 mod sender;
 mod setup;
+mod tally;
 mod transaction;
 mod verifier;
 
@@ -115,6 +136,45 @@ impl FragmentBuilder {
         )
     }
 
+    pub fn transaction_to_many_with_values(
+        &self,
+        from: &Wallet,
+        outputs: &[(Address, Value)],
+    ) -> Result<Fragment, FragmentBuilderError> {
+        transaction_to_many_with_values(
+            &self.block0_hash,
+            &self.fees,
+            self.valid_until,
+            from,
+            outputs,
+        )
+    }
+
+    /// Wraps an already signed certificate of any type into a fragment, balanced and
+    /// witnessed by `funder`. Certificates without a dedicated helper can be signed
+    /// over [`certificate_auth_data`](Self::certificate_auth_data) first.
+    pub fn certificate(&self, funder: &Wallet, certificate: SignedCertificate) -> Fragment {
+        signed_certificate(
+            &self.block0_hash,
+            &self.fees,
+            self.valid_until,
+            funder,
+            certificate,
+        )
+    }
+
+    /// Data the payload authentication of `certificate` has to sign for the fragment
+    /// later built by [`certificate`](Self::certificate) with the same `funder`.
+    pub fn certificate_auth_data(&self, funder: &Wallet, certificate: &Certificate) -> Vec<u8> {
+        certificate_auth_data(
+            &self.block0_hash,
+            &self.fees,
+            self.valid_until,
+            funder,
+            certificate,
+        )
+    }
+
     pub fn full_delegation_cert_for_block0(
         valid_until: BlockDate,
         wallet: &Wallet,
@@ -123,6 +183,14 @@ impl FragmentBuilder {
         Initial::Cert(signed_delegation_cert(wallet, valid_until, pool_id).into())
     }
 
+    pub fn split_delegation_cert_for_block0(
+        valid_until: BlockDate,
+        wallet: &Wallet,
+        ratio: DelegationRatio,
+    ) -> Initial {
+        Initial::Cert(signed_split_delegation_cert(wallet, valid_until, ratio).into())
+    }
+
     pub fn stake_pool_registration(&self, funder: &Wallet, stake_pool: &StakePool) -> Fragment {
         let inner_wallet = funder.clone().into();
         self.fragment_factory().stake_pool_registration(
@@ -319,4 +387,27 @@ impl FragmentBuilder {
         self.fragment_factory()
             .vote_tally(self.valid_until, &inner_wallet, vote_tally)
     }
+
+    /// Proposal of the BFT leader `proposer` to change the blockchain
+    /// settings. The changes apply from the epoch following the one in which
+    /// a majority of the leaders voted for the proposal.
+    pub fn update_proposal(&self, proposer: &ConsensusLeaderId, changes: ConfigParams) -> Fragment {
+        Fragment::UpdateProposal(SignedUpdateProposal {
+            proposal: UpdateProposalWithProposer {
+                proposal: UpdateProposal { changes },
+                proposer_id: proposer.0.clone(),
+            },
+        })
+    }
+
+    /// Vote of the BFT leader `voter` for the update proposal `proposal_id`,
+    /// the id of the fragment of the proposal.
+    pub fn update_vote(&self, proposal_id: FragmentId, voter: &ConsensusLeaderId) -> Fragment {
+        Fragment::UpdateVote(SignedUpdateVote {
+            vote: UpdateVote {
+                proposal_id,
+                voter_id: voter.0.clone(),
+            },
+        })
+    }
 }
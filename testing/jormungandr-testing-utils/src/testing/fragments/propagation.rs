@@ -0,0 +1,289 @@
+use crate::testing::fragments::node::{FragmentNode, MemPoolCheck};
+use jormungandr_lib::interfaces::FragmentStatus;
+use serde::Serialize;
+use std::fmt;
+use std::time::{Duration, Instant};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum PropagationError {
+    #[error("fragment {fragment_id} did not reach the logs of node {alias} within {max:?}")]
+    NotInLogs {
+        fragment_id: String,
+        alias: String,
+        max: Duration,
+    },
+    #[error("fragment {fragment_id} was not in a block of node {alias} within {max:?}")]
+    NotInBlock {
+        fragment_id: String,
+        alias: String,
+        max: Duration,
+    },
+    #[error("fragment {fragment_id} was rejected by node {alias}: {reason}")]
+    Rejected {
+        fragment_id: String,
+        alias: String,
+        reason: String,
+    },
+}
+
+/// Times elapsed, from the start of the measurement, before a fragment showed
+/// up in the logs of a node and before the node reported it in a block.
+#[derive(Debug, Clone, Serialize)]
+pub struct NodePropagation {
+    pub alias: String,
+    pub in_logs: Option<Duration>,
+    pub in_block: Option<Duration>,
+    pub rejected: Option<String>,
+}
+
+impl NodePropagation {
+    fn new(alias: String) -> Self {
+        Self {
+            alias,
+            in_logs: None,
+            in_block: None,
+            rejected: None,
+        }
+    }
+
+    fn is_done(&self) -> bool {
+        self.in_block.is_some() || self.rejected.is_some()
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PropagationReport {
+    pub fragment_id: String,
+    pub nodes: Vec<NodePropagation>,
+}
+
+impl PropagationReport {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+
+    pub fn print(&self) {
+        println!("{}", self);
+    }
+
+    /// Longest time for the fragment to reach the logs of a node, `None` if
+    /// it did not reach all of them.
+    pub fn max_in_logs(&self) -> Option<Duration> {
+        self.nodes
+            .iter()
+            .map(|node| node.in_logs)
+            .collect::<Option<Vec<_>>>()?
+            .into_iter()
+            .max()
+    }
+
+    /// Longest time for the fragment to be in a block according to a node,
+    /// `None` if some node did not report it in a block.
+    pub fn max_in_block(&self) -> Option<Duration> {
+        self.nodes
+            .iter()
+            .map(|node| node.in_block)
+            .collect::<Option<Vec<_>>>()?
+            .into_iter()
+            .max()
+    }
+
+    /// The fragment reached the logs of every node within `max`, and no node
+    /// rejected it.
+    pub fn verify_in_logs(&self, max: Duration) -> Result<(), PropagationError> {
+        for node in &self.nodes {
+            self.verify_not_rejected(node)?;
+            if !matches!(node.in_logs, Some(elapsed) if elapsed <= max) {
+                return Err(PropagationError::NotInLogs {
+                    fragment_id: self.fragment_id.clone(),
+                    alias: node.alias.clone(),
+                    max,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Every node reported the fragment in a block within `max`.
+    pub fn verify_in_block(&self, max: Duration) -> Result<(), PropagationError> {
+        for node in &self.nodes {
+            self.verify_not_rejected(node)?;
+            if !matches!(node.in_block, Some(elapsed) if elapsed <= max) {
+                return Err(PropagationError::NotInBlock {
+                    fragment_id: self.fragment_id.clone(),
+                    alias: node.alias.clone(),
+                    max,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn verify_not_rejected(&self, node: &NodePropagation) -> Result<(), PropagationError> {
+        match &node.rejected {
+            Some(reason) => Err(PropagationError::Rejected {
+                fragment_id: self.fragment_id.clone(),
+                alias: node.alias.clone(),
+                reason: reason.clone(),
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+impl fmt::Display for PropagationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "propagation of fragment {}:", self.fragment_id)?;
+        for node in &self.nodes {
+            write!(f, "  {}: ", node.alias)?;
+            match node.in_logs {
+                Some(elapsed) => write!(f, "in logs after {:?}", elapsed)?,
+                None => write!(f, "not in logs")?,
+            }
+            match (&node.rejected, node.in_block) {
+                (Some(reason), _) => writeln!(f, ", rejected: {}", reason)?,
+                (None, Some(elapsed)) => writeln!(f, ", in block after {:?}", elapsed)?,
+                (None, None) => writeln!(f, ", not in block")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Follows a fragment sent to one node of a network until every node has it in
+/// a block, as reported by the fragment logs of the nodes.
+pub struct PropagationVerifier {
+    timeout: Duration,
+    poll_interval: Duration,
+}
+
+impl PropagationVerifier {
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            timeout,
+            poll_interval: Duration::from_millis(100),
+        }
+    }
+
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Polls the fragment logs of the nodes until all of them report the
+    /// fragment in a block or rejected, or until the timeout. The times are
+    /// measured from the call, which should follow the sending of the
+    /// fragment. The report is returned in any case, the caller verifies it
+    /// against its own limits.
+    pub fn measure<A: FragmentNode + ?Sized>(
+        &self,
+        check: &MemPoolCheck,
+        nodes: &[&A],
+    ) -> PropagationReport {
+        let start = Instant::now();
+        let mut propagations: Vec<NodePropagation> = nodes
+            .iter()
+            .map(|node| NodePropagation::new(node.alias().to_string()))
+            .collect();
+
+        while start.elapsed() < self.timeout {
+            for (node, propagation) in nodes.iter().zip(propagations.iter_mut()) {
+                if propagation.is_done() {
+                    continue;
+                }
+                let logs = match node.fragment_logs() {
+                    Ok(logs) => logs,
+                    Err(_) => continue,
+                };
+                let log = match logs.get(check.fragment_id()) {
+                    Some(log) => log,
+                    None => continue,
+                };
+                let elapsed = start.elapsed();
+                propagation.in_logs.get_or_insert(elapsed);
+                match log.status() {
+                    FragmentStatus::Pending => {}
+                    FragmentStatus::InABlock { .. } => propagation.in_block = Some(elapsed),
+                    FragmentStatus::Rejected { reason } => {
+                        propagation.rejected = Some(reason.clone())
+                    }
+                }
+            }
+            if propagations.iter().all(NodePropagation::is_done) {
+                break;
+            }
+            std::thread::sleep(self.poll_interval);
+        }
+
+        PropagationReport {
+            fragment_id: check.fragment_id().to_string(),
+            nodes: propagations,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(alias: &str, in_logs: Option<u64>, in_block: Option<u64>) -> NodePropagation {
+        NodePropagation {
+            alias: alias.to_string(),
+            in_logs: in_logs.map(Duration::from_secs),
+            in_block: in_block.map(Duration::from_secs),
+            rejected: None,
+        }
+    }
+
+    fn report(nodes: Vec<NodePropagation>) -> PropagationReport {
+        PropagationReport {
+            fragment_id: "fragment".to_string(),
+            nodes,
+        }
+    }
+
+    #[test]
+    pub fn slowest_node_bounds_the_propagation() {
+        let report = report(vec![
+            node("leader", Some(1), Some(4)),
+            node("passive", Some(2), Some(3)),
+        ]);
+        assert_eq!(report.max_in_logs(), Some(Duration::from_secs(2)));
+        assert_eq!(report.max_in_block(), Some(Duration::from_secs(4)));
+        assert!(report.verify_in_logs(Duration::from_secs(2)).is_ok());
+        assert!(matches!(
+            report.verify_in_block(Duration::from_secs(3)),
+            Err(PropagationError::NotInBlock { alias, .. }) if alias == "leader"
+        ));
+    }
+
+    #[test]
+    pub fn nodes_missing_the_fragment_fail_the_verification() {
+        let report = report(vec![
+            node("leader", Some(1), Some(2)),
+            node("passive", None, None),
+        ]);
+        assert_eq!(report.max_in_logs(), None);
+        assert_eq!(report.max_in_block(), None);
+        assert!(matches!(
+            report.verify_in_logs(Duration::from_secs(10)),
+            Err(PropagationError::NotInLogs { alias, .. }) if alias == "passive"
+        ));
+        assert!(report
+            .to_string()
+            .contains("passive: not in logs, not in block"));
+    }
+
+    #[test]
+    pub fn rejections_fail_the_verification() {
+        let mut rejecting = node("passive", Some(1), None);
+        rejecting.rejected = Some("invalid".to_string());
+        let report = report(vec![node("leader", Some(1), Some(2)), rejecting]);
+        assert!(matches!(
+            report.verify_in_logs(Duration::from_secs(10)),
+            Err(PropagationError::Rejected { reason, .. }) if reason == "invalid"
+        ));
+        assert!(report.to_string().contains("rejected: invalid"));
+    }
+}
@@ -3,24 +3,26 @@ use crate::{
     stake_pool::StakePool,
     testing::{
         ensure_node_is_in_sync_with_others,
-        fragments::node::{FragmentNode, MemPoolCheck},
-        FragmentSenderSetup, FragmentVerifier, SyncNode, SyncNodeError, SyncWaitParams,
+        fragments::node::{FragmentNode, FragmentNodeError, MemPoolCheck},
+        BatchLimits, FragmentSenderSetup, FragmentVerifier, SyncNode, SyncNodeError,
+        SyncWaitParams,
     },
     wallet::Wallet,
 };
-use chain_core::property::Fragment as _;
+use chain_core::property::{Fragment as _, Serialize as _};
 use chain_impl_mockchain::{
     block::BlockDate,
     certificate::{DecryptedPrivateTally, VotePlan, VoteTallyPayload},
     fee::LinearFee,
-    fragment::Fragment,
+    fragment::{Fragment, FragmentId},
     vote::Choice,
 };
 use jormungandr_lib::interfaces::Address;
 use jormungandr_lib::{
     crypto::hash::Hash,
-    interfaces::{FragmentStatus, Value},
+    interfaces::{FragmentLog, FragmentStatus, Value},
 };
+use std::collections::HashMap;
 use std::time::Duration;
 
 #[derive(custom_debug::Debug, thiserror::Error)]
@@ -127,8 +129,24 @@ impl<'a, S: SyncNode + Send> FragmentSender<'a, S> {
     ) -> Result<Vec<MemPoolCheck>, FragmentSenderError> {
         self.wait_for_node_sync_if_enabled(node)
             .map_err(FragmentSenderError::SyncNodeError)?;
-        node.send_batch_fragments(fragments, fail_fast)
-            .map_err(|e| e.into())
+
+        // the requests are sent in order: with `fail_fast` the node drops the
+        // rest of the batch after an invalid fragment, so the remaining
+        // requests are not sent either. Otherwise all the fragments are sent
+        // and the errors of all the failed requests are reported together.
+        let mut checks = Vec::with_capacity(fragments.len());
+        let mut errors = Vec::new();
+        for batch in split_batch(fragments, self.setup.batch_limits()) {
+            match node.send_batch_fragments(batch, fail_fast) {
+                Ok(batch_checks) => checks.extend(batch_checks),
+                Err(err) if fail_fast => return Err(err.into()),
+                Err(err) => errors.push(err),
+            }
+        }
+        match merge_batch_errors(errors) {
+            Some(err) => Err(err.into()),
+            None => Ok(checks),
+        }
     }
 
     pub fn send_transaction<A: FragmentNode + SyncNode + Sized + Send>(
@@ -197,6 +215,29 @@ impl<'a, S: SyncNode + Send> FragmentSender<'a, S> {
         self.send_fragment(from, fragment, via)
     }
 
+    /// Sends the certificate moving the delegation of `from` from `current`
+    /// to `target_distribution`, nothing if both have the same proportions
+    pub fn send_rebalance_delegation<A: FragmentNode + SyncNode + Sized + Send>(
+        &self,
+        from: &mut Wallet,
+        current: &[(&StakePool, u8)],
+        target_distribution: &[(&StakePool, u8)],
+        via: &A,
+    ) -> Result<Option<MemPoolCheck>, FragmentSenderError> {
+        let fragment = match from.issue_rebalance_delegation_cert(
+            &self.block0_hash,
+            &self.fees,
+            self.valid_until,
+            current,
+            target_distribution,
+        )? {
+            Some(fragment) => fragment,
+            None => return Ok(None),
+        };
+        self.dump_fragment_if_enabled(from, &fragment, via)?;
+        self.send_fragment(from, fragment, via).map(Some)
+    }
+
     pub fn send_owner_delegation<A: FragmentNode + SyncNode + Sized + Send>(
         &self,
         from: &mut Wallet,
@@ -283,6 +324,55 @@ impl<'a, S: SyncNode + Send> FragmentSender<'a, S> {
         self.send_fragment(from, fragment, via)
     }
 
+    /// Casts one vote per wallet, `choices` holding the proposal index and the choice
+    /// of the wallet at the same position, and submits all of them in a single batch.
+    /// The returned checks follow the order of `wallets`.
+    pub fn send_vote_casts_batch<A: FragmentNode + SyncNode + Sized + Send>(
+        &self,
+        wallets: &mut [Wallet],
+        vote_plan: &VotePlan,
+        choices: &[(u8, Choice)],
+        via: &A,
+    ) -> Result<Vec<MemPoolCheck>, FragmentSenderError> {
+        assert_eq!(
+            wallets.len(),
+            choices.len(),
+            "exactly one choice per wallet is expected"
+        );
+
+        let mut fragments = Vec::with_capacity(wallets.len());
+        for (wallet, (proposal_index, choice)) in wallets.iter_mut().zip(choices) {
+            let fragment = wallet.issue_vote_cast_cert(
+                &self.block0_hash,
+                &self.fees,
+                self.valid_until,
+                vote_plan,
+                *proposal_index,
+                choice,
+            )?;
+            self.dump_fragment_if_enabled(wallet, &fragment, via)?;
+            fragments.push(fragment);
+        }
+
+        let ids: Vec<FragmentId> = fragments.iter().map(|fragment| fragment.id()).collect();
+        let checks = ids.iter().map(|id| MemPoolCheck::new(*id)).collect();
+        if let Err(err) = self.send_batch_fragments(fragments, self.setup.fail_fast(), via) {
+            // part of the batch may still have been accepted: only the
+            // counters of the wallets whose vote reached the mempool move on
+            let logs = via.fragment_logs().unwrap_or_default();
+            for (wallet, accepted) in wallets.iter_mut().zip(accepted_fragments(&ids, &logs)) {
+                if accepted {
+                    self.confirm_transaction_if_enabled(wallet);
+                }
+            }
+            return Err(err);
+        }
+        for wallet in wallets.iter_mut() {
+            self.confirm_transaction_if_enabled(wallet);
+        }
+        Ok(checks)
+    }
+
     pub fn send_public_vote_tally<A: FragmentNode + SyncNode + Sized + Send>(
         &self,
         from: &mut Wallet,
@@ -499,3 +589,175 @@ impl<'a, S: SyncNode + Send> FragmentSender<'a, S> {
         )
     }
 }
+
+// fixed part of the JSON body of a batch: `{"fail_fast":false,"fragments":[]}`
+const BATCH_BODY_OVERHEAD: u64 = 64;
+
+/// Tells, for each fragment, whether the node accepted it according to its
+/// fragment logs: fragments the node does not know or rejected were not.
+fn accepted_fragments(ids: &[FragmentId], logs: &HashMap<FragmentId, FragmentLog>) -> Vec<bool> {
+    ids.iter()
+        .map(|id| logs.get(id).map_or(false, |log| !log.is_rejected()))
+        .collect()
+}
+
+/// Reports the failures of several requests of the same batch as one error,
+/// with the reasons and the fragments of all of them.
+fn merge_batch_errors(errors: Vec<FragmentNodeError>) -> Option<FragmentNodeError> {
+    if errors.len() <= 1 {
+        return errors.into_iter().next();
+    }
+    let mut reasons = Vec::with_capacity(errors.len());
+    let mut alias = String::new();
+    let mut fragment_ids = Vec::new();
+    let mut logs = Vec::new();
+    for error in errors {
+        match error {
+            FragmentNodeError::CannotSendFragmentBatch {
+                reason,
+                alias: node,
+                fragment_ids: ids,
+                logs: node_logs,
+            } => {
+                reasons.push(reason);
+                alias = node;
+                fragment_ids.extend(ids);
+                logs = node_logs;
+            }
+            other => reasons.push(other.to_string()),
+        }
+    }
+    Some(FragmentNodeError::CannotSendFragmentBatch {
+        reason: reasons.join("; "),
+        alias,
+        fragment_ids,
+        logs,
+    })
+}
+
+/// Splits the fragments in consecutive batches within the limits of the node.
+/// A fragment exceeding the limits on its own is sent alone, the node decides
+/// what to do with it.
+fn split_batch(fragments: Vec<Fragment>, limits: BatchLimits) -> Vec<Vec<Fragment>> {
+    let mut batches = Vec::new();
+    let mut batch = Vec::new();
+    let mut body_size = BATCH_BODY_OVERHEAD;
+    let mut content_size = 0u64;
+    for fragment in fragments {
+        let size = fragment.serialize_as_vec().unwrap().len() as u64;
+        // hex encoded, quoted and separated by a comma
+        let fragment_body_size = 2 * size + 3;
+        let too_large = body_size + fragment_body_size > limits.max_body_size
+            || limits
+                .block_content_max_size
+                .map_or(false, |max| content_size + size > u64::from(max));
+        if too_large && !batch.is_empty() {
+            batches.push(std::mem::take(&mut batch));
+            body_size = BATCH_BODY_OVERHEAD;
+            content_size = 0;
+        }
+        body_size += fragment_body_size;
+        content_size += size;
+        batch.push(fragment);
+    }
+    if !batch.is_empty() {
+        batches.push(batch);
+    }
+    batches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chain_impl_mockchain::config::ConfigParams;
+    use jormungandr_lib::interfaces::FragmentOrigin;
+
+    fn fragments(count: usize) -> (Vec<Fragment>, u64) {
+        let fragment = Fragment::Initial(ConfigParams::new());
+        let size = fragment.serialize_as_vec().unwrap().len() as u64;
+        (vec![fragment; count], size)
+    }
+
+    fn batch_error(reason: &str, fragments: &[Fragment]) -> FragmentNodeError {
+        FragmentNodeError::CannotSendFragmentBatch {
+            reason: reason.to_string(),
+            alias: "node".to_string(),
+            fragment_ids: fragments.iter().map(|x| x.id()).collect(),
+            logs: Vec::new(),
+        }
+    }
+
+    fn batch_sizes(batches: &[Vec<Fragment>]) -> Vec<usize> {
+        batches.iter().map(Vec::len).collect()
+    }
+
+    #[test]
+    fn batches_fit_in_the_request_body() {
+        let (fragments, size) = fragments(7);
+        let limits =
+            BatchLimits::default().with_max_body_size(BATCH_BODY_OVERHEAD + 3 * (2 * size + 3));
+        assert_eq!(batch_sizes(&split_batch(fragments, limits)), vec![3, 3, 1]);
+    }
+
+    #[test]
+    fn batches_fit_in_a_block() {
+        let (fragments, size) = fragments(5);
+        let limits = BatchLimits::default().with_block_content_max_size(2 * size as u32);
+        assert_eq!(batch_sizes(&split_batch(fragments, limits)), vec![2, 2, 1]);
+    }
+
+    #[test]
+    fn oversized_fragments_are_sent_alone() {
+        let (fragments, _) = fragments(3);
+        let limits = BatchLimits::default().with_max_body_size(1);
+        assert_eq!(batch_sizes(&split_batch(fragments, limits)), vec![1, 1, 1]);
+        assert!(split_batch(Vec::new(), limits).is_empty());
+    }
+
+    #[test]
+    fn only_the_fragments_logged_and_not_rejected_are_accepted() {
+        let ids: Vec<FragmentId> = (0u8..4).map(|i| FragmentId::hash_bytes(&[i])).collect();
+        let mut logs = HashMap::new();
+        logs.insert(ids[0], FragmentLog::new(ids[0], FragmentOrigin::Rest));
+        let mut rejected = FragmentLog::new(ids[1], FragmentOrigin::Rest);
+        rejected.modify(FragmentStatus::Rejected {
+            reason: "invalid".to_string(),
+        });
+        logs.insert(ids[1], rejected);
+        let mut in_block = FragmentLog::new(ids[2], FragmentOrigin::Rest);
+        in_block.modify(FragmentStatus::InABlock {
+            date: BlockDate::first().into(),
+            block: FragmentId::hash_bytes(&[42]).into(),
+        });
+        logs.insert(ids[2], in_block);
+
+        assert_eq!(
+            accepted_fragments(&ids, &logs),
+            vec![true, false, true, false]
+        );
+    }
+
+    #[test]
+    fn errors_of_all_the_batches_are_reported() {
+        let (fragments, _) = fragments(3);
+        assert!(merge_batch_errors(Vec::new()).is_none());
+
+        let merged = merge_batch_errors(vec![
+            batch_error("first", &fragments[..1]),
+            FragmentNodeError::UnknownError,
+            batch_error("last", &fragments[1..]),
+        ])
+        .unwrap();
+        match merged {
+            FragmentNodeError::CannotSendFragmentBatch {
+                reason,
+                fragment_ids,
+                ..
+            } => {
+                assert_eq!(reason, "first; unknown error; last");
+                assert_eq!(fragment_ids.len(), 3);
+            }
+            other => panic!("unexpected error {:?}", other),
+        }
+    }
+}
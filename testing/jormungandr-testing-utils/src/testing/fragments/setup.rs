@@ -41,6 +41,49 @@ pub struct FragmentSenderSetup<'a, S: SyncNode + Send> {
 
     /// Just send fragment without any verifications
     pub fire_and_forget: bool,
+
+    /// Node stops processing a batch of fragments on the first invalid one
+    pub fail_fast: bool,
+
+    /// Batches of fragments larger than the limits of the node are sent in
+    /// several requests
+    pub batch_limits: BatchLimits,
+}
+
+// same as the default of the node
+const DEFAULT_MAX_BODY_SIZE: u64 = 16 * 1024 * 1024;
+
+/// Limits of the node on the size of a batch of fragments.
+#[derive(Clone, Copy, Debug)]
+pub struct BatchLimits {
+    /// Maximum size of a REST request body, `rest.max_body_size` in the node
+    /// configuration
+    pub max_body_size: u64,
+    /// Maximum size of the content of a block, `block_content_max_size` in
+    /// the settings of the blockchain. When set, a batch is split so that each
+    /// request can fit in a single block.
+    pub block_content_max_size: Option<u32>,
+}
+
+impl Default for BatchLimits {
+    fn default() -> Self {
+        Self {
+            max_body_size: DEFAULT_MAX_BODY_SIZE,
+            block_content_max_size: None,
+        }
+    }
+}
+
+impl BatchLimits {
+    pub fn with_max_body_size(mut self, max_body_size: u64) -> Self {
+        self.max_body_size = max_body_size;
+        self
+    }
+
+    pub fn with_block_content_max_size(mut self, block_content_max_size: u32) -> Self {
+        self.block_content_max_size = Some(block_content_max_size);
+        self
+    }
 }
 
 impl<'a, S: SyncNode + Send> FragmentSenderSetup<'a, S> {
@@ -81,6 +124,14 @@ impl<'a, S: SyncNode + Send> FragmentSenderSetup<'a, S> {
         self.fire_and_forget
     }
 
+    pub fn fail_fast(&self) -> bool {
+        self.fail_fast
+    }
+
+    pub fn batch_limits(&self) -> BatchLimits {
+        self.batch_limits
+    }
+
     pub fn new() -> Self {
         Self {
             resend_on_error: None,
@@ -90,6 +141,8 @@ impl<'a, S: SyncNode + Send> FragmentSenderSetup<'a, S> {
             auto_confirm: true,
             verify_strategy: None,
             fire_and_forget: false,
+            fail_fast: false,
+            batch_limits: BatchLimits::default(),
         }
     }
 }
@@ -206,6 +259,16 @@ impl<'a, S: SyncNode + Send> FragmentSenderSetupBuilder<'a, S> {
         self
     }
 
+    pub fn fail_fast(&mut self) -> &mut Self {
+        self.setup.fail_fast = true;
+        self
+    }
+
+    pub fn batch_limits(&mut self, batch_limits: BatchLimits) -> &mut Self {
+        self.setup.batch_limits = batch_limits;
+        self
+    }
+
     pub fn build(self) -> FragmentSenderSetup<'a, S> {
         self.setup
     }
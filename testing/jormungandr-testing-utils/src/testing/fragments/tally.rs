@@ -0,0 +1,306 @@
+use crate::testing::node::{JormungandrRest, RestError};
+use crate::wallet::Wallet;
+use chain_impl_mockchain::{
+    fragment::Fragment,
+    transaction::{InputEnum, UnspecifiedAccountIdentifier},
+    vote::{Choice, Payload},
+};
+use jormungandr_lib::interfaces::{Tally, VotePlanId, VotePlanStatus};
+use std::collections::HashMap;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum TallyVerifierError {
+    #[error("proposal {index} of vote plan {vote_plan} is not tallied")]
+    NotTallied { vote_plan: VotePlanId, index: u8 },
+    #[error("proposal {index} of vote plan {vote_plan} has a private tally")]
+    PrivateTally { vote_plan: VotePlanId, index: u8 },
+    #[error("no voting power given for a voter of proposal {index} of vote plan {vote_plan}")]
+    UnknownVoter { vote_plan: VotePlanId, index: u8 },
+    #[error(
+        "proposal {index} of vote plan {vote_plan}: expected {expected:?}, tallied {actual:?}"
+    )]
+    ResultsMismatch {
+        vote_plan: VotePlanId,
+        index: u8,
+        expected: Vec<u64>,
+        actual: Vec<u64>,
+    },
+    #[error("cannot get the voting power from the node")]
+    Rest(#[from] RestError),
+}
+
+/// Recomputes the public tally of vote plans from the vote casts sent to a
+/// node, as dumped by a `FragmentSender` or found in persistent fragment
+/// logs, and compares it with the tally of the node.
+///
+/// The vote casts are recorded in the order they were applied, a later vote of
+/// an account on a proposal replacing its earlier one as in the ledger. Vote
+/// casts the node rejected, or that did not reach a block, have to be left out
+/// by the caller.
+#[derive(Debug, Default)]
+pub struct TallyVerifier {
+    votes: HashMap<(VotePlanId, u8), HashMap<UnspecifiedAccountIdentifier, Choice>>,
+    voting_power: HashMap<UnspecifiedAccountIdentifier, u64>,
+}
+
+impl TallyVerifier {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_fragments<'a>(fragments: impl IntoIterator<Item = &'a Fragment>) -> Self {
+        let mut verifier = Self::new();
+        for fragment in fragments {
+            verifier.record(fragment);
+        }
+        verifier
+    }
+
+    /// Records the fragment if it is a public vote cast, ignores it otherwise
+    pub fn record(&mut self, fragment: &Fragment) -> &mut Self {
+        let tx = match fragment {
+            Fragment::VoteCast(tx) => tx.as_slice(),
+            _ => return self,
+        };
+        let vote_cast = tx.payload().into_payload();
+        let choice = match vote_cast.payload() {
+            Payload::Public { choice } => *choice,
+            Payload::Private { .. } => return self,
+        };
+        let voter = match tx.inputs().iter().next().map(|input| input.to_enum()) {
+            Some(InputEnum::AccountInput(voter, _)) => voter,
+            _ => return self,
+        };
+        self.votes
+            .entry((
+                vote_cast.vote_plan().clone().into(),
+                vote_cast.proposal_index(),
+            ))
+            .or_default()
+            .insert(voter, choice);
+        self
+    }
+
+    /// Voting power of the wallet at the time of the tally
+    pub fn voting_power(&mut self, wallet: &Wallet, value: u64) -> &mut Self {
+        if let Some(stake_key) = wallet.stake_key() {
+            self.voting_power.insert(stake_key, value);
+        }
+        self
+    }
+
+    /// Takes the voting power of the wallets from the current value of their
+    /// accounts, to be called once the tally is done
+    pub fn voting_power_from_node(
+        &mut self,
+        wallets: &[&Wallet],
+        rest: &JormungandrRest,
+    ) -> Result<&mut Self, TallyVerifierError> {
+        for wallet in wallets {
+            let value: u64 = (*rest.account_state(wallet)?.value()).into();
+            self.voting_power(wallet, value);
+        }
+        Ok(self)
+    }
+
+    /// The results expected for the proposal, one entry per option
+    pub fn expected_results(
+        &self,
+        vote_plan: &VotePlanId,
+        index: u8,
+        options: u8,
+    ) -> Result<Vec<u64>, TallyVerifierError> {
+        let mut results = vec![0; options as usize];
+        let votes = match self.votes.get(&(*vote_plan, index)) {
+            Some(votes) => votes,
+            None => return Ok(results),
+        };
+        for (voter, choice) in votes {
+            let power = match self.voting_power.get(voter) {
+                Some(power) => power,
+                None => {
+                    return Err(TallyVerifierError::UnknownVoter {
+                        vote_plan: *vote_plan,
+                        index,
+                    })
+                }
+            };
+            // the ledger rejects the choices out of the options
+            if let Some(result) = results.get_mut(choice.as_byte() as usize) {
+                *result += power;
+            }
+        }
+        Ok(results)
+    }
+
+    /// Every proposal of the vote plan is tallied with the results recomputed
+    /// from the recorded vote casts
+    pub fn verify(&self, vote_plan: &VotePlanStatus) -> Result<(), TallyVerifierError> {
+        for proposal in &vote_plan.proposals {
+            let actual = match &proposal.tally {
+                Some(Tally::Public { result }) => result.results(),
+                Some(Tally::Private { .. }) => {
+                    return Err(TallyVerifierError::PrivateTally {
+                        vote_plan: vote_plan.id,
+                        index: proposal.index,
+                    })
+                }
+                None => {
+                    return Err(TallyVerifierError::NotTallied {
+                        vote_plan: vote_plan.id,
+                        index: proposal.index,
+                    })
+                }
+            };
+            let expected =
+                self.expected_results(&vote_plan.id, proposal.index, proposal.options.end)?;
+            if expected != actual {
+                return Err(TallyVerifierError::ResultsMismatch {
+                    vote_plan: vote_plan.id,
+                    index: proposal.index,
+                    expected,
+                    actual,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{fragments::FragmentBuilder, VotePlanBuilder};
+    use chain_impl_mockchain::{
+        block::BlockDate,
+        certificate::VotePlan,
+        fee::LinearFee,
+        vote::{Options, PayloadType, TallyResult},
+    };
+    use jormungandr_lib::{crypto::hash::Hash, interfaces::VoteProposalStatus};
+
+    fn vote(wallet: &Wallet, vote_plan: &VotePlan, index: u8, choice: u8) -> Fragment {
+        FragmentBuilder::new(
+            &Hash::from([0; 32]),
+            &LinearFee::new(0, 0, 0),
+            BlockDate {
+                epoch: 1,
+                slot_id: 0,
+            },
+        )
+        .public_vote_cast(wallet, vote_plan, index, &Choice::new(choice))
+    }
+
+    fn status(vote_plan: &VotePlan, tallies: Vec<Option<Vec<u64>>>) -> VotePlanStatus {
+        let proposals = tallies
+            .into_iter()
+            .enumerate()
+            .map(|(index, results)| VoteProposalStatus {
+                index: index as u8,
+                proposal_id: Hash::from([index as u8; 32]),
+                options: 0..3,
+                tally: results.map(|results| {
+                    let mut result = TallyResult::new(Options::new_length(3).unwrap());
+                    for (choice, weight) in results.into_iter().enumerate() {
+                        result
+                            .add_vote(Choice::new(choice as u8), weight.into())
+                            .unwrap();
+                    }
+                    Tally::Public {
+                        result: result.into(),
+                    }
+                }),
+                votes_cast: 0,
+            })
+            .collect();
+        VotePlanStatus {
+            id: vote_plan.to_id().into(),
+            payload: PayloadType::Public,
+            vote_start: vote_plan.vote_start().into(),
+            vote_end: vote_plan.vote_end().into(),
+            committee_end: vote_plan.committee_end().into(),
+            committee_member_keys: Vec::new(),
+            proposals,
+        }
+    }
+
+    #[test]
+    pub fn later_votes_replace_earlier_ones_and_are_weighted() {
+        let mut rng = rand::thread_rng();
+        let alice = Wallet::new_account(&mut rng);
+        let bob = Wallet::new_account(&mut rng);
+        let vote_plan = VotePlanBuilder::new().proposals_count(2).build();
+        let vote_plan_id = vote_plan.to_id().into();
+
+        let fragments = vec![
+            vote(&alice, &vote_plan, 0, 0),
+            vote(&bob, &vote_plan, 0, 1),
+            vote(&alice, &vote_plan, 0, 1),
+        ];
+        let mut verifier = TallyVerifier::from_fragments(&fragments);
+        verifier.voting_power(&alice, 10).voting_power(&bob, 5);
+
+        assert_eq!(
+            verifier.expected_results(&vote_plan_id, 0, 3).unwrap(),
+            vec![0, 15, 0]
+        );
+        assert_eq!(
+            verifier.expected_results(&vote_plan_id, 1, 3).unwrap(),
+            vec![0, 0, 0]
+        );
+        verifier
+            .verify(&status(
+                &vote_plan,
+                vec![Some(vec![0, 15, 0]), Some(vec![0; 3])],
+            ))
+            .unwrap();
+    }
+
+    #[test]
+    pub fn votes_of_voters_without_voting_power_are_refused() {
+        let mut rng = rand::thread_rng();
+        let alice = Wallet::new_account(&mut rng);
+        let vote_plan = VotePlanBuilder::new().build();
+
+        let fragments = vec![vote(&alice, &vote_plan, 0, 1)];
+        let verifier = TallyVerifier::from_fragments(&fragments);
+        assert!(matches!(
+            verifier.expected_results(&vote_plan.to_id().into(), 0, 3),
+            Err(TallyVerifierError::UnknownVoter { index: 0, .. })
+        ));
+    }
+
+    #[test]
+    pub fn tallies_differing_from_the_votes_are_reported() {
+        let mut rng = rand::thread_rng();
+        let alice = Wallet::new_account(&mut rng);
+        let vote_plan = VotePlanBuilder::new().proposals_count(2).build();
+
+        let fragments = vec![vote(&alice, &vote_plan, 1, 2)];
+        let mut verifier = TallyVerifier::from_fragments(&fragments);
+        verifier.voting_power(&alice, 7);
+
+        match verifier.verify(&status(
+            &vote_plan,
+            vec![Some(vec![0; 3]), Some(vec![7, 0, 0])],
+        )) {
+            Err(TallyVerifierError::ResultsMismatch {
+                index,
+                expected,
+                actual,
+                ..
+            }) => {
+                assert_eq!(index, 1);
+                assert_eq!(expected, vec![0, 0, 7]);
+                assert_eq!(actual, vec![7, 0, 0]);
+            }
+            other => panic!("unexpected verification result {:?}", other),
+        }
+
+        assert!(matches!(
+            verifier.verify(&status(&vote_plan, vec![Some(vec![0; 3]), None])),
+            Err(TallyVerifierError::NotTallied { index: 1, .. })
+        ));
+    }
+}
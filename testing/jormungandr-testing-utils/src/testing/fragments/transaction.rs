@@ -2,13 +2,16 @@ use super::FragmentBuilderError;
 use crate::wallet::Wallet;
 use chain_impl_mockchain::{
     block::BlockDate,
+    certificate::{Certificate, CertificatePayload, SignedCertificate},
     fee::{FeeAlgorithm, LinearFee},
     fragment::Fragment,
-    transaction::{InputOutputBuilder, TxBuilder},
+    transaction::{
+        InputOutputBuilder, Payload, SetAuthData, Transaction, TxBuilder, TxBuilderState,
+    },
 };
 use jormungandr_lib::{
     crypto::hash::Hash,
-    interfaces::{Address, Value},
+    interfaces::{self, Address, Value},
 };
 
 pub fn transaction_to(
@@ -29,18 +32,37 @@ pub fn transaction_to_many(
     from: &Wallet,
     addresses: &[Address],
     value: Value,
+) -> Result<Fragment, FragmentBuilderError> {
+    let outputs: Vec<(Address, Value)> = addresses
+        .iter()
+        .cloned()
+        .map(|address| (address, value))
+        .collect();
+    transaction_to_many_with_values(block0_hash, fees, valid_until, from, &outputs)
+}
+
+/// Single transaction paying a (possibly) different value to each of the addresses
+pub fn transaction_to_many_with_values(
+    block0_hash: &Hash,
+    fees: &LinearFee,
+    valid_until: BlockDate,
+    from: &Wallet,
+    outputs: &[(Address, Value)],
 ) -> Result<Fragment, FragmentBuilderError> {
     let mut iobuilder = InputOutputBuilder::empty();
 
-    for address in addresses {
+    for (address, value) in outputs {
         iobuilder
-            .add_output(address.clone().into(), value.into())
+            .add_output(address.clone().into(), (*value).into())
             .unwrap();
     }
 
-    let value_u64: u64 = value.into();
-    let input_without_fees: Value = (value_u64 * addresses.len() as u64).into();
-    let input_value = fees.calculate(None, 1, addresses.len() as u8) + input_without_fees.into();
+    let input_without_fees: Value = outputs
+        .iter()
+        .map(|(_, value)| -> u64 { (*value).into() })
+        .sum::<u64>()
+        .into();
+    let input_value = fees.calculate(None, 1, outputs.len() as u8) + input_without_fees.into();
     let input = from.add_input_with_value(input_value.unwrap().into());
     iobuilder.add_input(&input).unwrap();
 
@@ -56,3 +78,178 @@ pub fn transaction_to_many(
     let tx = txbuilder.set_witnesses(&witnesses).set_payload_auth(&());
     Ok(Fragment::Transaction(tx))
 }
+
+/// Data which has to be signed by the payload authentication of `certificate` when it
+/// is later submitted with [`signed_certificate`] using the same parameters (and the
+/// same state of the `from` wallet).
+pub fn certificate_auth_data(
+    block0_hash: &Hash,
+    fees: &LinearFee,
+    valid_until: BlockDate,
+    from: &Wallet,
+    certificate: &Certificate,
+) -> Vec<u8> {
+    let fee = certificate_fee(fees, certificate);
+    let tx = CertificateTransaction {
+        block0_hash,
+        valid_until,
+        from,
+        fee,
+    };
+    match certificate {
+        Certificate::PoolRegistration(c) => tx.auth_data(c),
+        Certificate::PoolUpdate(c) => tx.auth_data(c),
+        Certificate::PoolRetirement(c) => tx.auth_data(c),
+        Certificate::StakeDelegation(c) => tx.auth_data(c),
+        Certificate::OwnerStakeDelegation(c) => tx.auth_data(c),
+        Certificate::VotePlan(c) => tx.auth_data(c),
+        Certificate::VoteCast(c) => tx.auth_data(c),
+        Certificate::VoteTally(c) => tx.auth_data(c),
+        Certificate::EncryptedVoteTally(c) => tx.auth_data(c),
+    }
+}
+
+/// Wraps any signed certificate into a fragment whose fees are paid (and witnessed)
+/// by `from`. The payload authentication is used as is, see [`certificate_auth_data`].
+pub fn signed_certificate(
+    block0_hash: &Hash,
+    fees: &LinearFee,
+    valid_until: BlockDate,
+    from: &Wallet,
+    certificate: SignedCertificate,
+) -> Fragment {
+    let unsigned = interfaces::SignedCertificate::from(certificate.clone()).strip_auth();
+    let fee = certificate_fee(fees, &unsigned.0);
+    let tx = CertificateTransaction {
+        block0_hash,
+        valid_until,
+        from,
+        fee,
+    };
+    match certificate {
+        SignedCertificate::PoolRegistration(c, a) => {
+            tx.fragment(&c, &a, Fragment::PoolRegistration)
+        }
+        SignedCertificate::PoolUpdate(c, a) => tx.fragment(&c, &a, Fragment::PoolUpdate),
+        SignedCertificate::PoolRetirement(c, a) => tx.fragment(&c, &a, Fragment::PoolRetirement),
+        SignedCertificate::StakeDelegation(c, a) => tx.fragment(&c, &a, Fragment::StakeDelegation),
+        SignedCertificate::OwnerStakeDelegation(c, a) => {
+            tx.fragment(&c, &a, Fragment::OwnerStakeDelegation)
+        }
+        SignedCertificate::VotePlan(c, a) => tx.fragment(&c, &a, Fragment::VotePlan),
+        SignedCertificate::VoteTally(c, a) => tx.fragment(&c, &a, Fragment::VoteTally),
+        SignedCertificate::EncryptedVoteTally(c, a) => {
+            tx.fragment(&c, &a, Fragment::EncryptedVoteTally)
+        }
+    }
+}
+
+fn certificate_fee(fees: &LinearFee, certificate: &Certificate) -> Value {
+    let payload = CertificatePayload::from(certificate);
+    fees.calculate(Some(payload.as_slice()), 1, 0).into()
+}
+
+/// Transaction with a single input paying the fees of a certificate
+struct CertificateTransaction<'a> {
+    block0_hash: &'a Hash,
+    valid_until: BlockDate,
+    from: &'a Wallet,
+    fee: Value,
+}
+
+impl<'a> CertificateTransaction<'a> {
+    fn witnessed<P: Payload>(&self, payload: &P) -> TxBuilderState<SetAuthData<P>> {
+        let input = self.from.add_input_with_value(self.fee);
+        let txbuilder = TxBuilder::new()
+            .set_payload(payload)
+            .set_expiry_date(self.valid_until)
+            .set_ios(&[input], &[]);
+
+        let sign_data = txbuilder.get_auth_data_for_witness().hash();
+        let witness = self.from.mk_witness(self.block0_hash, &sign_data);
+        txbuilder.set_witnesses(&[witness])
+    }
+
+    fn auth_data<P: Payload>(&self, payload: &P) -> Vec<u8> {
+        self.witnessed(payload).get_auth_data().0.to_vec()
+    }
+
+    fn fragment<P: Payload, F>(&self, payload: &P, auth: &P::Auth, to_fragment: F) -> Fragment
+    where
+        F: FnOnce(Transaction<P>) -> Fragment,
+    {
+        to_fragment(self.witnessed(payload).set_payload_auth(auth))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chain_impl_mockchain::{
+        account::DelegationType,
+        certificate::{OwnerStakeDelegation, PoolId},
+    };
+    use std::str::FromStr;
+
+    #[test]
+    fn signed_certificates_are_balanced_by_the_funder() {
+        let funder = Wallet::new_account(&mut rand::thread_rng());
+        let fees = LinearFee::new(2, 1, 0);
+        let valid_until = BlockDate::first().next_epoch();
+        let pool_id = PoolId::from_str(&hex::encode([1; 32])).unwrap();
+        let certificate = OwnerStakeDelegation {
+            delegation: DelegationType::Full(pool_id),
+        };
+        let fee = certificate_fee(&fees, &Certificate::from(certificate.clone()));
+        assert_eq!(fee, Value::from(3));
+
+        let fragment = signed_certificate(
+            &Hash::from([0; 32]),
+            &fees,
+            valid_until,
+            &funder,
+            SignedCertificate::OwnerStakeDelegation(certificate, ()),
+        );
+        let tx = match fragment {
+            Fragment::OwnerStakeDelegation(tx) => tx,
+            other => panic!("unexpected fragment {:?}", other),
+        };
+        let tx = tx.as_slice();
+        assert_eq!(tx.nb_inputs(), 1);
+        assert_eq!(tx.nb_outputs(), 0);
+        assert_eq!(tx.valid_until(), valid_until);
+        assert_eq!(Value::from(tx.total_input().unwrap()), fee);
+    }
+
+    #[test]
+    fn each_output_is_paid_its_own_value() {
+        let mut rng = rand::thread_rng();
+        let sender = Wallet::new_account(&mut rng);
+        let outputs: Vec<_> = [100, 250, 1]
+            .iter()
+            .map(|value| (Wallet::new_account(&mut rng).address(), Value::from(*value)))
+            .collect();
+
+        let fragment = transaction_to_many_with_values(
+            &Hash::from([0; 32]),
+            &LinearFee::new(2, 1, 0),
+            BlockDate::first().next_epoch(),
+            &sender,
+            &outputs,
+        )
+        .unwrap();
+        let tx = match fragment {
+            Fragment::Transaction(tx) => tx,
+            other => panic!("unexpected fragment {:?}", other),
+        };
+        let tx = tx.as_slice();
+        let paid: Vec<(Address, Value)> = tx
+            .outputs()
+            .iter()
+            .map(|output| (output.address.clone().into(), output.value.into()))
+            .collect();
+        assert_eq!(paid, outputs);
+        // one input and three outputs: 2 + 1 * 4 of fees
+        assert_eq!(Value::from(tx.total_input().unwrap()), Value::from(357));
+    }
+}
@@ -1,5 +1,6 @@
 pub mod asserts;
 pub mod block0;
+mod faucet;
 pub mod fragments;
 pub mod network_builder;
 pub mod node;
@@ -10,14 +11,18 @@ pub mod sync;
 mod verify;
 mod vit;
 
+pub use faucet::{Faucet, FaucetError};
 pub use fragments::{
-    signed_delegation_cert, signed_stake_pool_cert, vote_plan_cert, AdversaryFragmentGenerator,
-    AdversaryFragmentSender, AdversaryFragmentSenderError, AdversaryFragmentSenderSetup,
-    AdversaryVoteCastsGenerator, BatchFragmentGenerator, DummySyncNode, FragmentBuilder,
-    FragmentBuilderError, FragmentChainSender, FragmentGenerator, FragmentNode, FragmentNodeError,
-    FragmentSender, FragmentSenderError, FragmentSenderSetup, FragmentSenderSetupBuilder,
-    FragmentStatusProvider, FragmentVerifier, FragmentVerifierError, MemPoolCheck, VerifyStrategy,
-    VoteCastsGenerator,
+    signed_delegation_cert, signed_split_delegation_cert, signed_stake_pool_cert, vote_plan_cert,
+    AdversaryFragmentGenerator, AdversaryFragmentSender, AdversaryFragmentSenderError,
+    AdversaryFragmentSenderSetup, AdversaryVoteCastsGenerator, BatchFragmentGenerator, BatchLimits,
+    DummySyncNode, FragmentBuilder, FragmentBuilderError, FragmentChainSender, FragmentGenerator,
+    FragmentNode, FragmentNodeError, FragmentRemapper, FragmentSender, FragmentSenderError,
+    FragmentSenderSetup, FragmentSenderSetupBuilder, FragmentStatusProvider, FragmentVerifier,
+    FragmentVerifierError, LatencySummary, MemPoolCheck, NodePropagation, PersistentLogReplay,
+    PropagationError, PropagationReport, PropagationVerifier, ReplayConfig, ReplayEntry,
+    ReplayError, ReplayReport, TallyVerifier, TallyVerifierError, TpsLoadConfig, TpsLoadDriver,
+    TpsLoadReport, ValidityRemap, VerifyStrategy, VoteCastsGenerator,
 };
 pub use jortestkit::archive::decompress;
 pub use jortestkit::github::{CachedReleases, GitHubApiBuilder, GitHubApiError, Release};
@@ -32,8 +37,10 @@ pub use jortestkit::web::download_file;
 pub use remote::{RemoteJormungandr, RemoteJormungandrBuilder};
 pub use storage::{BranchCount, StopCriteria, StorageBuilder};
 pub use sync::{
-    ensure_node_is_in_sync_with_others, ensure_nodes_are_in_sync, MeasurementReportInterval,
-    MeasurementReporter, SyncNode, SyncNodeError, SyncWaitParams,
+    ensure_node_is_in_sync_with_others, ensure_nodes_are_in_sync, ensure_partitions_converged,
+    ensure_partitions_diverged, measure_bootstrap_sync, BootstrapSyncReport,
+    BootstrapSyncThresholds, MeasurementReportInterval, MeasurementReporter, SyncNode,
+    SyncNodeError, SyncWaitParams,
 };
 pub use vit::{VoteCastCounter, VotePlanBuilder, VotePlanExtension};
 
@@ -8,12 +8,57 @@ use jormungandr_lib::interfaces::{
 };
 use std::collections::HashMap;
 
+pub type BftRotationAlias = String;
+
+/// Change of the BFT leader set planned in a scenario. The leaders propose it
+/// and vote for it while the network runs, the new set is used from the epoch
+/// following the votes.
+#[derive(Clone, Debug)]
+pub struct BftRotation {
+    alias: BftRotationAlias,
+    added: Vec<NodeAlias>,
+    removed: Vec<NodeAlias>,
+}
+
+impl BftRotation {
+    pub fn new<S: Into<BftRotationAlias>>(alias: S) -> Self {
+        Self {
+            alias: alias.into(),
+            added: Vec::new(),
+            removed: Vec::new(),
+        }
+    }
+
+    pub fn add_leader<S: Into<NodeAlias>>(&mut self, alias: S) -> &mut Self {
+        self.added.push(alias.into());
+        self
+    }
+
+    pub fn remove_leader<S: Into<NodeAlias>>(&mut self, alias: S) -> &mut Self {
+        self.removed.push(alias.into());
+        self
+    }
+
+    pub fn alias(&self) -> &BftRotationAlias {
+        &self.alias
+    }
+
+    pub fn added(&self) -> &[NodeAlias] {
+        &self.added
+    }
+
+    pub fn removed(&self) -> &[NodeAlias] {
+        &self.removed
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct Blockchain {
     consensus: ConsensusVersion,
     slots_per_epoch: NumberOfSlotsPerEpoch,
     slot_duration: SlotDuration,
     leaders: Vec<NodeAlias>,
+    bft_rotations: Vec<BftRotation>,
     committees: Vec<WalletAlias>,
     external_committees: Vec<CommitteeIdDef>,
     vote_plans: Vec<VotePlanDef>,
@@ -38,6 +83,7 @@ impl Blockchain {
         Blockchain {
             consensus,
             leaders: Vec::new(),
+            bft_rotations: Vec::new(),
             wallets: HashMap::new(),
             committees: Vec::new(),
             external_committees: Vec::new(),
@@ -133,6 +179,10 @@ impl Blockchain {
         self.leaders.push(alias.into())
     }
 
+    pub fn add_bft_rotation(&mut self, bft_rotation: BftRotation) {
+        self.bft_rotations.push(bft_rotation)
+    }
+
     pub fn add_wallet(&mut self, wallet: WalletTemplate) {
         self.wallets.insert(wallet.alias().clone(), wallet);
     }
@@ -161,6 +211,10 @@ impl Blockchain {
         self.leaders.iter()
     }
 
+    pub fn bft_rotations(&self) -> impl Iterator<Item = &BftRotation> {
+        self.bft_rotations.iter()
+    }
+
     pub fn wallets(&self) -> impl Iterator<Item = &WalletTemplate> {
         self.wallets.values()
     }
@@ -0,0 +1,57 @@
+use rand::Rng;
+use std::time::Duration;
+
+/// Extra delay applied on data which was "lost". TCP never drops data, so lost
+/// packet is modelled as a delay of its retransmission
+pub const RETRANSMISSION_DELAY: Duration = Duration::from_millis(200);
+
+/// WAN-like conditions of the node network link
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct NetworkConditions {
+    /// constant delay added to data sent in both directions
+    pub latency: Duration,
+    /// random variation of the latency, delay is drawn from `latency +/- jitter`
+    pub jitter: Duration,
+    /// probability (0.0 - 1.0) that a chunk of data needs to be retransmitted
+    pub packet_loss: f64,
+}
+
+impl NetworkConditions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn latency(mut self, latency: Duration) -> Self {
+        self.latency = latency;
+        self
+    }
+
+    pub fn jitter(mut self, jitter: Duration) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    pub fn packet_loss(mut self, packet_loss: f64) -> Self {
+        self.packet_loss = packet_loss.max(0.0).min(1.0);
+        self
+    }
+
+    pub fn draw_delay<R: Rng>(&self, rng: &mut R) -> Duration {
+        let mut delay = self.latency;
+        if self.jitter > Duration::from_millis(0) {
+            let jitter = self.jitter.as_millis() as i64;
+            let offset = rng.gen_range(-jitter..=jitter);
+            delay = if offset >= 0 {
+                delay + Duration::from_millis(offset as u64)
+            } else {
+                delay
+                    .checked_sub(Duration::from_millis((-offset) as u64))
+                    .unwrap_or_else(|| Duration::from_millis(0))
+            };
+        }
+        if self.packet_loss > 0.0 && rng.gen_bool(self.packet_loss) {
+            delay += RETRANSMISSION_DELAY;
+        }
+        delay
+    }
+}
@@ -1,19 +1,26 @@
 mod blockchain;
+mod conditions;
+mod partition;
 mod rng;
 mod settings;
 mod spawn_params;
 mod topology;
+mod topology_generator;
 mod wallet;
 
-pub use blockchain::Blockchain;
+pub use blockchain::{BftRotation, BftRotationAlias, Blockchain};
 use chain_impl_mockchain::header::HeaderId;
+pub use conditions::{NetworkConditions, RETRANSMISSION_DELAY};
+pub use partition::Partition;
 pub use rng::{Random, Seed};
 pub use settings::{NodeSetting, Settings, WalletProxySettings};
 pub use spawn_params::{FaketimeConfig, SpawnParams};
 use std::path::PathBuf;
 pub use topology::{Node, NodeAlias, Topology, TopologyBuilder};
+pub use topology_generator::{TopologyGenerator, TopologyShape, TrustMode};
 pub use wallet::{
     ExternalWalletTemplate, LegacyWalletTemplate, Wallet, WalletAlias, WalletTemplate, WalletType,
+    WalletVote,
 };
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -0,0 +1,64 @@
+use super::{NodeAlias, NodeSetting};
+use jormungandr_lib::interfaces::TrustedPeer;
+use std::collections::HashMap;
+
+/// Split of the network into isolated groups of nodes.
+///
+/// Nodes only know (and therefore connect to) the members of their own group,
+/// while the connections to nodes from other groups are cut off.
+#[derive(Debug, Clone, Default)]
+pub struct Partition {
+    groups: Vec<Vec<NodeAlias>>,
+}
+
+impl Partition {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn group<S: Into<NodeAlias>, I: IntoIterator<Item = S>>(mut self, aliases: I) -> Self {
+        self.groups
+            .push(aliases.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn groups(&self) -> &[Vec<NodeAlias>] {
+        &self.groups
+    }
+
+    pub fn group_of(&self, alias: &str) -> Option<&[NodeAlias]> {
+        self.groups
+            .iter()
+            .find(|group| group.iter().any(|member| member == alias))
+            .map(|group| group.as_slice())
+    }
+
+    pub fn are_isolated(&self, left: &str, right: &str) -> bool {
+        match self.group_of(left) {
+            Some(group) => !group.iter().any(|member| member == right),
+            None => true,
+        }
+    }
+
+    /// trusted peers for node with given alias which does not cross partition boundaries.
+    /// Returns None if alias does not belong to any group
+    #[allow(deprecated)]
+    pub fn trusted_peers(
+        &self,
+        alias: &str,
+        nodes: &HashMap<NodeAlias, NodeSetting>,
+    ) -> Option<Vec<TrustedPeer>> {
+        let group = self.group_of(alias)?;
+        Some(
+            group
+                .iter()
+                .filter(|member| *member != alias)
+                .filter_map(|member| nodes.get(member))
+                .map(|node| TrustedPeer {
+                    address: node.config.p2p.public_address.clone(),
+                    id: None,
+                })
+                .collect(),
+        )
+    }
+}
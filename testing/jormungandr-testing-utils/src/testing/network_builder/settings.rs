@@ -1,18 +1,24 @@
 use super::LegacyWalletTemplate;
 use crate::testing::network_builder::{
-    Blockchain as BlockchainTemplate, ExternalWalletTemplate, Node as NodeTemplate, NodeAlias,
-    Random, Wallet, WalletAlias, WalletTemplate, WalletType,
+    BftRotationAlias, Blockchain as BlockchainTemplate, ExternalWalletTemplate,
+    Node as NodeTemplate, NodeAlias, Random, Wallet, WalletAlias, WalletTemplate, WalletType,
 };
 use crate::{stake_pool::StakePool, testing::signed_stake_pool_cert, wallet::Wallet as WalletLib};
 use chain_crypto::Ed25519;
 use chain_impl_mockchain::block::BlockDate;
-use chain_impl_mockchain::{certificate::VotePlan, chaintypes::ConsensusVersion, fee::LinearFee};
+use chain_impl_mockchain::config::{ConfigParam, ConfigParams};
+use chain_impl_mockchain::{
+    account::DelegationRatio,
+    certificate::{PoolId, VotePlan},
+    chaintypes::ConsensusVersion,
+    fee::LinearFee,
+};
 use jormungandr_lib::{
-    crypto::key::SigningKey,
+    crypto::key::{Identifier, SigningKey},
     interfaces::{
         ActiveSlotCoefficient, Bft, Block0Configuration, BlockchainConfiguration, CommitteeIdDef,
-        GenesisPraos, Initial, InitialUTxO, LegacyUTxO, NodeConfig, NodeId, NodeSecret,
-        TrustedPeer,
+        ConsensusLeaderId, GenesisPraos, Initial, InitialUTxO, LegacyUTxO, NodeConfig, NodeId,
+        NodeSecret, TrustedPeer,
     },
 };
 use rand_core::{CryptoRng, RngCore};
@@ -115,6 +121,9 @@ pub struct Settings {
     pub stake_pools: HashMap<NodeAlias, StakePool>,
 
     pub vote_plans: HashMap<VotePlanAlias, VotePlan>,
+
+    /// the changes of the BFT leader set proposed by each planned rotation
+    pub bft_rotations: HashMap<BftRotationAlias, ConfigParams>,
 }
 
 impl Settings {
@@ -140,11 +149,13 @@ impl Settings {
             legacy_wallets: HashMap::new(),
             stake_pools: HashMap::new(),
             vote_plans: HashMap::new(),
+            bft_rotations: HashMap::new(),
         };
 
-        settings.populate_trusted_peers();
+        settings.populate_trusted_peers(rng);
         settings.populate_block0_blockchain_initials(blockchain.wallets(), rng);
         settings.populate_block0_blockchain_configuration(&blockchain, rng);
+        settings.populate_bft_rotations(&blockchain, rng);
         settings.populate_block0_blockchain_legacy(blockchain.legacy_wallets());
         settings.populate_block0_blockchain_external(blockchain.external_wallets());
 
@@ -195,17 +206,9 @@ impl Settings {
         blockchain_configuration.consensus_leader_ids = {
             let mut leader_ids = Vec::new();
             for leader_alias in blockchain.leaders() {
-                let identifier = if let Some(node) = self.nodes.get_mut(leader_alias) {
-                    if let Some(bft) = &node.secret.bft {
-                        bft.signing_key.identifier()
-                    } else {
-                        let signing_key = SigningKey::generate(rng.rng_mut());
-                        let identifier = signing_key.identifier();
-                        node.secret.bft = Some(Bft { signing_key });
-                        identifier
-                    }
-                } else {
-                    SigningKey::<Ed25519>::generate(rng.rng_mut()).identifier()
+                let identifier = match self.nodes.get_mut(leader_alias) {
+                    Some(node) => bft_leader_identifier(node, rng),
+                    None => SigningKey::<Ed25519>::generate(rng.rng_mut()).identifier(),
                 };
                 leader_ids.push(identifier.into());
             }
@@ -232,6 +235,38 @@ impl Settings {
             ActiveSlotCoefficient::MAXIMUM;
     }
 
+    /// Gives a BFT key to the nodes joining the leaders in a rotation, and
+    /// prepares the changes each rotation proposes.
+    fn populate_bft_rotations<RNG>(
+        &mut self,
+        blockchain: &BlockchainTemplate,
+        rng: &mut Random<RNG>,
+    ) where
+        RNG: RngCore + CryptoRng,
+    {
+        for rotation in blockchain.bft_rotations() {
+            let mut changes = ConfigParams::new();
+            let added = rotation.added().iter().map(|alias| (alias, true));
+            let removed = rotation.removed().iter().map(|alias| (alias, false));
+            for (alias, add) in added.chain(removed) {
+                let node = self.nodes.get_mut(alias).unwrap_or_else(|| {
+                    panic!(
+                        "node {} of bft rotation {} not defined",
+                        alias,
+                        rotation.alias()
+                    )
+                });
+                let leader_id = ConsensusLeaderId::from(bft_leader_identifier(node, rng)).0;
+                changes.push(if add {
+                    ConfigParam::AddBftLeader(leader_id)
+                } else {
+                    ConfigParam::RemoveBftLeader(leader_id)
+                });
+            }
+            self.bft_rotations.insert(rotation.alias().clone(), changes);
+        }
+    }
+
     fn populate_block0_blockchain_initials<'a, RNG, I>(
         &'a mut self,
         wallet_templates: I,
@@ -262,75 +297,132 @@ impl Settings {
             self.block0.initial.push(initial_fragment);
 
             if let Some(delegation) = wallet_template.delegate() {
-                use chain_impl_mockchain::certificate::PoolId as StakePoolId;
-
-                // 1. retrieve the public data (we may need to create a stake pool
-                //    registration here)
-                let stake_pool_id: StakePoolId = if let Some(node) = self.nodes.get_mut(delegation)
-                {
-                    if let Some(genesis) = &node.secret.genesis {
-                        genesis.node_id.into_digest_of()
-                    } else {
-                        // create and register the stake pool
-                        let owner = WalletLib::new_account(&mut rand::rngs::OsRng);
-                        let stake_pool = StakePool::new(&owner);
-                        let node_id = stake_pool.id();
-                        node.secret.genesis = Some(GenesisPraos {
-                            sig_key: stake_pool.kes().signing_key(),
-                            vrf_key: stake_pool.vrf().signing_key(),
-                            node_id: {
-                                let bytes: [u8; 32] = node_id.clone().into();
-                                bytes.into()
-                            },
-                        });
-
-                        self.block0.initial.push(Initial::Cert(
-                            signed_stake_pool_cert(BlockDate::first().next_epoch(), &stake_pool)
-                                .into(),
-                        ));
-
-                        self.stake_pools
-                            .insert(delegation.clone(), stake_pool.clone());
-
-                        node_id
-                    }
-                } else {
-                    // delegating to a node that does not exist in the topology
-                    // so generate valid stake pool registration and delegation
-                    // to that node.
-                    unimplemented!("delegating stake to a stake pool that is not a node is not supported (yet)")
-                };
+                let stake_pool_id = self.stake_pool_id(delegation, rng);
 
-                // 2. create delegation certificate for the wallet stake key
+                // create delegation certificate for the wallet stake key
                 // and add it to the block0.initial array
                 let delegation_certificate = wallet
                     .delegation_cert_for_block0(BlockDate::first().next_epoch(), stake_pool_id);
 
                 self.block0.initial.push(delegation_certificate);
             }
+
+            if !wallet_template.delegation_ratios().is_empty() {
+                assert!(
+                    wallet_template.delegate().is_none(),
+                    "wallet '{}' both delegates to a node and splits its delegation",
+                    wallet_template.alias()
+                );
+                let mut parts = 0u8;
+                let mut pools = Vec::new();
+                for (delegation, weight) in wallet_template.delegation_ratios() {
+                    parts = parts.checked_add(*weight).unwrap_or_else(|| {
+                        panic!(
+                            "delegation weights of wallet '{}' add up to more than {}",
+                            wallet_template.alias(),
+                            u8::MAX
+                        )
+                    });
+                    pools.push((self.stake_pool_id(delegation, rng), *weight));
+                }
+                let ratio = DelegationRatio::new(parts, pools).unwrap_or_else(|| {
+                    panic!(
+                        "invalid delegation ratios for wallet '{}'",
+                        wallet_template.alias()
+                    )
+                });
+
+                let delegation_certificate =
+                    wallet.split_delegation_cert_for_block0(BlockDate::first().next_epoch(), ratio);
+
+                self.block0.initial.push(delegation_certificate);
+            }
         }
     }
 
-    #[allow(deprecated)]
-    fn populate_trusted_peers(&mut self) {
-        //generate public id for all nodes treated as trusted peers
-        let mut trusted_peers_aliases = HashSet::new();
-
-        //gather aliases which are trusted peers
-        for (_alias, node) in self.nodes.iter() {
-            for trusted_peer in node.node_topology.trusted_peers() {
-                trusted_peers_aliases.insert(trusted_peer.clone());
+    /// Id of the stake pool run by the node `delegation`. The stake pool is
+    /// created and registered in block0 if the node does not run one yet.
+    fn stake_pool_id<RNG>(&mut self, delegation: &NodeAlias, rng: &mut Random<RNG>) -> PoolId
+    where
+        RNG: RngCore + CryptoRng,
+    {
+        if let Some(node) = self.nodes.get_mut(delegation) {
+            if let Some(genesis) = &node.secret.genesis {
+                genesis.node_id.into_digest_of()
+            } else {
+                // create and register the stake pool
+                let owner = WalletLib::new_account(rng.rng_mut());
+                let stake_pool = StakePool::new_with_rng(&owner, rng.rng_mut());
+                let node_id = stake_pool.id();
+                node.secret.genesis = Some(GenesisPraos {
+                    sig_key: stake_pool.kes().signing_key(),
+                    vrf_key: stake_pool.vrf().signing_key(),
+                    node_id: {
+                        let bytes: [u8; 32] = node_id.clone().into();
+                        bytes.into()
+                    },
+                });
+
+                self.block0.initial.push(Initial::Cert(
+                    signed_stake_pool_cert(BlockDate::first().next_epoch(), &stake_pool).into(),
+                ));
+
+                self.stake_pools
+                    .insert(delegation.clone(), stake_pool.clone());
+
+                node_id
             }
+        } else {
+            // delegating to a node that does not exist in the topology
+            // so generate valid stake pool registration and delegation
+            // to that node.
+            unimplemented!(
+                "delegating stake to a stake pool that is not a node is not supported (yet)"
+            )
+        }
+    }
+
+    /// Adds nodes to a network whose block0 is already built: the new nodes
+    /// get their trusted peers, which may be nodes already in the network,
+    /// but they cannot be leaders nor stake pools.
+    pub fn add_nodes<RNG>(&mut self, nodes: HashMap<NodeAlias, NodeSetting>, rng: &mut Random<RNG>)
+    where
+        RNG: RngCore + CryptoRng,
+    {
+        let mut aliases = HashSet::new();
+        for (alias, node) in nodes {
+            assert!(
+                !self.nodes.contains_key(&alias),
+                "node '{}' is already in the network",
+                alias
+            );
+            aliases.insert(alias.clone());
+            self.nodes.insert(alias, node);
         }
+        self.populate_trusted_peers_of(|alias| aliases.contains(alias), rng);
+    }
 
+    fn populate_trusted_peers<RNG>(&mut self, rng: &mut Random<RNG>)
+    where
+        RNG: RngCore + CryptoRng,
+    {
+        self.populate_trusted_peers_of(|_| true, rng);
+    }
+
+    #[allow(deprecated)]
+    fn populate_trusted_peers_of<RNG, F>(&mut self, filter: F, rng: &mut Random<RNG>)
+    where
+        RNG: RngCore + CryptoRng,
+        F: Fn(&NodeAlias) -> bool,
+    {
         let nodes = self.nodes.clone();
-        for (_alias, node) in self.nodes.iter_mut() {
+        for (_alias, node) in self.nodes.iter_mut().filter(|(alias, _)| filter(alias)) {
             let mut trusted_peers = Vec::new();
 
             for trusted_peer in node.node_topology.trusted_peers() {
                 let trusted_peer = nodes.get(trusted_peer).unwrap();
                 let id = NodeId::from(
-                    <chain_crypto::SecretKey<chain_crypto::Ed25519>>::generate(rand::thread_rng())
+                    <chain_crypto::SecretKey<chain_crypto::Ed25519>>::generate(rng.rng_mut())
                         .to_public(),
                 );
                 trusted_peers.push(TrustedPeer {
@@ -345,3 +437,17 @@ impl Settings {
         }
     }
 }
+
+/// The identifier of the BFT key of the node, generated if the node has none.
+fn bft_leader_identifier<RNG>(node: &mut NodeSetting, rng: &mut Random<RNG>) -> Identifier<Ed25519>
+where
+    RNG: RngCore + CryptoRng,
+{
+    if let Some(bft) = &node.secret.bft {
+        return bft.signing_key.identifier();
+    }
+    let signing_key = SigningKey::generate(rng.rng_mut());
+    let identifier = signing_key.identifier();
+    node.secret.bft = Some(Bft { signing_key });
+    identifier
+}
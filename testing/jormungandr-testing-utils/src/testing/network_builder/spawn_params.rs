@@ -1,13 +1,13 @@
 use jormungandr_lib::interfaces::{
-    Explorer, LayersConfig, Mempool, NodeConfig, Policy, PreferredListConfig, TopicsOfInterest,
-    TrustedPeer,
+    BlockPropagationConfig, Explorer, LayersConfig, Log, LogEntry, LogOutput, Mempool, NodeConfig,
+    PersistentLog, Policy, PreferredListConfig, TopicsOfInterest, TrustedPeer,
 };
 use jormungandr_lib::time::Duration;
 use multiaddr::Multiaddr;
 use std::net::SocketAddr;
 use tracing::Level;
 
-use super::{LeadershipMode, PersistenceMode};
+use super::{LeadershipMode, NetworkConditions, PersistenceMode};
 use crate::testing::node::Version;
 use std::path::Path;
 use std::path::PathBuf;
@@ -17,6 +17,8 @@ pub struct SpawnParams {
     pub topics_of_interest: Option<TopicsOfInterest>,
     pub explorer: Option<Explorer>,
     pub mempool: Option<Mempool>,
+    pub pool_max_entries: Option<usize>,
+    pub log_max_entries: Option<usize>,
     pub policy: Option<Policy>,
     pub jormungandr: Option<PathBuf>,
     pub listen_address: Option<Option<SocketAddr>>,
@@ -36,8 +38,13 @@ pub struct SpawnParams {
     pub faketime: Option<FaketimeConfig>,
     pub gossip_interval: Option<Duration>,
     pub log_level: Option<Level>,
+    pub log_format: Option<String>,
+    pub allow_private_addresses: Option<bool>,
     pub max_bootstrap_attempts: Option<usize>,
     pub network_stuck_check: Option<Duration>,
+    pub block_propagation: Option<BlockPropagationConfig>,
+    pub preferred_peers: Option<Vec<TrustedPeer>>,
+    pub network_conditions: Option<NetworkConditions>,
 }
 
 #[derive(Clone)]
@@ -54,6 +61,8 @@ impl SpawnParams {
             topics_of_interest: None,
             explorer: None,
             mempool: None,
+            pool_max_entries: None,
+            log_max_entries: None,
             policy: None,
             jormungandr: None,
             alias: alias.to_owned(),
@@ -73,8 +82,13 @@ impl SpawnParams {
             faketime: None,
             gossip_interval: None,
             log_level: None,
+            log_format: None,
+            allow_private_addresses: None,
             max_bootstrap_attempts: None,
             network_stuck_check: None,
+            block_propagation: None,
+            preferred_peers: None,
+            network_conditions: None,
         }
     }
 
@@ -148,6 +162,16 @@ impl SpawnParams {
         self
     }
 
+    pub fn pool_max_entries(&mut self, pool_max_entries: usize) -> &mut Self {
+        self.pool_max_entries = Some(pool_max_entries);
+        self
+    }
+
+    pub fn log_max_entries(&mut self, log_max_entries: usize) -> &mut Self {
+        self.log_max_entries = Some(log_max_entries);
+        self
+    }
+
     pub fn bootstrap_from_peers(&mut self, bootstrap_from_peers: bool) -> &mut Self {
         self.bootstrap_from_peers = Some(bootstrap_from_peers);
         self
@@ -218,6 +242,18 @@ impl SpawnParams {
         self
     }
 
+    /// node log format ("json" or "plain"). Please note that the log parsing utilities
+    /// expect json format, so other formats only make sense for manual inspection
+    pub fn log_format<S: Into<String>>(&mut self, format: S) -> &mut Self {
+        self.log_format = Some(format.into());
+        self
+    }
+
+    pub fn allow_private_addresses(&mut self, allow_private_addresses: bool) -> &mut Self {
+        self.allow_private_addresses = Some(allow_private_addresses);
+        self
+    }
+
     pub fn max_bootstrap_attempts(&mut self, attempts: usize) -> &mut Self {
         self.max_bootstrap_attempts = Some(attempts);
         self
@@ -228,6 +264,26 @@ impl SpawnParams {
         self
     }
 
+    pub fn block_propagation(&mut self, block_propagation: BlockPropagationConfig) -> &mut Self {
+        self.block_propagation = Some(block_propagation);
+        self
+    }
+
+    pub fn preferred_peers(&mut self, preferred_peers: Vec<TrustedPeer>) -> &mut Self {
+        self.preferred_peers = Some(preferred_peers);
+        self
+    }
+
+    /// apply latency, jitter and packet loss on all incoming p2p connections of the node
+    pub fn network_conditions(&mut self, network_conditions: NetworkConditions) -> &mut Self {
+        self.network_conditions = Some(network_conditions);
+        self
+    }
+
+    pub fn get_network_conditions(&self) -> Option<NetworkConditions> {
+        self.network_conditions
+    }
+
     pub fn get_jormungandr(&self) -> &Option<PathBuf> {
         &self.jormungandr
     }
@@ -240,6 +296,8 @@ impl SpawnParams {
                 node_config.p2p.layers = Some(LayersConfig {
                     preferred_list: None,
                     topics_of_interest: Some(topics_of_interest.clone()),
+                    rings: None,
+                    cyclon: None,
                 });
             }
         }
@@ -252,6 +310,46 @@ impl SpawnParams {
             node_config.mempool = Some(mempool.clone());
         }
 
+        if let Some(pool_max_entries) = self.pool_max_entries {
+            node_config
+                .mempool
+                .get_or_insert_with(Default::default)
+                .pool_max_entries = pool_max_entries.into();
+        }
+
+        if let Some(log_max_entries) = self.log_max_entries {
+            node_config
+                .mempool
+                .get_or_insert_with(Default::default)
+                .log_max_entries = log_max_entries.into();
+        }
+
+        if let Some(persistent_fragment_log) = &self.persistent_fragment_log {
+            node_config
+                .mempool
+                .get_or_insert_with(Default::default)
+                .persistent_log = Some(PersistentLog {
+                dir: persistent_fragment_log.clone(),
+            });
+        }
+
+        if self.log_level.is_some() || self.log_format.is_some() {
+            let Log(mut log) = node_config.log.clone().unwrap_or_else(|| {
+                Log(LogEntry {
+                    format: "json".to_string(),
+                    level: Level::INFO.to_string(),
+                    output: LogOutput::Stdout,
+                })
+            });
+            if let Some(level) = &self.log_level {
+                log.level = level.to_string();
+            }
+            if let Some(format) = &self.log_format {
+                log.format = format.clone();
+            }
+            node_config.log = Some(Log(log));
+        }
+
         if let Some(policy) = &self.policy {
             node_config.p2p.policy = Some(policy.clone());
         }
@@ -268,6 +366,10 @@ impl SpawnParams {
             node_config.p2p.max_connections = Some(*max_connections);
         }
 
+        if let Some(allow_private_addresses) = self.allow_private_addresses {
+            node_config.p2p.allow_private_addresses = allow_private_addresses;
+        }
+
         if let Some(listen_address_option) = &self.listen_address {
             node_config.p2p.listen = *listen_address_option;
         }
@@ -283,6 +385,8 @@ impl SpawnParams {
                 node_config.p2p.layers = Some(LayersConfig {
                     preferred_list: Some(preferred_layer.clone()),
                     topics_of_interest: None,
+                    rings: None,
+                    cyclon: None,
                 });
             }
         }
@@ -310,5 +414,13 @@ impl SpawnParams {
         if let Some(network_stuck_check) = self.network_stuck_check {
             node_config.p2p.network_stuck_check = Some(network_stuck_check);
         }
+
+        if let Some(block_propagation) = &self.block_propagation {
+            node_config.p2p.block_propagation = Some(block_propagation.clone());
+        }
+
+        if let Some(preferred_peers) = &self.preferred_peers {
+            node_config.p2p.preferred_peers = Some(preferred_peers.clone());
+        }
     }
 }
@@ -62,6 +62,12 @@ impl Topology {
         self.nodes.keys()
     }
 
+    /// Adds the nodes of `delta` to this topology, `delta` being built with
+    /// [`TopologyBuilder::build_on`] this topology.
+    pub fn extend(&mut self, delta: Topology) {
+        self.nodes.extend(delta.nodes);
+    }
+
     pub fn format_into_graphviz_dot<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<W> {
         writeln!(writer, "digraph NodeTopology {{")?;
 
@@ -99,6 +105,24 @@ impl TopologyBuilder {
 
         Topology { nodes: self.nodes }
     }
+
+    /// Builds the nodes to add to `base`: their trusted peers can be nodes of
+    /// `base` as well as the nodes registered in this builder.
+    pub fn build_on(self, base: &Topology) -> Topology {
+        for node in self.nodes.values() {
+            if base.nodes.contains_key(node.alias()) {
+                panic!("Node '{}' is already defined", node.alias())
+            }
+            for trusted_peer in node.trusted_peers() {
+                if !self.nodes.contains_key(trusted_peer) && !base.nodes.contains_key(trusted_peer)
+                {
+                    panic!("Trusted peer has not been defined")
+                }
+            }
+        }
+
+        Topology { nodes: self.nodes }
+    }
 }
 
 impl Default for TopologyBuilder {
@@ -106,3 +130,55 @@ impl Default for TopologyBuilder {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn base() -> Topology {
+        let mut builder = TopologyBuilder::new();
+        builder.register_node(Node::new("Leader1"));
+        let mut leader_2 = Node::new("Leader2");
+        leader_2.add_trusted_peer("Leader1");
+        builder.register_node(leader_2);
+        builder.build()
+    }
+
+    #[test]
+    pub fn delta_can_trust_the_nodes_of_the_base_test() {
+        let mut topology = base();
+        let mut delta = TopologyBuilder::new();
+        let mut passive_1 = Node::new("Passive1");
+        passive_1.add_trusted_peer("Leader2");
+        delta.register_node(passive_1);
+        let mut passive_2 = Node::new("Passive2");
+        passive_2.add_trusted_peer("Passive1");
+        delta.register_node(passive_2);
+
+        let delta = delta.build_on(&topology);
+        assert_eq!(delta.aliases().count(), 2);
+
+        topology.extend(delta);
+        let mut aliases: Vec<_> = topology.aliases().cloned().collect();
+        aliases.sort();
+        assert_eq!(aliases, vec!["Leader1", "Leader2", "Passive1", "Passive2"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Trusted peer has not been defined")]
+    pub fn delta_cannot_trust_unknown_nodes_test() {
+        let mut delta = TopologyBuilder::new();
+        let mut passive = Node::new("Passive1");
+        passive.add_trusted_peer("Leader3");
+        delta.register_node(passive);
+        delta.build_on(&base());
+    }
+
+    #[test]
+    #[should_panic(expected = "Node 'Leader1' is already defined")]
+    pub fn delta_cannot_redefine_the_nodes_of_the_base_test() {
+        let mut delta = TopologyBuilder::new();
+        delta.register_node(Node::new("Leader1"));
+        delta.build_on(&base());
+    }
+}
@@ -0,0 +1,190 @@
+use super::{Node, NodeAlias, Random, Seed, Topology, TopologyBuilder};
+use rand::Rng;
+use rand_chacha::ChaChaRng;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TopologyShape {
+    /// every node is connected with its predecessor, last node closes the ring
+    Ring,
+    /// all nodes are connected to the first one
+    Star,
+    /// every node is connected with all other nodes
+    Mesh,
+    /// every node is connected with one random predecessor (so the graph stays connected)
+    /// and with each other predecessor with given probability
+    Random { extra_peer_probability: f64 },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrustMode {
+    /// node trusts only nodes generated before it, so nodes spawned in the order
+    /// of `TopologyGenerator::aliases` always have their trusted peers up
+    Upstream,
+    /// both ends of the connection trust each other. First nodes will try to
+    /// bootstrap from nodes which are not spawned yet
+    Mutual,
+}
+
+/// Generates topologies of arbitrary size, so tests are not limited to hand written ones.
+#[derive(Debug, Clone)]
+pub struct TopologyGenerator {
+    shape: TopologyShape,
+    nodes_count: usize,
+    alias_prefix: String,
+    trust_mode: TrustMode,
+    seed: Option<Seed>,
+}
+
+impl TopologyGenerator {
+    pub fn new(shape: TopologyShape, nodes_count: usize) -> Self {
+        Self {
+            shape,
+            nodes_count,
+            alias_prefix: "Node".to_string(),
+            trust_mode: TrustMode::Upstream,
+            seed: None,
+        }
+    }
+
+    pub fn ring(nodes_count: usize) -> Self {
+        Self::new(TopologyShape::Ring, nodes_count)
+    }
+
+    pub fn star(nodes_count: usize) -> Self {
+        Self::new(TopologyShape::Star, nodes_count)
+    }
+
+    pub fn mesh(nodes_count: usize) -> Self {
+        Self::new(TopologyShape::Mesh, nodes_count)
+    }
+
+    pub fn random(nodes_count: usize, extra_peer_probability: f64) -> Self {
+        Self::new(
+            TopologyShape::Random {
+                extra_peer_probability,
+            },
+            nodes_count,
+        )
+    }
+
+    pub fn alias_prefix<S: Into<String>>(mut self, alias_prefix: S) -> Self {
+        self.alias_prefix = alias_prefix.into();
+        self
+    }
+
+    pub fn trust_mode(mut self, trust_mode: TrustMode) -> Self {
+        self.trust_mode = trust_mode;
+        self
+    }
+
+    /// seed used for `TopologyShape::Random`, if not set random one is generated
+    pub fn seed(mut self, seed: Seed) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    pub fn alias(&self, index: usize) -> NodeAlias {
+        format!("{}{}", self.alias_prefix, index + 1)
+    }
+
+    /// aliases of all generated nodes, in the order in which they should be spawned
+    pub fn aliases(&self) -> Vec<NodeAlias> {
+        (0..self.nodes_count)
+            .map(|index| self.alias(index))
+            .collect()
+    }
+
+    /// list of connections (node index, trusted peer index), where trusted peer index
+    /// is always lower than node index
+    pub fn connections(&self) -> Vec<(usize, usize)> {
+        let count = self.nodes_count;
+        match self.shape {
+            TopologyShape::Ring => {
+                let mut connections: Vec<_> = (1..count).map(|i| (i, i - 1)).collect();
+                if count > 2 {
+                    connections.push((count - 1, 0));
+                }
+                connections
+            }
+            TopologyShape::Star => (1..count).map(|i| (i, 0)).collect(),
+            TopologyShape::Mesh => (1..count)
+                .flat_map(|i| (0..i).map(move |j| (i, j)))
+                .collect(),
+            TopologyShape::Random {
+                extra_peer_probability,
+            } => {
+                let seed = self
+                    .seed
+                    .unwrap_or_else(|| Seed::generate(rand::thread_rng()));
+                let mut random = Random::<ChaChaRng>::new(seed);
+                let rng = random.rng_mut();
+                let probability = extra_peer_probability.max(0.0).min(1.0);
+
+                let mut connections = Vec::new();
+                for i in 1..count {
+                    let parent = rng.gen_range(0..i);
+                    connections.push((i, parent));
+                    for j in (0..i).filter(|j| *j != parent) {
+                        if rng.gen_bool(probability) {
+                            connections.push((i, j));
+                        }
+                    }
+                }
+                connections
+            }
+        }
+    }
+
+    pub fn build(&self) -> Topology {
+        let mut nodes: Vec<Node> = self.aliases().into_iter().map(Node::new).collect();
+
+        for (node, peer) in self.connections() {
+            let peer_alias = self.alias(peer);
+            nodes[node].add_trusted_peer(peer_alias);
+            if self.trust_mode == TrustMode::Mutual {
+                let node_alias = self.alias(node);
+                nodes[peer].add_trusted_peer(node_alias);
+            }
+        }
+
+        let mut builder = TopologyBuilder::new();
+        for node in nodes {
+            builder.register_node(node);
+        }
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn generated_connections_count_test() {
+        assert_eq!(TopologyGenerator::ring(5).connections().len(), 5);
+        assert_eq!(TopologyGenerator::star(5).connections().len(), 4);
+        assert_eq!(TopologyGenerator::mesh(5).connections().len(), 10);
+    }
+
+    #[test]
+    pub fn random_topology_is_reproducible_and_upstream_only_test() {
+        let seed = Seed::generate(rand::thread_rng());
+        let generator = TopologyGenerator::random(20, 0.2).seed(seed);
+        let connections = generator.connections();
+
+        assert_eq!(connections, generator.connections());
+        assert!(connections.iter().all(|(node, peer)| peer < node));
+        for node in 1..20 {
+            assert!(connections.iter().any(|(from, _)| *from == node));
+        }
+    }
+
+    #[test]
+    pub fn mutual_trust_mode_adds_reverse_peers_test() {
+        let topology = TopologyGenerator::star(3)
+            .trust_mode(TrustMode::Mutual)
+            .build();
+        let hub_peers: Vec<_> = topology.node("Node1").unwrap().trusted_peers().collect();
+        assert_eq!(hub_peers.len(), 2);
+    }
+}
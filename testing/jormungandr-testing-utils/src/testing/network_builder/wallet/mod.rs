@@ -1,13 +1,13 @@
 mod template;
 
-pub use template::{ExternalWalletTemplate, LegacyWalletTemplate, WalletTemplate};
+pub use template::{ExternalWalletTemplate, LegacyWalletTemplate, WalletTemplate, WalletVote};
 
 use crate::wallet::{
     account::Wallet as AccountWallet, utxo::Wallet as UtxOWallet, Wallet as Inner, WalletError,
 };
 use chain_impl_mockchain::{
-    block::BlockDate, certificate::PoolId, fee::LinearFee, fragment::Fragment,
-    transaction::UnspecifiedAccountIdentifier, vote::CommitteeId,
+    account::DelegationRatio, block::BlockDate, certificate::PoolId, fee::LinearFee,
+    fragment::Fragment, transaction::UnspecifiedAccountIdentifier, vote::CommitteeId,
 };
 use jormungandr_lib::{
     crypto::hash::Hash,
@@ -76,6 +76,15 @@ impl Wallet {
         self.inner.delegation_cert_for_block0(valid_until, pool_id)
     }
 
+    pub fn split_delegation_cert_for_block0(
+        &self,
+        valid_until: BlockDate,
+        ratio: DelegationRatio,
+    ) -> Initial {
+        self.inner
+            .split_delegation_cert_for_block0(valid_until, ratio)
+    }
+
     pub fn template(&self) -> &WalletTemplate {
         &self.template
     }
@@ -2,7 +2,7 @@ mod external;
 mod legacy;
 
 use super::{WalletAlias, WalletType};
-use crate::testing::network_builder::NodeAlias;
+use crate::testing::network_builder::{settings::VotePlanAlias, NodeAlias};
 use chain_addr::Discrimination;
 use chain_impl_mockchain::value::Value;
 pub use external::ExternalWalletTemplate;
@@ -14,9 +14,19 @@ pub struct WalletTemplate {
     value: Value,
     wallet_type: WalletType,
     delegate: Option<NodeAlias>,
+    delegation_ratios: Vec<(NodeAlias, u8)>,
+    votes: Vec<WalletVote>,
     discrimination: Discrimination,
 }
 
+/// Vote the wallet casts once the network is running.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct WalletVote {
+    pub vote_plan: VotePlanAlias,
+    pub proposal_index: u8,
+    pub choice: u8,
+}
+
 impl WalletTemplate {
     pub fn new_account<S: Into<WalletAlias>>(
         alias: S,
@@ -45,6 +55,8 @@ impl WalletTemplate {
             value,
             wallet_type,
             delegate: None,
+            delegation_ratios: Vec::new(),
+            votes: Vec::new(),
             discrimination,
         }
     }
@@ -72,4 +84,22 @@ impl WalletTemplate {
     pub fn delegate_mut(&mut self) -> &mut Option<NodeAlias> {
         &mut self.delegate
     }
+
+    /// Nodes the stake of the wallet is split between, with the weight of
+    /// each of them. Exclusive with [`delegate`](Self::delegate).
+    pub fn delegation_ratios(&self) -> &[(NodeAlias, u8)] {
+        &self.delegation_ratios
+    }
+
+    pub fn delegation_ratios_mut(&mut self) -> &mut Vec<(NodeAlias, u8)> {
+        &mut self.delegation_ratios
+    }
+
+    pub fn votes(&self) -> &[WalletVote] {
+        &self.votes
+    }
+
+    pub fn votes_mut(&mut self) -> &mut Vec<WalletVote> {
+        &mut self.votes
+    }
 }
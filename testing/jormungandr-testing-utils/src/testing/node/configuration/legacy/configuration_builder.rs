@@ -90,6 +90,8 @@ impl LegacyNodeConfigConverter {
                 listen: source.rest.listen,
                 cors: None,
                 tls: None,
+                max_body_size: None,
+                request_timeout: None,
             },
             p2p: P2p {
                 trusted_peers,
@@ -149,6 +151,8 @@ impl LegacyNodeConfigConverter {
                 listen: source.rest.listen,
                 cors: None,
                 tls: None,
+                max_body_size: None,
+                request_timeout: None,
             },
             p2p: P2p {
                 trusted_peers,
@@ -2,13 +2,13 @@
 
 use jormungandr_lib::{
     interfaces::{
-        Explorer, LayersConfig, Log, Mempool, NodeConfig, P2p, Policy, Rest, Tls, TopicsOfInterest,
-        TrustedPeer,
+        Cors, Explorer, LayersConfig, Log, Mempool, NodeConfig, P2p, Policy, Rest, SimulationClock,
+        Tls, TopicsOfInterest, TrustedPeer,
     },
     time::Duration,
 };
 use multiaddr::Multiaddr;
-use std::path::PathBuf;
+use std::{num::NonZeroU32, path::PathBuf};
 
 #[derive(Debug, Clone)]
 pub struct NodeConfigBuilder {
@@ -18,6 +18,7 @@ pub struct NodeConfigBuilder {
     pub p2p: P2p,
     pub mempool: Option<Mempool>,
     pub explorer: Explorer,
+    pub simulation_clock: Option<SimulationClock>,
 }
 
 const DEFAULT_HOST: &str = "127.0.0.1";
@@ -49,12 +50,15 @@ impl NodeConfigBuilder {
                     .unwrap(),
                 tls: None,
                 cors: None,
+                max_body_size: None,
+                request_timeout: None,
             },
             p2p: P2p {
                 node_key_file: None,
                 trusted_peers: vec![],
                 public_address: grpc_public_address,
                 listen: None,
+                local_listen_port: None,
                 max_inbound_connections: None,
                 max_connections: None,
                 allow_private_addresses: true,
@@ -68,13 +72,18 @@ impl NodeConfigBuilder {
                         messages: String::from("high"),
                         blocks: String::from("high"),
                     }),
+                    rings: None,
+                    cyclon: None,
                 }),
                 gossip_interval: None,
                 max_bootstrap_attempts: None,
                 network_stuck_check: None,
+                block_propagation: None,
+                preferred_peers: None,
             },
             mempool: Some(Mempool::default()),
             explorer: Explorer { enabled: false },
+            simulation_clock: None,
         }
     }
 
@@ -108,11 +117,26 @@ impl NodeConfigBuilder {
         self
     }
 
+    pub fn with_local_listen_port(&mut self, port: u16) -> &mut Self {
+        self.p2p.local_listen_port = Some(port);
+        self
+    }
+
     pub fn with_rest_tls_config(&mut self, tls: Tls) -> &mut Self {
         self.rest.tls = Some(tls);
         self
     }
 
+    pub fn with_rest_cors_config(&mut self, cors: Cors) -> &mut Self {
+        self.rest.cors = Some(cors);
+        self
+    }
+
+    pub fn with_rest_max_body_size(&mut self, max_body_size: u64) -> &mut Self {
+        self.rest.max_body_size = Some(max_body_size);
+        self
+    }
+
     pub fn with_mempool(&mut self, mempool: Mempool) -> &mut Self {
         self.mempool = Some(mempool);
         self
@@ -123,6 +147,13 @@ impl NodeConfigBuilder {
         self
     }
 
+    /// Runs the clock of the node `acceleration` times faster than the system
+    /// clock. The node must be built with the `simulation-clock` feature.
+    pub fn with_simulation_clock(&mut self, acceleration: NonZeroU32) -> &mut Self {
+        self.simulation_clock = Some(SimulationClock { acceleration });
+        self
+    }
+
     pub fn build(&self) -> NodeConfig {
         NodeConfig {
             storage: self.storage.clone(),
@@ -133,6 +164,7 @@ impl NodeConfigBuilder {
             explorer: self.explorer.clone(),
             bootstrap_from_trusted_peers: Some(!self.p2p.trusted_peers.is_empty()),
             skip_bootstrap: Some(self.p2p.trusted_peers.is_empty()),
+            simulation_clock: self.simulation_clock,
         }
     }
 }
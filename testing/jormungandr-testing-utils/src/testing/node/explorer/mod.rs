@@ -16,8 +16,13 @@ mod client;
 // do not respect the naming convention
 #[allow(clippy::upper_case_acronyms)]
 mod data;
+mod typed;
 mod wrappers;
 
+pub use typed::{
+    ExplorerAddress, ExplorerBlock, ExplorerPayloadType, ExplorerProposal, ExplorerStakePool,
+    ExplorerTransaction, ExplorerTransactionIo, ExplorerVote, ExplorerVotePlan, TypedExplorer,
+};
 pub use wrappers::LastBlockResponse;
 
 pub mod load;
@@ -36,6 +41,10 @@ pub enum ExplorerError {
     SerializationError(#[from] serde_json::Error),
     #[error("request error")]
     ReqwestError(#[from] reqwest::Error),
+    #[error("graphql errors: {0:?}")]
+    GraphQlErrors(Vec<String>),
+    #[error("response does not contain any data")]
+    MissingData,
 }
 
 #[derive(Clone)]
@@ -54,6 +63,10 @@ impl Explorer {
         }
     }
 
+    pub fn typed(&self) -> TypedExplorer {
+        self.clone().into()
+    }
+
     pub fn uri(&self) -> String {
         self.client.base_url()
     }
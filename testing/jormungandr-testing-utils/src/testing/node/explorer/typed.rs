@@ -0,0 +1,446 @@
+use super::{
+    data::{
+        address, all_blocks, all_stake_pools, all_vote_plans, blocks_by_chain_length, stake_pool,
+        transaction_by_id, Address, AllBlocks, AllStakePools, AllVotePlans, BlocksByChainLength,
+        StakePool, TransactionById,
+    },
+    Explorer, ExplorerError,
+};
+use chain_impl_mockchain::block::BlockDate as LibBlockDate;
+use graphql_client::{GraphQLQuery, QueryBody, Response};
+use jormungandr_lib::{crypto::hash::Hash, interfaces::BlockDate};
+use serde::{de::DeserializeOwned, Deserialize, Deserializer};
+use std::ops::Range;
+use std::str::FromStr;
+
+/// Explorer client which, instead of raw graphql responses, returns
+/// strongly typed structures with already parsed hashes, dates and values
+#[derive(Clone)]
+pub struct TypedExplorer {
+    explorer: Explorer,
+}
+
+impl From<Explorer> for TypedExplorer {
+    fn from(explorer: Explorer) -> Self {
+        Self { explorer }
+    }
+}
+
+impl TypedExplorer {
+    pub fn explorer(&self) -> &Explorer {
+        &self.explorer
+    }
+
+    fn query<V: serde::Serialize, T: DeserializeOwned>(
+        &self,
+        query: QueryBody<V>,
+    ) -> Result<T, ExplorerError> {
+        let response: Response<T> = self.explorer.run(query)?.json()?;
+        if let Some(errors) = response.errors {
+            if !errors.is_empty() {
+                return Err(ExplorerError::GraphQlErrors(
+                    errors.into_iter().map(|error| error.message).collect(),
+                ));
+            }
+        }
+        response.data.ok_or(ExplorerError::MissingData)
+    }
+
+    /// last `limit` blocks of the tip branch
+    pub fn blocks(&self, limit: i64) -> Result<Vec<ExplorerBlock>, ExplorerError> {
+        let data: TipData<BlocksData> =
+            self.query(AllBlocks::build_query(all_blocks::Variables {
+                last: limit,
+            }))?;
+        Ok(data.tip.blocks.nodes())
+    }
+
+    pub fn blocks_at_chain_length(&self, length: u32) -> Result<Vec<Hash>, ExplorerError> {
+        let data: BlocksByChainLengthData = self.query(BlocksByChainLength::build_query(
+            blocks_by_chain_length::Variables {
+                length: length.to_string(),
+            },
+        ))?;
+        Ok(data
+            .blocks_by_chain_length
+            .into_iter()
+            .map(|block| block.id)
+            .collect())
+    }
+
+    pub fn transaction(&self, id: Hash) -> Result<Hash, ExplorerError> {
+        let data: TransactionData =
+            self.query(TransactionById::build_query(transaction_by_id::Variables {
+                id: id.to_string(),
+            }))?;
+        Ok(data.transaction.id)
+    }
+
+    /// address details together with its last transactions
+    pub fn address<S: Into<String>>(&self, bech32: S) -> Result<ExplorerAddress, ExplorerError> {
+        let data: AddressData = self.query(Address::build_query(address::Variables {
+            bech32: bech32.into(),
+        }))?;
+        Ok(ExplorerAddress {
+            id: data.address.id,
+            delegation: data.address.delegation.map(|pool| pool.id),
+            transactions: data.tip.transactions_by_address.nodes(),
+        })
+    }
+
+    pub fn transactions_by_address<S: Into<String>>(
+        &self,
+        bech32: S,
+    ) -> Result<Vec<ExplorerTransaction>, ExplorerError> {
+        self.address(bech32).map(|address| address.transactions)
+    }
+
+    pub fn stake_pools(&self, limit: i64) -> Result<Vec<Hash>, ExplorerError> {
+        let data: TipData<AllStakePoolsData> =
+            self.query(AllStakePools::build_query(all_stake_pools::Variables {
+                first: limit,
+            }))?;
+        Ok(data
+            .tip
+            .all_stake_pools
+            .nodes()
+            .into_iter()
+            .map(|pool: IdNode| pool.id)
+            .collect())
+    }
+
+    pub fn stake_pool(&self, id: Hash, limit: i64) -> Result<ExplorerStakePool, ExplorerError> {
+        let data: StakePoolData = self.query(StakePool::build_query(stake_pool::Variables {
+            id: id.to_string(),
+            first: limit,
+        }))?;
+        let stake_pool = data.stake_pool;
+        Ok(ExplorerStakePool {
+            id: stake_pool.id,
+            registered: stake_pool.registration.is_some(),
+            retired: stake_pool.retirement.is_some(),
+            blocks: stake_pool
+                .blocks
+                .nodes()
+                .into_iter()
+                .map(|block: IdNode| block.id)
+                .collect(),
+        })
+    }
+
+    pub fn vote_plans(&self, limit: i64) -> Result<Vec<ExplorerVotePlan>, ExplorerError> {
+        let data: TipData<AllVotePlansData> =
+            self.query(AllVotePlans::build_query(all_vote_plans::Variables {
+                first: limit,
+            }))?;
+        Ok(data
+            .tip
+            .all_vote_plans
+            .nodes()
+            .into_iter()
+            .map(Into::into)
+            .collect())
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExplorerBlock {
+    #[serde(deserialize_with = "from_str")]
+    pub id: Hash,
+    #[serde(deserialize_with = "block_date")]
+    pub date: BlockDate,
+    #[serde(deserialize_with = "previous_block")]
+    pub previous_block: Hash,
+    #[serde(deserialize_with = "from_str")]
+    pub chain_length: u32,
+    #[serde(deserialize_with = "from_str")]
+    pub total_input: u64,
+    #[serde(deserialize_with = "from_str")]
+    pub total_output: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct ExplorerAddress {
+    pub id: String,
+    /// id of the stake pool the address delegates to
+    pub delegation: Option<String>,
+    pub transactions: Vec<ExplorerTransaction>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ExplorerTransaction {
+    #[serde(deserialize_with = "from_str")]
+    pub id: Hash,
+    #[serde(rename = "blocks", deserialize_with = "block_dates")]
+    pub block_dates: Vec<BlockDate>,
+    pub inputs: Vec<ExplorerTransactionIo>,
+    pub outputs: Vec<ExplorerTransactionIo>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ExplorerTransactionIo {
+    #[serde(deserialize_with = "address_id")]
+    pub address: String,
+    #[serde(deserialize_with = "from_str")]
+    pub amount: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct ExplorerStakePool {
+    pub id: Hash,
+    pub registered: bool,
+    pub retired: bool,
+    /// ids of the blocks produced by stake pool
+    pub blocks: Vec<Hash>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum ExplorerPayloadType {
+    Public,
+    Private,
+}
+
+#[derive(Debug, Clone)]
+pub struct ExplorerVotePlan {
+    pub id: String,
+    pub vote_start: BlockDate,
+    pub vote_end: BlockDate,
+    pub committee_end: BlockDate,
+    pub payload_type: ExplorerPayloadType,
+    pub proposals: Vec<ExplorerProposal>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ExplorerProposal {
+    pub options: Range<u8>,
+    pub votes: Vec<ExplorerVote>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ExplorerVote {
+    pub address: String,
+    /// choice is only revealed for public vote plans
+    pub choice: Option<u8>,
+}
+
+// raw shapes of the graphql responses, converted into the public structures above
+
+#[derive(Deserialize)]
+struct TipData<T> {
+    tip: T,
+}
+
+#[derive(Deserialize)]
+struct Connection<T> {
+    edges: Option<Vec<Option<Edge<T>>>>,
+}
+
+#[derive(Deserialize)]
+struct Edge<T> {
+    node: T,
+}
+
+impl<T> Connection<T> {
+    fn nodes(self) -> Vec<T> {
+        self.edges
+            .unwrap_or_default()
+            .into_iter()
+            .flatten()
+            .map(|edge| edge.node)
+            .collect()
+    }
+}
+
+#[derive(Deserialize)]
+struct IdNode {
+    #[serde(deserialize_with = "from_str")]
+    id: Hash,
+}
+
+#[derive(Deserialize)]
+struct StringIdNode {
+    id: String,
+}
+
+#[derive(Deserialize)]
+struct BlocksData {
+    blocks: Connection<ExplorerBlock>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BlocksByChainLengthData {
+    blocks_by_chain_length: Vec<IdNode>,
+}
+
+#[derive(Deserialize)]
+struct TransactionData {
+    transaction: IdNode,
+}
+
+#[derive(Deserialize)]
+struct AddressData {
+    address: RawAddress,
+    tip: AddressTransactions,
+}
+
+#[derive(Deserialize)]
+struct RawAddress {
+    id: String,
+    delegation: Option<StringIdNode>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AddressTransactions {
+    transactions_by_address: Connection<ExplorerTransaction>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AllStakePoolsData {
+    all_stake_pools: Connection<IdNode>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct StakePoolData {
+    stake_pool: RawStakePool,
+}
+
+#[derive(Deserialize)]
+struct RawStakePool {
+    #[serde(deserialize_with = "from_str")]
+    id: Hash,
+    registration: Option<serde_json::Value>,
+    retirement: Option<serde_json::Value>,
+    blocks: Connection<IdNode>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AllVotePlansData {
+    all_vote_plans: Connection<RawVotePlan>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawVotePlan {
+    id: String,
+    #[serde(deserialize_with = "block_date")]
+    vote_start: BlockDate,
+    #[serde(deserialize_with = "block_date")]
+    vote_end: BlockDate,
+    #[serde(deserialize_with = "block_date")]
+    committee_end: BlockDate,
+    payload_type: ExplorerPayloadType,
+    proposals: Vec<RawProposal>,
+}
+
+#[derive(Deserialize)]
+struct RawProposal {
+    options: RawOptions,
+    votes: Connection<RawVote>,
+}
+
+#[derive(Deserialize)]
+struct RawOptions {
+    start: u8,
+    end: u8,
+}
+
+#[derive(Deserialize)]
+struct RawVote {
+    address: StringIdNode,
+    payload: RawVotePayload,
+}
+
+#[derive(Deserialize)]
+struct RawVotePayload {
+    choice: Option<u8>,
+}
+
+impl From<RawVotePlan> for ExplorerVotePlan {
+    fn from(vote_plan: RawVotePlan) -> Self {
+        Self {
+            id: vote_plan.id,
+            vote_start: vote_plan.vote_start,
+            vote_end: vote_plan.vote_end,
+            committee_end: vote_plan.committee_end,
+            payload_type: vote_plan.payload_type,
+            proposals: vote_plan
+                .proposals
+                .into_iter()
+                .map(|proposal| ExplorerProposal {
+                    options: proposal.options.start..proposal.options.end,
+                    votes: proposal
+                        .votes
+                        .nodes()
+                        .into_iter()
+                        .map(|vote| ExplorerVote {
+                            address: vote.address.id,
+                            choice: vote.payload.choice,
+                        })
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct RawBlockDate {
+    epoch: RawEpoch,
+    slot: String,
+}
+
+#[derive(Deserialize)]
+struct RawEpoch {
+    id: String,
+}
+
+impl RawBlockDate {
+    fn into_block_date<E: serde::de::Error>(self) -> Result<BlockDate, E> {
+        Ok(LibBlockDate {
+            epoch: self.epoch.id.parse().map_err(E::custom)?,
+            slot_id: self.slot.parse().map_err(E::custom)?,
+        }
+        .into())
+    }
+}
+
+fn from_str<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+    T::Err: std::fmt::Display,
+{
+    let value = String::deserialize(deserializer)?;
+    value.parse().map_err(serde::de::Error::custom)
+}
+
+fn block_date<'de, D: Deserializer<'de>>(deserializer: D) -> Result<BlockDate, D::Error> {
+    RawBlockDate::deserialize(deserializer)?.into_block_date()
+}
+
+fn block_dates<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<BlockDate>, D::Error> {
+    #[derive(Deserialize)]
+    struct Block {
+        date: RawBlockDate,
+    }
+
+    Vec::<Block>::deserialize(deserializer)?
+        .into_iter()
+        .map(|block| block.date.into_block_date())
+        .collect()
+}
+
+fn previous_block<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Hash, D::Error> {
+    IdNode::deserialize(deserializer).map(|block| block.id)
+}
+
+fn address_id<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    StringIdNode::deserialize(deserializer).map(|address| address.id)
+}
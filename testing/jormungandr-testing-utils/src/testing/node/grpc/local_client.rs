@@ -0,0 +1,121 @@
+use super::client::MockClientError;
+use super::local_proto::{
+    local_node_client::LocalNodeClient, PostFragmentsRequest, RejectionReason,
+};
+use super::read_into;
+
+use chain_core::property::Serialize;
+use chain_impl_mockchain::fragment::Fragment;
+use jormungandr_lib::interfaces::{
+    FragmentRejectionReason, FragmentsProcessingSummary, RejectedFragmentInfo,
+};
+use std::fmt;
+use std::net::SocketAddr;
+use tokio::runtime::{Builder, Runtime};
+use tonic::transport::Channel;
+
+/// Client of the gRPC services reserved to the clients on the host of the
+/// node, served on the local listen port of the node.
+pub struct JormungandrLocalClient {
+    addr: SocketAddr,
+    inner_client: LocalNodeClient<Channel>,
+    rt: Runtime,
+}
+
+impl Clone for JormungandrLocalClient {
+    fn clone(&self) -> Self {
+        JormungandrLocalClient::new(self.addr)
+    }
+}
+
+impl fmt::Debug for JormungandrLocalClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JormungandrLocalClient")
+            .field("host", &self.addr)
+            .finish()
+    }
+}
+
+impl JormungandrLocalClient {
+    pub fn new(addr: SocketAddr) -> Self {
+        let rt = Builder::new_current_thread().enable_all().build().unwrap();
+        let inner_client = rt.block_on(async {
+            LocalNodeClient::new(
+                tonic::transport::Endpoint::from_shared(format!("http://{}", addr))
+                    .unwrap()
+                    .connect_lazy()
+                    .unwrap(),
+            )
+        });
+
+        Self {
+            addr,
+            inner_client,
+            rt,
+        }
+    }
+
+    pub fn post_fragments(
+        &self,
+        fragments: &[Fragment],
+        fail_fast: bool,
+    ) -> Result<FragmentsProcessingSummary, MockClientError> {
+        let mut client = self.inner_client.clone();
+        let request = tonic::Request::new(PostFragmentsRequest {
+            fragments: fragments
+                .iter()
+                .map(|fragment| fragment.serialize_as_vec().unwrap())
+                .collect(),
+            fail_fast,
+        });
+        let response = self
+            .rt
+            .block_on(client.post_fragments(request))
+            .map_err(|err| MockClientError::InvalidRequest(err.message().to_string()))?
+            .into_inner();
+
+        let rejected = response
+            .rejected
+            .into_iter()
+            .map(|rejected| {
+                let reason = match RejectionReason::from_i32(rejected.reason) {
+                    Some(RejectionReason::FragmentAlreadyInLog) => {
+                        FragmentRejectionReason::FragmentAlreadyInLog
+                    }
+                    Some(RejectionReason::FragmentInvalid) => {
+                        FragmentRejectionReason::FragmentInvalid
+                    }
+                    Some(RejectionReason::PreviousFragmentInvalid) => {
+                        FragmentRejectionReason::PreviousFragmentInvalid
+                    }
+                    Some(RejectionReason::PoolOverflow) => FragmentRejectionReason::PoolOverflow {
+                        pool_number: rejected.pool_number as usize,
+                    },
+                    Some(RejectionReason::FragmentExpired) => {
+                        FragmentRejectionReason::FragmentExpired
+                    }
+                    Some(RejectionReason::FragmentExpiryTooFar) => {
+                        FragmentRejectionReason::FragmentExpiryTooFar
+                    }
+                    Some(RejectionReason::FragmentExpiresTooSoon) => {
+                        FragmentRejectionReason::FragmentExpiresTooSoon
+                    }
+                    None => {
+                        return Err(MockClientError::InvalidRequest(format!(
+                            "unknown rejection reason {}",
+                            rejected.reason
+                        )))
+                    }
+                };
+                Ok(RejectedFragmentInfo {
+                    id: read_into(&rejected.id),
+                    reason,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(FragmentsProcessingSummary {
+            accepted: response.accepted.iter().map(|id| read_into(id)).collect(),
+            rejected,
+        })
+    }
+}
@@ -1,13 +1,19 @@
 pub mod client;
+pub mod local_client;
 pub mod server;
 
 pub use client::JormungandrClient;
+pub use local_client::JormungandrLocalClient;
 pub use server::JormungandrServerImpl;
 
 mod proto {
     tonic::include_proto!("iohk.chain.node"); // The string specified here must match the proto package name
 }
 
+mod local_proto {
+    tonic::include_proto!("iohk.jormungandr.local");
+}
+
 use chain_core::mempack::{ReadBuf, Readable};
 
 pub fn read_into<T: Readable>(bytes: &[u8]) -> T {
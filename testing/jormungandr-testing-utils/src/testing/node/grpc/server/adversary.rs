@@ -0,0 +1,433 @@
+use super::{
+    builder::start_thread, Block, BlockEvent, BlockIds, ClientAuthRequest, ClientAuthResponse,
+    Fragment, FragmentIds, Gossip, HandshakeRequest, HandshakeResponse, Header, MethodType,
+    MockController, MockExitCode, MockServerData, MockVerifier, Node, NodeServer, PeersRequest,
+    PeersResponse, ProtocolVersion, PullBlocksRequest, PullBlocksToTipRequest, PullHeadersRequest,
+    PushHeadersResponse, TipRequest, TipResponse, UploadBlocksResponse,
+};
+use crate::testing::node::grpc::proto::block_event::Item as BlockEventItem;
+use chain_core::property::Serialize;
+use chain_impl_mockchain::{
+    block::{Block as LibBlock, BlockDate},
+    key::Hash,
+    testing::{GenesisPraosBlockBuilder, StakePoolBuilder, TestGen},
+};
+use chain_time::{Epoch, TimeEra};
+use jormungandr_lib::interfaces::TrustedPeer;
+use rand::{Rng, RngCore};
+use std::net::SocketAddr;
+use std::sync::{Arc, RwLock};
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::{Request, Response, Status};
+use tracing::info;
+
+const ADVERSARY_CHAIN_LENGTH: u32 = 10;
+const SLOTS_PER_EPOCH: u32 = 30;
+
+/// Misbehaviour of the adversary node towards the peers connected to it
+#[derive(Clone, Debug, PartialEq)]
+pub enum AdversaryBehaviour {
+    /// serves blocks which are properly chained, but their content is corrupted
+    InvalidBlocks,
+    /// serves headers which do not chain up to each other nor to the announced tip
+    ContradictoryHeaders,
+    /// serves random bytes instead of gossip about other nodes
+    GarbageGossip,
+}
+
+/// Node which speaks gRPC node protocol but serves invalid data to anyone
+/// who connects to it. Handshake is performed correctly, so honest nodes
+/// will try to exchange blocks/gossip with it.
+pub struct AdversaryNode {
+    controller: MockController,
+    behaviour: AdversaryBehaviour,
+    block_ids: Vec<Hash>,
+}
+
+impl AdversaryNode {
+    pub fn behaviour(&self) -> &AdversaryBehaviour {
+        &self.behaviour
+    }
+
+    /// Ids of the blocks which adversary is trying to push to its peers
+    pub fn block_ids(&self) -> &[Hash] {
+        &self.block_ids
+    }
+
+    pub fn address(&self) -> String {
+        self.controller.address()
+    }
+
+    pub fn to_trusted_peer(&self) -> TrustedPeer {
+        TrustedPeer {
+            address: format!("/ip4/127.0.0.1/tcp/{}", self.controller.port())
+                .parse()
+                .unwrap(),
+            id: None,
+        }
+    }
+
+    pub fn finish_and_verify_that<F: 'static + std::marker::Send>(
+        self,
+        verify_func: F,
+    ) -> MockExitCode
+    where
+        F: Fn(&MockVerifier) -> bool,
+    {
+        self.controller.finish_and_verify_that(verify_func)
+    }
+
+    pub fn stop(self) {
+        self.controller.stop()
+    }
+}
+
+pub struct AdversaryNodeBuilder {
+    port: u16,
+    genesis_hash: Hash,
+    protocol_version: ProtocolVersion,
+    behaviour: AdversaryBehaviour,
+}
+
+impl AdversaryNodeBuilder {
+    pub fn new(behaviour: AdversaryBehaviour) -> Self {
+        Self {
+            port: 9999,
+            genesis_hash: TestGen::hash(),
+            protocol_version: ProtocolVersion::GenesisPraos,
+            behaviour,
+        }
+    }
+
+    pub fn with_port(&mut self, port: u16) -> &mut Self {
+        self.port = port;
+        self
+    }
+
+    pub fn with_genesis_hash(&mut self, hash: Hash) -> &mut Self {
+        self.genesis_hash = hash;
+        self
+    }
+
+    pub fn with_protocol_version(&mut self, protocol_version: ProtocolVersion) -> &mut Self {
+        self.protocol_version = protocol_version;
+        self
+    }
+
+    pub fn build(&self) -> AdversaryNode {
+        let chain = adversary_chain(&self.genesis_hash, ADVERSARY_CHAIN_LENGTH);
+        let data = MockServerData::new(
+            self.genesis_hash,
+            chain.last().unwrap().header.clone(),
+            self.protocol_version.clone(),
+            format!("127.0.0.1:{}", self.port)
+                .parse::<SocketAddr>()
+                .unwrap(),
+        );
+        let block_ids = chain.iter().map(|block| block.header.id()).collect();
+        let behaviour = self.behaviour.clone();
+        let controller = start_thread(Arc::new(RwLock::new(data)), move |data| {
+            NodeServer::new(AdversaryServerImpl::new(data, behaviour, chain))
+        });
+        AdversaryNode {
+            controller,
+            behaviour: self.behaviour.clone(),
+            block_ids,
+        }
+    }
+}
+
+fn adversary_chain(genesis_hash: &Hash, length: u32) -> Vec<LibBlock> {
+    let stake_pool = StakePoolBuilder::new().build();
+    let time_era = TimeEra::new(0u64.into(), Epoch(0u32), SLOTS_PER_EPOCH);
+
+    let mut parent_id = *genesis_hash;
+    (1..=length)
+        .map(|chain_length| {
+            let block = GenesisPraosBlockBuilder::new()
+                .with_parent_id(parent_id)
+                .with_date(BlockDate {
+                    epoch: chain_length / SLOTS_PER_EPOCH,
+                    slot_id: chain_length % SLOTS_PER_EPOCH,
+                })
+                .with_chain_length(chain_length.into())
+                .build(&stake_pool, &time_era);
+            parent_id = block.header.id();
+            block
+        })
+        .collect()
+}
+
+fn garbage(len: usize) -> Vec<u8> {
+    let mut bytes = vec![0u8; len];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    bytes
+}
+
+pub struct AdversaryServerImpl {
+    data: Arc<RwLock<MockServerData>>,
+    behaviour: AdversaryBehaviour,
+    chain: Vec<LibBlock>,
+}
+
+impl AdversaryServerImpl {
+    pub fn new(
+        data: Arc<RwLock<MockServerData>>,
+        behaviour: AdversaryBehaviour,
+        chain: Vec<LibBlock>,
+    ) -> Self {
+        info!(
+            method = %MethodType::Init.to_string(),
+            "adversary node ({:?}) started on {}", behaviour, data.read().unwrap().profile().address()
+        );
+        Self {
+            data,
+            behaviour,
+            chain,
+        }
+    }
+
+    fn blocks(&self) -> Vec<Block> {
+        self.chain
+            .iter()
+            .map(|block| {
+                let mut content = block.serialize_as_vec().unwrap();
+                if self.behaviour == AdversaryBehaviour::InvalidBlocks {
+                    // keep the header intact so the block looks like the announced one,
+                    // but scramble everything after it
+                    let header_len = block.header.serialize_as_vec().unwrap().len();
+                    let len = content.len();
+                    if len > header_len {
+                        content[header_len..].copy_from_slice(&garbage(len - header_len));
+                    } else {
+                        content.extend(garbage(64));
+                    }
+                }
+                Block { content }
+            })
+            .collect()
+    }
+
+    fn headers(&self) -> Vec<Header> {
+        match self.behaviour {
+            AdversaryBehaviour::ContradictoryHeaders => {
+                // headers of an unrelated chain in reversed order, so none of them
+                // can be linked to each other nor to the announced tip
+                adversary_chain(&TestGen::hash(), self.chain.len() as u32)
+                    .into_iter()
+                    .rev()
+                    .map(|block| Header {
+                        content: block.header.serialize_as_vec().unwrap(),
+                    })
+                    .collect()
+            }
+            _ => self
+                .chain
+                .iter()
+                .map(|block| Header {
+                    content: block.header.serialize_as_vec().unwrap(),
+                })
+                .collect(),
+        }
+    }
+
+    fn gossip(&self) -> Vec<Vec<u8>> {
+        use bincode::Options;
+        match self.behaviour {
+            AdversaryBehaviour::GarbageGossip => {
+                let count = rand::thread_rng().gen_range(1..10);
+                (0..count)
+                    .map(|_| {
+                        let len = rand::thread_rng().gen_range(1..512);
+                        garbage(len)
+                    })
+                    .collect()
+            }
+            _ => {
+                let data = self.data.read().unwrap();
+                let mut self_gossip = Vec::new();
+                bincode::options()
+                    .with_limit(512)
+                    .serialize_into(&mut self_gossip, data.profile().gossip().as_ref())
+                    .unwrap();
+                vec![self_gossip]
+            }
+        }
+    }
+}
+
+fn stream_of<T: Send + 'static>(items: Vec<T>) -> ReceiverStream<Result<T, Status>> {
+    let (tx, rx) = mpsc::channel(items.len().max(1));
+    for item in items {
+        tx.try_send(Ok(item)).unwrap();
+    }
+    ReceiverStream::new(rx)
+}
+
+#[tonic::async_trait]
+impl Node for AdversaryServerImpl {
+    type PullBlocksStream = ReceiverStream<Result<Block, Status>>;
+    type PullBlocksToTipStream = ReceiverStream<Result<Block, Status>>;
+    type GetBlocksStream = ReceiverStream<Result<Block, Status>>;
+    type PullHeadersStream = ReceiverStream<Result<Header, Status>>;
+    type GetHeadersStream = ReceiverStream<Result<Header, Status>>;
+    type GetFragmentsStream = ReceiverStream<Result<Fragment, Status>>;
+    type BlockSubscriptionStream = ReceiverStream<Result<BlockEvent, Status>>;
+    type FragmentSubscriptionStream = ReceiverStream<Result<Fragment, Status>>;
+    type GossipSubscriptionStream = ReceiverStream<Result<Gossip, Status>>;
+
+    async fn handshake(
+        &self,
+        request: Request<HandshakeRequest>,
+    ) -> Result<Response<HandshakeResponse>, Status> {
+        info!(method = %MethodType::Handshake, "Handshake method received",);
+
+        let request = request.into_inner();
+        let mut data = self.data.write().unwrap();
+        let signature = data.node_signature(&request.nonce);
+        let nonce = data.generate_auth_nonce().to_vec();
+
+        Ok(Response::new(HandshakeResponse {
+            version: data.protocol().clone() as u32,
+            block0: data.genesis_hash().as_ref().to_vec(),
+            node_id: data.node_id().to_vec(),
+            signature,
+            nonce,
+        }))
+    }
+
+    async fn client_auth(
+        &self,
+        request: Request<ClientAuthRequest>,
+    ) -> Result<Response<ClientAuthResponse>, Status> {
+        info!(method = %MethodType::ClientAuth, "ClientAuth request received",);
+        let request = request.into_inner();
+        let data = self.data.read().unwrap();
+        if !data.validate_peer_node_id(&request.node_id, &request.signature) {
+            return Err(Status::invalid_argument("invalid node ID or signature"));
+        }
+        Ok(Response::new(ClientAuthResponse {}))
+    }
+
+    async fn tip(&self, _request: Request<TipRequest>) -> Result<Response<TipResponse>, Status> {
+        info!(method = %MethodType::Tip, "Tip request received");
+        Ok(Response::new(TipResponse {
+            block_header: self.data.read().unwrap().tip().to_raw().to_vec(),
+        }))
+    }
+
+    async fn peers(
+        &self,
+        _request: Request<PeersRequest>,
+    ) -> Result<Response<PeersResponse>, Status> {
+        info!(method = %MethodType::GetPeers, "Get peers request received");
+        Ok(Response::new(PeersResponse {
+            peers: self.gossip(),
+        }))
+    }
+
+    async fn get_blocks(
+        &self,
+        _request: Request<BlockIds>,
+    ) -> Result<Response<Self::GetBlocksStream>, Status> {
+        info!(method = %MethodType::GetBlocks, "Get blocks request received");
+        Ok(Response::new(stream_of(self.blocks())))
+    }
+
+    async fn get_headers(
+        &self,
+        _request: Request<BlockIds>,
+    ) -> Result<Response<Self::GetHeadersStream>, Status> {
+        info!(method = %MethodType::GetHeaders, "Get headers request received",);
+        Ok(Response::new(stream_of(self.headers())))
+    }
+
+    async fn get_fragments(
+        &self,
+        _request: Request<FragmentIds>,
+    ) -> Result<Response<Self::GetFragmentsStream>, Status> {
+        info!(method = %MethodType::GetFragments, "Get fragments request received",);
+        let fragments = (0..10)
+            .map(|_| Fragment {
+                content: garbage(128),
+            })
+            .collect();
+        Ok(Response::new(stream_of(fragments)))
+    }
+
+    async fn pull_headers(
+        &self,
+        _request: Request<PullHeadersRequest>,
+    ) -> Result<Response<Self::PullHeadersStream>, Status> {
+        info!(method = %MethodType::PullHeaders, "Pull Headers request received",);
+        Ok(Response::new(stream_of(self.headers())))
+    }
+
+    async fn pull_blocks(
+        &self,
+        _request: Request<PullBlocksRequest>,
+    ) -> Result<Response<Self::PullBlocksStream>, Status> {
+        info!(method = %MethodType::PullBlocks, "PullBlocks request received",);
+        Ok(Response::new(stream_of(self.blocks())))
+    }
+
+    async fn pull_blocks_to_tip(
+        &self,
+        _request: Request<PullBlocksToTipRequest>,
+    ) -> Result<Response<Self::PullBlocksToTipStream>, Status> {
+        info!(method = %MethodType::PullBlocksToTip, "PullBlocksToTip request received",);
+        Ok(Response::new(stream_of(self.blocks())))
+    }
+
+    async fn push_headers(
+        &self,
+        _request: Request<tonic::Streaming<Header>>,
+    ) -> Result<Response<PushHeadersResponse>, Status> {
+        info!(method = %MethodType::PushHeaders, "Push headers method received",);
+        Ok(Response::new(PushHeadersResponse::default()))
+    }
+
+    async fn upload_blocks(
+        &self,
+        _request: Request<tonic::Streaming<Block>>,
+    ) -> Result<Response<UploadBlocksResponse>, Status> {
+        info!(method = %MethodType::UploadBlocks, "Upload blocks method received",);
+        Ok(Response::new(UploadBlocksResponse::default()))
+    }
+
+    async fn block_subscription(
+        &self,
+        _request: Request<tonic::Streaming<Header>>,
+    ) -> Result<Response<Self::BlockSubscriptionStream>, Status> {
+        info!(method = %MethodType::BlockSubscription, "Block subscription event received",);
+        // announce our tip straight away so the peer tries to fetch our chain
+        let tip = self.chain.last().unwrap();
+        let announce = BlockEvent {
+            item: Some(BlockEventItem::Announce(Header {
+                content: tip.header.serialize_as_vec().unwrap(),
+            })),
+        };
+        Ok(Response::new(stream_of(vec![announce])))
+    }
+
+    async fn fragment_subscription(
+        &self,
+        _request: Request<tonic::Streaming<Fragment>>,
+    ) -> Result<Response<Self::FragmentSubscriptionStream>, Status> {
+        info!(method = %MethodType::FragmentSubscription, "Fragment subscription event received",);
+        let (_tx, rx) = mpsc::channel(1);
+        Ok(Response::new(ReceiverStream::new(rx)))
+    }
+
+    async fn gossip_subscription(
+        &self,
+        _request: Request<tonic::Streaming<Gossip>>,
+    ) -> Result<Response<Self::GossipSubscriptionStream>, Status> {
+        info!(method = %MethodType::GossipSubscription, "Gossip subscription event received",);
+        let gossip = vec![Gossip {
+            nodes: self.gossip(),
+        }];
+        Ok(Response::new(stream_of(gossip)))
+    }
+}
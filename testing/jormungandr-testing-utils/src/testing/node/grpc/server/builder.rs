@@ -9,7 +9,7 @@ use std::sync::RwLock;
 use tokio::sync::oneshot;
 use tonic::transport::Server;
 
-use crate::testing::node::grpc::server::NodeServer;
+use crate::testing::node::grpc::server::{Node, NodeServer};
 
 pub struct MockBuilder {
     mock_port: u16,
@@ -69,7 +69,9 @@ impl MockBuilder {
 
     pub fn build(&self) -> MockController {
         let data = self.build_data();
-        start_thread(data)
+        start_thread(data, |data| {
+            NodeServer::new(JormungandrServerImpl::new(data))
+        })
     }
 }
 
@@ -88,7 +90,11 @@ impl Write for ChannelWriter {
     }
 }
 
-fn start_thread(data: Arc<RwLock<MockServerData>>) -> MockController {
+pub(super) fn start_thread<S, F>(data: Arc<RwLock<MockServerData>>, service: F) -> MockController
+where
+    S: Node,
+    F: FnOnce(Arc<RwLock<MockServerData>>) -> NodeServer<S> + Send + 'static,
+{
     let (tx, rx) = sync_channel(100);
     let logger = MockLogger::new(rx);
     let (shutdown_signal, rx) = oneshot::channel::<()>();
@@ -106,9 +112,8 @@ fn start_thread(data: Arc<RwLock<MockServerData>>) -> MockController {
                 .build()
                 .unwrap();
             rt.block_on(async move {
-                let mock = JormungandrServerImpl::new(data_clone);
                 Server::builder()
-                    .add_service(NodeServer::new(mock))
+                    .add_service(service(data_clone))
                     .serve_with_shutdown(addr, rx.map(drop))
                     .await
                     .unwrap();
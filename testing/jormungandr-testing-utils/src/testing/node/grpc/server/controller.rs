@@ -71,6 +71,10 @@ impl MockController {
         self.stop_signal.send(()).unwrap();
     }
 
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
     pub fn address(&self) -> String {
         format!("127.0.0.1:{}", self.port)
     }
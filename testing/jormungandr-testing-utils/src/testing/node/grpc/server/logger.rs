@@ -7,7 +7,7 @@ use std::sync::mpsc::Receiver;
 
 pub struct MockLogger(JormungandrLogger);
 
-#[derive(Serialize, Deserialize, Eq, PartialEq, Debug)]
+#[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Clone, Copy)]
 pub enum MethodType {
     Init,
     Handshake,
@@ -17,12 +17,14 @@ use std::fmt;
 use std::sync::Arc;
 use tracing::info;
 
+mod adversary;
 mod builder;
 mod controller;
 mod data;
 mod logger;
 mod verifier;
 
+pub use adversary::{AdversaryBehaviour, AdversaryNode, AdversaryNodeBuilder};
 pub use builder::MockBuilder;
 pub use controller::MockController;
 pub use data::{header, MockServerData};
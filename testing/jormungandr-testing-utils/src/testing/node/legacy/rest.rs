@@ -1,6 +1,6 @@
 use crate::{
     testing::{
-        node::{RawRest, RestError, RestSettings},
+        node::{RawRest, RestError, RestSettings, RetryPolicy},
         MemPoolCheck,
     },
     wallet::Wallet,
@@ -55,6 +55,11 @@ impl BackwardCompatibleRest {
         self.settings.enable_debug = true;
     }
 
+    pub fn set_retry_policy(&mut self, retry: RetryPolicy) {
+        self.raw.set_retry_policy(retry);
+        self.settings.retry = retry;
+    }
+
     pub fn epoch_reward_history(&self, epoch: u32) -> Result<String, reqwest::Error> {
         let response_text = self.raw().epoch_reward_history(epoch)?.text()?;
         self.print_response_text(&response_text);
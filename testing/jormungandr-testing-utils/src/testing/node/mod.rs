@@ -3,16 +3,21 @@ pub mod configuration;
 pub mod grpc;
 mod legacy;
 mod logger;
+mod proxy;
 mod rest;
 pub mod time;
 mod verifier;
 
 pub mod explorer;
 pub use benchmark::*;
-pub use explorer::{Explorer, ExplorerError};
+pub use explorer::{Explorer, ExplorerError, TypedExplorer};
 pub use legacy::{download_last_n_releases, get_jormungandr_bin, version_0_8_19, Version};
 pub use logger::{JormungandrLogger, Level as LogLevel, LogEntry};
+pub use proxy::NetworkConditionsProxy;
 pub use rest::{
     uri_from_socket_addr, JormungandrRest, RawRest, RestError, RestRequestGen, RestSettings,
+    RetryPolicy,
+};
+pub use verifier::{
+    assert_accepted_rejected, assert_bad_request, JormungandrStateVerifier, PersistenceVerifier,
 };
-pub use verifier::{assert_accepted_rejected, assert_bad_request, JormungandrStateVerifier};
@@ -0,0 +1,152 @@
+use crate::testing::network_builder::NetworkConditions;
+use std::io::{self, Read, Write};
+use std::net::{Shutdown, SocketAddr, TcpListener, TcpStream};
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    mpsc, Arc,
+};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+const READ_TIMEOUT: Duration = Duration::from_millis(100);
+const BUFFER_SIZE: usize = 16 * 1024;
+
+/// Userspace TCP proxy which forwards all traffic to the target address
+/// while applying given network conditions (latency, jitter and packet loss)
+/// in both directions. Unlike tc/netem it does not require root privileges
+/// and affects only the traffic to a single node.
+pub struct NetworkConditionsProxy {
+    address: SocketAddr,
+    target: SocketAddr,
+    stop: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl NetworkConditionsProxy {
+    pub fn start(
+        address: SocketAddr,
+        target: SocketAddr,
+        conditions: NetworkConditions,
+    ) -> io::Result<Self> {
+        let listener = TcpListener::bind(address)?;
+        listener.set_nonblocking(true)?;
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let accept_stop = Arc::clone(&stop);
+        let handle = std::thread::spawn(move || {
+            while !accept_stop.load(Ordering::Relaxed) {
+                match listener.accept() {
+                    Ok((inbound, _)) => {
+                        let stop = Arc::clone(&accept_stop);
+                        std::thread::spawn(move || {
+                            if let Err(err) = proxy_connection(inbound, target, conditions, stop) {
+                                println!("network conditions proxy connection failed: {}", err);
+                            }
+                        });
+                    }
+                    Err(ref err) if err.kind() == io::ErrorKind::WouldBlock => {
+                        std::thread::sleep(POLL_INTERVAL)
+                    }
+                    Err(err) => {
+                        println!("network conditions proxy stopped: {}", err);
+                        break;
+                    }
+                }
+            }
+        });
+
+        Ok(Self {
+            address,
+            target,
+            stop,
+            handle: Some(handle),
+        })
+    }
+
+    pub fn address(&self) -> SocketAddr {
+        self.address
+    }
+
+    pub fn target(&self) -> SocketAddr {
+        self.target
+    }
+}
+
+impl Drop for NetworkConditionsProxy {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+fn proxy_connection(
+    inbound: TcpStream,
+    target: SocketAddr,
+    conditions: NetworkConditions,
+    stop: Arc<AtomicBool>,
+) -> io::Result<()> {
+    inbound.set_nonblocking(false)?;
+    let outbound = TcpStream::connect(target)?;
+
+    forward(
+        inbound.try_clone()?,
+        outbound.try_clone()?,
+        conditions,
+        Arc::clone(&stop),
+    )?;
+    forward(outbound, inbound, conditions, stop)?;
+    Ok(())
+}
+
+/// Moves data from `from` to `to` using two threads: reader stamps every chunk
+/// with its delivery time, while writer holds it back until that time passes.
+/// Delivery times never decrease, so data is not reordered.
+fn forward(
+    mut from: TcpStream,
+    mut to: TcpStream,
+    conditions: NetworkConditions,
+    stop: Arc<AtomicBool>,
+) -> io::Result<()> {
+    from.set_read_timeout(Some(READ_TIMEOUT))?;
+    let (sender, receiver) = mpsc::channel::<(Instant, Vec<u8>)>();
+
+    std::thread::spawn(move || {
+        let mut rng = rand::thread_rng();
+        let mut buffer = vec![0u8; BUFFER_SIZE];
+        let mut last_delivery = Instant::now();
+        while !stop.load(Ordering::Relaxed) {
+            match from.read(&mut buffer) {
+                Ok(0) => break,
+                Ok(size) => {
+                    let delivery =
+                        (Instant::now() + conditions.draw_delay(&mut rng)).max(last_delivery);
+                    last_delivery = delivery;
+                    if sender.send((delivery, buffer[..size].to_vec())).is_err() {
+                        break;
+                    }
+                }
+                Err(ref err)
+                    if err.kind() == io::ErrorKind::WouldBlock
+                        || err.kind() == io::ErrorKind::TimedOut => {}
+                Err(_) => break,
+            }
+        }
+    });
+
+    std::thread::spawn(move || {
+        for (delivery, data) in receiver {
+            let now = Instant::now();
+            if delivery > now {
+                std::thread::sleep(delivery - now);
+            }
+            if to.write_all(&data).is_err() {
+                break;
+            }
+        }
+        let _ = to.shutdown(Shutdown::Write);
+    });
+    Ok(())
+}
@@ -4,7 +4,7 @@ mod settings;
 
 pub use load::RestRequestGen;
 pub use raw::RawRest;
-pub use settings::RestSettings;
+pub use settings::{RestSettings, RetryPolicy};
 
 use crate::{testing::node::legacy, testing::MemPoolCheck, wallet::Wallet};
 use chain_impl_mockchain::fragment::{Fragment, FragmentId};
@@ -12,13 +12,15 @@ use jormungandr_lib::interfaces::{Address, FragmentStatus, VotePlanId};
 use jormungandr_lib::{
     crypto::hash::Hash,
     interfaces::{
-        AccountState, EnclaveLeaderId, EpochRewardsInfo, FragmentLog, LeadershipLog, NodeStatsDto,
-        PeerRecord, PeerStats, SettingsDto, StakeDistributionDto, VotePlanStatus,
+        AccountState, AccountStateAt, BootstrapStatus, ConfigurationSummary, EnclaveLeaderId,
+        EpochRewardsInfo, ExpiredFragment, FragmentConflict, FragmentLog, LeadershipLog,
+        NodeStatsDto, PeerEvent, PeerRecord, PeerStats, SettingsDto, StakeDistributionDto,
+        StakePoolBlock, StakePoolDashboard, VotePlanStatus,
     },
 };
 use std::collections::HashMap;
 use std::io::Read;
-use std::{fs::File, net::SocketAddr, path::Path};
+use std::{fs::File, net::SocketAddr, path::Path, time::Duration};
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -70,6 +72,10 @@ impl JormungandrRest {
         self.inner.enable_logger();
     }
 
+    pub fn set_retry_policy(&mut self, retry: RetryPolicy) {
+        self.inner.set_retry_policy(retry);
+    }
+
     pub fn inner(&self) -> &legacy::BackwardCompatibleRest {
         &self.inner
     }
@@ -146,10 +152,48 @@ impl JormungandrRest {
             .map_err(RestError::CannotDeserialize)
     }
 
+    pub fn account_state_at(
+        &self,
+        wallet: &Wallet,
+        at: Option<&str>,
+    ) -> Result<AccountStateAt, RestError> {
+        serde_json::from_str(&self.raw().account_state_at(wallet, at)?.text()?)
+            .map_err(RestError::CannotDeserialize)
+    }
+
+    pub fn stake_pool_dashboard(&self, pool_id: &str) -> Result<StakePoolDashboard, RestError> {
+        serde_json::from_str(&self.raw().stake_pool_dashboard(pool_id)?.text()?)
+            .map_err(RestError::CannotDeserialize)
+    }
+
+    pub fn stake_pool_blocks(
+        &self,
+        pool_id: &str,
+        epoch: Option<u32>,
+    ) -> Result<Vec<StakePoolBlock>, RestError> {
+        serde_json::from_str(&self.raw().stake_pool_blocks(pool_id, epoch)?.text()?)
+            .map_err(RestError::CannotDeserialize)
+    }
+
     pub fn network_stats(&self) -> Result<Vec<PeerStats>, RestError> {
         serde_json::from_str(&self.inner.network_stats()?).map_err(RestError::CannotDeserialize)
     }
 
+    pub fn bootstrap_status(&self) -> Result<BootstrapStatus, RestError> {
+        serde_json::from_str(&self.raw().bootstrap_status()?.text()?)
+            .map_err(RestError::CannotDeserialize)
+    }
+
+    pub fn configuration_summary(&self) -> Result<ConfigurationSummary, RestError> {
+        serde_json::from_str(&self.raw().configuration_summary()?.text()?)
+            .map_err(RestError::CannotDeserialize)
+    }
+
+    pub fn network_events(&self) -> Result<Vec<PeerEvent>, RestError> {
+        serde_json::from_str(&self.raw().network_events()?.text()?)
+            .map_err(RestError::CannotDeserialize)
+    }
+
     pub fn p2p_quarantined(&self) -> Result<Vec<PeerRecord>, RestError> {
         serde_json::from_str(&self.inner.p2p_quarantined()?).map_err(RestError::CannotDeserialize)
     }
@@ -178,6 +222,12 @@ impl JormungandrRest {
         serde_json::from_str(&self.inner.settings()?).map_err(RestError::CannotDeserialize)
     }
 
+    /// The settings that applied in `epoch`.
+    pub fn settings_at(&self, epoch: u32) -> Result<SettingsDto, RestError> {
+        serde_json::from_str(&self.raw().settings_at(epoch)?.text()?)
+            .map_err(RestError::CannotDeserialize)
+    }
+
     pub fn leaders_log(&self) -> Result<Vec<LeadershipLog>, RestError> {
         serde_json::from_str(&self.inner.leaders_log()?).map_err(RestError::CannotDeserialize)
     }
@@ -209,6 +259,16 @@ impl JormungandrRest {
         self.inner.fragments_logs()
     }
 
+    pub fn expired_fragments(&self) -> Result<Vec<ExpiredFragment>, RestError> {
+        serde_json::from_str(&self.raw().fragments_expired()?.text()?)
+            .map_err(RestError::CannotDeserialize)
+    }
+
+    pub fn fragment_conflicts(&self) -> Result<Vec<FragmentConflict>, RestError> {
+        serde_json::from_str(&self.raw().fragments_conflicts()?.text()?)
+            .map_err(RestError::CannotDeserialize)
+    }
+
     pub fn fragments_statuses(
         &self,
         ids: Vec<String>,
@@ -226,6 +286,21 @@ impl JormungandrRest {
             .map_err(Into::into)
     }
 
+    /// Moves the clock of the node forward, the node must run with a
+    /// simulated clock.
+    pub fn step_clock(&self, duration: Duration) -> Result<(), RestError> {
+        let response = self.raw().step_clock(duration)?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(RestError::NonSuccessErrorCode {
+                status,
+                response: response.text()?,
+                checks: Vec::new(),
+            });
+        }
+        Ok(())
+    }
+
     pub fn vote_plan_statuses(&self) -> Result<Vec<VotePlanStatus>, RestError> {
         serde_json::from_str(&self.inner.vote_plan_statuses()?)
             .map_err(RestError::CannotDeserialize)
@@ -1,4 +1,4 @@
-use super::RestSettings;
+use super::{RestSettings, RetryPolicy};
 use crate::{testing::node::RestError, wallet::Wallet};
 use bech32::FromBase32;
 use chain_core::property::Serialize;
@@ -8,10 +8,10 @@ use chain_impl_mockchain::fragment::Fragment;
 use jormungandr_lib::interfaces::{Address, FragmentsBatch, VotePlanId};
 use jortestkit::process::Wait;
 use reqwest::{
-    blocking::{Client, Response},
+    blocking::{Client, RequestBuilder, Response},
     header::{HeaderMap, HeaderValue, CONTENT_TYPE},
 };
-use std::fmt;
+use std::{fmt, time::Duration};
 
 enum ApiVersion {
     V0,
@@ -35,6 +35,7 @@ pub struct RawRest {
     uri: String,
     client: Client,
     logging_enabled: bool,
+    retry: RetryPolicy,
 }
 
 impl RawRest {
@@ -62,6 +63,7 @@ impl RawRest {
             uri,
             client,
             logging_enabled: settings.enable_debug,
+            retry: settings.retry,
         }
     }
 
@@ -73,6 +75,10 @@ impl RawRest {
         self.logging_enabled = false;
     }
 
+    pub fn set_retry_policy(&mut self, retry: RetryPolicy) {
+        self.retry = retry;
+    }
+
     pub fn epoch_reward_history(&self, epoch: u32) -> Result<Response, reqwest::Error> {
         let request = format!("rewards/epoch/{}", epoch);
         self.get(&request)
@@ -92,7 +98,33 @@ impl RawRest {
     fn get(&self, path: &str) -> Result<reqwest::blocking::Response, reqwest::Error> {
         let request = self.path(ApiVersion::V0, path);
         self.print_request_path(&request);
-        self.client.get(request).send()
+        self.send_idempotent(self.client.get(request))
+    }
+
+    /// Sends the request again, as configured by the retry policy, when the
+    /// connection to the node fails. Only the requests which can safely be
+    /// sent more than once are retried.
+    fn send_idempotent(&self, request: RequestBuilder) -> Result<Response, reqwest::Error> {
+        let mut retry = 0;
+        loop {
+            let attempt = match request.try_clone() {
+                Some(attempt) => attempt,
+                None => return request.send(),
+            };
+            match attempt.send() {
+                Err(err)
+                    if (err.is_connect() || err.is_timeout()) && retry < self.retry.max_retries =>
+                {
+                    let backoff = self.retry.backoff(retry);
+                    if self.logging_enabled {
+                        println!("Request failed ({}), retrying in {:?}", err, backoff);
+                    }
+                    std::thread::sleep(backoff);
+                    retry += 1;
+                }
+                result => return result,
+            }
+        }
     }
 
     fn path(&self, api_version: ApiVersion, path: &str) -> String {
@@ -112,6 +144,27 @@ impl RawRest {
         self.get(&format!("account/{}", key))
     }
 
+    /// State of the account right after the block given by `at`, a block hash
+    /// or an epoch number, or at the tip if not given.
+    pub fn account_state_at(
+        &self,
+        wallet: &Wallet,
+        at: Option<&str>,
+    ) -> Result<Response, reqwest::Error> {
+        let key = hex::encode(
+            Self::try_from_str(&wallet.identifier().to_bech32_str())
+                .as_ref()
+                .as_ref(),
+        );
+        let mut request = self
+            .client
+            .get(&self.path(ApiVersion::V1, &format!("accounts/{}/state", key)));
+        if let Some(at) = at {
+            request = request.query(&[("at", at)]);
+        }
+        self.send_idempotent(request)
+    }
+
     fn try_from_str(src: &str) -> account::Identifier {
         let (_, data) = bech32::decode(src).unwrap();
         let dat = Vec::from_base32(&data).unwrap();
@@ -123,6 +176,28 @@ impl RawRest {
         self.get("stake_pools")
     }
 
+    pub fn stake_pool_dashboard(&self, pool_id: &str) -> Result<Response, reqwest::Error> {
+        let request = self.path(
+            ApiVersion::V1,
+            &format!("stake-pools/{}/dashboard", pool_id),
+        );
+        self.send_idempotent(self.client.get(request))
+    }
+
+    pub fn stake_pool_blocks(
+        &self,
+        pool_id: &str,
+        epoch: Option<u32>,
+    ) -> Result<Response, reqwest::Error> {
+        let mut request = self
+            .client
+            .get(&self.path(ApiVersion::V1, &format!("stake-pools/{}/blocks", pool_id)));
+        if let Some(epoch) = epoch {
+            request = request.query(&[("epoch", epoch)]);
+        }
+        self.send_idempotent(request)
+    }
+
     pub fn stake_distribution_at(&self, epoch: u32) -> Result<Response, reqwest::Error> {
         let request = format!("stake/{}", epoch);
         self.get(&request)
@@ -136,6 +211,49 @@ impl RawRest {
         self.get("network/stats")
     }
 
+    pub fn bootstrap_status(&self) -> Result<Response, reqwest::Error> {
+        let request = self.path(ApiVersion::V1, "bootstrap/status");
+        self.send_idempotent(self.client.get(request))
+    }
+
+    pub fn configuration_summary(&self) -> Result<Response, reqwest::Error> {
+        let request = self.path(ApiVersion::V1, "node/configuration");
+        self.send_idempotent(self.client.get(request))
+    }
+
+    pub fn network_events(&self) -> Result<Response, reqwest::Error> {
+        let request = self.path(ApiVersion::V1, "network/events");
+        self.send_idempotent(self.client.get(request))
+    }
+
+    pub fn settings_at(&self, epoch: u32) -> Result<Response, reqwest::Error> {
+        self.send_idempotent(
+            self.client
+                .get(&self.path(ApiVersion::V1, "settings"))
+                .query(&[("epoch", epoch)]),
+        )
+    }
+
+    /// Server-sent events of the accounts, given as hex-encoded public keys.
+    /// The stream is read from the body of the response.
+    pub fn account_events(&self, account_ids: &[String]) -> Result<Response, reqwest::Error> {
+        self.send_idempotent(
+            self.client
+                .get(&self.path(ApiVersion::V1, "accounts/events"))
+                .query(&[("account_ids", account_ids.join(","))]),
+        )
+    }
+
+    /// Server-sent events at the start of each slot, or of each epoch when
+    /// `epochs_only` is set.
+    pub fn clock_events(&self, epochs_only: bool) -> Result<Response, reqwest::Error> {
+        self.send_idempotent(
+            self.client
+                .get(&self.path(ApiVersion::V1, "clock/events"))
+                .query(&[("epochs_only", epochs_only)]),
+        )
+    }
+
     pub fn p2p_quarantined(&self) -> Result<Response, reqwest::Error> {
         self.get("network/p2p/quarantined")
     }
@@ -222,17 +340,39 @@ impl RawRest {
         Ok(())
     }
 
+    /// Moves the simulated clock of the node forward, the precision is one
+    /// second.
+    pub fn step_clock(&self, duration: Duration) -> Result<Response, reqwest::Error> {
+        self.post(&format!("clock/step/{}", duration.as_secs()), Vec::new())
+    }
+
     pub fn fragments_logs(&self) -> Result<Response, reqwest::Error> {
-        self.client
-            .get(&self.path(ApiVersion::V1, "fragments/logs"))
-            .send()
+        self.send_idempotent(
+            self.client
+                .get(&self.path(ApiVersion::V1, "fragments/logs")),
+        )
+    }
+
+    pub fn fragments_expired(&self) -> Result<Response, reqwest::Error> {
+        self.send_idempotent(
+            self.client
+                .get(&self.path(ApiVersion::V1, "fragments/expired")),
+        )
+    }
+
+    pub fn fragments_conflicts(&self) -> Result<Response, reqwest::Error> {
+        self.send_idempotent(
+            self.client
+                .get(&self.path(ApiVersion::V1, "fragments/conflicts")),
+        )
     }
 
     pub fn fragments_statuses(&self, ids: Vec<String>) -> Result<Response, reqwest::Error> {
-        self.client
-            .get(&self.path(ApiVersion::V1, "fragments/statuses"))
-            .query(&[("fragment_ids", ids.join(","))])
-            .send()
+        self.send_idempotent(
+            self.client
+                .get(&self.path(ApiVersion::V1, "fragments/statuses"))
+                .query(&[("fragment_ids", ids.join(","))]),
+        )
     }
 
     pub fn send_fragment_batch(
@@ -266,7 +406,7 @@ impl RawRest {
         );
         let request = self.path(ApiVersion::V1, &path);
         self.print_request_path(&request);
-        self.client.get(request).send()
+        self.send_idempotent(self.client.get(request))
     }
 
     pub fn send_until_ok<F>(&self, action: F, mut wait: Wait) -> Result<(), RestError>
@@ -286,3 +426,60 @@ impl RawRest {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::{SocketAddr, TcpListener};
+    use std::time::Duration;
+
+    fn unused_address() -> SocketAddr {
+        TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+    }
+
+    fn rest(address: SocketAddr, retry: RetryPolicy) -> RawRest {
+        RawRest::new(
+            format!("http://{}/api", address),
+            RestSettings {
+                retry,
+                ..Default::default()
+            },
+        )
+    }
+
+    #[test]
+    fn requests_are_retried_until_the_node_listens() {
+        let address = unused_address();
+        let node = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(300));
+            let listener = TcpListener::bind(address).unwrap();
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = [0; 1024];
+            assert!(stream.read(&mut request).unwrap() > 0);
+            stream
+                .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\n\r\n")
+                .unwrap();
+        });
+
+        let retry = RetryPolicy {
+            max_retries: 20,
+            initial_backoff: Duration::from_millis(50),
+            max_backoff: Duration::from_millis(100),
+        };
+        let response = rest(address, retry).stake_distribution().unwrap();
+        assert_eq!(response.status(), reqwest::StatusCode::OK);
+        node.join().unwrap();
+    }
+
+    #[test]
+    fn connection_errors_are_returned_without_retries() {
+        let error = rest(unused_address(), RetryPolicy::none())
+            .stake_distribution()
+            .unwrap_err();
+        assert!(error.is_connect());
+    }
+}
@@ -1,18 +1,22 @@
+use rand::Rng;
 use reqwest::Certificate;
+use std::time::Duration;
 
 #[derive(Debug, Clone)]
 pub struct RestSettings {
     pub enable_debug: bool,
     pub use_https: bool,
     pub certificate: Option<Certificate>,
+    /// Retry policy of the idempotent requests (GETs) failing to connect to
+    /// the node, for instance while it is still starting up
+    pub retry: RetryPolicy,
 }
 
 impl RestSettings {
     pub fn new_use_https() -> Self {
         RestSettings {
-            enable_debug: false,
             use_https: true,
-            certificate: None,
+            ..Default::default()
         }
     }
 }
@@ -23,6 +27,70 @@ impl Default for RestSettings {
             enable_debug: false,
             use_https: false,
             certificate: None,
+            retry: RetryPolicy::default(),
+        }
+    }
+}
+
+/// Exponential backoff with jitter: the n-th retry waits a random duration
+/// between half and all of `min(initial_backoff * 2^n, max_backoff)`.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    /// Number of retries after the first attempt, 0 disables the retries
+    pub max_retries: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl RetryPolicy {
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            ..Default::default()
+        }
+    }
+
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let backoff = self
+            .initial_backoff
+            .checked_mul(2u32.saturating_pow(retry))
+            .unwrap_or(self.max_backoff)
+            .min(self.max_backoff);
+        let half = backoff / 2;
+        half + half.mul_f64(rand::thread_rng().gen_range(0.0..=1.0))
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 5,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(2),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_doubles_up_to_the_maximum() {
+        let retry = RetryPolicy {
+            max_retries: 10,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_millis(500),
+        };
+        for (attempt, expected) in vec![(0, 100), (1, 200), (2, 400), (3, 500), (31, 500)] {
+            let expected = Duration::from_millis(expected);
+            let backoff = retry.backoff(attempt);
+            assert!(
+                backoff >= expected / 2 && backoff <= expected,
+                "backoff {:?} of retry {} out of bounds",
+                backoff,
+                attempt
+            );
         }
     }
 }
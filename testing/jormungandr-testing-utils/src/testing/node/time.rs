@@ -1,6 +1,6 @@
 use super::JormungandrRest;
 use jormungandr_lib::interfaces::BlockDate;
-use std::str::FromStr;
+use std::{str::FromStr, time::Duration};
 
 pub fn wait_for_epoch(target_epoch_id: u32, mut rest: JormungandrRest) {
     rest.enable_logger();
@@ -10,6 +10,26 @@ pub fn wait_for_epoch(target_epoch_id: u32, mut rest: JormungandrRest) {
     }
 }
 
+/// Moves the simulated clock of the node to the start of the target epoch,
+/// then waits for a block in that epoch. Unlike `wait_for_epoch`, it does not
+/// wait for the epochs in real time, but the node must run with a simulated
+/// clock.
+pub fn step_to_epoch(target_epoch_id: u32, mut rest: JormungandrRest) {
+    rest.enable_logger();
+
+    let current = get_current_date(&mut rest);
+    if current.epoch() < target_epoch_id {
+        let settings = rest.settings().unwrap();
+        // the last block is about the current slot of the node
+        let slots = (target_epoch_id - current.epoch()) * settings.slots_per_epoch - current.slot();
+        rest.step_clock(Duration::from_secs(
+            settings.slot_duration * u64::from(slots),
+        ))
+        .unwrap();
+    }
+    wait_for_epoch(target_epoch_id, rest);
+}
+
 pub fn wait_for_date(target_block_date: BlockDate, mut rest: JormungandrRest) {
     rest.enable_logger();
 
@@ -1,6 +1,8 @@
 mod fragment_log;
+mod persistence;
 
 pub use fragment_log::{assert_accepted_rejected, assert_bad_request, FragmentLogVerifier};
+pub use persistence::PersistenceVerifier;
 
 use super::JormungandrRest;
 use crate::wallet::Wallet;
@@ -0,0 +1,166 @@
+use crate::testing::fragments::{FragmentExporter, PersistentLogViewer};
+use crate::testing::node::JormungandrRest;
+use chain_core::property::{Fragment as _, Serialize as _};
+use chain_impl_mockchain::fragment::{Fragment, FragmentId};
+use std::collections::HashSet;
+use std::fmt::Write as _;
+use std::path::Path;
+
+/// Checks the persistent fragment log of a node against the fragments sent to
+/// it (as dumped by the fragment sender) and against the fragment logs the
+/// node reports. On mismatch, the assertions print the fragments missing on
+/// each side.
+pub struct PersistenceVerifier {
+    viewer: PersistentLogViewer,
+}
+
+impl PersistenceVerifier {
+    pub fn new<P: AsRef<Path>>(persistent_log: P) -> Self {
+        Self {
+            viewer: PersistentLogViewer::new(persistent_log.as_ref().to_path_buf()),
+        }
+    }
+
+    /// All the fragments of the dump folder are persisted, byte for byte and
+    /// in the same order, and no other fragment is.
+    pub fn assert_matches_dump<P: AsRef<Path>>(self, dump_folder: P) -> Self {
+        let exporter = FragmentExporter::new(dump_folder.as_ref().to_path_buf())
+            .expect("cannot open the fragment dump folder");
+        let dumped = exporter.read().expect("cannot read the dumped fragments");
+        let persisted = self.viewer.get_all();
+
+        let dumped_ids = ids(&dumped);
+        let persisted_ids = ids(&persisted);
+        if dumped_ids != persisted_ids {
+            panic!(
+                "persistent log does not match the dump folder {}\n{}",
+                dump_folder.as_ref().display(),
+                diff("dump folder", &dumped_ids, &persisted_ids)
+            );
+        }
+        for (dumped, persisted) in dumped.iter().zip(persisted.iter()) {
+            assert_eq!(
+                dumped.serialize_as_vec().unwrap(),
+                persisted.serialize_as_vec().unwrap(),
+                "persisted fragment {} differs from the dumped one",
+                dumped.id()
+            );
+        }
+        self
+    }
+
+    /// All the fragments known to the fragment logs of the node are persisted.
+    pub fn assert_contains_fragment_logs(self, rest: &JormungandrRest) -> Self {
+        let logged: Vec<FragmentId> = rest
+            .fragment_logs()
+            .expect("cannot get the fragment logs")
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect();
+        let persisted: HashSet<FragmentId> = ids(&self.viewer.get_all()).into_iter().collect();
+
+        let missing: Vec<&FragmentId> =
+            logged.iter().filter(|id| !persisted.contains(id)).collect();
+        if !missing.is_empty() {
+            let mut message = format!(
+                "{} of the {} fragments in the fragment logs are not persisted:\n",
+                missing.len(),
+                logged.len()
+            );
+            for id in missing {
+                writeln!(message, "  {}", id).unwrap();
+            }
+            panic!("{}", message);
+        }
+        self
+    }
+
+    pub fn assert_size(self, size: usize) -> Self {
+        assert_eq!(
+            self.viewer.count(),
+            size,
+            "wrong number of fragments in the persistent log"
+        );
+        self
+    }
+}
+
+fn ids(fragments: &[Fragment]) -> Vec<FragmentId> {
+    fragments.iter().map(|fragment| fragment.id()).collect()
+}
+
+fn diff(expected_name: &str, expected: &[FragmentId], persisted: &[FragmentId]) -> String {
+    let expected_set: HashSet<&FragmentId> = expected.iter().collect();
+    let persisted_set: HashSet<&FragmentId> = persisted.iter().collect();
+
+    let mut message = format!(
+        "{} fragments in the {}, {} in the persistent log\n",
+        expected.len(),
+        expected_name,
+        persisted.len()
+    );
+    let missing: Vec<_> = expected
+        .iter()
+        .filter(|id| !persisted_set.contains(id))
+        .collect();
+    if !missing.is_empty() {
+        writeln!(message, "not persisted:").unwrap();
+        for id in missing {
+            writeln!(message, "  {}", id).unwrap();
+        }
+    }
+    let unexpected: Vec<_> = persisted
+        .iter()
+        .filter(|id| !expected_set.contains(id))
+        .collect();
+    if !unexpected.is_empty() {
+        writeln!(message, "persisted but not in the {}:", expected_name).unwrap();
+        for id in unexpected {
+            writeln!(message, "  {}", id).unwrap();
+        }
+    }
+    if let Some(index) = expected
+        .iter()
+        .zip(persisted.iter())
+        .position(|(expected, persisted)| expected != persisted)
+    {
+        writeln!(
+            message,
+            "first difference in order at position {}: {} vs {}",
+            index, expected[index], persisted[index]
+        )
+        .unwrap();
+    }
+    message
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn id(byte: u8) -> FragmentId {
+        FragmentId::hash_bytes(&[byte])
+    }
+
+    #[test]
+    fn diff_lists_the_fragments_missing_on_each_side() {
+        let message = diff("dump folder", &[id(1), id(2)], &[id(2), id(3)]);
+        assert!(message.starts_with("2 fragments in the dump folder, 2 in the persistent log"));
+        assert!(message.contains(&format!("not persisted:\n  {}\n", id(1))));
+        assert!(message.contains(&format!(
+            "persisted but not in the dump folder:\n  {}\n",
+            id(3)
+        )));
+    }
+
+    #[test]
+    fn diff_reports_the_first_difference_in_order() {
+        let message = diff("dump folder", &[id(1), id(2)], &[id(2), id(1)]);
+        assert!(!message.contains("not persisted"));
+        assert!(message.contains(&format!(
+            "first difference in order at position 0: {} vs {}",
+            id(1),
+            id(2)
+        )));
+    }
+}
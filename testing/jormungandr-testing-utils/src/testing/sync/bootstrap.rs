@@ -0,0 +1,153 @@
+use super::{SyncNode, SyncNodeError};
+use serde::Serialize;
+use std::fmt;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// Minimal acceptable throughput of a fresh node bootstrapping from a peer.
+/// Report which falls below any of the limits is treated as a regression.
+#[derive(Debug, Clone, Serialize)]
+pub struct BootstrapSyncThresholds {
+    pub min_blocks_per_sec: f64,
+    pub min_bytes_per_sec: f64,
+    pub timeout: Duration,
+}
+
+impl BootstrapSyncThresholds {
+    pub fn new(min_blocks_per_sec: f64, min_bytes_per_sec: f64, timeout: Duration) -> Self {
+        Self {
+            min_blocks_per_sec,
+            min_bytes_per_sec,
+            timeout,
+        }
+    }
+}
+
+impl Default for BootstrapSyncThresholds {
+    fn default() -> Self {
+        Self::new(0.0, 0.0, Duration::from_secs(10 * 60))
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BootstrapSyncReport {
+    pub name: String,
+    pub source: String,
+    pub target: String,
+    pub blocks: u32,
+    pub bytes: u64,
+    pub duration: Duration,
+    pub blocks_per_sec: f64,
+    pub bytes_per_sec: f64,
+}
+
+impl BootstrapSyncReport {
+    fn new(
+        name: String,
+        source: String,
+        target: String,
+        blocks: u32,
+        bytes: u64,
+        duration: Duration,
+    ) -> Self {
+        let secs = duration.as_secs_f64().max(f64::EPSILON);
+        Self {
+            name,
+            source,
+            target,
+            blocks,
+            bytes,
+            duration,
+            blocks_per_sec: blocks as f64 / secs,
+            bytes_per_sec: bytes as f64 / secs,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+
+    pub fn print(&self) {
+        println!("{}", self);
+    }
+
+    pub fn verify(&self, thresholds: &BootstrapSyncThresholds) -> Result<(), SyncNodeError> {
+        if self.blocks_per_sec < thresholds.min_blocks_per_sec
+            || self.bytes_per_sec < thresholds.min_bytes_per_sec
+        {
+            return Err(SyncNodeError::BootstrapRegression {
+                report: self.clone(),
+                thresholds: thresholds.clone(),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for BootstrapSyncReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} synced {} blocks ({} bytes) from {} in {:?} [{:.2} blocks/s, {:.2} bytes/s]",
+            self.name,
+            self.target,
+            self.blocks,
+            self.bytes,
+            self.source,
+            self.duration,
+            self.blocks_per_sec,
+            self.bytes_per_sec
+        )
+    }
+}
+
+/// Measures how long it takes for a fresh node to catch up with the `source` node.
+///
+/// The measurement starts right before `start_target` is called, so it includes
+/// the node startup, and stops as soon as the new node reaches the block height
+/// the source node had when the measurement began. The amount of transferred data
+/// is approximated by the size of the source node storage.
+pub fn measure_bootstrap_sync<S, T, F, P>(
+    name: &str,
+    source: &S,
+    source_storage: P,
+    thresholds: &BootstrapSyncThresholds,
+    start_target: F,
+) -> Result<(T, BootstrapSyncReport), SyncNodeError>
+where
+    S: SyncNode + ?Sized,
+    T: SyncNode,
+    F: FnOnce() -> T,
+    P: AsRef<Path>,
+{
+    let blocks = source.last_block_height();
+    let bytes = fs_extra::dir::get_size(source_storage.as_ref()).unwrap_or(0);
+
+    let start = Instant::now();
+    let target = start_target();
+
+    loop {
+        if target.last_block_height() >= blocks {
+            break;
+        }
+        if start.elapsed() > thresholds.timeout {
+            return Err(SyncNodeError::BootstrapTimeout {
+                alias: target.alias().to_string(),
+                expected_height: blocks,
+                actual_height: target.last_block_height(),
+                timeout: thresholds.timeout,
+            });
+        }
+        std::thread::sleep(Duration::from_millis(100));
+    }
+
+    let report = BootstrapSyncReport::new(
+        name.to_string(),
+        source.alias().to_string(),
+        target.alias().to_string(),
+        blocks,
+        bytes,
+        start.elapsed(),
+    );
+    Ok((target, report))
+}
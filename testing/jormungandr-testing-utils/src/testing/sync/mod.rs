@@ -1,11 +1,13 @@
-use crate::testing::verify::{assert_equals, Error as VerificationError};
+use crate::testing::verify::{assert, assert_equals, Error as VerificationError};
 use crate::testing::{benchmark_speed, Speed, Thresholds};
 
+mod bootstrap;
 mod measure;
 mod node;
 mod report;
 mod wait;
 
+pub use bootstrap::{measure_bootstrap_sync, BootstrapSyncReport, BootstrapSyncThresholds};
 use jormungandr_lib::time::Duration as LibsDuration;
 pub use measure::*;
 pub use node::{SyncNode, SyncNodeError, SyncNodeRecord};
@@ -96,6 +98,42 @@ pub fn ensure_nodes_are_in_sync<A: SyncNode + ?Sized>(
     Ok(())
 }
 
+/// Verifies that each partition of the network follows its own fork: nodes
+/// from different partitions must not share the same tip
+pub fn ensure_partitions_diverged<A: SyncNode + ?Sized>(
+    partitions: &[Vec<&A>],
+) -> Result<(), VerificationError> {
+    for (i, left_partition) in partitions.iter().enumerate() {
+        for right_partition in partitions.iter().skip(i + 1) {
+            for left in left_partition {
+                for right in right_partition {
+                    assert(
+                        left.tip() != right.tip(),
+                        &format!(
+                            "nodes from different partitions share the same tip. Left node: alias: {}, tip: {}, Right node: alias: {}, tip: {}",
+                            left.alias(),
+                            left.tip(),
+                            right.alias(),
+                            right.tip()
+                        ),
+                    )?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Verifies that once the partition is healed, nodes from all partitions
+/// converge on a single tip
+pub fn ensure_partitions_converged<A: SyncNode + ?Sized>(
+    sync_wait: SyncWaitParams,
+    partitions: &[Vec<&A>],
+) -> Result<(), VerificationError> {
+    let nodes: Vec<&A> = partitions.iter().flatten().copied().collect();
+    ensure_nodes_are_in_sync(sync_wait, &nodes)
+}
+
 pub fn wait_for_nodes_sync(sync_wait_params: &SyncWaitParams) {
     let wait_time = sync_wait_params.wait_time();
     std::thread::sleep(wait_time);
@@ -1,3 +1,4 @@
+use super::{BootstrapSyncReport, BootstrapSyncThresholds};
 use jormungandr_lib::crypto::hash::Hash;
 use std::{fmt, time::Duration};
 use thiserror::Error;
@@ -43,6 +44,18 @@ pub enum SyncNodeError {
         target_node: SyncNodeRecord,
         sync_nodes: Vec<SyncNodeRecord>,
     },
+    #[error("Timeout exceeded '{timeout:?}' while node '{alias}' was bootstrapping. Expected height: {expected_height}, actual: {actual_height}")]
+    BootstrapTimeout {
+        alias: String,
+        expected_height: u32,
+        actual_height: u32,
+        timeout: Duration,
+    },
+    #[error("bootstrap performance below thresholds: {report}, expected: {thresholds:?}")]
+    BootstrapRegression {
+        report: BootstrapSyncReport,
+        thresholds: BootstrapSyncThresholds,
+    },
     #[error("verification error")]
     VerificationError(#[from] crate::testing::verify::Error),
 }
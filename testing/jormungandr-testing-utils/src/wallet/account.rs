@@ -1,3 +1,4 @@
+use super::WalletError;
 use crate::testing::FragmentBuilderError;
 use chain_addr::Discrimination;
 use chain_impl_mockchain::{
@@ -17,6 +18,13 @@ use jormungandr_lib::{
 };
 
 use rand_core::{CryptoRng, RngCore};
+use std::path::Path;
+
+/// names of the files written by `jcli key generate`, `jcli key to-public`
+/// and `jcli address account` respectively
+pub const SECRET_KEY_FILE: &str = "secret_key.sk";
+pub const PUBLIC_KEY_FILE: &str = "public_key.pk";
+pub const ADDRESS_FILE: &str = "address";
 
 /// wallet for an account
 #[derive(Debug, Clone)]
@@ -64,10 +72,74 @@ impl Wallet {
         writeln!(w, "{}", self.signing_key().to_bech32_str())
     }
 
+    /// writes the secret key, the public key and the address of the account
+    /// in `dir`, each file with the same content as the corresponding jcli output
+    pub fn to_jcli_files<P: AsRef<Path>>(&self, dir: P) -> std::io::Result<()> {
+        let dir = dir.as_ref();
+        std::fs::create_dir_all(dir)?;
+        std::fs::write(
+            dir.join(SECRET_KEY_FILE),
+            format!("{}\n", self.signing_key.to_bech32_str()),
+        )?;
+        std::fs::write(
+            dir.join(PUBLIC_KEY_FILE),
+            format!("{}\n", self.identifier.to_bech32_str()),
+        )?;
+        std::fs::write(dir.join(ADDRESS_FILE), format!("{}\n", self.address()))
+    }
+
+    /// reads an account from files written by jcli (or [`to_jcli_files`](Self::to_jcli_files)).
+    /// Only the secret key is required, the public key and the address are checked
+    /// against it when present and the discrimination is taken from the address.
+    pub fn from_jcli_files<P: AsRef<Path>>(
+        dir: P,
+        spending_counter: Option<u32>,
+    ) -> Result<Self, WalletError> {
+        let dir = dir.as_ref();
+        let signing_key = SigningKey::from_bech32_str(
+            std::fs::read_to_string(dir.join(SECRET_KEY_FILE))?.trim(),
+        )?;
+        let identifier = signing_key.identifier();
+
+        let public_key_path = dir.join(PUBLIC_KEY_FILE);
+        if public_key_path.exists()
+            && std::fs::read_to_string(&public_key_path)?.trim() != identifier.to_bech32_str()
+        {
+            return Err(WalletError::KeyFileMismatch(public_key_path));
+        }
+
+        let address_path = dir.join(ADDRESS_FILE);
+        let discrimination = if address_path.exists() {
+            let address: Address = std::fs::read_to_string(&address_path)?.trim().parse()?;
+            let discrimination = address.1.discrimination();
+            if address.1 != identifier.to_address(discrimination) {
+                return Err(WalletError::KeyFileMismatch(address_path));
+            }
+            discrimination
+        } else {
+            Discrimination::Test
+        };
+
+        Ok(Wallet {
+            signing_key,
+            identifier,
+            internal_counter: spending_counter.unwrap_or(0).into(),
+            discrimination,
+        })
+    }
+
     pub fn address(&self) -> Address {
         self.identifier().to_address(self.discrimination).into()
     }
 
+    pub fn discrimination(&self) -> Discrimination {
+        self.discrimination
+    }
+
+    pub fn set_discrimination(&mut self, discrimination: Discrimination) {
+        self.discrimination = discrimination;
+    }
+
     pub fn set_counter(&mut self, value: u32) {
         self.internal_counter = account::SpendingCounter::from(value);
     }
@@ -142,3 +214,55 @@ impl Wallet {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_fs::TempDir;
+
+    #[test]
+    pub fn jcli_files_write_read_round_trip() {
+        let mut rng = rand::thread_rng();
+        let wallet = Wallet::generate(&mut rng, Discrimination::Production);
+
+        let temp_dir = TempDir::new().unwrap();
+        let directory = temp_dir.path().join("alice");
+        wallet.to_jcli_files(&directory).unwrap();
+        let read = Wallet::from_jcli_files(&directory, Some(3)).unwrap();
+
+        assert_eq!(
+            wallet.signing_key().to_bech32_str(),
+            read.signing_key().to_bech32_str()
+        );
+        assert_eq!(wallet.address(), read.address());
+        assert_eq!(read.discrimination(), Discrimination::Production);
+        assert_eq!(read.internal_counter(), 3.into());
+
+        // jcli does not write the public key and the address with the secret key
+        std::fs::remove_file(directory.join(PUBLIC_KEY_FILE)).unwrap();
+        std::fs::remove_file(directory.join(ADDRESS_FILE)).unwrap();
+        let read = Wallet::from_jcli_files(&directory, None).unwrap();
+        assert_eq!(wallet.identifier(), read.identifier());
+        assert_eq!(read.discrimination(), Discrimination::Test);
+    }
+
+    #[test]
+    pub fn address_of_another_key_is_rejected() {
+        let mut rng = rand::thread_rng();
+        let wallet = Wallet::generate(&mut rng, Discrimination::Test);
+        let other = Wallet::generate(&mut rng, Discrimination::Test);
+
+        let temp_dir = TempDir::new().unwrap();
+        wallet.to_jcli_files(temp_dir.path()).unwrap();
+        std::fs::write(
+            temp_dir.path().join(ADDRESS_FILE),
+            format!("{}\n", other.address()),
+        )
+        .unwrap();
+
+        assert!(matches!(
+            Wallet::from_jcli_files(temp_dir.path(), None),
+            Err(WalletError::KeyFileMismatch(path)) if path == temp_dir.path().join(ADDRESS_FILE)
+        ));
+    }
+}
@@ -12,19 +12,43 @@ use chain_vote::{
         ElectionPublicKey, MemberCommunicationKey, MemberCommunicationPublicKey, MemberPublicKey,
         MemberState,
     },
-    tally::{Crs, OpeningVoteKey},
+    tally::{Crs, EncryptedTally, OpeningVoteKey},
+    TallyOptimizationTable,
 };
-use jormungandr_lib::crypto::account::Identifier;
+use jormungandr_lib::{crypto::account::Identifier, interfaces::MEMBER_PUBLIC_KEY_BECH32_HRP};
 use rand_core::{CryptoRng, RngCore};
 use std::collections::HashMap;
 use std::fmt;
 use std::fs::File;
 use std::io::Write;
+use std::path::Path;
 
 pub const COMMUNICATION_SK_HRP: &str = "p256k1_vcommsk";
+pub const COMMUNICATION_PK_HRP: &str = "p256k1_vcommpk";
 pub const MEMBER_SK_HRP: &str = "p256k1_membersk";
+pub const MEMBER_PK_HRP: &str = MEMBER_PUBLIC_KEY_BECH32_HRP;
 pub const ENCRYPTING_VOTE_PK_HRP: &str = "p256k1_votepk";
 
+/// File names used in the committee directory, each key file holds a single bech32
+/// line as produced by the `jcli votes` commands:
+///
+/// ```text
+/// <directory>/election_public_key.pk        jcli votes election-key
+/// <directory>/threshold                    number of members needed to decrypt the tally
+/// <directory>/<member account id>/alias
+/// <directory>/<member account id>/communication_key.sk   jcli votes committee communication-key generate
+/// <directory>/<member account id>/communication_key.pk   jcli votes committee communication-key to-public
+/// <directory>/<member account id>/member_secret_key.sk   jcli votes committee member-key generate
+/// <directory>/<member account id>/member_public_key.pk   jcli votes committee member-key to-public
+/// ```
+pub const ELECTION_PK_FILE: &str = "election_public_key.pk";
+pub const THRESHOLD_FILE: &str = "threshold";
+pub const ALIAS_FILE: &str = "alias";
+pub const COMMUNICATION_SK_FILE: &str = "communication_key.sk";
+pub const COMMUNICATION_PK_FILE: &str = "communication_key.pk";
+pub const MEMBER_SK_FILE: &str = "member_secret_key.sk";
+pub const MEMBER_PK_FILE: &str = "member_public_key.pk";
+
 #[derive(Clone)]
 pub struct PrivateVoteCommitteeData {
     alias: String,
@@ -67,42 +91,116 @@ impl PrivateVoteCommitteeData {
         self.alias.clone()
     }
 
-    pub fn write_to(&self, directory: ChildPath) {
-        std::fs::create_dir_all(directory.path()).unwrap();
-        self.write_communication_key(&directory);
-        self.write_member_secret_key(&directory);
+    pub fn communication_public_key(&self) -> MemberCommunicationPublicKey {
+        self.communication_key.to_public()
     }
 
-    fn write_communication_key(&self, directory: &ChildPath) {
-        let path = directory.child("communication_key.sk");
-        let mut file = File::create(path.path()).unwrap();
+    /// Replaces the communication key of the member, e.g. after it was
+    /// compromised. The member keys are derived from the communication keys of
+    /// the whole committee, so they are stale until the committee regenerates
+    /// them: use [`PrivateVoteCommitteeDataManager::rotate_communication_key`]
+    /// to do both.
+    pub fn rotate_communication_key<RNG>(&mut self, rng: &mut RNG) -> MemberCommunicationPublicKey
+    where
+        RNG: RngCore + CryptoRng,
+    {
+        self.communication_key = MemberCommunicationKey::new(rng);
+        self.communication_key.to_public()
+    }
 
-        writeln!(
-            file,
-            "{}",
-            bech32::encode(
-                COMMUNICATION_SK_HRP,
-                self.communication_key.to_bytes().to_base32()
-            )
-            .unwrap()
-        )
-        .unwrap()
-    }
-
-    fn write_member_secret_key(&self, directory: &ChildPath) {
-        let path = directory.child("member_secret_key.sk");
-        let mut file = File::create(path.path()).unwrap();
-        writeln!(
-            file,
-            "{}",
-            bech32::encode(
-                MEMBER_SK_HRP,
-                self.member_secret_key().to_bytes().to_base32()
-            )
-            .unwrap()
+    fn set_member_state(&mut self, member_state: &MemberState) {
+        self.member_secret_key = member_state.secret_key().clone();
+        self.member_public_key = member_state.public_key().clone();
+    }
+
+    fn set_election_public_key(&mut self, election_public_key: ElectionPublicKey) {
+        self.election_public_key = election_public_key;
+    }
+
+    pub fn write_to(&self, directory: ChildPath) -> std::io::Result<()> {
+        std::fs::create_dir_all(directory.path())?;
+        std::fs::write(directory.child(ALIAS_FILE).path(), &self.alias)?;
+        write_bech32(
+            &directory.child(COMMUNICATION_SK_FILE),
+            COMMUNICATION_SK_HRP,
+            &self.communication_key.to_bytes(),
+        )?;
+        write_bech32(
+            &directory.child(COMMUNICATION_PK_FILE),
+            COMMUNICATION_PK_HRP,
+            &self.communication_key.to_public().to_bytes(),
+        )?;
+        write_bech32(
+            &directory.child(MEMBER_SK_FILE),
+            MEMBER_SK_HRP,
+            &self.member_secret_key.to_bytes(),
+        )?;
+        write_bech32(
+            &directory.child(MEMBER_PK_FILE),
+            MEMBER_PK_HRP,
+            &self.member_public_key.to_bytes(),
         )
-        .unwrap()
     }
+
+    /// Reads back the member data written by [`write_to`](Self::write_to). The
+    /// election public key belongs to the whole committee and is not stored
+    /// with the member.
+    pub fn read_from<P: AsRef<Path>>(
+        directory: P,
+        election_public_key: ElectionPublicKey,
+    ) -> Result<Self, Error> {
+        let directory = directory.as_ref();
+        let alias = std::fs::read_to_string(directory.join(ALIAS_FILE))?
+            .trim()
+            .to_string();
+
+        let communication_key = read_bech32(
+            &directory.join(COMMUNICATION_SK_FILE),
+            COMMUNICATION_SK_HRP,
+            MemberCommunicationKey::from_bytes,
+        )?;
+        let member_secret_key = read_bech32(
+            &directory.join(MEMBER_SK_FILE),
+            MEMBER_SK_HRP,
+            OpeningVoteKey::from_bytes,
+        )?;
+        let member_public_key = read_bech32(
+            &directory.join(MEMBER_PK_FILE),
+            MEMBER_PK_HRP,
+            MemberPublicKey::from_bytes,
+        )?;
+
+        Ok(Self::new(
+            alias,
+            communication_key,
+            member_secret_key,
+            member_public_key,
+            election_public_key,
+        ))
+    }
+}
+
+fn write_bech32(path: &ChildPath, hrp: &str, bytes: &[u8]) -> std::io::Result<()> {
+    let encoded = bech32::encode(hrp, bytes.to_base32())
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+    let mut file = File::create(path.path())?;
+    writeln!(file, "{}", encoded)
+}
+
+fn read_bech32<T, F>(path: &Path, expected_hrp: &str, from_bytes: F) -> Result<T, Error>
+where
+    F: FnOnce(&[u8]) -> Option<T>,
+{
+    let content = std::fs::read_to_string(path)?;
+    let (hrp, data) = bech32::decode(content.trim())?;
+    if hrp != expected_hrp {
+        return Err(Error::InvalidBech32Key {
+            expected: expected_hrp.to_string(),
+            actual: hrp,
+        });
+    }
+    let bytes = Vec::<u8>::from_base32(&data)?;
+    from_bytes(&bytes).ok_or_else(|| Error::InvalidCommitteeKey(path.to_path_buf()))
 }
 
 pub trait ElectionPublicKeyExtension {
@@ -147,6 +245,7 @@ impl fmt::Debug for PrivateVoteCommitteeData {
 #[derive(Clone, Debug)]
 pub struct PrivateVoteCommitteeDataManager {
     data: HashMap<Identifier, PrivateVoteCommitteeData>,
+    threshold: usize,
 }
 
 impl PrivateVoteCommitteeDataManager {
@@ -158,9 +257,7 @@ impl PrivateVoteCommitteeDataManager {
     where
         RNG: RngCore + CryptoRng,
     {
-        let mut buf = [0; 32];
-        rand::thread_rng().fill_bytes(&mut buf);
-        let crs = Crs::from_hash(&buf);
+        let crs = random_crs();
         let mut data = HashMap::new();
 
         let communication_secret_keys: Vec<MemberCommunicationKey> =
@@ -173,12 +270,21 @@ impl PrivateVoteCommitteeDataManager {
                 .map(|x| x.to_public())
                 .collect();
 
-        for (index, (alias, pk)) in committees.iter().enumerate() {
-            let ms = MemberState::new(&mut rng, threshold, &crs, &communication_public_keys, index);
+        let member_states: Vec<MemberState> = (0..committees.len())
+            .map(|index| {
+                MemberState::new(&mut rng, threshold, &crs, &communication_public_keys, index)
+            })
+            .collect();
+        // the election public key is the one of the whole committee
+        let election_public_key = ElectionPublicKey::from_participants(
+            &member_states
+                .iter()
+                .map(|ms| ms.public_key().clone())
+                .collect::<Vec<_>>(),
+        );
 
+        for (index, ((alias, pk), ms)) in committees.iter().zip(&member_states).enumerate() {
             let communication_secret_key = communication_secret_keys.get(index).unwrap();
-            let election_public_key =
-                ElectionPublicKey::from_participants(&[ms.public_key().clone()]);
 
             data.insert(
                 pk.clone(),
@@ -187,18 +293,68 @@ impl PrivateVoteCommitteeDataManager {
                     communication_secret_key.clone(),
                     ms.secret_key().clone(),
                     ms.public_key().clone(),
-                    election_public_key,
+                    election_public_key.clone(),
                 ),
             );
         }
 
-        Self { data }
+        Self { data, threshold }
     }
 
     pub fn get(&self, identifier: &Identifier) -> Option<&PrivateVoteCommitteeData> {
         self.data.get(identifier)
     }
 
+    pub fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    /// Gives a new communication key to the member `identifier` and regenerates
+    /// the member keys of the whole committee, as done when a member key is
+    /// compromised during the setup. The election public key changes too, so
+    /// the vote plans are to be built afterwards.
+    pub fn rotate_communication_key<RNG>(
+        &mut self,
+        rng: &mut RNG,
+        identifier: &Identifier,
+    ) -> Result<MemberCommunicationPublicKey, Error>
+    where
+        RNG: RngCore + CryptoRng,
+    {
+        let public_key = self
+            .data
+            .get_mut(identifier)
+            .ok_or_else(|| Error::InvalidCommitteeMemberId(identifier.to_bech32_str()))?
+            .rotate_communication_key(rng);
+        self.regenerate_member_keys(rng);
+        Ok(public_key)
+    }
+
+    /// Runs the key generation of the committee again from the current
+    /// communication keys of the members. The keys of all the members change,
+    /// as well as the election public key.
+    pub fn regenerate_member_keys<RNG>(&mut self, rng: &mut RNG)
+    where
+        RNG: RngCore + CryptoRng,
+    {
+        let crs = random_crs();
+        // any order works as long as the indices match the communication keys
+        let identifiers: Vec<Identifier> = self.data.keys().cloned().collect();
+        let communication_public_keys: Vec<MemberCommunicationPublicKey> = identifiers
+            .iter()
+            .map(|identifier| self.data[identifier].communication_public_key())
+            .collect();
+
+        for (index, identifier) in identifiers.iter().enumerate() {
+            let ms = MemberState::new(rng, self.threshold, &crs, &communication_public_keys, index);
+            self.data.get_mut(identifier).unwrap().set_member_state(&ms);
+        }
+        let election_public_key = self.election_public_key();
+        for data in self.data.values_mut() {
+            data.set_election_public_key(election_public_key.clone());
+        }
+    }
+
     pub fn election_public_key(&self) -> ElectionPublicKey {
         chain_vote::ElectionPublicKey::from_participants(&self.member_public_keys())
     }
@@ -207,20 +363,67 @@ impl PrivateVoteCommitteeDataManager {
         self.data.values().cloned().collect()
     }
 
+    /// Writes the committee using the layout described by [`ELECTION_PK_FILE`], which
+    /// can be passed directly to the `jcli votes` tally commands.
     pub fn write_to(&self, directory: ChildPath) -> std::io::Result<()> {
-        std::fs::create_dir_all(directory.path()).unwrap();
-        self.write_election_public_key(&directory);
+        std::fs::create_dir_all(directory.path())?;
+        write_bech32(
+            &directory.child(ELECTION_PK_FILE),
+            ENCRYPTING_VOTE_PK_HRP,
+            &self.election_public_key().to_bytes(),
+        )?;
+        std::fs::write(
+            directory.child(THRESHOLD_FILE).path(),
+            self.threshold.to_string(),
+        )?;
         for (id, data) in self.data.iter() {
             let item_directory = directory.child(id.to_bech32_str());
-            data.write_to(item_directory);
+            data.write_to(item_directory)?;
         }
         Ok(())
     }
 
-    fn write_election_public_key(&self, directory: &ChildPath) {
-        let path = directory.child("election_public_key.sk");
-        let mut file = File::create(path.path()).unwrap();
-        writeln!(file, "{}", self.election_public_key().to_base32().unwrap()).unwrap()
+    /// Reads a committee previously written with [`write_to`](Self::write_to).
+    /// Without a threshold file, all the members are needed to decrypt the tally.
+    /// The stored election public key must be the one of the members read.
+    pub fn read_from<P: AsRef<Path>>(directory: P) -> Result<Self, Error> {
+        let directory = directory.as_ref();
+        let election_key_path = directory.join(ELECTION_PK_FILE);
+        let election_public_key = read_bech32(
+            &election_key_path,
+            ENCRYPTING_VOTE_PK_HRP,
+            ElectionPublicKey::from_bytes,
+        )?;
+        let mut data = HashMap::new();
+        for entry in std::fs::read_dir(directory)? {
+            let entry = entry?;
+            if !entry.file_type()?.is_dir() {
+                continue;
+            }
+            let name = entry.file_name().to_string_lossy().into_owned();
+            let identifier = Identifier::from_bech32_str(&name)
+                .map_err(|_| Error::InvalidCommitteeMemberId(name))?;
+            data.insert(
+                identifier,
+                PrivateVoteCommitteeData::read_from(entry.path(), election_public_key.clone())?,
+            );
+        }
+        let members: Vec<MemberPublicKey> = data.values().map(|x| x.member_public_key()).collect();
+        if ElectionPublicKey::from_participants(&members).to_bytes()
+            != election_public_key.to_bytes()
+        {
+            return Err(Error::InvalidCommitteeKey(election_key_path));
+        }
+        let threshold_path = directory.join(THRESHOLD_FILE);
+        let threshold = if threshold_path.exists() {
+            std::fs::read_to_string(&threshold_path)?
+                .trim()
+                .parse()
+                .map_err(|_| Error::InvalidCommitteeThreshold(threshold_path))?
+        } else {
+            data.len()
+        };
+        Ok(Self { data, threshold })
     }
 
     pub fn member_public_keys(&self) -> Vec<MemberPublicKey> {
@@ -244,34 +447,228 @@ impl PrivateVoteCommitteeDataManager {
             .map(|(_encrypted_tally, max_votes)| *max_votes)
             .max()
             .unwrap();
-        let table = chain_vote::TallyOptimizationTable::generate(absolute_max_votes);
+        let table = TallyOptimizationTable::generate(absolute_max_votes);
 
         let proposals = encrypted_tally
             .into_iter()
             .map(|(encrypted_tally, max_votes)| {
-                let decrypt_shares = self
-                    .members()
-                    .iter()
-                    .map(|member| member.member_secret_key())
-                    .map(|secret_key| {
-                        encrypted_tally.partial_decrypt(&mut rand::thread_rng(), &secret_key)
-                    })
-                    .collect::<Vec<_>>();
-                let tally = encrypted_tally
-                    .validate_partial_decryptions(
-                        &vote_plan_status.committee_public_keys,
-                        &decrypt_shares,
-                    )
-                    .unwrap()
-                    .decrypt_tally(max_votes, &table)
-                    .unwrap();
-                DecryptedPrivateTallyProposal {
-                    decrypt_shares: decrypt_shares.into_boxed_slice(),
-                    tally_result: tally.votes.into_boxed_slice(),
-                }
+                self.decrypt_proposal_tally(
+                    &encrypted_tally,
+                    &vote_plan_status.committee_public_keys,
+                    max_votes,
+                    &table,
+                )
             })
             .collect::<Vec<_>>();
 
         DecryptedPrivateTally::new(proposals)
     }
+
+    fn decrypt_proposal_tally(
+        &self,
+        encrypted_tally: &EncryptedTally,
+        committee_public_keys: &[MemberPublicKey],
+        max_votes: u64,
+        table: &TallyOptimizationTable,
+    ) -> DecryptedPrivateTallyProposal {
+        let decrypt_shares = self
+            .members()
+            .iter()
+            .map(|member| member.member_secret_key())
+            .map(|secret_key| encrypted_tally.partial_decrypt(&mut rand::thread_rng(), &secret_key))
+            .collect::<Vec<_>>();
+        let tally = encrypted_tally
+            .validate_partial_decryptions(committee_public_keys, &decrypt_shares)
+            .unwrap()
+            .decrypt_tally(max_votes, table)
+            .unwrap();
+        DecryptedPrivateTallyProposal {
+            decrypt_shares: decrypt_shares.into_boxed_slice(),
+            tally_result: tally.votes.into_boxed_slice(),
+        }
+    }
+}
+
+fn random_crs() -> Crs {
+    let mut buf = [0; 32];
+    rand::thread_rng().fill_bytes(&mut buf);
+    Crs::from_hash(&buf)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wallet::Wallet;
+    use assert_fs::TempDir;
+    use chain_impl_mockchain::vote::encrypt_vote;
+    use chain_vote::{Ballot, Vote};
+
+    fn committees<RNG>(rng: &mut RNG, aliases: &[&str]) -> Vec<(WalletAlias, Identifier)>
+    where
+        RNG: RngCore + CryptoRng,
+    {
+        aliases
+            .iter()
+            .map(|alias| {
+                let identifier = Wallet::new_account(rng).identifier().into();
+                (alias.to_string(), identifier)
+            })
+            .collect()
+    }
+
+    #[test]
+    pub fn committee_data_write_read_round_trip() {
+        let mut rng = rand::thread_rng();
+        let committees = committees(&mut rng, &["alice", "bob", "carol"]);
+        let manager = PrivateVoteCommitteeDataManager::new(&mut rng, committees.clone(), 2);
+
+        let temp_dir = TempDir::new().unwrap();
+        let directory = temp_dir.child("committee");
+        manager.write_to(directory.clone()).unwrap();
+        let read = PrivateVoteCommitteeDataManager::read_from(directory.path()).unwrap();
+
+        assert_eq!(
+            manager.election_public_key().to_bytes(),
+            read.election_public_key().to_bytes()
+        );
+        assert_eq!(manager.threshold(), read.threshold());
+        for (alias, identifier) in committees {
+            let expected = manager.get(&identifier).unwrap();
+            let actual = read.get(&identifier).unwrap();
+            assert_eq!(alias, actual.alias());
+            assert_eq!(
+                expected.member_secret_key().to_bytes(),
+                actual.member_secret_key().to_bytes()
+            );
+            assert_eq!(
+                expected.member_public_key().to_bytes(),
+                actual.member_public_key().to_bytes()
+            );
+            // each member holds the key of the whole committee
+            assert_eq!(
+                manager.election_public_key().to_bytes(),
+                expected.election_public_key().to_bytes()
+            );
+            assert_eq!(
+                manager.election_public_key().to_bytes(),
+                actual.election_public_key().to_bytes()
+            );
+        }
+    }
+
+    #[test]
+    pub fn committee_with_another_election_key_is_rejected() {
+        let mut rng = rand::thread_rng();
+        let manager = PrivateVoteCommitteeDataManager::new(
+            &mut rng,
+            committees(&mut rng, &["alice", "bob"]),
+            2,
+        );
+        let other =
+            PrivateVoteCommitteeDataManager::new(&mut rng, committees(&mut rng, &["carol"]), 1);
+
+        let temp_dir = TempDir::new().unwrap();
+        let directory = temp_dir.child("committee");
+        manager.write_to(directory.clone()).unwrap();
+        write_bech32(
+            &directory.child(ELECTION_PK_FILE),
+            ENCRYPTING_VOTE_PK_HRP,
+            &other.election_public_key().to_bytes(),
+        )
+        .unwrap();
+
+        assert!(matches!(
+            PrivateVoteCommitteeDataManager::read_from(directory.path()),
+            Err(Error::InvalidCommitteeKey(_))
+        ));
+    }
+
+    #[test]
+    pub fn rotating_a_communication_key_regenerates_all_member_keys() {
+        let mut rng = rand::thread_rng();
+        let committees = committees(&mut rng, &["alice", "bob", "carol"]);
+        let mut manager = PrivateVoteCommitteeDataManager::new(&mut rng, committees.clone(), 2);
+        let previous = manager.clone();
+        let (_, rotated) = &committees[1];
+
+        let communication_key = manager.rotate_communication_key(&mut rng, rotated).unwrap();
+
+        let member = manager.get(rotated).unwrap();
+        assert_eq!(
+            communication_key.to_bytes(),
+            member.communication_public_key().to_bytes()
+        );
+        assert_ne!(
+            communication_key.to_bytes(),
+            previous
+                .get(rotated)
+                .unwrap()
+                .communication_public_key()
+                .to_bytes()
+        );
+        for (_, identifier) in &committees {
+            assert_ne!(
+                previous
+                    .get(identifier)
+                    .unwrap()
+                    .member_public_key()
+                    .to_bytes(),
+                manager
+                    .get(identifier)
+                    .unwrap()
+                    .member_public_key()
+                    .to_bytes()
+            );
+        }
+        assert_ne!(
+            previous.election_public_key().to_bytes(),
+            manager.election_public_key().to_bytes()
+        );
+    }
+
+    #[test]
+    pub fn rotating_an_unknown_member_fails() {
+        let mut rng = rand::thread_rng();
+        let committees = committees(&mut rng, &["alice", "bob"]);
+        let mut manager = PrivateVoteCommitteeDataManager::new(&mut rng, committees, 2);
+        let stranger = Wallet::new_account(&mut rng).identifier().into();
+
+        assert!(matches!(
+            manager.rotate_communication_key(&mut rng, &stranger),
+            Err(Error::InvalidCommitteeMemberId(_))
+        ));
+    }
+
+    #[test]
+    pub fn tally_is_decrypted_with_rotated_keys() {
+        let mut rng = rand::thread_rng();
+        let committees = committees(&mut rng, &["alice", "bob", "carol"]);
+        let mut manager = PrivateVoteCommitteeDataManager::new(&mut rng, committees.clone(), 2);
+        manager
+            .rotate_communication_key(&mut rng, &committees[0].1)
+            .unwrap();
+        manager
+            .rotate_communication_key(&mut rng, &committees[2].1)
+            .unwrap();
+
+        let election_public_key = manager.election_public_key();
+        let crs = Crs::from_hash(b"rotated committee");
+        let mut encrypted_tally = EncryptedTally::new(2, election_public_key.clone(), crs.clone());
+        for choice in &[0, 1, 1] {
+            let (vote, proof) =
+                encrypt_vote(&mut rng, &crs, &election_public_key, Vote::new(2, *choice));
+            let ballot =
+                Ballot::try_from_vote_and_proof(vote, &proof, &crs, &election_public_key).unwrap();
+            encrypted_tally.add(&ballot, 1);
+        }
+
+        let table = TallyOptimizationTable::generate(3);
+        let decrypted = manager.decrypt_proposal_tally(
+            &encrypted_tally,
+            &manager.member_public_keys(),
+            3,
+            &table,
+        );
+        assert_eq!(&*decrypted.tally_result, &[1, 2]);
+    }
 }
@@ -62,6 +62,14 @@ impl Wallet {
         self.address_nth(0)
     }
 
+    pub fn discrimination(&self) -> Discrimination {
+        self.discrimination
+    }
+
+    pub fn set_discrimination(&mut self, discrimination: Discrimination) {
+        self.discrimination = discrimination;
+    }
+
     pub fn address_nth(&self, i: usize) -> Address {
         self.signing_key(i)
             .identifier()
@@ -0,0 +1,242 @@
+//! Choice of the outputs a UTxO wallet spends to pay for a transaction.
+//!
+//! The strategies are deterministic: largest-first only depends on the order
+//! in which the outputs were added to the wallet, random-improve on its seed
+//! as well.
+
+use chain_impl_mockchain::fee::{FeeAlgorithm, LinearFee};
+use jormungandr_lib::interfaces::UTxOInfo;
+use rand::seq::SliceRandom;
+use rand_chacha::ChaChaRng;
+use rand_core::SeedableRng;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum InputSelectionError {
+    #[error("not enough funds: {needed} needed, {available} available")]
+    NotEnoughFunds { needed: u64, available: u64 },
+    #[error("the transaction would need more than 255 inputs")]
+    TooManyInputs,
+}
+
+/// The value a transaction has to spend: the value of its outputs and the
+/// fees, which grow with the number of inputs.
+#[derive(Debug, Clone, Copy)]
+pub struct SelectionTarget {
+    fees: LinearFee,
+    outputs_value: u64,
+    outputs: u8,
+}
+
+impl SelectionTarget {
+    pub fn new(fees: LinearFee, outputs_value: u64, outputs: u8) -> Self {
+        Self {
+            fees,
+            outputs_value,
+            outputs,
+        }
+    }
+
+    /// value needed with `inputs` inputs and no change
+    pub fn exact(&self, inputs: usize) -> u64 {
+        self.outputs_value + self.fees.calculate(None, inputs as u8, self.outputs).0
+    }
+
+    /// value needed with `inputs` inputs and an output for the change
+    pub fn with_change(&self, inputs: usize) -> u64 {
+        self.outputs_value
+            + self
+                .fees
+                .calculate(None, inputs as u8, self.outputs.saturating_add(1))
+                .0
+    }
+
+    /// the selected value balances the transaction, either exactly or with a
+    /// change output of some value
+    pub fn is_covered(&self, selected: u64, inputs: usize) -> bool {
+        selected == self.exact(inputs) || selected > self.with_change(inputs)
+    }
+
+    /// value of the change output, `None` when the selection is exact
+    pub fn change(&self, selected: u64, inputs: usize) -> Option<u64> {
+        if selected == self.exact(inputs) {
+            None
+        } else {
+            Some(selected - self.with_change(inputs))
+        }
+    }
+}
+
+/// Strategy of a UTxO wallet to choose the outputs it spends
+#[derive(Debug, Clone)]
+pub enum InputSelection {
+    /// spends the outputs of the highest values first, keeping the number of
+    /// inputs, and so the fees, low
+    LargestFirst,
+    /// picks random outputs until the target is covered, then adds more of
+    /// them while this brings the selected value closer to twice the target,
+    /// so that the change outputs are of a size similar to the payments
+    RandomImprove(ChaChaRng),
+}
+
+impl Default for InputSelection {
+    fn default() -> Self {
+        InputSelection::LargestFirst
+    }
+}
+
+impl InputSelection {
+    pub fn random_improve(seed: u64) -> Self {
+        InputSelection::RandomImprove(ChaChaRng::seed_from_u64(seed))
+    }
+
+    /// The outputs to spend among `utxos` to cover `target`
+    pub fn select(
+        &mut self,
+        utxos: &[UTxOInfo],
+        target: &SelectionTarget,
+    ) -> Result<Vec<UTxOInfo>, InputSelectionError> {
+        let selected = match self {
+            InputSelection::LargestFirst => largest_first(utxos, target),
+            InputSelection::RandomImprove(rng) => random_improve(utxos, target, rng),
+        };
+        let selected = selected.ok_or_else(|| InputSelectionError::NotEnoughFunds {
+            needed: target.with_change(1),
+            available: utxos.iter().map(value).sum(),
+        })?;
+        if selected.len() > u8::MAX as usize {
+            return Err(InputSelectionError::TooManyInputs);
+        }
+        Ok(selected)
+    }
+}
+
+fn value(utxo: &UTxOInfo) -> u64 {
+    (*utxo.associated_fund()).into()
+}
+
+fn largest_first(utxos: &[UTxOInfo], target: &SelectionTarget) -> Option<Vec<UTxOInfo>> {
+    let mut sorted = utxos.to_vec();
+    // stable, the outputs of equal values stay in the order of the wallet
+    sorted.sort_by_key(|utxo| std::cmp::Reverse(value(utxo)));
+
+    let mut selected = Vec::new();
+    let mut total = 0;
+    for utxo in sorted {
+        total += value(&utxo);
+        selected.push(utxo);
+        if target.is_covered(total, selected.len()) {
+            return Some(selected);
+        }
+    }
+    None
+}
+
+fn random_improve(
+    utxos: &[UTxOInfo],
+    target: &SelectionTarget,
+    rng: &mut ChaChaRng,
+) -> Option<Vec<UTxOInfo>> {
+    let mut remaining = utxos.to_vec();
+    remaining.shuffle(rng);
+    let mut remaining = remaining.into_iter();
+
+    let mut selected = Vec::new();
+    let mut total = 0;
+    while !target.is_covered(total, selected.len()) {
+        let utxo = remaining.next()?;
+        total += value(&utxo);
+        selected.push(utxo);
+    }
+
+    for utxo in remaining {
+        let inputs = selected.len() + 1;
+        let ideal = 2 * target.with_change(inputs);
+        let maximum = 3 * target.with_change(inputs);
+        let candidate = total + value(&utxo);
+        if candidate > maximum || ideal.saturating_sub(candidate) >= ideal.saturating_sub(total) {
+            continue;
+        }
+        if target.is_covered(candidate, inputs) {
+            total = candidate;
+            selected.push(utxo);
+        }
+    }
+    Some(selected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wallet::utxo::Wallet;
+    use chain_addr::Discrimination;
+    use jormungandr_lib::{crypto::hash::Hash, interfaces::Value};
+
+    fn utxos(values: &[u64]) -> Vec<UTxOInfo> {
+        let mut rng = ChaChaRng::seed_from_u64(0);
+        let address = Wallet::generate(&mut rng, Discrimination::Test).address();
+        values
+            .iter()
+            .enumerate()
+            .map(|(i, value)| {
+                UTxOInfo::new(
+                    Hash::from([i as u8; 32]),
+                    0,
+                    address.clone(),
+                    Value::from(*value),
+                )
+            })
+            .collect()
+    }
+
+    fn total(selected: &[UTxOInfo]) -> u64 {
+        selected.iter().map(value).sum()
+    }
+
+    #[test]
+    fn largest_first_spends_the_fewest_outputs() {
+        let utxos = utxos(&[10, 500, 30, 200]);
+        let target = SelectionTarget::new(LinearFee::new(1, 1, 0), 300, 1);
+        let selected = InputSelection::LargestFirst
+            .select(&utxos, &target)
+            .unwrap();
+        assert_eq!(total(&selected), 500);
+        assert_eq!(target.change(500, 1), Some(500 - 300 - 4));
+    }
+
+    #[test]
+    fn exact_selection_has_no_change() {
+        let utxos = utxos(&[100, 303]);
+        let target = SelectionTarget::new(LinearFee::new(1, 1, 0), 300, 1);
+        let selected = InputSelection::LargestFirst
+            .select(&utxos, &target)
+            .unwrap();
+        assert_eq!(total(&selected), 303);
+        assert_eq!(target.change(303, 1), None);
+    }
+
+    #[test]
+    fn random_improve_is_reproducible() {
+        let utxos = utxos(&[40, 70, 10, 90, 20, 60, 30, 80, 50]);
+        let target = SelectionTarget::new(LinearFee::new(1, 1, 0), 100, 1);
+        let first = InputSelection::random_improve(42)
+            .select(&utxos, &target)
+            .unwrap();
+        let second = InputSelection::random_improve(42)
+            .select(&utxos, &target)
+            .unwrap();
+        assert_eq!(first, second);
+        assert!(target.is_covered(total(&first), first.len()));
+        assert!(total(&first) <= 3 * target.with_change(first.len()));
+    }
+
+    #[test]
+    fn not_enough_funds() {
+        let utxos = utxos(&[10, 20]);
+        let target = SelectionTarget::new(LinearFee::new(1, 1, 0), 100, 1);
+        assert!(matches!(
+            InputSelection::LargestFirst.select(&utxos, &target),
+            Err(InputSelectionError::NotEnoughFunds { available: 30, .. })
+        ));
+    }
+}
@@ -1,11 +1,16 @@
 pub mod account;
 pub mod committee;
 pub mod delegation;
+pub mod input_selection;
+pub mod multisig;
+pub mod rebalance;
 pub mod utxo;
 
 pub use committee::{
     ElectionPublicKeyExtension, PrivateVoteCommitteeData, PrivateVoteCommitteeDataManager,
 };
+pub use input_selection::{InputSelection, InputSelectionError};
+pub use rebalance::{Rebalance, RebalanceError};
 
 use crate::{
     qr_code::{generate, KeyQrCode},
@@ -39,12 +44,19 @@ use chain_impl_mockchain::{
     vote::{Choice, CommitteeId},
 };
 use jormungandr_lib::{
-    crypto::{account::Identifier as AccountIdentifier, hash::Hash, key::Identifier},
-    interfaces::{Address, CommitteeIdDef, Initial, InitialUTxO, Value},
+    crypto::{
+        account::{Identifier as AccountIdentifier, SigningKeyParseError},
+        hash::Hash,
+        key::Identifier,
+    },
+    interfaces::{Address, CommitteeIdDef, Initial, InitialUTxO, UTxOInfo, Value},
 };
 use rand_core::{CryptoRng, RngCore};
 use std::io::Write;
-use std::{fs::File, path::Path};
+use std::{
+    fs::File,
+    path::{Path, PathBuf},
+};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -63,6 +75,22 @@ pub enum WalletError {
     ElectionPublicKey,
     #[error("invalid bech32 public key, expected {expected} hrp got {actual}")]
     InvalidBech32Key { expected: String, actual: String },
+    #[error("invalid committee key in {0}")]
+    InvalidCommitteeKey(PathBuf),
+    #[error("invalid committee member identifier '{0}'")]
+    InvalidCommitteeMemberId(String),
+    #[error("invalid committee threshold in {0}")]
+    InvalidCommitteeThreshold(PathBuf),
+    #[error("invalid secret key")]
+    InvalidSigningKey(#[from] SigningKeyParseError),
+    #[error("invalid address")]
+    InvalidAddress(#[from] chain_addr::Error),
+    #[error("{0} does not match the secret key")]
+    KeyFileMismatch(PathBuf),
+    #[error("cannot select the inputs of the transaction")]
+    InputSelection(#[from] input_selection::InputSelectionError),
+    #[error("cannot rebalance the delegation")]
+    Rebalance(#[from] rebalance::RebalanceError),
 }
 
 #[allow(clippy::large_enum_variant)]
@@ -71,6 +99,7 @@ pub enum Wallet {
     Account(account::Wallet),
     UTxO(utxo::Wallet),
     Delegation(delegation::Wallet),
+    Multisig(multisig::Wallet),
 }
 
 impl Wallet {
@@ -156,6 +185,20 @@ impl Wallet {
         Wallet::Delegation(delegation)
     }
 
+    /// a multisig account owned by `owners` new keys, `threshold` of which
+    /// sign the transactions
+    pub fn new_multisig<RNG>(rng: &mut RNG, threshold: usize, owners: usize) -> Wallet
+    where
+        RNG: CryptoRng + RngCore,
+    {
+        Wallet::Multisig(multisig::Wallet::generate(
+            rng,
+            threshold,
+            owners,
+            Discrimination::Test,
+        ))
+    }
+
     pub fn save_qr_code<P: AsRef<Path>>(&self, path: P, password: &[u8]) {
         let qr = match self {
             Wallet::Account(account) => {
@@ -172,6 +215,7 @@ impl Wallet {
                 delegation.last_signing_key().clone().into_secret_key(),
                 password,
             ),
+            Wallet::Multisig(_) => unimplemented!(),
         };
 
         qr.to_img().save(path).unwrap();
@@ -197,12 +241,29 @@ impl Wallet {
                 delegation.last_signing_key().clone().into_secret_key(),
                 password,
             ),
+            Wallet::Multisig(_) => unimplemented!(),
         };
 
         let mut file = File::create(path).unwrap();
         writeln!(file, "{}", qr)
     }
 
+    /// writes the account keys and address the way jcli does, see
+    /// [`account::Wallet::to_jcli_files`]
+    pub fn to_jcli_files<P: AsRef<Path>>(&self, dir: P) -> std::io::Result<()> {
+        match self {
+            Wallet::Account(account) => account.to_jcli_files(dir),
+            _ => unimplemented!(),
+        }
+    }
+
+    pub fn from_jcli_files<P: AsRef<Path>>(
+        dir: P,
+        spending_counter: Option<u32>,
+    ) -> Result<Wallet, WalletError> {
+        account::Wallet::from_jcli_files(dir, spending_counter).map(Wallet::Account)
+    }
+
     pub fn save_to_path<P: AsRef<Path>>(&self, path: P) -> std::io::Result<()> {
         let file = File::create(path).unwrap();
         self.save_to(&file)
@@ -221,9 +282,41 @@ impl Wallet {
             Wallet::Account(account) => account.address(),
             Wallet::UTxO(utxo) => utxo.address(),
             Wallet::Delegation(delegation) => delegation.address(),
+            Wallet::Multisig(multisig) => multisig.address(),
         }
     }
 
+    /// The address of the wallet, rendered with the given bech32 prefix
+    /// instead of the default one of the testing networks.
+    pub fn address_with_prefix(&self, prefix: &str) -> Address {
+        Address(prefix.to_owned(), self.address().1)
+    }
+
+    pub fn discrimination(&self) -> Discrimination {
+        match self {
+            Wallet::Account(account) => account.discrimination(),
+            Wallet::UTxO(utxo) => utxo.discrimination(),
+            Wallet::Delegation(delegation) => delegation.discrimination(),
+            Wallet::Multisig(multisig) => multisig.discrimination(),
+        }
+    }
+
+    /// Changes the discrimination of the addresses of the wallet, for networks
+    /// other than the testing ones. The keys of the wallet are kept.
+    pub fn set_discrimination(&mut self, discrimination: Discrimination) {
+        match self {
+            Wallet::Account(account) => account.set_discrimination(discrimination),
+            Wallet::UTxO(utxo) => utxo.set_discrimination(discrimination),
+            Wallet::Delegation(delegation) => delegation.set_discrimination(discrimination),
+            Wallet::Multisig(multisig) => multisig.set_discrimination(discrimination),
+        }
+    }
+
+    pub fn with_discrimination(mut self, discrimination: Discrimination) -> Self {
+        self.set_discrimination(discrimination);
+        self
+    }
+
     pub fn sign_slice(&self, data: &[u8]) -> Signature<TransactionBindingAuthDataPhantom, Ed25519> {
         match self {
             Wallet::Account(account) => account.signing_key().as_ref().sign_slice(data),
@@ -237,6 +330,7 @@ impl Wallet {
             Wallet::Account(account) => account.signing_key().to_bech32_str(),
             Wallet::UTxO(utxo) => utxo.last_signing_key().to_bech32_str(),
             Wallet::Delegation(delegation) => delegation.last_signing_key().to_bech32_str(),
+            Wallet::Multisig(_) => unimplemented!(),
         }
     }
 
@@ -245,6 +339,7 @@ impl Wallet {
             Wallet::Account(account) => Identifier::from(account.identifier().as_ref().clone()),
             Wallet::UTxO(utxo) => utxo.identifier(),
             Wallet::Delegation(delegation) => delegation.identifier(),
+            Wallet::Multisig(_) => unimplemented!(),
         }
     }
 
@@ -257,11 +352,30 @@ impl Wallet {
         }
     }
 
+    /// Adds an output a UTxO wallet can spend in its next transactions, see
+    /// [`utxo::Wallet::add_utxo`]
+    pub fn add_utxo(&mut self, utxo: UTxOInfo) -> bool {
+        match self {
+            Wallet::UTxO(wallet) => wallet.add_utxo(utxo),
+            _ => unimplemented!(),
+        }
+    }
+
+    /// How a UTxO wallet chooses the outputs it spends, largest first by
+    /// default
+    pub fn set_input_selection(&mut self, input_selection: InputSelection) {
+        match self {
+            Wallet::UTxO(utxo) => utxo.set_input_selection(input_selection),
+            _ => unimplemented!(),
+        }
+    }
+
     pub fn add_input_with_value(&self, value: Value) -> Input {
         match self {
             Wallet::Account(account) => account.add_input_with_value(value),
             Wallet::UTxO(_utxo) => unimplemented!(),
             Wallet::Delegation(_delegation) => unimplemented!(),
+            Wallet::Multisig(multisig) => multisig.add_input_with_value(value),
         }
     }
 
@@ -278,6 +392,7 @@ impl Wallet {
             Wallet::Account(account) => account.add_input(payload, iobuilder, fees),
             Wallet::UTxO(_utxo) => unimplemented!(),
             Wallet::Delegation(_delegation) => unimplemented!(),
+            Wallet::Multisig(multisig) => multisig.add_input(payload, iobuilder, fees),
         }
     }
 
@@ -290,12 +405,14 @@ impl Wallet {
             Wallet::Account(account) => account.mk_witness(block0_hash, signing_data),
             Wallet::UTxO(utxo) => utxo.mk_witness(block0_hash, signing_data),
             Wallet::Delegation(delegation) => delegation.mk_witness(block0_hash, signing_data),
+            Wallet::Multisig(multisig) => multisig.mk_witness(block0_hash, signing_data),
         }
     }
 
     pub fn confirm_transaction(&mut self) {
         match self {
             Wallet::Account(account) => account.increment_counter(),
+            Wallet::Multisig(multisig) => multisig.increment_counter(),
             _ => unimplemented!(),
         }
     }
@@ -303,6 +420,7 @@ impl Wallet {
     pub fn decrement_counter(&mut self) {
         match self {
             Wallet::Account(account) => account.decrement_counter(),
+            Wallet::Multisig(multisig) => multisig.decrement_counter(),
             _ => unimplemented!(),
         }
     }
@@ -310,6 +428,7 @@ impl Wallet {
     pub fn stake_key(&self) -> Option<UnspecifiedAccountIdentifier> {
         match &self {
             Wallet::Account(account) => Some(account.stake_key()),
+            Wallet::Multisig(multisig) => Some(multisig.stake_key()),
             _ => unimplemented!(),
         }
     }
@@ -318,6 +437,14 @@ impl Wallet {
         FragmentBuilder::full_delegation_cert_for_block0(valid_until, self, pool_id)
     }
 
+    pub fn split_delegation_cert_for_block0(
+        &self,
+        valid_until: BlockDate,
+        ratio: chain_impl_mockchain::account::DelegationRatio,
+    ) -> Initial {
+        FragmentBuilder::split_delegation_cert_for_block0(valid_until, self, ratio)
+    }
+
     pub fn transaction_to(
         &mut self,
         block0_hash: &Hash,
@@ -326,6 +453,9 @@ impl Wallet {
         address: Address,
         value: Value,
     ) -> Result<Fragment, WalletError> {
+        if let Wallet::UTxO(utxo) = self {
+            return utxo.transaction(block0_hash, fees, valid_until, &[(address, value)]);
+        }
         FragmentBuilder::new(block0_hash, fees, valid_until)
             .transaction(self, address, value)
             .map_err(WalletError::FragmentError)
@@ -339,11 +469,30 @@ impl Wallet {
         address: &[Address],
         value: Value,
     ) -> Result<Fragment, WalletError> {
+        if let Wallet::UTxO(utxo) = self {
+            let outputs: Vec<_> = address.iter().cloned().map(|a| (a, value)).collect();
+            return utxo.transaction(block0_hash, fees, valid_until, &outputs);
+        }
         FragmentBuilder::new(block0_hash, fees, valid_until)
             .transaction_to_many(self, address, value)
             .map_err(WalletError::FragmentError)
     }
 
+    pub fn transaction_to_many_with_values(
+        &mut self,
+        block0_hash: &Hash,
+        fees: &LinearFee,
+        valid_until: BlockDate,
+        outputs: &[(Address, Value)],
+    ) -> Result<Fragment, WalletError> {
+        if let Wallet::UTxO(utxo) = self {
+            return utxo.transaction(block0_hash, fees, valid_until, outputs);
+        }
+        FragmentBuilder::new(block0_hash, fees, valid_until)
+            .transaction_to_many_with_values(self, outputs)
+            .map_err(WalletError::FragmentError)
+    }
+
     pub fn issue_pool_retire_cert(
         &mut self,
         block0_hash: &Hash,
@@ -414,6 +563,25 @@ impl Wallet {
             .delegation_to_many(self, distribution))
     }
 
+    /// The certificate moving the delegation of the wallet from `current` to
+    /// `target_distribution`, `None` if both have the same proportions
+    pub fn issue_rebalance_delegation_cert(
+        &mut self,
+        block0_hash: &Hash,
+        fees: &LinearFee,
+        valid_until: BlockDate,
+        current: &[(&StakePool, u8)],
+        target_distribution: &[(&StakePool, u8)],
+    ) -> Result<Option<Fragment>, WalletError> {
+        let builder = FragmentBuilder::new(block0_hash, fees, valid_until);
+        Ok(match Rebalance::compute(current, target_distribution)? {
+            Rebalance::Unchanged => None,
+            Rebalance::Remove => Some(builder.delegation_remove(self)),
+            Rebalance::Full(stake_pool) => Some(builder.delegation(self, stake_pool)),
+            Rebalance::Split(distribution) => Some(builder.delegation_to_many(self, distribution)),
+        })
+    }
+
     pub fn remove_delegation_cert(
         &mut self,
         block0_hash: &Hash,
@@ -487,8 +655,10 @@ impl Wallet {
     }
 
     pub fn update_counter(&mut self, counter: u32) {
-        if let Wallet::Account(account) = self {
-            account.set_counter(counter)
+        match self {
+            Wallet::Account(account) => account.set_counter(counter),
+            Wallet::Multisig(multisig) => multisig.set_counter(counter),
+            _ => {}
         }
     }
 }
@@ -511,8 +681,44 @@ impl From<Wallet> for WalletLib {
                 None,
                 delegation.address().into(),
             ),
+            Wallet::Multisig(_) => unimplemented!(),
         };
         let address_data_value = AddressDataValue::new(address_data, ValueLib(0));
         WalletLib::from_address_data_value(address_data_value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn address_with_prefix_keeps_the_chain_address() {
+        let mut rng = rand::thread_rng();
+        let wallet = Wallet::new_account(&mut rng);
+
+        let address = wallet.address_with_prefix("addr");
+        assert_eq!(address.0, "addr");
+        assert_eq!(address.1, wallet.address().1);
+        assert!(address.to_string().starts_with("addr"));
+    }
+
+    #[test]
+    pub fn discrimination_changes_the_address_but_not_the_keys() {
+        let mut rng = rand::thread_rng();
+        for wallet in vec![Wallet::new_account(&mut rng), Wallet::new_utxo(&mut rng)] {
+            let identifier = wallet.identifier();
+            let test_address = wallet.address();
+            assert_eq!(wallet.discrimination(), Discrimination::Test);
+
+            let wallet = wallet.with_discrimination(Discrimination::Production);
+            assert_eq!(wallet.discrimination(), Discrimination::Production);
+            assert_eq!(
+                wallet.address().1.discrimination(),
+                Discrimination::Production
+            );
+            assert_ne!(wallet.address(), test_address);
+            assert_eq!(wallet.identifier(), identifier);
+        }
+    }
+}
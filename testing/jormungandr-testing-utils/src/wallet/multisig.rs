@@ -0,0 +1,227 @@
+use crate::testing::FragmentBuilderError;
+use chain_addr::{Discrimination, Kind};
+use chain_crypto::Ed25519;
+use chain_impl_mockchain::{
+    account::SpendingCounter,
+    fee::{FeeAlgorithm, LinearFee},
+    multisig::{DeclElement, Declaration, Identifier, Index, TreeIndex, WitnessBuilder},
+    transaction::{
+        Balance, Input, InputOutputBuilder, Payload, PayloadSlice, TransactionSignDataHash,
+        UnspecifiedAccountIdentifier, Witness, WitnessMultisigData,
+    },
+};
+use jormungandr_lib::{
+    crypto::{hash::Hash, key::SigningKey},
+    interfaces::{Address, Value},
+};
+use rand_core::{CryptoRng, RngCore};
+use std::convert::TryInto;
+
+/// wallet for a multisig account: the account is declared with the public
+/// keys of its owners and the number of them (the threshold) that must sign
+/// to spend from it. The wallet holds the secret keys of all the owners and
+/// signs with the first `threshold` of them, unless told otherwise.
+#[derive(Debug, Clone)]
+pub struct Wallet {
+    owners: Vec<SigningKey<Ed25519>>,
+
+    declaration: Declaration,
+
+    /// the indices of the owners signing the transactions
+    signers: Vec<usize>,
+
+    /// the counter as we know of this value needs to be in sync
+    /// with what is in the blockchain
+    internal_counter: SpendingCounter,
+
+    discrimination: Discrimination,
+}
+
+impl Wallet {
+    /// `threshold` must be between 1 and `owners`
+    pub fn generate<RNG>(
+        rng: &mut RNG,
+        threshold: usize,
+        owners: usize,
+        discrimination: Discrimination,
+    ) -> Self
+    where
+        RNG: CryptoRng + RngCore,
+    {
+        assert!(
+            threshold >= 1 && threshold <= owners,
+            "the threshold of a multisig account must be between 1 and the number of owners"
+        );
+        let owners: Vec<SigningKey<Ed25519>> = (0..owners)
+            .map(|_| SigningKey::generate(&mut *rng))
+            .collect();
+        let declaration = Declaration::new(
+            threshold,
+            owners
+                .iter()
+                .map(|owner| DeclElement::from_publickey(owner.identifier().as_ref()))
+                .collect(),
+        )
+        .expect("invalid multisig declaration");
+        Wallet {
+            owners,
+            declaration,
+            signers: (0..threshold).collect(),
+            internal_counter: SpendingCounter::zero(),
+            discrimination,
+        }
+    }
+
+    /// Chooses the owners signing the next transactions, by their index in
+    /// the declaration. Signing with fewer owners than the threshold makes
+    /// witnesses the ledger rejects.
+    pub fn set_signers(&mut self, signers: Vec<usize>) {
+        assert!(
+            signers.iter().all(|signer| *signer < self.owners.len()),
+            "no such owner in the multisig declaration"
+        );
+        self.signers = signers;
+    }
+
+    pub fn declaration(&self) -> &Declaration {
+        &self.declaration
+    }
+
+    pub fn identifier(&self) -> Identifier {
+        self.declaration.to_identifier()
+    }
+
+    pub fn address(&self) -> Address {
+        let identifier = self.identifier();
+        chain_addr::Address(
+            self.discrimination,
+            Kind::Multisig(
+                identifier
+                    .as_ref()
+                    .try_into()
+                    .expect("multisig identifier size doesn't match address kind"),
+            ),
+        )
+        .into()
+    }
+
+    pub fn discrimination(&self) -> Discrimination {
+        self.discrimination
+    }
+
+    pub fn set_discrimination(&mut self, discrimination: Discrimination) {
+        self.discrimination = discrimination;
+    }
+
+    pub fn set_counter(&mut self, value: u32) {
+        self.internal_counter = SpendingCounter::from(value);
+    }
+
+    pub fn increment_counter(&mut self) {
+        let v: u32 = self.internal_counter.into();
+        self.internal_counter = SpendingCounter::from(v + 1);
+    }
+
+    pub fn decrement_counter(&mut self) {
+        let v: u32 = self.internal_counter.into();
+        self.internal_counter = SpendingCounter::from(v - 1);
+    }
+
+    pub fn internal_counter(&self) -> SpendingCounter {
+        self.internal_counter
+    }
+
+    pub fn stake_key(&self) -> UnspecifiedAccountIdentifier {
+        UnspecifiedAccountIdentifier::from_multi_account(self.identifier())
+    }
+
+    pub fn mk_witness(
+        &self,
+        block0_hash: &Hash,
+        signing_data: &TransactionSignDataHash,
+    ) -> Witness {
+        let data = WitnessMultisigData::new(
+            &(*block0_hash).into_hash(),
+            signing_data,
+            self.internal_counter,
+        );
+        let mut builder = WitnessBuilder::new();
+        for signer in &self.signers {
+            let owner = &self.owners[*signer];
+            let index = Index::from_u8(*signer as u8).expect("too many multisig owners");
+            builder.append(
+                TreeIndex::D1(index),
+                owner.identifier().as_ref().clone(),
+                owner.as_ref().sign(&data),
+            );
+        }
+        Witness::Multisig(builder.finalize())
+    }
+
+    pub fn add_input_with_value(&self, value: Value) -> Input {
+        Input::from_account(self.stake_key(), value.into())
+    }
+
+    pub fn add_input<'a, Extra: Payload>(
+        &self,
+        payload: PayloadSlice<'a, Extra>,
+        iobuilder: &mut InputOutputBuilder,
+        fees: &LinearFee,
+    ) -> Result<(), FragmentBuilderError>
+    where
+        LinearFee: FeeAlgorithm,
+    {
+        let balance = iobuilder
+            .get_balance_with_placeholders(payload, fees, 1, 0)
+            .map_err(|_| FragmentBuilderError::CannotComputeBalance)?;
+        let value = match balance {
+            Balance::Negative(value) => value,
+            Balance::Zero => return Err(FragmentBuilderError::TransactionAlreadyBalanced),
+            Balance::Positive(value) => {
+                return Err(FragmentBuilderError::TransactionAlreadyExtraValue(
+                    value.into(),
+                ))
+            }
+        };
+
+        iobuilder
+            .add_input(&Input::from_account(self.stake_key(), value))
+            .unwrap();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn address_is_derived_from_the_declaration() {
+        let mut rng = rand::thread_rng();
+        let wallet = Wallet::generate(&mut rng, 2, 3, Discrimination::Test);
+        assert_eq!(wallet.declaration().threshold(), 2);
+        assert_eq!(wallet.declaration().total(), 3);
+
+        let address: chain_addr::Address = wallet.address().into();
+        assert_eq!(
+            address.kind(),
+            &Kind::Multisig(wallet.identifier().as_ref().try_into().unwrap())
+        );
+
+        let other = Wallet::generate(&mut rng, 2, 3, Discrimination::Test);
+        assert_ne!(wallet.address(), other.address());
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn threshold_above_the_owners_is_refused() {
+        Wallet::generate(&mut rand::thread_rng(), 4, 3, Discrimination::Test);
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn unknown_signers_are_refused() {
+        let mut wallet = Wallet::generate(&mut rand::thread_rng(), 1, 2, Discrimination::Test);
+        wallet.set_signers(vec![0, 2]);
+    }
+}
@@ -0,0 +1,158 @@
+//! Change of the delegation of an account from one distribution of its stake
+//! between stake pools to another.
+//!
+//! A delegation certificate replaces the whole delegation of the account, so
+//! the smallest change is no certificate at all when the distributions have
+//! the same proportions, then a full delegation for a single pool, then a
+//! split delegation with the smallest parts giving the target proportions.
+
+use crate::stake_pool::StakePool;
+use chain_impl_mockchain::certificate::PoolId;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum RebalanceError {
+    #[error("the distribution cannot be expressed in {max} parts, it needs {parts}")]
+    TooManyParts { parts: u64, max: u8 },
+}
+
+/// The certificate to issue to move a delegation to a target distribution
+#[derive(Debug, Clone)]
+pub enum Rebalance<'a> {
+    /// the delegation already has the target proportions
+    Unchanged,
+    /// the target distribution is empty, the delegation is removed
+    Remove,
+    Full(&'a StakePool),
+    Split(Vec<(&'a StakePool, u8)>),
+}
+
+impl<'a> Rebalance<'a> {
+    /// The change from `current` to `target`. The distributions are given as
+    /// parts for each pool: a pool can be listed several times and pools with
+    /// no part are ignored, as with a split delegation certificate.
+    pub fn compute(
+        current: &[(&StakePool, u8)],
+        target: &[(&'a StakePool, u8)],
+    ) -> Result<Self, RebalanceError> {
+        let target = normalize(target)?;
+        let unchanged = normalize(current)
+            .map(|current| {
+                current
+                    .iter()
+                    .map(|(pool, parts)| (pool.id(), *parts))
+                    .eq(target.iter().map(|(pool, parts)| (pool.id(), *parts)))
+            })
+            // a current distribution beyond a certificate is never the target
+            .unwrap_or(false);
+
+        Ok(match target.as_slice() {
+            _ if unchanged => Rebalance::Unchanged,
+            [] => Rebalance::Remove,
+            [(pool, _)] => Rebalance::Full(pool),
+            _ => Rebalance::Split(target),
+        })
+    }
+}
+
+/// Merges the parts of the pools listed several times, drops the pools with
+/// no part and divides the parts by their greatest common divisor. The pools
+/// are sorted by identifier.
+fn normalize<'a>(
+    distribution: &[(&'a StakePool, u8)],
+) -> Result<Vec<(&'a StakePool, u8)>, RebalanceError> {
+    let mut merged: Vec<(PoolId, &'a StakePool, u64)> = Vec::new();
+    for (pool, parts) in distribution.iter().filter(|(_, parts)| *parts > 0) {
+        let id = pool.id();
+        match merged.iter_mut().find(|(other, _, _)| *other == id) {
+            Some((_, _, total)) => *total += u64::from(*parts),
+            None => merged.push((id, pool, u64::from(*parts))),
+        }
+    }
+    merged.sort_by(|(a, _, _), (b, _, _)| a.as_ref().cmp(b.as_ref()));
+
+    let divisor = merged
+        .iter()
+        .fold(0, |divisor, (_, _, parts)| gcd(divisor, *parts))
+        .max(1);
+    let parts: u64 = merged.iter().map(|(_, _, parts)| parts / divisor).sum();
+    if parts > u64::from(u8::MAX) {
+        return Err(RebalanceError::TooManyParts {
+            parts,
+            max: u8::MAX,
+        });
+    }
+    Ok(merged
+        .into_iter()
+        .map(|(_, pool, parts)| (pool, (parts / divisor) as u8))
+        .collect())
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wallet::Wallet;
+
+    fn pools(count: usize) -> Vec<StakePool> {
+        let owner = Wallet::new_account(&mut rand::rngs::OsRng);
+        (0..count).map(|_| StakePool::new(&owner)).collect()
+    }
+
+    #[test]
+    fn same_proportions_need_no_certificate() {
+        let pools = pools(2);
+        let current = [(&pools[0], 1), (&pools[1], 3)];
+        let target = [(&pools[1], 6), (&pools[0], 1), (&pools[0], 1)];
+        assert!(matches!(
+            Rebalance::compute(&current, &target).unwrap(),
+            Rebalance::Unchanged
+        ));
+    }
+
+    #[test]
+    fn single_pool_is_a_full_delegation() {
+        let pools = pools(2);
+        let current = [(&pools[0], 1), (&pools[1], 1)];
+        let target = [(&pools[0], 0), (&pools[1], 10)];
+        match Rebalance::compute(&current, &target).unwrap() {
+            Rebalance::Full(pool) => assert_eq!(pool.id(), pools[1].id()),
+            other => panic!("unexpected rebalance {:?}", other),
+        }
+        assert!(matches!(
+            Rebalance::compute(&current, &[]).unwrap(),
+            Rebalance::Remove
+        ));
+    }
+
+    #[test]
+    fn split_parts_are_reduced() {
+        let pools = pools(3);
+        let target = [(&pools[0], 20), (&pools[1], 40), (&pools[2], 60)];
+        match Rebalance::compute(&[], &target).unwrap() {
+            Rebalance::Split(split) => {
+                let mut parts: Vec<u8> = split.iter().map(|(_, parts)| *parts).collect();
+                parts.sort_unstable();
+                assert_eq!(parts, vec![1, 2, 3]);
+            }
+            other => panic!("unexpected rebalance {:?}", other),
+        }
+    }
+
+    #[test]
+    fn too_many_parts() {
+        let pools = pools(2);
+        let target = [(&pools[0], 200), (&pools[1], 199)];
+        assert!(matches!(
+            Rebalance::compute(&[], &target),
+            Err(RebalanceError::TooManyParts { parts: 399, .. })
+        ));
+    }
+}
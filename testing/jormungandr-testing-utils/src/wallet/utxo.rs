@@ -1,11 +1,22 @@
+use super::input_selection::{InputSelection, SelectionTarget};
+use super::WalletError;
 use chain_addr::Discrimination;
-use chain_impl_mockchain::transaction::{TransactionSignDataHash, Witness};
+use chain_core::property::Fragment as _;
+use chain_impl_mockchain::{
+    block::BlockDate,
+    fee::LinearFee,
+    fragment::Fragment,
+    transaction::{
+        Input, InputEnum, InputOutputBuilder, TransactionSignDataHash, TxBuilder, UtxoPointer,
+        Witness,
+    },
+};
 use jormungandr_lib::{
     crypto::{
         hash::Hash,
         key::{self, Identifier},
     },
-    interfaces::{Address, UTxOInfo},
+    interfaces::{Address, UTxOInfo, Value},
 };
 use rand_chacha::ChaChaRng;
 use rand_core::{CryptoRng, RngCore, SeedableRng};
@@ -28,6 +39,9 @@ pub struct Wallet {
     /// sign the witness for the next transaction,
     utxos: Vec<(usize, UTxOInfo)>,
 
+    /// how the outputs spent by the next transactions are chosen
+    input_selection: InputSelection,
+
     discrimination: Discrimination,
 }
 
@@ -44,6 +58,7 @@ impl Wallet {
             seed,
             rng: ChaChaRng::from_seed(seed),
             utxos: Vec::new(),
+            input_selection: InputSelection::default(),
             discrimination,
         };
         wallet.generate_new_signing_key();
@@ -61,6 +76,14 @@ impl Wallet {
         self.address_nth(0)
     }
 
+    pub fn discrimination(&self) -> Discrimination {
+        self.discrimination
+    }
+
+    pub fn set_discrimination(&mut self, discrimination: Discrimination) {
+        self.discrimination = discrimination;
+    }
+
     pub fn address_nth(&self, i: usize) -> Address {
         self.signing_key(i)
             .identifier()
@@ -81,6 +104,125 @@ impl Wallet {
         self.signing_keys.get(index).expect("no signing key found")
     }
 
+    pub fn set_input_selection(&mut self, input_selection: InputSelection) {
+        self.input_selection = input_selection;
+    }
+
+    /// Adds an output the wallet can spend, e.g. a fund of block0. Returns
+    /// `false`, and ignores the output, if it is not sent to one of the
+    /// addresses of the wallet.
+    pub fn add_utxo(&mut self, utxo: UTxOInfo) -> bool {
+        let key_index =
+            (0..self.signing_keys.len()).find(|i| self.address_nth(*i) == *utxo.address());
+        match key_index {
+            Some(key_index) => {
+                self.utxos.push((key_index, utxo));
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// the outputs the wallet can spend, in the order they were added
+    pub fn utxos(&self) -> impl Iterator<Item = &UTxOInfo> {
+        self.utxos.iter().map(|(_, utxo)| utxo)
+    }
+
+    pub fn balance(&self) -> Value {
+        self.utxos()
+            .map(|utxo| -> u64 { (*utxo.associated_fund()).into() })
+            .sum::<u64>()
+            .into()
+    }
+
+    /// Transaction paying each of the outputs, from the outputs of the wallet
+    /// chosen by its input selection. The change, if any, goes to the first
+    /// address of the wallet as the last output. The wallet considers the
+    /// transaction accepted: the spent outputs are removed and the change is
+    /// added to its outputs.
+    pub fn transaction(
+        &mut self,
+        block0_hash: &Hash,
+        fees: &LinearFee,
+        valid_until: BlockDate,
+        outputs: &[(Address, Value)],
+    ) -> Result<Fragment, WalletError> {
+        let outputs_value = outputs
+            .iter()
+            .map(|(_, value)| -> u64 { (*value).into() })
+            .sum();
+        let target = SelectionTarget::new(*fees, outputs_value, outputs.len() as u8);
+        let utxos: Vec<UTxOInfo> = self.utxos().cloned().collect();
+        let selected = self.input_selection.select(&utxos, &target)?;
+        let selected_value = selected
+            .iter()
+            .map(|utxo| -> u64 { (*utxo.associated_fund()).into() })
+            .sum();
+        let change = target.change(selected_value, selected.len());
+
+        let mut iobuilder = InputOutputBuilder::empty();
+        for utxo in &selected {
+            iobuilder
+                .add_input(&Input::from_enum(InputEnum::UtxoInput(UtxoPointer {
+                    transaction_id: utxo.transaction_id().into_hash(),
+                    output_index: utxo.index_in_transaction(),
+                    value: (*utxo.associated_fund()).into(),
+                })))
+                .map_err(|_| WalletError::CannotAddInput)?;
+        }
+        for (address, value) in outputs {
+            iobuilder
+                .add_output(address.clone().into(), (*value).into())
+                .unwrap();
+        }
+        if let Some(change) = change {
+            iobuilder
+                .add_output(self.address().into(), Value::from(change).into())
+                .unwrap();
+        }
+
+        let ios = iobuilder.build();
+        let txbuilder = TxBuilder::new()
+            .set_nopayload()
+            .set_expiry_date(valid_until)
+            .set_ios(&ios.inputs, &ios.outputs);
+
+        let sign_data = txbuilder.get_auth_data_for_witness().hash();
+        let witnesses: Vec<Witness> = selected
+            .iter()
+            .map(|utxo| {
+                let key = self.signing_key(self.key_index(utxo));
+                Witness::new_utxo(&(*block0_hash).into_hash(), &sign_data, |d| {
+                    key.as_ref().sign(d)
+                })
+            })
+            .collect();
+        let fragment =
+            Fragment::Transaction(txbuilder.set_witnesses(&witnesses).set_payload_auth(&()));
+
+        self.utxos.retain(|(_, utxo)| !selected.contains(utxo));
+        if let Some(change) = change {
+            self.utxos.push((
+                0,
+                UTxOInfo::new(
+                    fragment.id().into(),
+                    outputs.len() as u8,
+                    self.address(),
+                    Value::from(change),
+                ),
+            ));
+        }
+        Ok(fragment)
+    }
+
+    fn key_index(&self, utxo: &UTxOInfo) -> usize {
+        self.utxos
+            .iter()
+            .find(|(_, owned)| owned == utxo)
+            .map(|(key_index, _)| *key_index)
+            .expect("the output belongs to the wallet")
+    }
+
     pub fn save_to<W: std::io::Write>(&self, mut w: W) -> std::io::Result<()> {
         writeln!(w, "{}", self.last_signing_key().to_bech32_str())
     }
@@ -95,3 +237,41 @@ impl Wallet {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transaction_spends_the_selected_outputs_and_keeps_the_change() {
+        let mut rng = ChaChaRng::seed_from_u64(0);
+        let mut wallet = Wallet::generate(&mut rng, Discrimination::Test);
+        let receiver = Wallet::generate(&mut rng, Discrimination::Test);
+        for (i, value) in [100u64, 500, 200].iter().enumerate() {
+            let utxo = UTxOInfo::new([i as u8; 32].into(), 0, wallet.address(), (*value).into());
+            assert!(wallet.add_utxo(utxo));
+        }
+        assert!(!wallet.add_utxo(UTxOInfo::new(
+            [9; 32].into(),
+            0,
+            receiver.address(),
+            Value::from(1000)
+        )));
+
+        let fragment = wallet
+            .transaction(
+                &[0; 32].into(),
+                &LinearFee::new(1, 1, 0),
+                BlockDate::first(),
+                &[(receiver.address(), Value::from(300))],
+            )
+            .unwrap();
+
+        // the output of 500 is spent, paying 300 and 4 of fees
+        let change = UTxOInfo::new(fragment.id().into(), 1, wallet.address(), Value::from(196));
+        let utxos: Vec<_> = wallet.utxos().cloned().collect();
+        assert_eq!(utxos.len(), 3);
+        assert_eq!(utxos[2], change);
+        assert_eq!(wallet.balance(), Value::from(100 + 200 + 196));
+    }
+}
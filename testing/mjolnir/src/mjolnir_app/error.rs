@@ -1,6 +1,7 @@
 use jormungandr_integration_tests::common::jormungandr::{JormungandrError, StartupError};
 use jormungandr_testing_utils::testing::block0::GetBlock0Error;
 use jormungandr_testing_utils::testing::node::RestError;
+use jormungandr_testing_utils::testing::{FragmentSenderError, ReplayError};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -17,4 +18,8 @@ pub enum MjolnirError {
     PaceTooLow(u64),
     #[error("get block0 error")]
     GetBlock0Error(#[from] GetBlock0Error),
+    #[error("cannot send fragment")]
+    FragmentSenderError(#[from] FragmentSenderError),
+    #[error("cannot load persistent log")]
+    ReplayError(#[from] ReplayError),
 }
@@ -3,6 +3,7 @@ use structopt::StructOpt;
 use thiserror::Error;
 
 mod batch;
+mod replay;
 mod standard;
 
 #[derive(StructOpt, Debug)]
@@ -11,6 +12,8 @@ pub enum FragmentLoadCommand {
     Batch(batch::Batch),
     /// sends fragments in single manner
     Standard(standard::Standard),
+    /// replays fragments recorded in persistent fragment log
+    Replay(replay::Replay),
 }
 
 #[derive(Error, Debug)]
@@ -24,6 +27,7 @@ impl FragmentLoadCommand {
         match self {
             FragmentLoadCommand::Batch(batch) => batch.exec(),
             FragmentLoadCommand::Standard(standard) => standard.exec(),
+            FragmentLoadCommand::Replay(replay) => replay.exec(),
         }
     }
 }
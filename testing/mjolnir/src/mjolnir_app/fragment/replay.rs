@@ -0,0 +1,102 @@
+use crate::mjolnir_app::MjolnirError;
+use chain_impl_mockchain::block::BlockDate;
+use jormungandr_lib::{crypto::hash::Hash, interfaces::BlockDate as BlockDateDto};
+use jormungandr_testing_utils::{
+    testing::{
+        FragmentRemapper, FragmentSender, FragmentSenderSetup, PersistentLogReplay,
+        RemoteJormungandrBuilder, ReplayConfig, ValidityRemap,
+    },
+    wallet::Wallet,
+};
+use rand::rngs::OsRng;
+use std::{path::PathBuf, str::FromStr};
+use structopt::StructOpt;
+
+/// replays workload recorded in persistent fragment log against given node
+#[derive(StructOpt, Debug)]
+pub struct Replay {
+    /// address in format:
+    /// /ip4/54.193.75.55/tcp/3000
+    #[structopt(short = "a", long = "address")]
+    pub endpoint: String,
+
+    /// folder with persistent fragment log files
+    #[structopt(long = "log")]
+    pub log: PathBuf,
+
+    /// replay speed multiplier (2.0 replays twice as fast as original)
+    #[structopt(long = "speedup", default_value = "1.0")]
+    pub speedup: f64,
+
+    /// expiry date (epoch.slot) of the replayed fragments
+    #[structopt(long = "valid-until", default_value = "1.0")]
+    pub valid_until: BlockDateDto,
+
+    /// instead of setting the same expiry date for all fragments, map the earliest
+    /// expiry date from log to `--valid-until` and keep the distance for later ones
+    #[structopt(long = "shift-validity")]
+    pub shift_validity: bool,
+
+    /// number of wallets which will be funded by faucet and used to replay fragments
+    #[structopt(short = "w", long = "wallets", default_value = "100")]
+    pub wallets: usize,
+
+    #[structopt(long = "key", short = "k")]
+    faucet_key_file: PathBuf,
+
+    #[structopt(long = "spending-counter", short = "s")]
+    faucet_spending_counter: u32,
+}
+
+impl Replay {
+    pub fn exec(&self) -> Result<(), MjolnirError> {
+        let replay = PersistentLogReplay::from_dir(&self.log)?;
+        println!(
+            "{} fragments loaded, original workload lasted {:?}",
+            replay.len(),
+            replay.duration()
+        );
+
+        let mut faucet = Wallet::import_account(
+            self.faucet_key_file.clone(),
+            Some(self.faucet_spending_counter),
+        );
+        let mut builder = RemoteJormungandrBuilder::new("node".to_owned());
+        builder.with_rest(self.endpoint.parse().unwrap());
+        let remote_jormungandr = builder.build();
+
+        let settings = remote_jormungandr.rest().settings()?;
+        let block0_hash = Hash::from_str(&settings.block0_hash).unwrap();
+        let fees = settings.fees;
+        let valid_until: BlockDate = self.valid_until.into();
+
+        let wallets: Vec<Wallet> = std::iter::from_fn(|| Some(Wallet::new_account(&mut OsRng)))
+            .take(self.wallets)
+            .collect();
+        FragmentSender::new(
+            block0_hash,
+            fees,
+            valid_until,
+            FragmentSenderSetup::resend_3_times(),
+        )
+        .send_transaction_to_many(
+            &mut faucet,
+            &wallets,
+            &remote_jormungandr,
+            1_000.into(),
+        )?;
+
+        let validity = if self.shift_validity {
+            ValidityRemap::Shift(valid_until)
+        } else {
+            ValidityRemap::Fixed(valid_until)
+        };
+        let config = ReplayConfig::new(validity).speedup(self.speedup);
+        let mut remapper = FragmentRemapper::new(block0_hash, fees, wallets);
+
+        replay
+            .run(&config, &mut remapper, &remote_jormungandr)
+            .print();
+        Ok(())
+    }
+}
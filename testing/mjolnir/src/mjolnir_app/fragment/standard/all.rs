@@ -4,8 +4,8 @@ use jormungandr_integration_tests::common::startup;
 use jormungandr_lib::{crypto::hash::Hash, interfaces::BlockDate};
 use jormungandr_testing_utils::{
     testing::{
-        node::time, FragmentGenerator, FragmentSender, FragmentSenderSetup, FragmentStatusProvider,
-        RemoteJormungandrBuilder,
+        network_builder::Seed, node::time, FragmentGenerator, FragmentSender, FragmentSenderSetup,
+        FragmentStatusProvider, RemoteJormungandrBuilder,
     },
     wallet::Wallet,
 };
@@ -59,6 +59,11 @@ pub struct AllFragments {
     /// load test rump up period
     #[structopt(long = "rump-up")]
     rump_up: u32,
+
+    /// seed of the random choices, to replay a previous run. A random seed
+    /// is used if none is provided
+    #[structopt(long = "seed")]
+    seed: Option<Seed>,
 }
 
 impl AllFragments {
@@ -94,6 +99,10 @@ impl AllFragments {
             30,
             fragment_sender,
         );
+        if let Some(seed) = self.seed {
+            generator = generator.with_seed(seed);
+        }
+        let seed = *generator.seed();
 
         let current_date = BlockDate::from_str(
             rest.stats()
@@ -131,6 +140,7 @@ impl AllFragments {
         let stats =
             jortestkit::load::start_async(generator, fragment_status_provider, config, title);
         stats.print_summary(title);
+        println!("seed: {}", seed);
 
         Ok(())
     }